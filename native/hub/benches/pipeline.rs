@@ -0,0 +1,69 @@
+//! 데이터 경로 처리량 측정
+//!
+//! cargo bench --features bench
+//! cargo bench --features bench -- --save-baseline target/pipeline.json
+//! cargo bench --features bench -- --baseline target/pipeline.json
+//!
+//! 기준 결과보다 처리량이 10% 이상 떨어지면 실패 코드로 끝난다.
+
+use hub::bench::{BenchResult, DEFAULT_CASES, regressions, run};
+use std::process::ExitCode;
+
+const TOLERANCE: f64 = 0.1;
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let (mut save_to, mut compare_with) = (None, None);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--save-baseline" => save_to = args.next(),
+            "--baseline" => compare_with = args.next(),
+            _ => {} // cargo bench가 넘기는 --bench 등은 무시
+        }
+    }
+
+    let results = match run(&DEFAULT_CASES).await {
+        Ok(results) => results,
+        Err(e) => {
+            eprintln!("Benchmark failed: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    for result in &results {
+        println!("{}", result);
+    }
+
+    if let Some(path) = save_to {
+        let saved = serde_json::to_vec_pretty(&results)
+            .map_err(|e| e.to_string())
+            .and_then(|json| std::fs::write(&path, json).map_err(|e| e.to_string()));
+        match saved {
+            Ok(()) => println!("Saved baseline to {}", path),
+            Err(e) => eprintln!("Failed to save baseline {}: {}", path, e),
+        }
+    }
+
+    if let Some(path) = compare_with {
+        let baseline: Vec<BenchResult> = match std::fs::read(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|json| serde_json::from_slice(&json).map_err(|e| e.to_string()))
+        {
+            Ok(baseline) => baseline,
+            Err(e) => {
+                eprintln!("Failed to read baseline {}: {}", path, e);
+                return ExitCode::FAILURE;
+            }
+        };
+        let slower = regressions(&baseline, &results, TOLERANCE);
+        if !slower.is_empty() {
+            for line in slower {
+                eprintln!("Regression: {}", line);
+            }
+            return ExitCode::FAILURE;
+        }
+        println!("No regressions against {}", path);
+    }
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,27 @@
+//! Flutter 없이 감독자 트리를 띄워 로그인 → 사용자 데이터 조회 흐름을 확인한다.
+//!
+//! cargo run --example headless --features headless -- demo password
+
+use hub::headless::run_login_scenario;
+use std::process::ExitCode;
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let username = args.next().unwrap_or_else(|| "demo".to_string());
+    let password = args.next().unwrap_or_else(|| "password".to_string());
+
+    match run_login_scenario(&username, &password).await {
+        Ok(report) => {
+            println!(
+                "Created {} actors, logged in as {}, fetched {} items",
+                report.actor_count, report.user_id, report.item_count
+            );
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Headless scenario failed: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -1,119 +1,281 @@
-// use std::time::Duration;
-
-// use crate::signals::{BigBool, SmallNumber, SmallText};
-// use async_trait::async_trait;
-// use messages::prelude::{Actor, Address, Context, Handler, Notifiable};
-// use rinf::{DartSignal, RustSignal, debug_print};
-// use tokio::task::JoinSet;
-// use tokio::time::interval;
-
-// // Uncomment below to target the web.
-// // use tokio_with_wasm::alias as tokio;
-
-// /// The first actor.
-// pub struct FirstActor {
-//     /// Owned tasks that are canceled when the actor is dropped.
-//     _owned_tasks: JoinSet<()>,
-// }
-
-// // Implementing the `Actor` trait for `CountingActor`.
-// // This defines `FirstActor` as an actor in the async system.
-// impl Actor for FirstActor {}
-
-// impl FirstActor {
-//     /// Creates the actor and initializes its fields.
-//     pub fn new(self_addr: Address<Self>) -> Self {
-//         let mut _owned_tasks = JoinSet::new();
-//         _owned_tasks.spawn(Self::listen_to_dart(self_addr.clone()));
-//         _owned_tasks.spawn(Self::listen_to_timer(self_addr));
-//         FirstActor { _owned_tasks }
-//     }
-// }
-
-// // Implementing the `Notifiable` trait
-// // allows an actor's loop to listen for a specific message type.
-// #[async_trait]
-// impl Notifiable<SmallText> for FirstActor {
-//     async fn notify(&mut self, msg: SmallText, _: &Context<Self>) {
-//         debug_print!("{}", msg.text);
-//         SmallNumber { number: 7 }.send_signal_to_dart();
-//     }
-// }
-
-// // Implementing the `Handler` trait
-// // allows an actor's loop to respond to a specific message type.
-// #[async_trait]
-// impl Handler<BigBool> for FirstActor {
-//     type Result = bool;
-//     async fn handle(&mut self, msg: BigBool, _: &Context<Self>) -> bool {
-//         msg.send_signal_to_dart();
-//         false
-//     }
-// }
-
-// impl FirstActor {
-//     /// Listen to an external source, which in this case is Dart.
-//     async fn listen_to_dart(mut self_addr: Address<Self>) {
-//         let receiver = SmallText::get_dart_signal_receiver();
-//         while let Some(signal_pack) = receiver.recv().await {
-//             let _ = self_addr.notify(signal_pack.message).await;
-//         }
-//     }
-
-//     /// Listen to an external source, which in this case is a timer.
-//     async fn listen_to_timer(mut self_addr: Address<Self>) {
-//         let mut time_interval = interval(Duration::from_secs(3));
-//         let text = "From an owned task".to_owned();
-//         loop {
-//             time_interval.tick().await;
-//             let _ = self_addr.notify(SmallText { text: text.clone() }).await;
-//         }
-//     }
-// }
-
-use crate::signals::{SampleNumberInput, SampleNumberOutput};
+use crate::{
+    signals::{
+        CounterChangedSignal, DecrementCounter, IncrementCounter, ResetCounter, SampleNumberInput,
+        SampleNumberOutput, SetCounterStep,
+    },
+    study_actors::{
+        messages::{PersistState, PersistedState, RestoreState},
+        storage::Storage,
+    },
+};
 use async_trait::async_trait;
 use messages::{
     actor::Actor,
-    prelude::{Address, Context, Notifiable},
+    prelude::{Address, Context, Handler, Notifiable},
 };
 use rinf::{DartSignal, RustSignal, debug_print};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, sync::Arc};
 use tokio::task::JoinSet;
 
+/// Counters are saved under this prefix, followed by the counter's name.
+const KEY_PREFIX: &str = "counter/";
+/// The counter behind the original sample button, which counts in sevens.
+const SAMPLE_COUNTER: &str = "sample";
+const SAMPLE_STEP: i32 = 7;
+/// Bump this when `Counter` changes shape, so that older saved states are discarded.
+const STATE_VERSION: u32 = 1;
+
+/// Keeps any number of named counters and saves every change,
+/// so the counts survive app restarts.
 pub struct CountingActor {
-    count: i32,
+    counters: HashMap<String, Counter>,
+    storage: Arc<dyn Storage>,
     _owned_tasks: JoinSet<()>,
 }
 
 impl Actor for CountingActor {}
 
+/// The state of one counter.
+/// Changes only happen through `apply`, which returns the next state.
+#[derive(Clone, Copy, Serialize, Deserialize)]
+struct Counter {
+    value: i32,
+    step: i32,
+}
+
+enum CounterAction {
+    Increment,
+    Decrement,
+    Reset,
+    SetStep(i32),
+}
+
+impl Counter {
+    fn new(step: i32) -> Self {
+        Counter { value: 0, step }
+    }
+
+    fn apply(self, action: CounterAction) -> Self {
+        match action {
+            CounterAction::Increment => Counter {
+                value: self.value.saturating_add(self.step),
+                ..self
+            },
+            CounterAction::Decrement => Counter {
+                value: self.value.saturating_sub(self.step),
+                ..self
+            },
+            CounterAction::Reset => Counter { value: 0, ..self },
+            CounterAction::SetStep(step) => Counter { step, ..self },
+        }
+    }
+}
+
+/// Counters read back from storage when the actor starts.
+struct RestoredCounters(Vec<(String, Counter)>);
+
 impl CountingActor {
-    pub fn new(self_addr: Address<Self>) -> Self {
+    pub fn new(self_addr: Address<Self>, storage: Arc<dyn Storage>) -> Self {
         let mut owned_tasks = JoinSet::new();
-        owned_tasks.spawn(Self::listen_to_button_click(self_addr));
+        owned_tasks.spawn(Self::restore_counters(self_addr.clone(), storage.clone()));
+        owned_tasks.spawn(Self::listen_to_dart::<SampleNumberInput>(self_addr.clone()));
+        owned_tasks.spawn(Self::listen_to_dart::<IncrementCounter>(self_addr.clone()));
+        owned_tasks.spawn(Self::listen_to_dart::<DecrementCounter>(self_addr.clone()));
+        owned_tasks.spawn(Self::listen_to_dart::<ResetCounter>(self_addr.clone()));
+        owned_tasks.spawn(Self::listen_to_dart::<SetCounterStep>(self_addr));
         CountingActor {
-            count: 0,
+            counters: HashMap::new(),
+            storage,
             _owned_tasks: owned_tasks,
         }
     }
 
-    async fn listen_to_button_click(mut self_addr: Address<Self>) {
-        let receiver = SampleNumberInput::get_dart_signal_receiver();
+    /// Forwards one kind of Dart signal to this actor.
+    async fn listen_to_dart<S>(mut self_addr: Address<Self>)
+    where
+        S: DartSignal + Send + 'static,
+        Self: Notifiable<S>,
+    {
+        let receiver = S::get_dart_signal_receiver();
         while let Some(signal_pack) = receiver.recv().await {
-            let message = signal_pack.message;
-            let _ = self_addr.notify(message).await;
+            if self_addr.notify(signal_pack.message).await.is_err() {
+                break;
+            }
+        }
+    }
+
+    /// Loads every saved counter and hands them to the actor,
+    /// so Dart can show them right after a restart.
+    async fn restore_counters(mut self_addr: Address<Self>, storage: Arc<dyn Storage>) {
+        let entries = match storage.scan_prefix(KEY_PREFIX).await {
+            Ok(entries) => entries,
+            Err(e) => {
+                debug_print!("Failed to restore counters: {}", e);
+                return;
+            }
+        };
+        let counters = entries
+            .into_iter()
+            .filter_map(|(key, bytes)| {
+                let name = key.strip_prefix(KEY_PREFIX)?.to_owned();
+                let counter = serde_json::from_slice(&bytes).ok()?;
+                Some((name, counter))
+            })
+            .collect();
+        let _ = self_addr.notify(RestoredCounters(counters)).await;
+    }
+
+    /// Shows a counter from an earlier run, unless it has already changed in this one.
+    /// Returns whether the counter was taken.
+    fn adopt(&mut self, name: String, counter: Counter) -> bool {
+        if self.counters.contains_key(&name) {
+            return false;
+        }
+        self.counters.insert(name.clone(), counter);
+        CounterChangedSignal {
+            name,
+            value: counter.value,
+            step: counter.step,
+        }
+        .send_signal_to_dart();
+        true
+    }
+
+    /// Applies an action to a counter, saves the result, and tells Dart.
+    /// A counter that isn't in memory yet is read from storage first,
+    /// in case the action arrives before the restore finishes.
+    async fn change(&mut self, name: String, action: CounterAction) -> Counter {
+        let current = match self.counters.get(&name) {
+            Some(counter) => *counter,
+            None => self.load_counter(&name).await,
+        };
+        let counter = current.apply(action);
+        self.counters.insert(name.clone(), counter);
+        self.save_counter(&name, counter).await;
+        CounterChangedSignal {
+            name,
+            value: counter.value,
+            step: counter.step,
+        }
+        .send_signal_to_dart();
+        counter
+    }
+
+    async fn load_counter(&self, name: &str) -> Counter {
+        let default_step = if name == SAMPLE_COUNTER {
+            SAMPLE_STEP
+        } else {
+            1
+        };
+        match self.storage.load(&counter_key(name)).await {
+            Ok(bytes) => serde_json::from_slice(&bytes).unwrap_or(Counter::new(default_step)),
+            Err(_) => Counter::new(default_step),
+        }
+    }
+
+    /// A failed save only costs persistence, so the count keeps going.
+    async fn save_counter(&self, name: &str, counter: Counter) {
+        let saved = match serde_json::to_vec(&counter) {
+            Ok(bytes) => self
+                .storage
+                .save(&counter_key(name), &bytes)
+                .await
+                .map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+        if let Err(e) = saved {
+            debug_print!("Failed to save counter {}: {}", name, e);
         }
     }
 }
 
+fn counter_key(name: &str) -> String {
+    format!("{}{}", KEY_PREFIX, name)
+}
+
+#[async_trait]
+impl Notifiable<RestoredCounters> for CountingActor {
+    async fn notify(&mut self, msg: RestoredCounters, _: &Context<Self>) {
+        for (name, counter) in msg.0 {
+            // A counter changed before the restore finished is already newer.
+            self.adopt(name, counter);
+        }
+    }
+}
+
+/// Hands every counter over to the next launch.
+#[async_trait]
+impl Handler<PersistState> for CountingActor {
+    type Result = Option<PersistedState>;
+
+    async fn handle(&mut self, _: PersistState, _: &Context<Self>) -> Self::Result {
+        if self.counters.is_empty() {
+            return None;
+        }
+        PersistedState::new(STATE_VERSION, &self.counters)
+    }
+}
+
+/// Takes the counters from the last launch and saves the ones it adopts,
+/// so they are also found by name later on.
+#[async_trait]
+impl Handler<RestoreState> for CountingActor {
+    type Result = bool;
+
+    async fn handle(&mut self, msg: RestoreState, _: &Context<Self>) -> Self::Result {
+        let Some(counters) = msg.0.read::<HashMap<String, Counter>>(STATE_VERSION) else {
+            return false;
+        };
+        for (name, counter) in counters {
+            if self.adopt(name.clone(), counter) {
+                self.save_counter(&name, counter).await;
+            }
+        }
+        true
+    }
+}
+
+#[async_trait]
+impl Notifiable<IncrementCounter> for CountingActor {
+    async fn notify(&mut self, msg: IncrementCounter, _: &Context<Self>) {
+        self.change(msg.name, CounterAction::Increment).await;
+    }
+}
+
+#[async_trait]
+impl Notifiable<DecrementCounter> for CountingActor {
+    async fn notify(&mut self, msg: DecrementCounter, _: &Context<Self>) {
+        self.change(msg.name, CounterAction::Decrement).await;
+    }
+}
+
+#[async_trait]
+impl Notifiable<ResetCounter> for CountingActor {
+    async fn notify(&mut self, msg: ResetCounter, _: &Context<Self>) {
+        self.change(msg.name, CounterAction::Reset).await;
+    }
+}
+
+#[async_trait]
+impl Notifiable<SetCounterStep> for CountingActor {
+    async fn notify(&mut self, msg: SetCounterStep, _: &Context<Self>) {
+        self.change(msg.name, CounterAction::SetStep(msg.step))
+            .await;
+    }
+}
+
+/// The original sample button, now backed by the `sample` counter.
 #[async_trait]
 impl Notifiable<SampleNumberInput> for CountingActor {
     async fn notify(&mut self, msg: SampleNumberInput, _: &Context<Self>) {
         debug_print!("{}", msg.letter);
-        self.count += 7;
+        let counter = self
+            .change(SAMPLE_COUNTER.to_owned(), CounterAction::Increment)
+            .await;
 
         SampleNumberOutput {
-            current_number: self.count,
+            current_number: counter.value,
             dummy_one: 11,
             dummy_two: None,
             dummy_three: vec![22, 33, 44, 55],
@@ -121,3 +283,61 @@ impl Notifiable<SampleNumberInput> for CountingActor {
         .send_signal_to_dart();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, error::Error, sync::Arc};
+
+    use super::{Counter, CountingActor, STATE_VERSION, counter_key};
+    use crate::{
+        signals::IncrementCounter,
+        study_actors::{
+            messages::{PersistState, PersistedState, RestoreState},
+            storage::{MemoryStorage, Storage},
+            test_support::{TestActorHarness, settle},
+        },
+    };
+
+    fn increment(name: &str) -> IncrementCounter {
+        IncrementCounter {
+            name: name.to_owned(),
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn counters_survive_a_persist_and_restore() -> Result<(), Box<dyn Error>> {
+        let mut first = TestActorHarness::start(|addr| {
+            CountingActor::new(addr, Arc::new(MemoryStorage::new()))
+        });
+        first.notify(increment("sample")).await?;
+        first.notify(increment("laps")).await?;
+        first.notify(increment("laps")).await?;
+        let persisted = first.send(PersistState).await?.ok_or("nothing persisted")?;
+
+        // A fresh storage, as if the saved counters were lost with the old database.
+        let storage = Arc::new(MemoryStorage::new());
+        let mut second = TestActorHarness::start(|addr| CountingActor::new(addr, storage.clone()));
+        settle().await;
+        assert!(second.send(RestoreState(persisted.clone())).await?);
+        let restored = second.send(PersistState).await?.ok_or("nothing restored")?;
+        assert_eq!(restored.state, persisted.state);
+
+        let saved = storage.load(&counter_key("laps")).await?;
+        let laps: Counter = serde_json::from_slice(&saved)?;
+        assert_eq!((laps.value, laps.step), (2, 1));
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn counters_from_another_version_are_discarded() -> Result<(), Box<dyn Error>> {
+        let mut actor = TestActorHarness::start(|addr| {
+            CountingActor::new(addr, Arc::new(MemoryStorage::new()))
+        });
+        settle().await;
+        let counters = HashMap::from([("laps".to_owned(), Counter::new(1))]);
+        let future = PersistedState::new(STATE_VERSION + 1, &counters).ok_or("unserializable")?;
+        assert!(!actor.send(RestoreState(future)).await?);
+        assert!(actor.send(PersistState).await?.is_none());
+        Ok(())
+    }
+}
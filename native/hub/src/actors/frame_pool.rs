@@ -0,0 +1,71 @@
+/// Number of idle buffers kept around for reuse.
+const MAX_POOLED: usize = 4;
+
+/// Allocation counters for frame buffers.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FrameStats {
+    pub frames: u64,
+    pub reused: u64,
+    pub allocations: u64,
+    pub allocated_bytes: u64,
+}
+
+/// A small pool of byte buffers reused across frames.
+///
+/// Scratch buffers (raw pixels) go back to the pool after each frame.
+/// Encoded frames are handed to Dart by value, so they cannot come back,
+/// but they are allocated once with the right capacity
+/// so the encoder never has to grow them.
+#[derive(Default)]
+pub struct FramePool {
+    idle: Vec<Vec<u8>>,
+    last_encoded_len: usize,
+    stats: FrameStats,
+}
+
+impl FramePool {
+    /// Takes a zero-filled buffer of exactly `len` bytes.
+    pub fn acquire(&mut self, len: usize) -> Vec<u8> {
+        let reusable = self.idle.iter().position(|buf| buf.capacity() >= len);
+        let mut buf = match reusable {
+            Some(index) => {
+                self.stats.reused += 1;
+                self.idle.swap_remove(index)
+            }
+            None => {
+                self.stats.allocations += 1;
+                self.stats.allocated_bytes += len as u64;
+                Vec::with_capacity(len)
+            }
+        };
+        buf.clear();
+        buf.resize(len, 0);
+        buf
+    }
+
+    /// Returns a scratch buffer so the next frame can reuse it.
+    pub fn release(&mut self, buf: Vec<u8>) {
+        if self.idle.len() < MAX_POOLED {
+            self.idle.push(buf);
+        }
+    }
+
+    /// Creates an empty buffer for the encoder,
+    /// sized from the previous frame plus some headroom.
+    pub fn encode_buffer(&mut self) -> Vec<u8> {
+        let capacity = self.last_encoded_len + self.last_encoded_len / 8;
+        self.stats.allocations += 1;
+        self.stats.allocated_bytes += capacity as u64;
+        Vec::with_capacity(capacity)
+    }
+
+    /// Records a finished frame.
+    pub fn finish_frame(&mut self, encoded_len: usize) {
+        self.last_encoded_len = encoded_len;
+        self.stats.frames += 1;
+    }
+
+    pub fn stats(&self) -> FrameStats {
+        self.stats
+    }
+}
@@ -3,20 +3,39 @@
 //! Focus on message passing instead.
 
 mod first;
+mod frame_pool;
 mod second;
 mod performings;
-// use first::FirstActor;
-use messages::prelude::Context;
-use rinf::DartSignal;
-// use second::SecondActor;
+use messages::prelude::{Address, Context, Handler};
+use rinf::{DartSignal, dart_shutdown, debug_print};
+use std::sync::Arc;
 use tokio::spawn;
 
-use crate::{actors::first::CountingActor, signals::CreateActors};
+#[cfg(target_family = "wasm")]
+use crate::study_actors::storage::IndexedDbStorage;
+#[cfg(not(target_family = "wasm"))]
+use crate::study_actors::storage::SledStorage;
+use crate::{
+    actors::{first::CountingActor, performings::PerformingActor, second::WorkerPoolActor},
+    signals::CreateActors,
+    study_actors::{
+        messages::{PersistState, PersistedState, RestoreState},
+        storage::{MemoryStorage, Storage},
+    },
+};
+// The web worker looks up offloaded jobs by name, so it needs to see these.
+#[cfg(target_family = "wasm")]
+pub(crate) use performings::RenderFrameJob;
+#[cfg(target_family = "wasm")]
+pub(crate) use second::CountPrimesJob;
 
-// Uncomment below to target the web.
-// use tokio_with_wasm::alias as tokio;
+/// Actor states handed over between launches are saved under this prefix,
+/// followed by the actor's name.
+const STATE_PREFIX: &str = "state/";
 
 /// Creates and spawns the actors in the async system.
+/// Returns once Dart shuts down and the actors have saved their states,
+/// so the caller should wait for it before exiting.
 pub async fn create_actors() {
     // Though simple async tasks work, using the actor model
     // is highly recommended for state management
@@ -25,21 +44,112 @@ pub async fn create_actors() {
     // handling messages from other actors or external sources,
     // such as websockets or timers.
 
-    // Create actor contexts.
-    // let first_context = Context::new();
-    // let first_addr = first_context.address();
-    // let second_context = Context::new();
-
-    // // Spawn the actors.
-    // let first_actor = FirstActor::new(first_addr.clone());
-    // spawn(first_context.run(first_actor));
-    // let second_actor = SecondActor::new(first_addr);
-    // spawn(second_context.run(second_actor));
     let start_receiver = CreateActors::get_dart_signal_receiver();
-    start_receiver.recv().await;
+    // Dart may close before it asks for the actors.
+    let signal_pack = tokio::select! {
+        signal_pack = start_receiver.recv() => signal_pack,
+        () = dart_shutdown() => None,
+    };
+    let Some(signal_pack) = signal_pack else {
+        return;
+    };
+    let options = signal_pack.message;
+    let storage = open_storage(options.storage_path.as_deref());
     let counting_context = Context::new();
-    let counting_addr = counting_context.address();
+    let mut counting_addr = counting_context.address();
 
-    let counting_actor = CountingActor::new(counting_addr);
+    let counting_actor = CountingActor::new(counting_addr.clone(), storage.clone());
     spawn(counting_context.run(counting_actor));
+    restore_state(&storage, "counter", &mut counting_addr).await;
+
+    if options.stream_fractal {
+        let performing_context = Context::new();
+        let performing_addr = performing_context.address();
+        let performing_actor = PerformingActor::new(performing_addr);
+        spawn(performing_context.run(performing_actor));
+    }
+
+    let mut pool_addr = None;
+    if options.worker_pool {
+        // One worker per core; the web reports no parallelism, so it gets two.
+        let workers = std::thread::available_parallelism().map_or(2, |cores| cores.get());
+        let pool_context = Context::new();
+        let mut addr = pool_context.address();
+        let pool_actor = WorkerPoolActor::new(addr.clone(), workers);
+        spawn(pool_context.run(pool_actor));
+        restore_state(&storage, "pool", &mut addr).await;
+        pool_addr = Some(addr);
+    }
+
+    // Rinf keeps Dart waiting until the main function returns,
+    // so there is still time to save the states here.
+    dart_shutdown().await;
+    persist_state(&storage, "counter", &mut counting_addr).await;
+    if let Some(mut addr) = pool_addr {
+        persist_state(&storage, "pool", &mut addr).await;
+    }
+}
+
+/// Hands the state saved at the last shutdown back to an actor.
+/// The saved copy is deleted first, so that a crash can't restore it twice.
+async fn restore_state<A>(storage: &Arc<dyn Storage>, name: &str, addr: &mut Address<A>)
+where
+    A: Handler<RestoreState, Result = bool>,
+{
+    let key = format!("{}{}", STATE_PREFIX, name);
+    let Ok(bytes) = storage.load(&key).await else {
+        return;
+    };
+    let _ = storage.delete(&key).await;
+    let restored = match serde_json::from_slice::<PersistedState>(&bytes) {
+        Ok(state) => addr.send(RestoreState(state)).await.unwrap_or(false),
+        Err(_) => false,
+    };
+    if !restored {
+        debug_print!("Discarded incompatible {} state from last run", name);
+    }
+}
+
+/// Saves what an actor wants to keep for the next launch,
+/// or removes the old copy if it has nothing to keep.
+async fn persist_state<A>(storage: &Arc<dyn Storage>, name: &str, addr: &mut Address<A>)
+where
+    A: Handler<PersistState, Result = Option<PersistedState>>,
+{
+    let key = format!("{}{}", STATE_PREFIX, name);
+    let saved = match addr.send(PersistState).await {
+        Ok(Some(state)) => match serde_json::to_vec(&state) {
+            Ok(bytes) => storage.save(&key, &bytes).await.map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        },
+        Ok(None) => storage.delete(&key).await.map_err(|e| e.to_string()),
+        Err(e) => Err(e.to_string()),
+    };
+    if let Err(e) = saved {
+        debug_print!("Failed to save {} state: {}", name, e);
+    }
+}
+
+/// Opens the storage that the counters are saved to.
+/// Falls back to memory, which forgets everything on restart,
+/// when no path is given or the database can't be opened.
+#[cfg(not(target_family = "wasm"))]
+fn open_storage(path: Option<&str>) -> Arc<dyn Storage> {
+    match path.map(SledStorage::open) {
+        Some(Ok(storage)) => Arc::new(storage),
+        Some(Err(e)) => {
+            rinf::debug_print!("Failed to open counter storage, using memory: {}", e);
+            Arc::new(MemoryStorage::new())
+        }
+        None => Arc::new(MemoryStorage::new()),
+    }
+}
+
+/// On the web, the path names an IndexedDB database instead.
+#[cfg(target_family = "wasm")]
+fn open_storage(path: Option<&str>) -> Arc<dyn Storage> {
+    match path {
+        Some(name) => Arc::new(IndexedDbStorage::new(name)),
+        None => Arc::new(MemoryStorage::new()),
+    }
 }
@@ -1,18 +1,45 @@
 use async_trait::async_trait;
+use image::{
+    ExtendedColorType, ImageEncoder, ImageError,
+    codecs::png::{CompressionType, FilterType, PngEncoder},
+};
 use messages::{
     actor::Actor,
-    prelude::{Address, Context, Notifiable},
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use rinf::{RustSignal, RustSignalBinary, debug_print};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+#[cfg(not(target_family = "wasm"))]
+use tokio::time::MissedTickBehavior;
+use tokio::{
+    task::JoinSet,
+    time::{interval, sleep},
 };
-use rinf::RustSignalBinary;
-use tokio::task::JoinSet;
 
-use crate::signals::{SampleFractal, SampleSchema};
+use super::frame_pool::FramePool;
+use crate::{
+    signals::{FractalFrameStats, SampleFractal, SampleSchema},
+    study_actors::{
+        actors::{Instant, Throttle, WorkerJob, offload, performance_level, record_frame_time},
+        messages::{ScheduledTask, ThrottlePolicy},
+    },
+};
+
+const MAX_ITERATIONS: u32 = 200;
+const FRACTAL_CENTER: (f64, f64) = (-0.743_643_887_037_151, 0.131_825_904_205_33);
+const STATS_EVERY_FRAMES: u64 = 60;
 
 pub struct ImageInfo {
     pub scale: f64,
     pub data: Vec<u8>,
 }
+
+/// Renders a zooming fractal and streams it to Dart as PNG frames.
+/// Both the frames and their stats follow the battery scheduler's throttling.
 pub struct PerformingActor {
+    frame_pool: FramePool,
+    stats_throttle: Throttle,
     _owned_tasks: JoinSet<()>,
 }
 
@@ -22,8 +49,10 @@ impl PerformingActor {
     pub fn new(self_addr: Address<Self>) -> Self {
         let mut owned_tasks = JoinSet::new();
         // owned_tasks.spawn(Self::run_debug_tests());
-        // owned_tasks.spawn(Self::stream_fractal(self_addr));
+        owned_tasks.spawn(Self::stream_fractal(self_addr));
         PerformingActor {
+            frame_pool: FramePool::default(),
+            stats_throttle: Throttle::new(ScheduledTask::FrameStats),
             _owned_tasks: owned_tasks,
         }
     }
@@ -32,6 +61,7 @@ impl PerformingActor {
 #[async_trait]
 impl Notifiable<ImageInfo> for PerformingActor {
     async fn notify(&mut self, msg: ImageInfo, _: &Context<Self>) {
+        // The encoded frame is moved into the signal, not copied.
         SampleFractal {
             current_scale: msg.scale,
             dummy: Some(SampleSchema {
@@ -42,6 +72,66 @@ impl Notifiable<ImageInfo> for PerformingActor {
         .send_signal_to_dart(msg.data);
     }
 }
+
+struct RenderFrame {
+    scale: f64,
+}
+
+#[async_trait]
+impl Handler<RenderFrame> for PerformingActor {
+    type Result = ();
+
+    async fn handle(&mut self, msg: RenderFrame, ctx: &Context<Self>) {
+        // The performance governor lowers the resolution under load.
+        let size = performance_level().fractal_size();
+        let pixels = self.frame_pool.acquire((size * size * 3) as usize);
+        let encoded = self.frame_pool.encode_buffer();
+        let scale = msg.scale;
+        let job = RenderFrameJob {
+            scale,
+            size,
+            pixels,
+            encoded,
+        };
+        let started = Instant::now();
+        let rendered = offload(job).await;
+        record_frame_time(started.elapsed());
+
+        let (pixels, encoded) = match rendered {
+            Ok(rendered) => rendered,
+            Err(e) => {
+                debug_print!("Fractal render task failed: {}", e);
+                return;
+            }
+        };
+        self.frame_pool.release(pixels);
+        match encoded {
+            Ok(data) => {
+                self.frame_pool.finish_frame(data.len());
+                self.notify(ImageInfo { scale, data }, ctx).await;
+            }
+            Err(e) => debug_print!("Failed to encode fractal frame: {}", e),
+        }
+
+        // Stretched stats are sent every few intervals instead of every interval.
+        let every_frames = match self.stats_throttle.policy() {
+            ThrottlePolicy::Normal => STATS_EVERY_FRAMES,
+            ThrottlePolicy::Stretch(factor) => STATS_EVERY_FRAMES * u64::from(factor.max(1)),
+            ThrottlePolicy::Pause => return,
+        };
+        let stats = self.frame_pool.stats();
+        if stats.frames > 0 && stats.frames.is_multiple_of(every_frames) {
+            FractalFrameStats {
+                frames: stats.frames,
+                reused: stats.reused,
+                allocations: stats.allocations,
+                allocated_bytes: stats.allocated_bytes,
+            }
+            .send_signal_to_dart();
+        }
+    }
+}
+
 impl PerformingActor {
     #[cfg(debug_assertions)]
     const IS_DEBUG_MODE: bool = true;
@@ -49,5 +139,132 @@ impl PerformingActor {
     #[cfg(not(debug_assertions))]
     const IS_DEBUG_MODE: bool = false;
 
-    // async fn stream_fractal(mut self_addr:Address<>)
+    /// Unoptimized builds render much slower, so they stream fewer frames.
+    /// In low power mode the stream slows down or waits until it ends.
+    async fn stream_fractal(mut self_addr: Address<Self>) {
+        let period_ms = if Self::IS_DEBUG_MODE { 50 } else { 16 };
+        let period = Duration::from_millis(period_ms);
+        let mut time_interval = interval(period);
+        // 웹의 interval(setInterval)에는 놓친 틱 정책이 없다
+        #[cfg(not(target_family = "wasm"))]
+        time_interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        let mut throttle = Throttle::new(ScheduledTask::FractalStream);
+        let mut scale = 1.0;
+        loop {
+            time_interval.tick().await;
+            let factor = throttle.wait().await;
+            if factor > 1 {
+                sleep(period * (factor - 1)).await;
+            }
+            // Waiting for each frame keeps at most one render in flight.
+            if self_addr.send(RenderFrame { scale }).await.is_err() {
+                break;
+            }
+            scale *= 0.98;
+            if scale < 1e-5 {
+                scale = 1.0;
+            }
+        }
+    }
+}
+
+/// Renders one frame off the actor's mailbox.
+/// Native builds use a blocking thread; web builds send it to a web worker
+/// so the browser's main thread keeps painting.
+#[derive(Serialize, Deserialize)]
+pub struct RenderFrameJob {
+    scale: f64,
+    size: u32,
+    pixels: Vec<u8>,
+    encoded: Vec<u8>,
+}
+
+impl WorkerJob for RenderFrameJob {
+    const NAME: &'static str = "fractal/render";
+    type Output = (Vec<u8>, Result<Vec<u8>, String>);
+
+    fn run(self) -> Self::Output {
+        let (pixels, encoded) = render_frame(self.scale, self.size, self.pixels, self.encoded);
+        (pixels, encoded.map_err(|e| e.to_string()))
+    }
+}
+
+/// Draws into the pooled pixel buffer and encodes into the pre-sized output.
+/// The pixel buffer is always handed back so it can be reused.
+fn render_frame(
+    scale: f64,
+    size: u32,
+    mut pixels: Vec<u8>,
+    mut encoded: Vec<u8>,
+) -> (Vec<u8>, Result<Vec<u8>, ImageError>) {
+    draw_mandelbrot(&mut pixels, size, scale);
+    let encoder =
+        PngEncoder::new_with_quality(&mut encoded, CompressionType::Fast, FilterType::NoFilter);
+    let result = encoder
+        .write_image(&pixels, size, size, ExtendedColorType::Rgb8)
+        .map(|()| encoded);
+    (pixels, result)
+}
+
+fn draw_mandelbrot(pixels: &mut [u8], size: u32, scale: f64) {
+    let step = scale * 3.0 / size as f64;
+    for (index, pixel) in pixels.chunks_exact_mut(3).enumerate() {
+        let column = (index as u32 % size) as f64 - size as f64 / 2.0;
+        let row = (index as u32 / size) as f64 - size as f64 / 2.0;
+        let (cx, cy) = (
+            FRACTAL_CENTER.0 + column * step,
+            FRACTAL_CENTER.1 + row * step,
+        );
+
+        let (mut x, mut y) = (0.0_f64, 0.0_f64);
+        let mut iterations = 0;
+        while iterations < MAX_ITERATIONS && x * x + y * y <= 4.0 {
+            (x, y) = (x * x - y * y + cx, 2.0 * x * y + cy);
+            iterations += 1;
+        }
+
+        let shade = (iterations * 255 / MAX_ITERATIONS) as u8;
+        pixel.copy_from_slice(&[shade, shade / 2, 255 - shade]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FramePool, render_frame};
+    use image::ImageError;
+
+    #[test]
+    fn frames_reuse_pooled_pixels_and_presized_output() -> Result<(), ImageError> {
+        let size = 32;
+        let len = (size * size * 3) as usize;
+        let mut pool = FramePool::default();
+
+        let mut pixel_ptrs = Vec::new();
+        let mut encoded_lens = Vec::new();
+        for _ in 0..3 {
+            let pixels = pool.acquire(len);
+            pixel_ptrs.push(pixels.as_ptr());
+            let encoded = pool.encode_buffer();
+            let capacity = encoded.capacity();
+            let (pixels, encoded) = render_frame(0.5, size, pixels, encoded);
+            let encoded = encoded?;
+            // After the first frame the encoder writes into the pre-sized buffer without growing it
+            if !encoded_lens.is_empty() {
+                assert_eq!(encoded.capacity(), capacity);
+            }
+            encoded_lens.push(encoded.len());
+            pool.release(pixels);
+            pool.finish_frame(encoded.len());
+        }
+
+        // The same pixel buffer comes back every frame
+        assert!(pixel_ptrs.windows(2).all(|pair| pair[0] == pair[1]));
+        let stats = pool.stats();
+        assert_eq!(stats.frames, 3);
+        assert_eq!(stats.reused, 2);
+        // One pixel buffer plus one encode buffer per frame
+        assert_eq!(stats.allocations, 4);
+        assert!(encoded_lens.windows(2).all(|pair| pair[0] == pair[1]));
+        Ok(())
+    }
 }
@@ -1,34 +1,284 @@
-// use crate::actors::FirstActor;
-// use crate::signals::{BigBool, SmallBool};
-// use messages::prelude::{Actor, Address};
-// use rinf::debug_print;
-// use std::time::Duration;
-// use tokio::spawn;
-// use tokio::time::sleep;
-
-// // Uncomment below to target the web.
-// // use tokio_with_wasm::alias as tokio;
-
-// pub struct SecondActor {}
-
-// impl Actor for SecondActor {}
-
-// impl SecondActor {
-//     pub fn new(first_addr: Address<FirstActor>) -> Self {
-//         spawn(Self::ask_first_actor(first_addr));
-//         SecondActor {}
-//     }
-// }
-
-// impl SecondActor {
-//     async fn ask_first_actor(mut first_addr: Address<FirstActor>) {
-//         sleep(Duration::from_secs(10)).await;
-//         let big_bool = BigBool {
-//             member: true,
-//             nested: SmallBool(true),
-//         };
-//         if let Ok(response) = first_addr.send(big_bool).await {
-//             debug_print!("{}", response);
-//         }
-//     }
-// }
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use rinf::{DartSignal, RustSignal, debug_print};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, future::Future};
+use tokio::{sync::oneshot, task::JoinSet};
+
+use crate::{
+    signals::{PoolUtilization, SubmitWorkBatch, WorkBatchResult},
+    study_actors::{
+        actors::{Instant, OffloadError, WorkerJob, offload},
+        messages::{PersistState, PersistedState, RestoreState},
+    },
+};
+
+/// Larger limits are clamped so that one job can't exhaust memory.
+const MAX_LIMIT: u64 = 10_000_000;
+/// Bump this when the saved batches change shape, so that older ones are discarded.
+const STATE_VERSION: u32 = 1;
+
+/// Spreads batches of jobs over a fixed set of worker actors, round-robin.
+/// Each worker runs one job at a time,
+/// so no more than `workers` jobs run at once however many batches are queued.
+/// Dart submits work with `SubmitWorkBatch`, other actors with `RunBatch`.
+pub struct WorkerPoolActor {
+    workers: Vec<Address<WorkerActor>>,
+    /// Jobs handed to each worker that haven't finished yet.
+    pending: Vec<usize>,
+    next_worker: usize,
+    jobs_completed: u64,
+    /// The limits of Dart batches that haven't sent their results yet, by batch id.
+    /// These are handed over to the next launch when the app closes.
+    batches: BTreeMap<u64, Vec<u64>>,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for WorkerPoolActor {}
+
+/// Runs a batch for another actor.
+/// The prime counts are sent to `reply` in submission order,
+/// with `None` for jobs that failed.
+pub struct RunBatch {
+    pub limits: Vec<u64>,
+    pub reply: oneshot::Sender<Vec<Option<u64>>>,
+}
+
+struct JobFinished {
+    worker: usize,
+}
+
+struct BatchFinished {
+    batch_id: u64,
+}
+
+impl WorkerPoolActor {
+    /// Creates the pool and starts its workers.
+    /// The workers stop when the pool is dropped.
+    pub fn new(self_addr: Address<Self>, workers: usize) -> Self {
+        let mut owned_tasks = JoinSet::new();
+        owned_tasks.spawn(Self::listen_to_dart(self_addr));
+        let workers: Vec<_> = (0..workers.max(1))
+            .map(|_| {
+                let context = Context::new();
+                let address = context.address();
+                owned_tasks.spawn(context.run(WorkerActor));
+                address
+            })
+            .collect();
+        WorkerPoolActor {
+            pending: vec![0; workers.len()],
+            workers,
+            next_worker: 0,
+            jobs_completed: 0,
+            batches: BTreeMap::new(),
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    async fn listen_to_dart(mut self_addr: Address<Self>) {
+        let receiver = SubmitWorkBatch::get_dart_signal_receiver();
+        while let Some(signal_pack) = receiver.recv().await {
+            if self_addr.notify(signal_pack.message).await.is_err() {
+                break;
+            }
+        }
+    }
+
+    /// Hands each job to the next worker in turn.
+    /// The returned future collects the results in submission order;
+    /// it runs outside the mailbox so that the pool keeps taking batches.
+    fn dispatch(
+        &mut self,
+        limits: Vec<u64>,
+        ctx: &Context<Self>,
+    ) -> impl Future<Output = Vec<Option<u64>>> + Send + 'static {
+        let job_count = limits.len();
+        let mut jobs = JoinSet::new();
+        for (index, limit) in limits.into_iter().enumerate() {
+            let worker = self.next_worker;
+            self.next_worker = (worker + 1) % self.workers.len();
+            self.pending[worker] += 1;
+
+            let mut worker_addr = self.workers[worker].clone();
+            let mut pool_addr = ctx.address();
+            let job = CountPrimesJob {
+                limit: limit.min(MAX_LIMIT),
+            };
+            jobs.spawn(async move {
+                let count = match worker_addr.send(job).await {
+                    Ok(Ok(count)) => Some(count),
+                    Ok(Err(e)) => {
+                        debug_print!("Worker {} failed a job: {}", worker, e);
+                        None
+                    }
+                    Err(e) => {
+                        debug_print!("Worker {} is unavailable: {}", worker, e);
+                        None
+                    }
+                };
+                let _ = pool_addr.notify(JobFinished { worker }).await;
+                (index, count)
+            });
+        }
+        self.report_utilization();
+
+        async move {
+            let mut counts = vec![None; job_count];
+            while let Some(joined) = jobs.join_next().await {
+                if let Ok((index, count)) = joined {
+                    counts[index] = count;
+                }
+            }
+            counts
+        }
+    }
+
+    fn report_utilization(&self) {
+        PoolUtilization {
+            workers: self.workers.len() as u64,
+            busy_workers: self.pending.iter().filter(|&&jobs| jobs > 0).count() as u64,
+            jobs_in_flight: self.pending.iter().sum::<usize>() as u64,
+            jobs_completed: self.jobs_completed,
+        }
+        .send_signal_to_dart();
+    }
+}
+
+#[async_trait]
+impl Notifiable<SubmitWorkBatch> for WorkerPoolActor {
+    async fn notify(&mut self, msg: SubmitWorkBatch, ctx: &Context<Self>) {
+        let started = Instant::now();
+        let batch_id = msg.batch_id;
+        self.batches.insert(batch_id, msg.limits.clone());
+        let results = self.dispatch(msg.limits, ctx);
+        let mut pool_addr = ctx.address();
+        self._owned_tasks.spawn(async move {
+            let prime_counts = results.await;
+            WorkBatchResult {
+                batch_id,
+                prime_counts,
+                elapsed_ms: started.elapsed().as_millis() as u64,
+            }
+            .send_signal_to_dart();
+            let _ = pool_addr.notify(BatchFinished { batch_id }).await;
+        });
+    }
+}
+
+#[async_trait]
+impl Notifiable<BatchFinished> for WorkerPoolActor {
+    async fn notify(&mut self, msg: BatchFinished, _: &Context<Self>) {
+        self.batches.remove(&msg.batch_id);
+    }
+}
+
+/// Hands the unfinished Dart batches over to the next launch.
+/// Batches from `RunBatch` are left out, since nobody would be waiting for their results.
+#[async_trait]
+impl Handler<PersistState> for WorkerPoolActor {
+    type Result = Option<PersistedState>;
+
+    async fn handle(&mut self, _: PersistState, _: &Context<Self>) -> Self::Result {
+        if self.batches.is_empty() {
+            return None;
+        }
+        PersistedState::new(STATE_VERSION, &self.batches)
+    }
+}
+
+/// Runs the batches left unfinished by the last launch again,
+/// and Dart receives their results under the same batch ids.
+#[async_trait]
+impl Handler<RestoreState> for WorkerPoolActor {
+    type Result = bool;
+
+    async fn handle(&mut self, msg: RestoreState, ctx: &Context<Self>) -> Self::Result {
+        let Some(batches) = msg.0.read::<BTreeMap<u64, Vec<u64>>>(STATE_VERSION) else {
+            return false;
+        };
+        for (batch_id, limits) in batches {
+            if self.batches.contains_key(&batch_id) {
+                continue;
+            }
+            self.notify(SubmitWorkBatch { batch_id, limits }, ctx).await;
+        }
+        true
+    }
+}
+
+#[async_trait]
+impl Notifiable<RunBatch> for WorkerPoolActor {
+    async fn notify(&mut self, msg: RunBatch, ctx: &Context<Self>) {
+        let results = self.dispatch(msg.limits, ctx);
+        let reply = msg.reply;
+        self._owned_tasks.spawn(async move {
+            let _ = reply.send(results.await);
+        });
+    }
+}
+
+#[async_trait]
+impl Notifiable<JobFinished> for WorkerPoolActor {
+    async fn notify(&mut self, msg: JobFinished, _: &Context<Self>) {
+        self.jobs_completed += 1;
+        let Some(pending) = self.pending.get_mut(msg.worker) else {
+            return;
+        };
+        *pending = pending.saturating_sub(1);
+        if *pending == 0 {
+            self.report_utilization();
+        }
+    }
+}
+
+/// Runs one job at a time, off the async threads.
+struct WorkerActor;
+
+impl Actor for WorkerActor {}
+
+#[async_trait]
+impl Handler<CountPrimesJob> for WorkerActor {
+    type Result = Result<u64, OffloadError>;
+
+    async fn handle(&mut self, msg: CountPrimesJob, _: &Context<Self>) -> Self::Result {
+        offload(msg).await
+    }
+}
+
+/// Counts the primes up to `limit` with a sieve.
+#[derive(Serialize, Deserialize)]
+pub struct CountPrimesJob {
+    limit: u64,
+}
+
+impl WorkerJob for CountPrimesJob {
+    const NAME: &'static str = "pool/count_primes";
+    type Output = u64;
+
+    fn run(self) -> Self::Output {
+        count_primes(self.limit as usize)
+    }
+}
+
+fn count_primes(limit: usize) -> u64 {
+    if limit < 2 {
+        return 0;
+    }
+    let mut composite = vec![false; limit + 1];
+    let mut count = 0;
+    for n in 2..=limit {
+        if composite[n] {
+            continue;
+        }
+        count += 1;
+        // Squares can overflow on 32-bit targets such as the web.
+        for multiple in (n.saturating_mul(n)..=limit).step_by(n) {
+            composite[multiple] = true;
+        }
+    }
+    count
+}
@@ -1,25 +1,41 @@
 //! This `hub` crate is the
 //! entry point of the Rust logic.
 
+// 웹 타깃에서는 tokio와 같은 모양의 tokio_with_wasm을 모든 모듈에서 tokio라는 이름으로 쓴다
+#[cfg(target_family = "wasm")]
+extern crate tokio_with_wasm as tokio;
+
+#[cfg(all(target_family = "wasm", not(feature = "web")))]
+compile_error!("Building for the web requires the `web` feature (cargo build --features web)");
+
+// rinf 예제 액터와 신호 (Dart가 CreateActors를 보내면 시작한다)
 mod actors;
 mod signals;
 mod tutorial_functions;
 mod study_actors;
 
+// 데이터 경로 처리량 측정 (benches/pipeline.rs에서 사용)
+#[cfg(feature = "bench")]
+pub use study_actors::bench;
+
+// Flutter 없이 신호를 주입/수집하는 통합 테스트 도구 (examples/headless.rs에서 사용)
+#[cfg(feature = "headless")]
+pub use study_actors::headless;
+
+// 주기 작업을 가상 시간으로 앞당기는 테스트용 시계
+#[cfg(feature = "simulation")]
+pub use study_actors::actors::VirtualClock;
+
 use async_trait::async_trait;
 use messages::{
     actor::Actor,
     prelude::{Address, Context, Notifiable},
 };
-// use actors::create_actors;
 use rinf::{dart_shutdown, debug_print, write_interface};
 use tokio::spawn;
 
 // use crate::tutorial_functions::{calculate_precious_data, stream_amazing_number, tell_treasure};
 
-// Uncomment below to target the web.
-// use tokio_with_wasm::alias as tokio;
-
 write_interface!();
 
 struct Sum(usize, usize);
@@ -53,8 +69,22 @@ fn create_actors() -> Address<MyActor> {
 }
 
 // You can go with any async library, not just `tokio`.
+// 네이티브에서는 런타임을 직접 만들어, 액터 생성 전에 Dart가 보낸 설정(ConfigureRuntimeRequest)을 반영한다.
+// `multi-thread` 기능을 켜면 기본값으로 워커 스레드 풀 런타임을 사용한다.
+// CPU를 많이 쓰는 작업은 각 액터에서 offload()로 넘긴다 (네이티브는 spawn_blocking, 웹은 Web Worker).
+#[cfg(not(target_family = "wasm"))]
+fn main() {
+    study_actors::runtime::run(run_app);
+}
+
+// 웹에서는 브라우저 이벤트 루프 위에서 돌므로 런타임 설정을 바꿀 수 없다
+#[cfg(target_family = "wasm")]
 #[tokio::main(flavor = "current_thread")]
 async fn main() {
+    run_app().await;
+}
+
+async fn run_app() {
     // Spawn concurrent tasks.
     // Always use non-blocking async functions like `tokio::fs::File::open`.
     // If you must use blocking code, use `tokio::task::spawn_blocking`
@@ -67,6 +97,9 @@ async fn main() {
     let mut addr = create_actors();
     let _ = addr.notify(Sum(10, 5)).await;
     
+    // rinf 예제 액터 (Dart의 CreateActors 신호를 기다린다)
+    let example_actors = spawn(actors::create_actors());
+
     // study_actors 모듈 초기화
     debug_print!("Initializing study_actors module...");
     spawn(study_actors::initialize());
@@ -74,4 +107,6 @@ async fn main() {
 
     // Keep the main function running until Dart shutdown.
     dart_shutdown().await;
+    // 예제 액터가 다음 실행에 넘길 상태를 저장할 때까지 기다린다
+    let _ = example_actors.await;
 }
@@ -1,14 +1,14 @@
-use rinf::{DartSignal, RustSignal};
-use serde::{Deserialize, Serialize};
-
-#[derive(Deserialize, DartSignal)]
-pub struct UnitTestStart;
-
-#[derive(Serialize, RustSignal)]
-pub struct UnitTestEnd;
-
-#[derive(Serialize, RustSignal)]
-pub struct ComplexSignalTestResult(pub bool);
-
+use rinf::DartSignal;
+use serde::Deserialize;
+
+/// Starts the tutorial actors.
+/// Counters are persisted at `storage_path`
+/// (an IndexedDB name on the web), or kept in memory when it's omitted.
+/// The fractal stream and the worker pool cost CPU the whole time they run,
+/// so they only start when asked for.
 #[derive(Deserialize, DartSignal)]
-pub struct CreateActors;
+pub struct CreateActors {
+    pub storage_path: Option<String>,
+    pub stream_fractal: bool,
+    pub worker_pool: bool,
+}
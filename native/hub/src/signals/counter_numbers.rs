@@ -22,3 +22,38 @@ pub struct SampleSchema {
     pub sample_field_one: bool,
     pub sample_field_two: bool,
 }
+
+/// Moves a named counter up by its step.
+/// A counter that doesn't exist yet starts at zero with a step of one.
+#[derive(Deserialize, DartSignal)]
+pub struct IncrementCounter {
+    pub name: String,
+}
+
+/// Moves a named counter down by its step.
+#[derive(Deserialize, DartSignal)]
+pub struct DecrementCounter {
+    pub name: String,
+}
+
+/// Sets a named counter back to zero, keeping its step.
+#[derive(Deserialize, DartSignal)]
+pub struct ResetCounter {
+    pub name: String,
+}
+
+/// Changes how far a named counter moves per increment or decrement.
+#[derive(Deserialize, DartSignal)]
+pub struct SetCounterStep {
+    pub name: String,
+    pub step: i32,
+}
+
+/// The latest state of one counter.
+/// Sent after every change, and once per saved counter when the actor starts.
+#[derive(Serialize, RustSignal)]
+pub struct CounterChangedSignal {
+    pub name: String,
+    pub value: i32,
+    pub step: i32,
+}
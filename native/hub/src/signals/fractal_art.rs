@@ -1,5 +1,5 @@
 use crate::signals::SampleSchema;
-use rinf::RustSignalBinary;
+use rinf::{RustSignal, RustSignalBinary};
 use serde::Serialize;
 
 /// You can add your custom comments like this.
@@ -9,3 +9,13 @@ pub struct SampleFractal {
     pub current_scale: f64,
     pub dummy: Option<SampleSchema>,
 }
+
+/// Buffer allocation counters for the fractal stream,
+/// sent about once per second while frames are streaming.
+#[derive(Serialize, RustSignal)]
+pub struct FractalFrameStats {
+    pub frames: u64,
+    pub reused: u64,
+    pub allocations: u64,
+    pub allocated_bytes: u64,
+}
@@ -2,8 +2,9 @@ mod app_control;
 mod complex_types;
 mod counter_numbers;
 mod fractal_art;
+mod worker_pool;
 
 pub use app_control::*;
-pub use complex_types::*;
 pub use counter_numbers::*;
-pub use fractal_art::*;
\ No newline at end of file
+pub use fractal_art::*;
+pub use worker_pool::*;
\ No newline at end of file
@@ -0,0 +1,29 @@
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+
+/// A batch of jobs for the worker pool.
+/// Each job counts the primes up to its limit.
+#[derive(Deserialize, DartSignal)]
+pub struct SubmitWorkBatch {
+    pub batch_id: u64,
+    pub limits: Vec<u64>,
+}
+
+/// The results of one batch, in the order the jobs were submitted.
+/// A job that failed reports `None`.
+#[derive(Serialize, RustSignal)]
+pub struct WorkBatchResult {
+    pub batch_id: u64,
+    pub prime_counts: Vec<Option<u64>>,
+    pub elapsed_ms: u64,
+}
+
+/// How busy the worker pool is,
+/// sent when a batch is handed out and whenever a worker goes idle.
+#[derive(Serialize, RustSignal)]
+pub struct PoolUtilization {
+    pub workers: u64,
+    pub busy_workers: u64,
+    pub jobs_in_flight: u64,
+    pub jobs_completed: u64,
+}
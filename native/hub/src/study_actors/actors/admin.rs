@@ -0,0 +1,585 @@
+use async_trait::async_trait;
+use ed25519_dalek::{Signature, VerifyingKey};
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{LazyLock, RwLock},
+    time::Duration,
+};
+use tokio::{
+    sync::broadcast,
+    task::{AbortHandle, JoinSet},
+};
+
+use crate::study_actors::{
+    logging::{debug_print, log_level, set_log_level},
+    messages::{
+        AdminAction, AdminCommand, AdminConfig, AdminError, AdminOverride, AppEvent,
+        ApplyAdminCommand, FetchData, FetchMode, ListAdminOverrides, LogLevel, ScheduledTask,
+        SignedAdminCommand, StoreData, TrafficClass, WipeOrigin,
+    },
+    signals::{
+        AdminStateRequest, AdminStateSignal, EmitSignal, route_dart_signals, set_signal_recording,
+    },
+};
+
+use super::network::NetworkRequest;
+use super::{
+    Clock, EventBus, NetworkManagerActor, Priority, PriorityMailbox, StorageActor, TrustedClock,
+};
+
+const STATE_KEY: &str = "admin/state";
+// 시계가 바뀌어도 만료를 놓치지 않도록 이 간격으로 다시 확인한다
+const EXPIRY_RECHECK: Duration = Duration::from_secs(60);
+
+// 원격 명령으로 켜고 끈 기능 플래그 (적용 중인 명령이 없으면 비어 있다)
+static FEATURE_FLAGS: LazyLock<RwLock<HashMap<String, bool>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+// 마지막으로 권한이 바뀐 사용자의 구독이 켠 기능
+static ENTITLED_FEATURES: LazyLock<RwLock<HashSet<String>>> =
+    LazyLock::new(|| RwLock::new(HashSet::new()));
+
+// 재시작해도 만료 전의 명령이 이어지도록 저장하는 상태
+#[derive(Default, Serialize, Deserialize)]
+struct StoredState {
+    overrides: Vec<AdminOverride>,
+    seen: HashMap<String, u64>, // 적용한 명령 ID와 만료 시각 (재전송 방지)
+}
+
+// 관리 액터
+// 관리 서버가 서명한 원격 명령을 푸시나 폴링으로 받아 로그 상세도, 신호 기록, 기능 플래그를 잠시 바꾼다.
+// 모든 명령은 만료 시각이 있고, 만료되면 남은 명령만으로 상태를 다시 계산해 되돌린다.
+// 서버가 보낸 원격 삭제도 여기서 서명과 만료, 재전송을 확인한 뒤에만 이벤트 버스로 감독자에게 넘긴다.
+pub struct AdminActor {
+    public_key: Option<VerifyingKey>,
+    max_duration_secs: u64,
+    max_skew_secs: u64,
+    overrides: Vec<AdminOverride>, // 적용한 순서
+    seen: HashMap<String, u64>,
+    expiry_timer: Option<AbortHandle>,
+    storage: Address<StorageActor>,
+    event_bus: EventBus,
+    clock: TrustedClock,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for AdminActor {}
+
+impl AdminActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        config: AdminConfig,
+        storage: Address<StorageActor>,
+        network_manager: PriorityMailbox<NetworkManagerActor>,
+        event_bus: EventBus,
+        clock: TrustedClock,
+    ) -> Self {
+        let public_key = config.public_key.as_deref().and_then(|key| {
+            parse_public_key(key)
+                .map_err(|e| debug_print!("Invalid admin public key: {}", e))
+                .ok()
+        });
+
+        let mut owned_tasks = route_dart_signals!(self_addr, [AdminStateRequest]);
+        owned_tasks.spawn(Self::load_state(self_addr.clone()));
+        owned_tasks.spawn(Self::listen_to_events(
+            self_addr.clone(),
+            event_bus.subscribe(),
+        ));
+        if let (Some(_), Some(url)) = (&public_key, config.poll_url) {
+            owned_tasks.spawn(Self::poll_commands(
+                self_addr,
+                clock.clock().clone(),
+                network_manager,
+                url,
+                config.poll_interval_secs,
+            ));
+        }
+
+        Self {
+            public_key,
+            max_duration_secs: config.max_duration_secs,
+            max_skew_secs: config.max_skew_secs,
+            overrides: Vec::new(),
+            seen: HashMap::new(),
+            expiry_timer: None,
+            storage,
+            event_bus,
+            clock,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    async fn load_state(mut self_addr: Address<Self>) {
+        let _ = self_addr.notify(LoadAdminState).await;
+    }
+
+    async fn listen_to_events(
+        mut self_addr: Address<Self>,
+        mut events: broadcast::Receiver<AppEvent>,
+    ) {
+        loop {
+            match events.recv().await {
+                Ok(AppEvent::AdminCommandReceived(command)) => {
+                    let _ = self_addr.notify(CommandsReceived(vec![command])).await;
+                }
+                Ok(AppEvent::EntitlementsChanged { entitlements, .. }) => {
+                    let features = entitlements.into_iter().flat_map(|e| e.features).collect();
+                    let _ = self_addr.notify(EntitlementsUpdated(features)).await;
+                }
+                Ok(_) => {}
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    debug_print!("Admin actor skipped {} app events", skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    }
+
+    // 폴링 엔드포인트는 서명된 명령의 JSON 배열을 돌려준다 (이미 적용한 명령은 건너뛴다)
+    async fn poll_commands(
+        mut self_addr: Address<Self>,
+        clock: Clock,
+        network_manager: PriorityMailbox<NetworkManagerActor>,
+        url: String,
+        interval_secs: u64,
+    ) {
+        let mut interval = clock
+            .interval(Duration::from_secs(interval_secs.max(60)))
+            .throttled(ScheduledTask::AdminPoll);
+        loop {
+            interval.tick().await;
+            let request = NetworkRequest::new(url.clone()).traffic(TrafficClass::Sync);
+            let commands = match network_manager.request(Priority::Low, request).await {
+                Ok(Ok(response)) if response.is_success() => {
+                    response.json::<Vec<SignedAdminCommand>>()
+                }
+                Ok(Ok(response)) => {
+                    debug_print!("Admin command poll failed: {}", response.status);
+                    continue;
+                }
+                Ok(Err(e)) => {
+                    debug_print!("Admin command poll failed: {}", e);
+                    continue;
+                }
+                Err(_) => break,
+            };
+            match commands {
+                Ok(commands) if !commands.is_empty() => {
+                    let _ = self_addr.notify(CommandsReceived(commands)).await;
+                }
+                Ok(_) => {}
+                Err(e) => debug_print!("Invalid admin command list: {}", e),
+            }
+        }
+    }
+
+    fn apply(&mut self, signed: &SignedAdminCommand) -> Result<AdminOverride, AdminError> {
+        let Some(key) = &self.public_key else {
+            return Err("Admin commands are not configured".into());
+        };
+        let now = self.clock.trusted_now();
+        let command = verify_command(key, signed, now, self.max_duration_secs, self.max_skew_secs)?;
+        if self.seen.contains_key(&command.id) {
+            return Err(format!("Admin command {} was already applied", command.id).into());
+        }
+
+        let applied = AdminOverride {
+            command_id: command.id.clone(),
+            action: command.action,
+            applied_at: now,
+            expires_at: command.expires_at,
+        };
+        self.seen.insert(command.id, command.expires_at);
+        match &applied.action {
+            AdminAction::RemoteWipe { reason } => {
+                self.event_bus.publish(AppEvent::RemoteWipeRequested {
+                    origin: WipeOrigin::Server,
+                    reason: reason.clone(),
+                });
+            }
+            _ => self.overrides.push(applied.clone()),
+        }
+        Ok(applied)
+    }
+
+    // 적용 중인 명령만으로 로그 상세도, 신호 기록, 기능 플래그를 다시 계산한다
+    // 같은 대상을 바꾼 명령이 여럿이면 나중에 적용한 명령이 이긴다.
+    fn apply_effects(&self) {
+        let mut level = LogLevel::default();
+        let mut recording = false;
+        let mut flags = HashMap::new();
+        for applied in &self.overrides {
+            match &applied.action {
+                AdminAction::SetLogLevel { level: to } => level = *to,
+                AdminAction::RecordSignals => recording = true,
+                AdminAction::SetFeatureFlag { flag, enabled } => {
+                    flags.insert(flag.clone(), *enabled);
+                }
+                AdminAction::RemoteWipe { .. } => {}
+            }
+        }
+        set_log_level(level);
+        set_signal_recording(recording);
+        if let Ok(mut current) = FEATURE_FLAGS.write() {
+            *current = flags;
+        }
+    }
+
+    fn arm_expiry(&mut self, mut self_addr: Address<Self>) {
+        if let Some(timer) = self.expiry_timer.take() {
+            timer.abort();
+        }
+        let Some(expires_at) = self.overrides.iter().map(|o| o.expires_at).min() else {
+            return;
+        };
+        let wait_ms = (expires_at as i64 * 1000 - self.clock.now_millis()).max(0) as u64;
+        let wait = Duration::from_millis(wait_ms).min(EXPIRY_RECHECK);
+        let clock = self.clock.clock().clone();
+        let timer = self._owned_tasks.spawn(async move {
+            clock.sleep(wait).await;
+            let _ = self_addr.notify(OverridesExpired).await;
+        });
+        self.expiry_timer = Some(timer);
+    }
+
+    async fn persist(&mut self) -> Result<(), AdminError> {
+        let state = StoredState {
+            overrides: self.overrides.clone(),
+            seen: self.seen.clone(),
+        };
+        let request = StoreData {
+            key: STATE_KEY.to_string(),
+            data: serde_json::to_vec(&state)?,
+            user_id: None,
+            ttl: None,
+        };
+        self.storage.send(request).await??;
+        Ok(())
+    }
+
+    // 상태가 바뀐 뒤 효과 적용, 저장, 만료 타이머, Dart 알림을 한 번에 처리
+    async fn commit(&mut self, self_addr: Address<Self>) {
+        self.apply_effects();
+        self.arm_expiry(self_addr);
+        if let Err(e) = self.persist().await {
+            debug_print!("Failed to save admin overrides: {}", e);
+        }
+        self.send_state();
+    }
+
+    // 기능 플래그는 구독이 켠 기능 위에 관리 명령이 덮어쓴 값을 얹어 보낸다
+    fn send_state(&self) {
+        let mut overrides = self.overrides.clone();
+        overrides.sort_by_key(|o| o.expires_at);
+        let mut feature_flags: HashMap<String, bool> = ENTITLED_FEATURES
+            .read()
+            .map(|features| features.iter().map(|f| (f.clone(), true)).collect())
+            .unwrap_or_default();
+        if let Ok(flags) = FEATURE_FLAGS.read() {
+            feature_flags.extend(flags.iter().map(|(flag, enabled)| (flag.clone(), *enabled)));
+        }
+        AdminStateSignal {
+            log_level: log_level(),
+            recording_signals: overrides
+                .iter()
+                .any(|o| o.action == AdminAction::RecordSignals),
+            feature_flags,
+            overrides,
+        }
+        .emit();
+    }
+}
+
+// 서명 확인용 Ed25519 공개 키 (hex, 규칙 모듈 서명 확인에도 쓴다)
+pub(super) fn parse_public_key(key: &str) -> Result<VerifyingKey, AdminError> {
+    let bytes: [u8; 32] = hex::decode(key.trim())?
+        .try_into()
+        .map_err(|_| "Ed25519 public key must be 32 bytes")?;
+    Ok(VerifyingKey::from_bytes(&bytes)?)
+}
+
+// 서명, 발행 시각, 만료 시각을 확인하고 명령을 꺼낸다
+fn verify_command(
+    key: &VerifyingKey,
+    signed: &SignedAdminCommand,
+    now: u64,
+    max_duration_secs: u64,
+    max_skew_secs: u64,
+) -> Result<AdminCommand, AdminError> {
+    let signature = Signature::from_slice(&hex::decode(signed.signature.trim())?)?;
+    key.verify_strict(signed.payload.as_bytes(), &signature)?;
+
+    let command: AdminCommand = serde_json::from_str(&signed.payload)?;
+    if command.issued_at > now + max_skew_secs {
+        return Err(format!("Admin command {} is issued in the future", command.id).into());
+    }
+    if command.expires_at <= now {
+        return Err(format!("Admin command {} has expired", command.id).into());
+    }
+    if command.expires_at.saturating_sub(command.issued_at) > max_duration_secs {
+        return Err(format!("Admin command {} lasts longer than allowed", command.id).into());
+    }
+    Ok(command)
+}
+
+// 내부 메시지 정의
+struct LoadAdminState;
+struct CommandsReceived(Vec<SignedAdminCommand>);
+struct OverridesExpired;
+struct EntitlementsUpdated(HashSet<String>);
+
+#[async_trait]
+impl Notifiable<LoadAdminState> for AdminActor {
+    async fn notify(&mut self, _: LoadAdminState, ctx: &Context<Self>) {
+        let request = FetchData {
+            key: STATE_KEY.to_string(),
+            user_id: None,
+            mode: FetchMode::CacheFirst,
+        };
+        let stored = match self.storage.send(request).await {
+            Ok(Ok(bytes)) => serde_json::from_slice::<StoredState>(&bytes).unwrap_or_else(|e| {
+                debug_print!("Invalid stored admin state: {}", e);
+                StoredState::default()
+            }),
+            // 저장된 상태가 없으면 적용 중인 명령도 없다
+            Ok(Err(_)) => StoredState::default(),
+            Err(e) => {
+                debug_print!("Failed to load admin state: {}", e);
+                StoredState::default()
+            }
+        };
+
+        // 불러오기 전에 적용한 명령이 더 나중이다
+        let now = self.clock.trusted_now();
+        let current = std::mem::replace(&mut self.overrides, stored.overrides);
+        self.overrides.retain(|o| o.expires_at > now);
+        self.overrides.extend(current);
+        self.seen.extend(stored.seen);
+        self.seen.retain(|_, expires_at| *expires_at > now);
+        if !self.overrides.is_empty() {
+            self.commit(ctx.address()).await;
+        }
+    }
+}
+
+#[async_trait]
+impl Notifiable<CommandsReceived> for AdminActor {
+    async fn notify(&mut self, msg: CommandsReceived, ctx: &Context<Self>) {
+        let mut changed = false;
+        for signed in msg.0 {
+            match self.apply(&signed) {
+                Ok(applied) => {
+                    debug_print!(
+                        "Applied admin command {} until {}: {:?}",
+                        applied.command_id,
+                        applied.expires_at,
+                        applied.action
+                    );
+                    changed = true;
+                }
+                Err(e) => debug_print!("Rejected admin command: {}", e),
+            }
+        }
+        if changed {
+            self.commit(ctx.address()).await;
+        }
+    }
+}
+
+#[async_trait]
+impl Notifiable<OverridesExpired> for AdminActor {
+    async fn notify(&mut self, _: OverridesExpired, ctx: &Context<Self>) {
+        let now = self.clock.trusted_now();
+        let before = self.overrides.len();
+        self.overrides.retain(|o| {
+            let active = o.expires_at > now;
+            if !active {
+                debug_print!("Admin command {} expired", o.command_id);
+            }
+            active
+        });
+        self.seen.retain(|_, expires_at| *expires_at > now);
+        if self.overrides.len() == before {
+            self.arm_expiry(ctx.address());
+            return;
+        }
+        self.commit(ctx.address()).await;
+    }
+}
+
+#[async_trait]
+impl Notifiable<EntitlementsUpdated> for AdminActor {
+    async fn notify(&mut self, msg: EntitlementsUpdated, _: &Context<Self>) {
+        let Ok(mut features) = ENTITLED_FEATURES.write() else {
+            return;
+        };
+        if *features == msg.0 {
+            return;
+        }
+        *features = msg.0;
+        drop(features);
+        self.send_state();
+    }
+}
+
+#[async_trait]
+impl Handler<ApplyAdminCommand> for AdminActor {
+    type Result = Result<AdminOverride, AdminError>;
+
+    async fn handle(&mut self, msg: ApplyAdminCommand, ctx: &Context<Self>) -> Self::Result {
+        let applied = self.apply(&msg.command)?;
+        self.commit(ctx.address()).await;
+        Ok(applied)
+    }
+}
+
+#[async_trait]
+impl Handler<ListAdminOverrides> for AdminActor {
+    type Result = Vec<AdminOverride>;
+
+    async fn handle(&mut self, _: ListAdminOverrides, _: &Context<Self>) -> Self::Result {
+        let mut overrides = self.overrides.clone();
+        overrides.sort_by_key(|o| o.expires_at);
+        overrides
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<AdminStateRequest> for AdminActor {
+    async fn notify(&mut self, _: AdminStateRequest, _: &Context<Self>) {
+        self.send_state();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+    use std::{error::Error, sync::Arc};
+
+    use crate::study_actors::{
+        messages::NetworkConfig, storage::MemoryStorage, test_support::TestActorHarness,
+    };
+
+    fn sign(key: &SigningKey, command: &AdminCommand) -> SignedAdminCommand {
+        let payload = serde_json::to_string(command).unwrap_or_default();
+        SignedAdminCommand {
+            signature: hex::encode(key.sign(payload.as_bytes()).to_bytes()),
+            payload,
+        }
+    }
+
+    fn command(issued_at: u64, expires_at: u64) -> AdminCommand {
+        AdminCommand {
+            id: "cmd-1".to_string(),
+            issued_at,
+            expires_at,
+            action: AdminAction::SetLogLevel {
+                level: LogLevel::Trace,
+            },
+        }
+    }
+
+    #[test]
+    fn only_signed_unexpired_commands_within_limits_are_accepted() {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let public = key.verifying_key();
+        let verify = |signed: &SignedAdminCommand| verify_command(&public, signed, 1_000, 600, 60);
+
+        let accepted = verify(&sign(&key, &command(1_000, 1_300)));
+        assert!(accepted.is_ok_and(|c| c.action
+            == AdminAction::SetLogLevel {
+                level: LogLevel::Trace
+            }));
+
+        let mut tampered = sign(&key, &command(1_000, 1_300));
+        tampered.payload = tampered.payload.replace("1300", "9999");
+        assert!(verify(&tampered).is_err());
+
+        let other = SigningKey::from_bytes(&[8; 32]);
+        assert!(verify(&sign(&other, &command(1_000, 1_300))).is_err());
+
+        assert!(verify(&sign(&key, &command(900, 1_000))).is_err()); // 만료됨
+        assert!(verify(&sign(&key, &command(1_000, 2_000))).is_err()); // 최대 기간 초과
+        assert!(verify(&sign(&key, &command(1_100, 1_200))).is_err()); // 미래 발행
+    }
+
+    #[tokio::test]
+    async fn only_a_signed_unseen_remote_wipe_is_published() -> Result<(), Box<dyn Error>> {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let storage =
+            TestActorHarness::start(|_| StorageActor::new(Arc::new(MemoryStorage::new())));
+        let (_events, receiver) = broadcast::channel(1);
+        let network = TestActorHarness::start(|addr| {
+            NetworkManagerActor::new(
+                addr,
+                NetworkConfig::default(),
+                TrustedClock::new(),
+                receiver,
+            )
+        });
+        let event_bus = EventBus::new();
+        let mut published = event_bus.subscribe();
+        let config = AdminConfig {
+            public_key: Some(hex::encode(key.verifying_key().to_bytes())),
+            ..AdminConfig::default()
+        };
+        let clock = TrustedClock::new();
+        let now = clock.trusted_now();
+        let mut admin = TestActorHarness::start(|addr| {
+            let network = PriorityMailbox::new(network.addr());
+            AdminActor::new(
+                addr,
+                config,
+                storage.addr(),
+                network,
+                event_bus.clone(),
+                clock,
+            )
+        });
+
+        let wipe = AdminCommand {
+            id: "wipe-1".to_string(),
+            issued_at: now,
+            expires_at: now + 60,
+            action: AdminAction::RemoteWipe {
+                reason: Some("lost device".to_string()),
+            },
+        };
+        let unsigned = SignedAdminCommand {
+            payload: serde_json::to_string(&wipe)?,
+            signature: String::new(),
+        };
+        let result = admin.send(ApplyAdminCommand { command: unsigned }).await?;
+        assert!(result.is_err());
+        assert!(published.try_recv().is_err());
+
+        let signed = sign(&key, &wipe);
+        let result = admin
+            .send(ApplyAdminCommand {
+                command: signed.clone(),
+            })
+            .await?;
+        assert!(result.is_ok());
+        assert!(matches!(
+            published.try_recv(),
+            Ok(AppEvent::RemoteWipeRequested {
+                origin: WipeOrigin::Server,
+                ..
+            })
+        ));
+        assert!(admin.send(ListAdminOverrides).await?.is_empty());
+
+        // 같은 명령을 다시 보내도 두 번 지우지 않는다
+        let result = admin.send(ApplyAdminCommand { command: signed }).await?;
+        assert!(result.is_err());
+        assert!(published.try_recv().is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,250 @@
+use reqwest::{Method, Url};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::Value;
+
+use crate::study_actors::logging::debug_print;
+use crate::study_actors::messages::{ActorResult, ApiConfig, ApiError, Endpoint, TrafficClass};
+
+use super::network::NetworkRequest;
+use super::{CircuitBreaker, Clock, NetworkManagerActor, Priority, PriorityMailbox};
+
+// 오류 응답 본문을 그대로 보여줄 때의 최대 길이
+const MAX_ERROR_MESSAGE_CHARS: usize = 200;
+
+// 서버 API 클라이언트
+// 엔드포인트 정의로 URL을 만들고 응답을 타입으로 읽는다. 요청은 네트워크 관리자를 거쳐 보낸다.
+#[derive(Clone)]
+pub struct ApiClient {
+    network_manager: PriorityMailbox<NetworkManagerActor>,
+    base_url: Url,
+    timeout_ms: u64,
+    breaker: CircuitBreaker,
+}
+
+impl ApiClient {
+    // base_url이 없거나 잘못되었으면 None (호출하는 액터는 기기 안 구현으로 처리)
+    pub fn new(
+        network_manager: PriorityMailbox<NetworkManagerActor>,
+        config: &ApiConfig,
+    ) -> Option<Self> {
+        let base_url = config.base_url.as_deref()?;
+        match Url::parse(base_url) {
+            Ok(url) if !url.cannot_be_a_base() => Some(Self {
+                network_manager,
+                base_url: url,
+                timeout_ms: config.timeout_ms,
+                breaker: CircuitBreaker::new(config, Clock::system()),
+            }),
+            _ => {
+                debug_print!("Invalid API base URL: {}", base_url);
+                None
+            }
+        }
+    }
+
+    pub async fn send<E: Endpoint>(
+        &self,
+        priority: Priority,
+        endpoint: &E,
+    ) -> ActorResult<E::Response> {
+        let url = endpoint_url(&self.base_url, endpoint)?;
+        self.execute(priority, url, E::METHOD, endpoint.body())
+            .await
+    }
+
+    // 응답이 알려 준 다음 페이지 링크를 그대로 따라간다 (같은 서버의 주소만 허용)
+    pub async fn follow<E: Endpoint>(
+        &self,
+        priority: Priority,
+        link: &str,
+    ) -> ActorResult<E::Response> {
+        let url = self
+            .base_url
+            .join(link)
+            .map_err(|e| ApiError::InvalidUrl(format!("{}: {}", link, e)))?;
+        if url.origin() != self.base_url.origin() {
+            return Err(ApiError::InvalidUrl(format!("{}: not on the API host", link)).into());
+        }
+        self.execute(priority, url, E::METHOD, None::<&()>).await
+    }
+
+    async fn execute<R: DeserializeOwned, B: Serialize>(
+        &self,
+        priority: Priority,
+        url: Url,
+        method: Method,
+        body: Option<&B>,
+    ) -> ActorResult<R> {
+        // 차단된 호스트에는 보내지 않고 바로 실패한다
+        let host = url.host_str().unwrap_or_default().to_string();
+        self.breaker.acquire(&host)?;
+
+        let mut request = NetworkRequest::new(url.as_str())
+            .method(method)
+            .header("Accept", "application/json")
+            .timeout(self.timeout_ms)
+            .traffic(TrafficClass::Sync);
+        if let Some(body) = body {
+            request = request
+                .header("Content-Type", "application/json")
+                .json(body);
+        }
+
+        let response = self
+            .network_manager
+            .request(priority, request)
+            .await
+            .map_err(|e| ApiError::Transport(e.to_string()))??;
+        // 연결 실패와 서버 오류(5xx, 429)만 호스트 장애로 센다 (4xx는 요청 문제)
+        let status = response.status.as_u16();
+        if response.error.is_some() || status >= 500 || status == 429 {
+            self.breaker.record_failure(&host);
+        } else {
+            self.breaker.record_success(&host);
+        }
+        if let Some(error) = response.error {
+            return Err(ApiError::Transport(error).into());
+        }
+        if !response.status.is_success() {
+            return Err(ApiError::Status {
+                status: response.status.as_u16(),
+                message: error_message(&response.body),
+            }
+            .into());
+        }
+
+        // 204 등 본문이 없는 응답은 null로 읽는다
+        let body: &[u8] = if response.body.is_empty() {
+            b"null"
+        } else {
+            &response.body
+        };
+        serde_json::from_slice(body).map_err(|e| ApiError::Decode(e.to_string()).into())
+    }
+}
+
+// 기본 URL 뒤에 경로 템플릿을 채워 붙이고 쿼리를 더한다
+// 경로 값은 세그먼트 하나로 인코딩되므로 "/"가 들어 있어도 경로가 바뀌지 않는다.
+fn endpoint_url<E: Endpoint>(base_url: &Url, endpoint: &E) -> Result<Url, ApiError> {
+    let mut url = base_url.clone();
+    let params = endpoint.path_params();
+    {
+        let mut segments = url
+            .path_segments_mut()
+            .map_err(|_| ApiError::InvalidUrl(base_url.to_string()))?;
+        segments.pop_if_empty();
+        for segment in E::PATH.split('/').filter(|segment| !segment.is_empty()) {
+            match segment
+                .strip_prefix('{')
+                .and_then(|name| name.strip_suffix('}'))
+            {
+                Some(name) => {
+                    let value = params
+                        .iter()
+                        .find(|(key, value)| *key == name && !value.is_empty())
+                        .map(|(_, value)| *value)
+                        .ok_or_else(|| ApiError::MissingPathParam(name.to_string()))?;
+                    segments.push(value);
+                }
+                None => {
+                    segments.push(segment);
+                }
+            }
+        }
+    }
+
+    if let Some(query) = endpoint.query() {
+        let Value::Object(fields) =
+            serde_json::to_value(query).map_err(|e| ApiError::InvalidUrl(e.to_string()))?
+        else {
+            return Err(ApiError::InvalidUrl(format!(
+                "{}: query must be an object",
+                E::PATH
+            )));
+        };
+        // 값이 없는 필드는 빼고, 문자열이 아닌 값은 JSON 표기 그대로 쓴다
+        let pairs: Vec<(String, String)> = fields
+            .into_iter()
+            .filter_map(|(key, value)| match value {
+                Value::Null => None,
+                Value::String(value) => Some((key, value)),
+                value => Some((key, value.to_string())),
+            })
+            .collect();
+        if !pairs.is_empty() {
+            url.query_pairs_mut().extend_pairs(pairs);
+        }
+    }
+    Ok(url)
+}
+
+// 오류 응답에서 표시할 메시지를 꺼낸다 ({"message": ...} 또는 {"error": ...}, 아니면 본문 앞부분)
+fn error_message(body: &[u8]) -> String {
+    #[derive(Deserialize)]
+    struct ErrorBody {
+        message: Option<String>,
+        error: Option<String>,
+    }
+
+    match serde_json::from_slice::<ErrorBody>(body) {
+        Ok(ErrorBody {
+            message: Some(message),
+            ..
+        })
+        | Ok(ErrorBody {
+            error: Some(message),
+            ..
+        }) => message,
+        _ => String::from_utf8_lossy(body)
+            .chars()
+            .take(MAX_ERROR_MESSAGE_CHARS)
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::Url;
+    use std::error::Error;
+
+    use super::endpoint_url;
+    use crate::study_actors::messages::{
+        ApiError, GetItemEndpoint, ItemListQuery, ListItemsEndpoint,
+    };
+
+    #[test]
+    fn fills_path_template_and_query() -> Result<(), Box<dyn Error>> {
+        let base_url = Url::parse("https://api.example.com/v1/")?;
+
+        let get = GetItemEndpoint {
+            user_id: "user 1".to_string(),
+            item_id: "a/b".to_string(),
+        };
+        let url = endpoint_url(&base_url, &get)?;
+        assert_eq!(
+            url.as_str(),
+            "https://api.example.com/v1/users/user%201/items/a%2Fb"
+        );
+
+        let list = ListItemsEndpoint {
+            user_id: "user_1".to_string(),
+            query: ItemListQuery {
+                cursor: None,
+                limit: 50,
+            },
+        };
+        let url = endpoint_url(&base_url, &list)?;
+        assert_eq!(
+            url.as_str(),
+            "https://api.example.com/v1/users/user_1/items?limit=50"
+        );
+
+        let missing = GetItemEndpoint {
+            user_id: "user_1".to_string(),
+            item_id: String::new(),
+        };
+        let result = endpoint_url(&base_url, &missing);
+        assert!(matches!(result, Err(ApiError::MissingPathParam(name)) if name == "item_id"));
+        Ok(())
+    }
+}
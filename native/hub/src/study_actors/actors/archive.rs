@@ -0,0 +1,468 @@
+use async_trait::async_trait;
+use flate2::{Compression, read::GzDecoder, write::GzEncoder};
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use std::{
+    fs::{self, File},
+    io,
+    path::{Component, Path, PathBuf},
+};
+use tokio::task::JoinSet;
+use zip::{CompressionMethod, ZipArchive, ZipWriter, write::SimpleFileOptions};
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{ArchiveError, ArchiveFormat, ArchiveOperation, CreateArchive, ExtractArchive},
+    signals::{
+        ArchiveCompletedSignal, ArchiveProgressSignal, CreateArchiveRequest, EmitSignal,
+        ExtractArchiveRequest, route_dart_signals,
+    },
+};
+
+// 압축 액터
+// 실제 압축/해제는 블로킹 스레드 풀에서 수행해 액터 루프를 막지 않는다.
+pub struct ArchiveActor {
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for ArchiveActor {}
+
+impl ArchiveActor {
+    pub fn new(self_addr: Address<Self>) -> Self {
+        let owned_tasks =
+            route_dart_signals!(self_addr, [CreateArchiveRequest, ExtractArchiveRequest]);
+
+        Self {
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    async fn run_create(msg: CreateArchive) -> Result<usize, ArchiveError> {
+        let format = ArchiveFormat::from_path(&msg.dest).ok_or("Unsupported archive format")?;
+        tokio::task::spawn_blocking(move || {
+            let files = collect_files(&msg.paths)?;
+            match format {
+                ArchiveFormat::Zip => create_zip(&files, &msg.dest),
+                ArchiveFormat::TarGz => create_tar_gz(&files, &msg.dest),
+            }
+        })
+        .await?
+    }
+
+    async fn run_extract(msg: ExtractArchive) -> Result<usize, ArchiveError> {
+        let format = ArchiveFormat::from_path(&msg.archive).ok_or("Unsupported archive format")?;
+        tokio::task::spawn_blocking(move || {
+            let dest = Path::new(&msg.dest);
+            fs::create_dir_all(dest)?;
+            match format {
+                ArchiveFormat::Zip => extract_zip(&msg.archive, dest),
+                ArchiveFormat::TarGz => extract_tar_gz(&msg.archive, dest),
+            }
+        })
+        .await?
+    }
+
+    fn send_completed(
+        operation: ArchiveOperation,
+        target: String,
+        result: Result<usize, ArchiveError>,
+    ) {
+        match result {
+            Ok(entry_count) => {
+                debug_print!(
+                    "Archive {:?} completed: {} ({} entries)",
+                    operation,
+                    target,
+                    entry_count
+                );
+                ArchiveCompletedSignal {
+                    operation,
+                    target,
+                    success: true,
+                    entry_count: entry_count as u64,
+                    error: None,
+                }
+                .emit();
+            }
+            Err(e) => {
+                ArchiveCompletedSignal {
+                    operation,
+                    target,
+                    success: false,
+                    entry_count: 0,
+                    error: Some(e.to_string()),
+                }
+                .emit();
+            }
+        }
+    }
+}
+
+fn report_progress(
+    operation: ArchiveOperation,
+    target: &str,
+    processed: usize,
+    total: usize,
+    entry: &str,
+) {
+    ArchiveProgressSignal {
+        operation,
+        target: target.to_string(),
+        processed: processed as u64,
+        total: total as u64,
+        current_entry: entry.to_string(),
+    }
+    .emit();
+}
+
+// 입력 경로들을 순회해 (실제 경로, 압축 내부 이름) 목록 생성
+// 심볼릭 링크는 따라가지 않고 건너뛴다 (선택한 폴더 밖의 파일이 담기거나 순환에 빠지지 않도록).
+fn collect_files(paths: &[String]) -> io::Result<Vec<(PathBuf, String)>> {
+    let mut files = Vec::new();
+    for path in paths {
+        let root = Path::new(path);
+        let base = root.parent().unwrap_or(Path::new(""));
+        let mut stack = vec![root.to_path_buf()];
+        while let Some(current) = stack.pop() {
+            let file_type = fs::symlink_metadata(&current)?.file_type();
+            if file_type.is_symlink() {
+                debug_print!("Skipping symlink in archive input: {}", current.display());
+            } else if file_type.is_dir() {
+                for entry in fs::read_dir(&current)? {
+                    stack.push(entry?.path());
+                }
+            } else {
+                let name = current
+                    .strip_prefix(base)
+                    .unwrap_or(&current)
+                    .to_string_lossy()
+                    .replace('\\', "/");
+                files.push((current, name));
+            }
+        }
+    }
+    Ok(files)
+}
+
+// zip-slip 방지: 상위 디렉터리나 절대 경로로 빠져나가는 항목 거부
+fn safe_join(dest: &Path, relative: &Path) -> Result<PathBuf, ArchiveError> {
+    for component in relative.components() {
+        match component {
+            Component::Normal(_) | Component::CurDir => {}
+            _ => {
+                return Err(format!("Blocked unsafe archive entry: {}", relative.display()).into());
+            }
+        }
+    }
+    Ok(dest.join(relative))
+}
+
+fn create_zip(files: &[(PathBuf, String)], dest: &str) -> Result<usize, ArchiveError> {
+    let mut writer = ZipWriter::new(File::create(dest)?);
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    let total = files.len();
+
+    for (index, (path, name)) in files.iter().enumerate() {
+        writer.start_file(name.as_str(), options)?;
+        io::copy(&mut File::open(path)?, &mut writer)?;
+        report_progress(ArchiveOperation::Create, dest, index + 1, total, name);
+    }
+
+    writer.finish()?;
+    Ok(total)
+}
+
+fn create_tar_gz(files: &[(PathBuf, String)], dest: &str) -> Result<usize, ArchiveError> {
+    let encoder = GzEncoder::new(File::create(dest)?, Compression::default());
+    let mut builder = tar::Builder::new(encoder);
+    let total = files.len();
+
+    for (index, (path, name)) in files.iter().enumerate() {
+        builder.append_path_with_name(path, name)?;
+        report_progress(ArchiveOperation::Create, dest, index + 1, total, name);
+    }
+
+    builder.into_inner()?.finish()?;
+    Ok(total)
+}
+
+fn extract_zip(archive_path: &str, dest: &Path) -> Result<usize, ArchiveError> {
+    let mut archive = ZipArchive::new(File::open(archive_path)?)?;
+    let total = archive.len();
+
+    for index in 0..total {
+        let mut entry = archive.by_index(index)?;
+        let relative = entry
+            .enclosed_name()
+            .ok_or_else(|| format!("Blocked unsafe archive entry: {}", entry.name()))?;
+        // 링크 항목은 tar.gz와 마찬가지로 거부
+        if entry.is_symlink() {
+            return Err(format!("Blocked link entry: {}", entry.name()).into());
+        }
+        let target = safe_join(dest, &relative)?;
+
+        if entry.is_dir() {
+            fs::create_dir_all(&target)?;
+        } else {
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            io::copy(&mut entry, &mut File::create(&target)?)?;
+        }
+        report_progress(
+            ArchiveOperation::Extract,
+            archive_path,
+            index + 1,
+            total,
+            entry.name(),
+        );
+    }
+
+    Ok(total)
+}
+
+// tar.gz에는 목차가 없으므로 진행률의 전체 개수를 알려면 한 번 훑어야 한다
+fn count_tar_entries(archive_path: &str) -> Result<usize, ArchiveError> {
+    let mut archive = tar::Archive::new(GzDecoder::new(File::open(archive_path)?));
+    let mut count = 0;
+    for entry in archive.entries()? {
+        entry?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+fn extract_tar_gz(archive_path: &str, dest: &Path) -> Result<usize, ArchiveError> {
+    let total = count_tar_entries(archive_path)?;
+    let mut archive = tar::Archive::new(GzDecoder::new(File::open(archive_path)?));
+    let mut count = 0;
+
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let relative = entry.path()?.into_owned();
+
+        // 링크 항목은 대상 디렉터리 밖을 가리킬 수 있으므로 거부
+        let entry_type = entry.header().entry_type();
+        if entry_type.is_symlink() || entry_type.is_hard_link() {
+            return Err(format!("Blocked link entry: {}", relative.display()).into());
+        }
+
+        let target = safe_join(dest, &relative)?;
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        entry.unpack(&target)?;
+
+        count += 1;
+        report_progress(
+            ArchiveOperation::Extract,
+            archive_path,
+            count,
+            total,
+            &relative.to_string_lossy(),
+        );
+    }
+
+    Ok(count)
+}
+
+#[async_trait]
+impl Handler<CreateArchive> for ArchiveActor {
+    type Result = Result<usize, ArchiveError>;
+
+    async fn handle(&mut self, msg: CreateArchive, _: &Context<Self>) -> Self::Result {
+        Self::run_create(msg).await
+    }
+}
+
+#[async_trait]
+impl Handler<ExtractArchive> for ArchiveActor {
+    type Result = Result<usize, ArchiveError>;
+
+    async fn handle(&mut self, msg: ExtractArchive, _: &Context<Self>) -> Self::Result {
+        Self::run_extract(msg).await
+    }
+}
+
+// Dart 신호 처리
+// 작업이 끝날 때까지 액터를 붙잡지 않도록 소유 태스크로 실행
+#[async_trait]
+impl Notifiable<CreateArchiveRequest> for ArchiveActor {
+    async fn notify(&mut self, msg: CreateArchiveRequest, _: &Context<Self>) {
+        self._owned_tasks.spawn(async move {
+            let target = msg.dest.clone();
+            let result = Self::run_create(CreateArchive {
+                paths: msg.paths,
+                dest: msg.dest,
+            })
+            .await;
+            Self::send_completed(ArchiveOperation::Create, target, result);
+        });
+    }
+}
+
+#[async_trait]
+impl Notifiable<ExtractArchiveRequest> for ArchiveActor {
+    async fn notify(&mut self, msg: ExtractArchiveRequest, _: &Context<Self>) {
+        self._owned_tasks.spawn(async move {
+            let target = msg.archive.clone();
+            let result = Self::run_extract(ExtractArchive {
+                archive: msg.archive,
+                dest: msg.dest,
+            })
+            .await;
+            Self::send_completed(ArchiveOperation::Extract, target, result);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{collect_files, count_tar_entries, extract_tar_gz, extract_zip, safe_join};
+    use flate2::{Compression, write::GzEncoder};
+    use std::{
+        fs::{self, File},
+        io,
+        path::{Path, PathBuf},
+    };
+    use zip::{ZipWriter, write::SimpleFileOptions};
+
+    use crate::study_actors::messages::ArchiveError;
+
+    fn scratch_dir(case: &str) -> Result<PathBuf, ArchiveError> {
+        let dir =
+            std::env::temp_dir().join(format!("rinf_archive_{}_{}", case, std::process::id()));
+        if dir.exists() {
+            fs::remove_dir_all(&dir)?;
+        }
+        fs::create_dir_all(&dir)?;
+        Ok(dir)
+    }
+
+    fn write_tar_gz(
+        path: &Path,
+        build: impl FnOnce(&mut tar::Builder<GzEncoder<File>>) -> io::Result<()>,
+    ) -> Result<(), ArchiveError> {
+        let mut builder =
+            tar::Builder::new(GzEncoder::new(File::create(path)?, Compression::fast()));
+        build(&mut builder)?;
+        builder.into_inner()?.finish()?;
+        Ok(())
+    }
+
+    fn file_header(size: u64) -> tar::Header {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(size);
+        header.set_mode(0o644);
+        header.set_cksum();
+        header
+    }
+
+    fn link_header(kind: tar::EntryType) -> io::Result<tar::Header> {
+        let mut header = tar::Header::new_gnu();
+        header.set_entry_type(kind);
+        header.set_size(0);
+        header.set_link_name("../../etc/passwd")?;
+        header.set_cksum();
+        Ok(header)
+    }
+
+    #[test]
+    fn safe_join_blocks_paths_leaving_the_destination() -> Result<(), ArchiveError> {
+        let dest = Path::new("out");
+        assert_eq!(
+            safe_join(dest, Path::new("a/./b.txt"))?,
+            dest.join("a/./b.txt")
+        );
+        assert!(safe_join(dest, Path::new("../evil.txt")).is_err());
+        assert!(safe_join(dest, Path::new("a/../../evil.txt")).is_err());
+        assert!(safe_join(dest, Path::new("/etc/passwd")).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn zip_with_escaping_or_link_entries_is_rejected() -> Result<(), ArchiveError> {
+        let dir = scratch_dir("zip")?;
+        let dest = dir.join("out");
+        let options = SimpleFileOptions::default();
+
+        // enclosed_name이 없는 항목 (상위 디렉터리로 빠져나간다)
+        let slip = dir.join("slip.zip");
+        let mut writer = ZipWriter::new(File::create(&slip)?);
+        writer.start_file("../evil.txt", options)?;
+        writer.finish()?;
+        assert!(extract_zip(&slip.to_string_lossy(), &dest).is_err());
+        assert!(!dir.join("evil.txt").exists());
+
+        let link = dir.join("link.zip");
+        let mut writer = ZipWriter::new(File::create(&link)?);
+        writer.add_symlink("passwd", "/etc/passwd", options)?;
+        writer.finish()?;
+        assert!(extract_zip(&link.to_string_lossy(), &dest).is_err());
+        assert!(!dest.join("passwd").exists());
+
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn tar_gz_link_entries_are_rejected() -> Result<(), ArchiveError> {
+        let dir = scratch_dir("tar")?;
+        let dest = dir.join("out");
+        for (name, kind) in [
+            ("symlink", tar::EntryType::Symlink),
+            ("hardlink", tar::EntryType::Link),
+        ] {
+            let archive = dir.join(format!("{}.tar.gz", name));
+            write_tar_gz(&archive, |builder| {
+                builder.append_data(&mut link_header(kind)?, name, io::empty())
+            })?;
+            assert!(extract_tar_gz(&archive.to_string_lossy(), &dest).is_err());
+            assert!(!dest.join(name).exists());
+        }
+
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn tar_gz_entries_are_counted_before_extracting() -> Result<(), ArchiveError> {
+        let dir = scratch_dir("count")?;
+        let archive = dir.join("files.tar.gz");
+        write_tar_gz(&archive, |builder| {
+            for name in ["a.txt", "nested/b.txt", "nested/c.txt"] {
+                builder.append_data(&mut file_header(4), name, &b"data"[..])?;
+            }
+            Ok(())
+        })?;
+
+        let archive = archive.to_string_lossy();
+        assert_eq!(count_tar_entries(&archive)?, 3);
+        assert_eq!(extract_tar_gz(&archive, &dir.join("out"))?, 3);
+        assert_eq!(fs::read(dir.join("out/nested/c.txt"))?, b"data");
+
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn collect_files_skips_symlinks() -> Result<(), ArchiveError> {
+        let dir = scratch_dir("collect")?;
+        let input = dir.join("input");
+        fs::create_dir_all(&input)?;
+        fs::write(input.join("kept.txt"), b"kept")?;
+        fs::write(dir.join("outside.txt"), b"outside")?;
+        std::os::unix::fs::symlink(dir.join("outside.txt"), input.join("file_link"))?;
+        std::os::unix::fs::symlink(&dir, input.join("dir_link"))?;
+
+        let files = collect_files(&[input.to_string_lossy().into_owned()])?;
+        let names: Vec<&str> = files.iter().map(|(_, name)| name.as_str()).collect();
+        assert_eq!(names, ["input/kept.txt"]);
+
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+}
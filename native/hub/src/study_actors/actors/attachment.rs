@@ -0,0 +1,917 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use image::ImageFormat;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fs::{self, File},
+    io::{Cursor, Read, Write},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+use tokio::{sync::broadcast, task::JoinSet};
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        AppEvent, AttachBytes, AttachFile, AttachRemoteFile, Attachment, AttachmentConfig,
+        AttachmentError, CategoryUsage, ClearStorage, CollectOrphanedBlobs, FetchData, FetchMode,
+        IdKind, IntegrityReport, MeasureStorage, OpenAttachment, RecordItemAttachment,
+        ScheduledTask, StorageCategory, StoreData, TrafficClass, VerifyBlobs, WipeUserData,
+    },
+    signals::{
+        AttachFileRequest, AttachRemoteFileRequest, AttachmentAddedSignal, AttachmentReadySignal,
+        EmitSignal, OpenAttachmentRequest, route_dart_signals,
+    },
+};
+
+use super::network::NetworkRequest;
+use super::{
+    Clock, DataManagerActor, NetworkManagerActor, Priority, PriorityMailbox, StorageActor,
+    TrustedClock, generate_id,
+};
+
+const ATTACHMENTS_KEY: &str = "attachments/index";
+const COPY_CHUNK_SIZE: usize = 64 * 1024;
+const TEMP_PREFIX: &str = "tmp-";
+const TEMP_GRACE_SECS: u64 = 3600; // 복사 도중 중단된 임시 파일 보존 시간
+const QUARANTINE_DIR: &str = "quarantine"; // 블롭 디렉터리 아래, GC는 하위 디렉터리를 건드리지 않는다
+const DOWNLOAD_TIMEOUT_MS: u64 = 60_000;
+
+fn mime_type_for(file_name: &str) -> &'static str {
+    let extension = Path::new(file_name)
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_lowercase)
+        .unwrap_or_default();
+    match extension.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "bmp" => "image/bmp",
+        "pdf" => "application/pdf",
+        "txt" | "md" => "text/plain",
+        "csv" => "text/csv",
+        "json" => "application/json",
+        "zip" => "application/zip",
+        "mp3" => "audio/mpeg",
+        "m4a" => "audio/mp4",
+        "ogg" | "opus" => "audio/ogg",
+        "mp4" => "video/mp4",
+        _ => "application/octet-stream",
+    }
+}
+
+// image 크레이트로 디코딩 가능한 형식만 썸네일을 만든다
+fn has_thumbnail(mime_type: &str) -> bool {
+    matches!(
+        mime_type,
+        "image/png" | "image/jpeg" | "image/gif" | "image/webp" | "image/bmp"
+    )
+}
+
+fn temp_blob_path(blob_dir: &Path) -> PathBuf {
+    let nanos = Utc::now().timestamp_nanos_opt().unwrap_or_default();
+    blob_dir.join(format!("{}{}", TEMP_PREFIX, nanos))
+}
+
+// 임시 파일을 해시 이름으로 옮긴다 (같은 내용이 이미 있으면 임시 파일만 지움)
+fn commit_blob(blob_dir: &Path, temp_path: &Path, hash: &str) -> Result<(), AttachmentError> {
+    let blob_path = blob_dir.join(hash);
+    if blob_path.exists() {
+        fs::remove_file(temp_path)?;
+    } else {
+        fs::rename(temp_path, blob_path)?;
+    }
+    Ok(())
+}
+
+// 블로킹 스레드에서 파일을 청크 단위로 복사하며 해시 계산
+fn import_file_blocking(blob_dir: &Path, source: &Path) -> Result<(String, u64), AttachmentError> {
+    fs::create_dir_all(blob_dir)?;
+    let mut input = File::open(source)?;
+    let temp_path = temp_blob_path(blob_dir);
+
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0u8; COPY_CHUNK_SIZE];
+    let mut size = 0u64;
+    // 옮기기 전에 임시 파일을 닫는다
+    {
+        let mut output = File::create(&temp_path)?;
+        loop {
+            let read = input.read(&mut buffer)?;
+            if read == 0 {
+                break;
+            }
+            hasher.update(&buffer[..read]);
+            output.write_all(&buffer[..read])?;
+            size += read as u64;
+        }
+        output.sync_all()?;
+    }
+
+    let hash = hex::encode(hasher.finalize());
+    commit_blob(blob_dir, &temp_path, &hash)?;
+    Ok((hash, size))
+}
+
+fn import_bytes_blocking(blob_dir: &Path, bytes: &[u8]) -> Result<String, AttachmentError> {
+    fs::create_dir_all(blob_dir)?;
+    let hash = hex::encode(Sha256::digest(bytes));
+    let temp_path = temp_blob_path(blob_dir);
+    fs::write(&temp_path, bytes)?;
+    commit_blob(blob_dir, &temp_path, &hash)?;
+    Ok(hash)
+}
+
+// 비율을 유지해 size×size 안에 맞춘 PNG 썸네일
+fn thumbnail_blocking(source: &Path, size: u32) -> Result<Vec<u8>, AttachmentError> {
+    let thumbnail = image::open(source)?.thumbnail(size, size);
+    let mut bytes = Vec::new();
+    thumbnail.write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)?;
+    Ok(bytes)
+}
+
+fn collect_garbage_blocking(
+    blob_dir: &Path,
+    referenced: &HashSet<String>,
+) -> Result<usize, AttachmentError> {
+    let entries = match fs::read_dir(blob_dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut removed = 0;
+    for entry in entries {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            continue;
+        }
+        let name = entry.file_name().to_string_lossy().to_string();
+        let orphaned = if name.starts_with(TEMP_PREFIX) {
+            entry
+                .metadata()?
+                .modified()?
+                .elapsed()
+                .is_ok_and(|age| age.as_secs() > TEMP_GRACE_SECS)
+        } else {
+            !referenced.contains(&name)
+        };
+        if orphaned {
+            fs::remove_file(entry.path())?;
+            removed += 1;
+        }
+    }
+    Ok(removed)
+}
+
+// 블롭 디렉터리의 (파일 이름, 크기) 목록 (디렉터리가 없으면 비어 있다)
+fn list_blobs_blocking(blob_dir: &Path) -> Result<Vec<(String, u64)>, AttachmentError> {
+    let entries = match fs::read_dir(blob_dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut blobs = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        if metadata.is_file() {
+            blobs.push((
+                entry.file_name().to_string_lossy().to_string(),
+                metadata.len(),
+            ));
+        }
+    }
+    Ok(blobs)
+}
+
+fn remove_blobs_blocking(blob_dir: &Path, hashes: &[String]) -> Result<usize, AttachmentError> {
+    let mut removed = 0;
+    for hash in hashes {
+        match fs::remove_file(blob_dir.join(hash)) {
+            Ok(()) => removed += 1,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(removed)
+}
+
+// 블롭 이름은 내용의 SHA-256이므로 다시 해시해 비교한다 (파일이 없으면 None)
+fn blob_intact_blocking(path: &Path, hash: &str) -> Result<Option<bool>, AttachmentError> {
+    let mut file = match File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0u8; COPY_CHUNK_SIZE];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(Some(hex::encode(hasher.finalize()) == hash))
+}
+
+// blobs는 해시별 (설명, 지워도 되살릴 수 있는지)
+// 되살릴 수 있는 블롭은 지우고, 아니면 사용자가 꺼낼 수 있도록 격리 디렉터리로 옮긴다.
+fn verify_blobs_blocking(
+    blob_dir: &Path,
+    blobs: BTreeMap<String, (String, bool)>,
+) -> Result<IntegrityReport, AttachmentError> {
+    let mut report = IntegrityReport::default();
+    for (hash, (label, recoverable)) in blobs {
+        let path = blob_dir.join(&hash);
+        match blob_intact_blocking(&path, &hash)? {
+            None => continue,
+            Some(true) => report.checked += 1,
+            Some(false) if recoverable => {
+                report.checked += 1;
+                fs::remove_file(&path)?;
+                report.repaired.push(label);
+            }
+            Some(false) => {
+                report.checked += 1;
+                let quarantine_dir = blob_dir.join(QUARANTINE_DIR);
+                fs::create_dir_all(&quarantine_dir)?;
+                fs::rename(&path, quarantine_dir.join(&hash))?;
+                report.quarantined.push(label);
+            }
+        }
+    }
+    Ok(report)
+}
+
+// 첨부 파일 액터
+// 파일을 내용 주소 방식의 블롭 저장소에 복사하고, 메타데이터는 항목과 저장소 양쪽에 기록한다.
+// 어떤 첨부도 참조하지 않게 된 블롭은 주기적인 GC 작업이 지운다.
+pub struct AttachmentActor {
+    attachments: HashMap<String, Attachment>,
+    loaded: bool, // 색인을 읽기 전에는 GC가 모든 블롭을 고아로 볼 수 있으므로 막는다
+    blob_dir: PathBuf,
+    thumbnail_size: u32,
+    storage: Address<StorageActor>,
+    data: Address<DataManagerActor>,
+    network_manager: PriorityMailbox<NetworkManagerActor>,
+    clock: TrustedClock,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for AttachmentActor {}
+
+impl AttachmentActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        config: AttachmentConfig,
+        storage: Address<StorageActor>,
+        data: Address<DataManagerActor>,
+        network_manager: PriorityMailbox<NetworkManagerActor>,
+        events: broadcast::Receiver<AppEvent>,
+        clock: TrustedClock,
+    ) -> Self {
+        let mut owned_tasks = route_dart_signals!(
+            self_addr,
+            [
+                AttachFileRequest,
+                AttachRemoteFileRequest,
+                OpenAttachmentRequest
+            ]
+        );
+        owned_tasks.spawn(Self::load_attachments(self_addr.clone()));
+        owned_tasks.spawn(Self::listen_to_events(self_addr.clone(), events));
+        owned_tasks.spawn(Self::collect_garbage_periodically(
+            self_addr.clone(),
+            clock.clock().clone(),
+            config.gc_interval_secs,
+        ));
+
+        let blob_dir = config
+            .blob_dir
+            .map(PathBuf::from)
+            .unwrap_or_else(|| std::env::temp_dir().join("rinf_blobs"));
+
+        Self {
+            attachments: HashMap::new(),
+            loaded: false,
+            blob_dir,
+            thumbnail_size: config.thumbnail_size,
+            storage,
+            data,
+            network_manager,
+            clock,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    async fn load_attachments(mut self_addr: Address<Self>) {
+        let _ = self_addr.notify(LoadAttachments).await;
+    }
+
+    async fn listen_to_events(
+        mut self_addr: Address<Self>,
+        mut events: broadcast::Receiver<AppEvent>,
+    ) {
+        loop {
+            match events.recv().await {
+                Ok(event) => {
+                    let _ = self_addr.notify(AppEventReceived(event)).await;
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    debug_print!("Attachment actor skipped {} app events", skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    }
+
+    async fn collect_garbage_periodically(
+        mut self_addr: Address<Self>,
+        clock: Clock,
+        interval_secs: u64,
+    ) {
+        let mut interval = clock
+            .interval(Duration::from_secs(interval_secs.max(60)))
+            .throttled(ScheduledTask::AttachmentGc);
+        interval.tick().await; // 첫 틱은 즉시 발생하므로 건너뜀
+        loop {
+            interval.tick().await;
+            match self_addr.send(CollectOrphanedBlobs).await {
+                Ok(Ok(removed)) if removed > 0 => {
+                    debug_print!("Removed {} orphaned attachment blobs", removed);
+                }
+                Ok(Err(e)) => debug_print!("Attachment GC failed: {}", e),
+                _ => {}
+            }
+        }
+    }
+
+    fn blob_path(&self, hash: &str) -> PathBuf {
+        self.blob_dir.join(hash)
+    }
+
+    fn thumbnail_path(&self, attachment: &Attachment) -> Option<String> {
+        attachment
+            .thumbnail_hash
+            .as_deref()
+            .map(|hash| self.blob_path(hash).to_string_lossy().to_string())
+    }
+
+    // 썸네일로만 쓰이는 블롭 (같은 이미지를 원본으로 첨부한 경우는 빼야 원본이 지워지지 않는다)
+    fn thumbnail_hashes(&self) -> HashSet<String> {
+        let originals: HashSet<&str> = self
+            .attachments
+            .values()
+            .filter_map(|a| a.blob_hash.as_deref())
+            .collect();
+        self.attachments
+            .values()
+            .filter_map(|a| a.thumbnail_hash.as_deref())
+            .filter(|hash| !originals.contains(hash))
+            .map(str::to_string)
+            .collect()
+    }
+
+    fn send_added(&self, item_id: String, result: Result<Attachment, AttachmentError>) {
+        match result {
+            Ok(attachment) => {
+                AttachmentAddedSignal {
+                    item_id,
+                    thumbnail_path: self.thumbnail_path(&attachment),
+                    attachment: Some(attachment),
+                    error: None,
+                }
+                .emit();
+            }
+            Err(e) => {
+                AttachmentAddedSignal {
+                    item_id,
+                    attachment: None,
+                    thumbnail_path: None,
+                    error: Some(e.to_string()),
+                }
+                .emit();
+            }
+        }
+    }
+
+    async fn persist_attachments(&mut self) -> Result<(), AttachmentError> {
+        let request = StoreData {
+            key: ATTACHMENTS_KEY.to_string(),
+            data: serde_json::to_vec(&self.attachments)?,
+            user_id: None,
+            ttl: None,
+        };
+        self.storage.send(request).await??;
+        Ok(())
+    }
+
+    // 항목에 먼저 기록한 뒤 색인에 반영 (항목이 없으면 블롭은 GC 대상으로 남음)
+    async fn record(&mut self, attachment: Attachment) -> Result<(), AttachmentError> {
+        self.data
+            .send(RecordItemAttachment {
+                attachment: attachment.clone(),
+            })
+            .await??;
+        self.attachments.insert(attachment.id.clone(), attachment);
+        self.persist_attachments().await
+    }
+
+    // 썸네일 생성 실패는 첨부 자체를 실패시키지 않는다
+    async fn make_thumbnail(&self, source: PathBuf, mime_type: &str) -> Option<String> {
+        if !has_thumbnail(mime_type) {
+            return None;
+        }
+        let blob_dir = self.blob_dir.clone();
+        let size = self.thumbnail_size;
+        let result = tokio::task::spawn_blocking(move || {
+            let bytes = thumbnail_blocking(&source, size)?;
+            import_bytes_blocking(&blob_dir, &bytes)
+        })
+        .await;
+        match result {
+            Ok(Ok(hash)) => Some(hash),
+            Ok(Err(e)) => {
+                debug_print!("Thumbnail generation failed: {}", e);
+                None
+            }
+            Err(e) => {
+                debug_print!("Thumbnail task failed: {}", e);
+                None
+            }
+        }
+    }
+
+    async fn download(&mut self, url: &str) -> Result<String, AttachmentError> {
+        let request = NetworkRequest::new(url)
+            .timeout(DOWNLOAD_TIMEOUT_MS)
+            .traffic(TrafficClass::Downloads);
+        let response = self
+            .network_manager
+            .request(Priority::High, request)
+            .await??;
+        if let Some(error) = response.error {
+            return Err(error.into());
+        }
+        if !response.is_success() {
+            return Err(format!("Attachment download failed: {}", response.status).into());
+        }
+
+        let blob_dir = self.blob_dir.clone();
+        tokio::task::spawn_blocking(move || import_bytes_blocking(&blob_dir, &response.body))
+            .await?
+    }
+}
+
+// 내부 메시지 정의
+struct LoadAttachments;
+struct AppEventReceived(AppEvent);
+
+#[async_trait]
+impl Notifiable<LoadAttachments> for AttachmentActor {
+    async fn notify(&mut self, _: LoadAttachments, _: &Context<Self>) {
+        let request = FetchData {
+            key: ATTACHMENTS_KEY.to_string(),
+            user_id: None,
+            mode: FetchMode::CacheFirst,
+        };
+        match self.storage.send(request).await {
+            Ok(Ok(bytes)) => match serde_json::from_slice(&bytes) {
+                Ok(attachments) => {
+                    self.attachments = attachments;
+                    self.loaded = true;
+                }
+                // 색인이 손상되었으면 GC를 멈춘 채로 둔다
+                Err(e) => debug_print!("Invalid stored attachment index: {}", e),
+            },
+            // 저장된 색인이 없으면 첨부가 없는 상태
+            Ok(Err(_)) => self.loaded = true,
+            Err(e) => debug_print!("Failed to load attachment index: {}", e),
+        }
+    }
+}
+
+#[async_trait]
+impl Notifiable<AppEventReceived> for AttachmentActor {
+    async fn notify(&mut self, msg: AppEventReceived, _: &Context<Self>) {
+        let changed = match msg.0 {
+            // 메타데이터만 지우고 블롭은 다음 GC에서 정리
+            AppEvent::DataItemDeleted { item_id, .. } => {
+                let before = self.attachments.len();
+                self.attachments.retain(|_, a| a.item_id != item_id);
+                self.attachments.len() != before
+            }
+            // 중복 항목을 합치면 첨부가 남길 항목으로 옮겨진다 (합쳐진 항목의 삭제보다 먼저 온다)
+            AppEvent::DataItemUpdated { item, .. } => {
+                let mut moved = false;
+                for attachment in &item.attachments {
+                    if let Some(existing) = self.attachments.get_mut(&attachment.id)
+                        && existing.item_id != item.id
+                    {
+                        existing.item_id = item.id.clone();
+                        moved = true;
+                    }
+                }
+                moved
+            }
+            _ => false,
+        };
+        if changed && let Err(e) = self.persist_attachments().await {
+            debug_print!("Failed to save attachment index: {}", e);
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<AttachFile> for AttachmentActor {
+    type Result = Result<Attachment, AttachmentError>;
+
+    async fn handle(&mut self, msg: AttachFile, _: &Context<Self>) -> Self::Result {
+        let source = PathBuf::from(&msg.path);
+        let file_name = source
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .ok_or_else(|| format!("Not a file path: {}", msg.path))?;
+        let mime_type = mime_type_for(&file_name);
+
+        let blob_dir = self.blob_dir.clone();
+        let copy_source = source.clone();
+        let (hash, size) =
+            tokio::task::spawn_blocking(move || import_file_blocking(&blob_dir, &copy_source))
+                .await??;
+        let thumbnail_hash = self.make_thumbnail(self.blob_path(&hash), mime_type).await;
+
+        let attachment = Attachment {
+            id: generate_id(IdKind::Ulid),
+            item_id: msg.item_id,
+            file_name,
+            mime_type: mime_type.to_string(),
+            size,
+            blob_hash: Some(hash),
+            thumbnail_hash,
+            remote_url: None,
+            created_at: self.clock.trusted_now(),
+        };
+        self.record(attachment.clone()).await?;
+        debug_print!(
+            "Attached {} to {}",
+            attachment.file_name,
+            attachment.item_id
+        );
+        Ok(attachment)
+    }
+}
+
+#[async_trait]
+impl Handler<AttachBytes> for AttachmentActor {
+    type Result = Result<Attachment, AttachmentError>;
+
+    async fn handle(&mut self, msg: AttachBytes, _: &Context<Self>) -> Self::Result {
+        let mime_type = msg
+            .mime_type
+            .unwrap_or_else(|| mime_type_for(&msg.file_name).to_string());
+        let size = msg.bytes.len() as u64;
+
+        let blob_dir = self.blob_dir.clone();
+        let hash =
+            tokio::task::spawn_blocking(move || import_bytes_blocking(&blob_dir, &msg.bytes))
+                .await??;
+        let thumbnail_hash = self.make_thumbnail(self.blob_path(&hash), &mime_type).await;
+
+        let attachment = Attachment {
+            id: generate_id(IdKind::Ulid),
+            item_id: msg.item_id,
+            file_name: msg.file_name,
+            mime_type,
+            size,
+            blob_hash: Some(hash),
+            thumbnail_hash,
+            remote_url: None,
+            created_at: self.clock.trusted_now(),
+        };
+        self.record(attachment.clone()).await?;
+        Ok(attachment)
+    }
+}
+
+#[async_trait]
+impl Handler<AttachRemoteFile> for AttachmentActor {
+    type Result = Result<Attachment, AttachmentError>;
+
+    async fn handle(&mut self, msg: AttachRemoteFile, _: &Context<Self>) -> Self::Result {
+        let attachment = Attachment {
+            id: generate_id(IdKind::Ulid),
+            item_id: msg.item_id,
+            mime_type: mime_type_for(&msg.file_name).to_string(),
+            file_name: msg.file_name,
+            size: msg.size,
+            blob_hash: None,
+            thumbnail_hash: None,
+            remote_url: Some(msg.url),
+            created_at: self.clock.trusted_now(),
+        };
+        self.record(attachment.clone()).await?;
+        Ok(attachment)
+    }
+}
+
+#[async_trait]
+impl Handler<OpenAttachment> for AttachmentActor {
+    type Result = Result<Attachment, AttachmentError>;
+
+    async fn handle(&mut self, msg: OpenAttachment, _: &Context<Self>) -> Self::Result {
+        let mut attachment = self
+            .attachments
+            .get(&msg.attachment_id)
+            .cloned()
+            .ok_or_else(|| format!("Unknown attachment: {}", msg.attachment_id))?;
+
+        let local = attachment
+            .blob_hash
+            .as_deref()
+            .is_some_and(|hash| self.blob_path(hash).exists());
+        if local {
+            // 저장 공간 정리로 지운 썸네일은 열 때 다시 만든다 (원본이 같으면 해시도 같다)
+            let thumbnail_cleared = attachment
+                .thumbnail_hash
+                .as_deref()
+                .is_some_and(|hash| !self.blob_path(hash).exists());
+            if thumbnail_cleared && let Some(hash) = attachment.blob_hash.as_deref() {
+                let thumbnail_hash = self
+                    .make_thumbnail(self.blob_path(hash), &attachment.mime_type)
+                    .await;
+                if thumbnail_hash != attachment.thumbnail_hash {
+                    attachment.thumbnail_hash = thumbnail_hash;
+                    self.record(attachment.clone()).await?;
+                }
+            }
+            return Ok(attachment);
+        }
+
+        // 원격 첨부이거나 로컬 블롭이 사라진 경우 다시 내려받는다
+        let url = attachment
+            .remote_url
+            .clone()
+            .ok_or("Attachment blob is missing and has no remote source")?;
+        let hash = self.download(&url).await?;
+        let blob_path = self.blob_path(&hash);
+        #[cfg(not(target_family = "wasm"))]
+        let metadata = tokio::fs::metadata(&blob_path).await?;
+        // 웹에는 비동기 파일 API가 없다 (브라우저에서는 파일 시스템이 없어 오류가 난다)
+        #[cfg(target_family = "wasm")]
+        let metadata = std::fs::metadata(&blob_path)?;
+        attachment.size = metadata.len();
+        attachment.thumbnail_hash = self.make_thumbnail(blob_path, &attachment.mime_type).await;
+        attachment.blob_hash = Some(hash);
+
+        self.record(attachment.clone()).await?;
+        Ok(attachment)
+    }
+}
+
+#[async_trait]
+impl Handler<CollectOrphanedBlobs> for AttachmentActor {
+    type Result = Result<usize, AttachmentError>;
+
+    async fn handle(&mut self, _: CollectOrphanedBlobs, _: &Context<Self>) -> Self::Result {
+        if !self.loaded {
+            return Ok(0);
+        }
+
+        let referenced: HashSet<String> = self
+            .attachments
+            .values()
+            .flat_map(|a| [a.blob_hash.clone(), a.thumbnail_hash.clone()])
+            .flatten()
+            .collect();
+        let blob_dir = self.blob_dir.clone();
+        tokio::task::spawn_blocking(move || collect_garbage_blocking(&blob_dir, &referenced))
+            .await?
+    }
+}
+
+#[async_trait]
+impl Handler<MeasureStorage> for AttachmentActor {
+    type Result = Result<Vec<CategoryUsage>, AttachmentError>;
+
+    async fn handle(&mut self, _: MeasureStorage, _: &Context<Self>) -> Self::Result {
+        let thumbnails = self.thumbnail_hashes();
+        let blob_dir = self.blob_dir.clone();
+        let blobs = tokio::task::spawn_blocking(move || list_blobs_blocking(&blob_dir)).await??;
+
+        // 고아 블롭과 복사 중인 임시 파일은 원본 쪽에 센다 (원본 분류를 비우면 지워진다)
+        let mut originals = CategoryUsage::new(StorageCategory::Attachments, 0, 0);
+        let mut thumbnail_usage = CategoryUsage::new(StorageCategory::Thumbnails, 0, 0);
+        for (name, size) in blobs {
+            let usage = if thumbnails.contains(&name) {
+                &mut thumbnail_usage
+            } else {
+                &mut originals
+            };
+            usage.bytes += size;
+            usage.entries += 1;
+        }
+        Ok(vec![originals, thumbnail_usage])
+    }
+}
+
+#[async_trait]
+impl Handler<ClearStorage> for AttachmentActor {
+    type Result = Result<usize, AttachmentError>;
+
+    async fn handle(&mut self, msg: ClearStorage, ctx: &Context<Self>) -> Self::Result {
+        match msg.category {
+            // 항목에 붙은 첨부는 사용자 데이터라 고아 블롭만 지운다
+            StorageCategory::Attachments => self.handle(CollectOrphanedBlobs, ctx).await,
+            // 메타데이터의 썸네일 해시는 남겨 두고 파일만 지운다
+            StorageCategory::Thumbnails => {
+                if !self.loaded {
+                    return Err("Attachment index is not loaded yet".into());
+                }
+                let thumbnails: Vec<String> = self.thumbnail_hashes().into_iter().collect();
+                let blob_dir = self.blob_dir.clone();
+                tokio::task::spawn_blocking(move || remove_blobs_blocking(&blob_dir, &thumbnails))
+                    .await?
+            }
+            _ => Ok(0),
+        }
+    }
+}
+
+// 검사하는 동안 메일함이 막혀 있으므로 새 블롭이 쓰이거나 GC가 끼어들지 않는다
+// 지운 원본은 열 때 원격에서 다시 받고, 지운 썸네일은 열 때 다시 만든다.
+#[async_trait]
+impl Handler<VerifyBlobs> for AttachmentActor {
+    type Result = Result<IntegrityReport, AttachmentError>;
+
+    async fn handle(&mut self, _: VerifyBlobs, _: &Context<Self>) -> Self::Result {
+        let mut blobs: BTreeMap<String, (String, bool)> = BTreeMap::new();
+        for attachment in self.attachments.values() {
+            if let Some(hash) = &attachment.blob_hash {
+                let label = format!("attachment {} ({})", attachment.id, attachment.file_name);
+                let recoverable = attachment.remote_url.is_some();
+                let entry = blobs
+                    .entry(hash.clone())
+                    .or_insert_with(|| (label.clone(), recoverable));
+                // 같은 블롭을 되살릴 수 없는 원본이 하나라도 쓰면 격리한다
+                if !recoverable {
+                    *entry = (label, false);
+                }
+            }
+        }
+        for attachment in self.attachments.values() {
+            if let Some(hash) = &attachment.thumbnail_hash {
+                blobs
+                    .entry(hash.clone())
+                    .or_insert_with(|| (format!("thumbnail of {}", attachment.id), true));
+            }
+        }
+
+        let blob_dir = self.blob_dir.clone();
+        tokio::task::spawn_blocking(move || verify_blobs_blocking(&blob_dir, blobs)).await?
+    }
+}
+
+#[async_trait]
+impl Handler<WipeUserData> for AttachmentActor {
+    type Result = Result<(), AttachmentError>;
+
+    async fn handle(&mut self, _: WipeUserData, _: &Context<Self>) -> Self::Result {
+        self.attachments.clear();
+        #[cfg(not(target_family = "wasm"))]
+        let removed = tokio::fs::remove_dir_all(&self.blob_dir).await;
+        #[cfg(target_family = "wasm")]
+        let removed = std::fs::remove_dir_all(&self.blob_dir);
+        match removed {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<AttachFileRequest> for AttachmentActor {
+    async fn notify(&mut self, msg: AttachFileRequest, ctx: &Context<Self>) {
+        let item_id = msg.item_id.clone();
+        let result = self
+            .handle(
+                AttachFile {
+                    item_id: msg.item_id,
+                    path: msg.path,
+                },
+                ctx,
+            )
+            .await;
+        self.send_added(item_id, result);
+    }
+}
+
+#[async_trait]
+impl Notifiable<AttachRemoteFileRequest> for AttachmentActor {
+    async fn notify(&mut self, msg: AttachRemoteFileRequest, ctx: &Context<Self>) {
+        let item_id = msg.item_id.clone();
+        let result = self
+            .handle(
+                AttachRemoteFile {
+                    item_id: msg.item_id,
+                    url: msg.url,
+                    file_name: msg.file_name,
+                    size: msg.size,
+                },
+                ctx,
+            )
+            .await;
+        self.send_added(item_id, result);
+    }
+}
+
+#[async_trait]
+impl Notifiable<OpenAttachmentRequest> for AttachmentActor {
+    async fn notify(&mut self, msg: OpenAttachmentRequest, ctx: &Context<Self>) {
+        let attachment_id = msg.attachment_id.clone();
+        let result = self
+            .handle(
+                OpenAttachment {
+                    attachment_id: msg.attachment_id,
+                },
+                ctx,
+            )
+            .await;
+
+        match result {
+            Ok(attachment) => {
+                AttachmentReadySignal {
+                    attachment_id,
+                    local_path: attachment
+                        .blob_hash
+                        .as_deref()
+                        .map(|hash| self.blob_path(hash).to_string_lossy().to_string()),
+                    thumbnail_path: self.thumbnail_path(&attachment),
+                    error: None,
+                }
+                .emit();
+            }
+            Err(e) => {
+                AttachmentReadySignal {
+                    attachment_id,
+                    local_path: None,
+                    thumbnail_path: None,
+                    error: Some(e.to_string()),
+                }
+                .emit();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{QUARANTINE_DIR, verify_blobs_blocking};
+    use sha2::{Digest, Sha256};
+    use std::{collections::BTreeMap, error::Error, fs};
+
+    #[test]
+    fn corrupt_blobs_are_removed_or_quarantined() -> Result<(), Box<dyn Error>> {
+        let blob_dir =
+            std::env::temp_dir().join(format!("rinf_verify_blobs_{}", std::process::id()));
+        fs::create_dir_all(&blob_dir)?;
+        let hash_of = |bytes: &[u8]| hex::encode(Sha256::digest(bytes));
+        let intact = hash_of(b"intact");
+        let remote = hash_of(b"remote");
+        let local = hash_of(b"local");
+        fs::write(blob_dir.join(&intact), b"intact")?;
+        fs::write(blob_dir.join(&remote), b"truncated")?;
+        fs::write(blob_dir.join(&local), b"bit rot")?;
+        let blobs = BTreeMap::from([
+            (intact, ("intact".to_string(), false)),
+            (remote.clone(), ("remote".to_string(), true)),
+            (local.clone(), ("local".to_string(), false)),
+            (hash_of(b"missing"), ("missing".to_string(), false)),
+        ]);
+
+        let report = verify_blobs_blocking(&blob_dir, blobs);
+        let quarantined = blob_dir.join(QUARANTINE_DIR).join(&local).exists();
+        let remote_left = blob_dir.join(&remote).exists();
+        fs::remove_dir_all(&blob_dir)?;
+
+        // 없는 파일은 검사 대상에서 빠진다 (열 때 다시 받거나 오류를 낸다)
+        let report = report.map_err(|e| e.to_string())?;
+        assert_eq!(report.checked, 3);
+        assert_eq!(report.repaired, ["remote"]);
+        assert_eq!(report.quarantined, ["local"]);
+        assert!(quarantined && !remote_left);
+        Ok(())
+    }
+}
@@ -0,0 +1,390 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use std::{fs::File, io::ErrorKind, path::Path};
+use symphonia::core::{
+    audio::SampleBuffer, codecs::DecoderOptions, errors::Error as SymphoniaError,
+    formats::FormatOptions, io::MediaSourceStream, meta::MetadataOptions, probe::Hint,
+};
+use tokio::task::JoinSet;
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{AudioError, ComputeWaveform, TranscodeToOpus},
+    signals::{
+        AudioProgressSignal, EmitBinarySignal, EmitSignal, TranscodeAudioRequest,
+        TranscodedAudioSignal, WaveformRequest, WaveformSignal, route_dart_signals,
+    },
+};
+
+const DEFAULT_BITRATE_BPS: i32 = 24_000; // 음성용
+const PROGRESS_STEP: f32 = 0.05;
+
+// 모노로 합친 디코딩 결과
+struct DecodedAudio {
+    samples: Vec<f32>,
+    sample_rate: u32,
+}
+
+impl DecodedAudio {
+    fn duration_ms(&self) -> u64 {
+        self.samples.len() as u64 * 1000 / self.sample_rate.max(1) as u64
+    }
+}
+
+// symphonia로 파일을 디코딩해 모노 f32 샘플로 변환
+fn decode_mono(path: &str, mut on_progress: impl FnMut(f32)) -> Result<DecodedAudio, AudioError> {
+    let source = MediaSourceStream::new(Box::new(File::open(path)?), Default::default());
+    let mut hint = Hint::new();
+    if let Some(extension) = Path::new(path).extension().and_then(|e| e.to_str()) {
+        hint.with_extension(extension);
+    }
+
+    let probed = symphonia::default::get_probe().format(
+        &hint,
+        source,
+        &FormatOptions::default(),
+        &MetadataOptions::default(),
+    )?;
+    let mut format = probed.format;
+    let track = format
+        .default_track()
+        .ok_or("No audio track found")?
+        .clone();
+    let sample_rate = track
+        .codec_params
+        .sample_rate
+        .ok_or("Unknown sample rate")?;
+    let total_frames = track.codec_params.n_frames;
+    let mut decoder =
+        symphonia::default::get_codecs().make(&track.codec_params, &DecoderOptions::default())?;
+
+    let mut samples = Vec::new();
+    let mut reported = 0.0;
+    loop {
+        let packet = match format.next_packet() {
+            Ok(packet) => packet,
+            Err(SymphoniaError::IoError(e)) if e.kind() == ErrorKind::UnexpectedEof => break,
+            Err(e) => return Err(e.into()),
+        };
+        if packet.track_id() != track.id {
+            continue;
+        }
+
+        let decoded = match decoder.decode(&packet) {
+            Ok(decoded) => decoded,
+            // 손상된 패킷은 건너뜀
+            Err(SymphoniaError::DecodeError(_)) => continue,
+            Err(e) => return Err(e.into()),
+        };
+
+        let spec = *decoded.spec();
+        let channels = spec.channels.count().max(1);
+        let mut buffer = SampleBuffer::<f32>::new(decoded.capacity() as u64, spec);
+        buffer.copy_interleaved_ref(decoded);
+        samples.extend(
+            buffer
+                .samples()
+                .chunks(channels)
+                .map(|frame| frame.iter().sum::<f32>() / channels as f32),
+        );
+
+        if let Some(total) = total_frames.filter(|total| *total > 0) {
+            let progress = (samples.len() as f32 / total as f32).min(1.0);
+            if progress - reported >= PROGRESS_STEP {
+                reported = progress;
+                on_progress(progress);
+            }
+        }
+    }
+
+    Ok(DecodedAudio {
+        samples,
+        sample_rate,
+    })
+}
+
+// 구간별 최대 절대 진폭
+fn compute_peaks(samples: &[f32], bucket_count: usize) -> Vec<f32> {
+    if samples.is_empty() || bucket_count == 0 {
+        return Vec::new();
+    }
+    let bucket_size = samples.len().div_ceil(bucket_count);
+    samples
+        .chunks(bucket_size)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .fold(0.0f32, |peak, sample| peak.max(sample.abs()))
+                .min(1.0)
+        })
+        .collect()
+}
+
+// Ogg/Opus 인코딩 (libopus를 빌드하려면 cmake나 시스템 libopus가 필요해 audio-opus 기능 뒤에 둔다)
+#[cfg(feature = "audio-opus")]
+mod opus_encoder {
+    use ogg::{PacketWriteEndInfo, PacketWriter};
+    use opus::{Application, Bitrate, Channels, Encoder};
+
+    use super::DecodedAudio;
+    use crate::study_actors::messages::AudioError;
+
+    const OPUS_SAMPLE_RATE: u32 = 48_000;
+    const OPUS_FRAME_SIZE: usize = 960; // 20ms
+    const OPUS_MAX_PACKET: usize = 4000;
+    const OPUS_PRE_SKIP: u16 = 312;
+    const OGG_STREAM_SERIAL: u32 = 0x5249_4e46;
+
+    // Opus 인코더 입력용 48kHz 선형 보간 리샘플링
+    fn resample_linear(samples: &[f32], from_rate: u32, to_rate: u32) -> Vec<f32> {
+        if from_rate == to_rate || samples.is_empty() {
+            return samples.to_vec();
+        }
+        let ratio = from_rate as f64 / to_rate as f64;
+        let output_len = (samples.len() as f64 / ratio) as usize;
+        (0..output_len)
+            .map(|index| {
+                let position = index as f64 * ratio;
+                let base = position as usize;
+                let fraction = (position - base as f64) as f32;
+                let current = samples[base.min(samples.len() - 1)];
+                let next = samples[(base + 1).min(samples.len() - 1)];
+                current + (next - current) * fraction
+            })
+            .collect()
+    }
+
+    // RFC 7845 식별 헤더
+    fn opus_head(input_sample_rate: u32) -> Vec<u8> {
+        let mut head = Vec::with_capacity(19);
+        head.extend_from_slice(b"OpusHead");
+        head.push(1); // 버전
+        head.push(1); // 채널 수 (모노)
+        head.extend_from_slice(&OPUS_PRE_SKIP.to_le_bytes());
+        head.extend_from_slice(&input_sample_rate.to_le_bytes());
+        head.extend_from_slice(&0i16.to_le_bytes()); // 출력 게인
+        head.push(0); // 채널 매핑 패밀리
+        head
+    }
+
+    fn opus_tags() -> Vec<u8> {
+        let vendor = concat!("hub ", env!("CARGO_PKG_VERSION"));
+        let mut tags = Vec::new();
+        tags.extend_from_slice(b"OpusTags");
+        tags.extend_from_slice(&(vendor.len() as u32).to_le_bytes());
+        tags.extend_from_slice(vendor.as_bytes());
+        tags.extend_from_slice(&0u32.to_le_bytes()); // 사용자 주석 없음
+        tags
+    }
+
+    pub(super) fn encode_ogg_opus(
+        audio: &DecodedAudio,
+        bitrate_bps: i32,
+    ) -> Result<Vec<u8>, AudioError> {
+        let mut encoder = Encoder::new(OPUS_SAMPLE_RATE, Channels::Mono, Application::Voip)?;
+        encoder.set_bitrate(Bitrate::Bits(bitrate_bps))?;
+
+        let mut pcm = resample_linear(&audio.samples, audio.sample_rate, OPUS_SAMPLE_RATE);
+        let padded_len = pcm.len().div_ceil(OPUS_FRAME_SIZE).max(1) * OPUS_FRAME_SIZE;
+        pcm.resize(padded_len, 0.0);
+
+        let mut writer = PacketWriter::new(Vec::new());
+        writer.write_packet(
+            opus_head(audio.sample_rate),
+            OGG_STREAM_SERIAL,
+            PacketWriteEndInfo::EndPage,
+            0,
+        )?;
+        writer.write_packet(
+            opus_tags(),
+            OGG_STREAM_SERIAL,
+            PacketWriteEndInfo::EndPage,
+            0,
+        )?;
+
+        let frame_count = pcm.len() / OPUS_FRAME_SIZE;
+        for (index, frame) in pcm.chunks(OPUS_FRAME_SIZE).enumerate() {
+            let packet = encoder.encode_vec_float(frame, OPUS_MAX_PACKET)?;
+            let granule = OPUS_PRE_SKIP as u64 + ((index + 1) * OPUS_FRAME_SIZE) as u64;
+            let end_info = if index + 1 == frame_count {
+                PacketWriteEndInfo::EndStream
+            } else {
+                PacketWriteEndInfo::NormalPacket
+            };
+            writer.write_packet(packet, OGG_STREAM_SERIAL, end_info, granule)?;
+        }
+
+        Ok(writer.into_inner())
+    }
+}
+
+// audio-opus 기능을 끄고 빌드하면 파형 계산만 하고 변환 요청은 오류로 돌려준다
+#[cfg(not(feature = "audio-opus"))]
+mod opus_encoder {
+    use super::DecodedAudio;
+    use crate::study_actors::messages::AudioError;
+
+    pub(super) fn encode_ogg_opus(
+        _audio: &DecodedAudio,
+        _bitrate_bps: i32,
+    ) -> Result<Vec<u8>, AudioError> {
+        Err(
+            "Opus transcoding is not available in this build (enable the audio-opus feature)"
+                .into(),
+        )
+    }
+}
+
+// 오디오 액터
+// 디코딩/인코딩은 블로킹 스레드 풀에서 수행해 액터 루프를 막지 않는다.
+pub struct AudioActor {
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for AudioActor {}
+
+impl AudioActor {
+    pub fn new(self_addr: Address<Self>) -> Self {
+        let owned_tasks = route_dart_signals!(self_addr, [WaveformRequest, TranscodeAudioRequest]);
+
+        Self {
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    async fn run_waveform(msg: ComputeWaveform) -> Result<(Vec<f32>, u64), AudioError> {
+        tokio::task::spawn_blocking(move || {
+            let audio = decode_mono(&msg.path, |_| {})?;
+            Ok((
+                compute_peaks(&audio.samples, msg.bucket_count),
+                audio.duration_ms(),
+            ))
+        })
+        .await?
+    }
+
+    // request_id가 있으면 Dart로 진행률 전송
+    async fn run_transcode(
+        msg: TranscodeToOpus,
+        request_id: Option<String>,
+    ) -> Result<(Vec<u8>, u64), AudioError> {
+        tokio::task::spawn_blocking(move || {
+            let audio = decode_mono(&msg.path, |progress| {
+                if let Some(request_id) = &request_id {
+                    // 디코딩이 전체 작업의 대부분을 차지
+                    AudioProgressSignal {
+                        request_id: request_id.clone(),
+                        progress: progress * 0.8,
+                    }
+                    .emit();
+                }
+            })?;
+            let bytes = opus_encoder::encode_ogg_opus(
+                &audio,
+                msg.bitrate_bps.unwrap_or(DEFAULT_BITRATE_BPS),
+            )?;
+            Ok((bytes, audio.duration_ms()))
+        })
+        .await?
+    }
+}
+
+#[async_trait]
+impl Handler<ComputeWaveform> for AudioActor {
+    type Result = Result<Vec<f32>, AudioError>;
+
+    async fn handle(&mut self, msg: ComputeWaveform, _: &Context<Self>) -> Self::Result {
+        Self::run_waveform(msg).await.map(|(peaks, _)| peaks)
+    }
+}
+
+#[async_trait]
+impl Handler<TranscodeToOpus> for AudioActor {
+    type Result = Result<Vec<u8>, AudioError>;
+
+    async fn handle(&mut self, msg: TranscodeToOpus, _: &Context<Self>) -> Self::Result {
+        Self::run_transcode(msg, None).await.map(|(bytes, _)| bytes)
+    }
+}
+
+// Dart 신호 처리
+// 작업이 끝날 때까지 액터를 붙잡지 않도록 소유 태스크로 실행
+#[async_trait]
+impl Notifiable<WaveformRequest> for AudioActor {
+    async fn notify(&mut self, msg: WaveformRequest, _: &Context<Self>) {
+        self._owned_tasks.spawn(async move {
+            let result = Self::run_waveform(ComputeWaveform {
+                path: msg.path,
+                bucket_count: msg.bucket_count as usize,
+            })
+            .await;
+
+            match result {
+                Ok((peaks, duration_ms)) => {
+                    WaveformSignal {
+                        request_id: msg.request_id,
+                        peaks,
+                        duration_ms,
+                        error: None,
+                    }
+                    .emit();
+                }
+                Err(e) => {
+                    WaveformSignal {
+                        request_id: msg.request_id,
+                        peaks: Vec::new(),
+                        duration_ms: 0,
+                        error: Some(e.to_string()),
+                    }
+                    .emit();
+                }
+            }
+        });
+    }
+}
+
+#[async_trait]
+impl Notifiable<TranscodeAudioRequest> for AudioActor {
+    async fn notify(&mut self, msg: TranscodeAudioRequest, _: &Context<Self>) {
+        self._owned_tasks.spawn(async move {
+            let result = Self::run_transcode(
+                TranscodeToOpus {
+                    path: msg.path,
+                    bitrate_bps: msg.bitrate_bps,
+                },
+                Some(msg.request_id.clone()),
+            )
+            .await;
+
+            match result {
+                Ok((bytes, duration_ms)) => {
+                    debug_print!("Transcoded audio to Opus ({} bytes)", bytes.len());
+                    AudioProgressSignal {
+                        request_id: msg.request_id.clone(),
+                        progress: 1.0,
+                    }
+                    .emit();
+                    TranscodedAudioSignal {
+                        request_id: msg.request_id,
+                        success: true,
+                        duration_ms,
+                        error: None,
+                    }
+                    .emit_binary(bytes);
+                }
+                Err(e) => {
+                    TranscodedAudioSignal {
+                        request_id: msg.request_id,
+                        success: false,
+                        duration_ms: 0,
+                        error: Some(e.to_string()),
+                    }
+                    .emit_binary(Vec::new());
+                }
+            }
+        });
+    }
+}
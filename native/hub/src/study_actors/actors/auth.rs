@@ -3,17 +3,40 @@ use messages::{
     actor::Actor,
     prelude::{Address, Context, Handler, Notifiable},
 };
-use rinf::{debug_print, DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
 use tokio::task::JoinSet;
 
 use crate::study_actors::{
-    messages::{AuthError, AuthResult, Login, Logout, UserId, VerifyToken},
-    signals::{AuthStateChanged, LoginRequest, LoginResponse, LogoutRequest, LogoutResponse},
+    logging::debug_print,
+    messages::{
+        ActorResult, ApiError, AuthConfig, AuthError, AuthResult, CaptureSnapshot, DeleteData,
+        ExchangeAuthCode, ExchangeAuthCodeEndpoint, FetchData, FetchMode, Login, LoginEndpoint,
+        Logout, RevokeAllSessions, StoreData, TokenResponse, UserError, UserId, VerifyToken,
+    },
+    signals::{
+        AuthStateChanged, EmitSignal, LoginRequest, LogoutRequest, StateSnapshotSignal,
+        respond_to_dart, route_dart_signals,
+    },
 };
 
+use super::{
+    ApiClient, Clock, Priority, StorageActor, TraceId, TrustedClock,
+    idempotency::IdempotencyWindow, trace::traced,
+};
+
+// 시작 스냅샷용 마지막 세션 기록
+pub(super) const LAST_SESSION_KEY: &str = "snapshot/auth";
+
 pub struct AuthActor {
     active_sessions: HashMap<String, AuthSession>,
+    token_ttl_secs: u64,
+    clock: TrustedClock,
+    api: Option<ApiClient>, // 없으면 데모 계정으로 기기 안에서 인증
+    storage: Option<Address<StorageActor>>, // 없으면 마지막 세션을 남기지 않는다
+    // 로그인 요청의 멱등 키 → 발급한 토큰 (세션이 메모리에만 있으므로 이 기록도 저장하지 않는다)
+    logins: IdempotencyWindow<LoginReplay>,
     _owned_tasks: JoinSet<()>,
 }
 
@@ -23,23 +46,88 @@ struct AuthSession {
     expires_at: u64,
 }
 
+// 같은 키라도 같은 계정 정보로 온 요청에만 세션을 다시 준다
+struct LoginReplay {
+    credentials: Vec<u8>, // 사용자 이름과 비밀번호의 해시 (비밀번호 자체는 들고 있지 않는다)
+    token: String,
+}
+
+// 토큰은 남기지 않고 누가 언제까지 로그인해 있었는지만 남긴다
+#[derive(Serialize, Deserialize)]
+pub(super) struct LastSession {
+    pub(super) user_id: UserId,
+    expires_at: u64,
+}
+
 impl Actor for AuthActor {}
 
+// 로그인 흐름(ProcessLogin → UserManagerActor → AuthActor)의 한 단계로 기록한다
+traced!(AuthActor, handle: Login);
+
 impl AuthActor {
-    pub fn new(self_addr: Address<Self>) -> Self {
-        let mut owned_tasks = JoinSet::new();
+    pub fn new(self_addr: Address<Self>, config: AuthConfig, clock: TrustedClock) -> Self {
+        let mut owned_tasks = route_dart_signals!(self_addr, [LoginRequest, LogoutRequest]);
         
         // 토큰 만료 체크 작업 시작
-        owned_tasks.spawn(Self::check_token_expiry(self_addr));
+        owned_tasks.spawn(Self::check_token_expiry(
+            self_addr,
+            clock.clock().clone(),
+            config.expiry_check_interval_secs,
+        ));
         
         Self {
             active_sessions: HashMap::new(),
+            token_ttl_secs: config.token_ttl_secs,
+            clock,
+            api: None,
+            storage: None,
+            logins: IdempotencyWindow::default(),
             _owned_tasks: owned_tasks,
         }
     }
     
-    async fn check_token_expiry(mut self_addr: Address<Self>) {
-        let mut interval = tokio::time::interval(std::time::Duration::from_secs(60));
+    pub fn set_api_client(&mut self, api: ApiClient) {
+        self.api = Some(api);
+    }
+    
+    // 설정되어 있으면 마지막 세션을 저장해 다음 시작 때 첫 화면에 쓴다
+    pub fn set_storage(&mut self, storage: Address<StorageActor>) {
+        self.storage = Some(storage);
+    }
+    
+    async fn remember_session(&mut self, result: &AuthResult) {
+        let Some(storage) = self.storage.as_mut() else {
+            return;
+        };
+        let last = LastSession {
+            user_id: result.user_id.clone(),
+            expires_at: result.expires_at,
+        };
+        let Ok(data) = serde_json::to_vec(&last) else {
+            return;
+        };
+        let request = StoreData {
+            key: LAST_SESSION_KEY.to_string(),
+            data,
+            user_id: None,
+            ttl: None,
+        };
+        if !matches!(storage.send(request).await, Ok(Ok(()))) {
+            debug_print!("Failed to remember session for {}", result.user_id);
+        }
+    }
+    
+    async fn forget_session(&mut self) {
+        if let Some(storage) = self.storage.as_mut() {
+            let request = DeleteData {
+                key: LAST_SESSION_KEY.to_string(),
+            };
+            let _ = storage.send(request).await;
+        }
+    }
+    
+    async fn check_token_expiry(mut self_addr: Address<Self>, clock: Clock, interval_secs: u64) {
+        let mut interval = clock.interval(std::time::Duration::from_secs(interval_secs));
         loop {
             interval.tick().await;
             let _ = self_addr.notify(CheckExpiredTokens).await;
@@ -52,7 +140,63 @@ impl AuthActor {
     }
     
     fn get_current_timestamp(&self) -> u64 {
-        chrono::Utc::now().timestamp() as u64
+        // 기기 시계 변경으로 토큰 만료가 어긋나지 않도록 신뢰 시각 사용
+        self.clock.trusted_now()
+    }
+    
+    fn credentials_digest(username: &str, password: &str) -> Vec<u8> {
+        Sha256::new()
+            .chain_update(username.as_bytes())
+            .chain_update([0])
+            .chain_update(password.as_bytes())
+            .finalize()
+            .to_vec()
+    }
+    
+    // 아직 살아 있는 세션이면 로그인 결과로 다시 만든다
+    fn session_result(&self, token: &str) -> Option<AuthResult> {
+        let session = self.active_sessions.get(token)?;
+        (session.expires_at > self.get_current_timestamp()).then(|| AuthResult {
+            user_id: session.user_id.clone(),
+            token: session.token.clone(),
+            expires_at: session.expires_at,
+        })
+    }
+
+    // 세션을 만들고 인증 상태 변경을 Dart에 알림
+    fn start_session(&mut self, user_id: UserId) -> AuthResult {
+        let token = self.generate_token(&user_id);
+        self.open_session(user_id, token, self.token_ttl_secs)
+    }
+    
+    // 인증 서버가 발급한 토큰으로 세션 시작
+    fn start_remote_session(&mut self, response: TokenResponse) -> AuthResult {
+        self.open_session(response.user_id, response.access_token, response.expires_in)
+    }
+    
+    fn open_session(&mut self, user_id: UserId, token: String, ttl_secs: u64) -> AuthResult {
+        let expires_at = self.get_current_timestamp() + ttl_secs;
+        
+        self.active_sessions.insert(
+            token.clone(),
+            AuthSession {
+                user_id: user_id.clone(),
+                token: token.clone(),
+                expires_at,
+            },
+        );
+        
+        AuthStateChanged {
+            is_authenticated: true,
+            user_id: Some(user_id.clone()),
+        }
+        .emit();
+        
+        AuthResult {
+            user_id,
+            token,
+            expires_at,
+        }
     }
 }
 
@@ -79,7 +223,7 @@ impl Notifiable<CheckExpiredTokens> for AuthActor {
                     is_authenticated: false,
                     user_id: Some(session.user_id),
                 }
-                .send_signal_to_dart();
+                .emit();
             }
         }
     }
@@ -87,42 +231,64 @@ impl Notifiable<CheckExpiredTokens> for AuthActor {
 
 #[async_trait]
 impl Handler<Login> for AuthActor {
-    type Result = Result<AuthResult, AuthError>;
+    type Result = ActorResult<AuthResult>;
     
     async fn handle(&mut self, msg: Login, _: &Context<Self>) -> Self::Result {
-        // 실제 구현에서는 데이터베이스 확인 등의 인증 로직 필요
-        if msg.username == "demo" && msg.password == "password" {
-            let user_id = "user_1".to_string();
-            let token = self.generate_token(&user_id);
-            let expires_at = self.get_current_timestamp() + 3600; // 1시간 후 만료
-            
-            let auth_result = AuthResult {
-                user_id: user_id.clone(),
-                token: token.clone(),
-                expires_at,
+        if let Some(api) = &self.api {
+            let endpoint = LoginEndpoint {
+                username: msg.username,
+                password: msg.password,
             };
-            
-            // 세션 저장
-            self.active_sessions.insert(
-                token.clone(),
-                AuthSession {
-                    user_id: user_id.clone(),
-                    token: token.clone(),
-                    expires_at,
-                },
-            );
-            
-            // 인증 상태 변경 알림
-            AuthStateChanged {
-                is_authenticated: true,
-                user_id: Some(user_id),
-            }
-            .send_signal_to_dart();
-            
-            Ok(auth_result)
-        } else {
-            Err("Invalid username or password".into())
+            let response = api
+                .send(Priority::High, &endpoint)
+                .await
+                .map_err(|e| match e {
+                    // 로그인 엔드포인트의 401은 자격 증명이 틀렸다는 뜻
+                    UserError::Api(ApiError::Status { status: 401, .. }) => {
+                        AuthError::InvalidCredentials.into()
+                    }
+                    e => e,
+                })?;
+            let result = self.start_remote_session(response);
+            self.remember_session(&result).await;
+            return Ok(result);
+        }
+        
+        // 서버가 설정되지 않은 개발 빌드용 데모 계정
+        if msg.username != "demo" || msg.password != "password" {
+            return Err(AuthError::InvalidCredentials.into());
         }
+        let result = self.start_session("user_1".to_string());
+        self.remember_session(&result).await;
+        Ok(result)
+    }
+}
+
+#[async_trait]
+impl Handler<ExchangeAuthCode> for AuthActor {
+    type Result = ActorResult<AuthResult>;
+    
+    async fn handle(&mut self, msg: ExchangeAuthCode, _: &Context<Self>) -> Self::Result {
+        if msg.code.trim().is_empty() {
+            return Err(AuthError::MissingAuthCode.into());
+        }
+        
+        if let Some(api) = &self.api {
+            let endpoint = ExchangeAuthCodeEndpoint {
+                code: msg.code,
+                state: msg.state,
+            };
+            let response = api.send(Priority::High, &endpoint).await?;
+            let result = self.start_remote_session(response);
+            self.remember_session(&result).await;
+            return Ok(result);
+        }
+        
+        let user_id = format!("oauth_{}", msg.code.chars().take(8).collect::<String>());
+        debug_print!("Exchanged OAuth code for user: {}", user_id);
+        let result = self.start_session(user_id);
+        self.remember_session(&result).await;
+        Ok(result)
     }
 }
 
@@ -132,20 +298,41 @@ impl Handler<Logout> for AuthActor {
     
     async fn handle(&mut self, msg: Logout, _: &Context<Self>) -> Self::Result {
         if let Some(session) = self.active_sessions.remove(&msg.token) {
+            self.forget_session().await;
+            
             // 인증 상태 변경 알림
             AuthStateChanged {
                 is_authenticated: false,
                 user_id: Some(session.user_id),
             }
-            .send_signal_to_dart();
+            .emit();
             
             Ok(())
         } else {
-            Err("Invalid or expired token".into())
+            Err(AuthError::InvalidToken)
         }
     }
 }
 
+#[async_trait]
+impl Handler<RevokeAllSessions> for AuthActor {
+    type Result = Vec<UserId>;
+    
+    async fn handle(&mut self, _: RevokeAllSessions, _: &Context<Self>) -> Self::Result {
+        let mut user_ids = Vec::new();
+        for (_, session) in self.active_sessions.drain() {
+            AuthStateChanged {
+                is_authenticated: false,
+                user_id: Some(session.user_id.clone()),
+            }
+            .emit();
+            user_ids.push(session.user_id);
+        }
+        self.forget_session().await;
+        user_ids
+    }
+}
+
 #[async_trait]
 impl Handler<VerifyToken> for AuthActor {
     type Result = Result<UserId, AuthError>;
@@ -155,78 +342,254 @@ impl Handler<VerifyToken> for AuthActor {
             if session.expires_at > self.get_current_timestamp() {
                 Ok(session.user_id.clone())
             } else {
-                Err("Token expired".into())
+                Err(AuthError::TokenExpired)
             }
         } else {
-            Err("Invalid token".into())
+            Err(AuthError::InvalidToken)
         }
     }
 }
 
+// 저장된 마지막 세션 (기록이 없으면 로그아웃 상태로 보낸다)
+#[async_trait]
+impl Handler<CaptureSnapshot> for AuthActor {
+    type Result = Option<StateSnapshotSignal>;
+    
+    async fn handle(&mut self, _: CaptureSnapshot, _: &Context<Self>) -> Self::Result {
+        let request = FetchData {
+            key: LAST_SESSION_KEY.to_string(),
+            user_id: None,
+            mode: FetchMode::CacheFirst,
+        };
+        let last = match self.storage.as_mut()?.send(request).await {
+            Ok(Ok(bytes)) => serde_json::from_slice::<LastSession>(&bytes).ok(),
+            _ => None,
+        };
+        Some(StateSnapshotSignal::Auth {
+            user_id: last.as_ref().map(|last| last.user_id.clone()),
+            expires_at: last.map(|last| last.expires_at),
+        })
+    }
+}
+
 // Dart 신호 처리
 #[async_trait]
 impl Notifiable<LoginRequest> for AuthActor {
     async fn notify(&mut self, msg: LoginRequest, ctx: &Context<Self>) {
-        let login_result = self
-            .handle(
-                Login {
-                    username: msg.username,
-                    password: msg.password,
-                },
-                ctx,
+        // 응답에 추적 ID가 실리도록 추적 안에서 응답까지 보낸다
+        let trace = TraceId::current_or_begin();
+        let _ = trace
+            .run(
+                "AuthActor",
+                "LoginRequest",
+                respond_to_dart(msg, |msg| async move {
+                    // 재전송이나 두 번 누르기는 세션을 하나 더 만들지 않고 같은 세션으로 답한다
+                    // 키만 같고 계정 정보가 다르면 새 로그인으로 처리한다
+                    let now = self.get_current_timestamp();
+                    let credentials = Self::credentials_digest(&msg.username, &msg.password);
+                    if let Some(result) = msg
+                        .idempotency_key
+                        .as_deref()
+                        .and_then(|key| self.logins.get(key, now))
+                        .filter(|replay| replay.credentials == credentials)
+                        .and_then(|replay| self.session_result(&replay.token))
+                    {
+                        return Ok(result);
+                    }
+                    let login = Login {
+                        username: msg.username,
+                        password: msg.password,
+                    };
+                    let result = self.handle(login, ctx).await?;
+                    if let Some(key) = msg.idempotency_key {
+                        let replay = LoginReplay {
+                            credentials,
+                            token: result.token.clone(),
+                        };
+                        self.logins.record(key, replay, now);
+                    }
+                    Ok(result)
+                }),
             )
             .await;
-        
-        match login_result {
-            Ok(result) => {
-                LoginResponse {
-                    success: true,
-                    user_id: Some(result.user_id),
-                    token: Some(result.token),
-                    error: None,
-                }
-                .send_signal_to_dart();
-            }
-            Err(e) => {
-                LoginResponse {
-                    success: false,
-                    user_id: None,
-                    token: None,
-                    error: Some(e.to_string()),
-                }
-                .send_signal_to_dart();
-            }
-        }
     }
 }
 
 #[async_trait]
 impl Notifiable<LogoutRequest> for AuthActor {
     async fn notify(&mut self, msg: LogoutRequest, ctx: &Context<Self>) {
-        // 사용자 ID로 토큰 찾기 (실제 구현에서는 더 효율적인 방법 필요)
-        let token = self
-            .active_sessions
-            .iter()
-            .find(|(_, session)| session.user_id == msg.user_id)
-            .map(|(token, _)| token.clone());
-        
-        if let Some(token) = token {
-            let logout_result = self
-                .handle(
-                    Logout {
-                        user_id: msg.user_id,
-                        token,
-                    },
-                    ctx,
-                )
-                .await;
+        let _ = respond_to_dart(msg, |msg| async move {
+            // 사용자 ID로 토큰 찾기 (실제 구현에서는 더 효율적인 방법 필요)
+            let token = self
+                .active_sessions
+                .iter()
+                .find(|(_, session)| session.user_id == msg.user_id)
+                .map(|(token, _)| token.clone())
+                .ok_or_else(|| UserError::NotFound(format!("Session for user {}", msg.user_id)))?;
             
-            LogoutResponse {
-                success: logout_result.is_ok(),
-            }
-            .send_signal_to_dart();
-        } else {
-            LogoutResponse { success: false }.send_signal_to_dart();
-        }
+            let logout = Logout {
+                user_id: msg.user_id,
+                token,
+            };
+            Ok(self.handle(logout, ctx).await?)
+        })
+        .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{error::Error, time::Duration};
+
+    use super::AuthActor;
+    use crate::study_actors::{
+        actors::TrustedClock,
+        messages::{AuthConfig, AuthError},
+        signals::{
+            AuthStateChanged, CapturedSignal, LoginRequest, LoginResponse, LogoutRequest,
+            LogoutResponse,
+        },
+        test_support::{TestActorHarness, demo_login, settle, verify_token},
+    };
+
+    fn start_auth(token_ttl_secs: u64) -> TestActorHarness<AuthActor> {
+        let config = AuthConfig {
+            token_ttl_secs,
+            expiry_check_interval_secs: 10,
+        };
+        TestActorHarness::start(|addr| AuthActor::new(addr, config, TrustedClock::new()))
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn token_is_valid_until_ttl() -> Result<(), Box<dyn Error>> {
+        let mut auth = start_auth(60);
+        let session = auth.send(demo_login()).await??;
+
+        auth.advance(Duration::from_secs(30)).await;
+        let user_id = auth.send(verify_token(&session.token)).await??;
+        assert_eq!(user_id, session.user_id);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn expired_token_is_removed_and_reported() -> Result<(), Box<dyn Error>> {
+        let mut auth = start_auth(60);
+        let session = auth.send(demo_login()).await??;
+        let logged_in = auth.signals_of::<AuthStateChanged>();
+        assert_eq!(logged_in.len(), 1);
+        assert!(logged_in[0].is_authenticated);
+
+        // 만료 후 다음 점검 주기까지 진행
+        auth.advance(Duration::from_secs(71)).await;
+        let logged_out = auth.signals_of::<AuthStateChanged>();
+        assert_eq!(logged_out.len(), 1);
+        assert!(!logged_out[0].is_authenticated);
+        assert_eq!(logged_out[0].user_id.as_deref(), Some(session.user_id.as_str()));
+
+        let result = auth.send(verify_token(&session.token)).await?;
+        assert!(matches!(result, Err(AuthError::InvalidToken)));
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn expired_token_fails_before_cleanup_runs() -> Result<(), Box<dyn Error>> {
+        // 점검 주기(10초)보다 짧은 TTL이면 점검 전에도 검증에서 만료로 걸러야 한다
+        let mut auth = start_auth(3);
+        let session = auth.send(demo_login()).await??;
+
+        auth.advance(Duration::from_secs(5)).await;
+        let result = auth.send(verify_token(&session.token)).await?;
+        assert!(matches!(result, Err(AuthError::TokenExpired)));
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn dart_requests_are_answered_with_their_correlation_id() -> Result<(), Box<dyn Error>> {
+        let mut auth = start_auth(60);
+        auth.notify(LoginRequest {
+            correlation_id: 7,
+            username: "demo".to_string(),
+            password: "wrong".to_string(),
+            idempotency_key: None,
+        })
+        .await?;
+        settle().await;
+
+        let logins = auth.signals_of::<LoginResponse>();
+        assert_eq!(logins.len(), 1);
+        assert_eq!(logins[0].correlation_id, 7);
+        assert!(!logins[0].success);
+        assert_ne!(logins[0].trace_id, 0);
+        let error = logins[0].error.as_ref().map(|error| error.code.as_str());
+        assert_eq!(error, Some("invalid_credentials"));
+
+        auth.notify(LogoutRequest {
+            correlation_id: 8,
+            user_id: "nobody".to_string(),
+        })
+        .await?;
+        settle().await;
+        let logouts = auth.signals_of::<LogoutResponse>();
+        assert_eq!(logouts.len(), 1);
+        assert_eq!(logouts[0].correlation_id, 8);
+        let error = logouts[0].error.as_ref().map(|error| error.code.as_str());
+        assert_eq!(error, Some("not_found"));
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn repeated_login_key_needs_the_same_credentials() -> Result<(), Box<dyn Error>> {
+        let mut auth = start_auth(60);
+        let login = |correlation_id, password: &str| LoginRequest {
+            correlation_id,
+            username: "demo".to_string(),
+            password: password.to_string(),
+            idempotency_key: Some("login-1".to_string()),
+        };
+        auth.notify(login(1, "password")).await?;
+        settle().await;
+        auth.notify(login(2, "password")).await?;
+        settle().await;
+        auth.notify(login(3, "wrong")).await?;
+        settle().await;
+
+        let signals = auth.drain_signals();
+        let logins: Vec<LoginResponse> =
+            signals.iter().filter_map(CapturedSignal::decode).collect();
+        assert_eq!(logins.len(), 3);
+        assert!(logins[0].success && logins[1].success);
+        assert_eq!(logins[0].token, logins[1].token);
+        let changes: Vec<AuthStateChanged> =
+            signals.iter().filter_map(CapturedSignal::decode).collect();
+        assert_eq!(changes.len(), 1);
+        // 같은 키를 다시 써도 비밀번호가 틀리면 이미 만든 세션을 받지 못한다
+        assert!(!logins[2].success);
+        assert_eq!(logins[2].token, None);
+        let error = logins[2].error.as_ref().map(|error| error.code.as_str());
+        assert_eq!(error, Some("invalid_credentials"));
+        Ok(())
+    }
+
+    #[cfg(feature = "simulation")]
+    #[tokio::test]
+    async fn day_long_session_expires_in_virtual_time() -> Result<(), Box<dyn Error>> {
+        use crate::study_actors::actors::VirtualClock;
+
+        let simulation = VirtualClock::new();
+        let config = AuthConfig {
+            token_ttl_secs: 24 * 60 * 60,
+            expiry_check_interval_secs: 60,
+        };
+        let clock = TrustedClock::with_clock(simulation.clock());
+        let mut auth = TestActorHarness::start(|addr| AuthActor::new(addr, config, clock));
+        let session = auth.send(demo_login()).await??;
+
+        simulation.advance(Duration::from_secs(23 * 60 * 60)).await;
+        auth.send(verify_token(&session.token)).await??;
+
+        simulation.advance(Duration::from_secs(2 * 60 * 60)).await;
+        let result = auth.send(verify_token(&session.token)).await?;
+        assert!(matches!(result, Err(AuthError::InvalidToken)));
+        Ok(())
     }
 }
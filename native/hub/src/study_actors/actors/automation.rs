@@ -0,0 +1,389 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use tokio::{sync::broadcast, task::JoinSet};
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        AppEvent, AutomationError, AutomationRule, DataItem, FetchData, FetchMode, RegisterRule,
+        RemoveRule, RuleAction, RuleCondition, RuleTrigger, ScheduleLocalNotification, StoreData,
+        WipeUserData,
+    },
+    signals::{
+        EmitSignal, FetchUserDataRequest, ListRulesRequest, RegisterRuleRequest, RemoveRuleRequest,
+        RuleExecutedSignal, RuleSummary, RulesSignal, route_dart_signals,
+    },
+};
+
+use super::{DataManagerActor, NotificationActor, StorageActor, TrustedClock};
+
+const RULES_KEY: &str = "automation/rules";
+const MAX_RULES: usize = 100;
+const MAX_CONDITION_DEPTH: usize = 8;
+
+// 이벤트 하나에 대해 규칙을 평가할 때 필요한 정보
+struct EventContext<'a> {
+    trigger: RuleTrigger,
+    user_id: Option<&'a str>,
+    item: Option<&'a DataItem>,
+    tags: &'a [String],
+}
+
+impl<'a> EventContext<'a> {
+    // 규칙 트리거가 아닌 이벤트면 None
+    fn from_event(event: &'a AppEvent) -> Option<Self> {
+        let (trigger, user_id, item, tags) = match event {
+            AppEvent::DataItemCreated {
+                user_id,
+                item,
+                tags,
+            } => (
+                RuleTrigger::DataItemCreated,
+                Some(user_id),
+                Some(item),
+                tags.as_slice(),
+            ),
+            AppEvent::DataItemUpdated {
+                user_id,
+                item,
+                tags,
+            } => (
+                RuleTrigger::DataItemUpdated,
+                Some(user_id),
+                Some(item),
+                tags.as_slice(),
+            ),
+            AppEvent::DataItemDeleted { user_id, .. } => {
+                (RuleTrigger::DataItemDeleted, Some(user_id), None, &[][..])
+            }
+            AppEvent::ConnectivityChanged { online: true, .. } => {
+                (RuleTrigger::ConnectivityRestored, None, None, &[][..])
+            }
+            AppEvent::ConnectivityChanged { online: false, .. } => {
+                (RuleTrigger::ConnectivityLost, None, None, &[][..])
+            }
+            AppEvent::DataItemsSynced { .. }
+            | AppEvent::SystemNotice { .. }
+            | AppEvent::AdminCommandReceived(_)
+            | AppEvent::EntitlementsChanged { .. }
+            | AppEvent::RemoteWipeRequested { .. }
+            | AppEvent::ConfigChanged { .. } => return None,
+        };
+        Some(Self {
+            trigger,
+            user_id: user_id.map(String::as_str),
+            item,
+            tags,
+        })
+    }
+
+    fn matches(&self, condition: &RuleCondition) -> bool {
+        match condition {
+            RuleCondition::Always => true,
+            RuleCondition::HasTag { tag } => self.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)),
+            RuleCondition::TitleContains { text } => self
+                .item
+                .is_some_and(|item| item.title.to_lowercase().contains(&text.to_lowercase())),
+            RuleCondition::All { conditions } => conditions.iter().all(|c| self.matches(c)),
+            RuleCondition::Any { conditions } => conditions.iter().any(|c| self.matches(c)),
+            RuleCondition::Not { condition } => !self.matches(condition),
+        }
+    }
+
+    fn render(&self, template: &str) -> String {
+        match self.item {
+            Some(item) => template
+                .replace("{title}", &item.title)
+                .replace("{item_id}", &item.id),
+            None => template.to_string(),
+        }
+    }
+}
+
+fn condition_depth(condition: &RuleCondition) -> usize {
+    match condition {
+        RuleCondition::All { conditions } | RuleCondition::Any { conditions } => {
+            1 + conditions.iter().map(condition_depth).max().unwrap_or(0)
+        }
+        RuleCondition::Not { condition } => 1 + condition_depth(condition),
+        _ => 1,
+    }
+}
+
+fn validate_rule(rule: &AutomationRule) -> Result<(), AutomationError> {
+    if rule.id.trim().is_empty() {
+        return Err("Rule id is required".into());
+    }
+    if rule.actions.is_empty() {
+        return Err("Rule has no actions".into());
+    }
+    if condition_depth(&rule.condition) > MAX_CONDITION_DEPTH {
+        return Err("Rule condition is nested too deeply".into());
+    }
+    Ok(())
+}
+
+// 자동화 액터
+// Dart가 등록한 규칙을 저장소에 보관하고, 이벤트 버스의 이벤트마다 조건을 평가해 동작을 실행한다.
+pub struct AutomationActor {
+    rules: Vec<AutomationRule>,
+    storage: Address<StorageActor>,
+    notification: Address<NotificationActor>,
+    data: Address<DataManagerActor>,
+    clock: TrustedClock,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for AutomationActor {}
+
+impl AutomationActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        storage: Address<StorageActor>,
+        notification: Address<NotificationActor>,
+        data: Address<DataManagerActor>,
+        events: broadcast::Receiver<AppEvent>,
+        clock: TrustedClock,
+    ) -> Self {
+        let mut owned_tasks = route_dart_signals!(
+            self_addr,
+            [RegisterRuleRequest, RemoveRuleRequest, ListRulesRequest]
+        );
+        owned_tasks.spawn(Self::load_rules(self_addr.clone()));
+        owned_tasks.spawn(Self::listen_to_events(self_addr.clone(), events));
+
+        Self {
+            rules: Vec::new(),
+            storage,
+            notification,
+            data,
+            clock,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    async fn load_rules(mut self_addr: Address<Self>) {
+        let _ = self_addr.notify(LoadRules).await;
+    }
+
+    async fn listen_to_events(
+        mut self_addr: Address<Self>,
+        mut events: broadcast::Receiver<AppEvent>,
+    ) {
+        loop {
+            match events.recv().await {
+                Ok(event) => {
+                    let _ = self_addr.notify(AppEventReceived(event)).await;
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    debug_print!("Automation skipped {} app events", skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    }
+
+    async fn persist_rules(&mut self) -> Result<(), AutomationError> {
+        let request = StoreData {
+            key: RULES_KEY.to_string(),
+            data: serde_json::to_vec(&self.rules)?,
+            user_id: None,
+            ttl: None,
+        };
+        self.storage.send(request).await??;
+        Ok(())
+    }
+
+    fn send_rules(&self, error: Option<String>) {
+        let rules = self
+            .rules
+            .iter()
+            .map(|rule| RuleSummary {
+                id: rule.id.clone(),
+                name: rule.name.clone(),
+                enabled: rule.enabled,
+                rule_json: serde_json::to_string(rule).unwrap_or_default(),
+            })
+            .collect();
+        RulesSignal { rules, error }.emit();
+    }
+
+    async fn run_action(
+        &mut self,
+        rule_id: &str,
+        action: &RuleAction,
+        context: &EventContext<'_>,
+    ) -> Result<(), AutomationError> {
+        match action {
+            RuleAction::ScheduleNotification {
+                title,
+                body,
+                delay_secs,
+            } => {
+                let now = self.clock.trusted_now();
+                let target = context.item.map_or("event", |item| item.id.as_str());
+                self.notification
+                    .send(ScheduleLocalNotification {
+                        id: format!("rule-{}-{}-{}", rule_id, target, now),
+                        title: context.render(title),
+                        body: context.render(body),
+                        fire_at: now + delay_secs,
+                    })
+                    .await?;
+            }
+            RuleAction::TriggerSync { user_id } => {
+                // 푸시 "sync" 페이로드와 같은 방식으로 데이터 관리자에 재조회를 요청
+                let user_id = user_id
+                    .as_deref()
+                    .or(context.user_id)
+                    .ok_or("TriggerSync needs a user id")?;
+                self.data
+                    .notify(FetchUserDataRequest {
+                        user_id: user_id.to_string(),
+                        limit: None,
+                    })
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// 내부 메시지 정의
+struct LoadRules;
+struct AppEventReceived(AppEvent);
+
+#[async_trait]
+impl Notifiable<LoadRules> for AutomationActor {
+    async fn notify(&mut self, _: LoadRules, _: &Context<Self>) {
+        let request = FetchData {
+            key: RULES_KEY.to_string(),
+            user_id: None,
+            mode: FetchMode::CacheFirst,
+        };
+        if let Ok(Ok(bytes)) = self.storage.send(request).await {
+            match serde_json::from_slice(&bytes) {
+                Ok(rules) => self.rules = rules,
+                Err(e) => debug_print!("Invalid stored automation rules: {}", e),
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Notifiable<AppEventReceived> for AutomationActor {
+    async fn notify(&mut self, msg: AppEventReceived, _: &Context<Self>) {
+        let Some(context) = EventContext::from_event(&msg.0) else {
+            return;
+        };
+        let matched: Vec<AutomationRule> = self
+            .rules
+            .iter()
+            .filter(|rule| rule.enabled && rule.trigger == context.trigger)
+            .filter(|rule| context.matches(&rule.condition))
+            .cloned()
+            .collect();
+
+        for rule in matched {
+            let mut actions_run = 0;
+            let mut errors = Vec::new();
+            for action in &rule.actions {
+                match self.run_action(&rule.id, action, &context).await {
+                    Ok(()) => actions_run += 1,
+                    Err(e) => errors.push(e.to_string()),
+                }
+            }
+            debug_print!("Automation rule {} ran {} actions", rule.id, actions_run);
+
+            RuleExecutedSignal {
+                rule_id: rule.id,
+                actions_run,
+                errors,
+            }
+            .emit();
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<RegisterRule> for AutomationActor {
+    type Result = Result<(), AutomationError>;
+
+    async fn handle(&mut self, msg: RegisterRule, _: &Context<Self>) -> Self::Result {
+        let rule = msg.0;
+        validate_rule(&rule)?;
+
+        // 같은 id면 교체
+        if let Some(existing) = self.rules.iter_mut().find(|r| r.id == rule.id) {
+            *existing = rule;
+        } else if self.rules.len() >= MAX_RULES {
+            return Err(format!("Too many rules (max {})", MAX_RULES).into());
+        } else {
+            self.rules.push(rule);
+        }
+        self.persist_rules().await
+    }
+}
+
+#[async_trait]
+impl Handler<RemoveRule> for AutomationActor {
+    type Result = Result<bool, AutomationError>;
+
+    async fn handle(&mut self, msg: RemoveRule, _: &Context<Self>) -> Self::Result {
+        let before = self.rules.len();
+        self.rules.retain(|rule| rule.id != msg.rule_id);
+        if self.rules.len() == before {
+            return Ok(false);
+        }
+        self.persist_rules().await?;
+        Ok(true)
+    }
+}
+
+#[async_trait]
+impl Handler<WipeUserData> for AutomationActor {
+    type Result = Result<(), AutomationError>;
+
+    async fn handle(&mut self, _: WipeUserData, _: &Context<Self>) -> Self::Result {
+        // 저장된 규칙은 저장소와 함께 지워지므로 메모리의 규칙만 비워 더 실행되지 않게 한다
+        self.rules.clear();
+        Ok(())
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<RegisterRuleRequest> for AutomationActor {
+    async fn notify(&mut self, msg: RegisterRuleRequest, ctx: &Context<Self>) {
+        let result = match serde_json::from_str::<AutomationRule>(&msg.rule_json) {
+            Ok(rule) => self.handle(RegisterRule(rule), ctx).await,
+            Err(e) => Err(e.into()),
+        };
+        self.send_rules(result.err().map(|e| e.to_string()));
+    }
+}
+
+#[async_trait]
+impl Notifiable<RemoveRuleRequest> for AutomationActor {
+    async fn notify(&mut self, msg: RemoveRuleRequest, ctx: &Context<Self>) {
+        let result = self
+            .handle(
+                RemoveRule {
+                    rule_id: msg.rule_id,
+                },
+                ctx,
+            )
+            .await;
+        self.send_rules(result.err().map(|e| e.to_string()));
+    }
+}
+
+#[async_trait]
+impl Notifiable<ListRulesRequest> for AutomationActor {
+    async fn notify(&mut self, _: ListRulesRequest, _: &Context<Self>) {
+        self.send_rules(None);
+    }
+}
@@ -0,0 +1,137 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Notifiable},
+};
+use std::collections::HashSet;
+use tokio::task::JoinSet;
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{AppLifecycleState, BackgroundSubsystem, SetSubsystemSuspended, SuspendReason},
+    signals::{
+        AppLifecycleChanged, BackgroundModeSignal, EmitSignal, SetBackgroundPolicyRequest,
+        route_dart_signals,
+    },
+};
+
+use super::{DataManagerActor, NotificationActor, PresenceActor, WebSocketActor};
+
+// 데스크톱 백그라운드 모드 액터
+// Flutter 창이 닫히거나 숨겨져도 Rust 쪽은 트레이 등에서 계속 돌 수 있으므로,
+// 정책에 남긴 하위 시스템만 계속 돌리고 나머지는 포그라운드로 돌아올 때까지 멈춘다.
+// 수명 주기 신호는 여기서 받아 프레즌스 액터에도 전달한다.
+pub struct BackgroundModeActor {
+    keep_alive: HashSet<BackgroundSubsystem>,
+    in_background: bool,
+    suspended: HashSet<BackgroundSubsystem>,
+    data: Address<DataManagerActor>,
+    websocket: Address<WebSocketActor>,
+    notification: Address<NotificationActor>,
+    presence: Address<PresenceActor>,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for BackgroundModeActor {}
+
+impl BackgroundModeActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        data: Address<DataManagerActor>,
+        websocket: Address<WebSocketActor>,
+        notification: Address<NotificationActor>,
+        presence: Address<PresenceActor>,
+    ) -> Self {
+        let owned_tasks =
+            route_dart_signals!(self_addr, [AppLifecycleChanged, SetBackgroundPolicyRequest]);
+
+        Self {
+            // 정책을 받기 전에는 지금처럼 모두 계속 돌린다
+            keep_alive: BackgroundSubsystem::ALL.into_iter().collect(),
+            in_background: false,
+            suspended: HashSet::new(),
+            data,
+            websocket,
+            notification,
+            presence,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    // 현재 상태와 정책에 맞게 하위 시스템을 멈추거나 재개한다 (바뀐 것만 알린다)
+    async fn apply(&mut self) {
+        for subsystem in BackgroundSubsystem::ALL {
+            let suspend = self.in_background && !self.keep_alive.contains(&subsystem);
+            if suspend == self.suspended.contains(&subsystem) {
+                continue;
+            }
+
+            let msg = SetSubsystemSuspended {
+                suspended: suspend,
+                reason: SuspendReason::Background,
+            };
+            let sent = match subsystem {
+                BackgroundSubsystem::Sync => self.data.notify(msg).await,
+                BackgroundSubsystem::Realtime => self.websocket.notify(msg).await,
+                BackgroundSubsystem::Notifications => self.notification.notify(msg).await,
+            };
+            if let Err(e) = sent {
+                debug_print!(
+                    "Failed to update {:?} for background mode: {}",
+                    subsystem,
+                    e
+                );
+                continue;
+            }
+            if suspend {
+                self.suspended.insert(subsystem);
+            } else {
+                self.suspended.remove(&subsystem);
+            }
+        }
+
+        BackgroundModeSignal {
+            in_background: self.in_background,
+            keep_alive: Self::sorted(&self.keep_alive),
+            suspended: Self::sorted(&self.suspended),
+        }
+        .emit();
+    }
+
+    fn sorted(subsystems: &HashSet<BackgroundSubsystem>) -> Vec<BackgroundSubsystem> {
+        BackgroundSubsystem::ALL
+            .into_iter()
+            .filter(|subsystem| subsystems.contains(subsystem))
+            .collect()
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<AppLifecycleChanged> for BackgroundModeActor {
+    async fn notify(&mut self, msg: AppLifecycleChanged, _: &Context<Self>) {
+        let state = msg.state;
+        let _ = self.presence.notify(AppLifecycleChanged { state }).await;
+
+        // 데스크톱에서 Detached는 앱 종료가 아니라 창(엔진)이 떨어져 나간 상태일 수 있다
+        let in_background = match state {
+            AppLifecycleState::Resumed | AppLifecycleState::Inactive => false,
+            AppLifecycleState::Hidden | AppLifecycleState::Paused | AppLifecycleState::Detached => {
+                true
+            }
+        };
+        if in_background != self.in_background {
+            debug_print!("Background mode: {}", in_background);
+            self.in_background = in_background;
+            self.apply().await;
+        }
+    }
+}
+
+#[async_trait]
+impl Notifiable<SetBackgroundPolicyRequest> for BackgroundModeActor {
+    async fn notify(&mut self, msg: SetBackgroundPolicyRequest, _: &Context<Self>) {
+        self.keep_alive = msg.keep_alive.into_iter().collect();
+        self.apply().await;
+    }
+}
@@ -0,0 +1,459 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, time::Duration};
+use tokio::task::JoinSet;
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        AppEvent, BillingConfig, BillingError, Entitlement, FetchData, FetchMode, GetEntitlements,
+        GetEntitlementsEndpoint, ScanPrefix, ScheduledTask, StoreData, UserId, ValidateReceipt,
+        ValidateReceiptEndpoint, WipeUserData,
+    },
+    signals::{
+        EmitSignal, EntitlementsChangedSignal, EntitlementsRequest, ReceiptRejectedSignal,
+        ValidateReceiptRequest, route_dart_signals,
+    },
+};
+
+use super::{ApiClient, Clock, EventBus, Priority, StorageActor, TrustedClock};
+
+const ENTITLEMENT_KEY_PREFIX: &str = "billing/";
+// 만료와 유예 기간 끝을 이 간격으로 확인한다 (서버 재확인은 billing.revalidate_interval_secs마다)
+const CHECK_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+// 사용자별로 저장하는 권한 캐시
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct CachedEntitlements {
+    entitlements: Vec<Entitlement>, // 서버가 마지막으로 돌려준 그대로 (만료된 것 포함)
+    validated_at: u64,              // 마지막으로 서버에 확인한 시각
+    last_seen_at: u64, // 지금까지 본 가장 늦은 시각 (시계를 되돌려도 이보다 앞으로 가지 않는다)
+}
+
+// 결제 액터
+// Dart가 넘긴 스토어 영수증을 서버로 확인하고, 서버가 돌려준 권한을 사용자별로 저장한다.
+// 저장된 권한은 오프라인에서도 유예 기간 동안 쓰고, 주기적으로 서버에 조용히 다시 확인한다.
+// 쓸 수 있는 권한이 바뀌면 Dart와 이벤트 버스(한도 액터, 기능 플래그)에 알린다.
+pub struct BillingActor {
+    users: HashMap<UserId, CachedEntitlements>, // 불러온 사용자만
+    published: HashMap<UserId, Vec<Entitlement>>, // 마지막으로 알린 쓸 수 있는 권한
+    config: BillingConfig,
+    api: Option<ApiClient>, // 없으면 영수증을 확인할 수 없다
+    storage: Address<StorageActor>,
+    event_bus: EventBus,
+    clock: TrustedClock,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for BillingActor {}
+
+impl BillingActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        config: BillingConfig,
+        storage: Address<StorageActor>,
+        event_bus: EventBus,
+        clock: TrustedClock,
+    ) -> Self {
+        let mut owned_tasks =
+            route_dart_signals!(self_addr, [ValidateReceiptRequest, EntitlementsRequest]);
+        owned_tasks.spawn(Self::load_entitlements(self_addr.clone()));
+        owned_tasks.spawn(Self::periodic_check(self_addr, clock.clock().clone()));
+
+        Self {
+            users: HashMap::new(),
+            published: HashMap::new(),
+            config,
+            api: None,
+            storage,
+            event_bus,
+            clock,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    // 결제 서비스 클라이언트 설정
+    pub fn set_api_client(&mut self, api: ApiClient) {
+        self.api = Some(api);
+    }
+
+    async fn load_entitlements(mut self_addr: Address<Self>) {
+        let _ = self_addr.notify(LoadEntitlements).await;
+    }
+
+    async fn periodic_check(mut self_addr: Address<Self>, clock: Clock) {
+        let mut interval = clock
+            .interval(CHECK_INTERVAL)
+            .throttled(ScheduledTask::EntitlementCheck);
+        loop {
+            interval.tick().await;
+            let _ = self_addr.notify(CheckEntitlements).await;
+        }
+    }
+
+    fn entitlement_key(user_id: &str) -> String {
+        format!("{}{}", ENTITLEMENT_KEY_PREFIX, user_id)
+    }
+
+    async fn load_user(&mut self, user_id: &str) {
+        if self.users.contains_key(user_id) {
+            return;
+        }
+        let request = FetchData {
+            key: Self::entitlement_key(user_id),
+            user_id: Some(user_id.to_string()),
+            mode: FetchMode::CacheFirst,
+        };
+        let stored = match self.storage.send(request).await {
+            Ok(Ok(bytes)) => serde_json::from_slice(&bytes).unwrap_or_else(|e| {
+                debug_print!("Invalid stored entitlements for {}: {}", user_id, e);
+                CachedEntitlements::default()
+            }),
+            Ok(Err(_)) => CachedEntitlements::default(),
+            Err(e) => {
+                debug_print!("Failed to load entitlements for {}: {}", user_id, e);
+                CachedEntitlements::default()
+            }
+        };
+        self.users.insert(user_id.to_string(), stored);
+    }
+
+    // NTP로 맞춘 시각과 기기 시계가 크게 다르거나, 시계가 이미 본 시각보다 뒤로 갔으면 조작으로 본다
+    fn clock_tampered(&self, cache: &CachedEntitlements, now: u64) -> bool {
+        let max_skew_ms = self.config.max_clock_skew_secs as i64 * 1000;
+        let skewed = self.clock.is_synced() && self.clock.offset_millis().abs() > max_skew_ms;
+        let rewound = now + self.config.max_clock_skew_secs < cache.last_seen_at;
+        skewed || rewound
+    }
+
+    // 서버가 돌려준 권한으로 캐시를 바꾼다
+    fn store_validated(&mut self, user_id: &str, entitlements: Vec<Entitlement>) {
+        let now = self.clock.trusted_now();
+        let cache = self.users.entry(user_id.to_string()).or_default();
+        cache.entitlements = entitlements;
+        cache.validated_at = now.max(cache.last_seen_at);
+    }
+
+    // 지금 쓸 수 있는 권한을 다시 계산하고, 지난번 알린 것과 달라졌는지 돌려준다
+    fn reevaluate(&mut self, user_id: &str) -> (Vec<Entitlement>, bool) {
+        let now = self.clock.trusted_now();
+        let Some(cache) = self.users.get(user_id) else {
+            return (Vec::new(), false);
+        };
+        let tampered = self.clock_tampered(cache, now);
+        if tampered {
+            debug_print!("Clock tampering suspected, entitlement grace periods are off");
+        }
+        let usable = usable_entitlements(cache, now, tampered, &self.config);
+        if let Some(cache) = self.users.get_mut(user_id) {
+            cache.last_seen_at = cache.last_seen_at.max(now);
+        }
+
+        let changed = self
+            .published
+            .get(user_id)
+            .map_or(!usable.is_empty(), |published| *published != usable);
+        if changed {
+            self.published.insert(user_id.to_string(), usable.clone());
+        }
+        (usable, changed)
+    }
+
+    // 영수증 없이 서버에 남은 권한을 받아 온다 (실패하면 저장된 권한을 그대로 쓴다)
+    async fn revalidate(&mut self, user_id: &str) -> Result<(), BillingError> {
+        let Some(api) = &self.api else {
+            return Err("Receipt validation is not configured".into());
+        };
+        let endpoint = GetEntitlementsEndpoint {
+            user_id: user_id.to_string(),
+        };
+        let entitlements = api.send(Priority::Low, &endpoint).await?;
+        self.store_validated(user_id, entitlements);
+        Ok(())
+    }
+
+    async fn persist(&mut self, user_id: &str) -> Result<(), BillingError> {
+        let Some(cache) = self.users.get(user_id) else {
+            return Ok(());
+        };
+        let request = StoreData {
+            key: Self::entitlement_key(user_id),
+            data: serde_json::to_vec(cache)?,
+            user_id: Some(user_id.to_string()),
+            ttl: None,
+        };
+        self.storage.send(request).await??;
+        Ok(())
+    }
+
+    // 이벤트 버스에만 알린다 (Dart 요청에 대한 응답 신호는 신호 처리에서 보낸다)
+    fn publish(&self, user_id: &str, entitlements: Vec<Entitlement>) {
+        self.event_bus.publish(AppEvent::EntitlementsChanged {
+            user_id: user_id.to_string(),
+            entitlements,
+        });
+    }
+
+    fn announce(&self, user_id: &str, entitlements: Vec<Entitlement>) {
+        self.publish(user_id, entitlements.clone());
+        EntitlementsChangedSignal {
+            user_id: user_id.to_string(),
+            entitlements,
+        }
+        .emit();
+    }
+}
+
+// 캐시와 시각으로 지금 쓸 수 있는 권한을 고른다
+// 마지막 확인 후 오프라인 유예 기간이 지났으면 기간이 있는 권한은 모두 쓰지 않는다.
+// 시계 조작이 의심되면 오프라인 유예는 재확인 간격으로 줄이고, 만료 뒤 유예는 주지 않는다.
+fn usable_entitlements(
+    cache: &CachedEntitlements,
+    now: u64,
+    tampered: bool,
+    config: &BillingConfig,
+) -> Vec<Entitlement> {
+    let now = now.max(cache.last_seen_at);
+    let offline_limit = if tampered {
+        config.revalidate_interval_secs
+    } else {
+        config.offline_grace_secs
+    };
+    let fresh = now.saturating_sub(cache.validated_at) <= offline_limit;
+
+    cache
+        .entitlements
+        .iter()
+        .filter(|e| match e.expires_at {
+            None => true, // 평생 구매는 시각과 관계없다
+            Some(_) if !fresh => false,
+            Some(expires_at) if now < expires_at => true,
+            // 만료 뒤 아직 서버에 갱신을 확인하지 못했으면 잠시 더 쓴다
+            Some(expires_at) => {
+                !tampered
+                    && cache.validated_at < expires_at
+                    && now < expires_at.saturating_add(config.expiry_grace_secs)
+            }
+        })
+        .cloned()
+        .collect()
+}
+
+// 내부 메시지 정의
+struct LoadEntitlements;
+struct CheckEntitlements;
+
+// 저장된 권한으로 시작해 오프라인에서도 구독 기능이 켜지도록 한다
+#[async_trait]
+impl Notifiable<LoadEntitlements> for BillingActor {
+    async fn notify(&mut self, _: LoadEntitlements, _: &Context<Self>) {
+        let scan = ScanPrefix {
+            prefix: ENTITLEMENT_KEY_PREFIX.to_string(),
+        };
+        let entries = match self.storage.send(scan).await {
+            Ok(Ok(entries)) => entries,
+            Ok(Err(e)) => {
+                debug_print!("Failed to load entitlements: {}", e);
+                return;
+            }
+            Err(e) => {
+                debug_print!("Failed to load entitlements: {}", e);
+                return;
+            }
+        };
+
+        for (key, bytes) in entries {
+            let user_id = key.trim_start_matches(ENTITLEMENT_KEY_PREFIX).to_string();
+            // 불러오기 전에 확인한 영수증이 우선
+            if self.users.contains_key(&user_id) {
+                continue;
+            }
+            match serde_json::from_slice::<CachedEntitlements>(&bytes) {
+                Ok(cache) => {
+                    self.users.insert(user_id.clone(), cache);
+                    let (usable, changed) = self.reevaluate(&user_id);
+                    if changed {
+                        self.announce(&user_id, usable);
+                    }
+                }
+                Err(e) => debug_print!("Skipping stored entitlements for {}: {}", user_id, e),
+            }
+        }
+    }
+}
+
+// 재확인할 때가 된 사용자는 서버에 다시 묻고, 모두 만료와 유예 기간을 다시 따진다
+#[async_trait]
+impl Notifiable<CheckEntitlements> for BillingActor {
+    async fn notify(&mut self, _: CheckEntitlements, _: &Context<Self>) {
+        let now = self.clock.trusted_now();
+        let user_ids: Vec<UserId> = self.users.keys().cloned().collect();
+        for user_id in user_ids {
+            let due = self.users.get(&user_id).is_some_and(|cache| {
+                now.saturating_sub(cache.validated_at) >= self.config.revalidate_interval_secs
+            });
+            if due
+                && self.api.is_some()
+                && let Err(e) = self.revalidate(&user_id).await
+            {
+                debug_print!("Failed to revalidate entitlements for {}: {}", user_id, e);
+            }
+
+            let (usable, changed) = self.reevaluate(&user_id);
+            if changed {
+                self.announce(&user_id, usable);
+            }
+            // 본 시각을 남겨 재시작 뒤에도 시계를 되돌린 것을 알아챈다
+            if let Err(e) = self.persist(&user_id).await {
+                debug_print!("Failed to save entitlements for {}: {}", user_id, e);
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<ValidateReceipt> for BillingActor {
+    type Result = Result<Vec<Entitlement>, BillingError>;
+
+    async fn handle(&mut self, msg: ValidateReceipt, _: &Context<Self>) -> Self::Result {
+        let Some(api) = &self.api else {
+            return Err("Receipt validation is not configured".into());
+        };
+        let endpoint = ValidateReceiptEndpoint {
+            user_id: msg.user_id.clone(),
+            receipt: msg.receipt,
+        };
+        let entitlements = api.send(Priority::High, &endpoint).await?;
+
+        self.load_user(&msg.user_id).await;
+        self.store_validated(&msg.user_id, entitlements);
+        self.persist(&msg.user_id).await?;
+        let (usable, changed) = self.reevaluate(&msg.user_id);
+        if changed {
+            self.publish(&msg.user_id, usable.clone());
+        }
+        Ok(usable)
+    }
+}
+
+#[async_trait]
+impl Handler<GetEntitlements> for BillingActor {
+    type Result = Vec<Entitlement>;
+
+    async fn handle(&mut self, msg: GetEntitlements, _: &Context<Self>) -> Self::Result {
+        self.load_user(&msg.user_id).await;
+        let (usable, changed) = self.reevaluate(&msg.user_id);
+        if changed {
+            self.publish(&msg.user_id, usable.clone());
+        }
+        usable
+    }
+}
+
+// 저장소는 감독자가 지우므로 메모리의 권한만 비우고, 구독 기능을 끄도록 알린다
+#[async_trait]
+impl Handler<WipeUserData> for BillingActor {
+    type Result = Result<(), BillingError>;
+
+    async fn handle(&mut self, _: WipeUserData, _: &Context<Self>) -> Self::Result {
+        self.users.clear();
+        for user_id in std::mem::take(&mut self.published).into_keys() {
+            self.announce(&user_id, Vec::new());
+        }
+        Ok(())
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<ValidateReceiptRequest> for BillingActor {
+    async fn notify(&mut self, msg: ValidateReceiptRequest, ctx: &Context<Self>) {
+        let product_id = msg.receipt.product_id.clone();
+        let request = ValidateReceipt {
+            user_id: msg.user_id.clone(),
+            receipt: msg.receipt,
+        };
+        match self.handle(request, ctx).await {
+            // 바뀌지 않았어도 Dart는 구매 결과를 기다리므로 다시 보낸다
+            Ok(entitlements) => EntitlementsChangedSignal {
+                user_id: msg.user_id,
+                entitlements,
+            }
+            .emit(),
+            Err(e) => {
+                debug_print!("Receipt for {} was rejected: {}", product_id, e);
+                ReceiptRejectedSignal {
+                    user_id: msg.user_id,
+                    product_id,
+                    error: e.to_string(),
+                }
+                .emit();
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Notifiable<EntitlementsRequest> for BillingActor {
+    async fn notify(&mut self, msg: EntitlementsRequest, ctx: &Context<Self>) {
+        let request = GetEntitlements {
+            user_id: msg.user_id.clone(),
+        };
+        EntitlementsChangedSignal {
+            user_id: msg.user_id,
+            entitlements: self.handle(request, ctx).await,
+        }
+        .emit();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: u64 = 24 * 60 * 60;
+
+    fn premium(expires_at: Option<u64>) -> Entitlement {
+        Entitlement {
+            id: "premium".to_string(),
+            product_id: "premium_monthly".to_string(),
+            features: vec!["export".to_string()],
+            expires_at,
+        }
+    }
+
+    fn cache(validated_at: u64, last_seen_at: u64) -> CachedEntitlements {
+        CachedEntitlements {
+            entitlements: vec![premium(Some(30 * DAY)), premium(None)],
+            validated_at,
+            last_seen_at,
+        }
+    }
+
+    #[test]
+    fn cached_entitlements_lapse_after_grace_periods() {
+        let config = BillingConfig::default();
+        let count = |cache: &CachedEntitlements, now: u64, tampered: bool| {
+            usable_entitlements(cache, now, tampered, &config).len()
+        };
+
+        // 오프라인 유예 안
+        assert_eq!(count(&cache(10 * DAY, 10 * DAY), 16 * DAY, false), 2);
+        // 오프라인 유예가 지나면 평생 구매만 남는다
+        assert_eq!(count(&cache(10 * DAY, 10 * DAY), 18 * DAY, false), 1);
+        // 만료 뒤 갱신을 확인하지 못했으면 잠시 더 쓴다
+        assert_eq!(count(&cache(25 * DAY, 25 * DAY), 31 * DAY, false), 2);
+        assert_eq!(count(&cache(25 * DAY, 25 * DAY), 34 * DAY, false), 1);
+        // 만료 뒤에 확인했다면 유예가 없다
+        assert_eq!(count(&cache(30 * DAY, 30 * DAY), 31 * DAY, false), 1);
+        // 시계 조작이 의심되면 만료 뒤 유예도, 긴 오프라인 유예도 없다
+        assert_eq!(count(&cache(25 * DAY, 25 * DAY), 31 * DAY, true), 1);
+        assert_eq!(count(&cache(10 * DAY, 10 * DAY), 11 * DAY, true), 1);
+        // 시계를 되돌려도 이미 본 시각 기준으로 따진다
+        assert_eq!(count(&cache(10 * DAY, 40 * DAY), 12 * DAY, false), 1);
+    }
+}
@@ -0,0 +1,212 @@
+use futures_util::FutureExt;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context},
+};
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    panic::AssertUnwindSafe,
+};
+use thiserror::Error;
+
+use crate::study_actors::logging::debug_print;
+use crate::study_actors::signals::panic_message;
+
+use super::{actor_started, actor_stopped};
+
+#[derive(Debug, Error)]
+pub enum BuildError {
+    #[error("{actor} depends on {dependency}, which is not registered yet")]
+    MissingDependency {
+        actor: &'static str,
+        dependency: &'static str,
+    },
+}
+
+// 만들어진 액터의 주소를 타입별로 보관한다 (뒤에 만드는 액터의 의존성을 여기서 찾는다)
+// 타입마다 주소는 하나이고, 등록한 순서대로 이름을 기억한다.
+#[derive(Default)]
+pub struct ActorRegistry {
+    addresses: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
+    names: Vec<&'static str>,
+}
+
+impl ActorRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register<A: Actor + 'static>(&mut self, name: &'static str, address: Address<A>) {
+        if self
+            .addresses
+            .insert(TypeId::of::<A>(), Box::new(address))
+            .is_none()
+        {
+            self.names.push(name);
+        }
+    }
+
+    pub fn get<A: Actor + 'static>(&self) -> Option<Address<A>> {
+        self.addresses
+            .get(&TypeId::of::<A>())?
+            .downcast_ref::<Address<A>>()
+            .cloned()
+    }
+
+    pub fn names(&self) -> &[&'static str] {
+        &self.names
+    }
+}
+
+// 등록부에서 찾아 주입할 수 있는 의존성 (주소 하나 또는 주소 튜플)
+// 실패하면 찾지 못한 액터 이름을 돌려준다.
+pub trait Dependencies: Sized {
+    fn resolve(registry: &ActorRegistry) -> Result<Self, &'static str>;
+}
+
+impl Dependencies for () {
+    fn resolve(_: &ActorRegistry) -> Result<Self, &'static str> {
+        Ok(())
+    }
+}
+
+impl<A: Actor + 'static> Dependencies for Address<A> {
+    fn resolve(registry: &ActorRegistry) -> Result<Self, &'static str> {
+        registry.get::<A>().ok_or_else(short_type_name::<A>)
+    }
+}
+
+macro_rules! tuple_dependencies {
+    ($($dependency:ident),+) => {
+        impl<$($dependency: Dependencies),+> Dependencies for ($($dependency,)+) {
+            fn resolve(registry: &ActorRegistry) -> Result<Self, &'static str> {
+                Ok(($($dependency::resolve(registry)?,)+))
+            }
+        }
+    };
+}
+
+tuple_dependencies!(D1);
+tuple_dependencies!(D1, D2);
+tuple_dependencies!(D1, D2, D3);
+tuple_dependencies!(D1, D2, D3, D4);
+tuple_dependencies!(D1, D2, D3, D4, D5);
+tuple_dependencies!(D1, D2, D3, D4, D5, D6);
+tuple_dependencies!(D1, D2, D3, D4, D5, D6, D7);
+tuple_dependencies!(D1, D2, D3, D4, D5, D6, D7, D8);
+
+// 액터 하나를 구성한다: 컨텍스트와 주소 생성 → 의존성 주입 → 등록 → 실행
+// 예: let user_addr = ActorBuilder::new().spawn(&mut registry, UserManagerActor::new)?;
+//     (UserManagerActor::new의 두 번째 인자 Address<AuthActor>를 등록부에서 찾아 넘긴다)
+// 생성 직후 액터에서 값을 꺼내야 하거나 나중에 시작하는 액터는
+// register()로 주소만 먼저 올리고 직접 만든 액터를 start()로 실행한다.
+pub struct ActorBuilder<A: Actor> {
+    name: &'static str,
+    context: Context<A>,
+    address: Address<A>,
+}
+
+impl<A: Actor + Send + 'static> ActorBuilder<A> {
+    pub fn new() -> Self {
+        let context = Context::new();
+        let address = context.address();
+        Self {
+            name: short_type_name::<A>(),
+            context,
+            address,
+        }
+    }
+
+    pub fn address(&self) -> Address<A> {
+        self.address.clone()
+    }
+
+    pub fn register(self, registry: &mut ActorRegistry) -> Self {
+        registry.register(self.name, self.address.clone());
+        self
+    }
+
+    pub fn resolve<D: Dependencies>(&self, registry: &ActorRegistry) -> Result<D, BuildError> {
+        D::resolve(registry).map_err(|dependency| BuildError::MissingDependency {
+            actor: self.name,
+            dependency,
+        })
+    }
+
+    pub fn spawn<D: Dependencies>(
+        self,
+        registry: &mut ActorRegistry,
+        build: impl FnOnce(Address<A>, D) -> A,
+    ) -> Result<Address<A>, BuildError> {
+        let dependencies = self.resolve(registry)?;
+        let actor = build(self.address(), dependencies);
+        Ok(self.register(registry).start(actor))
+    }
+
+    // 실행 루프가 패닉으로 끝나면 기록만 하고 다른 액터는 계속 돈다
+    // (이 액터로 보내는 메시지는 이후 SendError로 실패한다)
+    // 실행 상태와 패닉은 진단 묶음에 담기도록 diagnostics에 남긴다.
+    pub fn start(self, actor: A) -> Address<A> {
+        let Self {
+            name,
+            context,
+            address,
+        } = self;
+        actor_started(name);
+        tokio::spawn(async move {
+            match AssertUnwindSafe(context.run(actor)).catch_unwind().await {
+                Ok(_) => actor_stopped(name, None),
+                Err(panic) => {
+                    let message = panic_message(panic.as_ref());
+                    debug_print!("{} panicked: {}", name, message);
+                    actor_stopped(name, Some(message));
+                }
+            }
+        });
+        address
+    }
+}
+
+impl<A: Actor + Send + 'static> Default for ActorBuilder<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn short_type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use super::{ActorBuilder, ActorRegistry, BuildError};
+    use crate::study_actors::actors::{AuthActor, TrustedClock, UserManagerActor};
+    use crate::study_actors::messages::AuthConfig;
+
+    #[tokio::test]
+    async fn dependencies_are_resolved_from_registered_actors() -> Result<(), Box<dyn Error>> {
+        let mut registry = ActorRegistry::new();
+
+        // 인증 액터가 없으면 사용자 관리자를 만들 수 없다
+        let missing = ActorBuilder::new().spawn(&mut registry, UserManagerActor::new);
+        assert!(matches!(
+            missing,
+            Err(BuildError::MissingDependency {
+                actor: "UserManagerActor",
+                dependency: "AuthActor",
+            })
+        ));
+
+        ActorBuilder::new().spawn(&mut registry, |addr, ()| {
+            AuthActor::new(addr, AuthConfig::default(), TrustedClock::new())
+        })?;
+        ActorBuilder::new().spawn(&mut registry, UserManagerActor::new)?;
+        assert_eq!(registry.names(), ["AuthActor", "UserManagerActor"]);
+        assert!(registry.get::<AuthActor>().is_some());
+        Ok(())
+    }
+}
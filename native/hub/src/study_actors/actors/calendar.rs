@@ -0,0 +1,646 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
+use chrono_tz::Tz;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use std::collections::HashSet;
+use tokio::task::JoinSet;
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        ActorResult, CalendarEvent, CreateDataItem, DataItem, ExportIcs, FetchData, FetchMode,
+        IcsImport, IdKind, ImportIcs, ScanPrefix, StoreData, UserError,
+    },
+    signals::{ExportIcsRequest, ImportIcsRequest, respond_to_dart, route_dart_signals},
+};
+
+use super::recurrence::{resolve_local, validate_rrule};
+use super::{DataManagerActor, StorageActor, TrustedClock, generate_id};
+
+const PRODID: &str = "-//rinf_experiment//Calendar//EN";
+// 한 파일에서 가져오는 일정 수 상한
+const MAX_IMPORT_EVENTS: usize = 5000;
+// 줄바꿈을 뺀 한 줄의 최대 길이 (RFC 5545 3.1)
+const MAX_LINE_OCTETS: usize = 75;
+const UNTITLED_EVENT: &str = "Untitled event";
+
+fn event_key(user_id: &str, item_id: &str) -> String {
+    format!("calendar/{}/{}", user_id, item_id)
+}
+
+fn events_prefix(user_id: &str) -> String {
+    format!("calendar/{}/", user_id)
+}
+
+// 내용 줄 하나 (이름과 매개변수 이름은 대문자, 매개변수 값은 따옴표를 벗긴다)
+// vCard도 같은 문법이라 연락처 가져오기에서 함께 쓴다.
+#[derive(Debug)]
+pub(crate) struct ContentLine {
+    pub(crate) name: String,
+    pub(crate) params: Vec<(String, String)>,
+    pub(crate) value: String,
+}
+
+impl ContentLine {
+    pub(crate) fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+// 접힌 줄을 펼친다 (공백이나 탭으로 시작하는 줄은 앞 줄에 이어진다)
+pub(crate) fn unfold(ics: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in ics.lines() {
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ if line.is_empty() => {}
+            _ => lines.push(line.to_string()),
+        }
+    }
+    lines
+}
+
+// NAME;PARAM=VALUE;...:value (따옴표 안의 ;와 :는 구분자가 아니다)
+pub(crate) fn parse_line(line: &str) -> Option<ContentLine> {
+    let mut in_quotes = false;
+    let mut parts = Vec::new();
+    let mut part_start = 0;
+    for (index, c) in line.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            ';' if !in_quotes => {
+                parts.push(&line[part_start..index]);
+                part_start = index + 1;
+            }
+            ':' if !in_quotes => {
+                parts.push(&line[part_start..index]);
+                let (name, params) = parts.split_first()?;
+                let params = params
+                    .iter()
+                    .map(|param| match param.split_once('=') {
+                        Some((key, value)) => (
+                            key.trim().to_ascii_uppercase(),
+                            value.trim_matches('"').to_string(),
+                        ),
+                        // vCard 2.1의 값만 있는 매개변수 (TEL;CELL:...)는 TYPE으로 본다
+                        None => ("TYPE".to_string(), param.trim().to_string()),
+                    })
+                    .collect();
+                return Some(ContentLine {
+                    name: name.trim().to_ascii_uppercase(),
+                    params,
+                    value: line[index + 1..].to_string(),
+                });
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+pub(crate) fn unescape_text(value: &str) -> String {
+    let mut text = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            text.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => text.push('\n'),
+            Some(escaped) => text.push(escaped),
+            None => text.push('\\'),
+        }
+    }
+    text
+}
+
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | ';' | ',' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// 시각 값 하나를 해석한 결과 (TZID나 Z로 알게 된 시간대 포함)
+struct IcsTime {
+    timestamp: i64,
+    timezone: Tz,
+    all_day: bool,
+}
+
+// DATE, UTC(Z), TZID가 붙은 현지 시각, 시간대 없는 현지 시각(fallback 기준)을 읽는다
+fn parse_time(line: &ContentLine, value: &str, fallback: Tz) -> Result<IcsTime, String> {
+    let timezone = match line.param("TZID") {
+        // Outlook 등이 붙이는 앞의 /는 전역 시간대 표시라 떼어 낸다
+        Some(tzid) => tzid
+            .trim_start_matches('/')
+            .parse()
+            .map_err(|_| format!("unknown TZID {}", tzid))?,
+        None => fallback,
+    };
+    let value = value.trim();
+    if line.param("VALUE") == Some("DATE") || (value.len() == 8 && !value.contains('T')) {
+        let date = NaiveDate::parse_from_str(value, "%Y%m%d")
+            .map_err(|_| format!("invalid date {}", value))?;
+        let at = resolve_local(&timezone, date.and_time(NaiveTime::MIN))
+            .ok_or_else(|| format!("invalid date {}", value))?;
+        return Ok(IcsTime {
+            timestamp: at.timestamp(),
+            timezone,
+            all_day: true,
+        });
+    }
+
+    let parse = |text: &str| {
+        NaiveDateTime::parse_from_str(text, "%Y%m%dT%H%M%S")
+            .map_err(|_| format!("invalid date-time {}", value))
+    };
+    if let Some(utc) = value.strip_suffix('Z') {
+        return Ok(IcsTime {
+            timestamp: parse(utc)?.and_utc().timestamp(),
+            timezone: Tz::UTC,
+            all_day: false,
+        });
+    }
+    let at = resolve_local(&timezone, parse(value)?)
+        .ok_or_else(|| format!("invalid local time {}", value))?;
+    Ok(IcsTime {
+        timestamp: at.timestamp(),
+        timezone,
+        all_day: false,
+    })
+}
+
+// DURATION 값을 초로 (예: PT1H30M, P1D, -P1W)
+fn parse_duration(value: &str) -> Option<i64> {
+    let value = value.trim();
+    let (sign, value) = match value.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let mut seconds = 0i64;
+    let mut in_time = false;
+    let mut number = String::new();
+    for c in value.strip_prefix('P')?.chars() {
+        match c {
+            'T' => in_time = true,
+            '0'..='9' => number.push(c),
+            unit => {
+                let n: i64 = number.parse().ok()?;
+                number.clear();
+                let unit_secs = match (unit, in_time) {
+                    ('W', false) => 7 * 86_400,
+                    ('D', false) => 86_400,
+                    ('H', true) => 3_600,
+                    ('M', true) => 60,
+                    ('S', true) => 1,
+                    _ => return None,
+                };
+                seconds += n * unit_secs;
+            }
+        }
+    }
+    number.is_empty().then_some(sign * seconds)
+}
+
+// VEVENT 하나에서 모은 속성 (항목을 만들기 전)
+#[derive(Debug, Default)]
+struct IcsEvent {
+    uid: Option<String>,
+    summary: Option<String>,
+    description: Option<String>,
+    location: Option<String>,
+    start: Option<ContentLine>,
+    end: Option<ContentLine>,
+    duration: Option<String>,
+    rrule: Option<String>,
+    exdates: Vec<ContentLine>,
+}
+
+impl IcsEvent {
+    fn apply(&mut self, line: ContentLine) {
+        match line.name.as_str() {
+            "UID" => self.uid = Some(line.value.trim().to_string()),
+            "SUMMARY" => self.summary = Some(unescape_text(&line.value)),
+            "DESCRIPTION" => self.description = Some(unescape_text(&line.value)),
+            "LOCATION" => self.location = Some(unescape_text(&line.value)),
+            "DTSTART" => self.start = Some(line),
+            "DTEND" => self.end = Some(line),
+            "DURATION" => self.duration = Some(line.value),
+            "RRULE" => self.rrule = Some(line.value.trim().to_string()),
+            "EXDATE" => self.exdates.push(line),
+            _ => {}
+        }
+    }
+
+    // 시각과 반복 규칙을 확인해 (제목, 내용, 일정)으로 바꾼다 (item_id는 항목을 만든 뒤 채운다)
+    fn into_event(self, fallback: Tz) -> Result<(String, String, CalendarEvent), String> {
+        let label = self
+            .uid
+            .clone()
+            .or_else(|| self.summary.clone())
+            .unwrap_or_else(|| "VEVENT".to_string());
+        let fail = |reason: String| format!("{}: {}", label, reason);
+
+        let start_line = self
+            .start
+            .as_ref()
+            .ok_or_else(|| fail("missing DTSTART".to_string()))?;
+        let start = parse_time(start_line, &start_line.value, fallback).map_err(&fail)?;
+        let end = match (&self.end, &self.duration) {
+            (Some(line), _) => Some(
+                parse_time(line, &line.value, fallback)
+                    .map_err(&fail)?
+                    .timestamp,
+            ),
+            (None, Some(duration)) => {
+                let seconds = parse_duration(duration)
+                    .ok_or_else(|| fail(format!("invalid DURATION {}", duration)))?;
+                Some(start.timestamp + seconds)
+            }
+            // 끝이 없는 하루 종일 일정은 그날 하루 (RFC 5545 3.6.1)
+            (None, None) if start.all_day => Some(start.timestamp + 86_400),
+            (None, None) => None,
+        };
+        if end.is_some_and(|end| end < start.timestamp) {
+            return Err(fail("DTEND is before DTSTART".to_string()));
+        }
+        if let Some(rrule) = &self.rrule {
+            validate_rrule(rrule).map_err(|e| fail(e.to_string()))?;
+        }
+        // 시간대 없는 EXDATE는 DTSTART와 같은 시간대로 본다
+        let mut exdates = Vec::new();
+        for line in &self.exdates {
+            for value in line.value.split(',') {
+                exdates.push(
+                    parse_time(line, value, start.timezone)
+                        .map_err(&fail)?
+                        .timestamp,
+                );
+            }
+        }
+
+        let title = self
+            .summary
+            .filter(|summary| !summary.trim().is_empty())
+            .unwrap_or_else(|| UNTITLED_EVENT.to_string());
+        let event = CalendarEvent {
+            item_id: String::new(),
+            uid: self
+                .uid
+                .unwrap_or_else(|| format!("{}@rinf_experiment", generate_id(IdKind::Ulid))),
+            start: start.timestamp,
+            end,
+            all_day: start.all_day,
+            timezone: start.timezone.name().to_string(),
+            rrule: self.rrule,
+            exdates,
+            location: self.location.filter(|location| !location.trim().is_empty()),
+        };
+        Ok((title, self.description.unwrap_or_default(), event))
+    }
+}
+
+// VEVENT마다 속성을 모은다 (VALARM처럼 안에 든 구성 요소와 VTIMEZONE은 건너뛴다)
+fn collect_events(ics: &str) -> Vec<IcsEvent> {
+    let mut events = Vec::new();
+    let mut current: Option<IcsEvent> = None;
+    let mut nested = 0usize;
+    for line in unfold(ics) {
+        let Some(line) = parse_line(&line) else {
+            continue;
+        };
+        let is_event = line.value.trim().eq_ignore_ascii_case("VEVENT");
+        let Some(event) = current.as_mut() else {
+            if line.name == "BEGIN" && is_event {
+                current = Some(IcsEvent::default());
+            }
+            continue;
+        };
+        match line.name.as_str() {
+            "BEGIN" => nested += 1,
+            "END" if nested > 0 => nested -= 1,
+            "END" if is_event => events.extend(current.take()),
+            _ if nested > 0 => {}
+            _ => event.apply(line),
+        }
+    }
+    events
+}
+
+// DTSTART처럼 시각을 담는 속성 (하루 종일이면 날짜만, UTC면 Z, 그 밖에는 TZID와 현지 시각)
+fn time_property(name: &str, timestamps: &[i64], all_day: bool, tz: Tz) -> Option<String> {
+    let (params, format) = if all_day {
+        (";VALUE=DATE".to_string(), "%Y%m%d")
+    } else if tz == Tz::UTC {
+        (String::new(), "%Y%m%dT%H%M%SZ")
+    } else {
+        (format!(";TZID={}", tz.name()), "%Y%m%dT%H%M%S")
+    };
+    let values = timestamps
+        .iter()
+        .map(|&timestamp| {
+            let at = tz.timestamp_opt(timestamp, 0).single()?;
+            Some(at.naive_local().format(format).to_string())
+        })
+        .collect::<Option<Vec<String>>>()?;
+    Some(format!("{}{}:{}", name, params, values.join(",")))
+}
+
+// 75옥텟마다 접는다 (UTF-8 문자 중간에서는 자르지 않는다)
+fn push_folded(ics: &mut String, line: &str) {
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > MAX_LINE_OCTETS {
+            ics.push_str("\r\n ");
+            width = 1;
+        }
+        ics.push(c);
+        width += c.len_utf8();
+    }
+    ics.push_str("\r\n");
+}
+
+// VTIMEZONE은 넣지 않는다 (주요 달력 앱은 IANA 이름의 TZID를 그대로 해석한다)
+fn write_ics(entries: &[(CalendarEvent, DataItem)], stamp: &str) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        format!("PRODID:{}", PRODID),
+        "CALSCALE:GREGORIAN".to_string(),
+    ];
+    for (event, item) in entries {
+        let tz: Tz = event.timezone.parse().unwrap_or(Tz::UTC);
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}", event.uid));
+        lines.push(format!("DTSTAMP:{}", stamp));
+        lines.extend(time_property("DTSTART", &[event.start], event.all_day, tz));
+        if let Some(end) = event.end {
+            lines.extend(time_property("DTEND", &[end], event.all_day, tz));
+        }
+        if let Some(rrule) = &event.rrule {
+            lines.push(format!("RRULE:{}", rrule));
+        }
+        if !event.exdates.is_empty() {
+            lines.extend(time_property("EXDATE", &event.exdates, event.all_day, tz));
+        }
+        lines.push(format!("SUMMARY:{}", escape_text(&item.title)));
+        if !item.content.is_empty() {
+            lines.push(format!("DESCRIPTION:{}", escape_text(&item.content)));
+        }
+        if let Some(location) = &event.location {
+            lines.push(format!("LOCATION:{}", escape_text(location)));
+        }
+        lines.push("END:VEVENT".to_string());
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    let mut ics = String::new();
+    for line in &lines {
+        push_folded(&mut ics, line);
+    }
+    ics
+}
+
+// 달력 액터
+// 다른 달력 앱과 일정을 주고받도록 iCalendar(RFC 5545)를 항목으로 가져오고 내보낸다.
+// 가져온 VEVENT는 제목과 설명을 가진 항목이 되고, 시각·반복 규칙·장소는 항목 id별로 따로 저장한다.
+// 시간대와 반복 규칙은 반복 일정 엔진과 같은 방식으로 해석하므로 가져온 일정을 그대로 펼칠 수 있다.
+pub struct CalendarActor {
+    storage: Address<StorageActor>,
+    data: Address<DataManagerActor>,
+    clock: TrustedClock,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for CalendarActor {}
+
+impl CalendarActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        storage: Address<StorageActor>,
+        data: Address<DataManagerActor>,
+        clock: TrustedClock,
+    ) -> Self {
+        let owned_tasks = route_dart_signals!(self_addr, [ImportIcsRequest, ExportIcsRequest]);
+
+        Self {
+            storage,
+            data,
+            clock,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    async fn stored_events(&mut self, user_id: &str) -> ActorResult<Vec<CalendarEvent>> {
+        let scan = ScanPrefix {
+            prefix: events_prefix(user_id),
+        };
+        let entries = self.storage.send(scan).await??;
+        let events = entries
+            .into_iter()
+            .filter_map(|(key, bytes)| match serde_json::from_slice(&bytes) {
+                Ok(event) => Some(event),
+                Err(e) => {
+                    debug_print!("Skipping stored calendar event {}: {}", key, e);
+                    None
+                }
+            })
+            .collect();
+        Ok(events)
+    }
+
+    // 지워졌거나 읽을 수 없는 항목은 None
+    async fn fetch_item(&mut self, user_id: &str, item_id: &str) -> Option<DataItem> {
+        let request = FetchData {
+            key: format!("items/{}", item_id),
+            user_id: Some(user_id.to_string()),
+            mode: FetchMode::CacheFirst,
+        };
+        let Ok(Ok(bytes)) = self.data.send(request).await else {
+            return None;
+        };
+        serde_json::from_slice(&bytes).ok()
+    }
+}
+
+#[async_trait]
+impl Handler<ImportIcs> for CalendarActor {
+    type Result = ActorResult<IcsImport>;
+
+    async fn handle(&mut self, msg: ImportIcs, _: &Context<Self>) -> Self::Result {
+        let fallback: Tz = msg
+            .timezone
+            .parse()
+            .map_err(|_| UserError::InvalidInput(format!("Unknown timezone: {}", msg.timezone)))?;
+        let events = collect_events(&msg.ics);
+        if events.is_empty() {
+            return Err(UserError::InvalidInput(
+                "No events found in calendar file".to_string(),
+            ));
+        }
+        if events.len() > MAX_IMPORT_EVENTS {
+            return Err(UserError::InvalidInput(format!(
+                "Calendar file has more than {} events",
+                MAX_IMPORT_EVENTS
+            )));
+        }
+
+        let mut known: HashSet<String> = self
+            .stored_events(&msg.user_id)
+            .await?
+            .into_iter()
+            .map(|event| event.uid)
+            .collect();
+        let mut report = IcsImport::default();
+        for event in events {
+            let (title, content, mut event) = match event.into_event(fallback) {
+                Ok(parsed) => parsed,
+                Err(reason) => {
+                    report.skipped.push(reason);
+                    continue;
+                }
+            };
+            if !known.insert(event.uid.clone()) {
+                report.duplicates += 1;
+                continue;
+            }
+
+            let create = CreateDataItem {
+                user_id: msg.user_id.clone(),
+                title,
+                content,
+                tags: Vec::new(),
+            };
+            event.item_id = self.data.send(create).await??.id;
+            let store = StoreData {
+                key: event_key(&msg.user_id, &event.item_id),
+                data: serde_json::to_vec(&event)?,
+                user_id: None,
+                ttl: None,
+            };
+            self.storage.send(store).await??;
+            report.imported.push(event);
+        }
+        Ok(report)
+    }
+}
+
+#[async_trait]
+impl Handler<ExportIcs> for CalendarActor {
+    type Result = ActorResult<String>;
+
+    async fn handle(&mut self, msg: ExportIcs, _: &Context<Self>) -> Self::Result {
+        let mut events = self.stored_events(&msg.user_id).await?;
+        if let Some(item_ids) = &msg.item_ids {
+            let wanted: HashSet<&String> = item_ids.iter().collect();
+            events.retain(|event| wanted.contains(&event.item_id));
+        }
+        events.sort_by_key(|event| event.start);
+
+        let mut entries = Vec::with_capacity(events.len());
+        for event in events {
+            if let Some(item) = self.fetch_item(&msg.user_id, &event.item_id).await {
+                entries.push((event, item));
+            }
+        }
+        let stamp = DateTime::from_timestamp(self.clock.now_millis() / 1000, 0)
+            .unwrap_or_default()
+            .format("%Y%m%dT%H%M%SZ")
+            .to_string();
+        Ok(write_ics(&entries, &stamp))
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<ImportIcsRequest> for CalendarActor {
+    async fn notify(&mut self, msg: ImportIcsRequest, ctx: &Context<Self>) {
+        let _ = respond_to_dart(msg, |msg| async move {
+            let import = ImportIcs {
+                user_id: msg.user_id,
+                ics: msg.ics,
+                timezone: msg.timezone,
+            };
+            Ok(self.handle(import, ctx).await?)
+        })
+        .await;
+    }
+}
+
+#[async_trait]
+impl Notifiable<ExportIcsRequest> for CalendarActor {
+    async fn notify(&mut self, msg: ExportIcsRequest, ctx: &Context<Self>) {
+        let _ = respond_to_dart(msg, |msg| async move {
+            let export = ExportIcs {
+                user_id: msg.user_id,
+                item_ids: msg.item_ids,
+            };
+            Ok(self.handle(export, ctx).await?)
+        })
+        .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn imports_and_exports_events_with_timezones() -> Result<(), Box<dyn Error>> {
+        let ics = "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nBEGIN:VEVENT\r\nUID:standup@example.com\r\n\
+                   DTSTART;TZID=America/New_York:20260302T090000\r\nDURATION:PT30M\r\n\
+                   RRULE:FREQ=WEEKLY;BYDAY=MO\r\nEXDATE;TZID=America/New_York:20260309T090000\r\n\
+                   SUMMARY:Stand\r\n up\\, daily\r\nBEGIN:VALARM\r\nDESCRIPTION:Reminder\r\n\
+                   END:VALARM\r\nEND:VEVENT\r\nBEGIN:VEVENT\r\nUID:holiday\r\n\
+                   DTSTART;VALUE=DATE:20260501\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n";
+        let fallback: Tz = "Asia/Seoul".parse()?;
+        let mut events = collect_events(ics).into_iter();
+
+        let (title, content, standup) =
+            events.next().ok_or("missing event")?.into_event(fallback)?;
+        assert_eq!((title.as_str(), content.as_str()), ("Standup, daily", ""));
+        // 2026-03-02 09:00 EST = 14:00 UTC, 3월 9일은 서머타임이라 13:00 UTC
+        assert_eq!(standup.start, 1_772_460_000);
+        assert_eq!(standup.end, Some(1_772_461_800));
+        assert_eq!(standup.exdates, vec![1_773_061_200]);
+        assert_eq!(standup.timezone, "America/New_York");
+
+        let (_, _, holiday) = events.next().ok_or("missing event")?.into_event(fallback)?;
+        assert!(holiday.all_day);
+        assert_eq!(holiday.end, Some(holiday.start + 86_400));
+
+        let item = DataItem {
+            id: standup.item_id.clone(),
+            title,
+            content,
+            created_at: 0,
+            updated_at: 0,
+            tags: Vec::new(),
+            attachments: Vec::new(),
+            owner: String::new(),
+        };
+        let exported = write_ics(&[(standup, item)], "20260101T000000Z");
+        assert!(exported.contains("DTSTART;TZID=America/New_York:20260302T090000\r\n"));
+        assert!(exported.contains("EXDATE;TZID=America/New_York:20260309T090000\r\n"));
+        assert!(exported.contains("SUMMARY:Standup\\, daily\r\n"));
+        Ok(())
+    }
+}
@@ -0,0 +1,830 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::{cmp::Reverse, collections::HashMap, time::Duration};
+use tokio::{sync::broadcast, task::JoinSet};
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        CaptureSnapshot, ChatError, ChatMessage, Conversation, DeliveryStatus, FetchData,
+        FetchMode, PersistState, PersistedState, RealtimeEnvelope, RealtimeEvent, RestoreState,
+        ScheduledTask, SendChatMessage, SendRealtimeEnvelope, StoreData, UserId, WipeUserData,
+    },
+    signals::{
+        ConversationListSignal, ConversationMessagesSignal, ConversationUpdatedSignal,
+        CreateConversationRequest, EmitSignal, LoadConversationsRequest, LoadMessagesRequest,
+        MarkConversationReadRequest, MessageReceivedSignal, MessageStatusSignal,
+        SendChatMessageRequest, StateSnapshotSignal, TypingIndicatorSignal, TypingRequest,
+        route_dart_signals,
+    },
+};
+
+use super::{Clock, StorageActor, TrustedClock, WebSocketActor};
+
+const CHAT_CHANNEL: &str = "chat";
+const CONVERSATIONS_KEY: &str = "chat/conversations";
+const OUTBOX_KEY: &str = "chat/outbox";
+const OUTBOX_RETRY_SECS: u64 = 15;
+const STATE_VERSION: u32 = 1; // 종료 시 남기는 발신 대기열의 구조 버전
+
+fn messages_key(conversation_id: &str) -> String {
+    format!("chat/messages/{}", conversation_id)
+}
+
+// 종료 시 남기는 발신 대기열 (메시지 아이디가 겹치지 않도록 순번도 함께 남긴다)
+#[derive(Serialize, Deserialize)]
+struct ChatState {
+    outbox: Vec<ChatMessage>,
+    sequence: u64,
+}
+
+// 서버와 주고받는 수신 확인 페이로드
+#[derive(Serialize, Deserialize)]
+struct ReceiptPayload {
+    conversation_id: String,
+    message_id: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct TypingPayload {
+    conversation_id: String,
+    user_id: UserId,
+    is_typing: bool,
+}
+
+// 채팅 액터
+// 대화/메시지는 저장소에 보관하고, 전송은 WebSocket 액터를 통해 이루어진다.
+// 오프라인 상태에서 보낸 메시지는 발신 대기열에 남아 서버 확인(ack)을 받을 때까지 재전송된다.
+pub struct ChatActor {
+    storage: Address<StorageActor>,
+    websocket: Address<WebSocketActor>,
+    clock: TrustedClock,
+    conversations: HashMap<String, Conversation>,
+    messages: HashMap<String, Vec<ChatMessage>>,
+    outbox: Vec<ChatMessage>,
+    sequence: u64,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for ChatActor {}
+
+impl ChatActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        storage: Address<StorageActor>,
+        websocket: Address<WebSocketActor>,
+        realtime_events: broadcast::Receiver<RealtimeEvent>,
+        clock: TrustedClock,
+    ) -> Self {
+        let mut owned_tasks = route_dart_signals!(
+            self_addr,
+            [
+                LoadConversationsRequest,
+                CreateConversationRequest,
+                LoadMessagesRequest,
+                SendChatMessageRequest,
+                TypingRequest,
+                MarkConversationReadRequest
+            ]
+        );
+        owned_tasks.spawn(Self::load_state(self_addr.clone()));
+        owned_tasks.spawn(Self::listen_to_realtime(self_addr.clone(), realtime_events));
+        owned_tasks.spawn(Self::retry_outbox(self_addr.clone(), clock.clock().clone()));
+
+        Self {
+            storage,
+            websocket,
+            clock,
+            conversations: HashMap::new(),
+            messages: HashMap::new(),
+            outbox: Vec::new(),
+            sequence: 0,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    async fn load_state(mut self_addr: Address<Self>) {
+        let _ = self_addr.notify(LoadChatState).await;
+    }
+
+    async fn listen_to_realtime(
+        mut self_addr: Address<Self>,
+        mut events: broadcast::Receiver<RealtimeEvent>,
+    ) {
+        loop {
+            match events.recv().await {
+                Ok(event) => {
+                    let _ = self_addr.notify(RealtimeEventReceived(event)).await;
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    debug_print!("Chat skipped {} realtime events", skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    }
+
+    async fn retry_outbox(mut self_addr: Address<Self>, clock: Clock) {
+        let mut interval = clock
+            .interval(Duration::from_secs(OUTBOX_RETRY_SECS))
+            .throttled(ScheduledTask::ChatRetry);
+        loop {
+            interval.tick().await;
+            let _ = self_addr.notify(FlushOutbox).await;
+        }
+    }
+
+    fn next_id(&mut self, prefix: &str) -> String {
+        self.sequence += 1;
+        format!("{}-{}-{}", prefix, self.clock.now_millis(), self.sequence)
+    }
+
+    async fn load_json<T: DeserializeOwned>(&mut self, key: &str) -> Option<T> {
+        let request = FetchData {
+            key: key.to_string(),
+            user_id: None,
+            mode: FetchMode::CacheFirst,
+        };
+        match self.storage.send(request).await {
+            Ok(Ok(bytes)) => serde_json::from_slice(&bytes).ok(),
+            _ => None,
+        }
+    }
+
+    async fn save_json<T: Serialize>(&mut self, key: &str, value: &T) -> Result<(), ChatError> {
+        let request = StoreData {
+            key: key.to_string(),
+            data: serde_json::to_vec(value)?,
+            user_id: None,
+            ttl: None,
+        };
+        self.storage.send(request).await??;
+        Ok(())
+    }
+
+    async fn persist_conversations(&mut self) {
+        let conversations: Vec<Conversation> = self.conversations.values().cloned().collect();
+        if let Err(e) = self.save_json(CONVERSATIONS_KEY, &conversations).await {
+            debug_print!("Failed to persist conversations: {}", e);
+        }
+    }
+
+    async fn persist_outbox(&mut self) {
+        let outbox = self.outbox.clone();
+        if let Err(e) = self.save_json(OUTBOX_KEY, &outbox).await {
+            debug_print!("Failed to persist chat outbox: {}", e);
+        }
+    }
+
+    async fn persist_messages(&mut self, conversation_id: &str) {
+        let messages = self
+            .messages
+            .get(conversation_id)
+            .cloned()
+            .unwrap_or_default();
+        if let Err(e) = self
+            .save_json(&messages_key(conversation_id), &messages)
+            .await
+        {
+            debug_print!("Failed to persist messages of {}: {}", conversation_id, e);
+        }
+    }
+
+    // 대화의 메시지는 처음 접근할 때 저장소에서 읽어옴
+    async fn ensure_messages_loaded(&mut self, conversation_id: &str) {
+        if self.messages.contains_key(conversation_id) {
+            return;
+        }
+        let messages = self
+            .load_json::<Vec<ChatMessage>>(&messages_key(conversation_id))
+            .await
+            .unwrap_or_default();
+        self.messages.insert(conversation_id.to_string(), messages);
+    }
+
+    // 같은 ID의 메시지는 갱신, 없으면 추가
+    async fn store_message(&mut self, message: &ChatMessage) {
+        self.ensure_messages_loaded(&message.conversation_id).await;
+        let messages = self
+            .messages
+            .entry(message.conversation_id.clone())
+            .or_default();
+        match messages.iter_mut().find(|m| m.id == message.id) {
+            Some(existing) => *existing = message.clone(),
+            None => messages.push(message.clone()),
+        }
+        self.persist_messages(&message.conversation_id).await;
+    }
+
+    async fn touch_conversation(&mut self, message: &ChatMessage, unread: bool) {
+        let conversation = self
+            .conversations
+            .entry(message.conversation_id.clone())
+            .or_insert_with(|| Conversation {
+                id: message.conversation_id.clone(),
+                title: message.sender_id.clone(),
+                participants: vec![message.sender_id.clone()],
+                last_message_preview: None,
+                unread_count: 0,
+                updated_at: 0,
+            });
+        conversation.last_message_preview = Some(message.body.chars().take(80).collect());
+        conversation.updated_at = message.sent_at;
+        if unread {
+            conversation.unread_count += 1;
+        }
+
+        let conversation = conversation.clone();
+        self.persist_conversations().await;
+        ConversationUpdatedSignal { conversation }.emit();
+    }
+
+    // 상태는 앞으로만 진행 (up_to가 true면 해당 메시지 이전의 내 메시지까지 모두 갱신)
+    async fn advance_status(
+        &mut self,
+        receipt: &ReceiptPayload,
+        status: DeliveryStatus,
+        up_to: bool,
+    ) {
+        self.ensure_messages_loaded(&receipt.conversation_id).await;
+        let Some(messages) = self.messages.get_mut(&receipt.conversation_id) else {
+            return;
+        };
+        let Some(target) = messages.iter().position(|m| m.id == receipt.message_id) else {
+            return;
+        };
+
+        let start = if up_to { 0 } else { target };
+        let target_sender = messages[target].sender_id.clone();
+        let mut changed = Vec::new();
+        for message in &mut messages[start..=target] {
+            if message.sender_id == target_sender && message.status < status {
+                message.status = status;
+                changed.push(message.id.clone());
+            }
+        }
+        if changed.is_empty() {
+            return;
+        }
+
+        self.persist_messages(&receipt.conversation_id).await;
+        for message_id in changed {
+            MessageStatusSignal {
+                conversation_id: receipt.conversation_id.clone(),
+                message_id,
+                status,
+            }
+            .emit();
+        }
+    }
+
+    async fn send_envelope<T: Serialize>(&mut self, kind: &str, data: &T) -> Result<(), ChatError> {
+        let envelope = RealtimeEnvelope {
+            channel: CHAT_CHANNEL.to_string(),
+            kind: kind.to_string(),
+            data: serde_json::to_value(data)?,
+        };
+        self.websocket
+            .send(SendRealtimeEnvelope(envelope))
+            .await??;
+        Ok(())
+    }
+
+    // 대기열에 없는 메시지만 뒤에 붙인다 (보낸 순서 유지)
+    fn merge_outbox(&mut self, messages: Vec<ChatMessage>) {
+        for message in messages {
+            if !self.outbox.iter().any(|queued| queued.id == message.id) {
+                self.outbox.push(message);
+            }
+        }
+    }
+
+    // 대기 중인 메시지를 순서대로 전송 (연결이 없으면 다음 기회에 재시도)
+    async fn flush_outbox(&mut self) {
+        for message in self.outbox.clone() {
+            if self.send_envelope("message", &message).await.is_err() {
+                break;
+            }
+        }
+    }
+
+    async fn handle_envelope(&mut self, envelope: RealtimeEnvelope) -> Result<(), ChatError> {
+        match envelope.kind.as_str() {
+            "message" => {
+                let mut message: ChatMessage = serde_json::from_value(envelope.data)?;
+                message.status = DeliveryStatus::Delivered;
+                self.store_message(&message).await;
+                self.touch_conversation(&message, true).await;
+                MessageReceivedSignal {
+                    message: message.clone(),
+                }
+                .emit();
+
+                let receipt = ReceiptPayload {
+                    conversation_id: message.conversation_id,
+                    message_id: message.id,
+                };
+                let _ = self.send_envelope("delivered", &receipt).await;
+            }
+            "ack" => {
+                let receipt: ReceiptPayload = serde_json::from_value(envelope.data)?;
+                self.outbox.retain(|m| m.id != receipt.message_id);
+                self.persist_outbox().await;
+                self.advance_status(&receipt, DeliveryStatus::Sent, false)
+                    .await;
+            }
+            "delivered" => {
+                let receipt: ReceiptPayload = serde_json::from_value(envelope.data)?;
+                self.advance_status(&receipt, DeliveryStatus::Delivered, false)
+                    .await;
+            }
+            "read" => {
+                let receipt: ReceiptPayload = serde_json::from_value(envelope.data)?;
+                self.advance_status(&receipt, DeliveryStatus::Read, true)
+                    .await;
+            }
+            "typing" => {
+                let typing: TypingPayload = serde_json::from_value(envelope.data)?;
+                TypingIndicatorSignal {
+                    conversation_id: typing.conversation_id,
+                    user_id: typing.user_id,
+                    is_typing: typing.is_typing,
+                }
+                .emit();
+            }
+            other => debug_print!("Unhandled chat envelope kind: {}", other),
+        }
+        Ok(())
+    }
+}
+
+// 내부 메시지 정의
+struct LoadChatState;
+struct FlushOutbox;
+struct RealtimeEventReceived(RealtimeEvent);
+
+#[async_trait]
+impl Notifiable<LoadChatState> for ChatActor {
+    async fn notify(&mut self, _: LoadChatState, _: &Context<Self>) {
+        let conversations = self
+            .load_json::<Vec<Conversation>>(CONVERSATIONS_KEY)
+            .await
+            .unwrap_or_default();
+        self.conversations = conversations
+            .into_iter()
+            .map(|conversation| (conversation.id.clone(), conversation))
+            .collect();
+        let stored = self
+            .load_json::<Vec<ChatMessage>>(OUTBOX_KEY)
+            .await
+            .unwrap_or_default();
+        // 불러오기 전에 넣은 메시지는 뒤에 둔다
+        let queued = std::mem::replace(&mut self.outbox, stored);
+        self.merge_outbox(queued);
+
+        debug_print!(
+            "Chat state loaded: {} conversations, {} queued messages",
+            self.conversations.len(),
+            self.outbox.len()
+        );
+    }
+}
+
+#[async_trait]
+impl Notifiable<FlushOutbox> for ChatActor {
+    async fn notify(&mut self, _: FlushOutbox, _: &Context<Self>) {
+        if !self.outbox.is_empty() {
+            self.flush_outbox().await;
+        }
+    }
+}
+
+// 보내지 못한 메시지 수
+// 시작 직후에는 상태를 불러오는 작업보다 먼저 올 수 있으므로 저장된 대기열을 읽는다.
+#[async_trait]
+impl Handler<CaptureSnapshot> for ChatActor {
+    type Result = Option<StateSnapshotSignal>;
+
+    async fn handle(&mut self, _: CaptureSnapshot, _: &Context<Self>) -> Self::Result {
+        let count = self
+            .load_json::<Vec<ChatMessage>>(OUTBOX_KEY)
+            .await
+            .map_or(0, |outbox| outbox.len());
+        Some(StateSnapshotSignal::PendingJobs {
+            source: "chat_outbox".to_string(),
+            count: count as u64,
+        })
+    }
+}
+
+#[async_trait]
+impl Notifiable<RealtimeEventReceived> for ChatActor {
+    async fn notify(&mut self, msg: RealtimeEventReceived, _: &Context<Self>) {
+        match msg.0 {
+            RealtimeEvent::Connected => self.flush_outbox().await,
+            RealtimeEvent::Disconnected => {}
+            RealtimeEvent::Envelope(envelope) if envelope.channel == CHAT_CHANNEL => {
+                if let Err(e) = self.handle_envelope(envelope).await {
+                    debug_print!("Invalid chat envelope: {}", e);
+                }
+            }
+            RealtimeEvent::Envelope(_) => {}
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<SendChatMessage> for ChatActor {
+    type Result = Result<ChatMessage, ChatError>;
+
+    async fn handle(&mut self, msg: SendChatMessage, _: &Context<Self>) -> Self::Result {
+        if !self.conversations.contains_key(&msg.conversation_id) {
+            return Err(format!("Unknown conversation: {}", msg.conversation_id).into());
+        }
+
+        let message = ChatMessage {
+            id: self.next_id(&msg.user_id),
+            conversation_id: msg.conversation_id,
+            sender_id: msg.user_id,
+            body: msg.body,
+            sent_at: self.clock.trusted_now(),
+            status: DeliveryStatus::Pending,
+        };
+
+        self.store_message(&message).await;
+        self.touch_conversation(&message, false).await;
+        self.outbox.push(message.clone());
+        self.persist_outbox().await;
+        self.flush_outbox().await;
+        Ok(message)
+    }
+}
+
+#[async_trait]
+impl Handler<PersistState> for ChatActor {
+    type Result = Option<PersistedState>;
+
+    async fn handle(&mut self, _: PersistState, _: &Context<Self>) -> Self::Result {
+        if self.outbox.is_empty() {
+            return None;
+        }
+        let state = ChatState {
+            outbox: self.outbox.clone(),
+            sequence: self.sequence,
+        };
+        PersistedState::new(STATE_VERSION, &state)
+    }
+}
+
+#[async_trait]
+impl Handler<RestoreState> for ChatActor {
+    type Result = bool;
+
+    async fn handle(&mut self, msg: RestoreState, _: &Context<Self>) -> Self::Result {
+        let Some(state) = msg.0.read::<ChatState>(STATE_VERSION) else {
+            return false;
+        };
+        self.sequence = self.sequence.max(state.sequence);
+        self.merge_outbox(state.outbox);
+        self.persist_outbox().await;
+        true
+    }
+}
+
+// 저장소는 개인정보 액터가 따로 비우므로 메모리 상태와 발신 대기열만 정리
+#[async_trait]
+impl Handler<WipeUserData> for ChatActor {
+    type Result = Result<(), ChatError>;
+
+    async fn handle(&mut self, _: WipeUserData, _: &Context<Self>) -> Self::Result {
+        self.conversations.clear();
+        self.messages.clear();
+        self.outbox.clear();
+        Ok(())
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<LoadConversationsRequest> for ChatActor {
+    async fn notify(&mut self, _: LoadConversationsRequest, _: &Context<Self>) {
+        let mut conversations: Vec<Conversation> = self.conversations.values().cloned().collect();
+        conversations.sort_by_key(|conversation| Reverse(conversation.updated_at));
+        ConversationListSignal { conversations }.emit();
+    }
+}
+
+#[async_trait]
+impl Notifiable<CreateConversationRequest> for ChatActor {
+    async fn notify(&mut self, msg: CreateConversationRequest, _: &Context<Self>) {
+        let conversation = Conversation {
+            id: self.next_id("conv"),
+            title: msg.title,
+            participants: msg.participants,
+            last_message_preview: None,
+            unread_count: 0,
+            updated_at: self.clock.trusted_now(),
+        };
+        self.conversations
+            .insert(conversation.id.clone(), conversation.clone());
+        self.messages.insert(conversation.id.clone(), Vec::new());
+        self.persist_conversations().await;
+
+        ConversationUpdatedSignal { conversation }.emit();
+    }
+}
+
+#[async_trait]
+impl Notifiable<LoadMessagesRequest> for ChatActor {
+    async fn notify(&mut self, msg: LoadMessagesRequest, _: &Context<Self>) {
+        self.ensure_messages_loaded(&msg.conversation_id).await;
+        let messages = self
+            .messages
+            .get(&msg.conversation_id)
+            .map(|messages| {
+                let skip = msg
+                    .limit
+                    .map_or(0, |limit| messages.len().saturating_sub(limit as usize));
+                messages[skip..].to_vec()
+            })
+            .unwrap_or_default();
+
+        ConversationMessagesSignal {
+            conversation_id: msg.conversation_id,
+            messages,
+        }
+        .emit();
+    }
+}
+
+#[async_trait]
+impl Notifiable<SendChatMessageRequest> for ChatActor {
+    async fn notify(&mut self, msg: SendChatMessageRequest, ctx: &Context<Self>) {
+        let result = self
+            .handle(
+                SendChatMessage {
+                    user_id: msg.user_id,
+                    conversation_id: msg.conversation_id,
+                    body: msg.body,
+                },
+                ctx,
+            )
+            .await;
+
+        match result {
+            Ok(message) => MessageReceivedSignal { message }.emit(),
+            Err(e) => debug_print!("Failed to send chat message: {}", e),
+        }
+    }
+}
+
+#[async_trait]
+impl Notifiable<TypingRequest> for ChatActor {
+    async fn notify(&mut self, msg: TypingRequest, _: &Context<Self>) {
+        // 입력 중 표시는 일시적인 정보라 실패해도 대기열에 넣지 않음
+        let payload = TypingPayload {
+            conversation_id: msg.conversation_id,
+            user_id: msg.user_id,
+            is_typing: msg.is_typing,
+        };
+        let _ = self.send_envelope("typing", &payload).await;
+    }
+}
+
+#[async_trait]
+impl Notifiable<MarkConversationReadRequest> for ChatActor {
+    async fn notify(&mut self, msg: MarkConversationReadRequest, _: &Context<Self>) {
+        self.ensure_messages_loaded(&msg.conversation_id).await;
+
+        // 상대가 보낸 마지막 메시지까지 읽음 처리 후 수신 확인 전송
+        let latest_incoming = self
+            .messages
+            .get_mut(&msg.conversation_id)
+            .and_then(|messages| {
+                let mut latest = None;
+                for message in messages.iter_mut().filter(|m| m.sender_id != msg.user_id) {
+                    message.status = DeliveryStatus::Read;
+                    latest = Some(message.id.clone());
+                }
+                latest
+            });
+
+        if let Some(message_id) = latest_incoming {
+            self.persist_messages(&msg.conversation_id).await;
+            let receipt = ReceiptPayload {
+                conversation_id: msg.conversation_id.clone(),
+                message_id,
+            };
+            let _ = self.send_envelope("read", &receipt).await;
+        }
+
+        if let Some(conversation) = self.conversations.get_mut(&msg.conversation_id) {
+            conversation.unread_count = 0;
+            let conversation = conversation.clone();
+            self.persist_conversations().await;
+            ConversationUpdatedSignal { conversation }.emit();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use std::{collections::HashSet, error::Error, sync::Arc};
+    use tokio::sync::broadcast;
+
+    use super::{CHAT_CHANNEL, ChatActor, ChatState, STATE_VERSION};
+    use crate::study_actors::{
+        actors::{StorageActor, TrustedClock, WebSocketActor},
+        messages::{
+            ChatMessage, DeliveryStatus, PersistState, PersistedState, RealtimeConfig,
+            RealtimeEnvelope, RealtimeEvent, RestoreState, SendChatMessage,
+        },
+        signals::{ConversationUpdatedSignal, CreateConversationRequest},
+        storage::MemoryStorage,
+        test_support::{TestActorHarness, settle},
+    };
+
+    // 연결되지 않은 WebSocket 액터를 쓰므로 보낸 메시지는 발신 대기열에 남는다
+    struct Chat {
+        actor: TestActorHarness<ChatActor>,
+        events: broadcast::Sender<RealtimeEvent>,
+        _storage: TestActorHarness<StorageActor>,
+        _websocket: TestActorHarness<WebSocketActor>,
+    }
+
+    async fn start_chat() -> Chat {
+        let storage =
+            TestActorHarness::start(|_| StorageActor::new(Arc::new(MemoryStorage::new())));
+        let websocket =
+            TestActorHarness::start(|addr| WebSocketActor::new(addr, RealtimeConfig::default()));
+        let (events, receiver) = broadcast::channel(8);
+        let actor = TestActorHarness::start(|addr| {
+            ChatActor::new(
+                addr,
+                storage.addr(),
+                websocket.addr(),
+                receiver,
+                TrustedClock::new(),
+            )
+        });
+        settle().await;
+        Chat {
+            actor,
+            events,
+            _storage: storage,
+            _websocket: websocket,
+        }
+    }
+
+    async fn create_conversation(chat: &mut Chat) -> Result<String, Box<dyn Error>> {
+        chat.actor
+            .notify(CreateConversationRequest {
+                title: "Study group".to_string(),
+                participants: vec!["alice".to_string(), "bob".to_string()],
+            })
+            .await?;
+        settle().await;
+        let created = chat.actor.signals_of::<ConversationUpdatedSignal>();
+        let conversation = created.into_iter().next().ok_or("no conversation")?;
+        Ok(conversation.conversation.id)
+    }
+
+    async fn send(
+        chat: &mut Chat,
+        conversation_id: &str,
+        body: &str,
+    ) -> Result<String, Box<dyn Error>> {
+        let message = chat
+            .actor
+            .send(SendChatMessage {
+                user_id: "alice".to_string(),
+                conversation_id: conversation_id.to_string(),
+                body: body.to_string(),
+            })
+            .await?
+            .map_err(|e| e.to_string())?;
+        Ok(message.id)
+    }
+
+    // 서버가 메시지를 받았다고 확인해 준 것처럼 실시간 이벤트를 흘린다
+    async fn ack(
+        chat: &mut Chat,
+        conversation_id: &str,
+        message_id: &str,
+    ) -> Result<(), Box<dyn Error>> {
+        let envelope = RealtimeEnvelope {
+            channel: CHAT_CHANNEL.to_string(),
+            kind: "ack".to_string(),
+            data: json!({ "conversation_id": conversation_id, "message_id": message_id }),
+        };
+        chat.events.send(RealtimeEvent::Envelope(envelope))?;
+        settle().await;
+        Ok(())
+    }
+
+    async fn outbox(chat: &mut Chat) -> Result<ChatState, Box<dyn Error>> {
+        let persisted = chat.actor.send(PersistState).await?;
+        let state = persisted.and_then(|persisted| persisted.read::<ChatState>(STATE_VERSION));
+        Ok(state.unwrap_or(ChatState {
+            outbox: Vec::new(),
+            sequence: 0,
+        }))
+    }
+
+    fn bodies(state: &ChatState) -> Vec<&str> {
+        state.outbox.iter().map(|m| m.body.as_str()).collect()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn unsent_messages_survive_a_persist_and_restore() -> Result<(), Box<dyn Error>> {
+        let mut first = start_chat().await;
+        let conversation_id = create_conversation(&mut first).await?;
+        send(&mut first, &conversation_id, "hello").await?;
+        send(&mut first, &conversation_id, "anyone there?").await?;
+        let persisted = first
+            .actor
+            .send(PersistState)
+            .await?
+            .ok_or("nothing persisted")?;
+
+        let mut second = start_chat().await;
+        assert!(second.actor.send(RestoreState(persisted.clone())).await?);
+        let restored = second
+            .actor
+            .send(PersistState)
+            .await?
+            .ok_or("nothing restored")?;
+        assert_eq!(restored.state, persisted.state);
+
+        let state = restored
+            .read::<ChatState>(STATE_VERSION)
+            .ok_or("unreadable")?;
+        assert_eq!(bodies(&state), ["hello", "anyone there?"]);
+        assert_eq!(state.sequence, 3); // 대화 하나와 메시지 둘
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn outbox_from_another_version_is_discarded() -> Result<(), Box<dyn Error>> {
+        let mut chat = start_chat().await;
+        let state = ChatState {
+            outbox: vec![ChatMessage {
+                id: "alice-1-1".to_string(),
+                conversation_id: "conv-1-1".to_string(),
+                sender_id: "alice".to_string(),
+                body: "hello".to_string(),
+                sent_at: 1,
+                status: DeliveryStatus::Pending,
+            }],
+            sequence: 1,
+        };
+        let future = PersistedState::new(STATE_VERSION + 1, &state).ok_or("unserializable")?;
+        assert!(!chat.actor.send(RestoreState(future)).await?);
+        assert!(chat.actor.send(PersistState).await?.is_none());
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn outbox_keeps_send_order_across_acks_and_restores() -> Result<(), Box<dyn Error>> {
+        let mut first = start_chat().await;
+        let conversation_id = create_conversation(&mut first).await?;
+        send(&mut first, &conversation_id, "one").await?;
+        let two = send(&mut first, &conversation_id, "two").await?;
+        send(&mut first, &conversation_id, "three").await?;
+
+        // 가운데 메시지만 확인받아도 남은 메시지의 순서는 그대로다
+        ack(&mut first, &conversation_id, &two).await?;
+        assert_eq!(bodies(&outbox(&mut first).await?), ["one", "three"]);
+        let persisted = first
+            .actor
+            .send(PersistState)
+            .await?
+            .ok_or("nothing persisted")?;
+
+        // 복원한 메시지는 이미 대기 중인 메시지 뒤에 붙고, 두 번 복원해도 겹치지 않는다
+        let mut second = start_chat().await;
+        let other_id = create_conversation(&mut second).await?;
+        send(&mut second, &other_id, "queued here").await?;
+        assert!(second.actor.send(RestoreState(persisted.clone())).await?);
+        assert!(second.actor.send(RestoreState(persisted)).await?);
+        let state = outbox(&mut second).await?;
+        assert_eq!(bodies(&state), ["queued here", "one", "three"]);
+
+        // 복원한 뒤에 보낸 메시지는 맨 뒤에 붙고 아이디도 겹치지 않는다
+        let after = send(&mut second, &other_id, "after restore").await?;
+        let state = outbox(&mut second).await?;
+        assert_eq!(
+            bodies(&state),
+            ["queued here", "one", "three", "after restore"]
+        );
+        let ids: HashSet<&str> = state.outbox.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids.len(), 4);
+        assert!(ids.contains(after.as_str()));
+        Ok(())
+    }
+}
@@ -0,0 +1,209 @@
+use std::{
+    collections::HashMap,
+    sync::{LazyLock, Mutex, MutexGuard, PoisonError},
+    time::Duration,
+};
+
+use crate::study_actors::{
+    messages::{ApiConfig, ApiError, CircuitState},
+    signals::{CircuitStateChangedSignal, EmitSignal},
+};
+
+use super::{Clock, Instant};
+
+// ApiClient 사본과 재시작된 액터가 같은 호스트 상태를 보도록 프로세스 전체에서 공유한다
+static CIRCUITS: LazyLock<Mutex<HashMap<String, Circuit>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Default)]
+struct Circuit {
+    state: CircuitState,
+    failures: u32,
+    opened_at: Option<Instant>,
+    probe_started: Option<Instant>,
+}
+
+// 호스트별 차단기
+// 연속으로 실패하면 쉬는 시간 동안 요청을 보내지 않고 ServiceUnavailable로 바로 실패한다.
+// 쉬는 시간이 지나면 요청 하나만 확인용으로 보내 성공하면 닫고, 실패하면 다시 연다.
+#[derive(Clone)]
+pub struct CircuitBreaker {
+    failure_threshold: u32,
+    cooldown: Duration,
+    clock: Clock, // 쉬는 시간을 재는 시계
+}
+
+impl CircuitBreaker {
+    pub fn new(config: &ApiConfig, clock: Clock) -> Self {
+        Self {
+            failure_threshold: config.circuit_failure_threshold.max(1),
+            cooldown: Duration::from_millis(config.circuit_cooldown_ms),
+            clock,
+        }
+    }
+
+    // 보내도 되면 Ok (반쯤 열린 상태에서는 확인 요청 하나만 통과)
+    pub fn acquire(&self, host: &str) -> Result<(), ApiError> {
+        let mut circuits = circuits();
+        let circuit = circuits.entry(host.to_string()).or_default();
+        let now = self.clock.now();
+        match circuit.state {
+            CircuitState::Closed => Ok(()),
+            CircuitState::Open => {
+                let opened_at = circuit.opened_at.unwrap_or(now);
+                let elapsed = now.saturating_duration_since(opened_at);
+                if elapsed < self.cooldown {
+                    return Err(self.unavailable(host, self.cooldown - elapsed));
+                }
+                circuit.state = CircuitState::HalfOpen;
+                circuit.probe_started = Some(now);
+                emit(host, CircuitState::HalfOpen, None);
+                Ok(())
+            }
+            // 확인 요청이 취소되어 결과가 오지 않으면 쉬는 시간 뒤 다른 요청으로 다시 확인한다
+            CircuitState::HalfOpen => match circuit.probe_started {
+                Some(started) if now.saturating_duration_since(started) < self.cooldown => {
+                    Err(self.unavailable(host, self.cooldown))
+                }
+                _ => {
+                    circuit.probe_started = Some(now);
+                    Ok(())
+                }
+            },
+        }
+    }
+
+    pub fn record_success(&self, host: &str) {
+        let mut circuits = circuits();
+        let Some(circuit) = circuits.get_mut(host) else {
+            return;
+        };
+        let was_closed = circuit.state == CircuitState::Closed;
+        *circuit = Circuit::default();
+        if !was_closed {
+            emit(host, CircuitState::Closed, None);
+        }
+    }
+
+    pub fn record_failure(&self, host: &str) {
+        let mut circuits = circuits();
+        let circuit = circuits.entry(host.to_string()).or_default();
+        circuit.failures += 1;
+        let trips = match circuit.state {
+            CircuitState::Closed => circuit.failures >= self.failure_threshold,
+            CircuitState::HalfOpen => true,
+            CircuitState::Open => false,
+        };
+        if trips {
+            circuit.state = CircuitState::Open;
+            circuit.opened_at = Some(self.clock.now());
+            circuit.probe_started = None;
+            emit(host, CircuitState::Open, Some(self.cooldown));
+        }
+    }
+
+    fn unavailable(&self, host: &str, retry_in: Duration) -> ApiError {
+        ApiError::ServiceUnavailable {
+            host: host.to_string(),
+            retry_in_ms: retry_in.as_millis() as u64,
+        }
+    }
+}
+
+fn circuits() -> MutexGuard<'static, HashMap<String, Circuit>> {
+    CIRCUITS.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn emit(host: &str, state: CircuitState, retry_in: Option<Duration>) {
+    CircuitStateChangedSignal {
+        host: host.to_string(),
+        state,
+        retry_in_ms: retry_in.map(|retry_in| retry_in.as_millis() as u64),
+    }
+    .emit();
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::CircuitBreaker;
+    use crate::study_actors::{
+        actors::Clock,
+        messages::{ApiConfig, ApiError},
+    };
+
+    // 차단기 상태는 프로세스 전체에서 공유하므로 테스트마다 다른 호스트를 쓴다
+    fn breaker() -> CircuitBreaker {
+        let config = ApiConfig {
+            circuit_failure_threshold: 3,
+            circuit_cooldown_ms: 1_000,
+            ..ApiConfig::default()
+        };
+        CircuitBreaker::new(&config, Clock::system())
+    }
+
+    fn is_blocked(breaker: &CircuitBreaker, host: &str) -> bool {
+        matches!(
+            breaker.acquire(host),
+            Err(ApiError::ServiceUnavailable { .. })
+        )
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn circuit_opens_probes_once_and_closes_after_success() {
+        let breaker = breaker();
+        let host = "closes.example.com";
+        for _ in 0..2 {
+            assert!(breaker.acquire(host).is_ok());
+            breaker.record_failure(host);
+        }
+        assert!(breaker.acquire(host).is_ok());
+        breaker.record_failure(host);
+        // 연속 실패가 한도에 닿으면 쉬는 시간 동안 막는다
+        assert!(is_blocked(&breaker, host));
+
+        tokio::time::advance(Duration::from_millis(999)).await;
+        assert!(is_blocked(&breaker, host));
+        tokio::time::advance(Duration::from_millis(1)).await;
+        // 반쯤 열린 상태에서는 확인 요청 하나만 보낸다
+        assert!(breaker.acquire(host).is_ok());
+        assert!(is_blocked(&breaker, host));
+
+        breaker.record_success(host);
+        assert!(breaker.acquire(host).is_ok());
+        assert!(breaker.acquire(host).is_ok());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn failed_probe_reopens_the_circuit() {
+        let breaker = breaker();
+        let host = "reopens.example.com";
+        for _ in 0..3 {
+            breaker.record_failure(host);
+        }
+        tokio::time::advance(Duration::from_secs(1)).await;
+        assert!(breaker.acquire(host).is_ok());
+
+        breaker.record_failure(host);
+        assert!(is_blocked(&breaker, host));
+        tokio::time::advance(Duration::from_secs(1)).await;
+        assert!(breaker.acquire(host).is_ok());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn success_resets_the_failure_count() {
+        let breaker = breaker();
+        let host = "resets.example.com";
+        breaker.record_failure(host);
+        breaker.record_failure(host);
+        breaker.record_success(host);
+        breaker.record_failure(host);
+        breaker.record_failure(host);
+        // 성공 뒤로는 두 번만 실패했으므로 아직 닫혀 있다
+        assert!(breaker.acquire(host).is_ok());
+
+        breaker.record_failure(host);
+        assert!(is_blocked(&breaker, host));
+    }
+}
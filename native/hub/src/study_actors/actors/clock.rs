@@ -0,0 +1,271 @@
+use std::time::Duration;
+
+use crate::study_actors::messages::ScheduledTask;
+
+use super::scheduler::Throttle;
+
+// 웹에는 tokio 시계가 없으므로 브라우저의 performance.now() 기반 Instant를 쓴다
+#[cfg(not(target_family = "wasm"))]
+pub use tokio::time::Instant;
+#[cfg(target_family = "wasm")]
+pub use web_time::Instant;
+
+#[cfg(feature = "simulation")]
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+#[cfg(feature = "simulation")]
+use tokio::sync::oneshot;
+
+// 주기 작업(토큰 만료, 캐시 정리, 네트워크 모니터, 동기화, 재시도)이 쓰는 시계
+// 평소에는 tokio 시계(웹에서는 gloo 타이머)를 쓰고, simulation 기능에서는 VirtualClock을 주입해
+// 몇 시간치 동작을 테스트에서 기다림 없이, 매번 같은 순서로 앞당길 수 있다.
+#[derive(Clone, Default)]
+pub struct Clock {
+    #[cfg(feature = "simulation")]
+    simulated: Option<VirtualClock>,
+}
+
+impl Clock {
+    pub fn system() -> Self {
+        Self::default()
+    }
+
+    pub fn now(&self) -> Instant {
+        #[cfg(feature = "simulation")]
+        if let Some(simulated) = &self.simulated {
+            return simulated.now();
+        }
+        Instant::now()
+    }
+
+    pub async fn sleep(&self, duration: Duration) {
+        self.sleep_until(self.now() + duration).await;
+    }
+
+    pub async fn sleep_until(&self, deadline: Instant) {
+        #[cfg(feature = "simulation")]
+        if let Some(simulated) = &self.simulated {
+            simulated.sleep_until(deadline).await;
+            return;
+        }
+        #[cfg(not(target_family = "wasm"))]
+        tokio::time::sleep_until(deadline).await;
+        // 브라우저 타이머는 Send가 아니지만 웹에서는 한 스레드만 쓴다
+        #[cfg(target_family = "wasm")]
+        send_wrapper::SendWrapper::new(gloo_timers::future::sleep(
+            deadline.saturating_duration_since(Instant::now()),
+        ))
+        .await;
+    }
+
+    // tokio::time::interval처럼 첫 틱은 바로 발생한다
+    pub fn interval(&self, period: Duration) -> Ticker {
+        Ticker {
+            clock: self.clone(),
+            next: self.now(),
+            period: period.max(MIN_PERIOD),
+            throttle: None,
+        }
+    }
+}
+
+// 0초 주기로 설정되어도 쉬지 않고 도는 일이 없도록 한다
+const MIN_PERIOD: Duration = Duration::from_millis(1);
+
+pub struct Ticker {
+    clock: Clock,
+    next: Instant,
+    period: Duration,
+    throttle: Option<Throttle>,
+}
+
+impl Ticker {
+    // 절전 중 스케줄러 정책에 따라 주기를 늘리거나 멈추는 작업으로 지정한다
+    pub fn throttled(mut self, task: ScheduledTask) -> Self {
+        self.throttle = Some(Throttle::new(task));
+        self
+    }
+
+    pub async fn tick(&mut self) -> Instant {
+        self.clock.sleep_until(self.next).await;
+        let tick = self.next;
+        let Some(throttle) = self.throttle.as_mut() else {
+            // tokio 기본 동작과 같이 늦어진 틱은 몰아서 발생시킨다
+            self.next = tick + self.period;
+            return tick;
+        };
+        // 멈췄던 동안 밀린 틱은 몰아서 발생시키지 않고 재개한 시각부터 다시 센다
+        let factor = throttle.wait().await;
+        self.next = self.clock.now().max(tick) + self.period * factor;
+        tick
+    }
+}
+
+// 직접 앞당기는 가상 시계 (simulation 기능)
+// advance()는 목표 시각까지 잠든 작업을 마감 순서대로 하나씩 깨우고,
+// 깨어난 작업이 다음 대기를 등록할 때까지 양보한 뒤 다음 마감으로 넘어간다.
+// 단일 스레드 런타임에서 돌리면 실행 순서가 매번 같다.
+#[cfg(feature = "simulation")]
+#[derive(Clone)]
+pub struct VirtualClock {
+    state: Arc<Mutex<VirtualState>>,
+}
+
+#[cfg(feature = "simulation")]
+struct VirtualState {
+    now: Instant,
+    next_id: u64,
+    // 같은 마감이면 먼저 잠든 작업부터 깨운다
+    sleepers: BTreeMap<(Instant, u64), oneshot::Sender<()>>,
+}
+
+#[cfg(feature = "simulation")]
+impl Default for VirtualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "simulation")]
+impl VirtualClock {
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(VirtualState {
+                now: Instant::now(),
+                next_id: 0,
+                sleepers: BTreeMap::new(),
+            })),
+        }
+    }
+
+    // 액터에 주입할 시계
+    pub fn clock(&self) -> Clock {
+        Clock {
+            simulated: Some(self.clone()),
+        }
+    }
+
+    pub fn now(&self) -> Instant {
+        self.state().now
+    }
+
+    // 대기 중인 작업 수 (모든 주기 작업이 자리를 잡았는지 확인할 때 사용)
+    pub fn pending(&self) -> usize {
+        self.state().sleepers.len()
+    }
+
+    pub async fn advance(&self, duration: Duration) {
+        let target = self.now() + duration;
+        settle().await;
+        loop {
+            let due = {
+                let mut state = self.state();
+                match state.sleepers.first_key_value() {
+                    Some((&(deadline, id), _)) if deadline <= target => {
+                        state.now = state.now.max(deadline);
+                        state.sleepers.remove(&(deadline, id))
+                    }
+                    _ => None,
+                }
+            };
+            let Some(wake) = due else {
+                break;
+            };
+            // 작업이 이미 끝났다면 받을 쪽이 없다
+            let _ = wake.send(());
+            settle().await;
+        }
+        self.state().now = target;
+        settle().await;
+    }
+
+    async fn sleep_until(&self, deadline: Instant) {
+        let woken = {
+            let mut state = self.state();
+            if deadline <= state.now {
+                return;
+            }
+            let (sender, receiver) = oneshot::channel();
+            let id = state.next_id;
+            state.next_id += 1;
+            state.sleepers.insert((deadline, id), sender);
+            receiver
+        };
+        let _ = woken.await;
+    }
+
+    fn state(&self) -> MutexGuard<'_, VirtualState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+// 깨어난 작업들이 한 차례씩 실행될 기회를 준다
+#[cfg(feature = "simulation")]
+async fn settle() {
+    for _ in 0..16 {
+        tokio::task::yield_now().await;
+    }
+}
+
+#[cfg(all(test, feature = "simulation"))]
+mod tests {
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use super::VirtualClock;
+
+    #[tokio::test]
+    async fn interval_ticks_once_per_period_in_virtual_time() {
+        let simulation = VirtualClock::new();
+        let ticks = Arc::new(Mutex::new(0u32));
+        let task = tokio::spawn({
+            let (clock, ticks) = (simulation.clock(), ticks.clone());
+            async move {
+                let mut interval = clock.interval(Duration::from_secs(60));
+                loop {
+                    interval.tick().await;
+                    if let Ok(mut ticks) = ticks.lock() {
+                        *ticks += 1;
+                    }
+                }
+            }
+        });
+
+        // 첫 틱은 즉시, 이후 3시간 동안 분마다 한 번씩
+        simulation.advance(Duration::from_secs(3 * 60 * 60)).await;
+        assert_eq!(ticks.lock().map(|ticks| *ticks).unwrap_or_default(), 181);
+        task.abort();
+    }
+
+    #[tokio::test]
+    async fn sleepers_wake_in_deadline_order() {
+        let simulation = VirtualClock::new();
+        let woken = Arc::new(Mutex::new(Vec::new()));
+        for secs in [30, 10, 20] {
+            let (clock, woken) = (simulation.clock(), woken.clone());
+            tokio::spawn(async move {
+                clock.sleep(Duration::from_secs(secs)).await;
+                if let Ok(mut woken) = woken.lock() {
+                    woken.push(secs);
+                }
+            });
+        }
+
+        simulation.advance(Duration::from_secs(15)).await;
+        assert_eq!(
+            woken.lock().map(|woken| woken.clone()).unwrap_or_default(),
+            [10]
+        );
+
+        simulation.advance(Duration::from_secs(60)).await;
+        assert_eq!(
+            woken.lock().map(|woken| woken.clone()).unwrap_or_default(),
+            [10, 20, 30]
+        );
+        assert_eq!(simulation.pending(), 0);
+    }
+}
@@ -0,0 +1,649 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, time::Duration};
+use tokio::{sync::broadcast, task::JoinSet};
+use yrs::{
+    Doc, GetString, OffsetKind, Options, ReadTxn, StateVector, Text, TextRef, Transact, Update,
+    updates::{decoder::Decode, encoder::Encode},
+};
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        ApplyEdit, CollabError, DataItem, FetchData, FetchMode, MergeRemoteUpdate, OpenDocument,
+        RealtimeEnvelope, RealtimeEvent, SendRealtimeEnvelope, SetItemContent, StoreData, TextEdit,
+        WipeUserData,
+    },
+    signals::{
+        ApplyEditRequest, CloseDocumentRequest, DocumentChangedSignal, DocumentSnapshotSignal,
+        EmitSignal, OpenDocumentRequest, route_dart_signals,
+    },
+};
+
+use super::{DataManagerActor, StorageActor, WebSocketActor};
+
+const COLLAB_CHANNEL: &str = "collab";
+const TEXT_NAME: &str = "content";
+const FLUSH_INTERVAL_SECS: u64 = 5;
+const SEED_CLIENT_ID: u64 = 0;
+
+fn document_key(item_id: &str) -> String {
+    format!("collab/{}", item_id)
+}
+
+// 서버를 거쳐 같은 문서를 연 다른 기기와 주고받는 페이로드 (바이너리는 hex)
+#[derive(Serialize, Deserialize)]
+struct UpdatePayload {
+    item_id: String,
+    update: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SyncPayload {
+    item_id: String,
+    state_vector: String,
+}
+
+fn doc_options() -> Options {
+    Options {
+        offset_kind: OffsetKind::Utf16,
+        ..Options::default()
+    }
+}
+
+// 모든 기기가 같은 client id로 초기 본문을 만들면 업데이트가 바이트 단위로 같아진다.
+// CRDT는 같은 (client, clock) 블록을 한 번만 적용하므로 여러 기기가 처음 열어도 본문이 중복되지 않는다.
+fn seed_update(content: &str) -> Vec<u8> {
+    let doc = Doc::with_options(Options {
+        client_id: SEED_CLIENT_ID,
+        ..doc_options()
+    });
+    let text = doc.get_or_insert_text(TEXT_NAME);
+    let mut txn = doc.transact_mut();
+    text.insert(&mut txn, 0, content);
+    txn.encode_update_v1()
+}
+
+// 공통 접두/접미사를 뺀 한 구간으로 변경을 표현 (UTF-16 단위, 서로게이트 쌍은 나누지 않음)
+fn diff_text(before: &str, after: &str) -> Option<TextEdit> {
+    let before: Vec<u16> = before.encode_utf16().collect();
+    let after: Vec<u16> = after.encode_utf16().collect();
+
+    let mut prefix = before
+        .iter()
+        .zip(&after)
+        .take_while(|(a, b)| a == b)
+        .count();
+    if prefix > 0 && (0xD800..0xDC00).contains(&before[prefix - 1]) {
+        prefix -= 1;
+    }
+
+    let max_suffix = before.len().min(after.len()) - prefix;
+    let mut suffix = before
+        .iter()
+        .rev()
+        .zip(after.iter().rev())
+        .take(max_suffix)
+        .take_while(|(a, b)| a == b)
+        .count();
+    if suffix > 0 && (0xDC00..0xE000).contains(&after[after.len() - suffix]) {
+        suffix -= 1;
+    }
+
+    let deleted = before.len() - prefix - suffix;
+    let inserted = &after[prefix..after.len() - suffix];
+    if deleted == 0 && inserted.is_empty() {
+        return None;
+    }
+    Some(TextEdit {
+        index: prefix as u32,
+        delete: deleted as u32,
+        insert: String::from_utf16_lossy(inserted),
+    })
+}
+
+struct CollabDocument {
+    doc: Doc,
+    text: TextRef,
+    dirty: bool,
+}
+
+impl CollabDocument {
+    fn from_update(update: &[u8]) -> Result<Self, CollabError> {
+        let doc = Doc::with_options(doc_options());
+        let text = doc.get_or_insert_text(TEXT_NAME);
+        let document = Self {
+            doc,
+            text,
+            dirty: false,
+        };
+        document.apply_update(update)?;
+        Ok(document)
+    }
+
+    fn content(&self) -> String {
+        let txn = self.doc.transact();
+        self.text.get_string(&txn)
+    }
+
+    // 전체 편집을 먼저 검증해 일부만 적용되는 일이 없게 한다
+    fn apply_edits(&self, edits: &[TextEdit]) -> Result<Vec<u8>, CollabError> {
+        let mut txn = self.doc.transact_mut();
+        let mut len = self.text.len(&txn);
+        for edit in edits {
+            let end = edit.index.checked_add(edit.delete);
+            if end.is_none_or(|end| end > len) {
+                return Err(format!(
+                    "Edit out of range: {}+{} > {}",
+                    edit.index, edit.delete, len
+                )
+                .into());
+            }
+            len = len - edit.delete + edit.insert.encode_utf16().count() as u32;
+        }
+
+        for edit in edits {
+            if edit.delete > 0 {
+                self.text.remove_range(&mut txn, edit.index, edit.delete);
+            }
+            if !edit.insert.is_empty() {
+                self.text.insert(&mut txn, edit.index, &edit.insert);
+            }
+        }
+        Ok(txn.encode_update_v1())
+    }
+
+    fn apply_update(&self, update: &[u8]) -> Result<(), CollabError> {
+        let update = Update::decode_v1(update)?;
+        let mut txn = self.doc.transact_mut();
+        txn.apply_update(update)?;
+        Ok(())
+    }
+
+    fn state_vector(&self) -> Vec<u8> {
+        self.doc.transact().state_vector().encode_v1()
+    }
+
+    fn diff_since(&self, state_vector: &[u8]) -> Result<Vec<u8>, CollabError> {
+        let state_vector = StateVector::decode_v1(state_vector)?;
+        Ok(self.doc.transact().encode_diff_v1(&state_vector))
+    }
+
+    fn encode_state(&self) -> Vec<u8> {
+        self.doc
+            .transact()
+            .encode_state_as_update_v1(&StateVector::default())
+    }
+}
+
+// 협업 편집 액터
+// DataItem.content를 yrs 텍스트 문서로 관리한다. 로컬 편집은 Dart에서, 원격 업데이트는 WebSocket 액터에서 받아 병합하고,
+// 변경된 문서는 주기적으로 저장소와 항목 본문에 반영한다.
+pub struct CollabActor {
+    documents: HashMap<String, CollabDocument>,
+    pending: HashMap<String, Vec<u8>>, // 연결이 끊겨 보내지 못한 업데이트 (문서별로 병합)
+    storage: Address<StorageActor>,
+    data: Address<DataManagerActor>,
+    websocket: Address<WebSocketActor>,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for CollabActor {}
+
+impl CollabActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        storage: Address<StorageActor>,
+        data: Address<DataManagerActor>,
+        websocket: Address<WebSocketActor>,
+        realtime_events: broadcast::Receiver<RealtimeEvent>,
+    ) -> Self {
+        let mut owned_tasks = route_dart_signals!(
+            self_addr,
+            [OpenDocumentRequest, CloseDocumentRequest, ApplyEditRequest]
+        );
+        owned_tasks.spawn(Self::listen_to_realtime(self_addr.clone(), realtime_events));
+        owned_tasks.spawn(Self::flush_periodically(self_addr.clone()));
+
+        Self {
+            documents: HashMap::new(),
+            pending: HashMap::new(),
+            storage,
+            data,
+            websocket,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    async fn listen_to_realtime(
+        mut self_addr: Address<Self>,
+        mut events: broadcast::Receiver<RealtimeEvent>,
+    ) {
+        loop {
+            match events.recv().await {
+                Ok(event) => {
+                    let _ = self_addr.notify(RealtimeEventReceived(event)).await;
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    debug_print!("Collab skipped {} realtime events", skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    }
+
+    async fn flush_periodically(mut self_addr: Address<Self>) {
+        let mut interval = tokio::time::interval(Duration::from_secs(FLUSH_INTERVAL_SECS));
+        loop {
+            interval.tick().await;
+            let _ = self_addr.notify(FlushDocuments).await;
+        }
+    }
+
+    // 저장된 문서 상태가 없으면 항목 본문으로 시작
+    async fn ensure_open(&mut self, item_id: &str) -> Result<(), CollabError> {
+        if self.documents.contains_key(item_id) {
+            return Ok(());
+        }
+
+        let request = FetchData {
+            key: document_key(item_id),
+            user_id: None,
+            mode: FetchMode::CacheFirst,
+        };
+        let state = match self.storage.send(request).await {
+            Ok(Ok(bytes)) => bytes,
+            _ => {
+                let request = FetchData {
+                    key: format!("items/{}", item_id),
+                    user_id: None,
+                    mode: FetchMode::CacheFirst,
+                };
+                let bytes = self.data.send(request).await??;
+                let item: DataItem = serde_json::from_slice(&bytes)?;
+                seed_update(&item.content).into()
+            }
+        };
+        let document = CollabDocument::from_update(&state)?;
+
+        // 다른 기기에 이 문서에서 빠진 변경을 요청
+        let sync = SyncPayload {
+            item_id: item_id.to_string(),
+            state_vector: hex::encode(document.state_vector()),
+        };
+        self.documents.insert(item_id.to_string(), document);
+        let _ = self.send_envelope("sync", &sync).await;
+        Ok(())
+    }
+
+    fn document(&self, item_id: &str) -> Result<&CollabDocument, CollabError> {
+        self.documents
+            .get(item_id)
+            .ok_or_else(|| format!("Document not open: {}", item_id).into())
+    }
+
+    async fn send_envelope<T: Serialize>(
+        &mut self,
+        kind: &str,
+        data: &T,
+    ) -> Result<(), CollabError> {
+        let envelope = RealtimeEnvelope {
+            channel: COLLAB_CHANNEL.to_string(),
+            kind: kind.to_string(),
+            data: serde_json::to_value(data)?,
+        };
+        self.websocket
+            .send(SendRealtimeEnvelope(envelope))
+            .await??;
+        Ok(())
+    }
+
+    // 보내지 못한 업데이트와 합쳐 전송하고, 실패하면 다음 연결 때까지 보관
+    async fn broadcast_update(
+        &mut self,
+        item_id: &str,
+        update: Vec<u8>,
+    ) -> Result<(), CollabError> {
+        let update = match self.pending.remove(item_id) {
+            Some(pending) => yrs::merge_updates_v1([pending.as_slice(), update.as_slice()])?,
+            None => update,
+        };
+        let payload = UpdatePayload {
+            item_id: item_id.to_string(),
+            update: hex::encode(&update),
+        };
+        if self.send_envelope("update", &payload).await.is_err() {
+            self.pending.insert(item_id.to_string(), update);
+        }
+        Ok(())
+    }
+
+    async fn flush_document(&mut self, item_id: &str) -> Result<(), CollabError> {
+        let Some(document) = self.documents.get_mut(item_id) else {
+            return Ok(());
+        };
+        if !document.dirty {
+            return Ok(());
+        }
+        document.dirty = false;
+        let state = document.encode_state();
+        let content = document.content();
+
+        self.storage
+            .send(StoreData {
+                key: document_key(item_id),
+                data: state,
+                user_id: None,
+                ttl: None,
+            })
+            .await??;
+        self.data
+            .send(SetItemContent {
+                item_id: item_id.to_string(),
+                content,
+            })
+            .await??;
+        Ok(())
+    }
+
+    async fn handle_envelope(
+        &mut self,
+        envelope: RealtimeEnvelope,
+        ctx: &Context<Self>,
+    ) -> Result<(), CollabError> {
+        match envelope.kind.as_str() {
+            "update" => {
+                let payload: UpdatePayload = serde_json::from_value(envelope.data)?;
+                // 열려 있지 않은 문서는 나중에 열 때 sync로 받는다
+                if !self.documents.contains_key(&payload.item_id) {
+                    return Ok(());
+                }
+                let request = MergeRemoteUpdate {
+                    item_id: payload.item_id.clone(),
+                    update: hex::decode(&payload.update)?,
+                };
+                let edits = self.handle(request, ctx).await?;
+                if !edits.is_empty() {
+                    DocumentChangedSignal {
+                        item_id: payload.item_id,
+                        edits,
+                    }
+                    .emit();
+                }
+            }
+            "sync" => {
+                let payload: SyncPayload = serde_json::from_value(envelope.data)?;
+                let Some(document) = self.documents.get(&payload.item_id) else {
+                    return Ok(());
+                };
+                let diff = document.diff_since(&hex::decode(&payload.state_vector)?)?;
+                let reply = UpdatePayload {
+                    item_id: payload.item_id,
+                    update: hex::encode(diff),
+                };
+                self.send_envelope("update", &reply).await?;
+            }
+            other => debug_print!("Unhandled collab envelope kind: {}", other),
+        }
+        Ok(())
+    }
+
+    // 재연결 시 보관한 업데이트를 보내고, 열린 문서마다 빠진 변경을 요청
+    async fn resync(&mut self) {
+        for (item_id, update) in std::mem::take(&mut self.pending) {
+            if let Err(e) = self.broadcast_update(&item_id, update).await {
+                debug_print!("Failed to resend collab update for {}: {}", item_id, e);
+            }
+        }
+
+        let syncs: Vec<SyncPayload> = self
+            .documents
+            .iter()
+            .map(|(item_id, document)| SyncPayload {
+                item_id: item_id.clone(),
+                state_vector: hex::encode(document.state_vector()),
+            })
+            .collect();
+        for sync in syncs {
+            if self.send_envelope("sync", &sync).await.is_err() {
+                break;
+            }
+        }
+    }
+}
+
+// 내부 메시지 정의
+struct FlushDocuments;
+struct RealtimeEventReceived(RealtimeEvent);
+
+#[async_trait]
+impl Notifiable<FlushDocuments> for CollabActor {
+    async fn notify(&mut self, _: FlushDocuments, _: &Context<Self>) {
+        let item_ids: Vec<String> = self.documents.keys().cloned().collect();
+        for item_id in item_ids {
+            if let Err(e) = self.flush_document(&item_id).await {
+                debug_print!("Failed to save collab document {}: {}", item_id, e);
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Notifiable<RealtimeEventReceived> for CollabActor {
+    async fn notify(&mut self, msg: RealtimeEventReceived, ctx: &Context<Self>) {
+        match msg.0 {
+            RealtimeEvent::Connected => self.resync().await,
+            RealtimeEvent::Disconnected => {}
+            RealtimeEvent::Envelope(envelope) if envelope.channel == COLLAB_CHANNEL => {
+                if let Err(e) = self.handle_envelope(envelope, ctx).await {
+                    debug_print!("Invalid collab envelope: {}", e);
+                }
+            }
+            RealtimeEvent::Envelope(_) => {}
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<OpenDocument> for CollabActor {
+    type Result = Result<String, CollabError>;
+
+    async fn handle(&mut self, msg: OpenDocument, _: &Context<Self>) -> Self::Result {
+        self.ensure_open(&msg.item_id).await?;
+        Ok(self.document(&msg.item_id)?.content())
+    }
+}
+
+#[async_trait]
+impl Handler<ApplyEdit> for CollabActor {
+    type Result = Result<(), CollabError>;
+
+    async fn handle(&mut self, msg: ApplyEdit, _: &Context<Self>) -> Self::Result {
+        self.ensure_open(&msg.item_id).await?;
+        let update = self.document(&msg.item_id)?.apply_edits(&msg.edits)?;
+        if let Some(document) = self.documents.get_mut(&msg.item_id) {
+            document.dirty = true;
+        }
+        self.broadcast_update(&msg.item_id, update).await
+    }
+}
+
+#[async_trait]
+impl Handler<MergeRemoteUpdate> for CollabActor {
+    type Result = Result<Vec<TextEdit>, CollabError>;
+
+    async fn handle(&mut self, msg: MergeRemoteUpdate, _: &Context<Self>) -> Self::Result {
+        let document = self
+            .documents
+            .get_mut(&msg.item_id)
+            .ok_or_else(|| format!("Document not open: {}", msg.item_id))?;
+
+        let before = document.content();
+        document.apply_update(&msg.update)?;
+        let after = document.content();
+        if before == after {
+            return Ok(Vec::new());
+        }
+        document.dirty = true;
+        Ok(diff_text(&before, &after).into_iter().collect())
+    }
+}
+
+#[async_trait]
+impl Handler<WipeUserData> for CollabActor {
+    type Result = Result<(), CollabError>;
+
+    async fn handle(&mut self, _: WipeUserData, _: &Context<Self>) -> Self::Result {
+        self.documents.clear();
+        self.pending.clear();
+        Ok(())
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<OpenDocumentRequest> for CollabActor {
+    async fn notify(&mut self, msg: OpenDocumentRequest, ctx: &Context<Self>) {
+        let item_id = msg.item_id.clone();
+        let result = self
+            .handle(
+                OpenDocument {
+                    item_id: msg.item_id,
+                },
+                ctx,
+            )
+            .await;
+
+        DocumentSnapshotSignal {
+            item_id,
+            content: result.as_ref().cloned().unwrap_or_default(),
+            error: result.err().map(|e| e.to_string()),
+        }
+        .emit();
+    }
+}
+
+#[async_trait]
+impl Notifiable<CloseDocumentRequest> for CollabActor {
+    async fn notify(&mut self, msg: CloseDocumentRequest, _: &Context<Self>) {
+        if let Err(e) = self.flush_document(&msg.item_id).await {
+            debug_print!("Failed to save collab document {}: {}", msg.item_id, e);
+        }
+        self.documents.remove(&msg.item_id);
+    }
+}
+
+#[async_trait]
+impl Notifiable<ApplyEditRequest> for CollabActor {
+    async fn notify(&mut self, msg: ApplyEditRequest, ctx: &Context<Self>) {
+        let item_id = msg.item_id.clone();
+        let result = self
+            .handle(
+                ApplyEdit {
+                    item_id: msg.item_id,
+                    edits: msg.edits,
+                },
+                ctx,
+            )
+            .await;
+
+        // 거부된 편집은 Dart 쪽 본문이 어긋났다는 뜻이므로 현재 본문을 다시 보낸다
+        if let Err(e) = result {
+            DocumentSnapshotSignal {
+                content: self
+                    .document(&item_id)
+                    .map(CollabDocument::content)
+                    .unwrap_or_default(),
+                item_id,
+                error: Some(e.to_string()),
+            }
+            .emit();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CollabDocument, CollabError, TextEdit, diff_text, seed_update};
+
+    fn edit(index: u32, delete: u32, insert: &str) -> TextEdit {
+        TextEdit {
+            index,
+            delete,
+            insert: insert.to_string(),
+        }
+    }
+
+    #[test]
+    fn concurrent_edits_converge_in_any_order() -> Result<(), CollabError> {
+        // 두 기기가 따로 처음 열어도 초기 본문은 한 번만 들어간다
+        let seed = seed_update("hello world");
+        let phone = CollabDocument::from_update(&seed)?;
+        let laptop = CollabDocument::from_update(&seed)?;
+        phone.apply_update(&seed_update("hello world"))?;
+
+        let from_phone = phone.apply_edits(&[edit(0, 5, "goodbye")])?;
+        let from_laptop = laptop.apply_edits(&[edit(11, 0, "!"), edit(6, 5, "there")])?;
+        phone.apply_update(&from_laptop)?;
+        laptop.apply_update(&from_phone)?;
+        // 같은 업데이트를 다시 받아도 바뀌지 않는다
+        laptop.apply_update(&from_phone)?;
+
+        assert_eq!(phone.content(), "goodbye there!");
+        assert_eq!(laptop.content(), phone.content());
+        Ok(())
+    }
+
+    #[test]
+    fn state_vector_diff_catches_up_an_offline_device() -> Result<(), CollabError> {
+        let seed = seed_update("notes");
+        let online = CollabDocument::from_update(&seed)?;
+        let offline = CollabDocument::from_update(&seed)?;
+        online.apply_edits(&[edit(5, 0, " for monday")])?;
+        offline.apply_edits(&[edit(0, 0, "my ")])?;
+
+        // 재연결하면 서로 상대가 모르는 부분만 주고받는다
+        let missing_offline = online.diff_since(&offline.state_vector())?;
+        let missing_online = offline.diff_since(&online.state_vector())?;
+        offline.apply_update(&missing_offline)?;
+        online.apply_update(&missing_online)?;
+        assert_eq!(online.content(), "my notes for monday");
+        assert_eq!(offline.content(), online.content());
+
+        // 저장해 둔 상태로 다시 열어도 같은 문서다
+        let reopened = CollabDocument::from_update(&online.encode_state())?;
+        assert_eq!(reopened.content(), online.content());
+        Ok(())
+    }
+
+    #[test]
+    fn out_of_range_edits_leave_the_document_untouched() -> Result<(), CollabError> {
+        let document = CollabDocument::from_update(&seed_update("abc"))?;
+        assert!(
+            document
+                .apply_edits(&[edit(0, 1, "x"), edit(2, 5, "")])
+                .is_err()
+        );
+        assert_eq!(document.content(), "abc");
+        Ok(())
+    }
+
+    #[test]
+    fn text_diff_does_not_split_surrogate_pairs() -> Result<(), CollabError> {
+        let before = "study 📘 notes";
+        let after = "study 📗 notes";
+        let change = diff_text(before, after).ok_or("no change")?;
+        assert_eq!(change.insert, "📗");
+        assert_eq!(change.delete, 2);
+
+        let document = CollabDocument::from_update(&seed_update(before))?;
+        document.apply_edits(&[change])?;
+        assert_eq!(document.content(), after);
+        assert!(diff_text(after, after).is_none());
+        Ok(())
+    }
+}
@@ -0,0 +1,734 @@
+use async_trait::async_trait;
+use hmac::{Hmac, Mac};
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler},
+};
+use serde::Serialize;
+use sha2::Sha256;
+#[cfg(unix)]
+use std::path::Path;
+use std::{collections::HashMap, net::SocketAddr, time::Duration};
+#[cfg(unix)]
+use tokio::net::UnixListener;
+use tokio::{
+    io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader},
+    net::TcpListener,
+    task::JoinSet,
+};
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        AppEvent, CommandChannelConfig, CommandError, CommandReceipt, FetchPagedRemote,
+        InboundCommand, ResolveDeepLink, SignedCommand, StoreData, WipeOrigin,
+    },
+    signals::{CommandChannelStatusSignal, EmitSignal, NavigateSignal},
+};
+
+use super::{DataManagerActor, EventBus, RouterActor, StorageActor, TrustedClock};
+
+type HmacSha256 = Hmac<Sha256>;
+
+const COMMAND_PATH: &str = "/commands";
+const MAX_HEADER_BYTES: u64 = 8 * 1024;
+const MAX_BODY_BYTES: usize = 64 * 1024;
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+// 감사 기록 키: audit/commands/{밀리초}-{순번}
+const AUDIT_PREFIX: &str = "audit/commands/";
+
+// 감사 기록 한 건 (서명이 틀린 요청도 남긴다)
+#[derive(Serialize)]
+struct AuditRecord<'a> {
+    at_ms: i64,
+    peer: &'a str,
+    command: Option<&'a str>,
+    outcome: String,
+}
+
+// 로컬 명령 채널 액터
+// 동반 CLI 같은 같은 기기의 프로그램이 서명한 명령을 받아 액터 메시지로 바꿔 전달한다.
+// TCP는 루프백 주소에만 열고, 허용 목록에 없는 명령은 서명이 맞아도 거부한다.
+pub struct CommandChannelActor {
+    config: CommandChannelConfig,
+    key: Vec<u8>,
+    clock: TrustedClock,
+    router: Address<RouterActor>,
+    data: Address<DataManagerActor>,
+    storage: Address<StorageActor>,
+    event_bus: EventBus,
+    seen: HashMap<String, u64>, // 허용 오차 안에서 이미 받은 서명 → 서명 시각
+    audit_seq: u64,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for CommandChannelActor {}
+
+impl CommandChannelActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        config: CommandChannelConfig,
+        clock: TrustedClock,
+        router: Address<RouterActor>,
+        data: Address<DataManagerActor>,
+        storage: Address<StorageActor>,
+        event_bus: EventBus,
+    ) -> Self {
+        let mut owned_tasks = JoinSet::new();
+        let key = config
+            .secret
+            .as_deref()
+            .and_then(|secret| hex::decode(secret.trim()).ok())
+            .filter(|key| !key.is_empty());
+        match &key {
+            Some(_) => {
+                owned_tasks.spawn(Self::listen(self_addr, config.listen.clone()));
+            }
+            None => report_failure("the secret is missing or not valid hex".to_string()),
+        }
+
+        Self {
+            config,
+            key: key.unwrap_or_default(),
+            clock,
+            router,
+            data,
+            storage,
+            event_bus,
+            seen: HashMap::new(),
+            audit_seq: 0,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    async fn listen(self_addr: Address<Self>, listen: String) {
+        let mut connections = JoinSet::new();
+
+        #[cfg(unix)]
+        if let Some(path) = listen.strip_prefix("unix:") {
+            let listener = match bind_unix_socket(Path::new(path)) {
+                Ok(listener) => listener,
+                Err(e) => {
+                    report_failure(format!("Failed to bind {}: {}", listen, e));
+                    return;
+                }
+            };
+            report_listening(&listen);
+            loop {
+                match listener.accept().await {
+                    Ok((stream, _)) => {
+                        connections.spawn(Self::serve(self_addr.clone(), stream, listen.clone()));
+                    }
+                    Err(e) => debug_print!("Command channel accept failed: {}", e),
+                }
+                while connections.try_join_next().is_some() {}
+            }
+        }
+
+        let local_only = listen
+            .parse::<SocketAddr>()
+            .is_ok_and(|addr| addr.ip().is_loopback());
+        if !local_only {
+            report_failure(format!(
+                "{} is not a loopback address or a unix: socket path",
+                listen
+            ));
+            return;
+        }
+        let listener = match TcpListener::bind(&listen).await {
+            Ok(listener) => listener,
+            Err(e) => {
+                report_failure(format!("Failed to bind {}: {}", listen, e));
+                return;
+            }
+        };
+        report_listening(&listen);
+        loop {
+            match listener.accept().await {
+                Ok((stream, peer)) => {
+                    connections.spawn(Self::serve(self_addr.clone(), stream, peer.to_string()));
+                }
+                Err(e) => debug_print!("Command channel accept failed: {}", e),
+            }
+            while connections.try_join_next().is_some() {}
+        }
+    }
+
+    // 연결 하나에서 요청 하나를 받아 처리하고 닫는다
+    async fn serve<S: AsyncRead + AsyncWrite + Unpin>(
+        mut self_addr: Address<Self>,
+        mut stream: S,
+        peer: String,
+    ) {
+        let result =
+            match tokio::time::timeout(REQUEST_TIMEOUT, read_request(&mut stream, peer)).await {
+                Ok(Ok(request)) => match self_addr.send(request).await {
+                    Ok(result) => result,
+                    Err(e) => Err(CommandError::Unavailable(e.to_string())),
+                },
+                Ok(Err(e)) => Err(e),
+                Err(_) => Err(CommandError::Malformed("request timed out".to_string())),
+            };
+
+        let (status, body) = match result {
+            Ok(receipt) => (200, serde_json::to_vec(&receipt).unwrap_or_default()),
+            Err(e) => {
+                let body = serde_json::json!({ "error": e.to_string() });
+                (e.status(), body.to_string().into_bytes())
+            }
+        };
+        if let Err(e) = write_response(&mut stream, status, &body).await {
+            debug_print!("Failed to answer command request: {}", e);
+        }
+    }
+
+    // 서명, 시각, 재전송 여부를 확인한 뒤 명령을 꺼낸다
+    fn verify(&mut self, msg: &SignedCommand) -> Result<InboundCommand, CommandError> {
+        if !signature_matches(&self.key, &msg.timestamp, &msg.body, &msg.signature) {
+            return Err(CommandError::BadSignature);
+        }
+
+        let now = self.clock.trusted_now();
+        let signed_at: u64 = msg
+            .timestamp
+            .trim()
+            .parse()
+            .map_err(|_| CommandError::Stale)?;
+        if now.abs_diff(signed_at) > self.config.max_skew_secs {
+            return Err(CommandError::Stale);
+        }
+        // 허용 오차가 지난 서명은 위의 시각 확인에서 걸리므로 더 기억할 필요가 없다
+        let window = self.config.max_skew_secs;
+        self.seen
+            .retain(|_, signed_at| now.abs_diff(*signed_at) <= window);
+        // 접두사나 대소문자만 바꾼 같은 서명도 재전송으로 본다
+        let signature = msg.signature.trim();
+        let signature = signature.strip_prefix("sha256=").unwrap_or(signature);
+        if self
+            .seen
+            .insert(signature.to_ascii_lowercase(), signed_at)
+            .is_some()
+        {
+            return Err(CommandError::Replayed);
+        }
+
+        serde_json::from_slice(&msg.body).map_err(|e| CommandError::Malformed(e.to_string()))
+    }
+
+    async fn dispatch(&mut self, command: InboundCommand) -> Result<CommandReceipt, CommandError> {
+        let name = command.name().to_string();
+        let detail = match command {
+            InboundCommand::OpenDeepLink { uri } => {
+                let navigation = self
+                    .router
+                    .send(ResolveDeepLink { uri })
+                    .await
+                    .map_err(|e| CommandError::Unavailable(e.to_string()))?
+                    .map_err(|e| CommandError::Rejected(e.to_string()))?;
+                let route = navigation.route.clone();
+                NavigateSignal {
+                    route: navigation.route,
+                    args: navigation.args,
+                }
+                .emit();
+                Some(route)
+            }
+            InboundCommand::RefreshRemote { user_id, page_size } => {
+                self.data
+                    .notify(FetchPagedRemote { user_id, page_size })
+                    .await
+                    .map_err(|e| CommandError::Unavailable(e.to_string()))?;
+                None
+            }
+            InboundCommand::RemoteWipe { reason } => {
+                self.event_bus.publish(AppEvent::RemoteWipeRequested {
+                    origin: WipeOrigin::User,
+                    reason,
+                });
+                None
+            }
+        };
+        Ok(CommandReceipt {
+            command: name,
+            detail,
+        })
+    }
+
+    async fn audit(&mut self, peer: &str, command: Option<&str>, error: Option<&CommandError>) {
+        let at_ms = self.clock.now_millis();
+        let outcome = match error {
+            Some(e) => e.to_string(),
+            None => "ok".to_string(),
+        };
+        debug_print!(
+            "Command channel: peer={} command={} outcome={}",
+            peer,
+            command.unwrap_or("-"),
+            outcome
+        );
+
+        self.audit_seq += 1;
+        let record = AuditRecord {
+            at_ms,
+            peer,
+            command,
+            outcome,
+        };
+        let Ok(data) = serde_json::to_vec(&record) else {
+            return;
+        };
+        let stored = self
+            .storage
+            .send(StoreData {
+                key: format!("{}{:013}-{:06}", AUDIT_PREFIX, at_ms, self.audit_seq),
+                data,
+                user_id: None,
+                ttl: None,
+            })
+            .await;
+        if !matches!(stored, Ok(Ok(()))) {
+            debug_print!("Failed to store command audit record");
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<SignedCommand> for CommandChannelActor {
+    type Result = Result<CommandReceipt, CommandError>;
+
+    async fn handle(&mut self, msg: SignedCommand, _: &Context<Self>) -> Self::Result {
+        let command = match self.verify(&msg) {
+            Ok(command) => command,
+            Err(e) => {
+                self.audit(&msg.peer, None, Some(&e)).await;
+                return Err(e);
+            }
+        };
+
+        let name = command.name();
+        let result = if self
+            .config
+            .allowed_commands
+            .iter()
+            .any(|allowed| allowed == name)
+        {
+            self.dispatch(command).await
+        } else {
+            Err(CommandError::NotAllowed(name.to_string()))
+        };
+        self.audit(&msg.peer, Some(name), result.as_ref().err())
+            .await;
+        result
+    }
+}
+
+// 채널을 연 주소나 열지 못한 이유를 Dart에 알린다 (설정에서 켰는데 조용히 꺼져 있지 않도록)
+fn report_listening(listen: &str) {
+    debug_print!("Command channel listening on {}", listen);
+    CommandChannelStatusSignal {
+        listening: Some(listen.to_string()),
+        error: None,
+    }
+    .emit();
+}
+
+fn report_failure(error: String) {
+    debug_print!("Command channel is not listening: {}", error);
+    CommandChannelStatusSignal {
+        listening: None,
+        error: Some(error),
+    }
+    .emit();
+}
+
+// 소켓 파일을 만든다 (앱을 실행한 사용자만 접속할 수 있다)
+// 0700 임시 디렉터리 안에서 만들고 권한을 줄인 뒤 제자리에 링크하므로, 권한이 넓은 순간에도
+// 다른 사용자가 접속할 수 없다. 링크는 이미 파일이 있으면 실패하므로 그 사이 생긴 파일도 덮어쓰지 않는다.
+// 이전 실행이 남긴 소켓만 지우고, 소켓이 아닌 파일이 있으면 건드리지 않고 오류를 돌려준다.
+#[cfg(unix)]
+fn bind_unix_socket(path: &Path) -> std::io::Result<UnixListener> {
+    use std::os::unix::fs::{DirBuilderExt, FileTypeExt, PermissionsExt};
+
+    let invalid = |message: String| std::io::Error::new(std::io::ErrorKind::InvalidInput, message);
+    if !path.is_absolute() {
+        return Err(invalid(format!(
+            "{} is not an absolute path",
+            path.display()
+        )));
+    }
+    let (Some(parent), Some(name)) = (path.parent(), path.file_name()) else {
+        return Err(invalid(format!("{} does not name a file", path.display())));
+    };
+    match std::fs::symlink_metadata(path) {
+        Ok(metadata) if metadata.file_type().is_socket() => std::fs::remove_file(path)?,
+        Ok(_) => {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::AlreadyExists,
+                format!("{} exists and is not a socket", path.display()),
+            ));
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e),
+    }
+
+    let staging = parent.join(format!(
+        ".{}.{}",
+        name.to_string_lossy(),
+        std::process::id()
+    ));
+    std::fs::DirBuilder::new().mode(0o700).create(&staging)?;
+    let staged = staging.join("socket");
+    let bound = UnixListener::bind(&staged).and_then(|listener| {
+        std::fs::set_permissions(&staged, std::fs::Permissions::from_mode(0o600))?;
+        std::fs::hard_link(&staged, path)?;
+        Ok(listener)
+    });
+    let _ = std::fs::remove_file(&staged);
+    let _ = std::fs::remove_dir(&staging);
+    bound
+}
+
+// hex(HMAC-SHA256(key, "{timestamp}.{body}")) 비교 (상수 시간)
+fn signature_matches(key: &[u8], timestamp: &str, body: &[u8], signature: &str) -> bool {
+    if key.is_empty() {
+        return false;
+    }
+    let signature = signature.trim();
+    let signature = signature.strip_prefix("sha256=").unwrap_or(signature);
+    let (Ok(expected), Ok(mut mac)) = (hex::decode(signature), HmacSha256::new_from_slice(key))
+    else {
+        return false;
+    };
+    mac.update(timestamp.trim().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    mac.verify_slice(&expected).is_ok()
+}
+
+// POST /commands 요청의 서명 헤더와 본문을 읽는다 (X-Timestamp, X-Signature, Content-Length)
+async fn read_request<S: AsyncRead + Unpin>(
+    stream: &mut S,
+    peer: String,
+) -> Result<SignedCommand, CommandError> {
+    let malformed = |e: std::io::Error| CommandError::Malformed(e.to_string());
+    let mut reader = BufReader::new(stream);
+    let mut request = SignedCommand {
+        peer,
+        timestamp: String::new(),
+        signature: String::new(),
+        body: Vec::new(),
+    };
+    let mut content_length = None;
+    {
+        let mut head = (&mut reader).take(MAX_HEADER_BYTES);
+        let mut line = String::new();
+        head.read_line(&mut line).await.map_err(malformed)?;
+        let mut parts = line.split_whitespace();
+        if (parts.next(), parts.next()) != (Some("POST"), Some(COMMAND_PATH)) {
+            return Err(CommandError::Malformed(format!(
+                "expected POST {}",
+                COMMAND_PATH
+            )));
+        }
+
+        loop {
+            line.clear();
+            if head.read_line(&mut line).await.map_err(malformed)? == 0 {
+                return Err(CommandError::Malformed("incomplete headers".to_string()));
+            }
+            let header = line.trim_end();
+            if header.is_empty() {
+                break;
+            }
+            let Some((name, value)) = header.split_once(':') else {
+                return Err(CommandError::Malformed(format!("bad header: {}", header)));
+            };
+            let value = value.trim().to_string();
+            match name.trim().to_ascii_lowercase().as_str() {
+                "content-length" => content_length = value.parse::<usize>().ok(),
+                "x-timestamp" => request.timestamp = value,
+                "x-signature" => request.signature = value,
+                _ => {}
+            }
+        }
+    }
+
+    let length = content_length
+        .ok_or_else(|| CommandError::Malformed("missing Content-Length".to_string()))?;
+    if length > MAX_BODY_BYTES {
+        return Err(CommandError::Malformed("body too large".to_string()));
+    }
+    request.body = vec![0; length];
+    reader
+        .read_exact(&mut request.body)
+        .await
+        .map_err(malformed)?;
+    Ok(request)
+}
+
+async fn write_response<S: AsyncWrite + Unpin>(
+    stream: &mut S,
+    status: u16,
+    body: &[u8],
+) -> std::io::Result<()> {
+    let reason = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        409 => "Conflict",
+        422 => "Unprocessable Entity",
+        503 => "Service Unavailable",
+        _ => "Internal Server Error",
+    };
+    let head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        status,
+        reason,
+        body.len()
+    );
+    stream.write_all(head.as_bytes()).await?;
+    stream.write_all(body).await?;
+    stream.shutdown().await
+}
+
+#[cfg(test)]
+mod tests {
+    use hmac::Mac;
+    use messages::prelude::Context;
+    use std::{error::Error, sync::Arc};
+    use tokio::io::AsyncWriteExt;
+
+    use super::{AUDIT_PREFIX, CommandChannelActor, HmacSha256, read_request, signature_matches};
+    use crate::study_actors::{
+        actors::{EventBus, StorageActor, TrustedClock},
+        messages::{AppEvent, CommandChannelConfig, CommandError, ScanPrefix, SignedCommand},
+        signals::CommandChannelStatusSignal,
+        storage::MemoryStorage,
+        test_support::{TestActorHarness, settle},
+    };
+
+    const KEY: &[u8] = b"companion-secret";
+
+    fn sign(timestamp: u64, body: &[u8]) -> Result<SignedCommand, Box<dyn Error>> {
+        let mut mac = HmacSha256::new_from_slice(KEY)?;
+        mac.update(format!("{}.", timestamp).as_bytes());
+        mac.update(body);
+        Ok(SignedCommand {
+            peer: "test".to_string(),
+            timestamp: timestamp.to_string(),
+            signature: hex::encode(mac.finalize().into_bytes()),
+            body: body.to_vec(),
+        })
+    }
+
+    fn start_channel(
+        listen: &str,
+        storage: &TestActorHarness<StorageActor>,
+        event_bus: EventBus,
+    ) -> TestActorHarness<CommandChannelActor> {
+        let config = CommandChannelConfig {
+            enabled: true,
+            listen: listen.to_string(),
+            secret: Some(hex::encode(KEY)),
+            allowed_commands: vec!["remote_wipe".to_string()],
+            max_skew_secs: 300,
+        };
+        TestActorHarness::start(|addr| {
+            CommandChannelActor::new(
+                addr,
+                config,
+                TrustedClock::new(),
+                Context::new().address(),
+                Context::new().address(),
+                storage.addr(),
+                event_bus,
+            )
+        })
+    }
+
+    #[tokio::test]
+    async fn reads_signed_request() -> Result<(), Box<dyn Error>> {
+        let key = b"companion-secret";
+        let body = br#"{"type":"open_deep_link","uri":"rinfapp://items/1"}"#;
+        let mut mac = HmacSha256::new_from_slice(key)?;
+        mac.update(b"1700000000.");
+        mac.update(body);
+        let signature = format!("sha256={}", hex::encode(mac.finalize().into_bytes()));
+
+        let (mut client, mut server) = tokio::io::duplex(4096);
+        let head = format!(
+            "POST /commands HTTP/1.1\r\nX-Timestamp: 1700000000\r\nX-Signature: {}\r\nContent-Length: {}\r\n\r\n",
+            signature,
+            body.len()
+        );
+        client.write_all(head.as_bytes()).await?;
+        client.write_all(body).await?;
+
+        let request = read_request(&mut server, "test".to_string()).await?;
+        assert_eq!(request.body, body);
+        assert!(signature_matches(
+            key,
+            &request.timestamp,
+            &request.body,
+            &request.signature
+        ));
+        // 다른 시각이나 키로는 맞지 않는다
+        assert!(!signature_matches(
+            key,
+            "1700000001",
+            &request.body,
+            &request.signature
+        ));
+        assert!(!signature_matches(
+            b"other",
+            &request.timestamp,
+            &request.body,
+            &request.signature
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn stale_replayed_and_disallowed_commands_are_rejected_and_audited()
+    -> Result<(), Box<dyn Error>> {
+        let mut storage =
+            TestActorHarness::start(|_| StorageActor::new(Arc::new(MemoryStorage::new())));
+        let event_bus = EventBus::new();
+        let mut published = event_bus.subscribe();
+        let mut channel = start_channel("127.0.0.1:0", &storage, event_bus);
+        let now = TrustedClock::new().trusted_now();
+        let wipe = br#"{"type":"remote_wipe","reason":"lost"}"#;
+
+        // 허용 오차(300초)를 넘긴 서명
+        let stale = channel.send(sign(now - 301, wipe)?).await?;
+        assert!(matches!(stale, Err(CommandError::Stale)));
+
+        let accepted = channel.send(sign(now, wipe)?).await?;
+        assert_eq!(accepted?.command, "remote_wipe");
+        assert!(matches!(
+            published.try_recv()?,
+            AppEvent::RemoteWipeRequested { .. }
+        ));
+
+        // 같은 서명은 접두사나 대소문자를 바꿔도 다시 받지 않는다
+        let replayed = channel.send(sign(now, wipe)?).await?;
+        assert!(matches!(replayed, Err(CommandError::Replayed)));
+        let mut prefixed = sign(now, wipe)?;
+        prefixed.signature = format!("sha256={}", prefixed.signature.to_ascii_uppercase());
+        let replayed = channel.send(prefixed).await?;
+        assert!(matches!(replayed, Err(CommandError::Replayed)));
+
+        // 서명이 맞아도 허용 목록에 없는 명령은 실행하지 않는다
+        let link = br#"{"type":"open_deep_link","uri":"rinfapp://items/1"}"#;
+        let disallowed = channel.send(sign(now, link)?).await?;
+        assert!(
+            matches!(disallowed, Err(CommandError::NotAllowed(name)) if name == "open_deep_link")
+        );
+        assert!(published.try_recv().is_err());
+
+        let records = storage
+            .send(ScanPrefix {
+                prefix: AUDIT_PREFIX.to_string(),
+            })
+            .await??;
+        let outcomes = records
+            .iter()
+            .map(|(_, data)| serde_json::from_slice::<serde_json::Value>(data))
+            .collect::<Result<Vec<_>, _>>()?;
+        let outcomes: Vec<(Option<&str>, Option<&str>)> = outcomes
+            .iter()
+            .map(|record| (record["command"].as_str(), record["outcome"].as_str()))
+            .collect();
+        let stale_outcome = CommandError::Stale.to_string();
+        let replayed_outcome = CommandError::Replayed.to_string();
+        let disallowed_outcome = CommandError::NotAllowed("open_deep_link".to_string()).to_string();
+        assert_eq!(
+            outcomes,
+            [
+                (None, Some(stale_outcome.as_str())),
+                (Some("remote_wipe"), Some("ok")),
+                (None, Some(replayed_outcome.as_str())),
+                (None, Some(replayed_outcome.as_str())),
+                (Some("open_deep_link"), Some(disallowed_outcome.as_str())),
+            ]
+        );
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn socket_is_private_and_never_replaces_other_files() -> Result<(), Box<dyn Error>> {
+        use std::os::unix::fs::{FileTypeExt, PermissionsExt};
+
+        let dir = std::env::temp_dir().join(format!("rinf_command_{}", std::process::id()));
+        std::fs::create_dir_all(&dir)?;
+        let path = dir.join("app.sock");
+        let _ = std::fs::remove_file(&path);
+        let storage =
+            TestActorHarness::start(|_| StorageActor::new(Arc::new(MemoryStorage::new())));
+        let listen = format!("unix:{}", path.display());
+
+        // 소켓이 아닌 파일은 지우지 않고 채널을 열지 못한 이유를 알린다
+        std::fs::write(&path, b"keep me")?;
+        let mut blocked = start_channel(&listen, &storage, EventBus::new());
+        settle().await;
+        let status = blocked.signals_of::<CommandChannelStatusSignal>();
+        assert_eq!(status.len(), 1);
+        assert!(status[0].listening.is_none());
+        assert!(
+            status[0]
+                .error
+                .as_deref()
+                .is_some_and(|e| e.contains("not a socket"))
+        );
+        assert_eq!(std::fs::read(&path)?, b"keep me");
+        drop(blocked);
+
+        std::fs::remove_file(&path)?;
+        let mut channel = start_channel(&listen, &storage, EventBus::new());
+        settle().await;
+        let status = channel.signals_of::<CommandChannelStatusSignal>();
+        assert_eq!(status[0].listening.as_deref(), Some(listen.as_str()));
+        let metadata = std::fs::symlink_metadata(&path)?;
+        assert!(metadata.file_type().is_socket());
+        assert_eq!(metadata.permissions().mode() & 0o777, 0o600);
+        tokio::net::UnixStream::connect(&path).await?;
+        drop(channel);
+
+        // 이전 실행이 남긴 소켓은 지우고 다시 연다
+        let mut restarted = start_channel(&listen, &storage, EventBus::new());
+        settle().await;
+        let status = restarted.signals_of::<CommandChannelStatusSignal>();
+        assert_eq!(status[0].listening.as_deref(), Some(listen.as_str()));
+        drop(restarted);
+        std::fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn bad_listen_address_is_reported() -> Result<(), Box<dyn Error>> {
+        let storage =
+            TestActorHarness::start(|_| StorageActor::new(Arc::new(MemoryStorage::new())));
+        let mut relative = start_channel("unix:app.sock", &storage, EventBus::new());
+        settle().await;
+        let status = relative.signals_of::<CommandChannelStatusSignal>();
+        assert!(
+            status[0]
+                .error
+                .as_deref()
+                .is_some_and(|e| e.contains("absolute"))
+        );
+
+        let mut public = start_channel("0.0.0.0:47800", &storage, EventBus::new());
+        settle().await;
+        let status = public.signals_of::<CommandChannelStatusSignal>();
+        assert!(
+            status[0]
+                .error
+                .as_deref()
+                .is_some_and(|e| e.contains("loopback"))
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,373 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use serde_json::{Map, Value};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use tokio::task::JoinSet;
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{AppConfig, AppEvent, ConfigError, GetConfig, SetConfigValue},
+    signals::{ConfigUpdatedSignal, EmitSignal, SetConfigRequest, route_dart_signals},
+};
+
+use super::EventBus;
+
+// 실행 중에 바꿔도 바로 적용되는 설정 영역 (쓰는 액터가 ConfigChanged를 구독해 반영한다)
+// 나머지 설정은 액터를 만들 때 한 번 읽으므로 설정 파일로만 바꿀 수 있다.
+const LIVE_SECTIONS: [&str; 2] = ["i18n", "quota"];
+
+// 설정 액터
+// 컴파일된 기본값 < 설정 파일 < Dart 오버라이드 순서로 병합한 설정을 보관한다.
+// 오버라이드는 설정 파일 옆의 *.overrides.json에 남겨 다음 실행에도 적용한다.
+pub struct ConfigActor {
+    defaults: Value,
+    file_layer: Value,
+    overrides: Value,
+    overrides_path: Option<PathBuf>, // 설정 파일 없이 실행하면 오버라이드는 메모리에만 남는다
+    current: AppConfig,
+    event_bus: Option<EventBus>,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for ConfigActor {}
+
+impl ConfigActor {
+    pub fn new(self_addr: Address<Self>, config_path: Option<&str>) -> Self {
+        let owned_tasks = route_dart_signals!(self_addr, [SetConfigRequest]);
+
+        // 다른 액터 생성에 바로 쓰일 수 있도록 설정 파일은 동기적으로 읽는다
+        let defaults = serde_json::to_value(AppConfig::default()).unwrap_or(Value::Null);
+        let file_layer = config_path.map_or(Value::Null, Self::read_config_file);
+        let overrides_path =
+            config_path.map(|path| Path::new(path).with_extension("overrides.json"));
+        let mut overrides = overrides_path
+            .as_deref()
+            .and_then(Path::to_str)
+            .map_or(Value::Null, Self::read_config_file);
+        // 손으로 고친 파일에 시작할 때만 읽는 설정이 있어도 실행 중 변경과 같은 범위만 받는다
+        if let Value::Object(sections) = &mut overrides {
+            sections.retain(|section, _| LIVE_SECTIONS.contains(&section.as_str()));
+        }
+
+        let mut actor = Self {
+            defaults,
+            file_layer,
+            overrides,
+            overrides_path,
+            current: AppConfig::default(),
+            event_bus: None,
+            _owned_tasks: owned_tasks,
+        };
+
+        // 잘못된 레이어만 빼고 다시 병합한다 (설정 파일이 잘못돼도 저장된 오버라이드는 남긴다)
+        if let Err(e) = Self::resolve_layers(&actor.defaults, &[&actor.file_layer]) {
+            debug_print!("Invalid config file, ignoring it: {}", e);
+            actor.file_layer = Value::Null;
+        }
+        if let Err(e) = actor.resolve() {
+            debug_print!("Invalid config overrides, ignoring them: {}", e);
+            actor.overrides = Value::Null;
+        }
+        match actor.resolve() {
+            Ok(config) => actor.current = config,
+            Err(e) => debug_print!("Invalid config, using defaults: {}", e),
+        }
+
+        actor
+    }
+
+    // 실행 중 바뀐 설정을 이벤트 버스로 알린다
+    pub fn set_event_bus(&mut self, event_bus: EventBus) {
+        self.event_bus = Some(event_bus);
+    }
+
+    pub fn current(&self) -> AppConfig {
+        self.current.clone()
+    }
+
+    fn save_overrides(&self) -> Result<(), ConfigError> {
+        let Some(path) = &self.overrides_path else {
+            return Ok(());
+        };
+        std::fs::write(path, serde_json::to_vec_pretty(&self.overrides)?)?;
+        Ok(())
+    }
+
+    fn read_config_file(path: &str) -> Value {
+        match std::fs::read_to_string(path) {
+            Ok(text) => serde_json::from_str(&text).unwrap_or_else(|e| {
+                debug_print!("Failed to parse config file {}: {}", path, e);
+                Value::Null
+            }),
+            Err(e) => {
+                debug_print!("Config file not loaded ({}): {}", path, e);
+                Value::Null
+            }
+        }
+    }
+
+    fn resolve(&self) -> Result<AppConfig, ConfigError> {
+        Self::resolve_layers(&self.defaults, &[&self.file_layer, &self.overrides])
+    }
+
+    // 각 레이어를 순서대로 병합한 뒤 타입 검사(역직렬화)와 값 검증을 수행
+    fn resolve_layers(defaults: &Value, layers: &[&Value]) -> Result<AppConfig, ConfigError> {
+        let mut merged = defaults.clone();
+        for layer in layers {
+            Self::merge(&mut merged, layer);
+        }
+
+        let config: AppConfig = serde_json::from_value(merged)?;
+        Self::validate(&config)?;
+        Ok(config)
+    }
+
+    fn validate(config: &AppConfig) -> Result<(), ConfigError> {
+        if config.network.max_connections == 0 {
+            return Err("network.max_connections must be greater than 0".into());
+        }
+        if config.network.monitor_interval_secs == 0
+            || config.cache.cleanup_interval_secs == 0
+            || config.auth.expiry_check_interval_secs == 0
+        {
+            return Err("Interval values must be greater than 0".into());
+        }
+        if config.auth.token_ttl_secs == 0 {
+            return Err("auth.token_ttl_secs must be greater than 0".into());
+        }
+        Ok(())
+    }
+
+    fn merge(base: &mut Value, layer: &Value) {
+        match (base, layer) {
+            (Value::Object(base_map), Value::Object(layer_map)) => {
+                for (key, value) in layer_map {
+                    match base_map.get_mut(key) {
+                        Some(existing) => Self::merge(existing, value),
+                        None => {
+                            base_map.insert(key.clone(), value.clone());
+                        }
+                    }
+                }
+            }
+            (_, Value::Null) => {}
+            (base, layer) => *base = layer.clone(),
+        }
+    }
+
+    fn set_path(target: &mut Value, key: &str, value: Value) {
+        let mut cursor = target;
+        let mut segments = key.split('.').peekable();
+        while let Some(segment) = segments.next() {
+            if !cursor.is_object() {
+                *cursor = Value::Object(Map::new());
+            }
+            let Value::Object(map) = cursor else {
+                return;
+            };
+            if segments.peek().is_none() {
+                map.insert(segment.to_string(), value);
+                return;
+            }
+            cursor = map.entry(segment.to_string()).or_insert(Value::Null);
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<GetConfig> for ConfigActor {
+    type Result = AppConfig;
+
+    async fn handle(&mut self, _: GetConfig, _: &Context<Self>) -> Self::Result {
+        self.current.clone()
+    }
+}
+
+#[async_trait]
+impl Handler<SetConfigValue> for ConfigActor {
+    type Result = Result<AppConfig, ConfigError>;
+
+    async fn handle(&mut self, msg: SetConfigValue, _: &Context<Self>) -> Self::Result {
+        // 알 수 없는 키는 거부
+        let pointer = format!("/{}", msg.key.replace('.', "/"));
+        if self.defaults.pointer(&pointer).is_none() {
+            return Err(format!("Unknown config key: {}", msg.key).into());
+        }
+        let section = msg.key.split('.').next().unwrap_or_default();
+        if !LIVE_SECTIONS.contains(&section) {
+            return Err(format!(
+                "{} is only read at startup, set it in the config file instead",
+                msg.key
+            )
+            .into());
+        }
+
+        let value: Value = serde_json::from_str(&msg.value_json)?;
+        let previous = self.overrides.clone();
+        Self::set_path(&mut self.overrides, &msg.key, value);
+
+        match self.resolve() {
+            Ok(config) => {
+                debug_print!("Config updated: {}", msg.key);
+                self.current = config.clone();
+                if let Err(e) = self.save_overrides() {
+                    debug_print!("Failed to save config overrides: {}", e);
+                }
+                if let Some(event_bus) = &self.event_bus {
+                    event_bus.publish(AppEvent::ConfigChanged {
+                        key: msg.key,
+                        config: Arc::new(config.clone()),
+                    });
+                }
+                Ok(config)
+            }
+            Err(e) => {
+                // 검증 실패 시 이전 오버라이드로 되돌림
+                self.overrides = previous;
+                Err(e)
+            }
+        }
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<SetConfigRequest> for ConfigActor {
+    async fn notify(&mut self, msg: SetConfigRequest, ctx: &Context<Self>) {
+        let key = msg.key.clone();
+        let result = self
+            .handle(
+                SetConfigValue {
+                    key: msg.key,
+                    value_json: msg.value_json,
+                },
+                ctx,
+            )
+            .await;
+
+        match result {
+            Ok(config) => {
+                ConfigUpdatedSignal {
+                    key,
+                    success: true,
+                    config_json: serde_json::to_string(&config).unwrap_or_default(),
+                    error: None,
+                }
+                .emit();
+            }
+            Err(e) => {
+                ConfigUpdatedSignal {
+                    key,
+                    success: false,
+                    config_json: serde_json::to_string(&self.current).unwrap_or_default(),
+                    error: Some(e.to_string()),
+                }
+                .emit();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConfigActor;
+    use std::fs;
+
+    use crate::study_actors::{
+        actors::EventBus,
+        messages::{AppEvent, ConfigError, GetConfig, SetConfigValue},
+        test_support::TestActorHarness,
+    };
+
+    #[tokio::test]
+    async fn live_override_is_published_and_kept_across_restart() -> Result<(), ConfigError> {
+        let dir = std::env::temp_dir().join(format!("rinf_config_{}", std::process::id()));
+        fs::create_dir_all(&dir)?;
+        let config_path = dir.join("config.json");
+        fs::write(&config_path, r#"{ "quota": { "warning_ratio": 0.5 } }"#)?;
+        let _ = fs::remove_file(dir.join("config.overrides.json"));
+        let path = config_path.to_str().ok_or("temp path is not utf-8")?;
+
+        let event_bus = EventBus::new();
+        let mut events = event_bus.subscribe();
+        let mut harness = TestActorHarness::start(|addr| {
+            let mut actor = ConfigActor::new(addr, Some(path));
+            actor.set_event_bus(event_bus.clone());
+            actor
+        });
+
+        let config = harness
+            .send(SetConfigValue {
+                key: "quota.warning_ratio".to_string(),
+                value_json: "0.9".to_string(),
+            })
+            .await??;
+        assert_eq!(config.quota.warning_ratio, 0.9);
+        let AppEvent::ConfigChanged { key, config } = events.try_recv()? else {
+            return Err("expected ConfigChanged".into());
+        };
+        assert_eq!(key, "quota.warning_ratio");
+        assert_eq!(config.quota.warning_ratio, 0.9);
+
+        // 설정 파일보다 저장된 오버라이드가 우선한다
+        let restarted = TestActorHarness::start(|addr| ConfigActor::new(addr, Some(path)));
+        let current = restarted.addr().send(GetConfig).await?;
+        assert_eq!(current.quota.warning_ratio, 0.9);
+
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn invalid_layer_is_dropped_without_the_other() -> Result<(), ConfigError> {
+        let dir = std::env::temp_dir().join(format!("rinf_config_layers_{}", std::process::id()));
+        fs::create_dir_all(&dir)?;
+        let config_path = dir.join("config.json");
+        let overrides_path = dir.join("config.overrides.json");
+        let path = config_path.to_str().ok_or("temp path is not utf-8")?;
+
+        // 설정 파일이 잘못돼도 저장된 오버라이드는 적용된다
+        fs::write(&config_path, r#"{ "network": { "max_connections": 0 } }"#)?;
+        fs::write(&overrides_path, r#"{ "quota": { "warning_ratio": 0.9 } }"#)?;
+        let harness = TestActorHarness::start(|addr| ConfigActor::new(addr, Some(path)));
+        let current = harness.addr().send(GetConfig).await?;
+        assert_ne!(current.network.max_connections, 0);
+        assert_eq!(current.quota.warning_ratio, 0.9);
+
+        // 오버라이드가 잘못되면 설정 파일은 그대로 적용된다
+        fs::write(&config_path, r#"{ "quota": { "warning_ratio": 0.5 } }"#)?;
+        fs::write(
+            &overrides_path,
+            r#"{ "quota": { "warning_ratio": "high" } }"#,
+        )?;
+        let harness = TestActorHarness::start(|addr| ConfigActor::new(addr, Some(path)));
+        let current = harness.addr().send(GetConfig).await?;
+        assert_eq!(current.quota.warning_ratio, 0.5);
+
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn startup_only_key_is_rejected() -> Result<(), ConfigError> {
+        let mut harness = TestActorHarness::start(|addr| ConfigActor::new(addr, None));
+
+        let result = harness
+            .send(SetConfigValue {
+                key: "network.max_connections".to_string(),
+                value_json: "16".to_string(),
+            })
+            .await?;
+        assert!(result.is_err());
+
+        let current = harness.send(GetConfig).await?;
+        assert_ne!(current.network.max_connections, 16);
+        Ok(())
+    }
+}
@@ -0,0 +1,593 @@
+use async_trait::async_trait;
+use base64::{Engine, engine::general_purpose::STANDARD};
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use std::collections::HashSet;
+use tokio::task::JoinSet;
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        ActorResult, AttachBytes, Contact, ContactField, CreateDataItem, ImportVcard, ScanPrefix,
+        SetItemContent, StoreData, UserError, VcardImport,
+    },
+    signals::{
+        EmitSignal, ImportVcardRequest, VcardImportProgressSignal, respond_to_dart,
+        route_dart_signals,
+    },
+};
+
+use super::calendar::{ContentLine, parse_line, unescape_text, unfold};
+use super::{AttachmentActor, DataManagerActor, StorageActor, normalize_email, normalize_phone};
+
+// 한 파일에서 가져오는 연락처 수 상한
+const MAX_IMPORT_CONTACTS: usize = 20_000;
+const MAX_PHOTO_BYTES: usize = 5 * 1024 * 1024;
+// 진행 신호를 보내는 간격 (카드 수)
+const PROGRESS_EVERY: usize = 50;
+// 이름이 이만큼 비슷하면 같은 사람으로 본다 (글자 bigram Dice 계수)
+const NAME_SIMILARITY: f64 = 0.8;
+
+fn contact_key(user_id: &str, item_id: &str) -> String {
+    format!("contacts/{}/{}", user_id, item_id)
+}
+
+fn contacts_prefix(user_id: &str) -> String {
+    format!("contacts/{}/", user_id)
+}
+
+fn non_empty(text: String) -> Option<String> {
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+// ;로 나뉜 구조 값 (N, ADR, ORG), 이스케이프된 \;에서는 나누지 않는다
+fn split_components(value: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut escaped = false;
+    for (index, c) in value.char_indices() {
+        match c {
+            '\\' if !escaped => {
+                escaped = true;
+                continue;
+            }
+            ';' if !escaped => {
+                parts.push(unescape_text(&value[start..index]).trim().to_string());
+                start = index + 1;
+            }
+            _ => {}
+        }
+        escaped = false;
+    }
+    parts.push(unescape_text(&value[start..]).trim().to_string());
+    parts
+}
+
+fn join_components(value: &str) -> Option<String> {
+    let parts: Vec<String> = split_components(value)
+        .into_iter()
+        .filter(|part| !part.is_empty())
+        .collect();
+    non_empty(parts.join(", "))
+}
+
+// TYPE 값 중 처음 나오는 의미 있는 것 (pref, internet, voice 같은 표시는 뺀다)
+fn field_label(line: &ContentLine) -> Option<String> {
+    line.params
+        .iter()
+        .filter(|(key, _)| key == "TYPE")
+        .flat_map(|(_, value)| value.split(','))
+        .map(|kind| kind.trim().to_ascii_lowercase())
+        .find(|kind| !kind.is_empty() && !matches!(kind.as_str(), "pref" | "internet" | "voice"))
+}
+
+fn field(line: &ContentLine, value: String) -> Option<ContactField> {
+    Some(ContactField {
+        label: field_label(line),
+        value: non_empty(value)?,
+    })
+}
+
+// 파일에 들어 있던 사진
+struct ContactPhoto {
+    mime_type: String,
+    bytes: Vec<u8>,
+}
+
+enum PhotoValue {
+    Embedded(ContactPhoto),
+    Url(String),
+}
+
+fn decode_photo(payload: &str) -> Option<Vec<u8>> {
+    let compact: String = payload.chars().filter(|c| !c.is_whitespace()).collect();
+    let bytes = STANDARD.decode(compact).ok()?;
+    (!bytes.is_empty() && bytes.len() <= MAX_PHOTO_BYTES).then_some(bytes)
+}
+
+// 4.0의 data: URI, 3.0의 ENCODING=b, 그리고 http(s) 링크
+fn parse_photo(line: &ContentLine) -> Option<PhotoValue> {
+    let value = line.value.trim();
+    if let Some(data) = value.strip_prefix("data:") {
+        let (meta, payload) = data.split_once(',')?;
+        let mime_type = meta.strip_suffix(";base64")?;
+        return Some(PhotoValue::Embedded(ContactPhoto {
+            mime_type: mime_type.to_string(),
+            bytes: decode_photo(payload)?,
+        }));
+    }
+    let encoding = line.param("ENCODING").map(str::to_ascii_uppercase);
+    if matches!(encoding.as_deref(), Some("B" | "BASE64")) {
+        let kind = field_label(line).unwrap_or_else(|| "jpeg".to_string());
+        return Some(PhotoValue::Embedded(ContactPhoto {
+            mime_type: format!("image/{}", kind),
+            bytes: decode_photo(value)?,
+        }));
+    }
+    (value.starts_with("https://") || value.starts_with("http://"))
+        .then(|| PhotoValue::Url(value.to_string()))
+}
+
+// 19900131, 1990-01-31, --0131 같은 값을 YYYY-MM-DD나 --MM-DD로 (읽지 못하면 그대로)
+fn normalize_birthday(value: &str) -> String {
+    let value = value.trim();
+    let date = value.split('T').next().unwrap_or(value);
+    let digits = date.replace('-', "");
+    if !digits.chars().all(|c| c.is_ascii_digit()) {
+        return value.to_string();
+    }
+    match (date.starts_with("--"), digits.len()) {
+        (false, 8) => format!("{}-{}-{}", &digits[..4], &digits[4..6], &digits[6..]),
+        (true, 4) => format!("--{}-{}", &digits[..2], &digits[2..]),
+        _ => value.to_string(),
+    }
+}
+
+// VCARD 하나에서 모은 값 (항목을 만들기 전)
+#[derive(Default)]
+struct ParsedCard {
+    contact: Contact,
+    photo: Option<ContactPhoto>,
+}
+
+impl ParsedCard {
+    fn apply(&mut self, line: ContentLine) {
+        // Apple 주소록이 붙이는 그룹 이름 (item1.EMAIL)은 뗀다
+        let name = line
+            .name
+            .rsplit_once('.')
+            .map_or(line.name.as_str(), |(_, name)| name);
+        let contact = &mut self.contact;
+        match name {
+            "UID" => contact.uid = non_empty(line.value.clone()),
+            "FN" => contact.display_name = unescape_text(&line.value).trim().to_string(),
+            "N" => {
+                let mut parts = split_components(&line.value).into_iter();
+                contact.family_name = parts.next().and_then(non_empty);
+                contact.given_name = parts.next().and_then(non_empty);
+            }
+            "ORG" => contact.organization = join_components(&line.value),
+            "TITLE" => contact.job_title = non_empty(unescape_text(&line.value)),
+            "EMAIL" => contact
+                .emails
+                .extend(field(&line, unescape_text(&line.value))),
+            "TEL" => {
+                let value = line.value.trim();
+                let value = value.strip_prefix("tel:").unwrap_or(value);
+                contact.phones.extend(field(&line, unescape_text(value)));
+            }
+            "ADR" => contact
+                .addresses
+                .extend(join_components(&line.value).and_then(|value| field(&line, value))),
+            "URL" => contact.urls.extend(non_empty(unescape_text(&line.value))),
+            "BDAY" => contact.birthday = non_empty(normalize_birthday(&line.value)),
+            "NOTE" => contact.note = non_empty(unescape_text(&line.value)),
+            "PHOTO" => match parse_photo(&line) {
+                Some(PhotoValue::Embedded(photo)) => self.photo = Some(photo),
+                Some(PhotoValue::Url(url)) => contact.photo_url = Some(url),
+                None => {}
+            },
+            _ => {}
+        }
+    }
+
+    // FN이 없으면 이름, 회사, 이메일, 전화번호 순으로 표시 이름을 정한다 (아무것도 없으면 None)
+    fn into_contact(self) -> Option<(Contact, Option<ContactPhoto>)> {
+        let mut contact = self.contact;
+        if contact.display_name.is_empty() {
+            let name = [
+                contact.given_name.as_deref(),
+                contact.family_name.as_deref(),
+            ]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" ");
+            contact.display_name = non_empty(name)
+                .or_else(|| contact.organization.clone())
+                .or_else(|| contact.emails.first().map(|email| email.value.clone()))
+                .or_else(|| contact.phones.first().map(|phone| phone.value.clone()))?;
+        }
+        Some((contact, self.photo))
+    }
+}
+
+// VCARD마다 값을 모은다 (2.1의 AGENT처럼 안에 든 카드는 건너뛴다)
+fn collect_cards(vcf: &str) -> Vec<ParsedCard> {
+    let mut cards = Vec::new();
+    let mut current: Option<ParsedCard> = None;
+    let mut nested = 0usize;
+    for line in unfold(vcf) {
+        let Some(line) = parse_line(&line) else {
+            continue;
+        };
+        let is_card = line.value.trim().eq_ignore_ascii_case("VCARD");
+        let Some(card) = current.as_mut() else {
+            if line.name == "BEGIN" && is_card {
+                current = Some(ParsedCard::default());
+            }
+            continue;
+        };
+        match line.name.as_str() {
+            "BEGIN" => nested += 1,
+            "END" if nested > 0 => nested -= 1,
+            "END" if is_card => cards.extend(current.take()),
+            _ if nested > 0 => {}
+            _ => card.apply(line),
+        }
+    }
+    cards
+}
+
+// 비교용 키는 입력란과 같은 정규화를 쓴다 (읽지 못하는 값은 소문자나 숫자만 비교)
+fn email_key(email: &str) -> String {
+    normalize_email(email).map_or_else(|_| email.trim().to_lowercase(), |email| email.match_key)
+}
+
+// 국가 번호가 없는 번호는 지역을 알 수 없어 숫자만 비교한다
+fn phone_key(phone: &str) -> String {
+    normalize_phone(phone, None).map_or_else(
+        |_| phone.chars().filter(char::is_ascii_digit).collect(),
+        |phone| phone.match_key,
+    )
+}
+
+// 대소문자와 문장 부호를 무시하고 단어 순서를 맞춘 이름 ("Kim, Minsu" == "minsu kim")
+fn name_key(name: &str) -> String {
+    let mut words: Vec<String> = name
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+    words.sort();
+    words.join(" ")
+}
+
+fn bigrams(text: &str) -> Vec<(char, char)> {
+    let chars: Vec<char> = text.chars().collect();
+    chars.windows(2).map(|pair| (pair[0], pair[1])).collect()
+}
+
+// 글자 bigram의 Dice 계수 (0.0 ~ 1.0), 한두 글자 이름은 같을 때만 1.0
+fn name_similarity(a: &str, b: &str) -> f64 {
+    let (a, b) = (name_key(a), name_key(b));
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    if a == b {
+        return 1.0;
+    }
+    let (a, mut b) = (bigrams(&a), bigrams(&b));
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    let total = a.len() + b.len();
+    let mut shared = 0;
+    for pair in a {
+        if let Some(index) = b.iter().position(|other| *other == pair) {
+            b.swap_remove(index);
+            shared += 1;
+        }
+    }
+    (2 * shared) as f64 / total as f64
+}
+
+// 이메일이 하나라도 겹치면 같은 사람, 둘 다 이메일이 있는데 겹치지 않으면 다른 사람 (동명이인),
+// 한쪽에만 이메일이 있으면 이름으로 판단한다
+fn is_same_contact(existing: &Contact, new: &Contact) -> bool {
+    let emails = |contact: &Contact| -> HashSet<String> {
+        contact
+            .emails
+            .iter()
+            .map(|email| email_key(&email.value))
+            .collect()
+    };
+    let (a, b) = (emails(existing), emails(new));
+    if !a.is_disjoint(&b) {
+        return true;
+    }
+    if !a.is_empty() && !b.is_empty() {
+        return false;
+    }
+    name_similarity(&existing.display_name, &new.display_name) >= NAME_SIMILARITY
+}
+
+fn merge_fields(existing: &mut Vec<ContactField>, new: Vec<ContactField>, key: fn(&str) -> String) {
+    for field in new {
+        if !existing
+            .iter()
+            .any(|known| key(&known.value) == key(&field.value))
+        {
+            existing.push(field);
+        }
+    }
+}
+
+// 비어 있는 값만 채우고 목록은 없는 것만 더한다 (기존 값은 덮어쓰지 않는다)
+fn merge_contact(existing: &mut Contact, new: Contact) {
+    let fill = |slot: &mut Option<String>, value: Option<String>| {
+        if slot.is_none() {
+            *slot = value;
+        }
+    };
+    fill(&mut existing.uid, new.uid);
+    fill(&mut existing.given_name, new.given_name);
+    fill(&mut existing.family_name, new.family_name);
+    fill(&mut existing.organization, new.organization);
+    fill(&mut existing.job_title, new.job_title);
+    fill(&mut existing.birthday, new.birthday);
+    fill(&mut existing.note, new.note);
+    fill(&mut existing.photo_url, new.photo_url);
+    merge_fields(&mut existing.emails, new.emails, email_key);
+    merge_fields(&mut existing.phones, new.phones, phone_key);
+    merge_fields(&mut existing.addresses, new.addresses, str::to_lowercase);
+    for url in new.urls {
+        if !existing.urls.contains(&url) {
+            existing.urls.push(url);
+        }
+    }
+}
+
+// 항목 본문 (검색에 걸리도록 회사, 이메일, 전화번호를 줄마다)
+fn contact_summary(contact: &Contact) -> String {
+    let organization = match (&contact.organization, &contact.job_title) {
+        (Some(organization), Some(title)) => Some(format!("{} · {}", organization, title)),
+        (organization, title) => organization.clone().or_else(|| title.clone()),
+    };
+    organization
+        .into_iter()
+        .chain(contact.emails.iter().map(|email| email.value.clone()))
+        .chain(contact.phones.iter().map(|phone| phone.value.clone()))
+        .chain(contact.note.clone())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// 연락처 액터
+// vCard 파일의 연락처를 항목으로 가져온다. 이름, 이메일, 전화번호 같은 구조화된 값은 항목 id별로
+// 따로 저장하고, 파일에 든 사진은 첨부 액터로 넘겨 썸네일까지 만든다.
+// 이미 있는 사람은 새로 만들지 않고 빠진 값만 기존 연락처에 합친다.
+pub struct ContactsActor {
+    storage: Address<StorageActor>,
+    data: Address<DataManagerActor>,
+    attachments: Address<AttachmentActor>,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for ContactsActor {}
+
+impl ContactsActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        storage: Address<StorageActor>,
+        data: Address<DataManagerActor>,
+        attachments: Address<AttachmentActor>,
+    ) -> Self {
+        let owned_tasks = route_dart_signals!(self_addr, [ImportVcardRequest]);
+
+        Self {
+            storage,
+            data,
+            attachments,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    async fn stored_contacts(&mut self, user_id: &str) -> ActorResult<Vec<Contact>> {
+        let scan = ScanPrefix {
+            prefix: contacts_prefix(user_id),
+        };
+        let entries = self.storage.send(scan).await??;
+        let contacts = entries
+            .into_iter()
+            .filter_map(|(key, bytes)| match serde_json::from_slice(&bytes) {
+                Ok(contact) => Some(contact),
+                Err(e) => {
+                    debug_print!("Skipping stored contact {}: {}", key, e);
+                    None
+                }
+            })
+            .collect();
+        Ok(contacts)
+    }
+
+    async fn save_contact(&mut self, user_id: &str, contact: &Contact) -> ActorResult<()> {
+        let store = StoreData {
+            key: contact_key(user_id, &contact.item_id),
+            data: serde_json::to_vec(contact)?,
+            user_id: None,
+            ttl: None,
+        };
+        self.storage.send(store).await??;
+        Ok(())
+    }
+
+    // 사진을 붙이지 못해도 연락처는 가져온다
+    async fn attach_photo(&mut self, item_id: &str, photo: ContactPhoto) -> Option<String> {
+        let extension = photo.mime_type.rsplit('/').next().unwrap_or("jpeg");
+        let attach = AttachBytes {
+            item_id: item_id.to_string(),
+            file_name: format!("photo.{}", extension),
+            mime_type: Some(photo.mime_type.clone()),
+            bytes: photo.bytes,
+        };
+        match self.attachments.send(attach).await {
+            Ok(Ok(attachment)) => Some(attachment.id),
+            Ok(Err(e)) => {
+                debug_print!("Failed to attach contact photo: {}", e);
+                None
+            }
+            Err(e) => {
+                debug_print!("Failed to attach contact photo: {}", e);
+                None
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<ImportVcard> for ContactsActor {
+    type Result = ActorResult<VcardImport>;
+
+    async fn handle(&mut self, msg: ImportVcard, _: &Context<Self>) -> Self::Result {
+        let cards = collect_cards(&msg.vcf);
+        if cards.is_empty() {
+            return Err(UserError::InvalidInput(
+                "No contacts found in vCard file".to_string(),
+            ));
+        }
+        if cards.len() > MAX_IMPORT_CONTACTS {
+            return Err(UserError::InvalidInput(format!(
+                "vCard file has more than {} contacts",
+                MAX_IMPORT_CONTACTS
+            )));
+        }
+
+        let total = cards.len();
+        let progress = |processed: usize| VcardImportProgressSignal {
+            correlation_id: msg.correlation_id,
+            processed: processed as u64,
+            total: total as u64,
+        };
+        let mut known = self.stored_contacts(&msg.user_id).await?;
+        let mut report = VcardImport::default();
+        for (index, card) in cards.into_iter().enumerate() {
+            if index > 0 && index % PROGRESS_EVERY == 0 {
+                progress(index).emit();
+            }
+            let Some((contact, photo)) = card.into_contact() else {
+                report
+                    .skipped
+                    .push(format!("vCard {}: no name, email or phone", index + 1));
+                continue;
+            };
+
+            if let Some(existing) = known
+                .iter_mut()
+                .find(|existing| is_same_contact(existing, &contact))
+            {
+                merge_contact(existing, contact);
+                if existing.photo_attachment_id.is_none()
+                    && let Some(photo) = photo
+                {
+                    existing.photo_attachment_id =
+                        self.attach_photo(&existing.item_id, photo).await;
+                }
+                let content = SetItemContent {
+                    item_id: existing.item_id.clone(),
+                    content: contact_summary(existing),
+                };
+                self.data.send(content).await??;
+                self.save_contact(&msg.user_id, existing).await?;
+                report.merged += 1;
+                continue;
+            }
+
+            let mut contact = contact;
+            let create = CreateDataItem {
+                user_id: msg.user_id.clone(),
+                title: contact.display_name.clone(),
+                content: contact_summary(&contact),
+                tags: Vec::new(),
+            };
+            contact.item_id = self.data.send(create).await??.id;
+            if let Some(photo) = photo {
+                contact.photo_attachment_id = self.attach_photo(&contact.item_id, photo).await;
+            }
+            self.save_contact(&msg.user_id, &contact).await?;
+            known.push(contact.clone());
+            report.imported.push(contact);
+        }
+        progress(total).emit();
+        Ok(report)
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<ImportVcardRequest> for ContactsActor {
+    async fn notify(&mut self, msg: ImportVcardRequest, ctx: &Context<Self>) {
+        let _ = respond_to_dart(msg, |msg| async move {
+            let import = ImportVcard {
+                correlation_id: msg.correlation_id,
+                user_id: msg.user_id,
+                vcf: msg.vcf,
+            };
+            Ok(self.handle(import, ctx).await?)
+        })
+        .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn parses_vcards_and_matches_duplicates() -> Result<(), Box<dyn Error>> {
+        let vcf = "BEGIN:VCARD\r\nVERSION:3.0\r\nN:Kim;Minsu;;;\r\nORG:Example\\, Inc.;R&D\r\n\
+                   item1.EMAIL;TYPE=INTERNET,WORK:minsu@example.com\r\n\
+                   TEL;TYPE=CELL:+82 10-1234-\r\n 5678\r\nBDAY:19900131\r\n\
+                   PHOTO;ENCODING=b;TYPE=PNG:iVBORw0KGgo=\r\nEND:VCARD\r\n\
+                   BEGIN:VCARD\r\nVERSION:4.0\r\nEMAIL:nobody@example.com\r\nEND:VCARD\r\n\
+                   BEGIN:VCARD\r\nVERSION:4.0\r\nNOTE:empty\r\nEND:VCARD\r\n";
+        let mut cards = collect_cards(vcf).into_iter();
+
+        let (minsu, photo) = cards
+            .next()
+            .ok_or("missing card")?
+            .into_contact()
+            .ok_or("no name")?;
+        assert_eq!(minsu.display_name, "Minsu Kim");
+        assert_eq!(minsu.organization.as_deref(), Some("Example, Inc., R&D"));
+        assert_eq!(minsu.emails[0].label.as_deref(), Some("work"));
+        assert_eq!(minsu.phones[0].value, "+82 10-1234-5678");
+        assert_eq!(minsu.birthday.as_deref(), Some("1990-01-31"));
+        assert_eq!(
+            photo.map(|photo| photo.mime_type).as_deref(),
+            Some("image/png")
+        );
+
+        let (nobody, _) = cards
+            .next()
+            .ok_or("missing card")?
+            .into_contact()
+            .ok_or("no name")?;
+        assert_eq!(nobody.display_name, "nobody@example.com");
+        assert!(cards.next().ok_or("missing card")?.into_contact().is_none());
+
+        let same_name = Contact {
+            display_name: "kim, minsu".to_string(),
+            ..Contact::default()
+        };
+        assert!(is_same_contact(&minsu, &same_name));
+        assert!(!is_same_contact(&minsu, &nobody));
+        assert!(name_similarity("Jonathan Smith", "Jonathon Smith") >= NAME_SIMILARITY);
+        assert!(name_similarity("Dan Kim", "Don Kim") < NAME_SIMILARITY);
+        Ok(())
+    }
+}
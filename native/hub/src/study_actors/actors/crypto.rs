@@ -0,0 +1,552 @@
+use aes_gcm::{
+    Aes256Gcm, KeyInit, Nonce,
+    aead::{Aead, AeadCore, OsRng},
+};
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use tokio::task::JoinSet;
+use x25519_dalek::{PublicKey, StaticSecret};
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{CryptoError, Decrypt, Encrypt, GenerateKey, RotateEncryptionKey},
+    signals::{
+        CreateKeyPairRequest, CryptoResultSignal, DecryptRequest, DeriveSharedKeyRequest,
+        EmitBinarySignal, EmitSignal, EncryptRequest, EncryptionKeyRotationSignal,
+        GenerateKeyRequest, KeyReadySignal, PublicKeySignal, ReceiveBinarySignal,
+        RotateEncryptionKeyRequest, route_dart_signals,
+    },
+    storage::{SealedSecretStore, SecretStore},
+};
+
+use super::{TrackedTask, WorkerJob, offload};
+
+const NONCE_LEN: usize = 12;
+const KEY_LEN: usize = 32;
+const SHARED_KEY_CONTEXT: &[u8] = b"rinf-x25519-aes256gcm";
+
+// 암호화 액터
+// 키는 비밀 저장소에만 보관되고 Dart로 전달되지 않는다 (공개키 제외).
+// 비밀 저장소의 데이터 키 교체도 여기서 백그라운드 작업으로 진행한다.
+pub struct CryptoActor {
+    secret_store: Arc<SealedSecretStore>,
+    rotation: Option<TrackedTask>,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for CryptoActor {}
+
+impl CryptoActor {
+    pub fn new(self_addr: Address<Self>, secret_store: Arc<SealedSecretStore>) -> Self {
+        let mut owned_tasks = route_dart_signals!(
+            self_addr,
+            [
+                GenerateKeyRequest,
+                CreateKeyPairRequest,
+                DeriveSharedKeyRequest,
+                RotateEncryptionKeyRequest
+            ]
+        );
+        owned_tasks.spawn(Self::listen_to_encrypt_requests(self_addr.clone()));
+        owned_tasks.spawn(Self::listen_to_decrypt_requests(self_addr.clone()));
+        owned_tasks.spawn(Self::resume_key_rotation(self_addr.clone()));
+
+        Self {
+            secret_store,
+            rotation: None,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    // 앱이 교체 도중에 꺼졌으면 저장된 커서 다음부터 이어 간다 (비밀 저장소가 재시작 후에도 남을 때)
+    async fn resume_key_rotation(mut self_addr: Address<Self>) {
+        let _ = self_addr.notify(ResumeKeyRotation).await;
+    }
+
+    async fn listen_to_encrypt_requests(mut self_addr: Address<Self>) {
+        let receiver = EncryptRequest::binary_signal_receiver();
+        while let Some(signal_pack) = receiver.recv().await {
+            let _ = self_addr
+                .notify(DartEncrypt(signal_pack.message, signal_pack.binary))
+                .await;
+        }
+    }
+
+    async fn listen_to_decrypt_requests(mut self_addr: Address<Self>) {
+        let receiver = DecryptRequest::binary_signal_receiver();
+        while let Some(signal_pack) = receiver.recv().await {
+            let _ = self_addr
+                .notify(DartDecrypt(signal_pack.message, signal_pack.binary))
+                .await;
+        }
+    }
+
+    fn key_name(key_id: &str) -> String {
+        format!("aes_key/{}", key_id)
+    }
+
+    fn key_pair_name(key_pair_id: &str) -> String {
+        format!("x25519/{}", key_pair_id)
+    }
+
+    // 웹 워커로도 보낼 수 있도록 암호 객체 대신 키 바이트를 넘긴다 (길이는 여기서 확인)
+    async fn load_key(&self, key_id: &str) -> Result<Vec<u8>, CryptoError> {
+        let key = self
+            .secret_store
+            .get_secret(&Self::key_name(key_id))
+            .await?
+            .ok_or_else(|| format!("Unknown key: {}", key_id))?;
+        if key.len() != KEY_LEN {
+            return Err("Invalid key length".into());
+        }
+        Ok(key)
+    }
+
+    async fn load_key_pair(&self, key_pair_id: &str) -> Result<Option<StaticSecret>, CryptoError> {
+        let Some(bytes) = self
+            .secret_store
+            .get_secret(&Self::key_pair_name(key_pair_id))
+            .await?
+        else {
+            return Ok(None);
+        };
+        let bytes: [u8; 32] = bytes
+            .as_slice()
+            .try_into()
+            .map_err(|_| "Corrupted key pair")?;
+        Ok(Some(StaticSecret::from(bytes)))
+    }
+
+    // 키 쌍이 없으면 새로 만들고 공개키만 반환
+    async fn create_key_pair(&self, key_pair_id: &str) -> Result<Vec<u8>, CryptoError> {
+        let secret = match self.load_key_pair(key_pair_id).await? {
+            Some(secret) => secret,
+            None => {
+                let secret = StaticSecret::random_from_rng(OsRng);
+                self.secret_store
+                    .put_secret(&Self::key_pair_name(key_pair_id), secret.as_bytes())
+                    .await?;
+                secret
+            }
+        };
+        Ok(PublicKey::from(&secret).as_bytes().to_vec())
+    }
+
+    // 교체 작업은 액터 밖에서 값 하나씩 진행해 그동안에도 암복호화 요청을 받는다
+    fn start_rotation_job(&mut self, key_id: String) {
+        if self
+            .rotation
+            .as_ref()
+            .is_some_and(|task| !task.is_finished())
+        {
+            return;
+        }
+        let job = Self::reseal_secrets(self.secret_store.clone(), key_id);
+        self.rotation = Some(TrackedTask::spawn(&mut self._owned_tasks, job));
+    }
+
+    // 실패하면 멈추고, 교체는 진행 중으로 남아 다음 요청이나 재시작 때 커서부터 이어진다
+    async fn reseal_secrets(secret_store: Arc<SealedSecretStore>, key_id: String) {
+        loop {
+            let signal = match secret_store.rotate_step().await {
+                Ok(Some(progress)) => EncryptionKeyRotationSignal {
+                    key_id: progress.key_id,
+                    done: progress.done,
+                    total: progress.total,
+                    finished: progress.finished,
+                    error: None,
+                },
+                // 교체 도중 비밀 값이 모두 지워졌다
+                Ok(None) => return,
+                Err(e) => EncryptionKeyRotationSignal {
+                    key_id: key_id.clone(),
+                    done: 0,
+                    total: 0,
+                    finished: false,
+                    error: Some(e.to_string()),
+                },
+            };
+            let stop = signal.finished || signal.error.is_some();
+            if signal.finished {
+                debug_print!("Switched to encryption key: {}", signal.key_id);
+            }
+            signal.emit();
+            if stop {
+                return;
+            }
+        }
+    }
+
+    // X25519 키 합의 결과를 SHA-256으로 유도해 AES 키로 저장
+    async fn derive_shared_key(&self, msg: &DeriveSharedKeyRequest) -> Result<(), CryptoError> {
+        let secret = self
+            .load_key_pair(&msg.key_pair_id)
+            .await?
+            .ok_or_else(|| format!("Unknown key pair: {}", msg.key_pair_id))?;
+        let peer: [u8; 32] = msg
+            .peer_public_key
+            .as_slice()
+            .try_into()
+            .map_err(|_| "Invalid peer public key")?;
+
+        let shared = secret.diffie_hellman(&PublicKey::from(peer));
+        let key = Sha256::new()
+            .chain_update(SHARED_KEY_CONTEXT)
+            .chain_update(shared.as_bytes())
+            .finalize();
+
+        Ok(self
+            .secret_store
+            .put_secret(&Self::key_name(&msg.key_id), &key)
+            .await?)
+    }
+}
+
+// 내부 메시지 정의 (Dart 요청 + 바이너리 페이로드)
+struct DartEncrypt(EncryptRequest, Vec<u8>);
+struct DartDecrypt(DecryptRequest, Vec<u8>);
+struct ResumeKeyRotation;
+
+#[async_trait]
+impl Handler<GenerateKey> for CryptoActor {
+    type Result = Result<(), CryptoError>;
+
+    async fn handle(&mut self, msg: GenerateKey, _: &Context<Self>) -> Self::Result {
+        let name = Self::key_name(&msg.key_id);
+        if self.secret_store.get_secret(&name).await?.is_some() {
+            return Ok(());
+        }
+
+        let key = Aes256Gcm::generate_key(OsRng);
+        self.secret_store.put_secret(&name, &key).await?;
+        debug_print!("Generated encryption key: {}", msg.key_id);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Handler<RotateEncryptionKey> for CryptoActor {
+    type Result = Result<String, CryptoError>;
+
+    async fn handle(&mut self, _: RotateEncryptionKey, _: &Context<Self>) -> Self::Result {
+        let key_id = self.secret_store.begin_rotation().await?;
+        self.start_rotation_job(key_id.clone());
+        Ok(key_id)
+    }
+}
+
+// 암복호화 본문 (offload로 실행)
+#[derive(Serialize, Deserialize)]
+pub(super) enum CipherJob {
+    Encrypt { key: Vec<u8>, plaintext: Vec<u8> },
+    Decrypt { key: Vec<u8>, ciphertext: Vec<u8> },
+}
+
+impl WorkerJob for CipherJob {
+    const NAME: &'static str = "crypto/cipher";
+    type Output = Result<Vec<u8>, String>;
+
+    fn run(self) -> Self::Output {
+        match self {
+            Self::Encrypt { key, plaintext } => {
+                let cipher = Aes256Gcm::new_from_slice(&key).map_err(|_| "Invalid key length")?;
+                let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+                let ciphertext = cipher
+                    .encrypt(&nonce, plaintext.as_slice())
+                    .map_err(|_| "Encryption failed")?;
+
+                let mut output = nonce.to_vec();
+                output.extend_from_slice(&ciphertext);
+                Ok(output)
+            }
+            Self::Decrypt { key, ciphertext } => {
+                let cipher = Aes256Gcm::new_from_slice(&key).map_err(|_| "Invalid key length")?;
+                let (nonce, body) = ciphertext.split_at(NONCE_LEN);
+                cipher
+                    .decrypt(Nonce::from_slice(nonce), body)
+                    .map_err(|_| "Decryption failed (wrong key or tampered data)".to_string())
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<Encrypt> for CryptoActor {
+    type Result = Result<Vec<u8>, CryptoError>;
+
+    async fn handle(&mut self, msg: Encrypt, _: &Context<Self>) -> Self::Result {
+        let key = self.load_key(&msg.key_id).await?;
+        // 큰 페이로드의 암호화가 메일박스를 막지 않도록 블로킹 스레드(웹에서는 워커)로 넘긴다
+        let job = CipherJob::Encrypt {
+            key,
+            plaintext: msg.plaintext,
+        };
+        Ok(offload(job).await??)
+    }
+}
+
+#[async_trait]
+impl Handler<Decrypt> for CryptoActor {
+    type Result = Result<Vec<u8>, CryptoError>;
+
+    async fn handle(&mut self, msg: Decrypt, _: &Context<Self>) -> Self::Result {
+        if msg.ciphertext.len() < NONCE_LEN {
+            return Err("Ciphertext too short".into());
+        }
+
+        let key = self.load_key(&msg.key_id).await?;
+        let job = CipherJob::Decrypt {
+            key,
+            ciphertext: msg.ciphertext,
+        };
+        Ok(offload(job).await??)
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<GenerateKeyRequest> for CryptoActor {
+    async fn notify(&mut self, msg: GenerateKeyRequest, ctx: &Context<Self>) {
+        let key_id = msg.key_id.clone();
+        let result = self.handle(GenerateKey { key_id: msg.key_id }, ctx).await;
+
+        KeyReadySignal {
+            key_id,
+            success: result.is_ok(),
+            error: result.err().map(|e| e.to_string()),
+        }
+        .emit();
+    }
+}
+
+#[async_trait]
+impl Notifiable<DartEncrypt> for CryptoActor {
+    async fn notify(&mut self, msg: DartEncrypt, ctx: &Context<Self>) {
+        let DartEncrypt(request, plaintext) = msg;
+        let result = self
+            .handle(
+                Encrypt {
+                    key_id: request.key_id,
+                    plaintext,
+                },
+                ctx,
+            )
+            .await;
+
+        send_crypto_result(request.request_id, result);
+    }
+}
+
+#[async_trait]
+impl Notifiable<DartDecrypt> for CryptoActor {
+    async fn notify(&mut self, msg: DartDecrypt, ctx: &Context<Self>) {
+        let DartDecrypt(request, ciphertext) = msg;
+        let result = self
+            .handle(
+                Decrypt {
+                    key_id: request.key_id,
+                    ciphertext,
+                },
+                ctx,
+            )
+            .await;
+
+        send_crypto_result(request.request_id, result);
+    }
+}
+
+#[async_trait]
+impl Notifiable<CreateKeyPairRequest> for CryptoActor {
+    async fn notify(&mut self, msg: CreateKeyPairRequest, _: &Context<Self>) {
+        let result = self.create_key_pair(&msg.key_pair_id).await;
+
+        PublicKeySignal {
+            key_pair_id: msg.key_pair_id,
+            public_key: result.as_ref().cloned().unwrap_or_default(),
+            error: result.err().map(|e| e.to_string()),
+        }
+        .emit();
+    }
+}
+
+#[async_trait]
+impl Notifiable<DeriveSharedKeyRequest> for CryptoActor {
+    async fn notify(&mut self, msg: DeriveSharedKeyRequest, _: &Context<Self>) {
+        let result = self.derive_shared_key(&msg).await;
+
+        KeyReadySignal {
+            key_id: msg.key_id,
+            success: result.is_ok(),
+            error: result.err().map(|e| e.to_string()),
+        }
+        .emit();
+    }
+}
+
+#[async_trait]
+impl Notifiable<RotateEncryptionKeyRequest> for CryptoActor {
+    async fn notify(&mut self, _: RotateEncryptionKeyRequest, ctx: &Context<Self>) {
+        // 진행 상황은 교체 작업이 보낸다
+        if let Err(e) = self.handle(RotateEncryptionKey, ctx).await {
+            EncryptionKeyRotationSignal {
+                key_id: String::new(),
+                done: 0,
+                total: 0,
+                finished: false,
+                error: Some(e.to_string()),
+            }
+            .emit();
+        }
+    }
+}
+
+#[async_trait]
+impl Notifiable<ResumeKeyRotation> for CryptoActor {
+    async fn notify(&mut self, _: ResumeKeyRotation, _: &Context<Self>) {
+        match self.secret_store.pending_rotation().await {
+            Ok(Some(key_id)) => {
+                debug_print!("Resuming encryption key rotation: {}", key_id);
+                self.start_rotation_job(key_id);
+            }
+            Ok(None) => {}
+            Err(e) => debug_print!("Failed to read key rotation state: {}", e),
+        }
+    }
+}
+
+fn send_crypto_result(request_id: String, result: Result<Vec<u8>, CryptoError>) {
+    match result {
+        Ok(bytes) => {
+            CryptoResultSignal {
+                request_id,
+                success: true,
+                error: None,
+            }
+            .emit_binary(bytes);
+        }
+        Err(e) => {
+            CryptoResultSignal {
+                request_id,
+                success: false,
+                error: Some(e.to_string()),
+            }
+            .emit_binary(Vec::new());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::CryptoActor;
+    use crate::study_actors::{
+        messages::{CryptoError, Decrypt, Encrypt, GenerateKey, RotateEncryptionKey},
+        signals::EncryptionKeyRotationSignal,
+        storage::{MemorySecretStore, SealedSecretStore, SecretStore},
+        test_support::{TestActorHarness, settle},
+    };
+
+    #[tokio::test]
+    async fn rotation_reports_progress_and_keeps_keys_usable() -> Result<(), CryptoError> {
+        let store = Arc::new(SealedSecretStore::new(Arc::new(MemorySecretStore::new())));
+        let mut crypto = TestActorHarness::start(|addr| CryptoActor::new(addr, store.clone()));
+        for key_id in ["a", "b"] {
+            crypto
+                .send(GenerateKey {
+                    key_id: key_id.to_string(),
+                })
+                .await??;
+        }
+        let ciphertext = crypto
+            .send(Encrypt {
+                key_id: "a".to_string(),
+                plaintext: b"hello".to_vec(),
+            })
+            .await??;
+
+        let key_id = crypto.send(RotateEncryptionKey).await??;
+        let mut progress = Vec::new();
+        for _ in 0..10 {
+            settle().await;
+            progress.extend(crypto.signals_of::<EncryptionKeyRotationSignal>());
+            if progress.last().is_some_and(|signal| signal.finished) {
+                break;
+            }
+        }
+        let steps: Vec<_> = progress
+            .iter()
+            .map(|signal| {
+                (
+                    signal.key_id.as_str(),
+                    signal.done,
+                    signal.total,
+                    signal.finished,
+                )
+            })
+            .collect();
+        assert_eq!(
+            steps,
+            vec![
+                (key_id.as_str(), 1, 2, false),
+                (key_id.as_str(), 2, 2, false),
+                (key_id.as_str(), 2, 2, true)
+            ]
+        );
+        assert_eq!(store.pending_rotation().await?, None);
+
+        let plaintext = crypto
+            .send(Decrypt {
+                key_id: "a".to_string(),
+                ciphertext,
+            })
+            .await??;
+        assert_eq!(plaintext, b"hello");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn interrupted_rotation_resumes_when_the_actor_starts() -> Result<(), CryptoError> {
+        let store = Arc::new(SealedSecretStore::new(Arc::new(MemorySecretStore::new())));
+        for name in ["aes_key/a", "aes_key/b", "x25519/c"] {
+            store.put_secret(name, name.as_bytes()).await?;
+        }
+        // 첫 값만 다시 봉인하고 멈춘 상태에서 액터가 새로 뜬다
+        let key_id = store.begin_rotation().await?;
+        store.rotate_step().await?;
+
+        let mut crypto = TestActorHarness::start(|addr| CryptoActor::new(addr, store.clone()));
+        let mut progress = Vec::new();
+        for _ in 0..10 {
+            settle().await;
+            progress.extend(crypto.signals_of::<EncryptionKeyRotationSignal>());
+            if progress.last().is_some_and(|signal| signal.finished) {
+                break;
+            }
+        }
+        let steps: Vec<_> = progress
+            .iter()
+            .map(|signal| (signal.key_id.as_str(), signal.done, signal.finished))
+            .collect();
+        assert_eq!(
+            steps,
+            vec![
+                (key_id.as_str(), 2, false),
+                (key_id.as_str(), 3, false),
+                (key_id.as_str(), 3, true)
+            ]
+        );
+        assert_eq!(store.pending_rotation().await?, None);
+        assert_eq!(
+            store.get_secret("aes_key/b").await?,
+            Some(b"aes_key/b".to_vec())
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,409 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde_json::Value;
+use std::{collections::HashMap, str::FromStr, time::Duration};
+use tokio::task::JoinSet;
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        ActorResult, ArithmeticOp, CalculateMoney, Conversion, ConvertCurrency, CurrencyConfig,
+        FormatMoney, RoundingMode, RoundingRule, UserError,
+    },
+    signals::{
+        CalculateMoneyRequest, ConvertCurrencyRequest, FormatMoneyRequest, respond_to_dart,
+        route_dart_signals,
+    },
+};
+
+use super::network::NetworkRequest;
+use super::{Clock, Instant, NetworkManagerActor, Priority, PriorityMailbox};
+
+// 기준 통화 하나의 환율표
+struct CachedRates {
+    rates: HashMap<String, Decimal>,
+    published_at: i64, // 제공자가 밝힌 갱신 시각 (없으면 받은 시각)
+    fetched: Instant,
+}
+
+// 통화 금액 액터
+// 금액은 Decimal로 계산해 부동소수점 오차 없이 통화별 소수 자릿수로 반올림한다.
+pub struct CurrencyActor {
+    rates: HashMap<String, CachedRates>,
+    config: CurrencyConfig,
+    network_manager: PriorityMailbox<NetworkManagerActor>,
+    clock: Clock,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for CurrencyActor {}
+
+impl CurrencyActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        network_manager: PriorityMailbox<NetworkManagerActor>,
+        config: CurrencyConfig,
+        clock: Clock,
+    ) -> Self {
+        let owned_tasks = route_dart_signals!(
+            self_addr,
+            [
+                CalculateMoneyRequest,
+                ConvertCurrencyRequest,
+                FormatMoneyRequest
+            ]
+        );
+        Self {
+            rates: HashMap::new(),
+            config,
+            network_manager,
+            clock,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    // from 1단위당 to 환율 (캐시가 만료됐으면 다시 받고, 받지 못하면 만료된 환율이라도 쓴다)
+    async fn rate(&mut self, from: &str, to: &str) -> ActorResult<(Decimal, i64)> {
+        let ttl = Duration::from_secs(self.config.rates_ttl_secs);
+        let now = self.clock.now();
+        let fresh = self
+            .rates
+            .get(from)
+            .is_some_and(|cached| now.saturating_duration_since(cached.fetched) < ttl);
+        if !fresh {
+            match self.fetch_rates(from).await {
+                Ok(rates) => {
+                    self.rates.insert(from.to_string(), rates);
+                }
+                Err(e) if self.rates.contains_key(from) => {
+                    debug_print!("Using stale {} exchange rates: {}", from, e);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        let cached = self
+            .rates
+            .get(from)
+            .ok_or_else(|| UserError::NotFound(format!("Exchange rates for {}", from)))?;
+        let rate = cached
+            .rates
+            .get(to)
+            .copied()
+            .ok_or_else(|| UserError::NotFound(format!("Exchange rate {} -> {}", from, to)))?;
+        Ok((rate, cached.published_at))
+    }
+
+    async fn fetch_rates(&mut self, base: &str) -> ActorResult<CachedRates> {
+        let url = self.config.rates_url.replace("{base}", base);
+        let response = self
+            .network_manager
+            .request(Priority::High, NetworkRequest::new(url).timeout(10_000))
+            .await
+            .map_err(|e| UserError::Upstream(e.to_string()))??;
+        if !response.is_success() {
+            return Err(UserError::Upstream(format!(
+                "Failed to download exchange rates: {}",
+                response.status
+            )));
+        }
+
+        let body = response.json::<Value>()?;
+        // 숫자는 문자열로 옮겨 Decimal로 읽는다 (f64를 거치면 자릿수가 틀어진다)
+        let rates: HashMap<String, Decimal> = body
+            .get("rates")
+            .and_then(Value::as_object)
+            .into_iter()
+            .flatten()
+            .filter_map(|(code, rate)| {
+                let text = match rate {
+                    Value::Number(number) => number.to_string(),
+                    Value::String(text) => text.clone(),
+                    _ => return None,
+                };
+                let rate = Decimal::from_str(&text)
+                    .or_else(|_| Decimal::from_scientific(&text))
+                    .ok()?;
+                Some((code.to_ascii_uppercase(), rate))
+            })
+            .collect();
+        if rates.is_empty() {
+            return Err(UserError::NotFound(format!("Exchange rates for {}", base)));
+        }
+
+        Ok(CachedRates {
+            rates,
+            published_at: body
+                .get("time_last_update_unix")
+                .and_then(Value::as_i64)
+                .unwrap_or_else(|| Utc::now().timestamp()),
+            fetched: self.clock.now(),
+        })
+    }
+}
+
+// 통화 기호와 소수 자릿수 (ISO 4217, 표에 없는 통화는 코드와 2자리)
+fn currency_info(code: &str) -> (Option<&'static str>, u32) {
+    match code {
+        "USD" => (Some("$"), 2),
+        "EUR" => (Some("€"), 2),
+        "GBP" => (Some("£"), 2),
+        "JPY" => (Some("¥"), 0),
+        "KRW" => (Some("₩"), 0),
+        "CNY" => (Some("¥"), 2),
+        "INR" => (Some("₹"), 2),
+        "CAD" => (Some("CA$"), 2),
+        "AUD" => (Some("A$"), 2),
+        "VND" => (Some("₫"), 0),
+        "BHD" | "KWD" | "OMR" | "JOD" => (None, 3),
+        _ => (None, 2),
+    }
+}
+
+fn currency_code(code: &str) -> ActorResult<String> {
+    let code = code.trim().to_ascii_uppercase();
+    if code.len() != 3 || !code.bytes().all(|b| b.is_ascii_alphabetic()) {
+        return Err(UserError::InvalidInput(format!(
+            "{} is not an ISO 4217 currency code",
+            code
+        )));
+    }
+    Ok(code)
+}
+
+fn parse_amount(field: &str, text: &str) -> ActorResult<Decimal> {
+    Decimal::from_str(text.trim())
+        .map_err(|e| UserError::InvalidInput(format!("{} is not a decimal amount: {}", field, e)))
+}
+
+fn out_of_range() -> UserError {
+    UserError::InvalidInput("Amount is out of range".to_string())
+}
+
+fn strategy(mode: RoundingMode) -> RoundingStrategy {
+    match mode {
+        RoundingMode::HalfEven => RoundingStrategy::MidpointNearestEven,
+        RoundingMode::HalfUp => RoundingStrategy::MidpointAwayFromZero,
+        RoundingMode::Down => RoundingStrategy::ToZero,
+        RoundingMode::Up => RoundingStrategy::AwayFromZero,
+        RoundingMode::Floor => RoundingStrategy::ToNegativeInfinity,
+        RoundingMode::Ceiling => RoundingStrategy::ToPositiveInfinity,
+    }
+}
+
+// 규칙이 없으면 통화 소수 자릿수로 은행가 반올림
+fn round_money(amount: Decimal, code: &str, rule: Option<&RoundingRule>) -> ActorResult<Decimal> {
+    let strategy = strategy(rule.map_or(RoundingMode::HalfEven, |rule| rule.mode));
+    let Some(increment) = rule.and_then(|rule| rule.increment.as_deref()) else {
+        let (_, minor_units) = currency_info(code);
+        return Ok(amount.round_dp_with_strategy(minor_units, strategy));
+    };
+
+    let increment = parse_amount("increment", increment)?;
+    if increment <= Decimal::ZERO {
+        return Err(UserError::InvalidInput(
+            "Rounding increment must be positive".to_string(),
+        ));
+    }
+    amount
+        .checked_div(increment)
+        .ok_or_else(out_of_range)?
+        .round_dp_with_strategy(0, strategy)
+        .checked_mul(increment)
+        .ok_or_else(out_of_range)
+}
+
+// 언어별 숫자 표기 (그룹 구분자, 소수점, 기호 위치)의 간략한 구현
+struct NumberStyle {
+    group: &'static str,
+    decimal: &'static str,
+    symbol_first: bool,
+}
+
+fn number_style(locale: &str) -> NumberStyle {
+    let language = locale.split(['-', '_']).next().unwrap_or(locale);
+    match language {
+        "de" | "es" | "it" | "nl" | "pt" | "id" | "tr" | "da" => NumberStyle {
+            group: ".",
+            decimal: ",",
+            symbol_first: false,
+        },
+        "fr" | "ru" | "uk" | "pl" | "cs" | "sv" | "nb" | "fi" => NumberStyle {
+            group: "\u{202f}",
+            decimal: ",",
+            symbol_first: false,
+        },
+        _ => NumberStyle {
+            group: ",",
+            decimal: ".",
+            symbol_first: true,
+        },
+    }
+}
+
+fn format_money(amount: Decimal, code: &str, locale: &str) -> String {
+    let (symbol, minor_units) = currency_info(code);
+    let rounded = amount.round_dp_with_strategy(minor_units, RoundingStrategy::MidpointNearestEven);
+    let digits = format!("{:.*}", minor_units as usize, rounded.abs());
+    let (integer, fraction) = digits.split_once('.').unwrap_or((&digits, ""));
+
+    let style = number_style(locale);
+    let mut number = String::new();
+    for (i, digit) in integer.chars().enumerate() {
+        if i > 0 && (integer.len() - i) % 3 == 0 {
+            number.push_str(style.group);
+        }
+        number.push(digit);
+    }
+    if !fraction.is_empty() {
+        number.push_str(style.decimal);
+        number.push_str(fraction);
+    }
+
+    let sign = if rounded.is_sign_negative() && !rounded.is_zero() {
+        "-"
+    } else {
+        ""
+    };
+    match (symbol, style.symbol_first) {
+        (Some(symbol), true) => format!("{}{}{}", sign, symbol, number),
+        (None, true) => format!("{}{}\u{a0}{}", sign, code, number),
+        (symbol, false) => format!("{}{}\u{a0}{}", sign, number, symbol.unwrap_or(code)),
+    }
+}
+
+#[async_trait]
+impl Handler<CalculateMoney> for CurrencyActor {
+    type Result = ActorResult<Decimal>;
+
+    async fn handle(&mut self, msg: CalculateMoney, _: &Context<Self>) -> Self::Result {
+        let code = currency_code(&msg.currency)?;
+        let (left, right) = (msg.left, msg.right);
+        let result = match msg.op {
+            ArithmeticOp::Add => left.checked_add(right),
+            ArithmeticOp::Subtract => left.checked_sub(right),
+            ArithmeticOp::Multiply => left.checked_mul(right),
+            ArithmeticOp::Divide if right.is_zero() => {
+                return Err(UserError::InvalidInput("Division by zero".to_string()));
+            }
+            ArithmeticOp::Divide => left.checked_div(right),
+        }
+        .ok_or_else(out_of_range)?;
+        round_money(result, &code, msg.rounding.as_ref())
+    }
+}
+
+#[async_trait]
+impl Handler<ConvertCurrency> for CurrencyActor {
+    type Result = ActorResult<Conversion>;
+
+    async fn handle(&mut self, msg: ConvertCurrency, _: &Context<Self>) -> Self::Result {
+        let from = currency_code(&msg.from)?;
+        let to = currency_code(&msg.to)?;
+        let (rate, rates_fetched_at) = if from == to {
+            (Decimal::ONE, Utc::now().timestamp())
+        } else {
+            self.rate(&from, &to).await?
+        };
+        let amount = msg.amount.checked_mul(rate).ok_or_else(out_of_range)?;
+        Ok(Conversion {
+            amount: round_money(amount, &to, msg.rounding.as_ref())?,
+            rate,
+            rates_fetched_at,
+        })
+    }
+}
+
+#[async_trait]
+impl Handler<FormatMoney> for CurrencyActor {
+    type Result = ActorResult<String>;
+
+    async fn handle(&mut self, msg: FormatMoney, _: &Context<Self>) -> Self::Result {
+        let code = currency_code(&msg.currency)?;
+        Ok(format_money(msg.amount, &code, &msg.locale))
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<CalculateMoneyRequest> for CurrencyActor {
+    async fn notify(&mut self, msg: CalculateMoneyRequest, ctx: &Context<Self>) {
+        let _ = respond_to_dart(msg, |msg| async move {
+            let calculate = CalculateMoney {
+                currency: msg.currency,
+                op: msg.op,
+                left: parse_amount("left", &msg.left)?,
+                right: parse_amount("right", &msg.right)?,
+                rounding: msg.rounding,
+            };
+            Ok(self.handle(calculate, ctx).await?.to_string())
+        })
+        .await;
+    }
+}
+
+#[async_trait]
+impl Notifiable<ConvertCurrencyRequest> for CurrencyActor {
+    async fn notify(&mut self, msg: ConvertCurrencyRequest, ctx: &Context<Self>) {
+        let _ = respond_to_dart(msg, |msg| async move {
+            let convert = ConvertCurrency {
+                amount: parse_amount("amount", &msg.amount)?,
+                from: msg.from,
+                to: msg.to,
+                rounding: msg.rounding,
+            };
+            Ok(self.handle(convert, ctx).await?)
+        })
+        .await;
+    }
+}
+
+#[async_trait]
+impl Notifiable<FormatMoneyRequest> for CurrencyActor {
+    async fn notify(&mut self, msg: FormatMoneyRequest, ctx: &Context<Self>) {
+        let _ = respond_to_dart(msg, |msg| async move {
+            let format = FormatMoney {
+                amount: parse_amount("amount", &msg.amount)?,
+                currency: msg.currency,
+                locale: msg.locale,
+            };
+            Ok(self.handle(format, ctx).await?)
+        })
+        .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+    use std::{error::Error, str::FromStr};
+
+    use super::{format_money, round_money};
+    use crate::study_actors::messages::{RoundingMode, RoundingRule};
+
+    #[test]
+    fn rounds_to_currency_rules_and_formats_per_locale() -> Result<(), Box<dyn Error>> {
+        let amount = Decimal::from_str("1234.565")?;
+        assert_eq!(round_money(amount, "USD", None)?.to_string(), "1234.56");
+        assert_eq!(round_money(amount, "KRW", None)?.to_string(), "1235");
+        assert_eq!(round_money(amount, "BHD", None)?.to_string(), "1234.565");
+
+        let cash = RoundingRule {
+            mode: RoundingMode::HalfUp,
+            increment: Some("0.05".to_string()),
+        };
+        let rounded = round_money(Decimal::from_str("12.324")?, "CHF", Some(&cash))?;
+        assert_eq!(rounded, Decimal::from_str("12.30")?);
+
+        assert_eq!(format_money(amount, "USD", "en-US"), "$1,234.56");
+        assert_eq!(format_money(-amount, "EUR", "de-DE"), "-1.234,56\u{a0}€");
+        assert_eq!(format_money(amount, "KRW", "ko"), "₩1,235");
+        assert_eq!(format_money(amount, "CHF", "en"), "CHF\u{a0}1,234.56");
+        Ok(())
+    }
+}
@@ -1,63 +1,458 @@
 use async_trait::async_trait;
-use chrono::Utc;
 use messages::{
     actor::Actor,
     prelude::{Address, Context, Handler, Notifiable},
 };
-use rinf::{RustSignal, debug_print};
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, HashMap, HashSet},
+    sync::Arc,
+};
 use tokio::task::JoinSet;
 
 use crate::study_actors::{
+    logging::debug_print,
     messages::{
-        CacheData, DataItem, FetchData, FetchRecentData, StoreData, UserData, UserError, UserId,
+        ActorResult, AddItemToCollection, AddTag, ApiError, AppEvent, CacheData, CaptureSnapshot,
+        CategoryUsage, CheckQuota, ClearStorage, Collection, CompactStorage, CompactionStats,
+        CreateCollection, CreateDataItem, DataItem, DeleteData, DeletedItem, FetchData, FetchMode,
+        FetchPagedRemote, FetchRecentData, GetItemEndpoint, GetItemHistory, IdKind, ItemListQuery,
+        ItemPage, ItemRevision, ItemsByTag, ListItems, ListItemsEndpoint, MeasureStorage,
+        MergeItems, MutationKind, OpenSyncItems, PersistState, PersistedState, PushItemsBody,
+        PushItemsEndpoint, PushLocalChanges, QuotaMetric, RecordItemAttachment, RecordUsage,
+        RedoLastChange, RemoteItemPage, RemoveTag, RestoreState, RevertItemToRevision, ScanPrefix,
+        ScheduledTask, SealSyncItems, SetItemContent, SetStorageWritesPaused,
+        SetSubsystemSuspended, StorageCategory, StorageError, StoreData, SuspendReason,
+        SyncPayload, UndoLastChange, UserData, UserError, UserId, WipeUserData,
     },
     signals::{
-        CreateDataItemRequest, DataItemCreatedSignal, DataItemDeletedSignal, DataItemUpdatedSignal,
-        DeleteDataItemRequest, FetchUserDataRequest, UpdateDataItemRequest, UserDataResponse,
+        AddItemToCollectionRequest, AddTagRequest, CollectionListSignal, CollectionUpdatedSignal,
+        CreateCollectionRequest, CreateDataItemRequest, DataChangedSignal, DataItemConflictSignal,
+        DataItemCreatedSignal, DataItemDeletedSignal, DataItemUpdatedSignal, DataRefreshedSignal,
+        DeleteDataItemRequest, EmitSignal, FetchPagedRemoteRequest, FetchUserDataRequest,
+        GetItemHistoryRequest, HistoryActionSignal, ItemHistorySignal, ItemTagsChangedSignal,
+        ItemsByTagRequest, ItemsByTagSignal, ItemsMergedSignal, ListCollectionsRequest,
+        ListTagsRequest, LocalChangesPushedSignal, MergeItemsRequest, MutationConfirmedSignal,
+        MutationRolledBackSignal, PushLocalChangesRequest, RedoRequest, RemoveTagRequest,
+        RevertItemToRevisionRequest, StateSnapshotSignal, TagListSignal, UndoRequest,
+        UpdateDataItemRequest, UserDataResponse, route_dart_signals,
     },
+    storage::Storage,
+};
+
+use super::{
+    ApiClient, Clock, EventBus, Priority, PriorityMailbox, QuotaActor, SyncCryptoActor, Timed,
+    TraceId, Traced, TrackedTask, TrustedClock,
+    auth::{LAST_SESSION_KEY, LastSession},
+    diff::merge_text,
+    history::{self, UndoStack},
+    id_gen::generate_id,
+    idempotency::IdempotencyWindow,
+    lanes::prioritize,
+    metrics::instrument,
+    migrations,
+    tags::{TagIndex, UserTagIndex},
+    trace::traced,
 };
 
-use super::NetworkManagerActor;
+const TAG_INDEX_KEY: &str = "tags/by_user";
+const COLLECTIONS_KEY: &str = "collections/by_user";
+// 사용자별로 나누기 전의 전역 태그 색인과 컬렉션 (처음 불러올 때 나눠 옮긴다)
+const LEGACY_TAG_INDEX_KEY: &str = "tags/index";
+const LEGACY_COLLECTIONS_KEY: &str = "collections";
+const IDEMPOTENCY_KEY: &str = "idempotency/data";
+const OWNERS_CLAIMED_KEY: &str = "meta/item_owners_claimed";
+const DEFAULT_PAGE_SIZE: usize = 20;
+const MAX_PAGE_SIZE: usize = 100;
+const DEFAULT_REMOTE_PAGE_SIZE: usize = 50;
+const SNAPSHOT_ITEMS: usize = 20; // 시작 스냅샷에 넣을 최근 항목 수
+const STATE_VERSION: u32 = 1; // 종료 시 남기는 대기 중 가져오기 기록의 구조 버전
+// 서버가 다음 페이지를 끝없이 알려 줘도 여기서 멈춘다
+const MAX_REMOTE_PAGES: usize = 1000;
 
 // 데이터 관리자 액터
+// 항목은 items/{id} 키로 저장하고, 사용자별 태그 색인과 컬렉션은 처음 사용할 때 불러온다.
+// 수정할 때마다 직전 상태를 history/{id}에 리비전으로 남긴다.
 pub struct DataManagerActor {
     cache_actor: Address<CacheActor>,
     storage_actor: Address<StorageActor>,
-    network_manager: Option<Address<NetworkManagerActor>>,
+    api: Option<ApiClient>, // 서버 API가 설정된 경우에만 있다
+    remote_fetches: HashMap<UserId, TrackedTask>, // 사용자별 진행 중인 원격 페이지 가져오기
+    remote_pushes: HashMap<UserId, TrackedTask>, // 사용자별 진행 중인 변경 올리기
+    revalidating: HashSet<String>, // 만료된 값을 돌려주고 서버에서 다시 받는 중인 항목 키
+    deferred_fetches: HashMap<UserId, Option<usize>>, // 백그라운드 모드나 절전 중 미뤄 둔 가져오기
+    deferred_pushes: HashMap<UserId, bool>, // 같은 이유로 미뤄 둔 올리기 (전부 다시 올릴지)
+    sync_suspended: HashSet<SuspendReason>,
+    event_bus: Option<EventBus>,
+    quota: Option<Address<QuotaActor>>, // 없으면 사용량 한도를 확인하지 않는다
+    sync_crypto: Option<Address<SyncCryptoActor>>, // 없으면 서버와 평문 항목만 주고받는다
+    default_cache_ttl: u64,
+    tag_index: UserTagIndex,
+    collections: BTreeMap<UserId, Vec<Collection>>,
+    idempotency: IdempotencyWindow<RecordedMutation>, // (사용자, 변경 종류, 멱등 키) → 변경 결과
+    clock: TrustedClock,
+    index_loaded: bool,
+    owners_claimed: bool, // 주인 없는 예전 항목을 이미 옮겼는지 (ensure_loaded에서 읽는다)
+    undo_stack: UndoStack,
+    lanes: PriorityMailbox<Self>,
     _owned_tasks: JoinSet<()>,
 }
 
+// 멱등 키로 처리한 변경의 결과 (같은 키가 다시 오면 같은 신호를 다시 보낸다)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum MutationReplay {
+    Created(DataItem),
+    Updated(DataItem),
+    Deleted(String),
+}
+
+impl MutationReplay {
+    fn item_id(&self) -> &str {
+        match self {
+            Self::Created(item) | Self::Updated(item) => &item.id,
+            Self::Deleted(item_id) => item_id,
+        }
+    }
+}
+
+// 결과와 함께 요청 내용의 해시를 남겨 같은 키로 다른 내용이 오면 알아챈다
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RecordedMutation {
+    payload: Vec<u8>,
+    outcome: MutationReplay,
+}
+
 impl Actor for DataManagerActor {}
 
+// Dart 요청은 Timed로 받아 처리 지연을 지표 액터에 남긴다
+instrument!(
+    DataManagerActor,
+    notify:
+    FetchUserDataRequest, CreateDataItemRequest, UpdateDataItemRequest, DeleteDataItemRequest,
+    AddTagRequest, RemoveTagRequest, ListTagsRequest, CreateCollectionRequest,
+    AddItemToCollectionRequest, ListCollectionsRequest, ItemsByTagRequest, GetItemHistoryRequest,
+    RevertItemToRevisionRequest, UndoRequest, RedoRequest, FetchPagedRemoteRequest,
+    PushLocalChangesRequest, MergeItemsRequest,
+);
+
+// 감독자의 로그인 흐름에서 Traced로 받는다
+traced!(DataManagerActor, handle: FetchRecentData);
+
+// Dart 요청은 고우선, 일괄 가져오기와 올리기는 저우선 갈래로 받는다 (new 참고)
+prioritize!(
+    DataManagerActor,
+    notify:
+    Timed<FetchUserDataRequest>, Timed<CreateDataItemRequest>, Timed<UpdateDataItemRequest>,
+    Timed<DeleteDataItemRequest>, Timed<AddTagRequest>, Timed<RemoveTagRequest>,
+    Timed<ListTagsRequest>, Timed<CreateCollectionRequest>, Timed<AddItemToCollectionRequest>,
+    Timed<ListCollectionsRequest>, Timed<ItemsByTagRequest>, Timed<GetItemHistoryRequest>,
+    Timed<RevertItemToRevisionRequest>, Timed<UndoRequest>, Timed<RedoRequest>,
+    Timed<FetchPagedRemoteRequest>, Timed<PushLocalChangesRequest>, Timed<MergeItemsRequest>,
+);
+
 impl DataManagerActor {
-    pub fn new(cache_actor: Address<CacheActor>, storage_actor: Address<StorageActor>) -> Self {
+    pub fn new(
+        self_addr: Address<Self>,
+        cache_actor: Address<CacheActor>,
+        storage_actor: Address<StorageActor>,
+        default_cache_ttl: u64,
+        clock: TrustedClock,
+    ) -> Self {
+        let lanes = PriorityMailbox::new(self_addr.clone());
+        let mut owned_tasks = route_dart_signals!(
+            lanes,
+            [
+                FetchUserDataRequest => Priority::High,
+                CreateDataItemRequest => Priority::High,
+                UpdateDataItemRequest => Priority::High,
+                DeleteDataItemRequest => Priority::High,
+                AddTagRequest => Priority::High,
+                RemoveTagRequest => Priority::High,
+                ListTagsRequest => Priority::High,
+                CreateCollectionRequest => Priority::High,
+                AddItemToCollectionRequest => Priority::High,
+                ListCollectionsRequest => Priority::High,
+                ItemsByTagRequest => Priority::High,
+                GetItemHistoryRequest => Priority::High,
+                RevertItemToRevisionRequest => Priority::High,
+                UndoRequest => Priority::High,
+                RedoRequest => Priority::High,
+                MergeItemsRequest => Priority::High,
+                // 서버와 주고받는 일괄 동기화는 화면이 기다리는 요청을 앞지르지 않는다
+                FetchPagedRemoteRequest => Priority::Low,
+                PushLocalChangesRequest => Priority::Low,
+            ]
+        );
+        // 업그레이드 뒤 첫 로그인보다 먼저 주인 없는 항목을 옮기도록 시작하자마자 불러온다
+        owned_tasks.spawn(Self::load_indexes(self_addr));
+
         Self {
             cache_actor,
             storage_actor,
-            network_manager: None,
-            _owned_tasks: JoinSet::new(),
+            api: None,
+            remote_fetches: HashMap::new(),
+            remote_pushes: HashMap::new(),
+            revalidating: HashSet::new(),
+            deferred_fetches: HashMap::new(),
+            deferred_pushes: HashMap::new(),
+            sync_suspended: HashSet::new(),
+            event_bus: None,
+            quota: None,
+            sync_crypto: None,
+            default_cache_ttl,
+            tag_index: UserTagIndex::default(),
+            collections: BTreeMap::new(),
+            idempotency: IdempotencyWindow::default(),
+            clock,
+            index_loaded: false,
+            owners_claimed: false,
+            undo_stack: UndoStack::default(),
+            lanes,
+            _owned_tasks: owned_tasks,
         }
     }
 
-    fn generate_item_id(&self) -> String {
-        format!("item_{}", Utc::now().timestamp_millis())
+    async fn load_indexes(mut self_addr: Address<Self>) {
+        let _ = self_addr.notify(LoadIndexes).await;
     }
 
-    // 네트워크 매니저 액터 주소를 설정하는 메서드 추가
-    pub fn set_network_manager(&mut self, network_manager: Address<NetworkManagerActor>) {
-        debug_print!("Setting network manager for DataManagerActor");
-        self.network_manager = Some(network_manager);
+    // 일괄 작업을 저우선으로 보낼 수 있도록 다른 액터에 넘겨 준다
+    pub fn lanes(&self) -> PriorityMailbox<Self> {
+        self.lanes.clone()
     }
-}
 
-#[async_trait]
-impl Handler<FetchData> for DataManagerActor {
-    type Result = Result<Vec<u8>, UserError>;
+    fn item_key(item_id: &str) -> String {
+        format!("items/{}", item_id)
+    }
 
-    async fn handle(&mut self, msg: FetchData, _: &Context<Self>) -> Self::Result {
+    // 접두사 조회로 저장된 모든 항목을 읽는다 (해석할 수 없는 항목은 건너뛴다)
+    async fn load_all_items(&mut self) -> Result<Vec<DataItem>, UserError> {
+        self.ensure_loaded().await;
+        let entries = self
+            .storage_actor
+            .send(Traced::new(ScanPrefix {
+                prefix: Self::item_key(""),
+            }))
+            .await??;
+        Ok(entries
+            .iter()
+            .filter_map(|(_, data)| serde_json::from_slice::<DataItem>(data).ok())
+            .map(|mut item| {
+                item.tags = self.tag_index.tags_of(&item.owner, &item.id);
+                item
+            })
+            .collect())
+    }
+
+    // 요청한 사용자가 만든 항목만 (다른 사용자의 항목은 뺀다)
+    async fn load_user_items(&mut self, user_id: &str) -> Result<Vec<DataItem>, UserError> {
+        self.ensure_loaded().await;
+        let mut items = self.load_all_items().await?;
+        items.retain(|item| item.owner == user_id);
+        Ok(items)
+    }
+
+    // 주인을 남기기 전에 저장된 항목은 업그레이드 전에 마지막으로 로그인해 있던 사용자에게 넘긴다
+    // 그런 기록이 없으면 아무에게도 넘기지 않는다 (공용 기기에서 다음 사용자가 가져가지 않도록).
+    // 한 번 옮기면 표시를 남기므로 나중에 주인 없는 항목이 생겨도 다른 사용자가 가져가지 않는다.
+    // 저장하지 못하면 표시를 남기지 않고 다음 시작 때 다시 옮긴다.
+    async fn migrate_unowned_items(&mut self) -> Result<usize, UserError> {
+        let last_session = FetchData {
+            key: LAST_SESSION_KEY.to_string(),
+            user_id: None,
+            mode: FetchMode::CacheFirst,
+        };
+        let last_user = match self.fetch_cached(last_session).await {
+            Ok(bytes) => serde_json::from_slice::<LastSession>(&bytes)
+                .ok()
+                .map(|session| session.user_id)
+                .filter(|user_id| !user_id.is_empty()),
+            Err(_) => None,
+        };
+
+        let mut migrated = 0;
+        if let Some(user_id) = last_user {
+            // 불러오는 중이므로 load_all_items 대신 저장된 그대로 읽는다
+            let entries = self
+                .storage_actor
+                .send(Traced::new(ScanPrefix {
+                    prefix: Self::item_key(""),
+                }))
+                .await??;
+            let items = entries
+                .iter()
+                .filter_map(|(_, data)| serde_json::from_slice::<DataItem>(data).ok());
+            for mut item in items {
+                if item.owner.is_empty() {
+                    item.owner = user_id.clone();
+                    self.write_item(&item).await?;
+                    migrated += 1;
+                }
+            }
+            if self.tag_index.transfer("", &user_id) {
+                self.save_tag_index().await?;
+            }
+            if let Some(unowned) = self.collections.remove("") {
+                self.collections.entry(user_id).or_default().extend(unowned);
+                self.save_collections().await?;
+            }
+        }
+        self.persist(OWNERS_CLAIMED_KEY.to_string(), b"true".to_vec())
+            .await?;
+        self.owners_claimed = true;
+        Ok(migrated)
+    }
+
+    // 저장된 항목이 다른 사용자의 것이면 없는 항목처럼 거절한다 (없으면 None)
+    async fn load_owned_item(
+        &mut self,
+        user_id: &str,
+        item_id: &str,
+    ) -> Result<Option<DataItem>, UserError> {
+        self.ensure_loaded().await;
+        match self.load_item(item_id).await {
+            Some(item) if item.owner != user_id => Err(UserError::UnknownItem(item_id.to_string())),
+            item => Ok(item),
+        }
+    }
+
+    async fn owned_item(&mut self, user_id: &str, item_id: &str) -> Result<DataItem, UserError> {
+        self.load_owned_item(user_id, item_id)
+            .await?
+            .ok_or_else(|| UserError::UnknownItem(item_id.to_string()))
+    }
+
+    // 사용자별로 아직 서버에 올리지 않은 변경
+    fn outbox_key(user_id: &str) -> String {
+        format!("sync_outbox/{}", user_id)
+    }
+
+    async fn load_outbox(&mut self, user_id: &str) -> SyncOutbox {
+        let request = FetchData {
+            key: Self::outbox_key(user_id),
+            user_id: None,
+            mode: FetchMode::CacheFirst,
+        };
+        self.fetch_cached(request)
+            .await
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .unwrap_or_default()
+    }
+
+    async fn save_outbox(&mut self, user_id: &str, outbox: &SyncOutbox) -> Result<(), UserError> {
+        let data = serde_json::to_vec(outbox)?;
+        self.persist(Self::outbox_key(user_id), data).await
+    }
+
+    // 항목의 변경을 주인의 올릴 목록에 남긴다 (deleted_at이 있으면 삭제 표시)
+    // 남기지 못한 변경은 전부 다시 올릴 때(full) 함께 올라간다.
+    async fn record_change(&mut self, owner: &str, item_id: &str, deleted_at: Option<u64>) {
+        if owner.is_empty() {
+            return;
+        }
+        let mut outbox = self.load_outbox(owner).await;
+        outbox.last_seq += 1;
+        let change = PendingChange {
+            seq: outbox.last_seq,
+            deleted_at,
+        };
+        outbox.changes.insert(item_id.to_string(), change);
+        if let Err(e) = self.save_outbox(owner, &outbox).await {
+            debug_print!("Failed to record change of {}: {}", item_id, e);
+        }
+    }
+
+    // 이번에 올릴 변경 (full이면 사용자의 모든 항목과 남은 삭제 표시)
+    // 다른 사용자의 항목은 올리지 않는다.
+    async fn pending_push(
+        &mut self,
+        user_id: &UserId,
+        full: bool,
+    ) -> Result<PendingPush, UserError> {
+        let outbox = self.load_outbox(user_id).await;
+        let mut items = if full {
+            self.load_user_items(user_id).await?
+        } else {
+            Vec::new()
+        };
+        let mut changes: Vec<_> = outbox.changes.into_iter().collect();
+        changes.sort_by_key(|(_, change)| change.seq);
+        let mut deleted = Vec::new();
+        for (id, change) in changes {
+            match change.deleted_at {
+                Some(deleted_at) => deleted.push(DeletedItem { id, deleted_at }),
+                None if full => {}
+                None => {
+                    if let Some(item) = self.load_item(&id).await
+                        && item.owner == *user_id
+                    {
+                        items.push(item);
+                    }
+                }
+            }
+        }
+        items.sort_by_key(|item| item.updated_at);
+        Ok(PendingPush {
+            items,
+            deleted,
+            through: outbox.last_seq,
+        })
+    }
+
+    fn history_key(item_id: &str) -> String {
+        format!("history/{}", item_id)
+    }
+
+    // 원격 데이터 서비스 클라이언트 설정
+    pub fn set_api_client(&mut self, api: ApiClient) {
+        debug_print!("Setting API client for DataManagerActor");
+        self.api = Some(api);
+    }
+
+    // 항목 생성/수정/삭제를 이벤트 버스로 발행 (자동화 등에서 구독)
+    pub fn set_event_bus(&mut self, event_bus: EventBus) {
+        self.event_bus = Some(event_bus);
+    }
+
+    // 서버와 주고받는 항목을 봉인하고 푼다
+    pub fn set_sync_crypto(&mut self, sync_crypto: Address<SyncCryptoActor>) {
+        self.sync_crypto = Some(sync_crypto);
+    }
+
+    // 항목을 만들거나 원격 페이지를 받기 전에 사용량 한도를 묻는다
+    pub fn set_quota(&mut self, quota: Address<QuotaActor>) {
+        self.quota = Some(quota);
+    }
+
+    // 한도 액터가 없거나 응답하지 않으면 허용한다 (거부하면 한도 액터가 Dart에 알린다)
+    async fn quota_allows(&mut self, user_id: &UserId, metric: QuotaMetric, amount: u64) -> bool {
+        let Some(quota) = self.quota.as_mut() else {
+            return true;
+        };
+        let check = CheckQuota {
+            user_id: user_id.clone(),
+            metric,
+            amount,
+        };
+        match quota.send(check).await {
+            Ok(decision) => decision.allowed,
+            Err(_) => true,
+        }
+    }
+
+    fn publish(&self, event: AppEvent) {
+        if let Some(event_bus) = &self.event_bus {
+            event_bus.publish(event);
+        }
+    }
+
+    // 캐시 → 저장소 순으로 조회하고, 저장소에서 찾으면 캐시에 채워 둔다
+    async fn fetch_cached(&mut self, msg: FetchData) -> Result<Arc<[u8]>, UserError> {
         // 1. 먼저 캐시에서 확인
-        let cache_result = self.cache_actor.send(msg.clone()).await;
+        let cache_result = self.cache_actor.send(Timed::new(msg.clone())).await;
 
         if let Ok(Ok(data)) = cache_result {
             debug_print!("Cache hit for key: {}", msg.key);
@@ -65,7 +460,7 @@ impl Handler<FetchData> for DataManagerActor {
         }
 
         // 2. 캐시에 없으면 저장소에서 확인
-        let storage_result = self.storage_actor.send(msg.clone()).await;
+        let storage_result = self.storage_actor.send(Timed::new(msg.clone())).await;
 
         if let Ok(Ok(data)) = storage_result {
             debug_print!("Storage hit for key: {}", msg.key);
@@ -73,285 +468,3395 @@ impl Handler<FetchData> for DataManagerActor {
             // 캐시에 저장
             let _ = self
                 .cache_actor
-                .send(CacheData {
+                .send(Timed::new(CacheData {
                     key: msg.key,
-                    data: data.clone(),
-                    ttl: Some(3600), // 1시간 캐시
-                })
+                    data: Arc::clone(&data),
+                    ttl: Some(self.default_cache_ttl),
+                }))
                 .await;
 
             return Ok(data);
         }
 
         // 3. 저장소에도 없으면 네트워크에서 가져오기 (실제 구현에서는 필요)
-        Err("Data not found".into())
+        Err(UserError::NotFound(msg.key))
     }
-}
-
-#[async_trait]
-impl Handler<StoreData> for DataManagerActor {
-    type Result = Result<(), UserError>;
-
-    async fn handle(&mut self, msg: StoreData, _: &Context<Self>) -> Self::Result {
-        // 1. 저장소에 저장
-        let storage_result = self.storage_actor.send(msg.clone()).await??;
 
-        // 2. 캐시에도 저장
+    async fn persist(&mut self, key: String, data: Vec<u8>) -> Result<(), UserError> {
+        self.storage_actor
+            .send(Timed::new(StoreData {
+                key: key.clone(),
+                data: data.clone(),
+                user_id: None,
+                ttl: None,
+            }))
+            .await??;
         let _ = self
             .cache_actor
-            .send(CacheData {
-                key: msg.key,
-                data: msg.data,
-                ttl: msg.ttl,
-            })
+            .send(Timed::new(CacheData {
+                key,
+                data: data.into(),
+                ttl: Some(self.default_cache_ttl),
+            }))
             .await;
+        Ok(())
+    }
 
-        Ok(storage_result)
+    async fn remove_key(&mut self, key: String) -> Result<(), UserError> {
+        self.storage_actor
+            .send(Timed::new(DeleteData { key: key.clone() }))
+            .await??;
+        let _ = self.cache_actor.send(Timed::new(DeleteData { key })).await;
+        Ok(())
     }
-}
 
-#[async_trait]
-impl Handler<FetchRecentData> for DataManagerActor {
-    type Result = Result<UserData, UserError>;
+    async fn ensure_loaded(&mut self) {
+        if self.index_loaded {
+            return;
+        }
+        self.index_loaded = true;
 
-    async fn handle(&mut self, msg: FetchRecentData, _: &Context<Self>) -> Self::Result {
-        // 실제 구현에서는 저장소에서 사용자의 최근 데이터 가져오기
-        let limit = msg.limit.unwrap_or(10);
+        let index_key = FetchData {
+            key: TAG_INDEX_KEY.to_string(),
+            user_id: None,
+            mode: FetchMode::CacheFirst,
+        };
+        if let Ok(bytes) = self.fetch_cached(index_key).await {
+            match UserTagIndex::from_bytes(&bytes) {
+                Ok(index) => self.tag_index = index,
+                Err(e) => debug_print!("Failed to load tag index: {}", e),
+            }
+        }
 
-        // 예시 데이터 생성
-        let items = (0..limit)
-            .map(|i| DataItem {
-                id: format!("item_{}", i),
-                title: format!("Item {}", i),
-                content: format!("Content for item {}", i),
-                created_at: Utc::now().timestamp() as u64 - i as u64 * 3600,
-                updated_at: Utc::now().timestamp() as u64 - i as u64 * 1800,
-            })
-            .collect();
+        let collections_key = FetchData {
+            key: COLLECTIONS_KEY.to_string(),
+            user_id: None,
+            mode: FetchMode::CacheFirst,
+        };
+        if let Ok(bytes) = self.fetch_cached(collections_key).await {
+            match serde_json::from_slice(&bytes) {
+                Ok(collections) => self.collections = collections,
+                Err(e) => debug_print!("Failed to load collections: {}", e),
+            }
+        }
+        if let Err(e) = self.split_legacy_indexes().await {
+            debug_print!("Failed to split tag index and collections by user: {}", e);
+        }
 
-        let user_data = UserData {
-            user_id: msg.user_id,
-            items,
-            last_updated: Utc::now().timestamp() as u64,
+        let idempotency_key = FetchData {
+            key: IDEMPOTENCY_KEY.to_string(),
+            user_id: None,
+            mode: FetchMode::CacheFirst,
         };
+        if let Ok(bytes) = self.fetch_cached(idempotency_key).await {
+            match IdempotencyWindow::from_bytes(&bytes) {
+                Ok(idempotency) => self.idempotency = idempotency,
+                Err(e) => debug_print!("Failed to load idempotency keys: {}", e),
+            }
+        }
 
-        Ok(user_data)
+        let owners_claimed_key = FetchData {
+            key: OWNERS_CLAIMED_KEY.to_string(),
+            user_id: None,
+            mode: FetchMode::CacheFirst,
+        };
+        self.owners_claimed = self.fetch_cached(owners_claimed_key).await.is_ok();
+        if !self.owners_claimed {
+            match self.migrate_unowned_items().await {
+                Ok(0) => {}
+                Ok(migrated) => debug_print!("Assigned {} unowned items", migrated),
+                Err(e) => debug_print!("Failed to assign unowned items: {}", e),
+            }
+        }
     }
-}
 
-// Dart 신호 처리
-#[async_trait]
-impl Notifiable<FetchUserDataRequest> for DataManagerActor {
-    async fn notify(&mut self, msg: FetchUserDataRequest, ctx: &Context<Self>) {
-        let data_result = self
-            .handle(
-                FetchRecentData {
-                    user_id: msg.user_id,
-                    limit: msg.limit,
-                },
-                ctx,
-            )
-            .await;
+    // 예전 전역 색인과 컬렉션을 항목 주인별로 나눠 옮긴다
+    // 주인을 알 수 없는 항목과 컬렉션은 주인 없는 항목과 함께 옮긴다 (migrate_unowned_items).
+    // 새 키에 먼저 저장하고 예전 키를 지우므로 중간에 멈추면 다음에 다시 나눈다.
+    async fn split_legacy_indexes(&mut self) -> Result<(), UserError> {
+        let legacy_index = FetchData {
+            key: LEGACY_TAG_INDEX_KEY.to_string(),
+            user_id: None,
+            mode: FetchMode::CacheFirst,
+        };
+        let legacy_index = self.fetch_cached(legacy_index).await.ok();
+        let legacy_collections = FetchData {
+            key: LEGACY_COLLECTIONS_KEY.to_string(),
+            user_id: None,
+            mode: FetchMode::CacheFirst,
+        };
+        let legacy_collections = self.fetch_cached(legacy_collections).await.ok();
+        if legacy_index.is_none() && legacy_collections.is_none() {
+            return Ok(());
+        }
 
-        match data_result {
-            Ok(user_data) => {
-                UserDataResponse {
-                    user_id: user_data.user_id,
-                    items: user_data.items,
-                    last_updated: user_data.last_updated,
-                    error: None,
+        let entries = self
+            .storage_actor
+            .send(Traced::new(ScanPrefix {
+                prefix: Self::item_key(""),
+            }))
+            .await??;
+        let owners: HashMap<String, UserId> = entries
+            .iter()
+            .filter_map(|(_, data)| serde_json::from_slice::<DataItem>(data).ok())
+            .map(|item| (item.id, item.owner))
+            .collect();
+        let owner_of = |item_id: &str| owners.get(item_id).cloned().unwrap_or_default();
+
+        if let Some(bytes) = legacy_index {
+            let legacy = TagIndex::from_bytes(&bytes)?;
+            for (item_id, tags) in legacy.entries() {
+                let index = self.tag_index.user_mut(&owner_of(item_id));
+                for tag in tags {
+                    index.add(item_id, tag);
                 }
-                .send_signal_to_dart();
             }
-            Err(e) => {
-                UserDataResponse {
-                    user_id: msg.user_id,
-                    items: vec![],
-                    last_updated: 0,
-                    error: Some(e.to_string()),
-                }
-                .send_signal_to_dart();
+            self.save_tag_index().await?;
+            self.remove_key(LEGACY_TAG_INDEX_KEY.to_string()).await?;
+        }
+        if let Some(bytes) = legacy_collections {
+            let legacy: Vec<Collection> = serde_json::from_slice(&bytes)?;
+            for collection in legacy {
+                let owner = collection
+                    .item_ids
+                    .iter()
+                    .map(|item_id| owner_of(item_id))
+                    .find(|owner| !owner.is_empty())
+                    .unwrap_or_default();
+                self.collections.entry(owner).or_default().push(collection);
             }
+            self.save_collections().await?;
+            self.remove_key(LEGACY_COLLECTIONS_KEY.to_string()).await?;
         }
+        Ok(())
     }
-}
 
-#[async_trait]
-impl Notifiable<CreateDataItemRequest> for DataManagerActor {
-    async fn notify(&mut self, msg: CreateDataItemRequest, _: &Context<Self>) {
-        let now = Utc::now().timestamp() as u64;
-        let item = DataItem {
-            id: self.generate_item_id(),
-            title: msg.title,
-            content: msg.content,
-            created_at: now,
-            updated_at: now,
-        };
+    async fn save_tag_index(&mut self) -> Result<(), UserError> {
+        let data = self.tag_index.to_bytes()?;
+        self.persist(TAG_INDEX_KEY.to_string(), data).await
+    }
 
-        // 실제 구현에서는 저장소에 저장
+    async fn save_collections(&mut self) -> Result<(), UserError> {
+        let data = serde_json::to_vec(&self.collections)?;
+        self.persist(COLLECTIONS_KEY.to_string(), data).await
+    }
 
-        // Dart에 알림
-        DataItemCreatedSignal {
-            user_id: msg.user_id,
-            item,
-        }
-        .send_signal_to_dart();
+    // 멱등 키는 사용자와 변경 종류마다 따로 둔다
+    // 다른 사용자나 다른 종류의 요청이 우연히 같은 키를 써도 서로 가로채지 않는다.
+    // 사용자 id 앞에 길이를 붙여 id에 구분자가 들어 있어도 다른 범위와 섞이지 않는다.
+    fn idempotency_scope(user_id: &UserId, kind: MutationKind, key: &str) -> String {
+        format!("{}:{}/{:?}/{}", user_id.len(), user_id, kind, key)
     }
-}
 
-#[async_trait]
-impl Notifiable<UpdateDataItemRequest> for DataManagerActor {
-    async fn notify(&mut self, msg: UpdateDataItemRequest, _: &Context<Self>) {
-        // 실제 구현에서는 저장소에서 아이템 가져와서 업데이트
-        let now = Utc::now().timestamp() as u64;
-        let item = DataItem {
-            id: msg.item_id.clone(),
-            title: msg.title.unwrap_or_else(|| "Updated Item".to_string()),
-            content: msg.content.unwrap_or_else(|| "Updated content".to_string()),
-            created_at: now - 3600, // 예시용
-            updated_at: now,
-        };
+    // 멱등 키와 함께 기억할 요청 내용의 해시
+    fn payload_digest(payload: &impl Serialize) -> Vec<u8> {
+        Sha256::digest(serde_json::to_vec(payload).unwrap_or_default()).to_vec()
+    }
 
-        // Dart에 알림
-        DataItemUpdatedSignal {
-            user_id: msg.user_id,
-            item,
+    // 같은 사용자가 같은 종류의 변경을 같은 멱등 키로 이미 처리했으면 그때의 결과
+    // 키만 같고 내용이 다르면 재전송이 아니므로 적용하지도, 예전 결과를 돌려주지도 않고 거절한다.
+    async fn repeated_mutation(
+        &mut self,
+        user_id: &UserId,
+        kind: MutationKind,
+        key: Option<&str>,
+        payload: &[u8],
+    ) -> Option<Result<MutationReplay, UserError>> {
+        let key = key?;
+        let scope = Self::idempotency_scope(user_id, kind, key);
+        self.ensure_loaded().await;
+        let now = self.clock.trusted_now();
+        let recorded = self.idempotency.get(&scope, now)?;
+        if recorded.payload != payload {
+            return Some(Err(UserError::InvalidInput(format!(
+                "Idempotency key {} was already used for a different {:?} request",
+                key, kind
+            ))));
         }
-        .send_signal_to_dart();
+        Some(Ok(recorded.outcome.clone()))
     }
-}
-
-#[async_trait]
-impl Notifiable<DeleteDataItemRequest> for DataManagerActor {
-    async fn notify(&mut self, msg: DeleteDataItemRequest, _: &Context<Self>) {
-        // 실제 구현에서는 저장소에서 아이템 삭제
 
-        // Dart에 알림
-        DataItemDeletedSignal {
-            user_id: msg.user_id,
-            item_id: msg.item_id,
+    // 성공한 변경만 기억한다 (실패한 요청은 같은 키로 다시 보낼 수 있어야 한다)
+    // 앱을 곧바로 다시 켠 뒤에 온 재전송도 걸러지도록 저장해 둔다.
+    async fn remember_mutation(
+        &mut self,
+        user_id: &UserId,
+        key: Option<String>,
+        payload: Vec<u8>,
+        outcome: MutationReplay,
+    ) {
+        let Some(key) = key else {
+            return;
+        };
+        let kind = match outcome {
+            MutationReplay::Created(_) => MutationKind::Create,
+            MutationReplay::Updated(_) => MutationKind::Update,
+            MutationReplay::Deleted(_) => MutationKind::Delete,
+        };
+        let key = Self::idempotency_scope(user_id, kind, &key);
+        let now = self.clock.trusted_now();
+        self.idempotency
+            .record(key, RecordedMutation { payload, outcome }, now);
+        let saved = match self.idempotency.to_bytes() {
+            Ok(data) => self.persist(IDEMPOTENCY_KEY.to_string(), data).await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = saved {
+            debug_print!("Failed to save idempotency keys: {}", e);
         }
-        .send_signal_to_dart();
     }
-}
-
-// 캐시 액터
-pub struct CacheActor {
-    cache: HashMap<String, CacheEntry>,
-    _owned_tasks: JoinSet<()>,
-}
-
-struct CacheEntry {
-    data: Vec<u8>,
-    expires_at: Option<u64>,
-}
 
-impl Actor for CacheActor {}
+    // 저장된 항목의 태그는 무시하고 색인 기준으로 채운다
+    async fn load_item(&mut self, item_id: &str) -> Option<DataItem> {
+        let bytes = self
+            .fetch_cached(FetchData {
+                key: Self::item_key(item_id),
+                user_id: None,
+                mode: FetchMode::CacheFirst,
+            })
+            .await
+            .ok()?;
+        let mut item: DataItem = serde_json::from_slice(&bytes).ok()?;
+        item.tags = self.tag_index.tags_of(&item.owner, item_id);
+        Some(item)
+    }
 
-impl CacheActor {
-    pub fn new(addr: Address<Self>) -> Self {
-        let mut owned_tasks = JoinSet::new();
+    // 이 기기에서 바꾼 항목을 저장하고 올릴 변경으로 남긴다
+    async fn save_item(&mut self, item: &DataItem) -> Result<(), UserError> {
+        self.write_item(item).await?;
+        self.record_change(&item.owner, &item.id, None).await;
+        Ok(())
+    }
 
-        // 캐시 정리 작업 시작
-        owned_tasks.spawn(Self::cleanup_cache(addr));
+    // 서버에서 받은 항목처럼 다시 올릴 필요가 없는 항목을 저장한다
+    async fn write_item(&mut self, item: &DataItem) -> Result<(), UserError> {
+        let data = serde_json::to_vec(item)?;
+        self.persist(Self::item_key(&item.id), data).await
+    }
 
-        Self {
-            cache: HashMap::new(),
-            _owned_tasks: owned_tasks,
-        }
+    async fn load_history(&mut self, item_id: &str) -> Vec<ItemRevision> {
+        let request = FetchData {
+            key: Self::history_key(item_id),
+            user_id: None,
+            mode: FetchMode::CacheFirst,
+        };
+        self.fetch_cached(request)
+            .await
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .unwrap_or_default()
     }
 
-    async fn cleanup_cache(_self_addr: Address<Self>) {
-        // 실제 구현에서는 주기적으로 만료된 캐시 항목 정리
-        let mut interval = tokio::time::interval(std::time::Duration::from_secs(60));
-        loop {
-            interval.tick().await;
-            // 실제 구현에서는 self_addr.notify(CleanupCache).await 호출
+    // 직전 상태를 리비전으로 남기고 새 상태를 저장한 뒤 수정 이벤트 발행
+    async fn commit_update(
+        &mut self,
+        user_id: &str,
+        item: &DataItem,
+        previous: Option<&DataItem>,
+    ) -> Result<(), UserError> {
+        if let Some(previous) = previous {
+            let mut revisions = self.load_history(&previous.id).await;
+            history::push_revision(&mut revisions, previous);
+            let data = serde_json::to_vec(&revisions)?;
+            self.persist(Self::history_key(&previous.id), data).await?;
         }
+        self.save_item(item).await?;
+
+        self.publish(AppEvent::DataItemUpdated {
+            user_id: user_id.to_string(),
+            item: item.clone(),
+            tags: item.tags.clone(),
+        });
+        Ok(())
     }
 
-    fn get_current_timestamp(&self) -> u64 {
-        Utc::now().timestamp() as u64
+    // 스냅샷의 본문으로 현재 항목을 덮어쓴다 (태그와 첨부는 현재 상태 유지)
+    async fn restore_snapshot(
+        &mut self,
+        user_id: &str,
+        snapshot: &DataItem,
+        current: &DataItem,
+    ) -> Result<DataItem, UserError> {
+        let mut item = current.clone();
+        item.title = snapshot.title.clone();
+        item.content = snapshot.content.clone();
+        item.updated_at = self.clock.trusted_now();
+        self.commit_update(user_id, &item, Some(current)).await?;
+        Ok(item)
     }
-}
 
-#[async_trait]
-impl Handler<FetchData> for CacheActor {
-    type Result = Result<Vec<u8>, UserError>;
+    async fn current_item(&mut self, item_id: &str) -> Result<DataItem, UserError> {
+        self.load_item(item_id)
+            .await
+            .ok_or_else(|| UserError::UnknownItem(item_id.to_string()))
+    }
 
-    async fn handle(&mut self, msg: FetchData, _: &Context<Self>) -> Self::Result {
-        if let Some(entry) = self.cache.get(&msg.key) {
-            // 만료 확인
-            if let Some(expires_at) = entry.expires_at {
-                if expires_at < self.get_current_timestamp() {
-                    self.cache.remove(&msg.key);
-                    return Err("Cache entry expired".into());
-                }
+    fn send_history_action(&self, user_id: UserId, result: Result<DataItem, UserError>) {
+        if let Ok(item) = &result {
+            DataItemUpdatedSignal {
+                user_id: user_id.clone(),
+                item: item.clone(),
             }
+            .emit();
+        }
 
-            Ok(entry.data.clone())
-        } else {
-            Err("Cache miss".into())
+        HistoryActionSignal {
+            can_undo: self.undo_stack.can_undo(&user_id),
+            can_redo: self.undo_stack.can_redo(&user_id),
+            user_id,
+            item: result.as_ref().ok().cloned(),
+            error: result.err().map(|e| e.to_string()),
         }
+        .emit();
     }
-}
 
-#[async_trait]
-impl Handler<CacheData> for CacheActor {
-    type Result = Result<(), UserError>;
+    // 태그 변경은 항목 수정 이벤트로 발행해 태그 조건 자동화가 반응하게 한다
+    async fn publish_tags_changed(&mut self, user_id: UserId, item_id: &str) {
+        if let Some(item) = self.load_item(item_id).await {
+            self.publish(AppEvent::DataItemUpdated {
+                user_id,
+                tags: item.tags.clone(),
+                item,
+            });
+        }
+    }
 
-    async fn handle(&mut self, msg: CacheData, _: &Context<Self>) -> Self::Result {
-        let expires_at = msg.ttl.map(|ttl| self.get_current_timestamp() + ttl);
+    // 항목과 리비전을 지우고 태그 색인과 컬렉션에서도 뺀 뒤 삭제를 알린다
+    // 다른 사용자의 항목이거나 항목 자체를 지우지 못했으면 색인과 컬렉션은 그대로 두고 삭제를 알리지 않는다
+    // 지운 항목은 주인의 올릴 목록에 삭제 표시로 남겨 서버 사본도 지우게 한다.
+    async fn remove_item(&mut self, user_id: UserId, item_id: String) -> Result<(), UserError> {
+        let owner = self
+            .load_owned_item(&user_id, &item_id)
+            .await?
+            .map(|item| item.owner);
+        self.remove_key(Self::item_key(&item_id)).await?;
+        if let Some(owner) = owner {
+            let deleted_at = self.clock.trusted_now();
+            self.record_change(&owner, &item_id, Some(deleted_at)).await;
+        }
 
-        self.cache.insert(
-            msg.key,
-            CacheEntry {
-                data: msg.data,
-                expires_at,
-            },
-        );
+        if let Err(e) = self.remove_key(Self::history_key(&item_id)).await {
+            debug_print!("Failed to delete history of {}: {}", item_id, e);
+        }
+        self.undo_stack.forget_item(&item_id);
+
+        // 사용자의 태그 색인과 컬렉션에서도 제거
+        if self.tag_index.user_mut(&user_id).remove_item(&item_id)
+            && let Err(e) = self.save_tag_index().await
+        {
+            debug_print!("Failed to save tag index: {}", e);
+        }
+        let mut collections_changed = false;
+        for collection in self.collections.entry(user_id.clone()).or_default() {
+            let before = collection.item_ids.len();
+            collection.item_ids.retain(|id| id != &item_id);
+            collections_changed |= collection.item_ids.len() != before;
+        }
+        if collections_changed && let Err(e) = self.save_collections().await {
+            debug_print!("Failed to save collections: {}", e);
+        }
+
+        self.publish(AppEvent::DataItemDeleted {
+            user_id: user_id.clone(),
+            item_id: item_id.clone(),
+        });
 
+        // Dart에 알림
+        DataItemDeletedSignal { user_id, item_id }.emit();
         Ok(())
     }
-}
-
-// 저장소 액터
-pub struct StorageActor {
-    // 실제 구현에서는 파일 시스템이나 데이터베이스 연결
-    _owned_tasks: JoinSet<()>,
-}
 
-impl Actor for StorageActor {}
+    // 다음 페이지가 없을 때까지 받아 저장하고, 페이지마다 Dart에 배치로 보낸다
+    // 받는 동안에도 다른 요청을 처리하도록 액터 밖의 작업에서 돈다.
+    #[allow(clippy::too_many_arguments)]
+    async fn fetch_remote_pages(
+        mut self_addr: Address<Self>,
+        api: ApiClient,
+        mut quota: Option<Address<QuotaActor>>,
+        mut sync_crypto: Option<Address<SyncCryptoActor>>,
+        clock: TrustedClock,
+        user_id: UserId,
+        page_size: usize,
+        trace_id: u64,
+    ) {
+        let mut next_page = None;
+        let mut seen = HashSet::new();
+        for _ in 0..MAX_REMOTE_PAGES {
+            let fetched = Self::fetch_remote_page(
+                &mut self_addr,
+                &api,
+                sync_crypto.as_mut(),
+                &user_id,
+                page_size,
+                next_page,
+            )
+            .await;
+            if let Some(quota) = quota.as_mut() {
+                let usage = RecordUsage {
+                    user_id: user_id.clone(),
+                    metric: QuotaMetric::ApiCalls,
+                    amount: 1,
+                };
+                let _ = quota.notify(usage).await;
+            }
+            let (items, next) = match fetched {
+                Ok(page) => page,
+                Err(e) => {
+                    debug_print!("Remote fetch for {} stopped: {}", user_id, e);
+                    UserDataResponse {
+                        user_id,
+                        items: vec![],
+                        last_updated: 0,
+                        error: Some(e.to_string()),
+                        trace_id,
+                        has_more: false,
+                    }
+                    .emit();
+                    return;
+                }
+            };
+
+            // 서버가 이미 받은 페이지를 다시 가리키면 멈춘다
+            let has_more = next.as_ref().is_some_and(|next| seen.insert(next.clone()));
+            UserDataResponse {
+                user_id: user_id.clone(),
+                items,
+                last_updated: clock.trusted_now(),
+                error: None,
+                trace_id,
+                has_more,
+            }
+            .emit();
+            if !has_more {
+                return;
+            }
+            next_page = next;
+        }
+        debug_print!("Remote fetch for {} hit the page limit", user_id);
+    }
+
+    // 한 페이지를 받아 풀고 저장한다 (저장은 메일박스를 거쳐 같은 사용자의 다른 변경과 섞이지 않는다)
+    async fn fetch_remote_page(
+        self_addr: &mut Address<Self>,
+        api: &ApiClient,
+        sync_crypto: Option<&mut Address<SyncCryptoActor>>,
+        user_id: &UserId,
+        page_size: usize,
+        next_page: Option<NextPage>,
+    ) -> ActorResult<(Vec<DataItem>, Option<NextPage>)> {
+        let page: RemoteItemPage = match next_page {
+            Some(NextPage::Link(link)) => {
+                api.follow::<ListItemsEndpoint>(Priority::Low, &link)
+                    .await?
+            }
+            cursor => {
+                let endpoint = ListItemsEndpoint {
+                    user_id: user_id.clone(),
+                    query: ItemListQuery {
+                        cursor: cursor.and_then(NextPage::into_cursor),
+                        limit: page_size,
+                    },
+                };
+                api.send(Priority::Low, &endpoint).await?
+            }
+        };
+
+        let next = match (page.next_page, page.next_cursor) {
+            (Some(link), _) => Some(NextPage::Link(link)),
+            (None, Some(cursor)) => Some(NextPage::Cursor(cursor)),
+            (None, None) => None,
+        };
+        let items = Self::open_payloads(sync_crypto, user_id, page.items).await?;
+        let items = self_addr
+            .send(StoreRemotePage {
+                user_id: user_id.clone(),
+                items,
+            })
+            .await??;
+        Ok((items, next))
+    }
+
+    // 서버에서 받은 항목을 푼다 (암호화 액터가 없으면 평문 항목만 받는다)
+    async fn open_payloads(
+        sync_crypto: Option<&mut Address<SyncCryptoActor>>,
+        user_id: &UserId,
+        items: Vec<SyncPayload>,
+    ) -> ActorResult<Vec<DataItem>> {
+        let Some(sync_crypto) = sync_crypto else {
+            return items
+                .into_iter()
+                .map(|payload| match payload {
+                    SyncPayload::Plain(item) => Ok(item),
+                    SyncPayload::Sealed(sealed) => Err(UserError::InvalidInput(format!(
+                        "Sync item {} is encrypted but sync encryption is unavailable",
+                        sealed.id
+                    ))),
+                })
+                .collect();
+        };
+        let open = OpenSyncItems {
+            user_id: user_id.clone(),
+            items,
+        };
+        sync_crypto.send(open).await?
+    }
+
+    // 바뀐 항목과 삭제 표시를 페이지 크기로 나눠 봉인해 올린다
+    // 모두 올린 뒤에만 올린 변경을 목록에서 지우므로 중간에 실패하면 다음 요청이 처음부터 다시 올린다.
+    async fn push_local_items(
+        mut self_addr: Address<Self>,
+        api: ApiClient,
+        mut quota: Option<Address<QuotaActor>>,
+        mut sync_crypto: Option<Address<SyncCryptoActor>>,
+        user_id: UserId,
+        pending: PendingPush,
+    ) {
+        let pages = pending
+            .items
+            .chunks(MAX_PAGE_SIZE)
+            .map(|page| (page.to_vec(), Vec::new()))
+            .chain(
+                pending
+                    .deleted
+                    .chunks(MAX_PAGE_SIZE)
+                    .map(|page| (Vec::new(), page.to_vec())),
+            );
+        let mut pushed = 0;
+        for (items, deleted) in pages {
+            let count = (items.len() + deleted.len()) as u64;
+            let result =
+                Self::push_page(&api, sync_crypto.as_mut(), &user_id, items, deleted).await;
+            if let Some(quota) = quota.as_mut() {
+                let usage = RecordUsage {
+                    user_id: user_id.clone(),
+                    metric: QuotaMetric::ApiCalls,
+                    amount: 1,
+                };
+                let _ = quota.notify(usage).await;
+            }
+            if let Err(e) = result {
+                debug_print!("Pushing local changes for {} stopped: {}", user_id, e);
+                LocalChangesPushedSignal {
+                    user_id,
+                    pushed,
+                    error: Some(e.to_string()),
+                }
+                .emit();
+                return;
+            }
+            pushed += count;
+        }
+        let done = LocalChangesPushed {
+            user_id,
+            pushed,
+            through: pending.through,
+        };
+        let _ = self_addr.notify(done).await;
+    }
+
+    // 암호화를 켰지만 잠긴 사용자면 봉인이 실패하므로 평문은 서버로 나가지 않는다
+    async fn push_page(
+        api: &ApiClient,
+        sync_crypto: Option<&mut Address<SyncCryptoActor>>,
+        user_id: &UserId,
+        items: Vec<DataItem>,
+        deleted: Vec<DeletedItem>,
+    ) -> ActorResult<()> {
+        let items = match sync_crypto {
+            Some(sync_crypto) => {
+                let seal = SealSyncItems {
+                    user_id: user_id.clone(),
+                    items,
+                };
+                sync_crypto.send(seal).await??
+            }
+            None => items.into_iter().map(SyncPayload::Plain).collect(),
+        };
+        let endpoint = PushItemsEndpoint {
+            user_id: user_id.clone(),
+            body: PushItemsBody { items, deleted },
+        };
+        api.send(Priority::Low, &endpoint).await?;
+        Ok(())
+    }
+
+    // 캐시 TTL 안이면 캐시 값을 돌려주고, 지났으면 None을 돌려주며 서버에서 다시 받기 시작한다
+    async fn fresh_or_revalidate(
+        &mut self,
+        self_addr: Address<Self>,
+        msg: &FetchData,
+    ) -> Option<Arc<[u8]>> {
+        if let Ok(Ok(data)) = self.cache_actor.send(Timed::new(msg.clone())).await {
+            return Some(data);
+        }
+        self.revalidate(self_addr, msg);
+        None
+    }
+
+    // 만료된 값을 돌려준 항목 키를 서버에서 다시 받는다 (항목이 아닌 키는 서버에 없으므로 건너뛴다)
+    // 같은 키를 받는 중이면 다시 요청하지 않는다.
+    fn revalidate(&mut self, self_addr: Address<Self>, msg: &FetchData) {
+        let (Some(api), Some(user_id)) = (self.api.clone(), msg.user_id.clone()) else {
+            return;
+        };
+        let Some(item_id) = msg.key.strip_prefix(&Self::item_key("")) else {
+            return;
+        };
+        if item_id.is_empty()
+            || !self.sync_suspended.is_empty()
+            || !self.revalidating.insert(msg.key.clone())
+        {
+            return;
+        }
+        self._owned_tasks.spawn(Self::fetch_remote_item(
+            self_addr,
+            api,
+            self.quota.clone(),
+            self.sync_crypto.clone(),
+            user_id,
+            msg.key.clone(),
+            item_id.to_string(),
+        ));
+    }
+
+    async fn fetch_remote_item(
+        mut self_addr: Address<Self>,
+        api: ApiClient,
+        mut quota: Option<Address<QuotaActor>>,
+        mut sync_crypto: Option<Address<SyncCryptoActor>>,
+        user_id: UserId,
+        key: String,
+        item_id: String,
+    ) {
+        let endpoint = GetItemEndpoint {
+            user_id: user_id.clone(),
+            item_id,
+        };
+        let fetched = api.send(Priority::Low, &endpoint).await;
+        if let Some(quota) = quota.as_mut() {
+            let usage = RecordUsage {
+                user_id: user_id.clone(),
+                metric: QuotaMetric::ApiCalls,
+                amount: 1,
+            };
+            let _ = quota.notify(usage).await;
+        }
+        let opened = match fetched {
+            Ok(payload) => Self::open_payloads(sync_crypto.as_mut(), &user_id, vec![payload]).await,
+            Err(e) => Err(e),
+        };
+        let item = match opened {
+            Ok(mut items) => items.pop(),
+            Err(e) => {
+                debug_print!("Revalidation of {} failed: {}", key, e);
+                None
+            }
+        };
+        let _ = self_addr
+            .notify(ItemRevalidated { user_id, key, item })
+            .await;
+    }
+}
+
+// 서버가 알려 준 다음 페이지 위치
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum NextPage {
+    Cursor(String),
+    Link(String),
+}
+
+impl NextPage {
+    fn into_cursor(self) -> Option<String> {
+        match self {
+            Self::Cursor(cursor) => Some(cursor),
+            Self::Link(_) => None,
+        }
+    }
+}
+
+// 사용자별로 아직 올리지 않은 변경 (항목 id → 마지막 변경)
+// 변경마다 순번을 올리므로 올리는 동안 다시 바뀐 항목은 같은 초 안에 바뀌었어도 목록에 남는다.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SyncOutbox {
+    last_seq: u64,
+    changes: BTreeMap<String, PendingChange>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct PendingChange {
+    seq: u64,
+    deleted_at: Option<u64>, // 지운 항목이면 지운 시각
+}
+
+// 이번에 올릴 항목과 삭제 표시 (through: 포함된 변경 중 가장 큰 순번)
+struct PendingPush {
+    items: Vec<DataItem>,
+    deleted: Vec<DeletedItem>,
+    through: u64,
+}
+
+// 내부 메시지 정의
+struct LoadIndexes;
+
+struct StoreRemotePage {
+    user_id: UserId,
+    items: Vec<DataItem>,
+}
+
+// 바뀐 항목을 모두 올렸다 (through: 올린 변경 중 가장 큰 순번)
+struct LocalChangesPushed {
+    user_id: UserId,
+    pushed: u64,
+    through: u64,
+}
+
+// 서버에서 다시 받은 항목 (받지 못했으면 None이고, 받는 중 표시만 지운다)
+struct ItemRevalidated {
+    user_id: UserId,
+    key: String,
+    item: Option<DataItem>,
+}
+
+#[async_trait]
+impl Notifiable<LoadIndexes> for DataManagerActor {
+    async fn notify(&mut self, _: LoadIndexes, _: &Context<Self>) {
+        self.ensure_loaded().await;
+    }
+}
+
+#[async_trait]
+impl Handler<FetchData> for DataManagerActor {
+    type Result = ActorResult<Arc<[u8]>>;
+
+    // StaleWhileRevalidate는 캐시 TTL 안이면 그대로 쓰고, 지났으면 저장된 값을 돌려주면서 다시 받는다
+    async fn handle(&mut self, msg: FetchData, ctx: &Context<Self>) -> Self::Result {
+        if msg.mode == FetchMode::StaleWhileRevalidate
+            && let Some(data) = self.fresh_or_revalidate(ctx.address(), &msg).await
+        {
+            return Ok(data);
+        }
+        self.fetch_cached(msg).await
+    }
+}
+
+#[async_trait]
+impl Handler<StoreData> for DataManagerActor {
+    type Result = ActorResult<()>;
+
+    async fn handle(&mut self, msg: StoreData, _: &Context<Self>) -> Self::Result {
+        // 1. 저장소에 저장
+        let storage_result = self.storage_actor.send(Timed::new(msg.clone())).await??;
+
+        // 2. 캐시에도 저장
+        let _ = self
+            .cache_actor
+            .send(Timed::new(CacheData {
+                key: msg.key,
+                data: msg.data.into(),
+                ttl: msg.ttl,
+            }))
+            .await;
+
+        Ok(storage_result)
+    }
+}
+
+#[async_trait]
+impl Handler<FetchRecentData> for DataManagerActor {
+    type Result = ActorResult<UserData>;
+
+    async fn handle(&mut self, msg: FetchRecentData, ctx: &Context<Self>) -> Self::Result {
+        let limit = msg.limit.unwrap_or(10);
+
+        // 사용자의 항목을 최근 수정 순으로 돌려준다 (없으면 빈 목록)
+        let mut items = self.load_user_items(&msg.user_id).await?;
+        items.sort_by_key(|item| Reverse(item.updated_at));
+        items.truncate(limit);
+
+        // 저장된 목록은 바로 돌려주고, 캐시 TTL이 지난 항목은 서버에서 받아 DataRefreshedSignal로 알린다
+        if msg.mode == FetchMode::StaleWhileRevalidate {
+            for item in &items {
+                let fetch = FetchData {
+                    key: Self::item_key(&item.id),
+                    user_id: Some(msg.user_id.clone()),
+                    mode: msg.mode,
+                };
+                self.fresh_or_revalidate(ctx.address(), &fetch).await;
+            }
+        }
+        Ok(UserData {
+            user_id: msg.user_id,
+            items,
+            last_updated: self.clock.trusted_now(),
+        })
+    }
+}
+
+// 시작 스냅샷용 마지막 사용자의 최근 항목 (마지막 사용자를 모르면 넣지 않는다)
+#[async_trait]
+impl Handler<CaptureSnapshot> for DataManagerActor {
+    type Result = Option<StateSnapshotSignal>;
+
+    async fn handle(&mut self, msg: CaptureSnapshot, _: &Context<Self>) -> Self::Result {
+        let user_id = msg.user_id?;
+        let mut items = match self.load_user_items(&user_id).await {
+            Ok(items) => items,
+            Err(e) => {
+                debug_print!("Failed to load items for snapshot: {}", e);
+                return None;
+            }
+        };
+        items.sort_by_key(|item| Reverse(item.updated_at));
+        items.truncate(SNAPSHOT_ITEMS);
+        Some(StateSnapshotSignal::RecentItems { items })
+    }
+}
+
+#[async_trait]
+impl Handler<AddTag> for DataManagerActor {
+    type Result = ActorResult<Vec<String>>;
+
+    async fn handle(&mut self, msg: AddTag, _: &Context<Self>) -> Self::Result {
+        self.ensure_loaded().await;
+        let tag = TagIndex::normalize(&msg.tag)?;
+        self.owned_item(&msg.user_id, &msg.item_id).await?;
+
+        let user_id = msg.user_id.clone();
+        if self.tag_index.user_mut(&user_id).add(&msg.item_id, &tag) {
+            self.save_tag_index().await?;
+            self.publish_tags_changed(msg.user_id, &msg.item_id).await;
+        }
+        Ok(self.tag_index.tags_of(&user_id, &msg.item_id))
+    }
+}
+
+#[async_trait]
+impl Handler<RemoveTag> for DataManagerActor {
+    type Result = ActorResult<Vec<String>>;
+
+    async fn handle(&mut self, msg: RemoveTag, _: &Context<Self>) -> Self::Result {
+        self.ensure_loaded().await;
+        let tag = TagIndex::normalize(&msg.tag)?;
+        self.owned_item(&msg.user_id, &msg.item_id).await?;
+
+        let user_id = msg.user_id.clone();
+        if self.tag_index.user_mut(&user_id).remove(&msg.item_id, &tag) {
+            self.save_tag_index().await?;
+            self.publish_tags_changed(msg.user_id, &msg.item_id).await;
+        }
+        Ok(self.tag_index.tags_of(&user_id, &msg.item_id))
+    }
+}
+
+#[async_trait]
+impl Handler<CreateCollection> for DataManagerActor {
+    type Result = ActorResult<Collection>;
+
+    async fn handle(&mut self, msg: CreateCollection, _: &Context<Self>) -> Self::Result {
+        self.ensure_loaded().await;
+        let name = msg.name.trim();
+        if name.is_empty() {
+            return Err(UserError::InvalidInput(
+                "Collection name must not be empty".to_string(),
+            ));
+        }
+
+        let collection = Collection {
+            id: generate_id(IdKind::Ulid),
+            name: name.to_string(),
+            item_ids: Vec::new(),
+            created_at: self.clock.trusted_now(),
+        };
+        self.collections
+            .entry(msg.user_id)
+            .or_default()
+            .push(collection.clone());
+        self.save_collections().await?;
+        Ok(collection)
+    }
+}
+
+#[async_trait]
+impl Handler<AddItemToCollection> for DataManagerActor {
+    type Result = ActorResult<Collection>;
+
+    async fn handle(&mut self, msg: AddItemToCollection, _: &Context<Self>) -> Self::Result {
+        self.ensure_loaded().await;
+        self.owned_item(&msg.user_id, &msg.item_id).await?;
+
+        let collection = self
+            .collections
+            .entry(msg.user_id)
+            .or_default()
+            .iter_mut()
+            .find(|c| c.id == msg.collection_id)
+            .ok_or_else(|| UserError::UnknownCollection(msg.collection_id.clone()))?;
+        if collection.item_ids.contains(&msg.item_id) {
+            return Ok(collection.clone());
+        }
+        collection.item_ids.push(msg.item_id);
+        let collection = collection.clone();
+
+        self.save_collections().await?;
+        Ok(collection)
+    }
+}
+
+#[async_trait]
+impl Handler<ItemsByTag> for DataManagerActor {
+    type Result = ActorResult<ItemPage>;
+
+    async fn handle(&mut self, msg: ItemsByTag, _: &Context<Self>) -> Self::Result {
+        self.ensure_loaded().await;
+        let tag = TagIndex::normalize(&msg.tag)?;
+        let limit = match msg.limit {
+            0 => DEFAULT_PAGE_SIZE,
+            limit => limit.min(MAX_PAGE_SIZE),
+        };
+
+        let item_ids = self.tag_index.items_with(&msg.user_id, &tag);
+        let total = item_ids.len();
+        let mut items = Vec::new();
+        for item_id in item_ids.iter().skip(msg.offset).take(limit) {
+            // 색인에는 있지만 저장소에서 사라졌거나 주인이 바뀐 항목은 건너뛴다
+            if let Some(item) = self.load_item(item_id).await
+                && item.owner == msg.user_id
+            {
+                items.push(item);
+            }
+        }
+
+        let end = msg.offset.saturating_add(limit);
+        Ok(ItemPage {
+            items,
+            total,
+            next_offset: (end < total).then_some(end),
+        })
+    }
+}
+
+#[async_trait]
+impl Handler<RecordItemAttachment> for DataManagerActor {
+    type Result = ActorResult<DataItem>;
+
+    async fn handle(&mut self, msg: RecordItemAttachment, _: &Context<Self>) -> Self::Result {
+        self.ensure_loaded().await;
+        let attachment = msg.attachment;
+        let Some(mut item) = self.load_item(&attachment.item_id).await else {
+            return Err(UserError::UnknownItem(attachment.item_id));
+        };
+
+        match item.attachments.iter_mut().find(|a| a.id == attachment.id) {
+            Some(existing) => *existing = attachment,
+            None => item.attachments.push(attachment),
+        }
+        item.updated_at = self.clock.trusted_now();
+        self.save_item(&item).await?;
+        Ok(item)
+    }
+}
+
+#[async_trait]
+impl Handler<CreateDataItem> for DataManagerActor {
+    type Result = ActorResult<DataItem>;
+
+    async fn handle(&mut self, msg: CreateDataItem, _: &Context<Self>) -> Self::Result {
+        // 새 항목 크기는 제목과 본문 길이로 어림한다
+        let size = (msg.title.len() + msg.content.len()) as u64;
+        if !self
+            .quota_allows(&msg.user_id, QuotaMetric::ItemsCreated, 1)
+            .await
+            || !self
+                .quota_allows(&msg.user_id, QuotaMetric::StorageBytes, size)
+                .await
+        {
+            return Err(UserError::QuotaExceeded(format!(
+                "not creating item for {}",
+                msg.user_id
+            )));
+        }
+        self.ensure_loaded().await;
+        let now = self.clock.trusted_now();
+        let mut item = DataItem {
+            id: generate_id(IdKind::Ulid),
+            title: msg.title,
+            content: msg.content,
+            created_at: now,
+            updated_at: now,
+            tags: Vec::new(),
+            attachments: Vec::new(),
+            owner: msg.user_id.clone(),
+        };
+
+        for tag in &msg.tags {
+            match TagIndex::normalize(tag) {
+                Ok(tag) => {
+                    self.tag_index.user_mut(&msg.user_id).add(&item.id, &tag);
+                }
+                Err(e) => debug_print!("Ignoring tag {:?}: {}", tag, e),
+            }
+        }
+        item.tags = self.tag_index.tags_of(&msg.user_id, &item.id);
+
+        self.save_item(&item).await?;
+        if !item.tags.is_empty()
+            && let Err(e) = self.save_tag_index().await
+        {
+            debug_print!("Failed to save tag index: {}", e);
+        }
+
+        self.publish(AppEvent::DataItemCreated {
+            user_id: msg.user_id.clone(),
+            item: item.clone(),
+            tags: item.tags.clone(),
+        });
+
+        // Dart에 알림 (공유 시트 등 Rust에서 만든 항목도 목록에 바로 보이도록)
+        DataItemCreatedSignal {
+            user_id: msg.user_id,
+            item: item.clone(),
+        }
+        .emit();
+        Ok(item)
+    }
+}
+
+// 협업 문서는 자체적으로 편집 이력을 가지므로 리비전/실행 취소 기록 없이 저장만 한다
+#[async_trait]
+impl Handler<SetItemContent> for DataManagerActor {
+    type Result = ActorResult<()>;
+
+    async fn handle(&mut self, msg: SetItemContent, _: &Context<Self>) -> Self::Result {
+        self.ensure_loaded().await;
+        let mut item = self.current_item(&msg.item_id).await?;
+        if item.content == msg.content {
+            return Ok(());
+        }
+        item.content = msg.content;
+        item.updated_at = self.clock.trusted_now();
+        self.save_item(&item).await
+    }
+}
+
+#[async_trait]
+impl Handler<ListItems> for DataManagerActor {
+    type Result = ActorResult<Vec<DataItem>>;
+
+    async fn handle(&mut self, msg: ListItems, _: &Context<Self>) -> Self::Result {
+        let mut items = self.load_user_items(&msg.user_id).await?;
+        items.sort_by_key(|item| Reverse(item.updated_at));
+        Ok(items)
+    }
+}
+
+// 합쳐지는 항목의 마지막 상태도 리비전으로 남겨 남길 항목의 이력에서 되돌릴 수 있게 한다
+// 첨부는 남길 항목을 가리키도록 옮기고, 수정 이벤트를 받은 첨부 액터가 색인도 고친다.
+#[async_trait]
+impl Handler<MergeItems> for DataManagerActor {
+    type Result = ActorResult<DataItem>;
+
+    async fn handle(&mut self, msg: MergeItems, _: &Context<Self>) -> Self::Result {
+        self.ensure_loaded().await;
+        let mut merge_ids: Vec<String> = Vec::new();
+        for id in msg.merge_ids {
+            if id != msg.keep_id && !merge_ids.contains(&id) {
+                merge_ids.push(id);
+            }
+        }
+        if merge_ids.is_empty() {
+            return Err(UserError::InvalidInput(format!(
+                "No other items to merge into {}",
+                msg.keep_id
+            )));
+        }
+
+        // 하나라도 없으면 아무것도 바꾸지 않는다
+        let current = self.owned_item(&msg.user_id, &msg.keep_id).await?;
+        let mut others = Vec::with_capacity(merge_ids.len());
+        for id in &merge_ids {
+            others.push(self.owned_item(&msg.user_id, id).await?);
+        }
+
+        let mut item = current.clone();
+        let mut revisions = vec![snapshot_revision(&current)];
+        for other in &others {
+            let content = other.content.trim();
+            if !content.is_empty() && !item.content.contains(content) {
+                if !item.content.trim().is_empty() {
+                    item.content.push_str("\n\n");
+                }
+                item.content.push_str(content);
+            }
+            for tag in &other.tags {
+                self.tag_index.user_mut(&msg.user_id).add(&item.id, tag);
+            }
+            for attachment in &other.attachments {
+                if item.attachments.iter().all(|a| a.id != attachment.id) {
+                    let mut attachment = attachment.clone();
+                    attachment.item_id = item.id.clone();
+                    item.attachments.push(attachment);
+                }
+            }
+            revisions.extend(self.load_history(&other.id).await);
+            revisions.push(snapshot_revision(other));
+        }
+        item.tags = self.tag_index.tags_of(&msg.user_id, &item.id);
+        item.updated_at = self.clock.trusted_now();
+
+        let mut history = self.load_history(&item.id).await;
+        history::merge_revisions(&mut history, revisions);
+        let data = serde_json::to_vec(&history)?;
+        self.persist(Self::history_key(&item.id), data).await?;
+        self.commit_update(&msg.user_id, &item, None).await?;
+        if let Err(e) = self.save_tag_index().await {
+            debug_print!("Failed to save tag index: {}", e);
+        }
+
+        // 컬렉션에서는 합쳐진 항목 자리를 남길 항목으로 바꾼다
+        let mut collections_changed = false;
+        for collection in self.collections.entry(msg.user_id.clone()).or_default() {
+            if !collection.item_ids.iter().any(|id| merge_ids.contains(id)) {
+                continue;
+            }
+            let mut item_ids = Vec::with_capacity(collection.item_ids.len());
+            for id in collection.item_ids.drain(..) {
+                let id = if merge_ids.contains(&id) {
+                    item.id.clone()
+                } else {
+                    id
+                };
+                if !item_ids.contains(&id) {
+                    item_ids.push(id);
+                }
+            }
+            collection.item_ids = item_ids;
+            collections_changed = true;
+        }
+        if collections_changed && let Err(e) = self.save_collections().await {
+            debug_print!("Failed to save collections: {}", e);
+        }
+
+        for id in merge_ids {
+            if let Err(e) = self.remove_item(msg.user_id.clone(), id.clone()).await {
+                debug_print!("Failed to delete item {}: {}", id, e);
+            }
+        }
+
+        DataItemUpdatedSignal {
+            user_id: msg.user_id,
+            item: item.clone(),
+        }
+        .emit();
+        Ok(item)
+    }
+}
+
+#[async_trait]
+impl Handler<GetItemHistory> for DataManagerActor {
+    type Result = ActorResult<Vec<ItemRevision>>;
+
+    async fn handle(&mut self, msg: GetItemHistory, _: &Context<Self>) -> Self::Result {
+        self.ensure_loaded().await;
+        self.owned_item(&msg.user_id, &msg.item_id).await?;
+        Ok(self.load_history(&msg.item_id).await)
+    }
+}
+
+#[async_trait]
+impl Handler<RevertItemToRevision> for DataManagerActor {
+    type Result = ActorResult<DataItem>;
+
+    async fn handle(&mut self, msg: RevertItemToRevision, _: &Context<Self>) -> Self::Result {
+        self.ensure_loaded().await;
+        let current = self.owned_item(&msg.user_id, &msg.item_id).await?;
+        let revision = self
+            .load_history(&msg.item_id)
+            .await
+            .into_iter()
+            .find(|r| r.revision == msg.revision)
+            .ok_or(UserError::UnknownRevision(msg.revision))?;
+
+        let mut item = current.clone();
+        item.title = revision.title;
+        item.content = revision.content;
+        item.updated_at = self.clock.trusted_now();
+        self.commit_update(&msg.user_id, &item, Some(&current))
+            .await?;
+
+        // 되돌리기도 일반 수정처럼 실행 취소할 수 있다
+        self.undo_stack.record(&msg.user_id, current);
+        Ok(item)
+    }
+}
+
+#[async_trait]
+impl Handler<UndoLastChange> for DataManagerActor {
+    type Result = ActorResult<DataItem>;
+
+    async fn handle(&mut self, msg: UndoLastChange, _: &Context<Self>) -> Self::Result {
+        self.ensure_loaded().await;
+        let snapshot = self
+            .undo_stack
+            .pop_undo(&msg.user_id)
+            .ok_or(UserError::NothingToUndo)?;
+        let result = match self.current_item(&snapshot.id).await {
+            Ok(current) => self
+                .restore_snapshot(&msg.user_id, &snapshot, &current)
+                .await
+                .map(|item| (item, current)),
+            Err(e) => Err(e),
+        };
+
+        match result {
+            Ok((item, current)) => {
+                self.undo_stack.push_redo(&msg.user_id, current);
+                Ok(item)
+            }
+            Err(e) => {
+                self.undo_stack.push_undo(&msg.user_id, snapshot);
+                Err(e)
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<RedoLastChange> for DataManagerActor {
+    type Result = ActorResult<DataItem>;
+
+    async fn handle(&mut self, msg: RedoLastChange, _: &Context<Self>) -> Self::Result {
+        self.ensure_loaded().await;
+        let snapshot = self
+            .undo_stack
+            .pop_redo(&msg.user_id)
+            .ok_or(UserError::NothingToRedo)?;
+        let result = match self.current_item(&snapshot.id).await {
+            Ok(current) => self
+                .restore_snapshot(&msg.user_id, &snapshot, &current)
+                .await
+                .map(|item| (item, current)),
+            Err(e) => Err(e),
+        };
+
+        match result {
+            Ok((item, current)) => {
+                self.undo_stack.push_undo(&msg.user_id, current);
+                Ok(item)
+            }
+            Err(e) => {
+                self.undo_stack.push_redo(&msg.user_id, snapshot);
+                Err(e)
+            }
+        }
+    }
+}
+
+// 개인정보 삭제 시 메모리에 남은 색인도 비운다 (저장소는 개인정보 액터가 비움)
+#[async_trait]
+impl Handler<WipeUserData> for DataManagerActor {
+    type Result = ActorResult<()>;
+
+    async fn handle(&mut self, _: WipeUserData, _: &Context<Self>) -> Self::Result {
+        for (_, fetch) in self.remote_fetches.drain() {
+            fetch.abort();
+        }
+        for (_, push) in self.remote_pushes.drain() {
+            push.abort();
+        }
+        self.deferred_fetches.clear();
+        self.deferred_pushes.clear();
+        self.tag_index = UserTagIndex::default();
+        self.collections.clear();
+        self.idempotency = IdempotencyWindow::default();
+        self.index_loaded = false;
+        self.owners_claimed = false;
+        self.undo_stack.clear();
+        Ok(())
+    }
+}
+
+// 원격 페이지를 저장하고 저장된 항목(태그는 색인 기준)을 돌려준다
+#[async_trait]
+impl Handler<StoreRemotePage> for DataManagerActor {
+    type Result = ActorResult<Vec<DataItem>>;
+
+    async fn handle(&mut self, msg: StoreRemotePage, _: &Context<Self>) -> Self::Result {
+        self.ensure_loaded().await;
+
+        let mut stored = Vec::with_capacity(msg.items.len());
+        let mut changed = Vec::new();
+        let mut tags_changed = false;
+        for mut item in msg.items {
+            if item.id.is_empty() {
+                continue;
+            }
+            // 이 사용자의 계정에서 받은 항목이므로 주인은 요청한 사용자다
+            // 같은 id의 다른 사용자 항목은 건드리지 않는다.
+            item.owner = msg.user_id.clone();
+            let local = self.load_item(&item.id).await;
+            if let Some(local) = local.as_ref()
+                && !local.owner.is_empty()
+                && local.owner != msg.user_id
+            {
+                debug_print!("Ignoring remote {} owned by another user", item.id);
+                continue;
+            }
+            // 기기에서 더 나중에 고친 항목은 덮어쓰지 않는다 (올리는 일은 동기화가 맡는다)
+            if let Some(local) = local.as_ref()
+                && local.updated_at > item.updated_at
+            {
+                stored.push(local.clone());
+                continue;
+            }
+
+            for tag in &item.tags {
+                if let Ok(tag) = TagIndex::normalize(tag) {
+                    tags_changed |= self.tag_index.user_mut(&msg.user_id).add(&item.id, &tag);
+                }
+            }
+            item.tags = self.tag_index.tags_of(&msg.user_id, &item.id);
+            self.write_item(&item).await?;
+            if local.is_none_or(|local| local.updated_at < item.updated_at) {
+                changed.push(item.clone());
+            }
+            stored.push(item);
+        }
+        if tags_changed {
+            self.save_tag_index().await?;
+        }
+        if !changed.is_empty() {
+            self.publish(AppEvent::DataItemsSynced {
+                user_id: msg.user_id.clone(),
+                items: changed,
+            });
+        }
+        Ok(stored)
+    }
+}
+
+// 저장은 원격 페이지와 같은 경로로 해서 기기에서 더 나중에 고친 항목을 덮어쓰지 않는다
+#[async_trait]
+impl Notifiable<ItemRevalidated> for DataManagerActor {
+    async fn notify(&mut self, msg: ItemRevalidated, ctx: &Context<Self>) {
+        self.revalidating.remove(&msg.key);
+        let Some(item) = msg.item else {
+            return;
+        };
+        let served = self.load_item(&item.id).await;
+        let store = StoreRemotePage {
+            user_id: msg.user_id.clone(),
+            items: vec![item],
+        };
+        let stored = match self.handle(store, ctx).await {
+            Ok(stored) => stored,
+            Err(e) => {
+                debug_print!("Failed to store revalidated {}: {}", msg.key, e);
+                return;
+            }
+        };
+        for item in stored {
+            if served
+                .as_ref()
+                .is_none_or(|served| served.updated_at < item.updated_at)
+            {
+                DataRefreshedSignal {
+                    user_id: msg.user_id.clone(),
+                    key: msg.key.clone(),
+                    item,
+                }
+                .emit();
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Notifiable<FetchPagedRemote> for DataManagerActor {
+    async fn notify(&mut self, msg: FetchPagedRemote, ctx: &Context<Self>) {
+        let trace_id = TraceId::current_or_begin().into();
+        let Some(api) = self.api.clone() else {
+            UserDataResponse {
+                user_id: msg.user_id,
+                items: vec![],
+                last_updated: 0,
+                error: Some(ApiError::NotConfigured.to_string()),
+                trace_id,
+                has_more: false,
+            }
+            .emit();
+            return;
+        };
+        if !self.sync_suspended.is_empty() {
+            debug_print!(
+                "Sync is suspended, deferring remote fetch for {}",
+                msg.user_id
+            );
+            self.deferred_fetches.insert(msg.user_id, msg.page_size);
+            return;
+        }
+        if !self
+            .quota_allows(&msg.user_id, QuotaMetric::ApiCalls, 1)
+            .await
+        {
+            UserDataResponse {
+                user_id: msg.user_id,
+                items: vec![],
+                last_updated: 0,
+                error: Some("API call quota exceeded".to_string()),
+                trace_id,
+                has_more: false,
+            }
+            .emit();
+            return;
+        }
+
+        // 같은 사용자의 이전 가져오기는 새 요청으로 대체
+        if let Some(previous) = self.remote_fetches.remove(&msg.user_id) {
+            previous.abort();
+        }
+        let page_size = msg
+            .page_size
+            .unwrap_or(DEFAULT_REMOTE_PAGE_SIZE)
+            .clamp(1, MAX_PAGE_SIZE);
+        let fetch = TrackedTask::spawn(
+            &mut self._owned_tasks,
+            Self::fetch_remote_pages(
+                ctx.address(),
+                api,
+                self.quota.clone(),
+                self.sync_crypto.clone(),
+                self.clock.clone(),
+                msg.user_id.clone(),
+                page_size,
+                trace_id,
+            ),
+        );
+        self.remote_fetches.insert(msg.user_id, fetch);
+    }
+}
+
+#[async_trait]
+impl Notifiable<PushLocalChanges> for DataManagerActor {
+    async fn notify(&mut self, msg: PushLocalChanges, ctx: &Context<Self>) {
+        let Some(api) = self.api.clone() else {
+            LocalChangesPushedSignal {
+                user_id: msg.user_id,
+                pushed: 0,
+                error: Some(ApiError::NotConfigured.to_string()),
+            }
+            .emit();
+            return;
+        };
+        if !self.sync_suspended.is_empty() {
+            debug_print!("Sync is suspended, deferring push for {}", msg.user_id);
+            *self.deferred_pushes.entry(msg.user_id).or_default() |= msg.full;
+            return;
+        }
+        if !self
+            .quota_allows(&msg.user_id, QuotaMetric::ApiCalls, 1)
+            .await
+        {
+            LocalChangesPushedSignal {
+                user_id: msg.user_id,
+                pushed: 0,
+                error: Some("API call quota exceeded".to_string()),
+            }
+            .emit();
+            return;
+        }
+
+        let pending = match self.pending_push(&msg.user_id, msg.full).await {
+            Ok(pending) => pending,
+            Err(e) => {
+                LocalChangesPushedSignal {
+                    user_id: msg.user_id,
+                    pushed: 0,
+                    error: Some(e.to_string()),
+                }
+                .emit();
+                return;
+            }
+        };
+
+        // 같은 사용자의 이전 올리기는 새 요청으로 대체 (올린 변경을 지우지 않았으므로 다시 올린다)
+        if let Some(previous) = self.remote_pushes.remove(&msg.user_id) {
+            previous.abort();
+        }
+        let push = TrackedTask::spawn(
+            &mut self._owned_tasks,
+            Self::push_local_items(
+                ctx.address(),
+                api,
+                self.quota.clone(),
+                self.sync_crypto.clone(),
+                msg.user_id.clone(),
+                pending,
+            ),
+        );
+        self.remote_pushes.insert(msg.user_id, push);
+    }
+}
+
+#[async_trait]
+impl Notifiable<LocalChangesPushed> for DataManagerActor {
+    async fn notify(&mut self, msg: LocalChangesPushed, _: &Context<Self>) {
+        self.remote_pushes.remove(&msg.user_id);
+        // 올리는 동안 새로 남은 변경(더 큰 순번)은 다음 요청이 올린다
+        let mut outbox = self.load_outbox(&msg.user_id).await;
+        outbox.changes.retain(|_, change| change.seq > msg.through);
+        let mut error = None;
+        if let Err(e) = self.save_outbox(&msg.user_id, &outbox).await {
+            // 올린 변경을 지우지 못하면 다음 요청이 같은 항목을 다시 올린다
+            debug_print!("Failed to clear pushed changes for {}: {}", msg.user_id, e);
+            error = Some(e.to_string());
+        }
+        LocalChangesPushedSignal {
+            user_id: msg.user_id,
+            pushed: msg.pushed,
+            error,
+        }
+        .emit();
+    }
+}
+
+// 백그라운드 모드와 절전 (멈춘 동안 원격 가져오기를 미뤘다가, 모두 풀리면 처음부터 다시 받는다)
+#[async_trait]
+impl Notifiable<SetSubsystemSuspended> for DataManagerActor {
+    async fn notify(&mut self, msg: SetSubsystemSuspended, ctx: &Context<Self>) {
+        let was_suspended = !self.sync_suspended.is_empty();
+        if msg.suspended {
+            self.sync_suspended.insert(msg.reason);
+        } else {
+            self.sync_suspended.remove(&msg.reason);
+        }
+        if was_suspended != self.sync_suspended.is_empty() {
+            return;
+        }
+        if msg.suspended {
+            // 진행 중이던 가져오기의 페이지 크기는 알 수 없으므로 기본값으로 다시 받는다
+            for (user_id, fetch) in self.remote_fetches.drain() {
+                if !fetch.is_finished() {
+                    fetch.abort();
+                    self.deferred_fetches.entry(user_id).or_insert(None);
+                }
+            }
+            for (user_id, push) in self.remote_pushes.drain() {
+                if !push.is_finished() {
+                    push.abort();
+                    self.deferred_pushes.entry(user_id).or_default();
+                }
+            }
+            return;
+        }
+
+        let deferred: Vec<_> = self.deferred_fetches.drain().collect();
+        for (user_id, page_size) in deferred {
+            self.notify(FetchPagedRemote { user_id, page_size }, ctx)
+                .await;
+        }
+        let deferred: Vec<_> = self.deferred_pushes.drain().collect();
+        for (user_id, full) in deferred {
+            self.notify(PushLocalChanges { user_id, full }, ctx).await;
+        }
+    }
+}
+
+// 끝나지 않은 원격 가져오기 (미뤄 둔 것과 진행 중이던 것)를 남겨 다음 실행에서 다시 받는다
+#[async_trait]
+impl Handler<PersistState> for DataManagerActor {
+    type Result = Option<PersistedState>;
+
+    async fn handle(&mut self, _: PersistState, _: &Context<Self>) -> Self::Result {
+        let mut pending = self.deferred_fetches.clone();
+        for (user_id, fetch) in &self.remote_fetches {
+            if !fetch.is_finished() {
+                pending.entry(user_id.clone()).or_insert(None);
+            }
+        }
+        if pending.is_empty() {
+            return None;
+        }
+        PersistedState::new(STATE_VERSION, &pending)
+    }
+}
+
+#[async_trait]
+impl Handler<RestoreState> for DataManagerActor {
+    type Result = bool;
+
+    async fn handle(&mut self, msg: RestoreState, ctx: &Context<Self>) -> Self::Result {
+        let Some(pending) = msg.0.read::<HashMap<UserId, Option<usize>>>(STATE_VERSION) else {
+            return false;
+        };
+        debug_print!("Resuming {} remote fetches from last run", pending.len());
+        // 멈춘 상태라면 FetchPagedRemote가 다시 미뤄 둔다
+        for (user_id, page_size) in pending {
+            self.notify(FetchPagedRemote { user_id, page_size }, ctx)
+                .await;
+        }
+        true
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<FetchUserDataRequest> for DataManagerActor {
+    async fn notify(&mut self, msg: FetchUserDataRequest, ctx: &Context<Self>) {
+        let trace = TraceId::current_or_begin();
+        let fetch = FetchRecentData {
+            user_id: msg.user_id.clone(),
+            limit: msg.limit.map(|limit| limit as usize),
+            mode: FetchMode::StaleWhileRevalidate,
+        };
+        let data_result = trace
+            .run("DataManagerActor", "FetchUserDataRequest", self.handle(fetch, ctx))
+            .await;
+
+        match data_result {
+            Ok(user_data) => {
+                UserDataResponse {
+                    user_id: user_data.user_id,
+                    items: user_data.items,
+                    last_updated: user_data.last_updated,
+                    error: None,
+                    trace_id: trace.into(),
+                    has_more: false,
+                }
+                .emit();
+            }
+            Err(e) => {
+                UserDataResponse {
+                    user_id: msg.user_id,
+                    items: vec![],
+                    last_updated: 0,
+                    error: Some(e.to_string()),
+                    trace_id: trace.into(),
+                    has_more: false,
+                }
+                .emit();
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Notifiable<CreateDataItemRequest> for DataManagerActor {
+    async fn notify(&mut self, msg: CreateDataItemRequest, ctx: &Context<Self>) {
+        let key = msg.idempotency_key.as_deref();
+        let payload = Self::payload_digest(&(&msg.title, &msg.content, &msg.tags));
+        if let Some(replay) = self
+            .repeated_mutation(&msg.user_id, MutationKind::Create, key, &payload)
+            .await
+        {
+            send_repeated_mutation(msg.user_id, msg.temp_id, MutationKind::Create, replay, None);
+            return;
+        }
+
+        if let Some(temp_id) = &msg.temp_id {
+            let now = self.clock.trusted_now();
+            let provisional = DataItem {
+                id: temp_id.clone(),
+                title: msg.title.clone(),
+                content: msg.content.clone(),
+                created_at: now,
+                updated_at: now,
+                tags: msg
+                    .tags
+                    .iter()
+                    .filter_map(|tag| TagIndex::normalize(tag).ok())
+                    .collect(),
+                attachments: Vec::new(),
+                owner: msg.user_id.clone(),
+            };
+            DataChangedSignal {
+                user_id: msg.user_id.clone(),
+                temp_id: temp_id.clone(),
+                kind: MutationKind::Create,
+                item_id: temp_id.clone(),
+                item: Some(provisional),
+            }
+            .emit();
+        }
+
+        let create = CreateDataItem {
+            user_id: msg.user_id.clone(),
+            title: msg.title,
+            content: msg.content,
+            tags: msg.tags,
+        };
+        let created = match self.handle(create, ctx).await {
+            Ok(item) => {
+                let item_id = item.id.clone();
+                let outcome = MutationReplay::Created(item);
+                self.remember_mutation(&msg.user_id, msg.idempotency_key, payload, outcome)
+                    .await;
+                Ok(item_id)
+            }
+            Err(e) => {
+                debug_print!("Failed to create item: {}", e);
+                Err(e)
+            }
+        };
+        if let Some(temp_id) = msg.temp_id {
+            send_mutation_result(msg.user_id, temp_id, MutationKind::Create, created, None);
+        }
+    }
+}
+
+#[async_trait]
+impl Notifiable<UpdateDataItemRequest> for DataManagerActor {
+    async fn notify(&mut self, msg: UpdateDataItemRequest, _: &Context<Self>) {
+        self.ensure_loaded().await;
+        let key = msg.idempotency_key.as_deref();
+        let payload =
+            Self::payload_digest(&(&msg.item_id, &msg.title, &msg.content, msg.base_updated_at));
+        if let Some(replay) = self
+            .repeated_mutation(&msg.user_id, MutationKind::Update, key, &payload)
+            .await
+        {
+            let current = self.load_item(&msg.item_id).await;
+            send_repeated_mutation(
+                msg.user_id,
+                msg.temp_id,
+                MutationKind::Update,
+                replay,
+                current,
+            );
+            return;
+        }
+        let now = self.clock.trusted_now();
+
+        // 저장된 항목이 없으면 새 항목으로 취급 (다른 사용자의 항목이면 건드리지 않는다)
+        let previous = match self.load_owned_item(&msg.user_id, &msg.item_id).await {
+            Ok(previous) => previous,
+            Err(e) => {
+                debug_print!("Rejected update of {}: {}", msg.item_id, e);
+                if let Some(temp_id) = msg.temp_id {
+                    send_mutation_result(msg.user_id, temp_id, MutationKind::Update, Err(e), None);
+                }
+                return;
+            }
+        };
+        let mut item = match previous.clone() {
+            Some(item) => item,
+            None => DataItem {
+                id: msg.item_id.clone(),
+                title: String::new(),
+                content: String::new(),
+                created_at: now,
+                updated_at: now,
+                tags: Vec::new(),
+                attachments: Vec::new(),
+                owner: msg.user_id.clone(),
+            },
+        };
+        let mut content = msg.content;
+
+        if let Some(temp_id) = &msg.temp_id {
+            let mut provisional = item.clone();
+            if let Some(title) = &msg.title {
+                provisional.title = title.clone();
+            }
+            if let Some(content) = &content {
+                provisional.content = content.clone();
+            }
+            provisional.updated_at = now;
+            DataChangedSignal {
+                user_id: msg.user_id.clone(),
+                temp_id: temp_id.clone(),
+                kind: MutationKind::Update,
+                item_id: item.id.clone(),
+                item: Some(provisional),
+            }
+            .emit();
+        }
+
+        // 수정 시작 뒤에 다른 기기의 수정이 먼저 저장됐으면 기준 리비전과 3-way 병합한다
+        // 같은 줄을 양쪽에서 고쳤으면 저장하지 않고 Dart에 수동 해결을 맡긴다.
+        if let (Some(base_updated_at), Some(current), Some(incoming)) =
+            (msg.base_updated_at, previous.as_ref(), content.as_ref())
+            && current.updated_at != base_updated_at
+        {
+            let base = self
+                .load_history(&current.id)
+                .await
+                .into_iter()
+                .rev()
+                .find(|revision| revision.updated_at == base_updated_at);
+            match base.map(|base| merge_text(&base.content, &current.content, incoming)) {
+                Some(outcome) if outcome.conflicts == 0 => content = Some(outcome.text),
+                outcome => {
+                    if let Some(temp_id) = msg.temp_id {
+                        let conflict = UserError::InvalidInput(format!(
+                            "{} was changed on another device",
+                            current.id
+                        ));
+                        send_mutation_result(
+                            msg.user_id.clone(),
+                            temp_id,
+                            MutationKind::Update,
+                            Err(conflict),
+                            Some(current.clone()),
+                        );
+                    }
+                    DataItemConflictSignal {
+                        user_id: msg.user_id,
+                        current: current.clone(),
+                        incoming_content: incoming.clone(),
+                        merged: outcome.map(|outcome| outcome.text),
+                    }
+                    .emit();
+                    return;
+                }
+            }
+        }
+
+        if let Some(title) = msg.title {
+            item.title = title;
+        }
+        if let Some(content) = content {
+            item.content = content;
+        }
+        item.updated_at = now;
+
+        let saved = match self
+            .commit_update(&msg.user_id, &item, previous.as_ref())
+            .await
+        {
+            Ok(()) => {
+                if let Some(previous) = previous.clone() {
+                    self.undo_stack.record(&msg.user_id, previous);
+                }
+                let outcome = MutationReplay::Updated(item.clone());
+                self.remember_mutation(&msg.user_id, msg.idempotency_key, payload, outcome)
+                    .await;
+                Ok(item.id.clone())
+            }
+            Err(e) => {
+                debug_print!("Failed to save item {}: {}", item.id, e);
+                Err(e)
+            }
+        };
+        if let Some(temp_id) = msg.temp_id {
+            // 되돌린 화면에 저장하지 못한 항목을 다시 보내지 않는다
+            let failed = saved.is_err();
+            let user_id = msg.user_id.clone();
+            send_mutation_result(user_id, temp_id, MutationKind::Update, saved, previous);
+            if failed {
+                return;
+            }
+        }
+
+        // Dart에 알림
+        DataItemUpdatedSignal {
+            user_id: msg.user_id,
+            item,
+        }
+        .emit();
+    }
+}
+
+#[async_trait]
+impl Notifiable<DeleteDataItemRequest> for DataManagerActor {
+    async fn notify(&mut self, msg: DeleteDataItemRequest, _: &Context<Self>) {
+        self.ensure_loaded().await;
+        let key = msg.idempotency_key.as_deref();
+        let payload = Self::payload_digest(&msg.item_id);
+        if let Some(replay) = self
+            .repeated_mutation(&msg.user_id, MutationKind::Delete, key, &payload)
+            .await
+        {
+            let current = self.load_item(&msg.item_id).await;
+            send_repeated_mutation(
+                msg.user_id,
+                msg.temp_id,
+                MutationKind::Delete,
+                replay,
+                current,
+            );
+            return;
+        }
+
+        // 낙관적 모드면 되돌릴 때 보낼 항목을 지우기 전에 읽어 둔다
+        // 다른 사용자의 항목이면 화면도 바꾸지 않고 거절한다.
+        let previous = match self.load_owned_item(&msg.user_id, &msg.item_id).await {
+            Ok(previous) => previous,
+            Err(e) => {
+                debug_print!("Rejected delete of {}: {}", msg.item_id, e);
+                if let Some(temp_id) = msg.temp_id {
+                    send_mutation_result(msg.user_id, temp_id, MutationKind::Delete, Err(e), None);
+                }
+                return;
+            }
+        };
+        if let Some(temp_id) = &msg.temp_id {
+            DataChangedSignal {
+                user_id: msg.user_id.clone(),
+                temp_id: temp_id.clone(),
+                kind: MutationKind::Delete,
+                item_id: msg.item_id.clone(),
+                item: None,
+            }
+            .emit();
+        }
+        let removed = match self
+            .remove_item(msg.user_id.clone(), msg.item_id.clone())
+            .await
+        {
+            Ok(()) => {
+                let outcome = MutationReplay::Deleted(msg.item_id.clone());
+                self.remember_mutation(&msg.user_id, msg.idempotency_key, payload, outcome)
+                    .await;
+                Ok(msg.item_id)
+            }
+            Err(e) => {
+                debug_print!("Failed to delete item {}: {}", msg.item_id, e);
+                Err(e)
+            }
+        };
+        if let Some(temp_id) = msg.temp_id {
+            send_mutation_result(
+                msg.user_id,
+                temp_id,
+                MutationKind::Delete,
+                removed,
+                previous,
+            );
+        }
+    }
+}
+
+#[async_trait]
+impl Notifiable<AddTagRequest> for DataManagerActor {
+    async fn notify(&mut self, msg: AddTagRequest, ctx: &Context<Self>) {
+        let item_id = msg.item_id.clone();
+        let result = self
+            .handle(
+                AddTag {
+                    user_id: msg.user_id,
+                    item_id: msg.item_id,
+                    tag: msg.tag,
+                },
+                ctx,
+            )
+            .await;
+
+        send_item_tags(item_id, result);
+    }
+}
+
+#[async_trait]
+impl Notifiable<RemoveTagRequest> for DataManagerActor {
+    async fn notify(&mut self, msg: RemoveTagRequest, ctx: &Context<Self>) {
+        let item_id = msg.item_id.clone();
+        let result = self
+            .handle(
+                RemoveTag {
+                    user_id: msg.user_id,
+                    item_id: msg.item_id,
+                    tag: msg.tag,
+                },
+                ctx,
+            )
+            .await;
+
+        send_item_tags(item_id, result);
+    }
+}
+
+#[async_trait]
+impl Notifiable<ListTagsRequest> for DataManagerActor {
+    async fn notify(&mut self, msg: ListTagsRequest, _: &Context<Self>) {
+        self.ensure_loaded().await;
+        TagListSignal {
+            tags: self.tag_index.counts(&msg.user_id),
+        }
+        .emit();
+    }
+}
+
+#[async_trait]
+impl Notifiable<CreateCollectionRequest> for DataManagerActor {
+    async fn notify(&mut self, msg: CreateCollectionRequest, ctx: &Context<Self>) {
+        let create = CreateCollection {
+            user_id: msg.user_id,
+            name: msg.name,
+        };
+        let result = self.handle(create, ctx).await;
+        send_collection(result);
+    }
+}
+
+#[async_trait]
+impl Notifiable<AddItemToCollectionRequest> for DataManagerActor {
+    async fn notify(&mut self, msg: AddItemToCollectionRequest, ctx: &Context<Self>) {
+        let result = self
+            .handle(
+                AddItemToCollection {
+                    user_id: msg.user_id,
+                    collection_id: msg.collection_id,
+                    item_id: msg.item_id,
+                },
+                ctx,
+            )
+            .await;
+        send_collection(result);
+    }
+}
+
+#[async_trait]
+impl Notifiable<ListCollectionsRequest> for DataManagerActor {
+    async fn notify(&mut self, msg: ListCollectionsRequest, _: &Context<Self>) {
+        self.ensure_loaded().await;
+        CollectionListSignal {
+            collections: self
+                .collections
+                .get(&msg.user_id)
+                .cloned()
+                .unwrap_or_default(),
+        }
+        .emit();
+    }
+}
+
+#[async_trait]
+impl Notifiable<ItemsByTagRequest> for DataManagerActor {
+    async fn notify(&mut self, msg: ItemsByTagRequest, ctx: &Context<Self>) {
+        let result = self
+            .handle(
+                ItemsByTag {
+                    user_id: msg.user_id,
+                    tag: msg.tag.clone(),
+                    offset: msg.offset as usize,
+                    limit: msg.limit as usize,
+                },
+                ctx,
+            )
+            .await;
+
+        match result {
+            Ok(page) => {
+                ItemsByTagSignal {
+                    request_id: msg.request_id,
+                    tag: msg.tag,
+                    items: page.items,
+                    total: page.total as u64,
+                    next_offset: page.next_offset.map(|offset| offset as u64),
+                    error: None,
+                }
+                .emit();
+            }
+            Err(e) => {
+                ItemsByTagSignal {
+                    request_id: msg.request_id,
+                    tag: msg.tag,
+                    items: Vec::new(),
+                    total: 0,
+                    next_offset: None,
+                    error: Some(e.to_string()),
+                }
+                .emit();
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Notifiable<GetItemHistoryRequest> for DataManagerActor {
+    async fn notify(&mut self, msg: GetItemHistoryRequest, ctx: &Context<Self>) {
+        let item_id = msg.item_id.clone();
+        let result = self
+            .handle(
+                GetItemHistory {
+                    user_id: msg.user_id,
+                    item_id: msg.item_id,
+                },
+                ctx,
+            )
+            .await;
+
+        ItemHistorySignal {
+            item_id,
+            revisions: result.as_ref().cloned().unwrap_or_default(),
+            error: result.err().map(|e| e.to_string()),
+        }
+        .emit();
+    }
+}
+
+#[async_trait]
+impl Notifiable<RevertItemToRevisionRequest> for DataManagerActor {
+    async fn notify(&mut self, msg: RevertItemToRevisionRequest, ctx: &Context<Self>) {
+        let user_id = msg.user_id.clone();
+        let result = self
+            .handle(
+                RevertItemToRevision {
+                    user_id: msg.user_id,
+                    item_id: msg.item_id,
+                    revision: msg.revision,
+                },
+                ctx,
+            )
+            .await;
+        self.send_history_action(user_id, result);
+    }
+}
+
+#[async_trait]
+impl Notifiable<UndoRequest> for DataManagerActor {
+    async fn notify(&mut self, msg: UndoRequest, ctx: &Context<Self>) {
+        let user_id = msg.user_id.clone();
+        let result = self
+            .handle(
+                UndoLastChange {
+                    user_id: msg.user_id,
+                },
+                ctx,
+            )
+            .await;
+        self.send_history_action(user_id, result);
+    }
+}
+
+#[async_trait]
+impl Notifiable<RedoRequest> for DataManagerActor {
+    async fn notify(&mut self, msg: RedoRequest, ctx: &Context<Self>) {
+        let user_id = msg.user_id.clone();
+        let result = self
+            .handle(
+                RedoLastChange {
+                    user_id: msg.user_id,
+                },
+                ctx,
+            )
+            .await;
+        self.send_history_action(user_id, result);
+    }
+}
+
+#[async_trait]
+impl Notifiable<FetchPagedRemoteRequest> for DataManagerActor {
+    async fn notify(&mut self, msg: FetchPagedRemoteRequest, ctx: &Context<Self>) {
+        let fetch = FetchPagedRemote {
+            user_id: msg.user_id,
+            page_size: msg.page_size.map(|size| size as usize),
+        };
+        self.notify(fetch, ctx).await;
+    }
+}
+
+#[async_trait]
+impl Notifiable<PushLocalChangesRequest> for DataManagerActor {
+    async fn notify(&mut self, msg: PushLocalChangesRequest, ctx: &Context<Self>) {
+        let push = PushLocalChanges {
+            user_id: msg.user_id,
+            full: msg.full,
+        };
+        self.notify(push, ctx).await;
+    }
+}
+
+#[async_trait]
+impl Notifiable<MergeItemsRequest> for DataManagerActor {
+    async fn notify(&mut self, msg: MergeItemsRequest, ctx: &Context<Self>) {
+        let user_id = msg.user_id.clone();
+        let merged_ids: Vec<String> = msg
+            .merge_ids
+            .iter()
+            .filter(|id| **id != msg.keep_id)
+            .cloned()
+            .collect();
+        let result = self
+            .handle(
+                MergeItems {
+                    user_id: msg.user_id,
+                    keep_id: msg.keep_id,
+                    merge_ids: msg.merge_ids,
+                },
+                ctx,
+            )
+            .await;
+        ItemsMergedSignal {
+            user_id,
+            merged_ids: if result.is_ok() {
+                merged_ids
+            } else {
+                Vec::new()
+            },
+            item: result.as_ref().ok().cloned(),
+            error: result.err().map(|e| e.to_string()),
+        }
+        .emit();
+    }
+}
+
+// 합칠 때 남기는 리비전 (번호는 history::merge_revisions가 다시 매긴다)
+fn snapshot_revision(item: &DataItem) -> ItemRevision {
+    ItemRevision {
+        revision: 0,
+        title: item.title.clone(),
+        content: item.content.clone(),
+        updated_at: item.updated_at,
+    }
+}
+
+// 낙관적 변경의 결과 (저장됐으면 임시 id → 실제 id, 아니면 restored로 화면을 되돌리게 한다)
+fn send_mutation_result(
+    user_id: UserId,
+    temp_id: String,
+    kind: MutationKind,
+    result: Result<String, UserError>,
+    restored: Option<DataItem>,
+) {
+    match result {
+        Ok(item_id) => MutationConfirmedSignal {
+            user_id,
+            temp_id,
+            item_id,
+        }
+        .emit(),
+        Err(e) => MutationRolledBackSignal {
+            user_id,
+            temp_id,
+            kind,
+            restored,
+            error: e.to_string(),
+        }
+        .emit(),
+    }
+}
+
+// 같은 멱등 키로 다시 온 요청 (다시 적용하지 않고 처음 처리했을 때의 신호를 다시 보낸다)
+// 낙관적 모드면 새 임시 id도 앞서 처리한 항목에 잇는다.
+// 내용이 다른 요청이었으면 낙관적 변경을 current(지금 저장된 항목)로 되돌리게 한다.
+fn send_repeated_mutation(
+    user_id: UserId,
+    temp_id: Option<String>,
+    kind: MutationKind,
+    replay: Result<MutationReplay, UserError>,
+    current: Option<DataItem>,
+) {
+    let replay = match replay {
+        Ok(replay) => replay,
+        Err(e) => {
+            debug_print!("Rejecting {:?} request: {}", kind, e);
+            if let Some(temp_id) = temp_id {
+                send_mutation_result(user_id, temp_id, kind, Err(e), current);
+            }
+            return;
+        }
+    };
+    debug_print!("Ignoring repeated {:?} of item {}", kind, replay.item_id());
+    if let Some(temp_id) = temp_id {
+        let item_id = replay.item_id().to_string();
+        send_mutation_result(user_id.clone(), temp_id, kind, Ok(item_id), None);
+    }
+    match replay {
+        MutationReplay::Created(item) => DataItemCreatedSignal { user_id, item }.emit(),
+        MutationReplay::Updated(item) => DataItemUpdatedSignal { user_id, item }.emit(),
+        MutationReplay::Deleted(item_id) => DataItemDeletedSignal { user_id, item_id }.emit(),
+    }
+}
+
+fn send_item_tags(item_id: String, result: Result<Vec<String>, UserError>) {
+    ItemTagsChangedSignal {
+        item_id,
+        tags: result.as_ref().cloned().unwrap_or_default(),
+        error: result.err().map(|e| e.to_string()),
+    }
+    .emit();
+}
+
+fn send_collection(result: Result<Collection, UserError>) {
+    CollectionUpdatedSignal {
+        collection: result.as_ref().ok().cloned(),
+        error: result.err().map(|e| e.to_string()),
+    }
+    .emit();
+}
+
+// 캐시 액터
+pub struct CacheActor {
+    cache: HashMap<String, CacheEntry>,
+    clock: TrustedClock,
+    _owned_tasks: JoinSet<()>,
+}
+
+// 적중할 때마다 복사하지 않도록 데이터는 Arc로 공유한다
+struct CacheEntry {
+    data: Arc<[u8]>,
+    expires_at: Option<u64>,
+}
+
+impl Actor for CacheActor {}
+
+instrument!(CacheActor, handle: FetchData, CacheData, DeleteData);
+
+impl CacheActor {
+    pub fn new(addr: Address<Self>, cleanup_interval_secs: u64, clock: TrustedClock) -> Self {
+        let mut owned_tasks = JoinSet::new();
+
+        // 캐시 정리 작업 시작
+        owned_tasks.spawn(Self::cleanup_cache(
+            addr,
+            clock.clock().clone(),
+            cleanup_interval_secs,
+        ));
+
+        Self {
+            cache: HashMap::new(),
+            clock,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    // 조회되지 않은 채 만료된 항목이 메모리에 남지 않도록 주기적으로 정리
+    async fn cleanup_cache(mut self_addr: Address<Self>, clock: Clock, interval_secs: u64) {
+        let mut interval = clock
+            .interval(std::time::Duration::from_secs(interval_secs))
+            .throttled(ScheduledTask::CacheCleanup);
+        loop {
+            interval.tick().await;
+            let _ = self_addr.notify(CleanupCache).await;
+        }
+    }
+
+    fn get_current_timestamp(&self) -> u64 {
+        self.clock.trusted_now()
+    }
+}
+
+#[async_trait]
+impl Handler<FetchData> for CacheActor {
+    type Result = Result<Arc<[u8]>, StorageError>;
+
+    async fn handle(&mut self, msg: FetchData, _: &Context<Self>) -> Self::Result {
+        if let Some(entry) = self.cache.get(&msg.key) {
+            // 만료 확인
+            if let Some(expires_at) = entry.expires_at
+                && expires_at < self.get_current_timestamp()
+            {
+                self.cache.remove(&msg.key);
+                return Err(StorageError::Expired(msg.key));
+            }
+
+            Ok(Arc::clone(&entry.data))
+        } else {
+            Err(StorageError::NotFound(msg.key))
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<CacheData> for CacheActor {
+    type Result = Result<(), StorageError>;
+
+    async fn handle(&mut self, msg: CacheData, _: &Context<Self>) -> Self::Result {
+        let expires_at = msg.ttl.map(|ttl| self.get_current_timestamp() + ttl);
+
+        self.cache.insert(
+            msg.key,
+            CacheEntry {
+                data: msg.data,
+                expires_at,
+            },
+        );
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Handler<DeleteData> for CacheActor {
+    type Result = Result<(), StorageError>;
+
+    async fn handle(&mut self, msg: DeleteData, _: &Context<Self>) -> Self::Result {
+        self.cache.remove(&msg.key);
+        Ok(())
+    }
+}
+
+// 내부 메시지 정의
+struct CleanupCache;
+
+#[async_trait]
+impl Notifiable<CleanupCache> for CacheActor {
+    async fn notify(&mut self, _: CleanupCache, _: &Context<Self>) {
+        let now = self.get_current_timestamp();
+        self.cache
+            .retain(|_, entry| entry.expires_at.is_none_or(|expires_at| expires_at >= now));
+    }
+}
+
+#[async_trait]
+impl Handler<WipeUserData> for CacheActor {
+    type Result = Result<(), StorageError>;
+
+    async fn handle(&mut self, _: WipeUserData, _: &Context<Self>) -> Self::Result {
+        self.cache.clear();
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Handler<MeasureStorage> for CacheActor {
+    type Result = Result<Vec<CategoryUsage>, StorageError>;
+
+    async fn handle(&mut self, _: MeasureStorage, _: &Context<Self>) -> Self::Result {
+        let bytes = self
+            .cache
+            .values()
+            .map(|entry| entry.data.len() as u64)
+            .sum();
+        Ok(vec![CategoryUsage::new(
+            StorageCategory::Caches,
+            bytes,
+            self.cache.len() as u64,
+        )])
+    }
+}
+
+// 저장소에 원본이 있는 사본뿐이라 비워도 다음 조회가 다시 채운다
+#[async_trait]
+impl Handler<ClearStorage> for CacheActor {
+    type Result = Result<usize, StorageError>;
+
+    async fn handle(&mut self, msg: ClearStorage, _: &Context<Self>) -> Self::Result {
+        if msg.category != StorageCategory::Caches {
+            return Ok(0);
+        }
+        let count = self.cache.len();
+        self.cache.clear();
+        Ok(count)
+    }
+}
+
+// 저장소 액터
+// 첫 요청을 처리하기 전에 저장소 형식 마이그레이션을 실행한다.
+pub struct StorageActor {
+    storage: Arc<dyn Storage>,
+    migrated: bool,
+    writes_paused: bool, // 원격 삭제 중에는 WipeUserData 말고는 쓰지 않는다
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for StorageActor {}
+
+instrument!(StorageActor, handle: FetchData, StoreData, DeleteData);
+traced!(StorageActor, handle: ScanPrefix);
 
 impl StorageActor {
-    pub fn new() -> Self {
+    pub fn new(storage: Arc<dyn Storage>) -> Self {
         Self {
+            storage,
+            migrated: false,
+            writes_paused: false,
             _owned_tasks: JoinSet::new(),
         }
     }
+
+    // 실패하면 기록만 하고 예전 형식 그대로 쓴다 (다음 실행에서 다시 시도)
+    async fn ensure_migrated(&mut self) {
+        if self.migrated {
+            return;
+        }
+        self.migrated = true;
+        if let Err(e) = migrations::migrate(self.storage.as_ref()).await {
+            debug_print!("Storage migration failed: {}", e);
+        }
+    }
+
+    fn check_writable(&self) -> Result<(), StorageError> {
+        if self.writes_paused {
+            return Err(StorageError::WritesPaused);
+        }
+        Ok(())
+    }
 }
 
 #[async_trait]
 impl Handler<FetchData> for StorageActor {
-    type Result = Result<Vec<u8>, UserError>;
+    type Result = Result<Arc<[u8]>, StorageError>;
 
     async fn handle(&mut self, msg: FetchData, _: &Context<Self>) -> Self::Result {
-        // 실제 구현에서는 파일 시스템이나 데이터베이스에서 데이터 가져오기
-        Err("Storage implementation not available".into())
+        self.ensure_migrated().await;
+        Ok(self.storage.load(&msg.key).await?.into())
     }
 }
 
 #[async_trait]
 impl Handler<StoreData> for StorageActor {
-    type Result = Result<(), UserError>;
+    type Result = Result<(), StorageError>;
 
     async fn handle(&mut self, msg: StoreData, _: &Context<Self>) -> Self::Result {
-        // 실제 구현에서는 파일 시스템이나 데이터베이스에 데이터 저장
+        self.check_writable()?;
+        self.ensure_migrated().await;
         debug_print!(
             "Storing data for key: {}, size: {} bytes",
             msg.key,
             msg.data.len()
         );
+        self.storage.save(&msg.key, &msg.data).await
+    }
+}
+
+#[async_trait]
+impl Handler<DeleteData> for StorageActor {
+    type Result = Result<(), StorageError>;
+
+    async fn handle(&mut self, msg: DeleteData, _: &Context<Self>) -> Self::Result {
+        self.check_writable()?;
+        self.ensure_migrated().await;
+        self.storage.delete(&msg.key).await
+    }
+}
+
+#[async_trait]
+impl Handler<ScanPrefix> for StorageActor {
+    type Result = Result<Vec<(String, Vec<u8>)>, StorageError>;
+
+    async fn handle(&mut self, msg: ScanPrefix, _: &Context<Self>) -> Self::Result {
+        self.ensure_migrated().await;
+        self.storage.scan_prefix(&msg.prefix).await
+    }
+}
+
+#[async_trait]
+impl Handler<WipeUserData> for StorageActor {
+    type Result = Result<(), StorageError>;
+
+    async fn handle(&mut self, _: WipeUserData, _: &Context<Self>) -> Self::Result {
+        self.ensure_migrated().await;
+        debug_print!("Wiping all stored data");
+        self.storage.clear().await
+    }
+}
+
+// 원격 삭제로 쓰기를 멈춘 동안에는 디스크를 건드리지 않는다
+#[async_trait]
+impl Handler<CompactStorage> for StorageActor {
+    type Result = Result<Option<CompactionStats>, StorageError>;
+
+    async fn handle(&mut self, _: CompactStorage, _: &Context<Self>) -> Self::Result {
+        self.check_writable()?;
+        self.ensure_migrated().await;
+        self.storage.compact().await
+    }
+}
+
+#[async_trait]
+impl Handler<SetStorageWritesPaused> for StorageActor {
+    type Result = ();
+
+    async fn handle(&mut self, msg: SetStorageWritesPaused, _: &Context<Self>) -> Self::Result {
+        self.writes_paused = msg.paused;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{error::Error, sync::Arc, time::Duration};
+
+    use super::{
+        CacheActor, DataManagerActor, ItemRevalidated, LocalChangesPushed, StorageActor,
+        StoreRemotePage, SyncOutbox,
+    };
+    use crate::study_actors::{
+        actors::{SyncCryptoActor, TrustedClock},
+        messages::{
+            AddItemToCollection, AddTag, CreateCollection, CreateDataItem, DataItem, FetchMode,
+            FetchRecentData, GetItemHistory, ItemRevision, ItemsByTag, MutationKind,
+            RevertItemToRevision, ScanPrefix, SealSyncItems, SetItemContent,
+            SetStorageWritesPaused, SetupSyncEncryption, StorageError, SyncPayload, UserError,
+        },
+        signals::{
+            CapturedSignal, CreateDataItemRequest, DataChangedSignal, DataItemCreatedSignal,
+            DataItemDeletedSignal, DataItemUpdatedSignal, DataRefreshedSignal,
+            DeleteDataItemRequest, MutationRolledBackSignal, UpdateDataItemRequest,
+        },
+        storage::MemoryStorage,
+        test_support::{TestActorHarness, cache_data, fetch_data, settle, store_data},
+    };
+
+    fn start_cache() -> TestActorHarness<CacheActor> {
+        TestActorHarness::start(|addr| CacheActor::new(addr, 60, TrustedClock::new()))
+    }
+
+    fn start_storage() -> TestActorHarness<StorageActor> {
+        TestActorHarness::start(|_| StorageActor::new(Arc::new(MemoryStorage::new())))
+    }
+
+    fn start_data_manager(
+        cache: &TestActorHarness<CacheActor>,
+        storage: &TestActorHarness<StorageActor>,
+    ) -> TestActorHarness<DataManagerActor> {
+        let (cache_addr, storage_addr) = (cache.addr(), storage.addr());
+        TestActorHarness::start(|addr| {
+            DataManagerActor::new(addr, cache_addr, storage_addr, 300, TrustedClock::new())
+        })
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn cache_entry_expires_after_ttl() -> Result<(), Box<dyn Error>> {
+        let mut cache = start_cache();
+        cache.send(cache_data("key", b"value", Some(30))).await??;
+
+        cache.advance(Duration::from_secs(10)).await;
+        let data = cache.send(fetch_data("key")).await??;
+        assert_eq!(&data[..], b"value");
+
+        cache.advance(Duration::from_secs(25)).await;
+        let result = cache.send(fetch_data("key")).await?;
+        assert!(matches!(result, Err(StorageError::Expired(_))));
+
+        // 만료된 항목은 조회할 때 지워진다
+        let result = cache.send(fetch_data("key")).await?;
+        assert!(matches!(result, Err(StorageError::NotFound(_))));
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn cache_entry_without_ttl_never_expires() -> Result<(), Box<dyn Error>> {
+        let mut cache = start_cache();
+        cache.send(cache_data("key", b"value", None)).await??;
+
+        cache.advance(Duration::from_secs(24 * 60 * 60)).await;
+        let data = cache.send(fetch_data("key")).await??;
+        assert_eq!(&data[..], b"value");
+        Ok(())
+    }
+
+    #[cfg(feature = "simulation")]
+    #[tokio::test]
+    async fn cleanup_evicts_expired_entries_in_virtual_time() -> Result<(), Box<dyn Error>> {
+        use crate::study_actors::actors::VirtualClock;
+
+        let simulation = VirtualClock::new();
+        let clock = TrustedClock::with_clock(simulation.clock());
+        let mut cache = TestActorHarness::start(|addr| CacheActor::new(addr, 60, clock));
+        cache.send(cache_data("short", b"value", Some(60 * 60))).await??;
+        cache.send(cache_data("long", b"value", Some(6 * 60 * 60))).await??;
+
+        // 조회 없이도 정리 주기에 지워지므로 Expired가 아니라 NotFound가 된다
+        simulation.advance(Duration::from_secs(2 * 60 * 60)).await;
+        let result = cache.send(fetch_data("short")).await?;
+        assert!(matches!(result, Err(StorageError::NotFound(_))));
+        cache.send(fetch_data("long")).await??;
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn fetch_falls_through_to_storage_and_fills_cache() -> Result<(), Box<dyn Error>> {
+        let mut cache = start_cache();
+        let mut storage = start_storage();
+        let mut data = start_data_manager(&cache, &storage);
+        storage.send(store_data("key", b"stored")).await??;
+
+        let fetched = data.send(fetch_data("key")).await??;
+        assert_eq!(&fetched[..], b"stored");
+
+        let cached = cache.send(fetch_data("key")).await??;
+        assert_eq!(&cached[..], b"stored");
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn fetch_prefers_cache_over_storage() -> Result<(), Box<dyn Error>> {
+        let mut cache = start_cache();
+        let mut storage = start_storage();
+        let mut data = start_data_manager(&cache, &storage);
+        storage.send(store_data("key", b"stored")).await??;
+        cache.send(cache_data("key", b"cached", Some(60))).await??;
+
+        let fetched = data.send(fetch_data("key")).await??;
+        assert_eq!(&fetched[..], b"cached");
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn expired_cache_entry_falls_through_to_storage() -> Result<(), Box<dyn Error>> {
+        let mut cache = start_cache();
+        let mut storage = start_storage();
+        let mut data = start_data_manager(&cache, &storage);
+        storage.send(store_data("key", b"stored")).await??;
+        cache.send(cache_data("key", b"stale", Some(5))).await??;
+
+        data.advance(Duration::from_secs(10)).await;
+        let fetched = data.send(fetch_data("key")).await??;
+        assert_eq!(&fetched[..], b"stored");
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn fetch_missing_key_is_not_found() -> Result<(), Box<dyn Error>> {
+        let cache = start_cache();
+        let storage = start_storage();
+        let mut data = start_data_manager(&cache, &storage);
+
+        let result = data.send(fetch_data("missing")).await?;
+        assert!(matches!(result, Err(UserError::NotFound(key)) if key == "missing"));
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn scan_prefix_returns_matching_entries_in_key_order() -> Result<(), Box<dyn Error>> {
+        let mut storage = start_storage();
+        storage.send(store_data("items/b", b"2")).await??;
+        storage.send(store_data("items/a", b"1")).await??;
+        storage.send(store_data("itemsx", b"x")).await??;
+        storage.send(store_data("users/a", b"u")).await??;
+
+        let entries = storage
+            .send(ScanPrefix {
+                prefix: "items/".to_string(),
+            })
+            .await??;
+        let keys: Vec<_> = entries.iter().map(|(key, _)| key.as_str()).collect();
+        assert_eq!(keys, ["items/a", "items/b"]);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn paused_storage_rejects_writes_until_resumed() -> Result<(), Box<dyn Error>> {
+        let mut storage = start_storage();
+        storage
+            .send(SetStorageWritesPaused { paused: true })
+            .await?;
+
+        let result = storage.send(store_data("key", b"value")).await?;
+        assert!(matches!(result, Err(StorageError::WritesPaused)));
+
+        storage
+            .send(SetStorageWritesPaused { paused: false })
+            .await?;
+        storage.send(store_data("key", b"value")).await??;
+        Ok(())
+    }
+
+    fn item(id: &str, title: &str, updated_at: u64, tags: &[&str]) -> DataItem {
+        DataItem {
+            id: id.to_string(),
+            title: title.to_string(),
+            content: String::new(),
+            created_at: 0,
+            updated_at,
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            attachments: Vec::new(),
+            owner: "user_1".to_string(),
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn remote_page_keeps_newer_local_items() -> Result<(), Box<dyn Error>> {
+        let cache = start_cache();
+        let mut storage = start_storage();
+        let mut data = start_data_manager(&cache, &storage);
+        let local = serde_json::to_vec(&item("a", "local", 200, &[]))?;
+        storage.send(store_data("items/a", &local)).await??;
+
+        let stored = data
+            .send(StoreRemotePage {
+                user_id: "user_1".to_string(),
+                items: vec![
+                    item("a", "remote", 100, &[]),
+                    item("b", "remote", 100, &[" Work "]),
+                ],
+            })
+            .await??;
+        let titles: Vec<_> = stored.iter().map(|item| item.title.as_str()).collect();
+        assert_eq!(titles, ["local", "remote"]);
+        assert_eq!(stored[1].tags, ["work"]);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn recent_data_lists_only_the_users_items() -> Result<(), Box<dyn Error>> {
+        let cache = start_cache();
+        let mut storage = start_storage();
+        let mut data = start_data_manager(&cache, &storage);
+        let mine = serde_json::to_vec(&item("a", "mine", 100, &[]))?;
+        let mut other = item("b", "other", 200, &[]);
+        other.owner = "user_2".to_string();
+        let other = serde_json::to_vec(&other)?;
+        storage.send(store_data("items/a", &mine)).await??;
+        storage.send(store_data("items/b", &other)).await??;
+
+        let recent = |user_id: &str| FetchRecentData {
+            user_id: user_id.to_string(),
+            limit: None,
+            mode: FetchMode::CacheFirst,
+        };
+        let mine = data.send(recent("user_1")).await??;
+        let titles: Vec<_> = mine.items.iter().map(|item| item.title.as_str()).collect();
+        assert_eq!(titles, ["mine"]);
+        // 항목이 없는 사용자에게는 예시 항목을 만들어 주지 않는다
+        let empty = data.send(recent("user_3")).await??;
+        assert!(empty.items.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn other_users_item_is_not_updated_or_deleted() -> Result<(), Box<dyn Error>> {
+        let cache = start_cache();
+        let mut storage = start_storage();
+        let mut data = start_data_manager(&cache, &storage);
+        let theirs = serde_json::to_vec(&item("a", "theirs", 100, &[]))?;
+        storage.send(store_data("items/a", &theirs)).await??;
+
+        data.notify(UpdateDataItemRequest {
+            user_id: "user_2".to_string(),
+            item_id: "a".to_string(),
+            title: Some("stolen".to_string()),
+            content: None,
+            base_updated_at: None,
+            temp_id: Some("tmp_1".to_string()),
+            idempotency_key: None,
+        })
+        .await?;
+        data.notify(DeleteDataItemRequest {
+            user_id: "user_2".to_string(),
+            item_id: "a".to_string(),
+            temp_id: Some("tmp_2".to_string()),
+            idempotency_key: None,
+        })
+        .await?;
+        settle().await;
+
+        let signals = data.drain_signals();
+        let rolled_back: Vec<MutationRolledBackSignal> =
+            signals.iter().filter_map(CapturedSignal::decode).collect();
+        let temp_ids: Vec<_> = rolled_back.iter().map(|s| s.temp_id.as_str()).collect();
+        assert_eq!(temp_ids, ["tmp_1", "tmp_2"]);
+        assert!(rolled_back.iter().all(|s| s.restored.is_none()));
+        let changed = signals.iter().filter(|signal| {
+            signal.is::<DataChangedSignal>() || signal.is::<DataItemDeletedSignal>()
+        });
+        assert_eq!(changed.count(), 0);
+
+        let stored = storage.send(fetch_data("items/a")).await??;
+        assert_eq!(serde_json::from_slice::<DataItem>(&stored)?.title, "theirs");
+        // 다른 사용자의 올릴 목록에 남기지 않는다
+        let outbox = storage.send(fetch_data("sync_outbox/user_1")).await?;
+        assert!(outbox.is_err());
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn unowned_items_move_to_the_last_signed_in_user() -> Result<(), Box<dyn Error>> {
+        let cache = start_cache();
+        let mut storage = start_storage();
+        let mut legacy = item("a", "legacy", 100, &[]);
+        legacy.owner = String::new();
+        storage
+            .send(store_data("items/a", &serde_json::to_vec(&legacy)?))
+            .await??;
+        let session = serde_json::json!({ "user_id": "user_1", "expires_at": 0 });
+        storage
+            .send(store_data("snapshot/auth", &serde_json::to_vec(&session)?))
+            .await??;
+
+        let recent = |user_id: &str| FetchRecentData {
+            user_id: user_id.to_string(),
+            limit: None,
+            mode: FetchMode::CacheFirst,
+        };
+        // 먼저 찾은 사용자가 아니라 업그레이드 전에 로그인해 있던 사용자가 받는다
+        let mut data = start_data_manager(&cache, &storage);
+        assert!(data.send(recent("user_2")).await??.items.is_empty());
+        assert_eq!(data.send(recent("user_1")).await??.items.len(), 1);
+        let stored = storage.send(fetch_data("items/a")).await??;
+        assert_eq!(serde_json::from_slice::<DataItem>(&stored)?.owner, "user_1");
+
+        // 옮긴 뒤에 생긴 주인 없는 항목은 다시 실행해도 옮기지 않는다
+        legacy.id = "b".to_string();
+        storage
+            .send(store_data("items/b", &serde_json::to_vec(&legacy)?))
+            .await??;
+        let fresh_cache = start_cache();
+        let mut restarted = start_data_manager(&fresh_cache, &storage);
+        assert_eq!(restarted.send(recent("user_1")).await??.items.len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn unowned_items_stay_hidden_without_a_recorded_session() -> Result<(), Box<dyn Error>>
+    {
+        let cache = start_cache();
+        let mut storage = start_storage();
+        let mut legacy = item("a", "legacy", 100, &[]);
+        legacy.owner = String::new();
+        storage
+            .send(store_data("items/a", &serde_json::to_vec(&legacy)?))
+            .await??;
+
+        let mut data = start_data_manager(&cache, &storage);
+        let recent = FetchRecentData {
+            user_id: "user_1".to_string(),
+            limit: None,
+            mode: FetchMode::CacheFirst,
+        };
+        assert!(data.send(recent).await??.items.is_empty());
+        let stored = storage.send(fetch_data("items/a")).await??;
+        assert!(serde_json::from_slice::<DataItem>(&stored)?.owner.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn tags_and_collections_are_kept_per_user() -> Result<(), Box<dyn Error>> {
+        let cache = start_cache();
+        let mut storage = start_storage();
+        let mine = serde_json::to_vec(&item("a", "mine", 100, &[]))?;
+        let mut theirs = item("b", "theirs", 100, &[]);
+        theirs.owner = "user_2".to_string();
+        storage.send(store_data("items/a", &mine)).await??;
+        storage
+            .send(store_data("items/b", &serde_json::to_vec(&theirs)?))
+            .await??;
+        // 사용자별로 나누기 전의 전역 색인은 항목 주인별로 나눠 옮긴다
+        let legacy = serde_json::json!({ "item_tags": { "a": ["work"], "b": ["work"] } });
+        storage
+            .send(store_data("tags/index", &serde_json::to_vec(&legacy)?))
+            .await??;
+
+        let mut data = start_data_manager(&cache, &storage);
+        let by_tag = |user_id: &str| ItemsByTag {
+            user_id: user_id.to_string(),
+            tag: "work".to_string(),
+            offset: 0,
+            limit: 0,
+        };
+        let page = data.send(by_tag("user_1")).await??;
+        let titles: Vec<_> = page.items.iter().map(|item| item.title.as_str()).collect();
+        assert_eq!((titles, page.total), (vec!["mine"], 1));
+        assert!(storage.send(fetch_data("tags/index")).await?.is_err());
+
+        // 다른 사용자의 항목에는 태그를 달거나 컬렉션에 넣지 못한다
+        let tag = AddTag {
+            user_id: "user_1".to_string(),
+            item_id: "b".to_string(),
+            tag: "stolen".to_string(),
+        };
+        assert!(matches!(
+            data.send(tag).await?,
+            Err(UserError::UnknownItem(_))
+        ));
+        let collection = data
+            .send(CreateCollection {
+                user_id: "user_1".to_string(),
+                name: "reading".to_string(),
+            })
+            .await??;
+        let add = |item_id: &str| AddItemToCollection {
+            user_id: "user_1".to_string(),
+            collection_id: collection.id.clone(),
+            item_id: item_id.to_string(),
+        };
+        assert!(data.send(add("b")).await?.is_err());
+        assert_eq!(data.send(add("a")).await??.item_ids, ["a"]);
+        // 다른 사용자는 이 컬렉션을 찾지 못한다
+        let foreign = AddItemToCollection {
+            user_id: "user_2".to_string(),
+            collection_id: collection.id.clone(),
+            item_id: "b".to_string(),
+        };
+        assert!(matches!(
+            data.send(foreign).await?,
+            Err(UserError::UnknownCollection(_))
+        ));
+        assert_eq!(data.send(by_tag("user_2")).await??.items[0].title, "theirs");
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn history_is_only_read_and_reverted_by_the_owner() -> Result<(), Box<dyn Error>> {
+        let cache = start_cache();
+        let mut storage = start_storage();
+        let mut data = start_data_manager(&cache, &storage);
+        let stored = serde_json::to_vec(&item("a", "current", 200, &[]))?;
+        storage.send(store_data("items/a", &stored)).await??;
+        let revisions = vec![ItemRevision {
+            revision: 1,
+            title: "secret draft".to_string(),
+            content: String::new(),
+            updated_at: 100,
+        }];
+        let revisions = serde_json::to_vec(&revisions)?;
+        storage.send(store_data("history/a", &revisions)).await??;
+
+        let history = |user_id: &str| GetItemHistory {
+            user_id: user_id.to_string(),
+            item_id: "a".to_string(),
+        };
+        assert!(matches!(
+            data.send(history("user_2")).await?,
+            Err(UserError::UnknownItem(_))
+        ));
+        assert_eq!(data.send(history("user_1")).await??.len(), 1);
+
+        let revert = |user_id: &str| RevertItemToRevision {
+            user_id: user_id.to_string(),
+            item_id: "a".to_string(),
+            revision: 1,
+        };
+        assert!(data.send(revert("user_2")).await?.is_err());
+        let stored: DataItem =
+            serde_json::from_slice(&storage.send(fetch_data("items/a")).await??)?;
+        assert_eq!(stored.title, "current");
+        assert_eq!(data.send(revert("user_1")).await??.title, "secret draft");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sealed_remote_items_need_the_sync_key() -> Result<(), Box<dyn Error>> {
+        let storage = start_storage();
+        let mut crypto = TestActorHarness::start(|addr| SyncCryptoActor::new(addr, storage.addr()));
+        let user = "user_1".to_string();
+        crypto
+            .send(SetupSyncEncryption {
+                user_id: user.clone(),
+                passphrase: "correct horse".to_string(),
+            })
+            .await??;
+        let sealed = crypto
+            .send(SealSyncItems {
+                user_id: user.clone(),
+                items: vec![item("a", "secret", 100, &[])],
+            })
+            .await??;
+        assert!(matches!(sealed[..], [SyncPayload::Sealed(_)]));
+
+        let opened =
+            DataManagerActor::open_payloads(Some(&mut crypto.addr()), &user, sealed.clone())
+                .await?;
+        assert_eq!(opened[0].title, "secret");
+        // 암호화 액터 없이는 봉인된 항목을 받지 않는다
+        assert!(
+            DataManagerActor::open_payloads(None, &user, sealed)
+                .await
+                .is_err()
+        );
+        Ok(())
+    }
+    #[tokio::test(start_paused = true)]
+    async fn revalidated_item_is_signalled_only_when_newer() -> Result<(), Box<dyn Error>> {
+        let cache = start_cache();
+        let mut storage = start_storage();
+        let mut data = start_data_manager(&cache, &storage);
+        let local = serde_json::to_vec(&item("a", "local", 100, &[]))?;
+        storage.send(store_data("items/a", &local)).await??;
+
+        let revalidated = |title: &str, updated_at: u64| ItemRevalidated {
+            user_id: "user_1".to_string(),
+            key: "items/a".to_string(),
+            item: Some(item("a", title, updated_at, &[])),
+        };
+        data.notify(revalidated("remote", 200)).await?;
+        data.notify(revalidated("older", 150)).await?;
+        settle().await;
+
+        let refreshed = data.signals_of::<DataRefreshedSignal>();
+        let titles: Vec<_> = refreshed
+            .iter()
+            .map(|signal| signal.item.title.as_str())
+            .collect();
+        assert_eq!(titles, ["remote"]);
+        let stored = data.send(fetch_data("items/a")).await??;
+        assert_eq!(serde_json::from_slice::<DataItem>(&stored)?.title, "remote");
+        Ok(())
+    }
+    #[tokio::test(start_paused = true)]
+    async fn failed_optimistic_update_is_rolled_back() -> Result<(), Box<dyn Error>> {
+        let cache = start_cache();
+        let mut storage = start_storage();
+        let mut data = start_data_manager(&cache, &storage);
+        let stored = serde_json::to_vec(&item("a", "before", 100, &[]))?;
+        storage.send(store_data("items/a", &stored)).await??;
+        storage
+            .send(SetStorageWritesPaused { paused: true })
+            .await?;
+
+        data.notify(UpdateDataItemRequest {
+            user_id: "user_1".to_string(),
+            item_id: "a".to_string(),
+            title: Some("after".to_string()),
+            content: None,
+            base_updated_at: None,
+            temp_id: Some("tmp_1".to_string()),
+            idempotency_key: None,
+        })
+        .await?;
+        settle().await;
+
+        let signals = data.drain_signals();
+        let echoed: Vec<DataChangedSignal> =
+            signals.iter().filter_map(CapturedSignal::decode).collect();
+        assert_eq!(echoed.len(), 1);
+        assert_eq!(echoed[0].kind, MutationKind::Update);
+        let provisional = echoed[0].item.as_ref().map(|item| item.title.as_str());
+        assert_eq!(provisional, Some("after"));
+
+        let rolled_back: Vec<MutationRolledBackSignal> =
+            signals.iter().filter_map(CapturedSignal::decode).collect();
+        assert_eq!(rolled_back.len(), 1);
+        assert_eq!(rolled_back[0].temp_id, "tmp_1");
+        let restored = rolled_back[0].restored.as_ref();
+        assert_eq!(restored.map(|item| item.title.as_str()), Some("before"));
+
+        // 저장하지 못한 항목이 되돌린 화면을 다시 덮지 않는다
+        let updated = signals
+            .iter()
+            .filter(|signal| signal.is::<DataItemUpdatedSignal>());
+        assert_eq!(updated.count(), 0);
+        Ok(())
+    }
+    #[tokio::test(start_paused = true)]
+    async fn repeated_create_is_applied_once_across_restart() -> Result<(), Box<dyn Error>> {
+        let cache = start_cache();
+        let mut storage = start_storage();
+        let create = || CreateDataItemRequest {
+            user_id: "user_1".to_string(),
+            title: "title".to_string(),
+            content: String::new(),
+            tags: Vec::new(),
+            temp_id: None,
+            idempotency_key: Some("key_1".to_string()),
+        };
+
+        let mut data = start_data_manager(&cache, &storage);
+        data.notify(create()).await?;
+        data.notify(create()).await?;
+        settle().await;
+        // 다시 온 요청에는 처음 만든 항목을 그대로 다시 알린다
+        let created = data.signals_of::<DataItemCreatedSignal>();
+        assert_eq!(created.len(), 2);
+        assert_eq!(created[0].item.id, created[1].item.id);
+
+        // 곧바로 다시 켠 것처럼 빈 캐시로 새 데이터 관리자를 띄우면 저장된 키를 읽는다
+        let fresh_cache = start_cache();
+        let mut restarted = start_data_manager(&fresh_cache, &storage);
+        restarted.notify(create()).await?;
+        settle().await;
+        let replayed = restarted.signals_of::<DataItemCreatedSignal>();
+        assert_eq!(replayed.len(), 1);
+        assert_eq!(replayed[0].item.id, created[0].item.id);
+
+        let scan = ScanPrefix {
+            prefix: "items/".to_string(),
+        };
+        assert_eq!(storage.send(scan).await??.len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn idempotency_key_is_scoped_by_user_and_kind() -> Result<(), Box<dyn Error>> {
+        let cache = start_cache();
+        let mut storage = start_storage();
+        let mut data = start_data_manager(&cache, &storage);
+        let create = |user_id: &str| CreateDataItemRequest {
+            user_id: user_id.to_string(),
+            title: "title".to_string(),
+            content: String::new(),
+            tags: Vec::new(),
+            temp_id: None,
+            idempotency_key: Some("key_1".to_string()),
+        };
+
+        // 다른 사용자가 같은 키를 써도 각자 항목을 만든다
+        data.notify(create("user_1")).await?;
+        data.notify(create("user_2")).await?;
+        settle().await;
+        let created = data.signals_of::<DataItemCreatedSignal>();
+        assert_eq!(created.len(), 2);
+        assert_ne!(created[0].item.id, created[1].item.id);
+
+        // 같은 키로 온 삭제는 생성의 재전송이 아니다
+        data.notify(DeleteDataItemRequest {
+            user_id: "user_1".to_string(),
+            item_id: created[0].item.id.clone(),
+            temp_id: None,
+            idempotency_key: Some("key_1".to_string()),
+        })
+        .await?;
+        settle().await;
+        let deleted = data.signals_of::<DataItemDeletedSignal>();
+        assert_eq!(deleted.len(), 1);
+        assert_eq!(deleted[0].item_id, created[0].item.id);
+
+        let scan = ScanPrefix {
+            prefix: "items/".to_string(),
+        };
+        assert_eq!(storage.send(scan).await??.len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn reused_key_with_different_payload_is_rejected() -> Result<(), Box<dyn Error>> {
+        let cache = start_cache();
+        let mut storage = start_storage();
+        let mut data = start_data_manager(&cache, &storage);
+        let create = |title: &str, temp_id: &str| CreateDataItemRequest {
+            user_id: "user_1".to_string(),
+            title: title.to_string(),
+            content: String::new(),
+            tags: Vec::new(),
+            temp_id: Some(temp_id.to_string()),
+            idempotency_key: Some("key_1".to_string()),
+        };
+
+        data.notify(create("first", "tmp_1")).await?;
+        settle().await;
+        data.drain_signals();
+
+        // 같은 키로 다른 제목이 오면 예전 결과를 돌려주지 않고 낙관적 변경을 되돌린다
+        data.notify(create("second", "tmp_2")).await?;
+        settle().await;
+        let signals = data.drain_signals();
+        let rolled_back: Vec<MutationRolledBackSignal> =
+            signals.iter().filter_map(CapturedSignal::decode).collect();
+        assert_eq!(rolled_back.len(), 1);
+        assert_eq!(rolled_back[0].temp_id, "tmp_2");
+        let created = signals
+            .iter()
+            .filter(|signal| signal.is::<DataItemCreatedSignal>());
+        assert_eq!(created.count(), 0);
+
+        let scan = ScanPrefix {
+            prefix: "items/".to_string(),
+        };
+        assert_eq!(storage.send(scan).await??.len(), 1);
+        Ok(())
+    }
+
+    #[cfg(feature = "simulation")]
+    #[tokio::test]
+    async fn idempotency_window_follows_the_trusted_clock() -> Result<(), Box<dyn Error>> {
+        use crate::study_actors::actors::VirtualClock;
+
+        let simulation = VirtualClock::new();
+        let clock = TrustedClock::with_clock(simulation.clock());
+        let cache = start_cache();
+        let storage = start_storage();
+        let (cache_addr, storage_addr) = (cache.addr(), storage.addr());
+        let mut data = TestActorHarness::start(|addr| {
+            DataManagerActor::new(addr, cache_addr, storage_addr, 300, clock)
+        });
+        let create = || CreateDataItemRequest {
+            user_id: "user_1".to_string(),
+            title: "title".to_string(),
+            content: String::new(),
+            tags: Vec::new(),
+            temp_id: None,
+            idempotency_key: Some("key_1".to_string()),
+        };
+
+        data.notify(create()).await?;
+        settle().await;
+        // 창이 지난 뒤에 같은 키로 온 요청은 새 요청이다
+        simulation.advance(Duration::from_secs(11 * 60)).await;
+        data.notify(create()).await?;
+        settle().await;
+        let created = data.signals_of::<DataItemCreatedSignal>();
+        assert_eq!(created.len(), 2);
+        assert_ne!(created[0].item.id, created[1].item.id);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn local_changes_are_queued_per_owner_with_tombstones() -> Result<(), Box<dyn Error>> {
+        let cache = start_cache();
+        let storage = start_storage();
+        let mut data = start_data_manager(&cache, &storage);
+        let create = |user_id: &str, title: &str| CreateDataItem {
+            user_id: user_id.to_string(),
+            title: title.to_string(),
+            content: String::new(),
+            tags: Vec::new(),
+        };
+        let a = data.send(create("user_1", "a")).await??;
+        let b = data.send(create("user_1", "b")).await??;
+        let c = data.send(create("user_2", "c")).await??;
+        // 서버에서 받은 항목은 다시 올리지 않는다
+        data.send(StoreRemotePage {
+            user_id: "user_1".to_string(),
+            items: vec![item("remote", "remote", 100, &[])],
+        })
+        .await??;
+
+        let addr = data.addr();
+        let outbox = |user_id: &str| {
+            let key = format!("sync_outbox/{}", user_id);
+            let mut addr = addr.clone();
+            async move {
+                let bytes = addr.send(fetch_data(&key)).await??;
+                Ok::<_, Box<dyn Error>>(serde_json::from_slice::<SyncOutbox>(&bytes)?)
+            }
+        };
+        let queued = outbox("user_1").await?;
+        let ids: Vec<_> = queued.changes.keys().cloned().collect();
+        let mut expected = vec![a.id.clone(), b.id.clone()];
+        expected.sort();
+        assert_eq!(ids, expected);
+        let others = outbox("user_2").await?;
+        assert_eq!(others.changes.keys().collect::<Vec<_>>(), [&c.id]);
+
+        // 올리는 동안 같은 초에 다시 바뀌거나 지운 항목은 올린 뒤에도 남는다
+        let through = queued.last_seq;
+        data.send(SetItemContent {
+            item_id: a.id.clone(),
+            content: "edited".to_string(),
+        })
+        .await??;
+        data.notify(DeleteDataItemRequest {
+            user_id: "user_1".to_string(),
+            item_id: b.id.clone(),
+            temp_id: None,
+            idempotency_key: None,
+        })
+        .await?;
+        data.notify(LocalChangesPushed {
+            user_id: "user_1".to_string(),
+            pushed: 2,
+            through,
+        })
+        .await?;
+        settle().await;
+
+        let queued = outbox("user_1").await?;
+        assert_eq!(queued.changes.len(), 2);
+        assert_eq!(queued.changes[&a.id].deleted_at, None);
+        assert!(queued.changes[&b.id].deleted_at.is_some());
+        Ok(())
+    }
+
+    // 만든 시각과 고친 시각은 기기 시계가 아니라 주입한 신뢰 시계를 따른다
+    #[tokio::test(start_paused = true)]
+    async fn item_timestamps_follow_the_trusted_clock() -> Result<(), Box<dyn Error>> {
+        let cache = start_cache();
+        let storage = start_storage();
+        let mut data = start_data_manager(&cache, &storage);
+        let created = data
+            .send(CreateDataItem {
+                user_id: "user_1".to_string(),
+                title: "a".to_string(),
+                content: String::new(),
+                tags: Vec::new(),
+            })
+            .await??;
+
+        data.advance(Duration::from_secs(60 * 60)).await;
+        data.send(SetItemContent {
+            item_id: created.id.clone(),
+            content: "later".to_string(),
+        })
+        .await??;
+        let recent = data
+            .send(FetchRecentData {
+                user_id: "user_1".to_string(),
+                limit: None,
+                mode: FetchMode::CacheFirst,
+            })
+            .await??;
+        assert_eq!(recent.items[0].updated_at, created.created_at + 60 * 60);
         Ok(())
     }
 }
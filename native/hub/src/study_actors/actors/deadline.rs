@@ -0,0 +1,70 @@
+use messages::prelude::{Address, Handler};
+use std::time::Duration;
+
+use crate::study_actors::logging::debug_print;
+use crate::study_actors::messages::{ActorResult, UserError};
+
+use super::Clock;
+
+// 다른 액터에 보내는 요청의 제한 시간
+// 답이 늦으면 UserError::Timeout으로 바꿔, 멈춘 액터 하나가 로그인 흐름 전체를 붙잡지 않게 한다.
+// 시간이 지나도 받는 쪽은 처리를 계속하며, 늦게 나온 결과만 버려진다.
+#[derive(Clone)]
+pub struct Deadline {
+    clock: Clock,
+    timeout: Duration,
+}
+
+impl Deadline {
+    pub fn new(clock: Clock, timeout_ms: u64) -> Self {
+        Self {
+            clock,
+            timeout: Duration::from_millis(timeout_ms),
+        }
+    }
+
+    pub async fn send<A, M>(&self, addr: &mut Address<A>, msg: M) -> ActorResult<A::Result>
+    where
+        A: Handler<M>,
+        A::Result: Send + Sync,
+        M: Send + 'static,
+    {
+        tokio::select! {
+            result = addr.send(msg) => Ok(result?),
+            _ = self.clock.sleep(self.timeout) => Err(UserError::Timeout {
+                actor: short_type_name::<A>(),
+                timeout_ms: self.timeout.as_millis() as u64,
+            }),
+        }
+    }
+
+    // 시간 초과나 액터 중단이면 stale 값이 있을 때 그것으로 대신한다 (처리 중 오류는 그대로 돌려준다)
+    pub async fn send_or_stale<A, M, T>(
+        &self,
+        addr: &mut Address<A>,
+        msg: M,
+        stale: Option<T>,
+    ) -> ActorResult<T>
+    where
+        A: Handler<M, Result = ActorResult<T>>,
+        M: Send + 'static,
+        T: Send + Sync,
+    {
+        match self.send(addr, msg).await {
+            Ok(result) => result,
+            Err(e @ (UserError::Timeout { .. } | UserError::ActorUnavailable(_))) => match stale {
+                Some(stale) => {
+                    debug_print!("Using stale result: {}", e);
+                    Ok(stale)
+                }
+                None => Err(e),
+            },
+            Err(e) => Err(e),
+        }
+    }
+}
+
+fn short_type_name<A>() -> &'static str {
+    let name = std::any::type_name::<A>();
+    name.rsplit("::").next().unwrap_or(name)
+}
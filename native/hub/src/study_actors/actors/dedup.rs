@@ -0,0 +1,375 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
+use tokio::{sync::broadcast, task::JoinSet};
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        ActorResult, AppEvent, DuplicateGroup, FindDuplicates, ListItems, ScheduledTask, UserError,
+        UserId, WipeUserData,
+    },
+    signals::{DuplicateCandidatesSignal, EmitSignal, FindDuplicatesRequest, route_dart_signals},
+};
+
+use super::{Clock, DataManagerActor, WorkerJob, offload};
+
+// 바뀐 항목이 있는 사용자만 이 간격으로 다시 검사한다
+const SCAN_INTERVAL_SECS: u64 = 15 * 60;
+const SHINGLE_CHARS: usize = 4;
+const MIN_SHINGLES: usize = 16; // 이보다 짧은 글은 우연히 겹치기 쉬워 후보에서 뺀다
+const NUM_HASHES: usize = 64;
+// 16개 띠 x 4행: 유사도 0.7인 쌍은 약 99%가 한 띠 이상에서 만난다
+const BANDS: usize = 16;
+const ROWS: usize = NUM_HASHES / BANDS;
+const SIMILARITY_THRESHOLD: f64 = 0.7;
+
+// 소문자로 바꾸고 문장 부호와 공백 차이는 공백 하나로 모은다
+fn normalize(text: &str) -> Vec<char> {
+    let lowered = text.to_lowercase();
+    let words: Vec<&str> = lowered
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect();
+    words.join(" ").chars().collect()
+}
+
+// 실행마다 같은 값이 나와야 하므로 std 해시 대신 FNV-1a를 쓴다
+fn fnv1a(chars: &[char]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    let mut buffer = [0u8; 4];
+    for c in chars {
+        for byte in c.encode_utf8(&mut buffer).bytes() {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+    hash
+}
+
+// splitmix64 마무리 단계 (시드마다 다른 순열처럼 섞는다)
+fn mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+// 글자 4-gram 집합의 MinHash 서명 (띄어쓰기 없는 한국어도 같은 방식으로 비교된다)
+fn signature(text: &str) -> Option<[u64; NUM_HASHES]> {
+    let chars = normalize(text);
+    let shingles: HashSet<u64> = chars.windows(SHINGLE_CHARS).map(fnv1a).collect();
+    if shingles.len() < MIN_SHINGLES {
+        return None;
+    }
+    let mut signature = [u64::MAX; NUM_HASHES];
+    for shingle in shingles {
+        for (index, slot) in signature.iter_mut().enumerate() {
+            let seed = (index as u64 + 1).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+            *slot = (*slot).min(mix(shingle ^ seed));
+        }
+    }
+    Some(signature)
+}
+
+// 같은 칸의 비율이 자카드 유사도의 추정값이다
+fn similarity(a: &[u64; NUM_HASHES], b: &[u64; NUM_HASHES]) -> f64 {
+    let same = a.iter().zip(b).filter(|(x, y)| x == y).count();
+    same as f64 / NUM_HASHES as f64
+}
+
+fn find_root(parents: &mut [usize], mut node: usize) -> usize {
+    while parents[node] != node {
+        parents[node] = parents[parents[node]];
+        node = parents[node];
+    }
+    node
+}
+
+// 띠가 하나라도 같은 쌍만 비교하고, 문턱을 넘은 쌍을 이어 묶는다
+// 묶음의 similarity는 묶음을 이은 쌍 중 가장 낮은 값이다.
+fn find_duplicate_groups(items: &[(String, String)]) -> Vec<DuplicateGroup> {
+    let signatures: Vec<(&str, [u64; NUM_HASHES])> = items
+        .iter()
+        .filter_map(|(id, text)| signature(text).map(|signature| (id.as_str(), signature)))
+        .collect();
+
+    let mut buckets: HashMap<(usize, &[u64]), Vec<usize>> = HashMap::new();
+    for (position, (_, signature)) in signatures.iter().enumerate() {
+        for (band, rows) in signature.chunks(ROWS).enumerate() {
+            buckets.entry((band, rows)).or_default().push(position);
+        }
+    }
+
+    let mut parents: Vec<usize> = (0..signatures.len()).collect();
+    let mut compared = HashSet::new();
+    let mut links = Vec::new();
+    for members in buckets.values() {
+        for (index, &a) in members.iter().enumerate() {
+            for &b in &members[index + 1..] {
+                if !compared.insert((a, b)) {
+                    continue;
+                }
+                let score = similarity(&signatures[a].1, &signatures[b].1);
+                if score >= SIMILARITY_THRESHOLD {
+                    let (root_a, root_b) = (find_root(&mut parents, a), find_root(&mut parents, b));
+                    parents[root_b] = root_a;
+                    links.push((a, score));
+                }
+            }
+        }
+    }
+
+    let mut lowest: HashMap<usize, f64> = HashMap::new();
+    for (node, score) in links {
+        let root = find_root(&mut parents, node);
+        let entry = lowest.entry(root).or_insert(score);
+        *entry = entry.min(score);
+    }
+    let mut members: HashMap<usize, Vec<usize>> = HashMap::new();
+    for position in 0..signatures.len() {
+        let root = find_root(&mut parents, position);
+        if lowest.contains_key(&root) {
+            members.entry(root).or_default().push(position);
+        }
+    }
+
+    let mut groups: Vec<DuplicateGroup> = members
+        .into_iter()
+        .map(|(root, positions)| DuplicateGroup {
+            item_ids: positions
+                .into_iter()
+                .map(|position| signatures[position].0.to_string())
+                .collect(),
+            similarity: lowest.get(&root).copied().unwrap_or(1.0),
+        })
+        .collect();
+    groups.sort_by(|a, b| {
+        b.item_ids
+            .len()
+            .cmp(&a.item_ids.len())
+            .then_with(|| a.item_ids.cmp(&b.item_ids))
+    });
+    groups
+}
+
+// 중복 찾기 본문 (항목이 많으면 서명 계산이 무거우므로 offload로 실행)
+// items는 (항목 id, 제목과 본문)이며 결과 묶음 안의 순서는 items의 순서를 따른다.
+#[derive(Serialize, Deserialize)]
+pub(super) struct DedupJob {
+    items: Vec<(String, String)>,
+}
+
+impl WorkerJob for DedupJob {
+    const NAME: &'static str = "items/dedup";
+    type Output = Vec<DuplicateGroup>;
+
+    fn run(self) -> Self::Output {
+        find_duplicate_groups(&self.items)
+    }
+}
+
+// 중복 항목 찾기 액터
+// 항목이 바뀐 사용자를 표시해 두었다가 주기적으로 MinHash 지문을 비교하고,
+// 후보 묶음이 지난번과 달라졌을 때만 Dart에 알린다. 합치기는 데이터 관리자가 맡는다.
+pub struct DedupActor {
+    data: Address<DataManagerActor>,
+    dirty_users: HashSet<UserId>,
+    candidates: HashMap<UserId, Vec<DuplicateGroup>>, // 마지막으로 찾은 후보
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for DedupActor {}
+
+impl DedupActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        data: Address<DataManagerActor>,
+        events: broadcast::Receiver<AppEvent>,
+        clock: Clock,
+    ) -> Self {
+        let mut owned_tasks = route_dart_signals!(self_addr, [FindDuplicatesRequest]);
+        owned_tasks.spawn(Self::listen_to_events(self_addr.clone(), events));
+        owned_tasks.spawn(Self::scan_periodically(self_addr, clock));
+
+        Self {
+            data,
+            dirty_users: HashSet::new(),
+            candidates: HashMap::new(),
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    async fn listen_to_events(
+        mut self_addr: Address<Self>,
+        mut events: broadcast::Receiver<AppEvent>,
+    ) {
+        loop {
+            match events.recv().await {
+                Ok(event) => {
+                    let _ = self_addr.notify(AppEventReceived(event)).await;
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    debug_print!("Dedup actor skipped {} app events", skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    }
+
+    async fn scan_periodically(mut self_addr: Address<Self>, clock: Clock) {
+        let mut interval = clock
+            .interval(Duration::from_secs(SCAN_INTERVAL_SECS))
+            .throttled(ScheduledTask::DedupScan);
+        loop {
+            interval.tick().await;
+            let _ = self_addr.notify(ScanDirtyUsers).await;
+        }
+    }
+}
+
+// 내부 메시지 정의
+struct AppEventReceived(AppEvent);
+struct ScanDirtyUsers;
+
+#[async_trait]
+impl Handler<FindDuplicates> for DedupActor {
+    type Result = ActorResult<Vec<DuplicateGroup>>;
+
+    async fn handle(&mut self, msg: FindDuplicates, _: &Context<Self>) -> Self::Result {
+        let items = self
+            .data
+            .send(ListItems {
+                user_id: msg.user_id.clone(),
+            })
+            .await??;
+        let job = DedupJob {
+            items: items
+                .into_iter()
+                .map(|item| (item.id, format!("{}\n{}", item.title, item.content)))
+                .collect(),
+        };
+        let groups = offload(job)
+            .await
+            .map_err(|e| UserError::Upstream(e.to_string()))?;
+        self.dirty_users.remove(&msg.user_id);
+        self.candidates.insert(msg.user_id, groups.clone());
+        Ok(groups)
+    }
+}
+
+#[async_trait]
+impl Notifiable<AppEventReceived> for DedupActor {
+    async fn notify(&mut self, msg: AppEventReceived, _: &Context<Self>) {
+        match msg.0 {
+            AppEvent::DataItemCreated { user_id, .. }
+            | AppEvent::DataItemUpdated { user_id, .. }
+            | AppEvent::DataItemDeleted { user_id, .. }
+            | AppEvent::DataItemsSynced { user_id, .. } => {
+                self.dirty_users.insert(user_id);
+            }
+            AppEvent::SystemNotice { .. }
+            | AppEvent::ConnectivityChanged { .. }
+            | AppEvent::AdminCommandReceived(_)
+            | AppEvent::EntitlementsChanged { .. }
+            | AppEvent::RemoteWipeRequested { .. }
+            | AppEvent::ConfigChanged { .. } => {}
+        }
+    }
+}
+
+#[async_trait]
+impl Notifiable<ScanDirtyUsers> for DedupActor {
+    async fn notify(&mut self, _: ScanDirtyUsers, ctx: &Context<Self>) {
+        for user_id in std::mem::take(&mut self.dirty_users) {
+            let previous = self.candidates.get(&user_id).cloned().unwrap_or_default();
+            let request = FindDuplicates {
+                user_id: user_id.clone(),
+            };
+            match self.handle(request, ctx).await {
+                Ok(groups) if groups != previous => {
+                    DuplicateCandidatesSignal {
+                        user_id,
+                        groups,
+                        error: None,
+                    }
+                    .emit();
+                }
+                Ok(_) => {}
+                Err(e) => debug_print!("Duplicate scan failed for {}: {}", user_id, e),
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<WipeUserData> for DedupActor {
+    type Result = ();
+
+    async fn handle(&mut self, _: WipeUserData, _: &Context<Self>) -> Self::Result {
+        self.dirty_users.clear();
+        self.candidates.clear();
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<FindDuplicatesRequest> for DedupActor {
+    async fn notify(&mut self, msg: FindDuplicatesRequest, ctx: &Context<Self>) {
+        let user_id = msg.user_id.clone();
+        let result = self
+            .handle(
+                FindDuplicates {
+                    user_id: msg.user_id,
+                },
+                ctx,
+            )
+            .await;
+        DuplicateCandidatesSignal {
+            user_id,
+            groups: result.as_ref().cloned().unwrap_or_default(),
+            error: result.err().map(|e| e.to_string()),
+        }
+        .emit();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::find_duplicate_groups;
+    use std::error::Error;
+
+    #[test]
+    fn groups_near_duplicates_and_skips_short_notes() -> Result<(), Box<dyn Error>> {
+        let note = "Meeting notes: discuss the quarterly budget with finance, review the hiring \
+                    plan and agree on the launch date for the mobile app.";
+        let korean =
+            "회의록: 재무팀과 분기 예산을 논의하고 채용 계획을 검토한 뒤 모바일 앱 출시일을 정한다";
+        let items = vec![
+            ("a".to_string(), note.to_string()),
+            (
+                "b".to_string(),
+                "Shopping list: milk, eggs, bread, coffee beans".to_string(),
+            ),
+            ("c".to_string(), note.replace("quarterly", "annual")),
+            ("d".to_string(), note.to_uppercase().replace(',', " ;")),
+            ("e".to_string(), "todo".to_string()),
+            ("f".to_string(), "todo".to_string()),
+            ("g".to_string(), korean.to_string()),
+            ("h".to_string(), korean.replace("분기", "연간")),
+        ];
+
+        let groups = find_duplicate_groups(&items);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].item_ids, ["a", "c", "d"]);
+        assert!(groups[0].similarity >= 0.7 && groups[0].similarity < 1.0);
+        assert_eq!(groups[1].item_ids, ["g", "h"]);
+        Ok(())
+    }
+}
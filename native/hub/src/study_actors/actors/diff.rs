@@ -0,0 +1,172 @@
+use async_trait::async_trait;
+use diffy::Line;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use tokio::task::JoinSet;
+
+use crate::study_actors::{
+    messages::{DiffHunk, DiffLine, DiffLineKind, DiffText, MergeOutcome, ThreeWayMerge},
+    signals::{DiffTextRequest, ThreeWayMergeRequest, respond_to_dart, route_dart_signals},
+};
+
+const CONFLICT_MARKER: &str = "<<<<<<<";
+
+// 줄 단위 비교 결과를 헝크 목록으로 (변경 앞뒤로 세 줄씩 문맥 포함)
+pub fn diff_text(old: &str, new: &str) -> Vec<DiffHunk> {
+    diffy::create_patch(old, new)
+        .hunks()
+        .iter()
+        .map(|hunk| DiffHunk {
+            old_start: hunk.old_range().start() as u32,
+            old_lines: hunk.old_range().len() as u32,
+            new_start: hunk.new_range().start() as u32,
+            new_lines: hunk.new_range().len() as u32,
+            lines: hunk
+                .lines()
+                .iter()
+                .map(|line| {
+                    let (kind, text) = match line {
+                        Line::Context(text) => (DiffLineKind::Context, text),
+                        Line::Insert(text) => (DiffLineKind::Insert, text),
+                        Line::Delete(text) => (DiffLineKind::Delete, text),
+                    };
+                    DiffLine {
+                        kind,
+                        text: text.trim_end_matches(['\n', '\r']).to_string(),
+                    }
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+// 3-way 병합 (같은 줄을 양쪽에서 다르게 고친 곳만 충돌로 남긴다)
+pub fn merge_text(base: &str, ours: &str, theirs: &str) -> MergeOutcome {
+    match diffy::merge(base, ours, theirs) {
+        Ok(text) => MergeOutcome { text, conflicts: 0 },
+        Err(text) => MergeOutcome {
+            conflicts: text
+                .lines()
+                .filter(|line| line.starts_with(CONFLICT_MARKER))
+                .count() as u32,
+            text,
+        },
+    }
+}
+
+// 텍스트 비교/병합 액터
+// 동기화 충돌 처리(데이터 관리자)는 merge_text를 직접 쓰고, Dart 화면은 신호로 요청한다.
+pub struct DiffActor {
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for DiffActor {}
+
+impl DiffActor {
+    pub fn new(self_addr: Address<Self>) -> Self {
+        Self {
+            _owned_tasks: route_dart_signals!(self_addr, [DiffTextRequest, ThreeWayMergeRequest]),
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<DiffText> for DiffActor {
+    type Result = Vec<DiffHunk>;
+
+    async fn handle(&mut self, msg: DiffText, _: &Context<Self>) -> Self::Result {
+        diff_text(&msg.old, &msg.new)
+    }
+}
+
+#[async_trait]
+impl Handler<ThreeWayMerge> for DiffActor {
+    type Result = MergeOutcome;
+
+    async fn handle(&mut self, msg: ThreeWayMerge, _: &Context<Self>) -> Self::Result {
+        merge_text(&msg.base, &msg.ours, &msg.theirs)
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<DiffTextRequest> for DiffActor {
+    async fn notify(&mut self, msg: DiffTextRequest, ctx: &Context<Self>) {
+        let _ = respond_to_dart(msg, |msg| async move {
+            let diff = DiffText {
+                old: msg.old,
+                new: msg.new,
+            };
+            Ok(self.handle(diff, ctx).await)
+        })
+        .await;
+    }
+}
+
+#[async_trait]
+impl Notifiable<ThreeWayMergeRequest> for DiffActor {
+    async fn notify(&mut self, msg: ThreeWayMergeRequest, ctx: &Context<Self>) {
+        let _ = respond_to_dart(msg, |msg| async move {
+            let merge = ThreeWayMerge {
+                base: msg.base,
+                ours: msg.ours,
+                theirs: msg.theirs,
+            };
+            Ok(self.handle(merge, ctx).await)
+        })
+        .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::merge_text;
+
+    const BASE: &str = "one\ntwo\nthree\nfour\nfive\n";
+
+    #[test]
+    fn edits_to_different_lines_merge_cleanly() {
+        let ours = "ONE\ntwo\nthree\nfour\nfive\n";
+        let theirs = "one\ntwo\nthree\nfour\nFIVE\n";
+
+        let merged = merge_text(BASE, ours, theirs);
+        assert_eq!(merged.conflicts, 0);
+        assert_eq!(merged.text, "ONE\ntwo\nthree\nfour\nFIVE\n");
+    }
+
+    #[test]
+    fn different_edits_to_the_same_line_conflict() {
+        let ours = "one\ntwo\nours\nfour\nfive\n";
+        let theirs = "one\ntwo\ntheirs\nfour\nfive\n";
+
+        let merged = merge_text(BASE, ours, theirs);
+        assert_eq!(merged.conflicts, 1);
+        // 양쪽 내용이 충돌 표시 안에 모두 남는다
+        assert!(merged.text.contains("<<<<<<<"));
+        assert!(merged.text.contains("ours\n"));
+        assert!(merged.text.contains("theirs\n"));
+        assert!(merged.text.starts_with("one\ntwo\n"));
+        assert!(merged.text.ends_with("four\nfive\n"));
+    }
+
+    #[test]
+    fn insertion_at_end_of_file_merges_with_an_earlier_edit() {
+        let ours = "one\ntwo\nthree\nfour\nfive\nsix\n";
+        let theirs = "ONE\ntwo\nthree\nfour\nfive\n";
+
+        let merged = merge_text(BASE, ours, theirs);
+        assert_eq!(merged.conflicts, 0);
+        assert_eq!(merged.text, "ONE\ntwo\nthree\nfour\nfive\nsix\n");
+    }
+
+    #[test]
+    fn identical_changes_on_both_sides_are_applied_once() {
+        let changed = "one\nTWO\nthree\nfour\nfive\nsix\n";
+
+        let merged = merge_text(BASE, changed, changed);
+        assert_eq!(merged.conflicts, 0);
+        assert_eq!(merged.text, changed);
+    }
+}
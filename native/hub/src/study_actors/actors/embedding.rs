@@ -0,0 +1,558 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, sync::Arc, time::Duration};
+use tokio::task::JoinSet;
+use tract_onnx::prelude::{
+    Datum, Framework, InferenceFact, InferenceModelExt, IntoTValue, TVec, Tensor, TypedModel,
+    TypedRunnableModel, tract_ndarray, tvec,
+};
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        DataItem, EmbedText, EmbeddingConfig, EmbeddingError, FetchData, FetchMode,
+        FetchRecentData, IndexItems, PerformanceLevel, SemanticMatch, SemanticSearch, StoreData,
+        WipeUserData,
+    },
+    signals::{
+        EmitSignal, IndexItemsForSearchRequest, SemanticIndexSignal, SemanticSearchRequest,
+        SemanticSearchResultSignal, route_dart_signals,
+    },
+};
+
+use super::{DataManagerActor, StorageActor, performance_level};
+
+const INDEX_KEY: &str = "embeddings/index";
+const MAX_INDEX_CANDIDATES: usize = 500;
+const DEFAULT_SEARCH_LIMIT: usize = 20;
+const STREAM_CHUNK_SIZE: usize = 10;
+const INDEX_BATCH_PAUSE_MS: u64 = 100;
+const MAX_WORD_CHARS: usize = 100;
+
+fn vector_key(item_id: &str) -> String {
+    format!("embeddings/item/{}", item_id)
+}
+
+// 색인된 항목 메타데이터 (벡터는 항목별 키에 f32 리틀 엔디언으로 저장)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct IndexEntry {
+    title: String,
+    updated_at: u64,
+}
+
+// BERT 계열 WordPiece 토크나이저 (소문자 변환, 구두점 분리, 최장 일치)
+struct WordPiece {
+    vocab: HashMap<String, i64>,
+    cls: i64,
+    sep: i64,
+    unk: i64,
+    pad: i64,
+}
+
+impl WordPiece {
+    fn from_vocab_file(path: &str) -> Result<Self, EmbeddingError> {
+        let vocab: HashMap<String, i64> = std::fs::read_to_string(path)?
+            .lines()
+            .enumerate()
+            .map(|(id, token)| (token.trim().to_string(), id as i64))
+            .collect();
+        let special = |token: &str| {
+            vocab
+                .get(token)
+                .copied()
+                .ok_or_else(|| format!("Vocabulary is missing {}", token))
+        };
+
+        Ok(Self {
+            cls: special("[CLS]")?,
+            sep: special("[SEP]")?,
+            unk: special("[UNK]")?,
+            pad: special("[PAD]")?,
+            vocab,
+        })
+    }
+
+    fn basic_tokens(text: &str) -> Vec<String> {
+        let mut tokens = Vec::new();
+        let mut current = String::new();
+        for c in text.to_lowercase().chars() {
+            if c.is_alphanumeric() {
+                current.push(c);
+                continue;
+            }
+            if !current.is_empty() {
+                tokens.push(std::mem::take(&mut current));
+            }
+            // 공백은 버리고 구두점/기호는 독립 토큰으로 둔다
+            if !c.is_whitespace() && !c.is_control() {
+                tokens.push(c.to_string());
+            }
+        }
+        if !current.is_empty() {
+            tokens.push(current);
+        }
+        tokens
+    }
+
+    fn word_pieces(&self, word: &str, ids: &mut Vec<i64>) {
+        let chars: Vec<char> = word.chars().collect();
+        if chars.len() > MAX_WORD_CHARS {
+            ids.push(self.unk);
+            return;
+        }
+
+        let mut pieces = Vec::new();
+        let mut start = 0;
+        while start < chars.len() {
+            let mut end = chars.len();
+            let mut found = None;
+            while start < end {
+                let piece: String = chars[start..end].iter().collect();
+                let piece = if start > 0 {
+                    format!("##{}", piece)
+                } else {
+                    piece
+                };
+                if let Some(&id) = self.vocab.get(&piece) {
+                    found = Some(id);
+                    break;
+                }
+                end -= 1;
+            }
+            match found {
+                Some(id) => pieces.push(id),
+                None => {
+                    ids.push(self.unk);
+                    return;
+                }
+            }
+            start = end;
+        }
+        ids.extend(pieces);
+    }
+
+    // [CLS] 토큰들 [SEP] [PAD]... 형태로 max_len 길이에 맞춘다
+    fn encode(&self, text: &str, max_len: usize) -> (Vec<i64>, Vec<i64>) {
+        let mut ids = vec![self.cls];
+        for word in Self::basic_tokens(text) {
+            self.word_pieces(&word, &mut ids);
+            if ids.len() >= max_len - 1 {
+                break;
+            }
+        }
+        ids.truncate(max_len - 1);
+        ids.push(self.sep);
+
+        let mut mask = vec![1i64; ids.len()];
+        ids.resize(max_len, self.pad);
+        mask.resize(max_len, 0);
+        (ids, mask)
+    }
+}
+
+// ONNX 문장 임베딩 모델 (입력 길이를 고정해 최적화된 실행 계획을 만든다)
+struct EmbeddingModel {
+    plan: TypedRunnableModel<TypedModel>,
+    tokenizer: WordPiece,
+    input_count: usize,
+    max_seq_len: usize,
+}
+
+impl EmbeddingModel {
+    fn load(
+        model_path: &str,
+        vocab_path: &str,
+        max_seq_len: usize,
+    ) -> Result<Self, EmbeddingError> {
+        let max_seq_len = max_seq_len.max(8);
+        let mut model = tract_onnx::onnx().model_for_path(model_path)?;
+        // input_ids, attention_mask, (token_type_ids)
+        let input_count = model.input_outlets()?.len().min(3);
+        for input in 0..input_count {
+            model = model.with_input_fact(
+                input,
+                InferenceFact::dt_shape(i64::datum_type(), tvec!(1, max_seq_len)),
+            )?;
+        }
+        let plan = model.into_optimized()?.into_runnable()?;
+
+        Ok(Self {
+            plan,
+            tokenizer: WordPiece::from_vocab_file(vocab_path)?,
+            input_count,
+            max_seq_len,
+        })
+    }
+
+    // 마지막 은닉 상태를 attention mask로 평균 낸 뒤 L2 정규화
+    fn embed(&self, text: &str) -> Result<Vec<f32>, EmbeddingError> {
+        let (ids, mask) = self.tokenizer.encode(text, self.max_seq_len);
+        let shape = (1, self.max_seq_len);
+        let type_ids = vec![0i64; self.max_seq_len];
+
+        let mut inputs: TVec<_> = tvec!();
+        for values in [ids, mask.clone(), type_ids]
+            .into_iter()
+            .take(self.input_count)
+        {
+            let tensor: Tensor = tract_ndarray::Array2::from_shape_vec(shape, values)?.into();
+            inputs.push(tensor.into_tvalue());
+        }
+
+        let outputs = self.plan.run(inputs)?;
+        let hidden = outputs
+            .first()
+            .ok_or("Model produced no output")?
+            .to_array_view::<f32>()?
+            .into_dimensionality::<tract_ndarray::Ix3>()?;
+
+        let dim = hidden.shape()[2];
+        let mut pooled = vec![0.0f32; dim];
+        for (position, &attend) in mask.iter().enumerate() {
+            if attend == 0 {
+                continue;
+            }
+            for (d, value) in pooled.iter_mut().enumerate() {
+                *value += hidden[[0, position, d]];
+            }
+        }
+
+        // 평균 후 정규화한 결과는 합을 바로 정규화한 것과 같다
+        let norm = pooled.iter().map(|v| v * v).sum::<f32>().sqrt();
+        if norm > 0.0 {
+            pooled.iter_mut().for_each(|v| *v /= norm);
+        }
+        Ok(pooled)
+    }
+}
+
+// 정규화된 벡터이므로 내적 = 코사인 유사도
+fn cosine(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn encode_vector(vector: &[f32]) -> Vec<u8> {
+    vector.iter().flat_map(|v| v.to_le_bytes()).collect()
+}
+
+fn decode_vector(bytes: &[u8]) -> Vec<f32> {
+    bytes
+        .chunks_exact(4)
+        .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect()
+}
+
+// 임베딩 액터 (ml 기능)
+// 모델 추론은 spawn_blocking에서 실행하고, 벡터는 저장소에 보관해 재시작 후에도 재계산하지 않는다.
+pub struct EmbeddingActor {
+    model: Option<Arc<EmbeddingModel>>,
+    storage: Address<StorageActor>,
+    data: Address<DataManagerActor>,
+    index: HashMap<String, IndexEntry>,
+    vectors: HashMap<String, Vec<f32>>,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for EmbeddingActor {}
+
+impl EmbeddingActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        storage: Address<StorageActor>,
+        data: Address<DataManagerActor>,
+        config: EmbeddingConfig,
+    ) -> Self {
+        let mut owned_tasks = route_dart_signals!(
+            self_addr,
+            [IndexItemsForSearchRequest, SemanticSearchRequest]
+        );
+        owned_tasks.spawn(Self::load_model(self_addr.clone(), config));
+        owned_tasks.spawn(Self::load_index(self_addr.clone()));
+
+        Self {
+            model: None,
+            storage,
+            data,
+            index: HashMap::new(),
+            vectors: HashMap::new(),
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    async fn load_model(mut self_addr: Address<Self>, config: EmbeddingConfig) {
+        let (Some(model_path), Some(vocab_path)) = (config.model_path, config.vocab_path) else {
+            debug_print!("Embedding model not configured, semantic search disabled");
+            return;
+        };
+
+        let loaded = tokio::task::spawn_blocking(move || {
+            EmbeddingModel::load(&model_path, &vocab_path, config.max_seq_len)
+        })
+        .await;
+        match loaded {
+            Ok(Ok(model)) => {
+                let _ = self_addr.notify(ModelLoaded(Arc::new(model))).await;
+            }
+            Ok(Err(e)) => debug_print!("Failed to load embedding model: {}", e),
+            Err(e) => debug_print!("Failed to load embedding model: {}", e),
+        }
+    }
+
+    async fn load_index(mut self_addr: Address<Self>) {
+        let _ = self_addr.notify(LoadIndex).await;
+    }
+
+    async fn embed(&self, text: String) -> Result<Vec<f32>, EmbeddingError> {
+        let model = self.model.clone().ok_or("Embedding model is not loaded")?;
+        tokio::task::spawn_blocking(move || model.embed(&text)).await?
+    }
+
+    async fn store(&mut self, key: String, data: Vec<u8>) -> Result<(), EmbeddingError> {
+        self.storage
+            .send(StoreData {
+                key,
+                data,
+                user_id: None,
+                ttl: None,
+            })
+            .await??;
+        Ok(())
+    }
+
+    async fn fetch(&mut self, key: String) -> Option<Arc<[u8]>> {
+        let request = FetchData {
+            key,
+            user_id: None,
+            mode: FetchMode::CacheFirst,
+        };
+        match self.storage.send(request).await {
+            Ok(Ok(bytes)) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    async fn index_item(&mut self, item: DataItem) -> Result<(), EmbeddingError> {
+        let vector = self
+            .embed(format!("{}\n{}", item.title, item.content))
+            .await?;
+        self.store(vector_key(&item.id), encode_vector(&vector))
+            .await?;
+        self.vectors.insert(item.id.clone(), vector);
+        self.index.insert(
+            item.id,
+            IndexEntry {
+                title: item.title,
+                updated_at: item.updated_at,
+            },
+        );
+        Ok(())
+    }
+}
+
+// 내부 메시지 정의
+struct ModelLoaded(Arc<EmbeddingModel>);
+struct LoadIndex;
+
+#[async_trait]
+impl Notifiable<ModelLoaded> for EmbeddingActor {
+    async fn notify(&mut self, msg: ModelLoaded, _: &Context<Self>) {
+        debug_print!("Embedding model loaded");
+        self.model = Some(msg.0);
+    }
+}
+
+#[async_trait]
+impl Notifiable<LoadIndex> for EmbeddingActor {
+    async fn notify(&mut self, _: LoadIndex, _: &Context<Self>) {
+        let Some(bytes) = self.fetch(INDEX_KEY.to_string()).await else {
+            return;
+        };
+        let index: HashMap<String, IndexEntry> = match serde_json::from_slice(&bytes) {
+            Ok(index) => index,
+            Err(e) => {
+                debug_print!("Invalid embedding index: {}", e);
+                return;
+            }
+        };
+
+        for (item_id, entry) in index {
+            // 벡터가 없는 항목은 다음 색인 때 다시 계산되도록 건너뛴다
+            if let Some(bytes) = self.fetch(vector_key(&item_id)).await {
+                self.vectors.insert(item_id.clone(), decode_vector(&bytes));
+                self.index.insert(item_id, entry);
+            }
+        }
+        debug_print!("Loaded {} embeddings", self.index.len());
+    }
+}
+
+#[async_trait]
+impl Handler<EmbedText> for EmbeddingActor {
+    type Result = Result<Vec<f32>, EmbeddingError>;
+
+    async fn handle(&mut self, msg: EmbedText, _: &Context<Self>) -> Self::Result {
+        self.embed(msg.text).await
+    }
+}
+
+#[async_trait]
+impl Handler<IndexItems> for EmbeddingActor {
+    type Result = Result<usize, EmbeddingError>;
+
+    async fn handle(&mut self, msg: IndexItems, _: &Context<Self>) -> Self::Result {
+        let mut indexed = 0;
+        for item in msg.items {
+            let up_to_date = self
+                .index
+                .get(&item.id)
+                .is_some_and(|entry| entry.updated_at >= item.updated_at);
+            if up_to_date {
+                continue;
+            }
+            self.index_item(item).await?;
+            indexed += 1;
+        }
+
+        if indexed > 0 {
+            let index = serde_json::to_vec(&self.index)?;
+            self.store(INDEX_KEY.to_string(), index).await?;
+        }
+        Ok(indexed)
+    }
+}
+
+#[async_trait]
+impl Handler<SemanticSearch> for EmbeddingActor {
+    type Result = Result<Vec<SemanticMatch>, EmbeddingError>;
+
+    async fn handle(&mut self, msg: SemanticSearch, _: &Context<Self>) -> Self::Result {
+        let query = self.embed(msg.query).await?;
+        let mut matches: Vec<SemanticMatch> = self
+            .vectors
+            .iter()
+            .filter_map(|(item_id, vector)| {
+                let entry = self.index.get(item_id)?;
+                Some(SemanticMatch {
+                    item_id: item_id.clone(),
+                    title: entry.title.clone(),
+                    score: cosine(&query, vector),
+                })
+            })
+            .collect();
+
+        matches.sort_by(|a, b| b.score.total_cmp(&a.score));
+        matches.truncate(msg.limit);
+        Ok(matches)
+    }
+}
+
+#[async_trait]
+impl Handler<WipeUserData> for EmbeddingActor {
+    type Result = Result<(), EmbeddingError>;
+
+    async fn handle(&mut self, _: WipeUserData, _: &Context<Self>) -> Self::Result {
+        self.index.clear();
+        self.vectors.clear();
+        Ok(())
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<IndexItemsForSearchRequest> for EmbeddingActor {
+    async fn notify(&mut self, msg: IndexItemsForSearchRequest, ctx: &Context<Self>) {
+        let candidates = self
+            .data
+            .send(FetchRecentData {
+                user_id: msg.user_id,
+                limit: Some(MAX_INDEX_CANDIDATES),
+                mode: FetchMode::CacheFirst,
+            })
+            .await;
+        let items = match candidates {
+            Ok(Ok(user_data)) => user_data.items,
+            Ok(Err(e)) => return send_index_error(e.to_string()),
+            Err(e) => return send_index_error(e.to_string()),
+        };
+
+        // 배치 크기는 성능 거버너가 정하고, 부하가 있으면 배치 사이에 잠깐 쉰다
+        let total = items.len();
+        let mut indexed = 0;
+        let mut remaining = items;
+        while !remaining.is_empty() {
+            let level = performance_level();
+            let rest = remaining.split_off(level.index_batch_size().min(remaining.len()));
+            match self.handle(IndexItems { items: remaining }, ctx).await {
+                Ok(count) => indexed += count,
+                Err(e) => return send_index_error(e.to_string()),
+            }
+            remaining = rest;
+            if level != PerformanceLevel::Full && !remaining.is_empty() {
+                tokio::time::sleep(Duration::from_millis(INDEX_BATCH_PAUSE_MS)).await;
+            }
+        }
+        SemanticIndexSignal {
+            indexed: indexed as u64,
+            total: total as u64,
+            error: None,
+        }
+        .emit();
+    }
+}
+
+#[async_trait]
+impl Notifiable<SemanticSearchRequest> for EmbeddingActor {
+    async fn notify(&mut self, msg: SemanticSearchRequest, ctx: &Context<Self>) {
+        let result = self
+            .handle(
+                SemanticSearch {
+                    query: msg.query,
+                    limit: msg
+                        .limit
+                        .map_or(DEFAULT_SEARCH_LIMIT, |limit| limit as usize),
+                },
+                ctx,
+            )
+            .await;
+
+        let matches = match result {
+            Ok(matches) => matches,
+            Err(e) => {
+                SemanticSearchResultSignal {
+                    request_id: msg.request_id,
+                    matches: vec![],
+                    done: true,
+                    error: Some(e.to_string()),
+                }
+                .emit();
+                return;
+            }
+        };
+
+        // 점수 높은 결과부터 조각으로 나누어 먼저 표시할 수 있게 한다
+        let chunk_count = matches.len().div_ceil(STREAM_CHUNK_SIZE).max(1);
+        let mut chunks = matches.chunks(STREAM_CHUNK_SIZE);
+        for index in 0..chunk_count {
+            SemanticSearchResultSignal {
+                request_id: msg.request_id.clone(),
+                matches: chunks.next().map(<[_]>::to_vec).unwrap_or_default(),
+                done: index + 1 == chunk_count,
+                error: None,
+            }
+            .emit();
+        }
+    }
+}
+
+fn send_index_error(error: String) {
+    SemanticIndexSignal {
+        indexed: 0,
+        total: 0,
+        error: Some(error),
+    }
+    .emit();
+}
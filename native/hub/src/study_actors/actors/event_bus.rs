@@ -0,0 +1,34 @@
+use tokio::sync::broadcast;
+
+use crate::study_actors::messages::AppEvent;
+
+const EVENT_BUS_CAPACITY: usize = 256;
+
+// 앱 전역 이벤트 버스
+// 발행 액터는 구독자를 몰라도 되고, 자동화 등 구독 액터는 생성 시 수신기를 받는다.
+#[derive(Clone)]
+pub struct EventBus {
+    sender: broadcast::Sender<AppEvent>,
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(EVENT_BUS_CAPACITY);
+        Self { sender }
+    }
+
+    // 구독자가 없으면 이벤트는 버려진다
+    pub fn publish(&self, event: AppEvent) {
+        let _ = self.sender.send(event);
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<AppEvent> {
+        self.sender.subscribe()
+    }
+}
@@ -0,0 +1,448 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use std::cmp::Reverse;
+use tokio::{sync::broadcast, task::JoinSet};
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        AppEvent, FeedAction, FeedEntry, FeedError, FeedPage, FeedSource, FetchData, FetchFeedPage,
+        FetchMode, IdKind, StoreData, UserId, WipeUserData,
+    },
+    signals::{EmitSignal, FeedEntrySignal, FeedPageRequest, FeedPageSignal, route_dart_signals},
+};
+
+use super::{StorageActor, TrustedClock, generate_id};
+
+const FEED_KEY: &str = "feed/entries";
+const MAX_ENTRIES: usize = 1_000;
+// 이 간격 안에 잇따른 같은 종류의 활동은 한 줄로 묶는다
+const GROUP_WINDOW_SECS: u64 = 10 * 60;
+// 묶인 줄이 기억하는 항목 수 (넘으면 count만 늘어난다)
+const MAX_GROUPED_ITEMS: usize = 50;
+
+// 이벤트 하나에서 나온 활동
+struct Activity {
+    source: FeedSource,
+    action: FeedAction,
+    user_id: Option<UserId>,
+    item_id: Option<String>,
+    notice_id: Option<String>,
+    title: String,
+    body: Option<String>,
+}
+
+impl Activity {
+    fn local(action: FeedAction, user_id: UserId, item_id: String, title: String) -> Self {
+        Self {
+            source: FeedSource::Local,
+            action,
+            user_id: Some(user_id),
+            item_id: Some(item_id),
+            notice_id: None,
+            title,
+            body: None,
+        }
+    }
+
+    fn from_event(event: AppEvent) -> Vec<Self> {
+        match event {
+            AppEvent::DataItemCreated { user_id, item, .. } => {
+                vec![Self::local(
+                    FeedAction::Created,
+                    user_id,
+                    item.id,
+                    item.title,
+                )]
+            }
+            AppEvent::DataItemUpdated { user_id, item, .. } => {
+                vec![Self::local(
+                    FeedAction::Updated,
+                    user_id,
+                    item.id,
+                    item.title,
+                )]
+            }
+            AppEvent::DataItemDeleted { user_id, item_id } => {
+                vec![Self::local(
+                    FeedAction::Deleted,
+                    user_id,
+                    item_id,
+                    String::new(),
+                )]
+            }
+            // 처음 받은 항목은 생성, 이미 있던 항목은 수정으로 본다
+            AppEvent::DataItemsSynced { user_id, items } => items
+                .into_iter()
+                .map(|item| Self {
+                    source: FeedSource::Sync,
+                    action: if item.created_at == item.updated_at {
+                        FeedAction::Created
+                    } else {
+                        FeedAction::Updated
+                    },
+                    user_id: Some(user_id.clone()),
+                    item_id: Some(item.id),
+                    notice_id: None,
+                    title: item.title,
+                    body: None,
+                })
+                .collect(),
+            AppEvent::SystemNotice { id, title, body } => vec![Self {
+                source: FeedSource::System,
+                action: FeedAction::Notice,
+                user_id: None,
+                item_id: None,
+                notice_id: id,
+                title,
+                body: Some(body),
+            }],
+            AppEvent::ConnectivityChanged { .. }
+            | AppEvent::AdminCommandReceived(_)
+            | AppEvent::EntitlementsChanged { .. }
+            | AppEvent::RemoteWipeRequested { .. }
+            | AppEvent::ConfigChanged { .. } => Vec::new(),
+        }
+    }
+
+    // 이미 있는 줄에 묶을 수 있는지
+    // 동기화로 받은 활동은 항목이 달라도 묶고, 이 기기의 활동은 같은 항목끼리만 묶는다.
+    // 방금 만든 항목을 고친 것은 생성 줄에 합친다.
+    fn groups_with(&self, entry: &FeedEntry) -> bool {
+        if self.action == FeedAction::Notice
+            || entry.source != self.source
+            || entry.user_id != self.user_id
+        {
+            return false;
+        }
+        let same_item = self
+            .item_id
+            .as_ref()
+            .is_some_and(|id| entry.item_ids.contains(id));
+        match self.source {
+            FeedSource::Sync => entry.action == self.action,
+            _ => {
+                same_item
+                    && (entry.action == self.action
+                        || (entry.action == FeedAction::Created
+                            && self.action == FeedAction::Updated))
+            }
+        }
+    }
+}
+
+// 활동을 피드에 넣고 바뀐 줄의 id를 돌려준다 (중복이면 None)
+fn record(entries: &mut Vec<FeedEntry>, activity: Activity, now: u64) -> Option<String> {
+    if let Some(notice_id) = &activity.notice_id
+        && entries.iter().any(|entry| &entry.id == notice_id)
+    {
+        return None;
+    }
+
+    let since = now.saturating_sub(GROUP_WINDOW_SECS);
+    let grouped = entries
+        .iter()
+        .take_while(|entry| entry.last_at >= since)
+        .position(|entry| activity.groups_with(entry));
+    let mut entry = match grouped {
+        Some(index) => {
+            let mut entry = entries.remove(index);
+            match activity.item_id {
+                Some(item_id) if !entry.item_ids.contains(&item_id) => {
+                    if entry.item_ids.len() < MAX_GROUPED_ITEMS {
+                        entry.item_ids.push(item_id);
+                    }
+                    entry.count += 1;
+                }
+                // 같은 항목을 동기화로 다시 받은 것은 세지 않는다
+                Some(_) if activity.source == FeedSource::Sync => {}
+                _ => entry.count += 1,
+            }
+            entry
+        }
+        None => FeedEntry {
+            id: activity
+                .notice_id
+                .unwrap_or_else(|| generate_id(IdKind::Ulid)),
+            source: activity.source,
+            action: activity.action,
+            user_id: activity.user_id,
+            item_ids: activity.item_id.into_iter().collect(),
+            title: String::new(),
+            body: None,
+            count: 1,
+            first_at: now,
+            last_at: now,
+        },
+    };
+    // 삭제된 항목은 제목이 없어 앞서 알던 제목을 남긴다
+    if !activity.title.is_empty() {
+        entry.title = activity.title;
+    }
+    if activity.body.is_some() {
+        entry.body = activity.body;
+    }
+    entry.last_at = now;
+    let id = entry.id.clone();
+    entries.insert(0, entry);
+    entries.truncate(MAX_ENTRIES);
+    Some(id)
+}
+
+// 활동 피드 액터
+// 이 기기의 항목 변경, 동기화로 받은 다른 기기의 변경, 시스템 공지를 시간 순의 한 목록으로 모은다.
+// 같은 공지가 다시 오면 버리고, 짧은 시간의 잇따른 변경은 한 줄로 묶어 저장소에 보관한다.
+pub struct FeedActor {
+    entries: Vec<FeedEntry>, // 최신 순
+    storage: Address<StorageActor>,
+    clock: TrustedClock,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for FeedActor {}
+
+impl FeedActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        storage: Address<StorageActor>,
+        events: broadcast::Receiver<AppEvent>,
+        clock: TrustedClock,
+    ) -> Self {
+        let mut owned_tasks = route_dart_signals!(self_addr, [FeedPageRequest]);
+        owned_tasks.spawn(Self::load_feed(self_addr.clone()));
+        owned_tasks.spawn(Self::listen_to_events(self_addr, events));
+
+        Self {
+            entries: Vec::new(),
+            storage,
+            clock,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    async fn load_feed(mut self_addr: Address<Self>) {
+        let _ = self_addr.notify(LoadFeed).await;
+    }
+
+    async fn listen_to_events(
+        mut self_addr: Address<Self>,
+        mut events: broadcast::Receiver<AppEvent>,
+    ) {
+        loop {
+            match events.recv().await {
+                Ok(event) => {
+                    let _ = self_addr.notify(AppEventReceived(event)).await;
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    debug_print!("Feed actor skipped {} app events", skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    }
+
+    async fn persist(&mut self) -> Result<(), FeedError> {
+        let request = StoreData {
+            key: FEED_KEY.to_string(),
+            data: serde_json::to_vec(&self.entries)?,
+            user_id: None,
+            ttl: None,
+        };
+        self.storage.send(request).await??;
+        Ok(())
+    }
+
+    fn page(&self, msg: &FetchFeedPage) -> FeedPage {
+        let visible: Vec<&FeedEntry> = self
+            .entries
+            .iter()
+            .filter(|entry| {
+                msg.user_id.is_none() || entry.user_id.is_none() || entry.user_id == msg.user_id
+            })
+            .collect();
+        FeedPage {
+            entries: visible
+                .iter()
+                .skip(msg.offset)
+                .take(msg.limit)
+                .map(|entry| (*entry).clone())
+                .collect(),
+            total: visible.len(),
+        }
+    }
+}
+
+// 내부 메시지 정의
+struct LoadFeed;
+struct AppEventReceived(AppEvent);
+
+#[async_trait]
+impl Notifiable<LoadFeed> for FeedActor {
+    async fn notify(&mut self, _: LoadFeed, _: &Context<Self>) {
+        let request = FetchData {
+            key: FEED_KEY.to_string(),
+            user_id: None,
+            mode: FetchMode::CacheFirst,
+        };
+        let stored = match self.storage.send(request).await {
+            Ok(Ok(bytes)) => serde_json::from_slice::<Vec<FeedEntry>>(&bytes).unwrap_or_else(|e| {
+                debug_print!("Invalid stored feed: {}", e);
+                Vec::new()
+            }),
+            // 저장된 피드가 없으면 빈 피드
+            Ok(Err(_)) => Vec::new(),
+            Err(e) => {
+                debug_print!("Failed to load feed: {}", e);
+                Vec::new()
+            }
+        };
+
+        // 불러오기 전에 들어온 활동이 우선
+        for entry in stored {
+            if !self.entries.iter().any(|current| current.id == entry.id) {
+                self.entries.push(entry);
+            }
+        }
+        self.entries.sort_by_key(|entry| Reverse(entry.last_at));
+        self.entries.truncate(MAX_ENTRIES);
+    }
+}
+
+#[async_trait]
+impl Notifiable<AppEventReceived> for FeedActor {
+    async fn notify(&mut self, msg: AppEventReceived, _: &Context<Self>) {
+        let now = self.clock.trusted_now();
+        let mut changed: Vec<String> = Vec::new();
+        for activity in Activity::from_event(msg.0) {
+            if let Some(id) = record(&mut self.entries, activity, now)
+                && !changed.contains(&id)
+            {
+                changed.push(id);
+            }
+        }
+        if changed.is_empty() {
+            return;
+        }
+        if let Err(e) = self.persist().await {
+            debug_print!("Failed to save feed: {}", e);
+        }
+
+        // 오래된 줄부터 보내 Dart가 받은 순서대로 맨 위에 올리면 되게 한다
+        for entry in self.entries.iter().rev() {
+            if changed.contains(&entry.id) {
+                FeedEntrySignal {
+                    entry: entry.clone(),
+                }
+                .emit();
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<FetchFeedPage> for FeedActor {
+    type Result = FeedPage;
+
+    async fn handle(&mut self, msg: FetchFeedPage, _: &Context<Self>) -> Self::Result {
+        self.page(&msg)
+    }
+}
+
+#[async_trait]
+impl Handler<WipeUserData> for FeedActor {
+    type Result = Result<(), FeedError>;
+
+    async fn handle(&mut self, _: WipeUserData, _: &Context<Self>) -> Self::Result {
+        // 저장된 피드는 저장소 삭제 단계에서 함께 지워진다
+        self.entries.clear();
+        Ok(())
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<FeedPageRequest> for FeedActor {
+    async fn notify(&mut self, msg: FeedPageRequest, _: &Context<Self>) {
+        let request = FetchFeedPage {
+            user_id: msg.user_id,
+            offset: msg.offset as usize,
+            limit: msg.limit as usize,
+        };
+        let page = self.page(&request);
+        FeedPageSignal {
+            user_id: request.user_id,
+            offset: request.offset as u64,
+            entries: page.entries,
+            total: page.total as u64,
+        }
+        .emit();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edit(action: FeedAction, item_id: &str, title: &str) -> Activity {
+        Activity::local(
+            action,
+            "user".to_string(),
+            item_id.to_string(),
+            title.to_string(),
+        )
+    }
+
+    fn synced(item_id: &str) -> Activity {
+        Activity {
+            source: FeedSource::Sync,
+            ..edit(FeedAction::Updated, item_id, item_id)
+        }
+    }
+
+    #[test]
+    fn edits_of_a_new_item_fold_into_its_created_entry() {
+        let mut entries = Vec::new();
+        let created = record(&mut entries, edit(FeedAction::Created, "a", "Draft"), 100);
+        let updated = record(&mut entries, edit(FeedAction::Updated, "a", "Final"), 200);
+        assert_eq!(created, updated);
+        assert_eq!(entries.len(), 1);
+        assert_eq!((entries[0].count, entries[0].title.as_str()), (2, "Final"));
+
+        // 묶는 간격이 지나면 새 줄
+        record(
+            &mut entries,
+            edit(FeedAction::Updated, "a", "Final"),
+            200 + GROUP_WINDOW_SECS + 1,
+        );
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].action, FeedAction::Updated);
+    }
+
+    #[test]
+    fn synced_items_group_and_repeated_notices_are_dropped() {
+        let mut entries = Vec::new();
+        for item_id in ["a", "b", "a"] {
+            record(&mut entries, synced(item_id), 100);
+        }
+        record(&mut entries, edit(FeedAction::Updated, "c", "Local"), 100);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].item_ids, ["a", "b"]);
+        assert_eq!(entries[1].count, 2);
+
+        let notice = || Activity {
+            source: FeedSource::System,
+            action: FeedAction::Notice,
+            user_id: None,
+            item_id: None,
+            notice_id: Some("maintenance".to_string()),
+            title: "Maintenance".to_string(),
+            body: Some("Tonight".to_string()),
+        };
+        assert!(record(&mut entries, notice(), 110).is_some());
+        assert!(record(&mut entries, notice(), 120).is_none());
+        assert_eq!(entries.len(), 3);
+    }
+}
@@ -0,0 +1,830 @@
+use async_trait::async_trait;
+use feed_rs::model::Entry;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use regex::Regex;
+use reqwest::{
+    StatusCode, Url,
+    header::{ETAG, LAST_MODIFIED},
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+    sync::LazyLock,
+    time::Duration,
+};
+use tokio::task::JoinSet;
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        ActorResult, DeleteData, FeedArticle, FeedReaderConfig, FeedReaderError, FeedSubscription,
+        FetchData, FetchMode, IdKind, ListFeedArticles, ScanPrefix, ScheduledTask, StoreData,
+        SubscribeFeed, TrafficClass, UnsubscribeFeed, UserError, WipeUserData,
+    },
+    signals::{
+        EmitSignal, FeedArticlesRequest, FeedArticlesSignal, FeedSubscriptionsRequest,
+        FeedSubscriptionsSignal, NewFeedArticlesSignal, RefreshFeedsRequest, SubscribeFeedRequest,
+        UnsubscribeFeedRequest, respond_to_dart, route_dart_signals,
+    },
+};
+
+use super::link_preview::{clean_text, resolve, truncate_chars};
+use super::network::NetworkRequest;
+use super::{
+    Clock, NetworkManagerActor, Priority, PriorityMailbox, StorageActor, TrustedClock, generate_id,
+};
+
+const SUBSCRIPTIONS_KEY: &str = "feed_reader/subscriptions";
+const ARTICLES_KEY_PREFIX: &str = "feed_reader/articles/";
+// 구독마다 확인할 때가 됐는지 보는 간격
+const POLL_CHECK_SECS: u64 = 5 * 60;
+const MAX_SUMMARY_CHARS: usize = 500;
+const FEED_ACCEPT: &str = "application/rss+xml, application/atom+xml, application/feed+json, \
+                           application/xml;q=0.9, text/xml;q=0.9, */*;q=0.8";
+
+static HTML_TAG: LazyLock<Option<Regex>> = LazyLock::new(|| Regex::new(r"(?s)<[^>]*>").ok());
+
+// 저장하는 구독 (조건부 요청에 쓰는 검증자와 다음 확인 시각 포함)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StoredSubscription {
+    info: FeedSubscription,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    next_poll_at: u64,
+    failures: u32, // 잇따른 실패 수 (확인 간격을 늘린다)
+}
+
+// 확인할 구독
+struct PollTarget {
+    subscription_id: String,
+    url: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+}
+
+struct ParsedFeed {
+    title: Option<String>,
+    site_url: Option<String>,
+    articles: Vec<FeedArticle>,
+}
+
+enum FetchedFeed {
+    NotModified,
+    Updated {
+        feed: ParsedFeed,
+        etag: Option<String>,
+        last_modified: Option<String>,
+    },
+}
+
+fn articles_key(subscription_id: &str) -> String {
+    format!("{}{}", ARTICLES_KEY_PREFIX, subscription_id)
+}
+
+// 같은 피드를 두 번 구독하지 않도록 조각을 뗀 주소로 비교한다
+fn feed_url(url: &str) -> ActorResult<Url> {
+    let mut parsed = Url::parse(url.trim())
+        .map_err(|e| UserError::InvalidInput(format!("Invalid feed URL {}: {}", url, e)))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(UserError::InvalidInput(format!(
+            "Only http(s) feeds can be subscribed: {}",
+            url
+        )));
+    }
+    parsed.set_fragment(None);
+    Ok(parsed)
+}
+
+// 글이 발행 시각을 알리지 않으면 받은 시각으로 정렬한다
+fn article_time(article: &FeedArticle) -> u64 {
+    article.published_at.unwrap_or(article.fetched_at)
+}
+
+// 실패할 때마다 확인 간격을 두 배로 (상한까지)
+fn backoff_secs(config: &FeedReaderConfig, failures: u32) -> u64 {
+    config
+        .poll_interval_secs
+        .saturating_mul(1 << failures.min(16))
+        .min(config.max_backoff_secs)
+}
+
+// HTML 요약을 태그 없는 짧은 텍스트로
+fn plain_summary(html: &str) -> Option<String> {
+    let text = match &*HTML_TAG {
+        Some(tag) => clean_text(&tag.replace_all(html, " ")),
+        None => clean_text(html),
+    };
+    (!text.is_empty()).then(|| truncate_chars(text, MAX_SUMMARY_CHARS))
+}
+
+fn article_from_entry(
+    entry: Entry,
+    subscription_id: &str,
+    base: &Url,
+    fetched_at: u64,
+) -> FeedArticle {
+    let url = entry
+        .links
+        .iter()
+        .find(|link| link.rel.as_deref().is_none_or(|rel| rel == "alternate"))
+        .or(entry.links.first())
+        .and_then(|link| resolve(base, &link.href));
+    let summary = entry
+        .summary
+        .map(|summary| summary.content)
+        .or_else(|| entry.content.and_then(|content| content.body))
+        .and_then(|html| plain_summary(&html));
+    FeedArticle {
+        id: entry.id,
+        subscription_id: subscription_id.to_string(),
+        title: entry
+            .title
+            .map(|title| clean_text(&title.content))
+            .filter(|title| !title.is_empty()),
+        url,
+        summary,
+        author: entry.authors.into_iter().next().map(|person| person.name),
+        published_at: entry
+            .published
+            .or(entry.updated)
+            .map(|at| at.timestamp().max(0) as u64),
+        fetched_at,
+    }
+}
+
+// feed-rs가 RSS 0.9~2.0, Atom 1.0, JSON Feed를 모두 읽는다 (id가 없는 글은 링크와 제목으로 만든다)
+fn parse_feed(
+    body: &[u8],
+    base: &Url,
+    subscription_id: &str,
+    fetched_at: u64,
+) -> ActorResult<ParsedFeed> {
+    let feed = feed_rs::parser::parse(body).map_err(|e| {
+        UserError::InvalidInput(format!("Not a valid RSS, Atom or JSON feed: {}", e))
+    })?;
+    let site_url = feed
+        .links
+        .iter()
+        .find(|link| link.rel.as_deref() != Some("self"))
+        .and_then(|link| resolve(base, &link.href));
+    let title = feed
+        .title
+        .map(|title| clean_text(&title.content))
+        .filter(|title| !title.is_empty());
+    let articles = feed
+        .entries
+        .into_iter()
+        .map(|entry| article_from_entry(entry, subscription_id, base, fetched_at))
+        .collect();
+    Ok(ParsedFeed {
+        title,
+        site_url,
+        articles,
+    })
+}
+
+// 받아 둔 글과 id가 겹치지 않는 글을 더하고 최신 순으로 상한까지 남긴다
+// 돌려주는 값은 남은 새 글이다 (상한 밖으로 밀려난 오래된 글은 새 글로 알리지 않는다).
+fn merge_articles(
+    existing: &mut Vec<FeedArticle>,
+    fetched: Vec<FeedArticle>,
+    max_articles: usize,
+) -> Vec<FeedArticle> {
+    let mut seen: HashSet<String> = existing.iter().map(|article| article.id.clone()).collect();
+    let mut fresh: Vec<FeedArticle> = fetched
+        .into_iter()
+        .filter(|article| seen.insert(article.id.clone()))
+        .collect();
+    existing.extend(fresh.iter().cloned());
+    existing.sort_by_key(|article| Reverse(article_time(article)));
+    existing.truncate(max_articles);
+
+    let kept: HashSet<&str> = existing.iter().map(|article| article.id.as_str()).collect();
+    fresh.retain(|article| kept.contains(article.id.as_str()));
+    fresh
+}
+
+// 검증자가 있으면 조건부 요청으로 보내 바뀌지 않은 피드는 본문 없이 304로 받는다
+async fn fetch_feed(
+    network_manager: &PriorityMailbox<NetworkManagerActor>,
+    config: &FeedReaderConfig,
+    target: &PollTarget,
+    (priority, traffic): (Priority, TrafficClass),
+    fetched_at: u64,
+) -> ActorResult<FetchedFeed> {
+    let base = feed_url(&target.url)?;
+    let mut request = NetworkRequest::new(target.url.as_str())
+        .header("Accept", FEED_ACCEPT)
+        .timeout(config.fetch_timeout_ms)
+        .max_body(config.max_feed_bytes)
+        .traffic(traffic);
+    if let Some(etag) = &target.etag {
+        request = request.header("If-None-Match", etag);
+    }
+    if let Some(last_modified) = &target.last_modified {
+        request = request.header("If-Modified-Since", last_modified);
+    }
+
+    let response = network_manager
+        .request(priority, request)
+        .await
+        .map_err(|e| UserError::Upstream(e.to_string()))??;
+    if let Some(error) = response.error {
+        return Err(UserError::Upstream(error));
+    }
+    if response.status == StatusCode::NOT_MODIFIED {
+        return Ok(FetchedFeed::NotModified);
+    }
+    if !response.is_success() {
+        return Err(UserError::Upstream(format!(
+            "Feed fetch failed: {}",
+            response.status
+        )));
+    }
+    if response.body.len() >= config.max_feed_bytes {
+        return Err(UserError::InvalidInput(format!(
+            "Feed is larger than {} bytes",
+            config.max_feed_bytes
+        )));
+    }
+
+    let header = |name| {
+        response
+            .headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+    };
+    Ok(FetchedFeed::Updated {
+        feed: parse_feed(&response.body, &base, &target.subscription_id, fetched_at)?,
+        etag: header(ETAG),
+        last_modified: header(LAST_MODIFIED),
+    })
+}
+
+// 피드 구독 액터
+// 구독한 RSS/Atom/JSON Feed를 구독마다 정한 간격으로 확인해 새 글을 저장소에 쌓고 Dart로 보낸다.
+// 확인은 액터 밖의 작업에서 한 피드씩 하고 결과만 메시지로 돌려받으므로 확인 중에도 목록 요청에 답한다.
+// 실패가 이어지는 피드는 확인 간격을 늘리고, 데이터 절약 모드에서는 예약된 확인을 미룬다.
+pub struct FeedReaderActor {
+    config: FeedReaderConfig,
+    storage: Address<StorageActor>,
+    network_manager: PriorityMailbox<NetworkManagerActor>,
+    clock: TrustedClock,
+    subscriptions: Vec<StoredSubscription>,
+    articles: HashMap<String, Vec<FeedArticle>>,
+    loaded: bool,
+    polling: bool,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for FeedReaderActor {}
+
+impl FeedReaderActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        config: FeedReaderConfig,
+        storage: Address<StorageActor>,
+        network_manager: PriorityMailbox<NetworkManagerActor>,
+        clock: TrustedClock,
+    ) -> Self {
+        let mut owned_tasks = route_dart_signals!(
+            self_addr,
+            [
+                SubscribeFeedRequest,
+                UnsubscribeFeedRequest,
+                FeedSubscriptionsRequest,
+                RefreshFeedsRequest,
+                FeedArticlesRequest,
+            ]
+        );
+        owned_tasks.spawn(Self::load_subscriptions(self_addr.clone()));
+        owned_tasks.spawn(Self::poll_schedule(self_addr, clock.clock().clone()));
+
+        Self {
+            config,
+            storage,
+            network_manager,
+            clock,
+            subscriptions: Vec::new(),
+            articles: HashMap::new(),
+            loaded: false,
+            polling: false,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    async fn load_subscriptions(mut self_addr: Address<Self>) {
+        let _ = self_addr.notify(LoadSubscriptions).await;
+    }
+
+    async fn poll_schedule(mut self_addr: Address<Self>, clock: Clock) {
+        let mut interval = clock
+            .interval(Duration::from_secs(POLL_CHECK_SECS))
+            .throttled(ScheduledTask::FeedPoll);
+        loop {
+            interval.tick().await;
+            let _ = self_addr.notify(PollDueFeeds { force: false }).await;
+        }
+    }
+
+    // 예약된 확인은 뒤 차선과 비필수 트래픽으로, 당겨서 새로 고침은 화면이 기다리는 요청으로 보낸다
+    async fn poll_feeds(
+        mut self_addr: Address<Self>,
+        network_manager: PriorityMailbox<NetworkManagerActor>,
+        config: FeedReaderConfig,
+        clock: TrustedClock,
+        targets: Vec<PollTarget>,
+        force: bool,
+    ) {
+        let lane = if force {
+            (Priority::High, TrafficClass::Other)
+        } else {
+            (Priority::Low, TrafficClass::Prefetch)
+        };
+        for target in targets {
+            let result = fetch_feed(
+                &network_manager,
+                &config,
+                &target,
+                lane,
+                clock.trusted_now(),
+            )
+            .await;
+            let polled = FeedPolled {
+                subscription_id: target.subscription_id,
+                result,
+            };
+            let _ = self_addr.notify(polled).await;
+        }
+        let _ = self_addr.notify(PollFinished).await;
+    }
+
+    async fn persist_subscriptions(&mut self) -> ActorResult<()> {
+        let request = StoreData {
+            key: SUBSCRIPTIONS_KEY.to_string(),
+            data: serde_json::to_vec(&self.subscriptions)?,
+            user_id: None,
+            ttl: None,
+        };
+        self.storage.send(request).await??;
+        Ok(())
+    }
+
+    async fn persist_articles(&mut self, subscription_id: &str) -> ActorResult<()> {
+        let articles = self
+            .articles
+            .get(subscription_id)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let request = StoreData {
+            key: articles_key(subscription_id),
+            data: serde_json::to_vec(articles)?,
+            user_id: None,
+            ttl: None,
+        };
+        self.storage.send(request).await??;
+        Ok(())
+    }
+
+    fn emit_subscriptions(&self) {
+        FeedSubscriptionsSignal {
+            subscriptions: self
+                .subscriptions
+                .iter()
+                .map(|subscription| subscription.info.clone())
+                .collect(),
+        }
+        .emit();
+    }
+
+    // 확인 결과를 구독에 반영한다 (새 글을 받았으면 true)
+    fn record_poll(&mut self, subscription_id: &str, result: ActorResult<FetchedFeed>) -> bool {
+        let now = self.clock.trusted_now();
+        let Some(subscription) = self
+            .subscriptions
+            .iter_mut()
+            .find(|subscription| subscription.info.id == subscription_id)
+        else {
+            return false;
+        };
+        subscription.info.last_polled_at = Some(now);
+        let fetched = match result {
+            Ok(fetched) => fetched,
+            Err(e) => {
+                debug_print!("Feed {} check failed: {}", subscription.info.url, e);
+                subscription.failures += 1;
+                subscription.info.last_error = Some(e.to_string());
+                subscription.next_poll_at = now + backoff_secs(&self.config, subscription.failures);
+                return false;
+            }
+        };
+        subscription.failures = 0;
+        subscription.info.last_error = None;
+        subscription.next_poll_at = now + self.config.poll_interval_secs;
+
+        let FetchedFeed::Updated {
+            feed,
+            etag,
+            last_modified,
+        } = fetched
+        else {
+            return false;
+        };
+        subscription.etag = etag;
+        subscription.last_modified = last_modified;
+        if feed.title.is_some() {
+            subscription.info.title = feed.title;
+        }
+        if feed.site_url.is_some() {
+            subscription.info.site_url = feed.site_url;
+        }
+
+        let existing = self
+            .articles
+            .entry(subscription_id.to_string())
+            .or_default();
+        let fresh = merge_articles(existing, feed.articles, self.config.max_articles_per_feed);
+        if fresh.is_empty() {
+            return false;
+        }
+        NewFeedArticlesSignal {
+            subscription_id: subscription_id.to_string(),
+            articles: fresh,
+        }
+        .emit();
+        true
+    }
+}
+
+// 내부 메시지 정의
+struct LoadSubscriptions;
+struct PollDueFeeds {
+    force: bool, // 때가 되지 않은 구독도 확인
+}
+struct FeedPolled {
+    subscription_id: String,
+    result: ActorResult<FetchedFeed>,
+}
+struct PollFinished;
+
+#[async_trait]
+impl Notifiable<LoadSubscriptions> for FeedReaderActor {
+    async fn notify(&mut self, _: LoadSubscriptions, ctx: &Context<Self>) {
+        let request = FetchData {
+            key: SUBSCRIPTIONS_KEY.to_string(),
+            user_id: None,
+            mode: FetchMode::CacheFirst,
+        };
+        let stored: Vec<StoredSubscription> = match self.storage.send(request).await {
+            Ok(Ok(bytes)) => serde_json::from_slice(&bytes).unwrap_or_else(|e| {
+                debug_print!("Invalid stored feed subscriptions: {}", e);
+                Vec::new()
+            }),
+            _ => Vec::new(),
+        };
+        let scan = ScanPrefix {
+            prefix: ARTICLES_KEY_PREFIX.to_string(),
+        };
+        let entries = match self.storage.send(scan).await {
+            Ok(Ok(entries)) => entries,
+            Ok(Err(e)) => {
+                debug_print!("Failed to load feed articles: {}", e);
+                Vec::new()
+            }
+            Err(e) => {
+                debug_print!("Failed to load feed articles: {}", e);
+                Vec::new()
+            }
+        };
+        for (key, bytes) in entries {
+            let subscription_id = key.trim_start_matches(ARTICLES_KEY_PREFIX).to_string();
+            match serde_json::from_slice::<Vec<FeedArticle>>(&bytes) {
+                Ok(articles) => {
+                    self.articles.entry(subscription_id).or_insert(articles);
+                }
+                Err(e) => debug_print!("Skipping stored articles for {}: {}", subscription_id, e),
+            }
+        }
+
+        // 불러오기 전에 구독한 피드는 뒤에 둔다
+        let added = std::mem::replace(&mut self.subscriptions, stored);
+        self.subscriptions.extend(added);
+        self.loaded = true;
+        debug_print!("Feed subscriptions loaded: {}", self.subscriptions.len());
+        self.emit_subscriptions();
+        self.notify(PollDueFeeds { force: false }, ctx).await;
+    }
+}
+
+#[async_trait]
+impl Notifiable<PollDueFeeds> for FeedReaderActor {
+    async fn notify(&mut self, msg: PollDueFeeds, ctx: &Context<Self>) {
+        if !self.loaded || self.polling {
+            return;
+        }
+        let now = self.clock.trusted_now();
+        let targets: Vec<PollTarget> = self
+            .subscriptions
+            .iter()
+            .filter(|subscription| msg.force || subscription.next_poll_at <= now)
+            .map(|subscription| PollTarget {
+                subscription_id: subscription.info.id.clone(),
+                url: subscription.info.url.clone(),
+                etag: subscription.etag.clone(),
+                last_modified: subscription.last_modified.clone(),
+            })
+            .collect();
+        if targets.is_empty() {
+            return;
+        }
+
+        self.polling = true;
+        self._owned_tasks.spawn(Self::poll_feeds(
+            ctx.address(),
+            self.network_manager.clone(),
+            self.config.clone(),
+            self.clock.clone(),
+            targets,
+            msg.force,
+        ));
+    }
+}
+
+#[async_trait]
+impl Notifiable<FeedPolled> for FeedReaderActor {
+    async fn notify(&mut self, msg: FeedPolled, _: &Context<Self>) {
+        // 데이터 절약 모드가 풀린 뒤 다음 확인 주기에 다시 시도한다
+        if let Err(UserError::DataSaverDeferred(_)) = &msg.result {
+            return;
+        }
+        let changed = self.record_poll(&msg.subscription_id, msg.result);
+        if changed && let Err(e) = self.persist_articles(&msg.subscription_id).await {
+            debug_print!("Failed to persist feed articles: {}", e);
+        }
+    }
+}
+
+#[async_trait]
+impl Notifiable<PollFinished> for FeedReaderActor {
+    async fn notify(&mut self, _: PollFinished, _: &Context<Self>) {
+        self.polling = false;
+        if let Err(e) = self.persist_subscriptions().await {
+            debug_print!("Failed to persist feed subscriptions: {}", e);
+        }
+        self.emit_subscriptions();
+    }
+}
+
+#[async_trait]
+impl Handler<SubscribeFeed> for FeedReaderActor {
+    type Result = ActorResult<FeedSubscription>;
+
+    async fn handle(&mut self, msg: SubscribeFeed, _: &Context<Self>) -> Self::Result {
+        let url = feed_url(&msg.url)?;
+        if let Some(existing) = self
+            .subscriptions
+            .iter()
+            .find(|subscription| subscription.info.url == url.as_str())
+        {
+            return Ok(existing.info.clone());
+        }
+        if self.subscriptions.len() >= self.config.max_subscriptions {
+            return Err(UserError::InvalidInput(format!(
+                "Too many feed subscriptions (max {})",
+                self.config.max_subscriptions
+            )));
+        }
+
+        let now = self.clock.trusted_now();
+        let target = PollTarget {
+            subscription_id: generate_id(IdKind::Ulid),
+            url: url.to_string(),
+            etag: None,
+            last_modified: None,
+        };
+        let lane = (Priority::High, TrafficClass::Other);
+        let fetched = fetch_feed(&self.network_manager, &self.config, &target, lane, now).await?;
+        let FetchedFeed::Updated {
+            feed,
+            etag,
+            last_modified,
+        } = fetched
+        else {
+            return Err(UserError::Upstream(
+                "Feed answered Not Modified to an unconditional request".to_string(),
+            ));
+        };
+
+        let subscription = StoredSubscription {
+            info: FeedSubscription {
+                id: target.subscription_id,
+                url: target.url,
+                title: feed.title,
+                site_url: feed.site_url,
+                last_polled_at: Some(now),
+                last_error: None,
+            },
+            etag,
+            last_modified,
+            next_poll_at: now + self.config.poll_interval_secs,
+            failures: 0,
+        };
+        let info = subscription.info.clone();
+        let id = info.id.clone();
+        let mut articles = Vec::new();
+        merge_articles(
+            &mut articles,
+            feed.articles,
+            self.config.max_articles_per_feed,
+        );
+        self.articles.insert(id.clone(), articles);
+        self.subscriptions.push(subscription);
+
+        // 글을 먼저 저장해야 다음 확인이 304를 받아도 글이 남는다
+        let persisted = match self.persist_articles(&id).await {
+            Ok(()) => self.persist_subscriptions().await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = persisted {
+            self.subscriptions.pop();
+            self.articles.remove(&id);
+            return Err(e);
+        }
+        self.emit_subscriptions();
+        Ok(info)
+    }
+}
+
+#[async_trait]
+impl Handler<UnsubscribeFeed> for FeedReaderActor {
+    type Result = ActorResult<()>;
+
+    async fn handle(&mut self, msg: UnsubscribeFeed, _: &Context<Self>) -> Self::Result {
+        let Some(index) = self
+            .subscriptions
+            .iter()
+            .position(|subscription| subscription.info.id == msg.subscription_id)
+        else {
+            return Err(UserError::NotFound(msg.subscription_id));
+        };
+        let removed = self.subscriptions.remove(index);
+        if let Err(e) = self.persist_subscriptions().await {
+            self.subscriptions.insert(index, removed);
+            return Err(e);
+        }
+        self.articles.remove(&msg.subscription_id);
+        let delete = DeleteData {
+            key: articles_key(&msg.subscription_id),
+        };
+        if let Err(e) = self.storage.send(delete).await? {
+            debug_print!("Failed to delete feed articles: {}", e);
+        }
+        self.emit_subscriptions();
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Handler<ListFeedArticles> for FeedReaderActor {
+    type Result = ActorResult<Vec<FeedArticle>>;
+
+    async fn handle(&mut self, msg: ListFeedArticles, _: &Context<Self>) -> Self::Result {
+        let mut articles: Vec<FeedArticle> = match &msg.subscription_id {
+            Some(id) => self
+                .articles
+                .get(id)
+                .cloned()
+                .ok_or_else(|| UserError::NotFound(id.clone()))?,
+            None => self.articles.values().flatten().cloned().collect(),
+        };
+        articles.sort_by_key(|article| Reverse(article_time(article)));
+        articles.truncate(msg.limit);
+        Ok(articles)
+    }
+}
+
+// 저장소는 감독자가 지우므로 메모리의 구독과 글만 비운다
+#[async_trait]
+impl Handler<WipeUserData> for FeedReaderActor {
+    type Result = Result<(), FeedReaderError>;
+
+    async fn handle(&mut self, _: WipeUserData, _: &Context<Self>) -> Self::Result {
+        self.subscriptions.clear();
+        self.articles.clear();
+        Ok(())
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<SubscribeFeedRequest> for FeedReaderActor {
+    async fn notify(&mut self, msg: SubscribeFeedRequest, ctx: &Context<Self>) {
+        let _ = respond_to_dart(msg, |msg| async move {
+            Ok(self.handle(SubscribeFeed { url: msg.url }, ctx).await?)
+        })
+        .await;
+    }
+}
+
+#[async_trait]
+impl Notifiable<UnsubscribeFeedRequest> for FeedReaderActor {
+    async fn notify(&mut self, msg: UnsubscribeFeedRequest, ctx: &Context<Self>) {
+        let unsubscribe = UnsubscribeFeed {
+            subscription_id: msg.subscription_id,
+        };
+        if let Err(e) = self.handle(unsubscribe, ctx).await {
+            debug_print!("Failed to unsubscribe feed: {}", e);
+        }
+    }
+}
+
+#[async_trait]
+impl Notifiable<FeedSubscriptionsRequest> for FeedReaderActor {
+    async fn notify(&mut self, _: FeedSubscriptionsRequest, _: &Context<Self>) {
+        self.emit_subscriptions();
+    }
+}
+
+#[async_trait]
+impl Notifiable<RefreshFeedsRequest> for FeedReaderActor {
+    async fn notify(&mut self, _: RefreshFeedsRequest, ctx: &Context<Self>) {
+        self.notify(PollDueFeeds { force: true }, ctx).await;
+    }
+}
+
+#[async_trait]
+impl Notifiable<FeedArticlesRequest> for FeedReaderActor {
+    async fn notify(&mut self, msg: FeedArticlesRequest, ctx: &Context<Self>) {
+        let list = ListFeedArticles {
+            subscription_id: msg.subscription_id.clone(),
+            limit: msg.limit as usize,
+        };
+        match self.handle(list, ctx).await {
+            Ok(articles) => FeedArticlesSignal {
+                subscription_id: msg.subscription_id,
+                articles,
+            }
+            .emit(),
+            Err(e) => debug_print!("Failed to list feed articles: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn parses_rss_and_keeps_only_new_articles() -> Result<(), Box<dyn Error>> {
+        let base = feed_url("https://example.com/blog/feed.xml")?;
+        let rss = br#"<?xml version="1.0"?>
+            <rss version="2.0"><channel>
+              <title>Example &amp; Co</title>
+              <link>https://example.com/blog/</link>
+              <item>
+                <guid>post-2</guid>
+                <title>Second</title>
+                <link>/blog/2</link>
+                <description><![CDATA[<p>Hello <b>world</b></p>]]></description>
+                <pubDate>Tue, 02 Jan 2024 00:00:00 GMT</pubDate>
+              </item>
+              <item>
+                <guid>post-1</guid>
+                <title>First</title>
+                <link>https://example.com/blog/1</link>
+                <pubDate>Mon, 01 Jan 2024 00:00:00 GMT</pubDate>
+              </item>
+            </channel></rss>"#;
+        let feed = parse_feed(rss, &base, "sub", 1_800_000_000)?;
+        assert_eq!(feed.title.as_deref(), Some("Example & Co"));
+        assert_eq!(feed.site_url.as_deref(), Some("https://example.com/blog/"));
+        let second = &feed.articles[0];
+        assert_eq!(second.id, "post-2");
+        assert_eq!(second.url.as_deref(), Some("https://example.com/blog/2"));
+        assert_eq!(second.summary.as_deref(), Some("Hello world"));
+        assert_eq!(second.published_at, Some(1_704_153_600));
+        assert!(parse_feed(b"<html></html>", &base, "sub", 0).is_err());
+
+        // 상한이 2이면 다시 받은 글은 새 글이 아니고, 가장 오래된 글은 밀려난다
+        let mut existing = vec![feed.articles[1].clone()];
+        let mut newer = second.clone();
+        newer.id = "post-3".to_string();
+        newer.published_at = Some(1_704_240_000);
+        let fresh = merge_articles(&mut existing, vec![newer, second.clone()], 2);
+        let ids: Vec<&str> = existing.iter().map(|article| article.id.as_str()).collect();
+        assert_eq!(ids, ["post-3", "post-2"]);
+        assert_eq!(fresh.len(), 2);
+
+        let config = FeedReaderConfig::default();
+        assert_eq!(backoff_secs(&config, 0), config.poll_interval_secs);
+        assert_eq!(backoff_secs(&config, 2), config.poll_interval_secs * 4);
+        assert_eq!(backoff_secs(&config, 40), config.max_backoff_secs);
+        Ok(())
+    }
+}
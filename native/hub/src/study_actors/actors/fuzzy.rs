@@ -0,0 +1,251 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use serde::{Deserialize, Serialize};
+use tokio::task::JoinSet;
+
+use crate::study_actors::{
+    messages::{FuzzyHit, FuzzyMatch},
+    signals::{EmitSignal, FuzzyMatchRequest, FuzzyMatchSignal, route_dart_signals},
+};
+
+use super::{OffloadError, WorkerJob, offload};
+
+const SCORE_MATCH: i32 = 16;
+const BONUS_BOUNDARY: i32 = 8; // 단어가 시작하는 글자와 일치
+const BONUS_FIRST_CHAR: i32 = 8; // 후보의 첫 글자와 일치
+const BONUS_CONSECUTIVE: i32 = 4;
+const PENALTY_GAP_START: i32 = 3;
+const PENALTY_GAP_EXTENSION: i32 = 1;
+const DEFAULT_LIMIT: usize = 50;
+// 이보다 후보가 적으면 넘기는 비용이 더 크므로 바로 계산한다
+const OFFLOAD_THRESHOLD: usize = 2_000;
+
+fn is_separator(c: char) -> bool {
+    c.is_whitespace() || matches!(c, '_' | '-' | '/' | '\\' | '.' | ':')
+}
+
+// 단어가 시작하는 자리 (맨 앞, 구분자 뒤, camelCase의 대문자, 글자 뒤의 숫자)
+fn is_boundary(prev: Option<char>, c: char) -> bool {
+    match prev {
+        None => true,
+        Some(prev) => {
+            is_separator(prev)
+                || (prev.is_lowercase() && c.is_uppercase())
+                || (!prev.is_numeric() && c.is_numeric())
+        }
+    }
+}
+
+fn fold(c: char, case_sensitive: bool) -> char {
+    if case_sensitive {
+        c
+    } else {
+        c.to_lowercase().next().unwrap_or(c)
+    }
+}
+
+// 검색어 글자가 모두 순서대로 들어 있으면 점수와 일치한 글자 위치(char 기준)
+// 앞으로 한 번 훑어 끝 위치를 찾고, 거꾸로 다시 맞춰 가장 짧은 구간에서 점수를 매긴다.
+fn score_candidate(
+    query: &[char],
+    candidate: &[char],
+    case_sensitive: bool,
+) -> Option<(i32, Vec<usize>)> {
+    let matches = |i: usize, qi: usize| fold(candidate[i], case_sensitive) == query[qi];
+
+    let mut qi = 0;
+    let end = (0..candidate.len()).find(|&i| {
+        if matches(i, qi) {
+            qi += 1;
+        }
+        qi == query.len()
+    })?;
+
+    let mut qi = query.len();
+    let start = (0..=end).rev().find(|&i| {
+        if matches(i, qi - 1) {
+            qi -= 1;
+        }
+        qi == 0
+    })?;
+
+    let (mut score, mut positions, mut qi, mut in_gap) = (0, Vec::new(), 0, false);
+    for i in start..=end {
+        if qi < query.len() && matches(i, qi) {
+            score += SCORE_MATCH;
+            if is_boundary(i.checked_sub(1).map(|prev| candidate[prev]), candidate[i]) {
+                score += BONUS_BOUNDARY;
+            }
+            if i == 0 {
+                score += BONUS_FIRST_CHAR;
+            }
+            if positions.last().is_some_and(|&last| last + 1 == i) {
+                score += BONUS_CONSECUTIVE;
+            }
+            positions.push(i);
+            qi += 1;
+            in_gap = false;
+        } else {
+            score -= if in_gap {
+                PENALTY_GAP_EXTENSION
+            } else {
+                PENALTY_GAP_START
+            };
+            in_gap = true;
+        }
+    }
+    Some((score, positions))
+}
+
+// 점수가 높은 순, 같으면 짧은 후보와 앞쪽 후보 순
+// 검색어의 공백은 무시하며, 빈 검색어면 후보를 원래 순서대로 돌려준다.
+pub fn fuzzy_match(query: &str, candidates: &[String], limit: usize) -> Vec<FuzzyHit> {
+    let case_sensitive = query.chars().any(char::is_uppercase);
+    let query: Vec<char> = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| fold(c, case_sensitive))
+        .collect();
+    if query.is_empty() {
+        return (0..candidates.len().min(limit))
+            .map(|index| FuzzyHit {
+                index: index as u32,
+                score: 0,
+                positions: Vec::new(),
+            })
+            .collect();
+    }
+
+    let mut hits: Vec<(FuzzyHit, usize)> = candidates
+        .iter()
+        .enumerate()
+        .filter_map(|(index, candidate)| {
+            let chars: Vec<char> = candidate.chars().collect();
+            let (score, positions) = score_candidate(&query, &chars, case_sensitive)?;
+            let mut utf16_offsets = Vec::with_capacity(chars.len());
+            let mut offset = 0u32;
+            for c in &chars {
+                utf16_offsets.push(offset);
+                offset += c.len_utf16() as u32;
+            }
+            let hit = FuzzyHit {
+                index: index as u32,
+                score,
+                positions: positions.iter().map(|&i| utf16_offsets[i]).collect(),
+            };
+            Some((hit, chars.len()))
+        })
+        .collect();
+    hits.sort_by(|(a, a_len), (b, b_len)| {
+        b.score
+            .cmp(&a.score)
+            .then(a_len.cmp(b_len))
+            .then(a.index.cmp(&b.index))
+    });
+    hits.truncate(limit);
+    hits.into_iter().map(|(hit, _)| hit).collect()
+}
+
+// 퍼지 검색 본문 (후보가 많으면 offload로 실행)
+#[derive(Serialize, Deserialize)]
+pub(super) struct FuzzyMatchJob {
+    query: String,
+    candidates: Vec<String>,
+    limit: usize,
+}
+
+impl WorkerJob for FuzzyMatchJob {
+    const NAME: &'static str = "text/fuzzy";
+    type Output = Vec<FuzzyHit>;
+
+    fn run(self) -> Self::Output {
+        fuzzy_match(&self.query, &self.candidates, self.limit)
+    }
+}
+
+// 퍼지 검색 액터
+// Flutter 명령 팔레트와 선택 목록이 수천 개의 문자열 정렬을 맡긴다.
+pub struct FuzzyMatchActor {
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for FuzzyMatchActor {}
+
+impl FuzzyMatchActor {
+    pub fn new(self_addr: Address<Self>) -> Self {
+        Self {
+            _owned_tasks: route_dart_signals!(self_addr, [FuzzyMatchRequest]),
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<FuzzyMatch> for FuzzyMatchActor {
+    type Result = Result<Vec<FuzzyHit>, OffloadError>;
+
+    async fn handle(&mut self, msg: FuzzyMatch, _: &Context<Self>) -> Self::Result {
+        if msg.candidates.len() < OFFLOAD_THRESHOLD {
+            return Ok(fuzzy_match(&msg.query, &msg.candidates, msg.limit));
+        }
+        let job = FuzzyMatchJob {
+            query: msg.query,
+            candidates: msg.candidates,
+            limit: msg.limit,
+        };
+        offload(job).await
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<FuzzyMatchRequest> for FuzzyMatchActor {
+    async fn notify(&mut self, msg: FuzzyMatchRequest, ctx: &Context<Self>) {
+        let request = FuzzyMatch {
+            query: msg.query,
+            candidates: msg.candidates,
+            limit: msg.limit.map_or(DEFAULT_LIMIT, |limit| limit as usize),
+        };
+        let (hits, error) = match self.handle(request, ctx).await {
+            Ok(hits) => (hits, None),
+            Err(e) => (Vec::new(), Some(e.to_string())),
+        };
+        FuzzyMatchSignal {
+            request_id: msg.request_id,
+            hits,
+            error,
+        }
+        .emit();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fuzzy_match;
+
+    fn candidates(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn word_starts_rank_above_scattered_matches() {
+        let list = candidates(&["Open Recent File", "toggle preview", "open file", "profile"]);
+        let hits = fuzzy_match("of", &list, 10);
+        let order: Vec<u32> = hits.iter().map(|hit| hit.index).collect();
+        // "open file"과 "Open Recent File"은 두 단어의 첫 글자에 맞고, 짧은 쪽이 앞선다
+        assert_eq!(order[..2], [2, 0]);
+        assert_eq!(hits[0].positions, [0, 5]);
+        assert!(!order.contains(&1));
+    }
+
+    #[test]
+    fn uppercase_query_is_case_sensitive_and_positions_are_utf16() {
+        let list = candidates(&["😀 Save", "save"]);
+        let hits = fuzzy_match("S", &list, 10);
+        assert_eq!(hits.len(), 1);
+        // 이모지는 UTF-16 두 단위에 공백 하나를 더해 S는 3번째 위치
+        assert_eq!(hits[0].positions, [3]);
+    }
+}
@@ -0,0 +1,199 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Notifiable},
+};
+use std::{sync::OnceLock, time::Duration};
+use tokio::{
+    sync::{mpsc, watch},
+    task::JoinSet,
+};
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::PerformanceLevel,
+    signals::{EmitSignal, PerformanceGovernorSignal},
+};
+
+use super::Clock;
+#[cfg(not(target_family = "wasm"))]
+use super::jobs_in_flight;
+
+const SAMPLE_INTERVAL_SECS: u64 = 1;
+// 최고 해상도로 환산한 프레임 렌더링 시간 기준
+const REDUCED_FRAME_MS: f64 = 40.0;
+const MINIMAL_FRAME_MS: f64 = 80.0;
+const RECOVERY_SAMPLES: u32 = 3; // 이만큼 연속으로 여유가 있어야 한 단계 올린다
+
+// 프레임 렌더링 시간은 다른 모듈(프랙털 스트림)에서 동기적으로 남길 수 있도록 전역 채널로 모은다
+static FRAME_TIMES: OnceLock<mpsc::UnboundedSender<Duration>> = OnceLock::new();
+// 지금 적용할 성능 단계 (CPU를 많이 쓰는 작업이 작업마다 확인한다)
+static LEVEL: OnceLock<watch::Sender<PerformanceLevel>> = OnceLock::new();
+
+fn level_sender() -> &'static watch::Sender<PerformanceLevel> {
+    LEVEL.get_or_init(|| watch::channel(PerformanceLevel::Full).0)
+}
+
+// 거버너가 없으면 항상 Full
+pub fn performance_level() -> PerformanceLevel {
+    *level_sender().borrow()
+}
+
+// 거버너가 없으면 측정값은 버려진다
+pub fn record_frame_time(elapsed: Duration) {
+    if let Some(frame_times) = FRAME_TIMES.get() {
+        let _ = frame_times.send(elapsed);
+    }
+}
+
+// 웹 워커로 넘긴 작업은 따로 세지 않는다
+#[cfg(not(target_family = "wasm"))]
+fn queue_depth() -> usize {
+    jobs_in_flight()
+}
+
+#[cfg(target_family = "wasm")]
+fn queue_depth() -> usize {
+    0
+}
+
+// 성능 거버너 액터
+// 블로킹 작업 대기열 깊이와 프레임 렌더링 시간을 주기적으로 보고 프랙털 해상도,
+// OCR 전처리 품질, 검색 색인 배치 크기를 낮추거나 되돌린다.
+// 부하가 오르면 바로 낮추고, 내릴 때는 여유가 이어질 때 한 단계씩 올려 오르내림을 막는다.
+pub struct PerformanceGovernorActor {
+    cores: usize,
+    level: PerformanceLevel,
+    calm_samples: u32,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for PerformanceGovernorActor {}
+
+impl PerformanceGovernorActor {
+    pub fn new(self_addr: Address<Self>, clock: Clock) -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        if FRAME_TIMES.set(sender).is_err() {
+            debug_print!("Frame time recorder already installed, new frames are not measured");
+        }
+
+        let mut owned_tasks = JoinSet::new();
+        owned_tasks.spawn(Self::sample_load(self_addr, clock, receiver));
+
+        Self {
+            // 웹은 병렬성을 알려주지 않으므로 2로 본다
+            cores: std::thread::available_parallelism().map_or(2, |cores| cores.get()),
+            level: performance_level(),
+            calm_samples: 0,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    async fn sample_load(
+        mut self_addr: Address<Self>,
+        clock: Clock,
+        mut frame_times: mpsc::UnboundedReceiver<Duration>,
+    ) {
+        let mut interval = clock.interval(Duration::from_secs(SAMPLE_INTERVAL_SECS));
+        loop {
+            interval.tick().await;
+            let (mut total, mut frames) = (Duration::ZERO, 0u32);
+            while let Ok(elapsed) = frame_times.try_recv() {
+                total += elapsed;
+                frames += 1;
+            }
+            let frame_ms = if frames > 0 {
+                total.as_secs_f64() * 1000.0 / f64::from(frames)
+            } else {
+                0.0
+            };
+            let sample = LoadSample {
+                queue_depth: queue_depth(),
+                frame_ms,
+            };
+            if self_addr.notify(sample).await.is_err() {
+                break;
+            }
+        }
+    }
+
+    // 부하에 맞는 단계와 그 이유
+    fn assess(&self, sample: &LoadSample) -> (PerformanceLevel, String) {
+        let by_queue = if sample.queue_depth > self.cores * 2 {
+            PerformanceLevel::Minimal
+        } else if sample.queue_depth > self.cores {
+            PerformanceLevel::Reduced
+        } else {
+            PerformanceLevel::Full
+        };
+
+        // 해상도를 낮춘 뒤 빨라진 것을 여유로 오해하지 않도록 최고 해상도 기준으로 환산한다
+        let ratio =
+            f64::from(PerformanceLevel::Full.fractal_size()) / f64::from(self.level.fractal_size());
+        let full_frame_ms = sample.frame_ms * ratio * ratio;
+        let by_frames = if full_frame_ms > MINIMAL_FRAME_MS {
+            PerformanceLevel::Minimal
+        } else if full_frame_ms > REDUCED_FRAME_MS {
+            PerformanceLevel::Reduced
+        } else {
+            PerformanceLevel::Full
+        };
+
+        let target = by_queue.max(by_frames);
+        let reason = if target == PerformanceLevel::Full {
+            "Load is back to normal".to_string()
+        } else if by_queue >= by_frames {
+            format!(
+                "{} blocking jobs queued on {} cores",
+                sample.queue_depth, self.cores
+            )
+        } else {
+            format!("Frames take about {:.1} ms at full size", full_frame_ms)
+        };
+        (target, reason)
+    }
+}
+
+// 내부 메시지 정의
+struct LoadSample {
+    queue_depth: usize,
+    frame_ms: f64,
+}
+
+#[async_trait]
+impl Notifiable<LoadSample> for PerformanceGovernorActor {
+    async fn notify(&mut self, msg: LoadSample, _: &Context<Self>) {
+        let (target, reason) = self.assess(&msg);
+        let next = if target > self.level {
+            target
+        } else if target < self.level {
+            self.calm_samples += 1;
+            if self.calm_samples < RECOVERY_SAMPLES {
+                return;
+            }
+            match self.level {
+                PerformanceLevel::Minimal => PerformanceLevel::Reduced,
+                PerformanceLevel::Reduced | PerformanceLevel::Full => PerformanceLevel::Full,
+            }
+        } else {
+            self.calm_samples = 0;
+            return;
+        };
+
+        self.calm_samples = 0;
+        self.level = next;
+        level_sender().send_replace(next);
+        debug_print!("Performance governor: {:?} ({})", next, reason);
+        PerformanceGovernorSignal {
+            level: next,
+            reason,
+            queue_depth: msg.queue_depth as u64,
+            frame_ms: msg.frame_ms,
+            fractal_size: next.fractal_size(),
+            ocr_deskew: next.ocr_deskew(),
+            ocr_crop: next.ocr_crop(),
+            index_batch_size: next.index_batch_size() as u64,
+        }
+        .emit();
+    }
+}
@@ -0,0 +1,342 @@
+// Handler 구현 컴파일 검사
+// 액터·메시지 쌍마다 Handler 구현이 있고 응답 타입이 아래 표와 같은지 빌드할 때마다 확인한다.
+// 핸들러를 추가하거나 응답 타입을 바꾸면 이 표도 함께 고친다 (빠뜨리면 여기서 컴파일이 깨진다).
+use messages::prelude::Handler;
+use rust_decimal::Decimal;
+use std::sync::Arc;
+
+use crate::study_actors::messages::{
+    ActorResult, AddItemToCollection, AddTag, AdminError, AdminOverride, AnalyzeText, AppConfig,
+    ApplyAdminCommand, ApplyEdit, ArchiveError, AttachBytes, AttachFile, AttachRemoteFile,
+    Attachment, AttachmentError, AuthError, AuthResult, AutomationError, BillingError, CacheData,
+    CalculateMoney, CancelReminder, CaptureSnapshot, CategoryCleared, CategoryUsage,
+    ChangeSyncPassphrase, ChatError, ChatMessage, CheckConsent, CheckQuota, ClearStorage,
+    ClearStorageCategory, CollabError, CollectOrphanedBlobs, Collection, CompactStorage,
+    CompactionStats, ComposeMail, ComputeHash, ConfigError, Conversion, ConvertCurrency,
+    CreateArchive, CreateCollection, CreateDataItem, CreateDiagnosticBundle, CryptoError, DataItem,
+    Decrypt, DeleteData, DiagnosticBundle, DiagnosticsError, DiffHunk, DiffText,
+    DiscardSensitivePayload, DuplicateGroup, Encrypt, Entitlement, ExchangeAuthCode,
+    ExpandRecurrence, ExportIcs, ExportSyncKeyring, ExtractArchive, FeedArticle, FeedError,
+    FeedPage, FeedReaderError, FeedSubscription, FetchData, FetchFeedPage, FetchLinkPreview,
+    FetchRecentData, FieldError, FindDuplicates, FormatMoney, FuzzyHit, FuzzyMatch, GenerateIds,
+    GenerateKey, GetConfig, GetEntitlements, GetItemHistory, GetLatencyStats, GetMedia,
+    GetPlatformCapabilities, GetPresence, GetProfile, GetStorageUsage, HashError, I18nError,
+    IcsImport, ImportIcs, ImportSyncKeyring, ImportVcard, InboxError, InboxNotification,
+    IngestSharedContent, IngestedContent, IntegrityReport, ItemPage, ItemRevision, ItemsByTag,
+    LatencyStat, LinkPreview, ListAdminOverrides, ListFeedArticles, ListItems, ListNotifications,
+    LoadLocaleBundle, LockSyncEncryption, Login, Logout, MailError, MaintenanceReport,
+    MarkNotificationsRead, MeasureStorage, MediaCacheError, MediaEntry, MergeItems, MergeOutcome,
+    MergeRemoteUpdate, Navigation, NormalizeContactField, NormalizedContactField,
+    NotificationError, NotificationPage, Occurrence, OcrError, OpenAttachment, OpenDocument,
+    OpenSyncItems, PersistState, PersistedState, PinMedia, PlanLimits, PlatformCapabilities,
+    PostNotification, PreprocessFrame, PresenceState, ProcessLogin, ProcessedFrame,
+    QuerySuggestion, QuotaDecision, QuotaError, RankItems, RankedItem, RankingError, RealtimeError,
+    RecordItemAccess, RecordItemAttachment, RecordSearchQuery, RedoLastChange, RefreshPlanLimits,
+    RegisterPushToken, RegisterRule, RegisterTemplate, Reminder, ReminderError, RemoveRule,
+    RemoveTag, RenderMarkdown, RenderTemplate, ResolveDeepLink, RestoreState, RevertItemToRevision,
+    RevokeAllSessions, RotateEncryptionKey, RotateSyncKey, RouterError, RunMaintenance, ScanPrefix,
+    ScheduleLocalNotification, SealSyncItems, SearchUsers, SendChatMessage, SendRealtimeEnvelope,
+    SetConfigValue, SetItemContent, SetReminder, SetStorageWritesPaused, SetupSyncEncryption,
+    SnoozeReminder, StorageError, StorageUsageReport, StoreData, StoreSensitivePayload,
+    SubscribeFeed, SuggestQueries, SyncClock, SyncPayload, TextAnalysis, TextAnalysisError,
+    TextEdit, ThreeWayMerge, TimeError, Translate, UndoLastChange, UnlockSyncEncryption,
+    UnsubscribeFeed, UpdateProfile, UserData, UserId, UserProfile, UserSession, ValidateForm,
+    ValidateReceipt, VcardImport, VerifyBlobs, VerifyHash, VerifyToken, WipeReport, WipeUserData,
+};
+#[cfg(not(target_family = "wasm"))]
+use crate::study_actors::messages::{
+    AudioError, ComputeWaveform, MqttError, MqttPublish, MqttSubscribe, TranscodeToOpus,
+};
+#[cfg(all(feature = "wasm-rules", not(target_family = "wasm")))]
+use crate::study_actors::messages::{
+    CheckRulesUpdate, GetRulesModule, RulesError, RulesModuleInfo, RulesValidation, ScoreWithRules,
+    ValidateWithRules,
+};
+#[cfg(all(
+    feature = "command-channel",
+    not(any(target_family = "wasm", target_os = "android", target_os = "ios"))
+))]
+use crate::study_actors::messages::{CommandError, CommandReceipt, SignedCommand};
+#[cfg(feature = "ml")]
+use crate::study_actors::messages::{
+    EmbedText, EmbeddingError, IndexItems, SemanticMatch, SemanticSearch,
+};
+#[cfg(all(feature = "scripting", not(target_family = "wasm")))]
+use crate::study_actors::messages::{
+    ListScripts, RegisterScript, RemoveScript, ScriptError, ScriptInfo,
+};
+use crate::study_actors::signals::StateSnapshotSignal;
+
+#[cfg(all(
+    feature = "command-channel",
+    not(any(target_family = "wasm", target_os = "android", target_os = "ios"))
+))]
+use super::CommandChannelActor;
+#[cfg(feature = "ml")]
+use super::EmbeddingActor;
+#[cfg(all(feature = "wasm-rules", not(target_family = "wasm")))]
+use super::RulesEngineActor;
+#[cfg(all(feature = "scripting", not(target_family = "wasm")))]
+use super::ScriptActor;
+use super::{
+    AdminActor, AppSupervisor, ArchiveActor, AttachmentActor, AuthActor, AutomationActor,
+    BillingActor, CacheActor, CalendarActor, ChatActor, CollabActor, ConfigActor, ContactsActor,
+    CryptoActor, CurrencyActor, DataManagerActor, DedupActor, DiagnosticsActor, DiffActor,
+    FeedActor, FeedReaderActor, FuzzyMatchActor, HashActor, I18nActor, IdGenActor, InboxActor,
+    IngestActor, LinkPreviewActor, MailActor, MaintenanceActor, MarkdownActor, MediaCacheActor,
+    MetricsActor, NetworkManagerActor, NotificationActor, OcrPrepActor, OffloadError,
+    PlatformActor, PresenceActor, Prioritized, PrivacyActor, QuotaActor, RRuleActor, RankingActor,
+    RouterActor, SchedulerActor, SensitivePayloadActor, StorageActor, StorageUsageActor,
+    SyncCryptoActor, TemplateActor, TextAnalysisActor, TimeActor, Timed, Traced, UserManagerActor,
+    UserProfileActor, ValidationActor, WebSocketActor,
+    network::{NetworkRequest, PendingResponse},
+};
+#[cfg(not(target_family = "wasm"))]
+use super::{AudioActor, MqttActor};
+
+const fn handles<A, M, R>()
+where
+    A: Handler<M, Result = R>,
+    M: Send + 'static,
+{
+}
+
+// 예: AuthActor => Login: Result<AuthResult, AuthError>
+macro_rules! check_handlers {
+    ($($actor:ty => $message:ty: $result:ty),+ $(,)?) => {
+        const _: () = {$(handles::<$actor, $message, $result>();)+};
+    };
+}
+
+check_handlers! {
+    ArchiveActor => CreateArchive: Result<usize, ArchiveError>,
+    ArchiveActor => ExtractArchive: Result<usize, ArchiveError>,
+    AttachmentActor => AttachFile: Result<Attachment, AttachmentError>,
+    AttachmentActor => AttachBytes: Result<Attachment, AttachmentError>,
+    AttachmentActor => AttachRemoteFile: Result<Attachment, AttachmentError>,
+    AttachmentActor => OpenAttachment: Result<Attachment, AttachmentError>,
+    AttachmentActor => CollectOrphanedBlobs: Result<usize, AttachmentError>,
+    AttachmentActor => MeasureStorage: Result<Vec<CategoryUsage>, AttachmentError>,
+    AttachmentActor => ClearStorage: Result<usize, AttachmentError>,
+    AttachmentActor => VerifyBlobs: Result<IntegrityReport, AttachmentError>,
+    AttachmentActor => WipeUserData: Result<(), AttachmentError>,
+    AuthActor => Login: ActorResult<AuthResult>,
+    AuthActor => Traced<Login>: ActorResult<AuthResult>,
+    AuthActor => ExchangeAuthCode: ActorResult<AuthResult>,
+    AuthActor => Logout: Result<(), AuthError>,
+    AuthActor => VerifyToken: Result<UserId, AuthError>,
+    AuthActor => RevokeAllSessions: Vec<UserId>,
+    AuthActor => CaptureSnapshot: Option<StateSnapshotSignal>,
+    AutomationActor => RegisterRule: Result<(), AutomationError>,
+    AutomationActor => RemoveRule: Result<bool, AutomationError>,
+    AutomationActor => WipeUserData: Result<(), AutomationError>,
+    CalendarActor => ImportIcs: ActorResult<IcsImport>,
+    CalendarActor => ExportIcs: ActorResult<String>,
+    ChatActor => SendChatMessage: Result<ChatMessage, ChatError>,
+    ChatActor => WipeUserData: Result<(), ChatError>,
+    ChatActor => CaptureSnapshot: Option<StateSnapshotSignal>,
+    ChatActor => PersistState: Option<PersistedState>,
+    ChatActor => RestoreState: bool,
+    CollabActor => OpenDocument: Result<String, CollabError>,
+    CollabActor => ApplyEdit: Result<(), CollabError>,
+    CollabActor => MergeRemoteUpdate: Result<Vec<TextEdit>, CollabError>,
+    CollabActor => WipeUserData: Result<(), CollabError>,
+    ConfigActor => GetConfig: AppConfig,
+    ConfigActor => SetConfigValue: Result<AppConfig, ConfigError>,
+    ContactsActor => ImportVcard: ActorResult<VcardImport>,
+    CryptoActor => GenerateKey: Result<(), CryptoError>,
+    CryptoActor => Encrypt: Result<Vec<u8>, CryptoError>,
+    CryptoActor => Decrypt: Result<Vec<u8>, CryptoError>,
+    CryptoActor => RotateEncryptionKey: Result<String, CryptoError>,
+    DedupActor => FindDuplicates: ActorResult<Vec<DuplicateGroup>>,
+    DedupActor => WipeUserData: (),
+    HashActor => ComputeHash: Result<String, HashError>,
+    HashActor => VerifyHash: Result<bool, HashError>,
+    I18nActor => LoadLocaleBundle: Result<usize, I18nError>,
+    I18nActor => Translate: String,
+    DiffActor => DiffText: Vec<DiffHunk>,
+    DiffActor => ThreeWayMerge: MergeOutcome,
+    FuzzyMatchActor => FuzzyMatch: Result<Vec<FuzzyHit>, OffloadError>,
+    InboxActor => PostNotification: Result<InboxNotification, InboxError>,
+    InboxActor => ListNotifications: NotificationPage,
+    InboxActor => MarkNotificationsRead: Result<usize, InboxError>,
+    InboxActor => WipeUserData: Result<(), InboxError>,
+    FeedActor => FetchFeedPage: FeedPage,
+    FeedActor => WipeUserData: Result<(), FeedError>,
+    DiagnosticsActor => CreateDiagnosticBundle: Result<DiagnosticBundle, DiagnosticsError>,
+    DiagnosticsActor => MeasureStorage: Result<Vec<CategoryUsage>, DiagnosticsError>,
+    DiagnosticsActor => ClearStorage: Result<usize, DiagnosticsError>,
+    AdminActor => ApplyAdminCommand: Result<AdminOverride, AdminError>,
+    AdminActor => ListAdminOverrides: Vec<AdminOverride>,
+    QuotaActor => CheckQuota: QuotaDecision,
+    QuotaActor => RefreshPlanLimits: Result<PlanLimits, QuotaError>,
+    BillingActor => ValidateReceipt: Result<Vec<Entitlement>, BillingError>,
+    BillingActor => GetEntitlements: Vec<Entitlement>,
+    BillingActor => WipeUserData: Result<(), BillingError>,
+    MailActor => ComposeMail: ActorResult<String>,
+    MailActor => WipeUserData: Result<(), MailError>,
+    MailActor => PersistState: Option<PersistedState>,
+    MailActor => RestoreState: bool,
+    IngestActor => IngestSharedContent: ActorResult<IngestedContent>,
+    LinkPreviewActor => FetchLinkPreview: ActorResult<LinkPreview>,
+    FeedReaderActor => SubscribeFeed: ActorResult<FeedSubscription>,
+    FeedReaderActor => UnsubscribeFeed: ActorResult<()>,
+    FeedReaderActor => ListFeedArticles: ActorResult<Vec<FeedArticle>>,
+    FeedReaderActor => WipeUserData: Result<(), FeedReaderError>,
+    MarkdownActor => RenderMarkdown: String,
+    MediaCacheActor => GetMedia: Result<MediaEntry, MediaCacheError>,
+    MediaCacheActor => PinMedia: Result<MediaEntry, MediaCacheError>,
+    MediaCacheActor => MeasureStorage: Result<Vec<CategoryUsage>, MediaCacheError>,
+    MediaCacheActor => ClearStorage: Result<usize, MediaCacheError>,
+    MediaCacheActor => VerifyBlobs: Result<IntegrityReport, MediaCacheError>,
+    MediaCacheActor => WipeUserData: Result<(), MediaCacheError>,
+    MetricsActor => GetLatencyStats: Vec<LatencyStat>,
+    NotificationActor => RegisterPushToken: Result<(), NotificationError>,
+    NotificationActor => ScheduleLocalNotification: (),
+    NotificationActor => WipeUserData: Result<(), NotificationError>,
+    OcrPrepActor => PreprocessFrame: Result<ProcessedFrame, OcrError>,
+    PlatformActor => GetPlatformCapabilities: PlatformCapabilities,
+    PresenceActor => GetPresence: Option<PresenceState>,
+    PrivacyActor => CheckConsent: bool,
+    PrivacyActor => WipeUserData: WipeReport,
+    RankingActor => RecordItemAccess: (),
+    RankingActor => RankItems: Result<Vec<RankedItem>, RankingError>,
+    RankingActor => RecordSearchQuery: Result<(), RankingError>,
+    RankingActor => SuggestQueries: Vec<QuerySuggestion>,
+    RankingActor => WipeUserData: Result<(), RankingError>,
+    RouterActor => ResolveDeepLink: Result<Navigation, RouterError>,
+    SensitivePayloadActor => StoreSensitivePayload: String,
+    SensitivePayloadActor => DiscardSensitivePayload: bool,
+    SensitivePayloadActor => WipeUserData: (),
+    StorageUsageActor => GetStorageUsage: ActorResult<StorageUsageReport>,
+    StorageUsageActor => ClearStorageCategory: ActorResult<CategoryCleared>,
+    MaintenanceActor => RunMaintenance: MaintenanceReport,
+    TextAnalysisActor => AnalyzeText: Result<TextAnalysis, TextAnalysisError>,
+    TimeActor => SyncClock: Result<i64, TimeError>,
+    WebSocketActor => SendRealtimeEnvelope: Result<(), RealtimeError>,
+}
+
+// 저장소 계층은 StorageError, 그 위 사용자·데이터 계층은 ActorResult로 답한다
+check_handlers! {
+    CacheActor => FetchData: Result<Arc<[u8]>, StorageError>,
+    CacheActor => Timed<FetchData>: Result<Arc<[u8]>, StorageError>,
+    CacheActor => CacheData: Result<(), StorageError>,
+    CacheActor => Timed<CacheData>: Result<(), StorageError>,
+    CacheActor => DeleteData: Result<(), StorageError>,
+    CacheActor => Timed<DeleteData>: Result<(), StorageError>,
+    CacheActor => WipeUserData: Result<(), StorageError>,
+    CacheActor => MeasureStorage: Result<Vec<CategoryUsage>, StorageError>,
+    CacheActor => ClearStorage: Result<usize, StorageError>,
+    StorageActor => FetchData: Result<Arc<[u8]>, StorageError>,
+    StorageActor => Timed<FetchData>: Result<Arc<[u8]>, StorageError>,
+    StorageActor => StoreData: Result<(), StorageError>,
+    StorageActor => Timed<StoreData>: Result<(), StorageError>,
+    StorageActor => DeleteData: Result<(), StorageError>,
+    StorageActor => Timed<DeleteData>: Result<(), StorageError>,
+    StorageActor => CompactStorage: Result<Option<CompactionStats>, StorageError>,
+    StorageActor => ScanPrefix: Result<Vec<(String, Vec<u8>)>, StorageError>,
+    StorageActor => Traced<ScanPrefix>: Result<Vec<(String, Vec<u8>)>, StorageError>,
+    StorageActor => WipeUserData: Result<(), StorageError>,
+    StorageActor => SetStorageWritesPaused: (),
+    DataManagerActor => FetchData: ActorResult<Arc<[u8]>>,
+    DataManagerActor => StoreData: ActorResult<()>,
+    DataManagerActor => FetchRecentData: ActorResult<UserData>,
+    DataManagerActor => Traced<FetchRecentData>: ActorResult<UserData>,
+    DataManagerActor => AddTag: ActorResult<Vec<String>>,
+    DataManagerActor => RemoveTag: ActorResult<Vec<String>>,
+    DataManagerActor => CreateCollection: ActorResult<Collection>,
+    DataManagerActor => AddItemToCollection: ActorResult<Collection>,
+    DataManagerActor => ItemsByTag: ActorResult<ItemPage>,
+    DataManagerActor => CreateDataItem: ActorResult<DataItem>,
+    DataManagerActor => RecordItemAttachment: ActorResult<DataItem>,
+    DataManagerActor => SetItemContent: ActorResult<()>,
+    DataManagerActor => ListItems: ActorResult<Vec<DataItem>>,
+    DataManagerActor => MergeItems: ActorResult<DataItem>,
+    DataManagerActor => GetItemHistory: ActorResult<Vec<ItemRevision>>,
+    DataManagerActor => RevertItemToRevision: ActorResult<DataItem>,
+    DataManagerActor => UndoLastChange: ActorResult<DataItem>,
+    DataManagerActor => RedoLastChange: ActorResult<DataItem>,
+    DataManagerActor => WipeUserData: ActorResult<()>,
+    DataManagerActor => CaptureSnapshot: Option<StateSnapshotSignal>,
+    DataManagerActor => PersistState: Option<PersistedState>,
+    DataManagerActor => RestoreState: bool,
+    NetworkManagerActor => NetworkRequest: PendingResponse,
+    NetworkManagerActor => Prioritized<NetworkRequest>: PendingResponse,
+    NetworkManagerActor => PersistState: Option<PersistedState>,
+    NetworkManagerActor => RestoreState: bool,
+    UserManagerActor => Login: ActorResult<AuthResult>,
+    UserManagerActor => Traced<Login>: ActorResult<AuthResult>,
+    UserManagerActor => GetProfile: ActorResult<UserProfile>,
+    UserManagerActor => Traced<GetProfile>: ActorResult<UserProfile>,
+    UserManagerActor => UpdateProfile: ActorResult<()>,
+    UserManagerActor => SearchUsers: ActorResult<Vec<UserProfile>>,
+    UserManagerActor => CaptureSnapshot: Option<StateSnapshotSignal>,
+    UserProfileActor => GetProfile: ActorResult<UserProfile>,
+    UserProfileActor => Traced<GetProfile>: ActorResult<UserProfile>,
+    UserProfileActor => UpdateProfile: ActorResult<UserProfile>,
+    AppSupervisor => ProcessLogin: ActorResult<UserSession>,
+    ValidationActor => ValidateForm: ActorResult<Vec<FieldError>>,
+    ValidationActor => NormalizeContactField: ActorResult<NormalizedContactField>,
+    CurrencyActor => CalculateMoney: ActorResult<Decimal>,
+    CurrencyActor => ConvertCurrency: ActorResult<Conversion>,
+    CurrencyActor => FormatMoney: ActorResult<String>,
+    RRuleActor => ExpandRecurrence: ActorResult<Vec<Occurrence>>,
+    TemplateActor => RegisterTemplate: ActorResult<()>,
+    TemplateActor => RenderTemplate: ActorResult<String>,
+    IdGenActor => GenerateIds: ActorResult<Vec<String>>,
+    SyncCryptoActor => SetupSyncEncryption: ActorResult<String>,
+    SyncCryptoActor => UnlockSyncEncryption: ActorResult<String>,
+    SyncCryptoActor => LockSyncEncryption: ActorResult<()>,
+    SyncCryptoActor => RotateSyncKey: ActorResult<String>,
+    SyncCryptoActor => ChangeSyncPassphrase: ActorResult<String>,
+    SyncCryptoActor => SealSyncItems: ActorResult<Vec<SyncPayload>>,
+    SyncCryptoActor => OpenSyncItems: ActorResult<Vec<DataItem>>,
+    SyncCryptoActor => ExportSyncKeyring: ActorResult<Vec<u8>>,
+    SyncCryptoActor => ImportSyncKeyring: ActorResult<Option<String>>,
+    SyncCryptoActor => WipeUserData: ActorResult<()>,
+    SchedulerActor => PersistState: Option<PersistedState>,
+    SchedulerActor => RestoreState: bool,
+    SchedulerActor => SetReminder: Result<Reminder, ReminderError>,
+    SchedulerActor => SnoozeReminder: Result<Reminder, ReminderError>,
+    SchedulerActor => CancelReminder: bool,
+    SchedulerActor => WipeUserData: Result<(), ReminderError>,
+}
+
+#[cfg(not(target_family = "wasm"))]
+check_handlers! {
+    AudioActor => ComputeWaveform: Result<Vec<f32>, AudioError>,
+    AudioActor => TranscodeToOpus: Result<Vec<u8>, AudioError>,
+    MqttActor => MqttPublish: Result<(), MqttError>,
+    MqttActor => MqttSubscribe: Result<(), MqttError>,
+}
+
+#[cfg(feature = "ml")]
+check_handlers! {
+    EmbeddingActor => EmbedText: Result<Vec<f32>, EmbeddingError>,
+    EmbeddingActor => IndexItems: Result<usize, EmbeddingError>,
+    EmbeddingActor => SemanticSearch: Result<Vec<SemanticMatch>, EmbeddingError>,
+    EmbeddingActor => WipeUserData: Result<(), EmbeddingError>,
+}
+
+#[cfg(all(feature = "scripting", not(target_family = "wasm")))]
+check_handlers! {
+    ScriptActor => RegisterScript: Result<ScriptInfo, ScriptError>,
+    ScriptActor => RemoveScript: Result<bool, ScriptError>,
+    ScriptActor => ListScripts: Vec<ScriptInfo>,
+}
+
+#[cfg(all(feature = "wasm-rules", not(target_family = "wasm")))]
+check_handlers! {
+    RulesEngineActor => ValidateWithRules: Result<RulesValidation, RulesError>,
+    RulesEngineActor => ScoreWithRules: Result<Option<f64>, RulesError>,
+    RulesEngineActor => CheckRulesUpdate: Result<Option<u64>, RulesError>,
+    RulesEngineActor => GetRulesModule: Option<RulesModuleInfo>,
+}
+
+#[cfg(all(
+    feature = "command-channel",
+    not(any(target_family = "wasm", target_os = "android", target_os = "ios"))
+))]
+check_handlers! {
+    CommandChannelActor => SignedCommand: Result<CommandReceipt, CommandError>,
+}
@@ -0,0 +1,171 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use sha2::{Digest, Sha256};
+use std::{fs::File, io::Read};
+use tokio::task::JoinSet;
+
+use crate::study_actors::{
+    messages::{ComputeHash, HashAlgorithm, HashError, HashSource, VerifyHash},
+    signals::{
+        EmitSignal, HashRequest, HashResultSignal, HashVerifiedSignal, VerifyHashRequest,
+        route_dart_signals,
+    },
+};
+
+const READ_CHUNK_SIZE: usize = 64 * 1024;
+
+// 알고리즘별 증분 해셔
+enum IncrementalHasher {
+    Sha256(Sha256),
+    Blake3(Box<blake3::Hasher>),
+    Crc32(crc32fast::Hasher),
+}
+
+impl IncrementalHasher {
+    fn new(algo: HashAlgorithm) -> Self {
+        match algo {
+            HashAlgorithm::Sha256 => Self::Sha256(Sha256::new()),
+            HashAlgorithm::Blake3 => Self::Blake3(Box::new(blake3::Hasher::new())),
+            HashAlgorithm::Crc32 => Self::Crc32(crc32fast::Hasher::new()),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        match self {
+            Self::Sha256(hasher) => hasher.update(data),
+            Self::Blake3(hasher) => {
+                hasher.update(data);
+            }
+            Self::Crc32(hasher) => hasher.update(data),
+        }
+    }
+
+    fn finalize_hex(self) -> String {
+        match self {
+            Self::Sha256(hasher) => hex::encode(hasher.finalize()),
+            Self::Blake3(hasher) => hasher.finalize().to_hex().to_string(),
+            Self::Crc32(hasher) => format!("{:08x}", hasher.finalize()),
+        }
+    }
+}
+
+// 블로킹 스레드에서 호출되는 해시 계산 (파일은 청크 단위로 읽음)
+pub fn hash_blocking(algo: HashAlgorithm, source: &HashSource) -> Result<String, HashError> {
+    let mut hasher = IncrementalHasher::new(algo);
+    match source {
+        HashSource::Bytes(bytes) => hasher.update(bytes),
+        HashSource::FilePath(path) => {
+            let mut file = File::open(path)?;
+            let mut buffer = vec![0u8; READ_CHUNK_SIZE];
+            loop {
+                let read = file.read(&mut buffer)?;
+                if read == 0 {
+                    break;
+                }
+                hasher.update(&buffer[..read]);
+            }
+        }
+    }
+    Ok(hasher.finalize_hex())
+}
+
+// 해시 액터
+// 계산은 spawn_blocking으로 넘겨 비동기 런타임을 막지 않는다.
+pub struct HashActor {
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for HashActor {}
+
+impl HashActor {
+    pub fn new(self_addr: Address<Self>) -> Self {
+        let owned_tasks = route_dart_signals!(self_addr, [HashRequest, VerifyHashRequest]);
+
+        Self {
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    async fn compute(algo: HashAlgorithm, source: HashSource) -> Result<String, HashError> {
+        tokio::task::spawn_blocking(move || hash_blocking(algo, &source)).await?
+    }
+
+    async fn verify(msg: VerifyHash) -> Result<(bool, String), HashError> {
+        let actual = Self::compute(msg.algo, msg.source).await?;
+        let matches = actual.eq_ignore_ascii_case(msg.expected_hex.trim());
+        Ok((matches, actual))
+    }
+}
+
+#[async_trait]
+impl Handler<ComputeHash> for HashActor {
+    type Result = Result<String, HashError>;
+
+    async fn handle(&mut self, msg: ComputeHash, _: &Context<Self>) -> Self::Result {
+        Self::compute(msg.algo, msg.source).await
+    }
+}
+
+#[async_trait]
+impl Handler<VerifyHash> for HashActor {
+    type Result = Result<bool, HashError>;
+
+    async fn handle(&mut self, msg: VerifyHash, _: &Context<Self>) -> Self::Result {
+        Self::verify(msg).await.map(|(matches, _)| matches)
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<HashRequest> for HashActor {
+    async fn notify(&mut self, msg: HashRequest, _: &Context<Self>) {
+        self._owned_tasks.spawn(async move {
+            let result = Self::compute(msg.algo, msg.source).await;
+            HashResultSignal {
+                request_id: msg.request_id,
+                algo: msg.algo,
+                hex_digest: result.as_ref().ok().cloned(),
+                error: result.err().map(|e| e.to_string()),
+            }
+            .emit();
+        });
+    }
+}
+
+#[async_trait]
+impl Notifiable<VerifyHashRequest> for HashActor {
+    async fn notify(&mut self, msg: VerifyHashRequest, _: &Context<Self>) {
+        self._owned_tasks.spawn(async move {
+            let result = Self::verify(VerifyHash {
+                algo: msg.algo,
+                source: msg.source,
+                expected_hex: msg.expected_hex,
+            })
+            .await;
+
+            match result {
+                Ok((matches, actual)) => {
+                    HashVerifiedSignal {
+                        request_id: msg.request_id,
+                        matches,
+                        actual_hex: Some(actual),
+                        error: None,
+                    }
+                    .emit();
+                }
+                Err(e) => {
+                    HashVerifiedSignal {
+                        request_id: msg.request_id,
+                        matches: false,
+                        actual_hex: None,
+                        error: Some(e.to_string()),
+                    }
+                    .emit();
+                }
+            }
+        });
+    }
+}
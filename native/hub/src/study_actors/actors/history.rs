@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+
+use crate::study_actors::messages::{DataItem, ItemRevision, UserId};
+
+const MAX_REVISIONS: usize = 20;
+const MAX_UNDO_DEPTH: usize = 50;
+
+// 수정 직전 상태를 전체 스냅샷으로 추가하고, 오래된 리비전부터 버린다
+pub(super) fn push_revision(revisions: &mut Vec<ItemRevision>, previous: &DataItem) {
+    let revision = revisions.last().map_or(1, |last| last.revision + 1);
+    revisions.push(ItemRevision {
+        revision,
+        title: previous.title.clone(),
+        content: previous.content.clone(),
+        updated_at: previous.updated_at,
+    });
+    if revisions.len() > MAX_REVISIONS {
+        let excess = revisions.len() - MAX_REVISIONS;
+        revisions.drain(..excess);
+    }
+}
+
+// 합쳐지는 항목들의 리비전을 시간순으로 끼워 넣고 번호를 1부터 다시 매긴다
+pub(super) fn merge_revisions(revisions: &mut Vec<ItemRevision>, others: Vec<ItemRevision>) {
+    revisions.extend(others);
+    revisions.sort_by_key(|r| r.updated_at);
+    for (index, revision) in revisions.iter_mut().enumerate() {
+        revision.revision = index as u64 + 1;
+    }
+    if revisions.len() > MAX_REVISIONS {
+        let excess = revisions.len() - MAX_REVISIONS;
+        revisions.drain(..excess);
+    }
+}
+
+// 사용자별 실행 취소/다시 실행 스택 (세션 동안만 유지)
+// 항목을 바꾸기 직전 상태를 쌓아 두고, 되돌릴 때 반대쪽 스택으로 옮긴다.
+#[derive(Default)]
+pub(super) struct UndoStack {
+    undo: HashMap<UserId, Vec<DataItem>>,
+    redo: HashMap<UserId, Vec<DataItem>>,
+}
+
+impl UndoStack {
+    // 새 변경이 생기면 다시 실행 기록은 무효가 된다
+    pub(super) fn record(&mut self, user_id: &str, previous: DataItem) {
+        Self::push(&mut self.undo, user_id, previous);
+        self.redo.remove(user_id);
+    }
+
+    pub(super) fn push_undo(&mut self, user_id: &str, item: DataItem) {
+        Self::push(&mut self.undo, user_id, item);
+    }
+
+    pub(super) fn push_redo(&mut self, user_id: &str, item: DataItem) {
+        Self::push(&mut self.redo, user_id, item);
+    }
+
+    pub(super) fn pop_undo(&mut self, user_id: &str) -> Option<DataItem> {
+        self.undo.get_mut(user_id)?.pop()
+    }
+
+    pub(super) fn pop_redo(&mut self, user_id: &str) -> Option<DataItem> {
+        self.redo.get_mut(user_id)?.pop()
+    }
+
+    pub(super) fn can_undo(&self, user_id: &str) -> bool {
+        self.undo
+            .get(user_id)
+            .is_some_and(|stack| !stack.is_empty())
+    }
+
+    pub(super) fn can_redo(&self, user_id: &str) -> bool {
+        self.redo
+            .get(user_id)
+            .is_some_and(|stack| !stack.is_empty())
+    }
+
+    // 삭제된 항목으로 되돌리지 않도록 양쪽 스택에서 제거
+    pub(super) fn forget_item(&mut self, item_id: &str) {
+        for stack in self.undo.values_mut().chain(self.redo.values_mut()) {
+            stack.retain(|item| item.id != item_id);
+        }
+    }
+
+    pub(super) fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+
+    fn push(stacks: &mut HashMap<UserId, Vec<DataItem>>, user_id: &str, item: DataItem) {
+        let stack = stacks.entry(user_id.to_string()).or_default();
+        stack.push(item);
+        if stack.len() > MAX_UNDO_DEPTH {
+            stack.remove(0);
+        }
+    }
+}
@@ -0,0 +1,464 @@
+use async_trait::async_trait;
+use fixed_decimal::Decimal;
+use icu_locale_core::Locale;
+use icu_plurals::{PluralCategory, PluralRules};
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use serde_json::Value;
+use std::collections::HashMap;
+use tokio::{sync::broadcast, task::JoinSet};
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        AppEvent, BundleSource, I18nConfig, I18nError, LoadLocaleBundle, TrafficClass, Translate,
+    },
+    signals::{
+        EmitSignal, LoadLocaleBundleRequest, LocaleBundleLoadedSignal, TranslateRequest,
+        TranslationResponse, route_dart_signals,
+    },
+};
+
+use super::{NetworkManagerActor, Priority, PriorityMailbox};
+use super::network::NetworkRequest;
+
+const PLURAL_CATEGORIES: [&str; 6] = ["zero", "one", "two", "few", "many", "other"];
+
+// 번역 항목 (단순 문자열 또는 복수형 분기)
+enum Translation {
+    Simple(String),
+    Plural(HashMap<String, String>),
+}
+
+// 현지화 액터
+pub struct I18nActor {
+    bundles: HashMap<String, HashMap<String, Translation>>,
+    config: I18nConfig,
+    network_manager: PriorityMailbox<NetworkManagerActor>,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for I18nActor {}
+
+impl I18nActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        network_manager: PriorityMailbox<NetworkManagerActor>,
+        config: I18nConfig,
+        events: broadcast::Receiver<AppEvent>,
+    ) -> Self {
+        let mut owned_tasks =
+            route_dart_signals!(self_addr, [LoadLocaleBundleRequest, TranslateRequest]);
+        owned_tasks.spawn(Self::listen_to_events(self_addr.clone(), events));
+
+        // 기본 로케일 번들을 미리 로드
+        if let Some(source) = Self::default_source(&config, &config.default_locale) {
+            owned_tasks.spawn(Self::preload(
+                self_addr,
+                LoadLocaleBundle {
+                    locale: config.default_locale.clone(),
+                    source,
+                },
+            ));
+        }
+
+        Self {
+            bundles: HashMap::new(),
+            config,
+            network_manager,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    // 설정 화면에서 기본 로케일이나 번들 위치를 바꾸면 바로 적용한다
+    async fn listen_to_events(
+        mut self_addr: Address<Self>,
+        mut events: broadcast::Receiver<AppEvent>,
+    ) {
+        loop {
+            match events.recv().await {
+                Ok(AppEvent::ConfigChanged { key, config })
+                    if key.split('.').next() == Some("i18n") =>
+                {
+                    let _ = self_addr
+                        .notify(I18nConfigChanged(config.i18n.clone()))
+                        .await;
+                }
+                Ok(_) => {}
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    debug_print!("I18n actor skipped {} app events", skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    }
+
+    async fn preload(mut self_addr: Address<Self>, msg: LoadLocaleBundle) {
+        let locale = msg.locale.clone();
+        if let Ok(Err(e)) = self_addr.send(msg).await {
+            debug_print!("Failed to preload locale bundle {}: {}", locale, e);
+        }
+    }
+
+    // 설정된 번들 디렉터리 또는 CDN에서 로케일 번들 위치 결정
+    fn default_source(config: &I18nConfig, locale: &str) -> Option<BundleSource> {
+        if let Some(dir) = &config.bundle_dir {
+            Some(BundleSource::File(format!("{}/{}.json", dir, locale)))
+        } else {
+            config
+                .cdn_base_url
+                .as_ref()
+                .map(|base| BundleSource::Remote(format!("{}/{}.json", base, locale)))
+        }
+    }
+
+    async fn fetch_bundle(&mut self, source: &BundleSource) -> Result<Value, I18nError> {
+        match source {
+            #[cfg(not(target_family = "wasm"))]
+            BundleSource::File(path) => {
+                let text = tokio::fs::read_to_string(path).await?;
+                Ok(serde_json::from_str(&text)?)
+            }
+            // 브라우저에서는 로컬 파일을 읽을 수 없으므로 cdn_base_url을 써야 한다
+            #[cfg(target_family = "wasm")]
+            BundleSource::File(path) => {
+                Err(format!("Local bundle {} is not available on the web", path).into())
+            }
+            BundleSource::Remote(url) => {
+                let response = self
+                    .network_manager
+                    .request(
+                        Priority::High,
+                        NetworkRequest::new(url.clone())
+                            .timeout(10_000)
+                            .traffic(TrafficClass::Downloads),
+                    )
+                    .await??;
+                if !response.is_success() {
+                    return Err(format!("Failed to download bundle: {}", response.status).into());
+                }
+                Ok(response.json::<Value>()?)
+            }
+        }
+    }
+
+    // 중첩된 JSON 객체를 "a.b.c" 형태의 키로 평탄화
+    fn flatten(prefix: &str, value: &Value, out: &mut HashMap<String, Translation>) {
+        let Value::Object(map) = value else {
+            return;
+        };
+        for (key, value) in map {
+            let full_key = if prefix.is_empty() {
+                key.clone()
+            } else {
+                format!("{}.{}", prefix, key)
+            };
+            match value {
+                Value::String(text) => {
+                    out.insert(full_key, Translation::Simple(text.clone()));
+                }
+                Value::Object(forms)
+                    if forms
+                        .keys()
+                        .all(|k| PLURAL_CATEGORIES.contains(&k.as_str())) =>
+                {
+                    let forms = forms
+                        .iter()
+                        .filter_map(|(k, v)| v.as_str().map(|v| (k.clone(), v.to_string())))
+                        .collect();
+                    out.insert(full_key, Translation::Plural(forms));
+                }
+                Value::Object(_) => Self::flatten(&full_key, value, out),
+                _ => {}
+            }
+        }
+    }
+
+    // 요청 로케일 → 언어 코드 → 기본 로케일 → 대체 로케일 순서의 폴백 체인
+    fn fallback_chain(config: &I18nConfig, locale: Option<&str>) -> Vec<String> {
+        let mut chain: Vec<String> = Vec::new();
+        let requested = locale.unwrap_or(&config.default_locale);
+        let mut candidates = vec![requested.to_string()];
+        let mut current = requested;
+        while let Some(pos) = current.rfind(['-', '_']) {
+            current = &current[..pos];
+            candidates.push(current.to_string());
+        }
+        candidates.push(config.default_locale.clone());
+        candidates.push(config.fallback_locale.clone());
+
+        for candidate in candidates {
+            if !chain.contains(&candidate) {
+                chain.push(candidate);
+            }
+        }
+        chain
+    }
+
+    // CLDR 복수형 규칙 (기수)
+    // count는 문자열 그대로 넘겨 "1"과 "1.0"처럼 소수 자릿수에 따라 달라지는 언어도 맞게 고른다.
+    // 규칙이 없는 로케일은 언어 코드로 내려가 찾고, 로케일이나 숫자를 읽지 못하면 other다.
+    fn plural_category(locale: &str, count: &str) -> &'static str {
+        let Ok(locale) = Locale::try_from_str(&locale.replace('_', "-")) else {
+            return "other";
+        };
+        let Ok(rules) = PluralRules::try_new_cardinal((&locale).into()) else {
+            return "other";
+        };
+        let Ok(count) = Decimal::try_from_str(count) else {
+            return "other";
+        };
+        match rules.category_for(&count) {
+            PluralCategory::Zero => "zero",
+            PluralCategory::One => "one",
+            PluralCategory::Two => "two",
+            PluralCategory::Few => "few",
+            PluralCategory::Many => "many",
+            PluralCategory::Other => "other",
+        }
+    }
+
+    fn interpolate(template: &str, args: &HashMap<String, String>) -> String {
+        args.iter()
+            .fold(template.to_string(), |text, (name, value)| {
+                text.replace(&format!("{{{}}}", name), value)
+            })
+    }
+
+    fn lookup(&self, msg: &Translate) -> Option<(String, String)> {
+        for locale in Self::fallback_chain(&self.config, msg.locale.as_deref()) {
+            let Some(entry) = self.bundles.get(&locale).and_then(|b| b.get(&msg.key)) else {
+                continue;
+            };
+            let template = match entry {
+                Translation::Simple(text) => text.clone(),
+                Translation::Plural(forms) => {
+                    let count = msg.args.get("count").map_or("0", String::as_str);
+                    let category = Self::plural_category(&locale, count);
+                    match forms.get(category).or_else(|| forms.get("other")) {
+                        Some(text) => text.clone(),
+                        None => continue,
+                    }
+                }
+            };
+            return Some((Self::interpolate(&template, &msg.args), locale));
+        }
+        None
+    }
+}
+
+// 내부 메시지 정의
+struct I18nConfigChanged(I18nConfig);
+
+// 새 기본 로케일의 번들이 아직 없으면 불러온다
+#[async_trait]
+impl Notifiable<I18nConfigChanged> for I18nActor {
+    async fn notify(&mut self, msg: I18nConfigChanged, ctx: &Context<Self>) {
+        self.config = msg.0;
+        let locale = self.config.default_locale.clone();
+        if self.bundles.contains_key(&locale) {
+            return;
+        }
+        let Some(source) = Self::default_source(&self.config, &locale) else {
+            return;
+        };
+        let load = LoadLocaleBundle {
+            locale: locale.clone(),
+            source,
+        };
+        if let Err(e) = self.handle(load, ctx).await {
+            debug_print!("Failed to load locale bundle {}: {}", locale, e);
+        }
+    }
+}
+
+// 다른 액터가 Dart에 보낼 문구를 현지화할 때 쓴다
+// 현지화 액터가 없거나 응답하지 않으면 기본 문구에 인자만 채워 돌려준다.
+pub async fn translate(i18n: Option<&mut Address<I18nActor>>, msg: Translate) -> String {
+    let offline = |msg: &Translate| {
+        I18nActor::interpolate(msg.fallback.as_ref().unwrap_or(&msg.key), &msg.args)
+    };
+    match i18n {
+        Some(i18n) => match i18n.send(msg.clone()).await {
+            Ok(text) => text,
+            Err(_) => offline(&msg),
+        },
+        None => offline(&msg),
+    }
+}
+
+#[async_trait]
+impl Handler<LoadLocaleBundle> for I18nActor {
+    type Result = Result<usize, I18nError>;
+
+    async fn handle(&mut self, msg: LoadLocaleBundle, _: &Context<Self>) -> Self::Result {
+        let raw = self.fetch_bundle(&msg.source).await?;
+        let mut bundle = HashMap::new();
+        Self::flatten("", &raw, &mut bundle);
+
+        let key_count = bundle.len();
+        debug_print!("Loaded locale bundle {} ({} keys)", msg.locale, key_count);
+        self.bundles.insert(msg.locale, bundle);
+        Ok(key_count)
+    }
+}
+
+#[async_trait]
+impl Handler<Translate> for I18nActor {
+    type Result = String;
+
+    async fn handle(&mut self, msg: Translate, _: &Context<Self>) -> Self::Result {
+        // 번역이 없으면 기본 문구를, 그것도 없으면 키를 그대로 반환
+        match self.lookup(&msg) {
+            Some((text, _)) => text,
+            None => Self::interpolate(msg.fallback.as_ref().unwrap_or(&msg.key), &msg.args),
+        }
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<LoadLocaleBundleRequest> for I18nActor {
+    async fn notify(&mut self, msg: LoadLocaleBundleRequest, ctx: &Context<Self>) {
+        let source = match (msg.path, msg.url) {
+            (Some(path), _) => Some(BundleSource::File(path)),
+            (None, Some(url)) => Some(BundleSource::Remote(url)),
+            (None, None) => Self::default_source(&self.config, &msg.locale),
+        };
+
+        let Some(source) = source else {
+            LocaleBundleLoadedSignal {
+                locale: msg.locale,
+                success: false,
+                key_count: 0,
+                error: Some("No bundle source configured".to_string()),
+            }
+            .emit();
+            return;
+        };
+
+        let locale = msg.locale.clone();
+        let result = self
+            .handle(
+                LoadLocaleBundle {
+                    locale: msg.locale,
+                    source,
+                },
+                ctx,
+            )
+            .await;
+
+        match result {
+            Ok(key_count) => {
+                LocaleBundleLoadedSignal {
+                    locale,
+                    success: true,
+                    key_count: key_count as u64,
+                    error: None,
+                }
+                .emit();
+            }
+            Err(e) => {
+                LocaleBundleLoadedSignal {
+                    locale,
+                    success: false,
+                    key_count: 0,
+                    error: Some(e.to_string()),
+                }
+                .emit();
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Notifiable<TranslateRequest> for I18nActor {
+    async fn notify(&mut self, msg: TranslateRequest, _: &Context<Self>) {
+        let translate = Translate {
+            key: msg.key,
+            args: msg.args,
+            locale: msg.locale,
+            fallback: None,
+        };
+        let (text, resolved_locale) = match self.lookup(&translate) {
+            Some((text, locale)) => (text, Some(locale)),
+            None => (translate.key.clone(), None),
+        };
+
+        TranslationResponse {
+            key: translate.key,
+            text,
+            resolved_locale,
+        }
+        .emit();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{I18nActor, translate};
+    use crate::study_actors::messages::{I18nConfig, Translate};
+
+    #[test]
+    fn fallback_chain_walks_up_to_the_configured_locales() {
+        let config = I18nConfig {
+            default_locale: "ko".to_string(),
+            fallback_locale: "en".to_string(),
+            ..I18nConfig::default()
+        };
+        let chain = I18nActor::fallback_chain(&config, Some("zh-Hant-TW"));
+        assert_eq!(chain, ["zh-Hant-TW", "zh-Hant", "zh", "ko", "en"]);
+
+        // 요청 로케일이 없으면 기본 로케일부터, 같은 로케일은 한 번만 찾는다
+        let chain = I18nActor::fallback_chain(&config, None);
+        assert_eq!(chain, ["ko", "en"]);
+        let chain = I18nActor::fallback_chain(&config, Some("en_US"));
+        assert_eq!(chain, ["en_US", "en", "ko"]);
+    }
+
+    #[test]
+    fn plural_category_follows_cldr_rules() {
+        let cases = [
+            ("en", "1", "one"),
+            ("en", "1.0", "other"),
+            ("en-GB", "2", "other"),
+            ("ko", "1", "other"),
+            ("fr", "0", "one"),
+            ("fr", "1.5", "one"),
+            ("pl", "1", "one"),
+            ("pl", "3", "few"),
+            ("pl", "5", "many"),
+            ("pl", "22", "few"),
+            ("pl", "1.5", "other"),
+            ("ru", "21", "one"),
+            ("ru", "11", "many"),
+            ("ru", "1.5", "other"),
+            ("ar", "0", "zero"),
+            ("ar", "2", "two"),
+            ("ar", "103", "few"),
+            ("ar", "111", "many"),
+            ("ar", "100", "other"),
+            ("xx", "1", "other"),
+            ("en", "abc", "other"),
+        ];
+        for (locale, count, expected) in cases {
+            let category = I18nActor::plural_category(locale, count);
+            assert_eq!(category, expected, "{} {}", locale, count);
+        }
+    }
+
+    #[tokio::test]
+    async fn translate_without_actor_fills_the_fallback() {
+        let request = Translate {
+            key: "reminder.due".to_string(),
+            args: HashMap::from([("title".to_string(), "Dentist".to_string())]),
+            locale: None,
+            fallback: Some("Reminder: {title}".to_string()),
+        };
+        assert_eq!(translate(None, request).await, "Reminder: Dentist");
+    }
+}
@@ -0,0 +1,211 @@
+use aes_gcm::aead::{OsRng, rand_core::RngCore};
+use async_trait::async_trait;
+use chrono::Utc;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use std::sync::{Mutex, PoisonError};
+use tokio::task::JoinSet;
+use ulid::Ulid;
+use uuid::Builder;
+
+use crate::study_actors::{
+    messages::{ActorResult, GenerateIds, IdKind, UserError},
+    signals::{GenerateIdsRequest, respond_to_dart, route_dart_signals},
+};
+
+const MAX_IDS_PER_REQUEST: u32 = 1000;
+// 시각 뒤에 붙는 순번 비트 수 (UUIDv7은 버전과 변형 비트를 뺀 나머지)
+const ULID_SEQUENCE_BITS: u32 = 80;
+const UUID_V7_SEQUENCE_BITS: u32 = 74;
+
+static ULID_SEQUENCE: Mutex<Sequence> = Mutex::new(Sequence::new());
+static UUID_V7_SEQUENCE: Mutex<Sequence> = Mutex::new(Sequence::new());
+
+// 마지막으로 만든 ID의 시각과 순번
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Sequence {
+    millis: u64,
+    value: u128,
+}
+
+impl Sequence {
+    const fn new() -> Self {
+        Self {
+            millis: 0,
+            value: 0,
+        }
+    }
+
+    // 새 밀리초면 난수에서 시작하고, 같은 밀리초(또는 기기 시계가 뒤로 간 경우)면 순번을 1 올린다
+    // 순번이 넘치면 다음 밀리초로 넘어간다. 새 순번은 최상위 비트를 비워 올릴 여유를 둔다.
+    fn next(&mut self, now: u64, bits: u32, random: u128) -> (u64, u128) {
+        let max = (1u128 << bits) - 1;
+        if now > self.millis {
+            self.millis = now;
+            self.value = random & (max >> 1);
+        } else if self.value < max {
+            self.value += 1;
+        } else {
+            self.millis += 1;
+            self.value = random & (max >> 1);
+        }
+        (self.millis, self.value)
+    }
+}
+
+fn next_in(sequence: &Mutex<Sequence>, millis: u64, bits: u32) -> (u64, u128) {
+    let mut random = [0u8; 16];
+    OsRng.fill_bytes(&mut random);
+    sequence
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .next(millis, bits, u128::from_be_bytes(random))
+}
+
+// 74비트 순번을 UUIDv7의 rand_a(12비트)와 rand_b(62비트) 자리에 맞춰 넣는다
+fn uuid_v7_counter_bytes(value: u128) -> [u8; 10] {
+    let mut bytes = [0u8; 10];
+    bytes[..2].copy_from_slice(&((value >> 62) as u16).to_be_bytes());
+    bytes[2..].copy_from_slice(&((value as u64) & ((1 << 62) - 1)).to_be_bytes());
+    bytes
+}
+
+// 새 ID 하나
+// ULID와 UUIDv7은 시각 뒤에 순번을 붙여 같은 밀리초에 여러 개를 만들어도 만든 순서대로 정렬되고 겹치지 않는다.
+pub fn generate_id(kind: IdKind) -> String {
+    let millis = Utc::now().timestamp_millis().max(0) as u64;
+    match kind {
+        IdKind::Ulid => {
+            let (millis, value) = next_in(&ULID_SEQUENCE, millis, ULID_SEQUENCE_BITS);
+            Ulid::from_parts(millis, value).to_string()
+        }
+        IdKind::UuidV7 => {
+            let (millis, value) = next_in(&UUID_V7_SEQUENCE, millis, UUID_V7_SEQUENCE_BITS);
+            Builder::from_unix_timestamp_millis(millis, &uuid_v7_counter_bytes(value))
+                .into_uuid()
+                .to_string()
+        }
+        IdKind::Token => {
+            let mut bytes = [0u8; 32];
+            OsRng.fill_bytes(&mut bytes);
+            hex::encode(bytes)
+        }
+    }
+}
+
+// 예전 ID를 같은 시각의 ULID로 바꿀 때 쓴다
+// 난수 대신 예전 ID의 해시를 쓰므로 마이그레이션을 다시 실행해도 같은 ID가 나온다.
+pub(super) fn ulid_from_seed(millis: u64, seed: &str) -> String {
+    let hash = blake3::hash(seed.as_bytes());
+    let mut random = [0u8; 16];
+    random[6..].copy_from_slice(&hash.as_bytes()[..10]);
+    Ulid::from_parts(millis, u128::from_be_bytes(random)).to_string()
+}
+
+// ID 생성 액터 (Rust 쪽은 generate_id를 직접 쓰고, Dart는 신호로 요청한다)
+pub struct IdGenActor {
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for IdGenActor {}
+
+impl IdGenActor {
+    pub fn new(self_addr: Address<Self>) -> Self {
+        Self {
+            _owned_tasks: route_dart_signals!(self_addr, [GenerateIdsRequest]),
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<GenerateIds> for IdGenActor {
+    type Result = ActorResult<Vec<String>>;
+
+    async fn handle(&mut self, msg: GenerateIds, _: &Context<Self>) -> Self::Result {
+        if msg.count == 0 || msg.count > MAX_IDS_PER_REQUEST {
+            return Err(UserError::InvalidInput(format!(
+                "count must be between 1 and {}",
+                MAX_IDS_PER_REQUEST
+            )));
+        }
+        Ok((0..msg.count).map(|_| generate_id(msg.kind)).collect())
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<GenerateIdsRequest> for IdGenActor {
+    async fn notify(&mut self, msg: GenerateIdsRequest, ctx: &Context<Self>) {
+        let _ = respond_to_dart(msg, |msg| async move {
+            let generate = GenerateIds {
+                count: msg.count,
+                kind: msg.kind,
+            };
+            Ok(self.handle(generate, ctx).await?)
+        })
+        .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{
+        Sequence, ULID_SEQUENCE_BITS, UUID_V7_SEQUENCE_BITS, generate_id, uuid_v7_counter_bytes,
+    };
+    use crate::study_actors::messages::IdKind;
+
+    #[test]
+    fn ids_are_monotonic_within_a_millisecond() {
+        let mut sequence = Sequence::new();
+        let first = sequence.next(1_000, ULID_SEQUENCE_BITS, u128::MAX);
+        let second = sequence.next(1_000, ULID_SEQUENCE_BITS, 0);
+        // 기기 시계가 뒤로 가도 앞선 ID보다 작아지지 않는다
+        let third = sequence.next(999, ULID_SEQUENCE_BITS, 0);
+        assert_eq!(second, (1_000, first.1 + 1));
+        assert_eq!(third, (1_000, first.1 + 2));
+
+        for kind in [IdKind::Ulid, IdKind::UuidV7] {
+            let ids: Vec<String> = (0..1_000).map(|_| generate_id(kind)).collect();
+            assert!(ids.windows(2).all(|pair| pair[0] < pair[1]), "{:?}", kind);
+        }
+    }
+
+    #[test]
+    fn sequence_overflow_rolls_over_to_the_next_tick() {
+        for bits in [ULID_SEQUENCE_BITS, UUID_V7_SEQUENCE_BITS] {
+            let max = (1u128 << bits) - 1;
+            let mut sequence = Sequence {
+                millis: 1_000,
+                value: max,
+            };
+            let (millis, value) = sequence.next(1_000, bits, u128::MAX);
+            assert_eq!(millis, 1_001);
+            assert!(value < max);
+        }
+    }
+
+    #[test]
+    fn uuid_v7_counter_keeps_every_sequence_bit() {
+        let max = (1u128 << UUID_V7_SEQUENCE_BITS) - 1;
+        // rand_b가 넘쳐 rand_a로 올라가는 자리와 맨 끝에서도 순서가 유지된다
+        for value in [(1 << 62) - 1, max - 1] {
+            let uuid = |value| {
+                uuid::Builder::from_unix_timestamp_millis(1, &uuid_v7_counter_bytes(value))
+                    .into_uuid()
+            };
+            assert!(uuid(value) < uuid(value + 1));
+        }
+    }
+
+    #[test]
+    fn ids_are_unique_across_calls() {
+        for kind in [IdKind::Ulid, IdKind::UuidV7, IdKind::Token] {
+            let ids: HashSet<String> = (0..10_000).map(|_| generate_id(kind)).collect();
+            assert_eq!(ids.len(), 10_000, "{:?}", kind);
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::collections::HashMap;
+
+use crate::study_actors::messages::UserError;
+
+// 신호 재전송이나 두 번 누르기는 보통 몇 초 안에 오고, 앱을 곧바로 다시 켜도 이 안이다
+const WINDOW_SECS: u64 = 10 * 60;
+const MAX_KEYS: usize = 1000;
+
+// 최근에 처리한 Dart 요청의 멱등 키와 그 결과
+// 창이 지난 키는 기록할 때 정리하고, 너무 많이 쌓이면 오래된 것부터 잊는다.
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct IdempotencyWindow<T> {
+    entries: HashMap<String, IdempotencyEntry<T>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct IdempotencyEntry<T> {
+    outcome: T,
+    seen_at: u64,
+}
+
+impl<T> Default for IdempotencyWindow<T> {
+    fn default() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+}
+
+impl<T: Serialize + DeserializeOwned> IdempotencyWindow<T> {
+    pub(super) fn from_bytes(bytes: &[u8]) -> Result<Self, UserError> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+
+    pub(super) fn to_bytes(&self) -> Result<Vec<u8>, UserError> {
+        Ok(serde_json::to_vec(self)?)
+    }
+}
+
+impl<T> IdempotencyWindow<T> {
+    // 창 안에서 같은 키로 처리한 적이 있으면 그때의 결과
+    pub(super) fn get(&self, key: &str, now: u64) -> Option<&T> {
+        self.entries
+            .get(key)
+            .filter(|entry| now.saturating_sub(entry.seen_at) < WINDOW_SECS)
+            .map(|entry| &entry.outcome)
+    }
+
+    pub(super) fn record(&mut self, key: String, outcome: T, now: u64) {
+        self.entries
+            .retain(|_, entry| now.saturating_sub(entry.seen_at) < WINDOW_SECS);
+        if self.entries.len() >= MAX_KEYS
+            && let Some(oldest) = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.seen_at)
+                .map(|(key, _)| key.clone())
+        {
+            self.entries.remove(&oldest);
+        }
+        self.entries.insert(
+            key,
+            IdempotencyEntry {
+                outcome,
+                seen_at: now,
+            },
+        );
+    }
+}
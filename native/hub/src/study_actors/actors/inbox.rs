@@ -0,0 +1,383 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use std::cmp::Reverse;
+use tokio::task::JoinSet;
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        FetchData, FetchMode, IdKind, InboxError, InboxNotification, ListNotifications,
+        MarkNotificationsRead, NotificationConfig, NotificationPage, PostNotification, StoreData,
+        WipeUserData,
+    },
+    signals::{
+        EmitSignal, InboxBadgeSignal, ListNotificationsRequest, MarkReadRequest,
+        NotificationListSignal, NotificationReceivedSignal, route_dart_signals,
+    },
+};
+
+use super::{StorageActor, TrustedClock, generate_id};
+
+const INBOX_KEY: &str = "inbox/notifications";
+const SECS_PER_DAY: u64 = 24 * 60 * 60;
+
+// 받은 알림함 액터
+// 푸시, 동기화, 시스템 메시지로 들어온 알림을 읽음 상태와 함께 보관하고 안 읽은 수를 배지로 알린다.
+// 보관 기간이 지났거나 개수 한도를 넘은 알림은 오래된 것부터 지운다.
+pub struct InboxActor {
+    notifications: Vec<InboxNotification>, // 최신 순
+    max_items: usize,
+    retention_secs: u64,
+    storage: Address<StorageActor>,
+    clock: TrustedClock,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for InboxActor {}
+
+impl InboxActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        config: NotificationConfig,
+        storage: Address<StorageActor>,
+        clock: TrustedClock,
+    ) -> Self {
+        let mut owned_tasks =
+            route_dart_signals!(self_addr, [ListNotificationsRequest, MarkReadRequest]);
+        owned_tasks.spawn(Self::load_inbox(self_addr));
+
+        Self {
+            notifications: Vec::new(),
+            max_items: config.inbox_max_items.max(1),
+            retention_secs: config.inbox_retention_days * SECS_PER_DAY,
+            storage,
+            clock,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    async fn load_inbox(mut self_addr: Address<Self>) {
+        let _ = self_addr.notify(LoadInbox).await;
+    }
+
+    fn unread(&self) -> usize {
+        self.notifications.iter().filter(|n| !n.read).count()
+    }
+
+    // 보관 기간과 개수 한도를 넘은 알림 정리
+    fn prune(&mut self) {
+        let cutoff = self.clock.trusted_now().saturating_sub(self.retention_secs);
+        self.notifications.retain(|n| n.received_at >= cutoff);
+        self.notifications.truncate(self.max_items);
+    }
+
+    async fn persist(&mut self) -> Result<(), InboxError> {
+        let request = StoreData {
+            key: INBOX_KEY.to_string(),
+            data: serde_json::to_vec(&self.notifications)?,
+            user_id: None,
+            ttl: None,
+        };
+        self.storage.send(request).await??;
+        Ok(())
+    }
+
+    fn page(&self, msg: &ListNotifications) -> NotificationPage {
+        let matching: Vec<&InboxNotification> = self
+            .notifications
+            .iter()
+            .filter(|n| !msg.unread_only || !n.read)
+            .collect();
+        NotificationPage {
+            notifications: matching
+                .iter()
+                .skip(msg.offset)
+                .take(msg.limit)
+                .map(|n| (*n).clone())
+                .collect(),
+            total: matching.len(),
+            unread: self.unread(),
+        }
+    }
+}
+
+// 내부 메시지 정의
+struct LoadInbox;
+
+#[async_trait]
+impl Notifiable<LoadInbox> for InboxActor {
+    async fn notify(&mut self, _: LoadInbox, _: &Context<Self>) {
+        let request = FetchData {
+            key: INBOX_KEY.to_string(),
+            user_id: None,
+            mode: FetchMode::CacheFirst,
+        };
+        let stored = match self.storage.send(request).await {
+            Ok(Ok(bytes)) => serde_json::from_slice::<Vec<InboxNotification>>(&bytes)
+                .unwrap_or_else(|e| {
+                    debug_print!("Invalid stored inbox: {}", e);
+                    Vec::new()
+                }),
+            // 저장된 알림함이 없으면 빈 알림함
+            Ok(Err(_)) => Vec::new(),
+            Err(e) => {
+                debug_print!("Failed to load inbox: {}", e);
+                Vec::new()
+            }
+        };
+
+        // 불러오기 전에 들어온 알림이 우선
+        for notification in stored {
+            if !self.notifications.iter().any(|n| n.id == notification.id) {
+                self.notifications.push(notification);
+            }
+        }
+        self.notifications
+            .sort_by_key(|notification| Reverse(notification.received_at));
+        self.prune();
+        InboxBadgeSignal {
+            unread: self.unread() as u64,
+        }
+        .emit();
+    }
+}
+
+#[async_trait]
+impl Handler<PostNotification> for InboxActor {
+    type Result = Result<InboxNotification, InboxError>;
+
+    async fn handle(&mut self, msg: PostNotification, _: &Context<Self>) -> Self::Result {
+        let id = msg.id.unwrap_or_else(|| generate_id(IdKind::Ulid));
+        let read = match self.notifications.iter().position(|n| n.id == id) {
+            Some(index) => self.notifications.remove(index).read,
+            None => false,
+        };
+        let notification = InboxNotification {
+            id,
+            source: msg.source,
+            title: msg.title,
+            body: msg.body,
+            link: msg.link,
+            received_at: self.clock.trusted_now(),
+            read,
+        };
+        self.notifications.insert(0, notification.clone());
+        self.prune();
+        self.persist().await?;
+
+        NotificationReceivedSignal {
+            notification: notification.clone(),
+            unread: self.unread() as u64,
+        }
+        .emit();
+        Ok(notification)
+    }
+}
+
+#[async_trait]
+impl Handler<ListNotifications> for InboxActor {
+    type Result = NotificationPage;
+
+    async fn handle(&mut self, msg: ListNotifications, _: &Context<Self>) -> Self::Result {
+        self.page(&msg)
+    }
+}
+
+#[async_trait]
+impl Handler<MarkNotificationsRead> for InboxActor {
+    type Result = Result<usize, InboxError>;
+
+    async fn handle(&mut self, msg: MarkNotificationsRead, _: &Context<Self>) -> Self::Result {
+        let mut changed = false;
+        for notification in self.notifications.iter_mut() {
+            if !notification.read && (msg.ids.is_empty() || msg.ids.contains(&notification.id)) {
+                notification.read = true;
+                changed = true;
+            }
+        }
+        let unread = self.unread();
+        if changed {
+            self.persist().await?;
+            InboxBadgeSignal {
+                unread: unread as u64,
+            }
+            .emit();
+        }
+        Ok(unread)
+    }
+}
+
+#[async_trait]
+impl Handler<WipeUserData> for InboxActor {
+    type Result = Result<(), InboxError>;
+
+    async fn handle(&mut self, _: WipeUserData, _: &Context<Self>) -> Self::Result {
+        // 저장된 알림함은 저장소 삭제 단계에서 함께 지워진다
+        self.notifications.clear();
+        InboxBadgeSignal { unread: 0 }.emit();
+        Ok(())
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<ListNotificationsRequest> for InboxActor {
+    async fn notify(&mut self, msg: ListNotificationsRequest, _: &Context<Self>) {
+        let page = self.page(&ListNotifications {
+            offset: msg.offset as usize,
+            limit: msg.limit as usize,
+            unread_only: msg.unread_only,
+        });
+        NotificationListSignal {
+            notifications: page.notifications,
+            total: page.total as u64,
+            unread: page.unread as u64,
+        }
+        .emit();
+    }
+}
+
+#[async_trait]
+impl Notifiable<MarkReadRequest> for InboxActor {
+    async fn notify(&mut self, msg: MarkReadRequest, ctx: &Context<Self>) {
+        let request = MarkNotificationsRead { ids: msg.ids };
+        if let Err(e) = self.handle(request, ctx).await {
+            debug_print!("Failed to save inbox read state: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use super::*;
+    use crate::study_actors::{
+        messages::InboxSource,
+        storage::MemoryStorage,
+        test_support::{TestActorHarness, settle},
+    };
+
+    fn start_storage() -> TestActorHarness<StorageActor> {
+        TestActorHarness::start(|_| StorageActor::new(Arc::new(MemoryStorage::new())))
+    }
+
+    fn start_inbox(
+        storage: &TestActorHarness<StorageActor>,
+        clock: &TrustedClock,
+        inbox_max_items: usize,
+    ) -> TestActorHarness<InboxActor> {
+        let config = NotificationConfig {
+            inbox_max_items,
+            inbox_retention_days: 1,
+            ..NotificationConfig::default()
+        };
+        TestActorHarness::start(|addr| InboxActor::new(addr, config, storage.addr(), clock.clone()))
+    }
+
+    fn post(id: &str) -> PostNotification {
+        PostNotification {
+            id: Some(id.to_string()),
+            source: InboxSource::Push,
+            title: format!("title {id}"),
+            body: String::new(),
+            link: None,
+        }
+    }
+
+    fn list(unread_only: bool) -> ListNotifications {
+        ListNotifications {
+            offset: 0,
+            limit: 10,
+            unread_only,
+        }
+    }
+
+    fn ids(page: &NotificationPage) -> Vec<&str> {
+        page.notifications.iter().map(|n| n.id.as_str()).collect()
+    }
+
+    fn badges(harness: &mut TestActorHarness<InboxActor>) -> Vec<u64> {
+        let signals = harness.signals_of::<InboxBadgeSignal>();
+        signals.into_iter().map(|signal| signal.unread).collect()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn old_and_overflowing_notifications_are_pruned() -> Result<(), InboxError> {
+        let storage = start_storage();
+        let clock = TrustedClock::new();
+        let mut inbox = start_inbox(&storage, &clock, 2);
+        settle().await;
+        for id in ["a", "b", "c"] {
+            inbox.send(post(id)).await??;
+        }
+        let page = inbox.send(list(false)).await?;
+        assert_eq!(ids(&page), ["c", "b"]);
+        assert_eq!(page.total, 2);
+
+        // 보관 기간이 지난 알림은 다음 알림이 들어올 때 읽지 않았어도 지운다
+        inbox.advance(Duration::from_secs(SECS_PER_DAY + 1)).await;
+        inbox.send(post("d")).await??;
+        let page = inbox.send(list(false)).await?;
+        assert_eq!(ids(&page), ["d"]);
+        assert_eq!(page.unread, 1);
+
+        drop(inbox);
+
+        // 저장된 알림함을 다시 불러오면 안 읽은 수를 배지로 알린다
+        let mut reloaded = start_inbox(&storage, &clock, 2);
+        settle().await;
+        assert_eq!(badges(&mut reloaded), [1]);
+        assert_eq!(ids(&reloaded.send(list(false)).await?), ["d"]);
+        drop(reloaded);
+
+        // 불러올 때도 보관 기간을 넘긴 알림은 버린다
+        storage.advance(Duration::from_secs(SECS_PER_DAY + 1)).await;
+        let mut reloaded = start_inbox(&storage, &clock, 2);
+        settle().await;
+        assert_eq!(badges(&mut reloaded), [0]);
+        assert_eq!(reloaded.send(list(false)).await?.total, 0);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn badge_follows_read_state() -> Result<(), InboxError> {
+        let storage = start_storage();
+        let clock = TrustedClock::new();
+        let mut inbox = start_inbox(&storage, &clock, 10);
+        settle().await;
+        inbox.drain_signals();
+        inbox.send(post("a")).await??;
+        inbox.send(post("b")).await??;
+        let received = inbox.signals_of::<NotificationReceivedSignal>();
+        let unread: Vec<u64> = received.iter().map(|signal| signal.unread).collect();
+        assert_eq!(unread, [1, 2]);
+
+        let read_a = MarkNotificationsRead {
+            ids: vec!["a".to_string()],
+        };
+        assert_eq!(inbox.send(read_a.clone()).await??, 1);
+        assert_eq!(badges(&mut inbox), [1]);
+        // 이미 읽은 알림을 다시 읽어도 배지는 그대로라 알리지 않는다
+        assert_eq!(inbox.send(read_a).await??, 1);
+        assert!(badges(&mut inbox).is_empty());
+
+        // 같은 id로 내용이 바뀌어도 읽음 상태는 남는다
+        inbox.send(post("a")).await??;
+        let page = inbox.send(list(true)).await?;
+        assert_eq!(ids(&page), ["b"]);
+        assert_eq!(page.unread, 1);
+
+        let read_all = MarkNotificationsRead { ids: Vec::new() };
+        assert_eq!(inbox.send(read_all).await??, 0);
+        assert_eq!(badges(&mut inbox), [0]);
+
+        inbox.send(WipeUserData).await??;
+        assert_eq!(badges(&mut inbox), [0]);
+        assert_eq!(inbox.send(list(false)).await?.total, 0);
+        Ok(())
+    }
+}
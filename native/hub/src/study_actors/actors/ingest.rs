@@ -0,0 +1,343 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use reqwest::Url;
+use std::{fmt::Display, fs::File, io::Read, path::Path};
+use tokio::task::JoinSet;
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        ActorResult, AppError, AttachBytes, AttachFile, CreateDataItem, DataItem, ErrorEnvelope,
+        FetchLinkPreview, IngestConfig, IngestSharedContent, IngestedContent, LinkPreview,
+        SharedContentKind, UserError,
+    },
+    signals::{
+        EmitSignal, IngestSharedContentRequest, ReceiveBinarySignal, SharedContentIngestedSignal,
+    },
+};
+
+use super::{AttachmentActor, DataManagerActor, LinkPreviewActor};
+
+// 형식을 확인할 때 읽는 파일 앞부분
+const SNIFF_BYTES: usize = 16;
+const MAX_TITLE_CHARS: usize = 80;
+
+// 매직 바이트로 썸네일을 만들 수 있는 이미지인지 확인 (MIME 타입과 기본 확장자)
+fn sniff_image(header: &[u8]) -> Option<(&'static str, &'static str)> {
+    if header.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some(("image/png", "png"))
+    } else if header.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some(("image/jpeg", "jpg"))
+    } else if header.starts_with(b"GIF87a") || header.starts_with(b"GIF89a") {
+        Some(("image/gif", "gif"))
+    } else if header.len() >= 12 && header.starts_with(b"RIFF") && &header[8..12] == b"WEBP" {
+        Some(("image/webp", "webp"))
+    } else if header.starts_with(b"BM") {
+        Some(("image/bmp", "bmp"))
+    } else {
+        None
+    }
+}
+
+// 텍스트 전체가 http(s) 링크 하나일 때만 링크로 본다 (문장 속 링크는 텍스트 그대로 둔다)
+fn shared_url(text: &str) -> Option<Url> {
+    if text.chars().any(char::is_whitespace) {
+        return None;
+    }
+    let url = Url::parse(text).ok()?;
+    matches!(url.scheme(), "http" | "https").then_some(url)
+}
+
+// 첫 줄을 제목으로 (길면 자른다)
+fn title_from_text(text: &str) -> String {
+    let line = text
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .unwrap_or_default();
+    line.chars().take(MAX_TITLE_CHARS).collect()
+}
+
+fn attach_error(error: impl Display) -> UserError {
+    UserError::Upstream(format!("Failed to attach shared file: {}", error))
+}
+
+// 경로로 받은 파일의 크기를 확인하고 앞부분을 읽는다
+fn read_header_blocking(path: &str, max_bytes: u64) -> ActorResult<Vec<u8>> {
+    let open_error =
+        |e: std::io::Error| UserError::InvalidInput(format!("Cannot read shared file: {}", e));
+    let file = File::open(path).map_err(open_error)?;
+    let size = file.metadata().map_err(open_error)?.len();
+    if size > max_bytes {
+        return Err(UserError::InvalidInput(format!(
+            "Shared file is larger than {} bytes",
+            max_bytes
+        )));
+    }
+    let mut header = Vec::with_capacity(SNIFF_BYTES);
+    file.take(SNIFF_BYTES as u64)
+        .read_to_end(&mut header)
+        .map_err(open_error)?;
+    Ok(header)
+}
+
+// 공유 시트 액터
+// 운영체제가 앱으로 공유한 내용을 살펴 종류에 맞게 처리하고 새 항목으로 만든다.
+// 이미지와 파일은 첨부로 달고(이미지는 첨부 액터가 썸네일을 만든다), 링크는 미리보기 액터에서
+// 제목과 설명을 받으며(받지 못해도 링크는 저장한다), 텍스트는 첫 줄을 제목으로 쓴다.
+pub struct IngestActor {
+    config: IngestConfig,
+    data: Address<DataManagerActor>,
+    attachments: Address<AttachmentActor>,
+    link_preview: Address<LinkPreviewActor>,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for IngestActor {}
+
+impl IngestActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        config: IngestConfig,
+        data: Address<DataManagerActor>,
+        attachments: Address<AttachmentActor>,
+        link_preview: Address<LinkPreviewActor>,
+    ) -> Self {
+        let mut owned_tasks = JoinSet::new();
+        owned_tasks.spawn(Self::listen_to_ingest_requests(self_addr));
+
+        Self {
+            config,
+            data,
+            attachments,
+            link_preview,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    async fn listen_to_ingest_requests(mut self_addr: Address<Self>) {
+        let receiver = IngestSharedContentRequest::binary_signal_receiver();
+        while let Some(signal_pack) = receiver.recv().await {
+            let _ = self_addr
+                .notify(DartIngest(signal_pack.message, signal_pack.binary))
+                .await;
+        }
+    }
+
+    async fn read_header(&self, path: &str) -> ActorResult<Vec<u8>> {
+        let path = path.to_string();
+        let max_bytes = self.config.max_bytes;
+        tokio::task::spawn_blocking(move || read_header_blocking(&path, max_bytes))
+            .await
+            .map_err(|e| UserError::Upstream(format!("Shared file check failed: {}", e)))?
+    }
+
+    async fn create_item(
+        &mut self,
+        user_id: &str,
+        title: String,
+        content: String,
+    ) -> ActorResult<DataItem> {
+        let create = CreateDataItem {
+            user_id: user_id.to_string(),
+            title,
+            content,
+            tags: Vec::new(),
+        };
+        self.data.send(create).await?
+    }
+
+    // 첨부에 실패해도 만든 항목은 남는다 (Dart는 오류를 받고 다시 첨부하거나 항목을 지울 수 있다)
+    async fn ingest_file(
+        &mut self,
+        msg: IngestSharedContent,
+        image: Option<(&'static str, &'static str)>,
+    ) -> ActorResult<DataItem> {
+        let file_name = msg
+            .file_name
+            .or_else(|| {
+                let name = Path::new(msg.path.as_deref()?).file_name()?;
+                Some(name.to_string_lossy().to_string())
+            })
+            .unwrap_or_else(|| match image {
+                Some((_, extension)) => format!("shared.{}", extension),
+                None => "shared".to_string(),
+            });
+        let content = msg.text.unwrap_or_default();
+        let mut item = self
+            .create_item(&msg.user_id, file_name.clone(), content)
+            .await?;
+
+        let attached = match msg.path {
+            Some(path) if msg.bytes.is_empty() => {
+                let attach = AttachFile {
+                    item_id: item.id.clone(),
+                    path,
+                };
+                self.attachments.send(attach).await
+            }
+            _ => {
+                let attach = AttachBytes {
+                    item_id: item.id.clone(),
+                    file_name,
+                    mime_type: image.map(|(mime, _)| mime.to_string()).or(msg.mime),
+                    bytes: msg.bytes,
+                };
+                self.attachments.send(attach).await
+            }
+        };
+        let attachment = attached?.map_err(attach_error)?;
+        item.attachments.push(attachment);
+        Ok(item)
+    }
+
+    // 페이지를 가져오지 못하면 빈 미리보기 (오프라인에서 공유한 링크도 저장한다)
+    async fn fetch_preview(&mut self, url: &Url) -> LinkPreview {
+        let fetch = FetchLinkPreview {
+            url: url.to_string(),
+        };
+        match self.link_preview.send(fetch).await {
+            Ok(Ok(preview)) => preview,
+            Ok(Err(e)) => {
+                debug_print!("Failed to preview shared link: {}", e);
+                LinkPreview::default()
+            }
+            Err(e) => {
+                debug_print!("Failed to preview shared link: {}", e);
+                LinkPreview::default()
+            }
+        }
+    }
+
+    async fn ingest_url(&mut self, user_id: &str, url: Url) -> ActorResult<DataItem> {
+        let preview = self.fetch_preview(&url).await;
+        let title = preview
+            .title
+            .unwrap_or_else(|| url.host_str().unwrap_or(url.as_str()).to_string());
+        let content = match preview.description {
+            Some(description) => format!("{}\n\n{}", url, description),
+            None => url.to_string(),
+        };
+        self.create_item(user_id, title, content).await
+    }
+}
+
+// 내부 메시지 정의
+struct DartIngest(IngestSharedContentRequest, Vec<u8>);
+
+#[async_trait]
+impl Handler<IngestSharedContent> for IngestActor {
+    type Result = ActorResult<IngestedContent>;
+
+    async fn handle(&mut self, msg: IngestSharedContent, _: &Context<Self>) -> Self::Result {
+        if msg.bytes.len() as u64 > self.config.max_bytes {
+            return Err(UserError::InvalidInput(format!(
+                "Shared file is larger than {} bytes",
+                self.config.max_bytes
+            )));
+        }
+
+        let header = if !msg.bytes.is_empty() {
+            Some(msg.bytes[..msg.bytes.len().min(SNIFF_BYTES)].to_vec())
+        } else if let Some(path) = &msg.path {
+            Some(self.read_header(path).await?)
+        } else {
+            None
+        };
+        if let Some(header) = header {
+            let image = sniff_image(&header);
+            let kind = match image {
+                Some(_) => SharedContentKind::Image,
+                None => SharedContentKind::File,
+            };
+            let item = self.ingest_file(msg, image).await?;
+            return Ok(IngestedContent { kind, item });
+        }
+
+        let text = msg
+            .text
+            .as_deref()
+            .map(str::trim)
+            .filter(|text| !text.is_empty())
+            .ok_or_else(|| UserError::InvalidInput("Shared content is empty".to_string()))?;
+        match shared_url(text) {
+            Some(url) => {
+                let item = self.ingest_url(&msg.user_id, url).await?;
+                Ok(IngestedContent {
+                    kind: SharedContentKind::Url,
+                    item,
+                })
+            }
+            None => {
+                let item = self
+                    .create_item(&msg.user_id, title_from_text(text), text.to_string())
+                    .await?;
+                Ok(IngestedContent {
+                    kind: SharedContentKind::Text,
+                    item,
+                })
+            }
+        }
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<DartIngest> for IngestActor {
+    async fn notify(&mut self, msg: DartIngest, ctx: &Context<Self>) {
+        let DartIngest(request, bytes) = msg;
+        let correlation_id = request.correlation_id;
+        let ingest = IngestSharedContent {
+            user_id: request.user_id,
+            mime: request.mime,
+            path: request.path,
+            bytes,
+            file_name: request.file_name,
+            text: request.text,
+        };
+        let signal = match self.handle(ingest, ctx).await {
+            Ok(ingested) => SharedContentIngestedSignal {
+                correlation_id,
+                kind: Some(ingested.kind),
+                item: Some(ingested.item),
+                error: None,
+            },
+            Err(e) => {
+                debug_print!("Shared content was not ingested: {}", e);
+                SharedContentIngestedSignal {
+                    correlation_id,
+                    kind: None,
+                    item: None,
+                    error: Some(ErrorEnvelope::from(&AppError::from(e))),
+                }
+            }
+        };
+        signal.emit();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sniffs_shared_files_links_and_text() {
+        assert_eq!(
+            sniff_image(b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR"),
+            Some(("image/png", "png"))
+        );
+        assert_eq!(
+            sniff_image(b"RIFF\x10\0\0\0WEBPVP8 "),
+            Some(("image/webp", "webp"))
+        );
+        assert_eq!(sniff_image(b"%PDF-1.7\n"), None);
+
+        assert!(shared_url("https://example.com/a?b=c").is_some());
+        assert!(shared_url("see https://example.com").is_none());
+        assert!(shared_url("javascript:alert(1)").is_none());
+        assert_eq!(title_from_text("\n  Groceries  \nmilk\neggs"), "Groceries");
+    }
+}
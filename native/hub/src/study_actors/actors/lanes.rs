@@ -0,0 +1,182 @@
+use futures_util::future::BoxFuture;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Handler},
+};
+use thiserror::Error;
+use tokio::sync::{mpsc, oneshot};
+
+const LANE_CAPACITY: usize = 256;
+// 저우선 메시지가 기다리는 동안 연속으로 처리할 수 있는 고우선 메시지 수
+const MAX_HIGH_STREAK: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Priority {
+    High, // 화면이 기다리는 요청
+    Low,  // 일괄 가져오기, 백그라운드 동기화 등
+}
+
+// 우선순위 메일박스를 거쳐 전달되는 메시지
+// 전달자가 처리 완료까지 기다릴 수 있도록 Notifiable 메시지도 Handler로 감싼다.
+// HTTP 왕복처럼 오래 걸리는 일은 핸들러가 작업으로 넘기고 oneshot 수신자를 돌려준다 (request 참고).
+pub struct Prioritized<M>(pub M);
+
+#[derive(Debug, Error)]
+#[error("Priority mailbox closed")]
+pub struct MailboxClosed;
+
+type Job<A> = Box<dyn FnOnce(Address<A>) -> BoxFuture<'static, ()> + Send>;
+
+// 두 갈래 메일박스
+// 액터 메일박스에는 한 번에 하나씩만 넘기므로 고우선 메시지가 쌓인 일괄 작업을 앞지른다.
+pub struct PriorityMailbox<A: Actor> {
+    high: mpsc::Sender<Job<A>>,
+    low: mpsc::Sender<Job<A>>,
+}
+
+impl<A: Actor> Clone for PriorityMailbox<A> {
+    fn clone(&self) -> Self {
+        Self {
+            high: self.high.clone(),
+            low: self.low.clone(),
+        }
+    }
+}
+
+impl<A: Actor> PriorityMailbox<A> {
+    // 전달 작업은 모든 사본이 버려지면 스스로 끝난다
+    pub fn new(addr: Address<A>) -> Self {
+        let (high, high_receiver) = mpsc::channel(LANE_CAPACITY);
+        let (low, low_receiver) = mpsc::channel(LANE_CAPACITY);
+        tokio::spawn(Self::forward(addr, high_receiver, low_receiver));
+        Self { high, low }
+    }
+
+    async fn forward(
+        addr: Address<A>,
+        mut high: mpsc::Receiver<Job<A>>,
+        mut low: mpsc::Receiver<Job<A>>,
+    ) {
+        let mut high_streak = 0;
+        loop {
+            // 고우선이 계속 밀려와도 저우선이 굶지 않도록 일정 횟수마다 양보
+            let job = if high_streak >= MAX_HIGH_STREAK
+                && let Ok(job) = low.try_recv()
+            {
+                high_streak = 0;
+                job
+            } else {
+                tokio::select! {
+                    biased;
+                    Some(job) = high.recv() => {
+                        high_streak += 1;
+                        job
+                    }
+                    Some(job) = low.recv() => {
+                        high_streak = 0;
+                        job
+                    }
+                    else => break,
+                }
+            };
+            // 핸들러가 돌아올 때까지만 기다린다 (결과를 나중에 보내는 핸들러는 곧바로 돌아온다)
+            job(addr.clone()).await;
+        }
+    }
+
+    async fn push(&self, priority: Priority, job: Job<A>) -> Result<(), MailboxClosed> {
+        let lane = match priority {
+            Priority::High => &self.high,
+            Priority::Low => &self.low,
+        };
+        lane.send(job).await.map_err(|_| MailboxClosed)
+    }
+
+    // 대기열에 넣기만 하고 결과는 버린다 (대기열이 가득 차면 자리가 날 때까지 기다림)
+    pub async fn notify<M>(&self, priority: Priority, msg: M) -> Result<(), MailboxClosed>
+    where
+        A: Handler<Prioritized<M>>,
+        <A as Handler<Prioritized<M>>>::Result: Send + Sync,
+        M: Send + 'static,
+    {
+        let job: Job<A> = Box::new(move |mut addr| {
+            Box::pin(async move {
+                let _ = addr.send(Prioritized(msg)).await;
+            })
+        });
+        self.push(priority, job).await
+    }
+
+    // 차례가 와서 처리될 때까지 기다려 결과를 돌려받는다
+    pub async fn send<M>(
+        &self,
+        priority: Priority,
+        msg: M,
+    ) -> Result<<A as Handler<Prioritized<M>>>::Result, MailboxClosed>
+    where
+        A: Handler<Prioritized<M>>,
+        <A as Handler<Prioritized<M>>>::Result: Send + Sync,
+        M: Send + 'static,
+    {
+        let (sender, receiver) = oneshot::channel();
+        let job: Job<A> = Box::new(move |mut addr| {
+            Box::pin(async move {
+                if let Ok(result) = addr.send(Prioritized(msg)).await {
+                    let _ = sender.send(result);
+                }
+            })
+        });
+        self.push(priority, job).await?;
+        receiver.await.map_err(|_| MailboxClosed)
+    }
+
+    // 액터가 일을 넘겨받기만 하고 결과는 나중에 보내는 메시지 (예: NetworkRequest)
+    // 전달자는 넘겨받을 때까지만 기다리므로 느린 요청이 진행 중이어도 다음 메시지가 바로 들어간다.
+    pub async fn request<M, T>(&self, priority: Priority, msg: M) -> Result<T, MailboxClosed>
+    where
+        A: Handler<Prioritized<M>, Result = oneshot::Receiver<T>>,
+        M: Send + 'static,
+        T: Send + 'static,
+    {
+        let pending = self.send(priority, msg).await?;
+        pending.await.map_err(|_| MailboxClosed)
+    }
+}
+
+// 기존 Handler/Notifiable 구현을 Prioritized<M>으로도 받을 수 있게 감싼다
+// 예: prioritize!(NetworkManagerActor, handle: NetworkRequest);
+macro_rules! prioritize {
+    ($actor:ty, handle: $($message:ty),+ $(,)?) => {$(
+        #[::async_trait::async_trait]
+        impl ::messages::prelude::Handler<$crate::study_actors::actors::Prioritized<$message>>
+            for $actor
+        {
+            type Result = <$actor as ::messages::prelude::Handler<$message>>::Result;
+
+            async fn handle(
+                &mut self,
+                msg: $crate::study_actors::actors::Prioritized<$message>,
+                ctx: &::messages::prelude::Context<Self>,
+            ) -> Self::Result {
+                <Self as ::messages::prelude::Handler<$message>>::handle(self, msg.0, ctx).await
+            }
+        }
+    )+};
+    ($actor:ty, notify: $($message:ty),+ $(,)?) => {$(
+        #[::async_trait::async_trait]
+        impl ::messages::prelude::Handler<$crate::study_actors::actors::Prioritized<$message>>
+            for $actor
+        {
+            type Result = ();
+
+            async fn handle(
+                &mut self,
+                msg: $crate::study_actors::actors::Prioritized<$message>,
+                ctx: &::messages::prelude::Context<Self>,
+            ) {
+                <Self as ::messages::prelude::Notifiable<$message>>::notify(self, msg.0, ctx).await;
+            }
+        }
+    )+};
+}
+pub(crate) use prioritize;
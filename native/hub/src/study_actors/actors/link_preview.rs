@@ -0,0 +1,352 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use regex::Regex;
+use reqwest::{Url, header::CONTENT_TYPE};
+use std::{collections::HashMap, sync::LazyLock};
+use tokio::task::JoinSet;
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        ActorResult, CacheData, FetchData, FetchLinkPreview, FetchMode, LinkPreview,
+        LinkPreviewConfig, TrafficClass, UserError,
+    },
+    signals::{LinkPreviewRequest, respond_to_dart, route_dart_signals},
+};
+
+use super::network::NetworkRequest;
+use super::{CacheActor, NetworkManagerActor, Priority, PriorityMailbox};
+
+const CACHE_PREFIX: &str = "link_preview/";
+const MAX_DESCRIPTION_CHARS: usize = 300;
+
+// 같은 정보를 여러 태그가 주면 앞 순위 태그를 쓴다
+const TITLE_KEYS: [&str; 2] = ["og:title", "twitter:title"];
+const DESCRIPTION_KEYS: [&str; 3] = ["og:description", "twitter:description", "description"];
+const IMAGE_KEYS: [&str; 5] = [
+    "og:image:secure_url",
+    "og:image",
+    "og:image:url",
+    "twitter:image",
+    "twitter:image:src",
+];
+
+static TITLE_TAG: LazyLock<Option<Regex>> =
+    LazyLock::new(|| Regex::new(r"(?is)<title[^>]*>(.*?)</title>").ok());
+static META_TAG: LazyLock<Option<Regex>> = LazyLock::new(|| Regex::new(r"(?is)<meta\s[^>]*>").ok());
+static LINK_TAG: LazyLock<Option<Regex>> = LazyLock::new(|| Regex::new(r"(?is)<link\s[^>]*>").ok());
+static TAG_ATTR: LazyLock<Option<Regex>> = LazyLock::new(|| {
+    Regex::new(r#"(?is)([a-z][a-z0-9:_-]*)\s*=\s*(?:"([^"]*)"|'([^']*)'|([^\s"'=<>`]+))"#).ok()
+});
+
+// 태그 안의 흔한 HTML 엔터티를 풀고 공백을 하나로 줄인다
+pub(crate) fn clean_text(text: &str) -> String {
+    let decoded = text
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&");
+    decoded.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+pub(crate) fn truncate_chars(text: String, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text;
+    }
+    let mut truncated: String = text.chars().take(max_chars - 1).collect();
+    truncated.push('…');
+    truncated
+}
+
+// 속성 이름은 소문자로, 값은 엔터티를 푼 채로
+fn tag_attrs(tag: &str) -> Vec<(String, String)> {
+    let Some(tag_attr) = &*TAG_ATTR else {
+        return Vec::new();
+    };
+    tag_attr
+        .captures_iter(tag)
+        .map(|attr| {
+            let value = attr
+                .get(2)
+                .or_else(|| attr.get(3))
+                .or_else(|| attr.get(4))
+                .map_or("", |m| m.as_str());
+            (attr[1].to_ascii_lowercase(), clean_text(value))
+        })
+        .collect()
+}
+
+fn attr<'a>(attrs: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    attrs
+        .iter()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.as_str())
+}
+
+// 상대 주소를 페이지 기준 절대 주소로 (http(s)가 아니면 버린다)
+pub(crate) fn resolve(base: &Url, href: &str) -> Option<String> {
+    let url = base.join(href).ok()?;
+    matches!(url.scheme(), "http" | "https").then(|| url.to_string())
+}
+
+// 미리보기할 수 있는 주소인지 확인하고 캐시 키가 같아지도록 조각을 뗀다
+fn preview_url(url: &str) -> ActorResult<Url> {
+    let mut parsed = Url::parse(url.trim())
+        .map_err(|e| UserError::InvalidInput(format!("Invalid link {}: {}", url, e)))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(UserError::InvalidInput(format!(
+            "Only http(s) links can be previewed: {}",
+            url
+        )));
+    }
+    parsed.set_fragment(None);
+    Ok(parsed)
+}
+
+// 메타 태그가 없으면 <title>을 제목으로, 아이콘 링크가 없으면 /favicon.ico를 쓴다
+fn parse_link_preview(html: &str, url: &Url) -> LinkPreview {
+    let mut meta: HashMap<String, String> = HashMap::new();
+    if let Some(meta_tag) = &*META_TAG {
+        for tag in meta_tag.find_iter(html) {
+            let attrs = tag_attrs(tag.as_str());
+            let key = attr(&attrs, "property").or_else(|| attr(&attrs, "name"));
+            let (Some(key), Some(content)) = (key, attr(&attrs, "content")) else {
+                continue;
+            };
+            if !content.is_empty() {
+                meta.entry(key.to_ascii_lowercase())
+                    .or_insert_with(|| content.to_string());
+            }
+        }
+    }
+    let first = |keys: &[&str]| keys.iter().find_map(|key| meta.get(*key).cloned());
+
+    let title = first(&TITLE_KEYS).or_else(|| {
+        let captures = TITLE_TAG.as_ref()?.captures(html)?;
+        Some(clean_text(&captures[1])).filter(|title| !title.is_empty())
+    });
+
+    let mut icon = None;
+    let mut touch_icon = None;
+    if let Some(link_tag) = &*LINK_TAG {
+        for tag in link_tag.find_iter(html) {
+            let attrs = tag_attrs(tag.as_str());
+            let (Some(rel), Some(href)) = (attr(&attrs, "rel"), attr(&attrs, "href")) else {
+                continue;
+            };
+            let rel = rel.to_ascii_lowercase();
+            let slot = if rel.split_whitespace().any(|token| token == "icon") {
+                &mut icon
+            } else if rel.starts_with("apple-touch-icon") {
+                &mut touch_icon
+            } else {
+                continue;
+            };
+            if slot.is_none() {
+                *slot = resolve(url, href);
+            }
+        }
+    }
+
+    LinkPreview {
+        url: url.to_string(),
+        title,
+        description: first(&DESCRIPTION_KEYS)
+            .map(|description| truncate_chars(description, MAX_DESCRIPTION_CHARS)),
+        image_url: first(&IMAGE_KEYS).and_then(|image| resolve(url, &image)),
+        site_name: meta.get("og:site_name").cloned(),
+        favicon_url: icon.or(touch_icon).or_else(|| resolve(url, "/favicon.ico")),
+    }
+}
+
+// 링크 미리보기 액터
+// 페이지 앞부분만 받아 Open Graph·Twitter 메타 태그와 아이콘 링크에서 카드 내용을 만들고 캐시에 둔다.
+// 채팅 화면은 링크 여러 개를 한꺼번에 요청하므로 Dart 요청은 액터를 막지 않도록 작업으로 띄워 처리한다.
+pub struct LinkPreviewActor {
+    config: LinkPreviewConfig,
+    cache: Address<CacheActor>,
+    network_manager: PriorityMailbox<NetworkManagerActor>,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for LinkPreviewActor {}
+
+impl LinkPreviewActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        config: LinkPreviewConfig,
+        cache: Address<CacheActor>,
+        network_manager: PriorityMailbox<NetworkManagerActor>,
+    ) -> Self {
+        let owned_tasks = route_dart_signals!(self_addr, [LinkPreviewRequest]);
+
+        Self {
+            config,
+            cache,
+            network_manager,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    // 캐시에 없으면 페이지를 가져와 파싱한다 (실패한 결과는 캐시하지 않는다)
+    async fn load_preview(
+        config: LinkPreviewConfig,
+        mut cache: Address<CacheActor>,
+        network_manager: PriorityMailbox<NetworkManagerActor>,
+        url: String,
+    ) -> ActorResult<LinkPreview> {
+        let url = preview_url(&url)?;
+        let key = format!("{}{}", CACHE_PREFIX, url);
+        let cached = cache
+            .send(FetchData {
+                key: key.clone(),
+                user_id: None,
+                mode: FetchMode::CacheFirst,
+            })
+            .await;
+        if let Ok(Ok(data)) = cached {
+            match serde_json::from_slice(&data) {
+                Ok(preview) => return Ok(preview),
+                Err(e) => debug_print!("Discarding cached link preview {}: {}", url, e),
+            }
+        }
+
+        // 미리보기는 없어도 되는 정보라 데이터 절약 모드에서는 미리보기 이미지처럼 미룬다
+        let request = NetworkRequest::new(url.as_str())
+            .header("Accept", "text/html,application/xhtml+xml")
+            .timeout(config.fetch_timeout_ms)
+            .max_body(config.max_page_bytes)
+            .traffic(TrafficClass::Images);
+        let response = network_manager
+            .request(Priority::High, request)
+            .await
+            .map_err(|e| UserError::Upstream(e.to_string()))??;
+        if let Some(error) = response.error {
+            return Err(UserError::Upstream(error));
+        }
+        if !response.is_success() {
+            return Err(UserError::Upstream(format!(
+                "Link preview fetch failed: {}",
+                response.status
+            )));
+        }
+
+        let mime_type = response
+            .headers
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.split(';').next())
+            .unwrap_or_default()
+            .trim()
+            .to_lowercase();
+        let preview = if mime_type.starts_with("image/") {
+            LinkPreview {
+                url: url.to_string(),
+                image_url: Some(url.to_string()),
+                ..Default::default()
+            }
+        } else if mime_type.is_empty() || mime_type.contains("html") {
+            parse_link_preview(&String::from_utf8_lossy(&response.body), &url)
+        } else {
+            LinkPreview {
+                url: url.to_string(),
+                ..Default::default()
+            }
+        };
+
+        match serde_json::to_vec(&preview) {
+            Ok(data) => {
+                let _ = cache
+                    .send(CacheData {
+                        key,
+                        data: data.into(),
+                        ttl: Some(config.cache_ttl_secs),
+                    })
+                    .await;
+            }
+            Err(e) => debug_print!("Failed to cache link preview {}: {}", url, e),
+        }
+        Ok(preview)
+    }
+}
+
+#[async_trait]
+impl Handler<FetchLinkPreview> for LinkPreviewActor {
+    type Result = ActorResult<LinkPreview>;
+
+    async fn handle(&mut self, msg: FetchLinkPreview, _: &Context<Self>) -> Self::Result {
+        Self::load_preview(
+            self.config.clone(),
+            self.cache.clone(),
+            self.network_manager.clone(),
+            msg.url,
+        )
+        .await
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<LinkPreviewRequest> for LinkPreviewActor {
+    async fn notify(&mut self, msg: LinkPreviewRequest, _: &Context<Self>) {
+        let config = self.config.clone();
+        let cache = self.cache.clone();
+        let network_manager = self.network_manager.clone();
+        self._owned_tasks.spawn(async move {
+            let _ = respond_to_dart(msg, move |msg| async move {
+                Ok(Self::load_preview(config, cache, network_manager, msg.url).await?)
+            })
+            .await;
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn parses_open_graph_twitter_and_fallback_tags() -> Result<(), Box<dyn Error>> {
+        let url = preview_url("https://example.com/posts/1#comments")?;
+        assert_eq!(url.as_str(), "https://example.com/posts/1");
+        assert!(preview_url("file:///etc/passwd").is_err());
+
+        let html = r#"<html><head>
+            <title>Fallback &amp; title</title>
+            <meta name="twitter:title" content="Twitter title">
+            <meta content='Rich &quot;card&quot;' property='og:description'>
+            <meta name=description content="Plain description">
+            <meta name="twitter:image" content="/images/card.png">
+            <meta property="og:site_name" content="Example">
+            <link rel="apple-touch-icon" href="/touch.png">
+            <link rel="shortcut icon" href="//cdn.example.com/icon.ico">
+            </head></html>"#;
+        assert_eq!(
+            parse_link_preview(html, &url),
+            LinkPreview {
+                url: "https://example.com/posts/1".to_string(),
+                title: Some("Twitter title".to_string()),
+                description: Some("Rich \"card\"".to_string()),
+                image_url: Some("https://example.com/images/card.png".to_string()),
+                site_name: Some("Example".to_string()),
+                favicon_url: Some("https://cdn.example.com/icon.ico".to_string()),
+            }
+        );
+
+        let bare = parse_link_preview("<title> Just a title </title>", &url);
+        assert_eq!(bare.title.as_deref(), Some("Just a title"));
+        assert_eq!(bare.image_url, None);
+        assert_eq!(
+            bare.favicon_url.as_deref(),
+            Some("https://example.com/favicon.ico")
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,669 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use std::{fmt::Display, time::Duration};
+use tokio::task::JoinSet;
+
+#[cfg(all(
+    feature = "smtp",
+    not(any(target_family = "wasm", target_os = "android", target_os = "ios"))
+))]
+use crate::study_actors::messages::SmtpConfig;
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        ActorResult, ComposeMail, FetchData, FetchMode, MailConfig, MailError, MailStatus,
+        PersistState, PersistedState, RenderTemplate, RestoreState, ScheduledTask, StoreData,
+        TrafficClass, UserError, WipeUserData,
+    },
+    signals::{EmitSignal, MailStatusSignal, SendMailRequest, respond_to_dart, route_dart_signals},
+};
+
+use super::network::NetworkRequest;
+use super::{
+    Clock, NetworkManagerActor, Priority, PriorityMailbox, StorageActor, TemplateActor,
+    TrustedClock,
+};
+
+const OUTBOX_KEY: &str = "mail/outbox";
+const RETRY_CHECK_SECS: u64 = 30;
+const SEND_TIMEOUT_MS: u64 = 30_000;
+const MAX_RECIPIENTS: usize = 50;
+const STATE_VERSION: u32 = 1; // 종료 시 남기는 발송 대기열의 구조 버전
+
+// 대기열에 보관하는 렌더링된 메일
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct QueuedMail {
+    id: String,
+    to: Vec<String>,
+    reply_to: Option<String>,
+    subject: String,
+    text: String,
+    html: Option<String>,
+    attempts: u32,
+    next_attempt_at: i64, // 유닉스 밀리초
+    last_error: Option<String>,
+}
+
+// 종료 시 남기는 대기열 (메일 아이디가 겹치지 않도록 순번도 함께 남긴다)
+#[derive(Serialize, Deserialize)]
+struct MailState {
+    outbox: Vec<QueuedMail>,
+    sequence: u64,
+}
+
+// 발송 API로 보내는 본문
+#[derive(Serialize)]
+struct MailPayload<'a> {
+    from: &'a str,
+    to: &'a [String],
+    reply_to: Option<&'a str>,
+    subject: &'a str,
+    text: &'a str,
+    html: Option<&'a str>,
+}
+
+// 영구 오류는 다시 보내도 같은 결과라 바로 실패로 처리한다
+enum DeliveryError {
+    Transient(String),
+    Permanent(String),
+}
+
+fn permanent(error: impl Display) -> DeliveryError {
+    DeliveryError::Permanent(error.to_string())
+}
+
+// 받는 사람은 주소만 받는다 (표시 이름, 여러 주소, 줄바꿈으로 헤더를 끼워 넣지 못하게)
+fn valid_address(address: &str) -> bool {
+    let Some((local, domain)) = address.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && domain.contains('.')
+        && !domain.starts_with('.')
+        && !domain.ends_with('.')
+        && !domain.contains('@')
+        && !address.chars().any(|c| {
+            c.is_whitespace() || c.is_control() || matches!(c, '<' | '>' | ',' | ';' | '"')
+        })
+}
+
+fn validate(msg: &ComposeMail) -> ActorResult<()> {
+    if msg.to.is_empty() || msg.to.len() > MAX_RECIPIENTS {
+        return Err(UserError::InvalidInput(format!(
+            "A mail needs 1 to {} recipients",
+            MAX_RECIPIENTS
+        )));
+    }
+    if let Some(address) = msg
+        .to
+        .iter()
+        .chain(msg.reply_to.iter())
+        .find(|address| !valid_address(address))
+    {
+        return Err(UserError::InvalidInput(format!(
+            "Invalid email address: {}",
+            address
+        )));
+    }
+    if msg.subject.trim().is_empty() || msg.subject.chars().any(char::is_control) {
+        return Err(UserError::InvalidInput(
+            "Mail subject must be a single non-empty line".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+// 실패한 횟수만큼 두 배로 늘린 대기 시간 (retry_max_secs에서 멈춘다)
+fn retry_delay_secs(config: &MailConfig, attempts: u32) -> u64 {
+    let doublings = attempts.saturating_sub(1).min(20);
+    config
+        .retry_base_secs
+        .saturating_mul(1 << doublings)
+        .min(config.retry_max_secs)
+}
+
+fn emit_status(mail: &QueuedMail, status: MailStatus) {
+    MailStatusSignal {
+        mail_id: mail.id.clone(),
+        status,
+        attempts: mail.attempts,
+        error: mail.last_error.clone(),
+        next_attempt_at: (status == MailStatus::Retrying).then_some(mail.next_attempt_at),
+    }
+    .emit();
+}
+
+// 데스크톱에서 SMTP 서버로 직접 보낸다
+#[cfg(all(
+    feature = "smtp",
+    not(any(target_family = "wasm", target_os = "android", target_os = "ios"))
+))]
+async fn send_smtp(smtp: &SmtpConfig, from: &str, mail: &QueuedMail) -> Result<(), DeliveryError> {
+    use lettre::{
+        AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+        message::{Mailbox, MultiPart, SinglePart},
+        transport::smtp::authentication::Credentials,
+    };
+
+    let mut builder = Message::builder()
+        .from(from.parse::<Mailbox>().map_err(permanent)?)
+        .subject(&mail.subject);
+    for to in &mail.to {
+        builder = builder.to(to.parse::<Mailbox>().map_err(permanent)?);
+    }
+    if let Some(reply_to) = &mail.reply_to {
+        builder = builder.reply_to(reply_to.parse::<Mailbox>().map_err(permanent)?);
+    }
+    let message = match &mail.html {
+        Some(html) => builder.multipart(MultiPart::alternative_plain_html(
+            mail.text.clone(),
+            html.clone(),
+        )),
+        None => builder.singlepart(SinglePart::plain(mail.text.clone())),
+    }
+    .map_err(permanent)?;
+
+    let relay = if smtp.implicit_tls {
+        AsyncSmtpTransport::<Tokio1Executor>::relay(&smtp.host)
+    } else {
+        AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&smtp.host)
+    };
+    let mut transport = relay
+        .map_err(permanent)?
+        .port(smtp.port)
+        .timeout(Some(Duration::from_millis(SEND_TIMEOUT_MS)));
+    if let (Some(username), Some(password)) = (&smtp.username, &smtp.password) {
+        transport = transport.credentials(Credentials::new(username.clone(), password.clone()));
+    }
+    match transport.build().send(message).await {
+        Ok(_) => Ok(()),
+        Err(e) if e.is_permanent() => Err(permanent(e)),
+        Err(e) => Err(DeliveryError::Transient(e.to_string())),
+    }
+}
+
+// 메일 액터
+// 등록된 템플릿으로 본문을 만들어 저장소의 발송 대기열에 넣고, 설정한 발송 API
+// (smtp 기능을 켠 데스크톱은 SMTP)로 보낸다. 일시적인 실패는 간격을 늘려 가며 다시 보낸다.
+// 보내던 중 앱이 꺼지면 다음 실행에서 다시 보내므로 같은 메일이 두 번 갈 수 있다.
+pub struct MailActor {
+    config: MailConfig,
+    storage: Address<StorageActor>,
+    templates: Address<TemplateActor>,
+    network_manager: PriorityMailbox<NetworkManagerActor>,
+    clock: TrustedClock,
+    outbox: Vec<QueuedMail>,
+    sequence: u64,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for MailActor {}
+
+impl MailActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        config: MailConfig,
+        storage: Address<StorageActor>,
+        templates: Address<TemplateActor>,
+        network_manager: PriorityMailbox<NetworkManagerActor>,
+        clock: TrustedClock,
+    ) -> Self {
+        let mut owned_tasks = route_dart_signals!(self_addr, [SendMailRequest]);
+        owned_tasks.spawn(Self::load_outbox(self_addr.clone()));
+        owned_tasks.spawn(Self::retry_outbox(self_addr, clock.clock().clone()));
+
+        Self {
+            config,
+            storage,
+            templates,
+            network_manager,
+            clock,
+            outbox: Vec::new(),
+            sequence: 0,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    async fn load_outbox(mut self_addr: Address<Self>) {
+        let _ = self_addr.notify(LoadOutbox).await;
+    }
+
+    async fn flush_soon(mut self_addr: Address<Self>) {
+        let _ = self_addr.notify(FlushOutbox).await;
+    }
+
+    async fn retry_outbox(mut self_addr: Address<Self>, clock: Clock) {
+        let mut interval = clock
+            .interval(Duration::from_secs(RETRY_CHECK_SECS))
+            .throttled(ScheduledTask::MailRetry);
+        loop {
+            interval.tick().await;
+            let _ = self_addr.notify(FlushOutbox).await;
+        }
+    }
+
+    async fn persist_outbox(&mut self) -> ActorResult<()> {
+        let request = StoreData {
+            key: OUTBOX_KEY.to_string(),
+            data: serde_json::to_vec(&self.outbox)?,
+            user_id: None,
+            ttl: None,
+        };
+        self.storage.send(request).await??;
+        Ok(())
+    }
+
+    // 이미 있는 메일은 시도 횟수가 많은 쪽을 남기고, 없는 메일은 뒤에 붙인다
+    fn merge_outbox(&mut self, mails: Vec<QueuedMail>) {
+        for mail in mails {
+            match self.outbox.iter_mut().find(|queued| queued.id == mail.id) {
+                Some(queued) if queued.attempts < mail.attempts => *queued = mail,
+                Some(_) => {}
+                None => self.outbox.push(mail),
+            }
+        }
+    }
+
+    async fn render(&mut self, name: String, context: serde_json::Value) -> ActorResult<String> {
+        self.templates
+            .send(RenderTemplate { name, context })
+            .await?
+    }
+
+    // 발송 수단이 없으면 메일은 대기열에서 설정을 기다린다
+    fn has_provider(&self) -> bool {
+        #[cfg(all(
+            feature = "smtp",
+            not(any(target_family = "wasm", target_os = "android", target_os = "ios"))
+        ))]
+        if self.config.smtp.is_some() {
+            return true;
+        }
+        self.config.api_url.is_some()
+    }
+
+    async fn deliver(&self, mail: &QueuedMail) -> Result<(), DeliveryError> {
+        #[cfg(all(
+            feature = "smtp",
+            not(any(target_family = "wasm", target_os = "android", target_os = "ios"))
+        ))]
+        if let Some(smtp) = &self.config.smtp {
+            return send_smtp(smtp, &self.config.from, mail).await;
+        }
+        match &self.config.api_url {
+            Some(url) => self.send_api(url, mail).await,
+            None => Err(DeliveryError::Transient(
+                "No mail provider is configured".to_string(),
+            )),
+        }
+    }
+
+    // 연결 실패와 5xx, 408, 429는 다시 보내고 나머지 4xx는 요청 문제로 본다
+    async fn send_api(&self, url: &str, mail: &QueuedMail) -> Result<(), DeliveryError> {
+        let payload = MailPayload {
+            from: &self.config.from,
+            to: &mail.to,
+            reply_to: mail.reply_to.as_deref(),
+            subject: &mail.subject,
+            text: &mail.text,
+            html: mail.html.as_deref(),
+        };
+        let mut request = NetworkRequest::new(url)
+            .method(Method::POST)
+            .header("Content-Type", "application/json")
+            .timeout(SEND_TIMEOUT_MS)
+            .traffic(TrafficClass::Other)
+            .json(&payload);
+        if let Some(key) = &self.config.api_key {
+            request = request.header("Authorization", &format!("Bearer {}", key));
+        }
+
+        let response = match self.network_manager.request(Priority::Low, request).await {
+            Ok(Ok(response)) => response,
+            Ok(Err(e)) => return Err(DeliveryError::Transient(e.to_string())),
+            Err(e) => return Err(DeliveryError::Transient(e.to_string())),
+        };
+        if let Some(error) = response.error {
+            return Err(DeliveryError::Transient(error));
+        }
+        let status = response.status.as_u16();
+        if response.status.is_success() {
+            Ok(())
+        } else if status >= 500 || status == 408 || status == 429 {
+            Err(DeliveryError::Transient(format!(
+                "Mail API returned status {}",
+                status
+            )))
+        } else {
+            Err(permanent(format!("Mail API returned status {}", status)))
+        }
+    }
+
+    // 때가 된 메일을 보내고, 보냈거나 포기한 메일은 대기열에서 뺀다
+    async fn flush_outbox(&mut self) {
+        if !self.has_provider() {
+            return;
+        }
+        let now = self.clock.now_millis();
+        let mut changed = false;
+        let mut kept = Vec::new();
+        for mut mail in std::mem::take(&mut self.outbox) {
+            if mail.next_attempt_at > now {
+                kept.push(mail);
+                continue;
+            }
+            changed = true;
+            mail.attempts += 1;
+            match self.deliver(&mail).await {
+                Ok(()) => {
+                    mail.last_error = None;
+                    emit_status(&mail, MailStatus::Sent);
+                }
+                Err(DeliveryError::Transient(error))
+                    if mail.attempts < self.config.max_attempts =>
+                {
+                    debug_print!("Mail {} failed, retrying: {}", mail.id, error);
+                    let delay_secs = retry_delay_secs(&self.config, mail.attempts);
+                    mail.next_attempt_at = self.clock.now_millis() + delay_secs as i64 * 1000;
+                    mail.last_error = Some(error);
+                    emit_status(&mail, MailStatus::Retrying);
+                    kept.push(mail);
+                }
+                Err(DeliveryError::Transient(error) | DeliveryError::Permanent(error)) => {
+                    debug_print!("Mail {} failed: {}", mail.id, error);
+                    mail.last_error = Some(error);
+                    emit_status(&mail, MailStatus::Failed);
+                }
+            }
+        }
+        self.outbox = kept;
+
+        if changed && let Err(e) = self.persist_outbox().await {
+            debug_print!("Failed to persist mail outbox: {}", e);
+        }
+    }
+}
+
+// 내부 메시지 정의
+struct LoadOutbox;
+struct FlushOutbox;
+
+#[async_trait]
+impl Notifiable<LoadOutbox> for MailActor {
+    async fn notify(&mut self, _: LoadOutbox, _: &Context<Self>) {
+        let request = FetchData {
+            key: OUTBOX_KEY.to_string(),
+            user_id: None,
+            mode: FetchMode::CacheFirst,
+        };
+        let stored: Vec<QueuedMail> = match self.storage.send(request).await {
+            Ok(Ok(bytes)) => serde_json::from_slice(&bytes).unwrap_or_else(|e| {
+                debug_print!("Invalid stored mail outbox: {}", e);
+                Vec::new()
+            }),
+            _ => Vec::new(),
+        };
+        // 불러오기 전에 넣은 메일은 뒤에 둔다
+        let queued = std::mem::replace(&mut self.outbox, stored);
+        self.merge_outbox(queued);
+        debug_print!("Mail outbox loaded: {} queued mails", self.outbox.len());
+    }
+}
+
+#[async_trait]
+impl Notifiable<FlushOutbox> for MailActor {
+    async fn notify(&mut self, _: FlushOutbox, _: &Context<Self>) {
+        if !self.outbox.is_empty() {
+            self.flush_outbox().await;
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<ComposeMail> for MailActor {
+    type Result = ActorResult<String>;
+
+    async fn handle(&mut self, msg: ComposeMail, ctx: &Context<Self>) -> Self::Result {
+        validate(&msg)?;
+        if self.outbox.len() >= self.config.max_queue {
+            return Err(UserError::InvalidInput(format!(
+                "Mail queue is full (max {})",
+                self.config.max_queue
+            )));
+        }
+
+        let text = self.render(msg.text_template, msg.context.clone()).await?;
+        let html = match msg.html_template {
+            Some(name) => Some(self.render(name, msg.context).await?),
+            None => None,
+        };
+
+        self.sequence += 1;
+        let now = self.clock.now_millis();
+        let mail = QueuedMail {
+            id: format!("mail-{}-{}", now, self.sequence),
+            to: msg.to,
+            reply_to: msg.reply_to,
+            subject: msg.subject,
+            text,
+            html,
+            attempts: 0,
+            next_attempt_at: now,
+            last_error: None,
+        };
+        self.outbox.push(mail.clone());
+        // 저장하지 못하면 꺼졌을 때 사라지므로 받지 않는다
+        if let Err(e) = self.persist_outbox().await {
+            self.outbox.pop();
+            return Err(e);
+        }
+        emit_status(&mail, MailStatus::Queued);
+        // 재시도 주기를 기다리지 않고 바로 보낸다 (응답은 보내기 전에 돌려준다)
+        self._owned_tasks.spawn(Self::flush_soon(ctx.address()));
+        Ok(mail.id)
+    }
+}
+
+// 저장소는 감독자가 지우므로 메모리의 대기열만 비운다
+#[async_trait]
+impl Handler<WipeUserData> for MailActor {
+    type Result = Result<(), MailError>;
+
+    async fn handle(&mut self, _: WipeUserData, _: &Context<Self>) -> Self::Result {
+        self.outbox.clear();
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Handler<PersistState> for MailActor {
+    type Result = Option<PersistedState>;
+
+    async fn handle(&mut self, _: PersistState, _: &Context<Self>) -> Self::Result {
+        if self.outbox.is_empty() {
+            return None;
+        }
+        let state = MailState {
+            outbox: self.outbox.clone(),
+            sequence: self.sequence,
+        };
+        PersistedState::new(STATE_VERSION, &state)
+    }
+}
+
+#[async_trait]
+impl Handler<RestoreState> for MailActor {
+    type Result = bool;
+
+    async fn handle(&mut self, msg: RestoreState, _: &Context<Self>) -> Self::Result {
+        let Some(state) = msg.0.read::<MailState>(STATE_VERSION) else {
+            return false;
+        };
+        self.sequence = self.sequence.max(state.sequence);
+        self.merge_outbox(state.outbox);
+        if let Err(e) = self.persist_outbox().await {
+            debug_print!("Failed to persist restored mail outbox: {}", e);
+        }
+        true
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<SendMailRequest> for MailActor {
+    async fn notify(&mut self, msg: SendMailRequest, ctx: &Context<Self>) {
+        let _ = respond_to_dart(msg, |msg| async move {
+            let context = serde_json::from_str(&msg.context_json).map_err(|e| {
+                UserError::InvalidInput(format!("context_json is not valid JSON: {}", e))
+            })?;
+            let compose = ComposeMail {
+                to: msg.to,
+                reply_to: msg.reply_to,
+                subject: msg.subject,
+                text_template: msg.text_template,
+                html_template: msg.html_template,
+                context,
+            };
+            Ok(self.handle(compose, ctx).await?)
+        })
+        .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use messages::prelude::Context;
+    use std::{error::Error, sync::Arc};
+
+    use super::*;
+    use crate::study_actors::{
+        messages::{RegisterTemplate, TemplateConfig, TemplateFormat},
+        storage::MemoryStorage,
+        test_support::{TestActorHarness, settle},
+    };
+
+    fn mail(to: &str, subject: &str) -> ComposeMail {
+        ComposeMail {
+            to: vec![to.to_string()],
+            reply_to: None,
+            subject: subject.to_string(),
+            text_template: "invite".to_string(),
+            html_template: None,
+            context: serde_json::Value::Null,
+        }
+    }
+
+    #[test]
+    fn rejects_header_injection_and_backs_off() {
+        assert!(validate(&mail("friend@example.com", "You're invited")).is_ok());
+        assert!(validate(&mail("friend@example.com\r\nBcc: x@y.z", "Hi")).is_err());
+        assert!(validate(&mail("Friend <friend@example.com>", "Hi")).is_err());
+        assert!(validate(&mail("a@example.com, b@example.com", "Hi")).is_err());
+        assert!(validate(&mail("friend@example", "Hi")).is_err());
+        assert!(validate(&mail("friend@example.com", "Hi\nBcc: x@y.z")).is_err());
+
+        let config = MailConfig {
+            retry_base_secs: 30,
+            retry_max_secs: 600,
+            ..MailConfig::default()
+        };
+        let delays: Vec<u64> = (1..=7).map(|n| retry_delay_secs(&config, n)).collect();
+        assert_eq!(delays, [30, 60, 120, 240, 480, 600, 600]);
+        assert_eq!(retry_delay_secs(&config, u32::MAX), 600);
+    }
+
+    // 발송 수단이 없는 메일 액터 (보낸 메일은 대기열에 남는다)
+    fn start_mail(
+        storage: &TestActorHarness<StorageActor>,
+        templates: &TestActorHarness<TemplateActor>,
+    ) -> TestActorHarness<MailActor> {
+        TestActorHarness::start(|addr| {
+            MailActor::new(
+                addr,
+                MailConfig::default(),
+                storage.addr(),
+                templates.addr(),
+                PriorityMailbox::new(Context::new().address()),
+                TrustedClock::new(),
+            )
+        })
+    }
+
+    fn start_storage() -> TestActorHarness<StorageActor> {
+        TestActorHarness::start(|_| StorageActor::new(Arc::new(MemoryStorage::new())))
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn queued_mails_survive_a_persist_and_restore() -> Result<(), Box<dyn Error>> {
+        let mut templates =
+            TestActorHarness::start(|addr| TemplateActor::new(addr, TemplateConfig::default()));
+        templates
+            .send(RegisterTemplate {
+                name: "invite".to_string(),
+                source: "Join us".to_string(),
+                format: TemplateFormat::Text,
+            })
+            .await??;
+        let first_storage = start_storage();
+        let mut first = start_mail(&first_storage, &templates);
+        settle().await;
+        first.send(mail("a@example.com", "First")).await??;
+        first.send(mail("b@example.com", "Second")).await??;
+        let persisted = first.send(PersistState).await?.ok_or("nothing persisted")?;
+
+        // 저장소가 비어 있는 새 실행에서도 스냅샷의 순서와 순번을 이어받는다
+        let second_storage = start_storage();
+        let mut second = start_mail(&second_storage, &templates);
+        settle().await;
+        assert!(second.send(RestoreState(persisted.clone())).await?);
+        let restored = second.send(PersistState).await?.ok_or("nothing restored")?;
+        assert_eq!(restored.state, persisted.state);
+
+        let state = restored
+            .read::<MailState>(STATE_VERSION)
+            .ok_or("unreadable")?;
+        let subjects: Vec<&str> = state.outbox.iter().map(|m| m.subject.as_str()).collect();
+        assert_eq!(subjects, ["First", "Second"]);
+        assert_eq!(state.sequence, 2);
+
+        // 같은 스냅샷을 다시 복원해도 메일이 늘지 않는다
+        assert!(second.send(RestoreState(persisted)).await?);
+        let again = second.send(PersistState).await?.ok_or("nothing restored")?;
+        let again = again.read::<MailState>(STATE_VERSION).ok_or("unreadable")?;
+        assert_eq!(again.outbox.len(), 2);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn outbox_from_another_version_is_discarded() -> Result<(), Box<dyn Error>> {
+        let templates =
+            TestActorHarness::start(|addr| TemplateActor::new(addr, TemplateConfig::default()));
+        let storage = start_storage();
+        let mut actor = start_mail(&storage, &templates);
+        settle().await;
+
+        let state = MailState {
+            outbox: vec![QueuedMail {
+                id: "mail-1-1".to_string(),
+                to: vec!["a@example.com".to_string()],
+                reply_to: None,
+                subject: "Old".to_string(),
+                text: "Join us".to_string(),
+                html: None,
+                attempts: 0,
+                next_attempt_at: 0,
+                last_error: None,
+            }],
+            sequence: 1,
+        };
+        let future = PersistedState::new(STATE_VERSION + 1, &state).ok_or("unserializable")?;
+        assert!(!actor.send(RestoreState(future)).await?);
+        assert!(actor.send(PersistState).await?.is_none());
+        Ok(())
+    }
+}
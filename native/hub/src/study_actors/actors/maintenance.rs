@@ -0,0 +1,266 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use std::time::Duration;
+use tokio::task::JoinSet;
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        CompactStorage, IntegrityReport, MaintenanceReport, RunMaintenance, ScanPrefix,
+        ScheduledTask, StorageConfig, VerifyBlobs,
+    },
+    signals::{EmitSignal, MaintenanceReportSignal, RunMaintenanceRequest, route_dart_signals},
+};
+
+use super::{AttachmentActor, Clock, Instant, MediaCacheActor, StorageActor, TrustedClock};
+
+// 점검 중에는 각 액터의 메일함이 길게 막히므로 이보다 자주 돌리지 않는다
+const MIN_INTERVAL_SECS: u64 = 3600;
+
+// 저장소 점검 액터
+// 저장소의 모든 값을 끝까지 읽어 손상을 드러내고, 블롭과 미디어 캐시 파일을 기록과 대조한 뒤
+// 디스크 공간을 회수한다. 파일을 고치는 일은 그 파일을 가진 액터가 자기 메일함 안에서 한다.
+pub struct MaintenanceActor {
+    storage: Address<StorageActor>,
+    attachment: Address<AttachmentActor>,
+    media_cache: Address<MediaCacheActor>,
+    clock: TrustedClock,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for MaintenanceActor {}
+
+impl MaintenanceActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        config: StorageConfig,
+        storage: Address<StorageActor>,
+        attachment: Address<AttachmentActor>,
+        media_cache: Address<MediaCacheActor>,
+        clock: TrustedClock,
+    ) -> Self {
+        let mut owned_tasks = route_dart_signals!(self_addr, [RunMaintenanceRequest]);
+        if config.maintenance_interval_secs > 0 {
+            owned_tasks.spawn(Self::run_periodically(
+                self_addr,
+                clock.clock().clone(),
+                config.maintenance_interval_secs,
+            ));
+        }
+
+        Self {
+            storage,
+            attachment,
+            media_cache,
+            clock,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    async fn run_periodically(mut self_addr: Address<Self>, clock: Clock, interval_secs: u64) {
+        let mut interval = clock
+            .interval(Duration::from_secs(interval_secs.max(MIN_INTERVAL_SECS)))
+            .throttled(ScheduledTask::Maintenance);
+        interval.tick().await; // 첫 틱은 즉시 발생하므로 건너뜀 (시작 직후 디스크 부하를 피한다)
+        loop {
+            interval.tick().await;
+            let _ = self_addr.notify(ScheduledMaintenance).await;
+        }
+    }
+}
+
+// 내부 메시지 정의
+struct ScheduledMaintenance;
+
+#[async_trait]
+impl Handler<RunMaintenance> for MaintenanceActor {
+    type Result = MaintenanceReport;
+
+    // 한 단계가 실패해도 나머지 단계는 진행하고 실패는 보고서에 남긴다
+    async fn handle(&mut self, _: RunMaintenance, _: &Context<Self>) -> Self::Result {
+        let started_at = self.clock.trusted_now();
+        let started = Instant::now();
+        let mut errors = Vec::new();
+
+        // sled는 값을 읽을 때 체크섬을 확인하므로 끝까지 읽히면 저장소는 온전하다
+        let scan = ScanPrefix {
+            prefix: String::new(),
+        };
+        let storage_entries = match self.storage.send(scan).await {
+            Ok(Ok(entries)) => entries.len() as u64,
+            Ok(Err(e)) => {
+                errors.push(format!("storage: {}", e));
+                0
+            }
+            Err(e) => {
+                errors.push(format!("storage: {}", e));
+                0
+            }
+        };
+
+        let mut integrity = IntegrityReport::default();
+        match self.attachment.send(VerifyBlobs).await {
+            Ok(Ok(report)) => integrity.merge(report),
+            Ok(Err(e)) => errors.push(format!("attachment: {}", e)),
+            Err(e) => errors.push(format!("attachment: {}", e)),
+        }
+        match self.media_cache.send(VerifyBlobs).await {
+            Ok(Ok(report)) => integrity.merge(report),
+            Ok(Err(e)) => errors.push(format!("media_cache: {}", e)),
+            Err(e) => errors.push(format!("media_cache: {}", e)),
+        }
+
+        let compaction = match self.storage.send(CompactStorage).await {
+            Ok(Ok(stats)) => stats,
+            Ok(Err(e)) => {
+                errors.push(format!("compaction: {}", e));
+                None
+            }
+            Err(e) => {
+                errors.push(format!("compaction: {}", e));
+                None
+            }
+        };
+
+        let report = MaintenanceReport {
+            started_at,
+            duration_ms: started.elapsed().as_millis() as u64,
+            storage_entries,
+            compaction,
+            integrity,
+            errors,
+        };
+        debug_print!(
+            "Maintenance checked {} keys and {} files: {} repaired, {} quarantined, {} errors",
+            report.storage_entries,
+            report.integrity.checked,
+            report.integrity.repaired.len(),
+            report.integrity.quarantined.len(),
+            report.errors.len()
+        );
+        report
+    }
+}
+
+#[async_trait]
+impl Notifiable<ScheduledMaintenance> for MaintenanceActor {
+    async fn notify(&mut self, _: ScheduledMaintenance, ctx: &Context<Self>) {
+        let report = self.handle(RunMaintenance, ctx).await;
+        MaintenanceReportSignal { report }.emit();
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<RunMaintenanceRequest> for MaintenanceActor {
+    async fn notify(&mut self, _: RunMaintenanceRequest, ctx: &Context<Self>) {
+        let report = self.handle(RunMaintenance, ctx).await;
+        MaintenanceReportSignal { report }.emit();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use messages::prelude::Context;
+    use sha2::{Digest, Sha256};
+    use std::{collections::HashMap, error::Error, fs, sync::Arc};
+
+    use super::*;
+    use crate::study_actors::{
+        actors::{EventBus, PriorityMailbox},
+        messages::{Attachment, AttachmentConfig},
+        storage::MemoryStorage,
+        test_support::{TestActorHarness, settle, store_data},
+    };
+
+    fn attachment(id: &str, blob: &[u8]) -> Attachment {
+        Attachment {
+            id: id.to_string(),
+            item_id: "item-1".to_string(),
+            file_name: format!("{id}.txt"),
+            mime_type: "text/plain".to_string(),
+            size: blob.len() as u64,
+            blob_hash: Some(hex::encode(Sha256::digest(blob))),
+            thumbnail_hash: None,
+            remote_url: None,
+            created_at: 0,
+        }
+    }
+
+    // 미디어 캐시 자리는 닫힌 주소라 그 단계는 실패한다
+    #[tokio::test(start_paused = true)]
+    async fn corrupt_local_blob_is_quarantined_and_failed_steps_are_reported()
+    -> Result<(), Box<dyn Error>> {
+        let blob_dir =
+            std::env::temp_dir().join(format!("rinf_maintenance_{}", std::process::id()));
+        fs::create_dir_all(&blob_dir)?;
+        let intact = attachment("intact", b"intact");
+        let corrupt = attachment("corrupt", b"original");
+        let corrupt_hash = corrupt.blob_hash.clone().unwrap_or_default();
+        fs::write(
+            blob_dir.join(intact.blob_hash.as_deref().unwrap_or_default()),
+            b"intact",
+        )?;
+        fs::write(blob_dir.join(&corrupt_hash), b"bit rot")?;
+
+        let mut storage =
+            TestActorHarness::start(|_| StorageActor::new(Arc::new(MemoryStorage::new())));
+        let index = HashMap::from([("intact", intact), ("corrupt", corrupt)]);
+        let stored = store_data("attachments/index", &serde_json::to_vec(&index)?);
+        storage.send(stored).await??;
+        let config = AttachmentConfig {
+            blob_dir: Some(blob_dir.to_string_lossy().into_owned()),
+            ..AttachmentConfig::default()
+        };
+        let attachments = TestActorHarness::start(|addr| {
+            AttachmentActor::new(
+                addr,
+                config,
+                storage.addr(),
+                Context::new().address(),
+                PriorityMailbox::new(Context::new().address()),
+                EventBus::new().subscribe(),
+                TrustedClock::new(),
+            )
+        });
+        let config = StorageConfig {
+            maintenance_interval_secs: 0,
+            ..StorageConfig::default()
+        };
+        let mut maintenance = TestActorHarness::start(|addr| {
+            MaintenanceActor::new(
+                addr,
+                config,
+                storage.addr(),
+                attachments.addr(),
+                Context::new().address(),
+                TrustedClock::new(),
+            )
+        });
+        settle().await;
+
+        let first = maintenance.send(RunMaintenance).await?;
+        let quarantined = blob_dir.join("quarantine").join(&corrupt_hash).exists();
+        // 격리한 파일은 블롭 디렉터리에 없으므로 다음 점검에서는 검사하지 않는다
+        let second = maintenance.send(RunMaintenance).await?;
+        fs::remove_dir_all(&blob_dir)?;
+
+        // 첨부 색인과 스키마 버전
+        assert_eq!(first.storage_entries, 2);
+        assert_eq!(first.integrity.checked, 2);
+        assert_eq!(
+            first.integrity.quarantined,
+            ["attachment corrupt (corrupt.txt)"]
+        );
+        assert!(first.integrity.repaired.is_empty());
+        assert!(quarantined);
+        assert_eq!(first.errors.len(), 1);
+        assert!(first.errors[0].starts_with("media_cache: "));
+        assert_eq!(second.integrity.checked, 1);
+        assert!(second.integrity.quarantined.is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,142 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use pulldown_cmark::{Event, Options, Parser, TagEnd, html};
+use tokio::task::JoinSet;
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{RenderMarkdown, RenderMode},
+    signals::{EmitSignal, RenderMarkdownRequest, RenderedHtmlSignal, route_dart_signals},
+};
+
+const DEFAULT_SUMMARY_CHARS: usize = 160;
+
+fn parser_options() -> Options {
+    Options::ENABLE_TABLES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_FOOTNOTES
+}
+
+// Markdown → HTML 변환 후 ammonia로 스크립트/이벤트 속성 등 위험 요소 제거
+pub fn render_html(markdown: &str) -> String {
+    let mut raw_html = String::with_capacity(markdown.len() * 3 / 2);
+    html::push_html(&mut raw_html, Parser::new_ext(markdown, parser_options()));
+    ammonia::clean(&raw_html)
+}
+
+// 서식 기호를 제거한 일반 텍스트 추출 (블록 경계는 줄바꿈으로 유지)
+pub fn render_plain_text(markdown: &str) -> String {
+    let mut text = String::with_capacity(markdown.len());
+    for event in Parser::new_ext(markdown, parser_options()) {
+        match event {
+            Event::Text(value) | Event::Code(value) => text.push_str(&value),
+            Event::SoftBreak => text.push(' '),
+            Event::HardBreak => text.push('\n'),
+            Event::End(
+                TagEnd::Paragraph
+                | TagEnd::Heading(_)
+                | TagEnd::Item
+                | TagEnd::CodeBlock
+                | TagEnd::TableRow
+                | TagEnd::TableHead,
+            ) => text.push('\n'),
+            Event::End(TagEnd::TableCell) => text.push(' '),
+            _ => {}
+        }
+    }
+    text.trim().to_string()
+}
+
+// 공백을 정리한 뒤 단어 경계에서 잘라낸 요약
+pub fn render_summary(markdown: &str, max_chars: usize) -> String {
+    let collapsed = render_plain_text(markdown)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    if collapsed.chars().count() <= max_chars {
+        return collapsed;
+    }
+
+    let cut = collapsed
+        .char_indices()
+        .nth(max_chars)
+        .map(|(index, _)| index)
+        .unwrap_or(collapsed.len());
+    let truncated = &collapsed[..cut];
+    let truncated = match truncated.rfind(' ') {
+        Some(space) if space > 0 => &truncated[..space],
+        _ => truncated,
+    };
+    format!("{}…", truncated.trim_end())
+}
+
+// Markdown 렌더링 액터
+pub struct MarkdownActor {
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for MarkdownActor {}
+
+impl MarkdownActor {
+    pub fn new(self_addr: Address<Self>) -> Self {
+        let owned_tasks = route_dart_signals!(self_addr, [RenderMarkdownRequest]);
+
+        Self {
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    fn render(msg: &RenderMarkdown) -> String {
+        match msg.mode {
+            RenderMode::Html => render_html(&msg.markdown),
+            RenderMode::PlainText => render_plain_text(&msg.markdown),
+            RenderMode::Summary => render_summary(
+                &msg.markdown,
+                msg.summary_max_chars.unwrap_or(DEFAULT_SUMMARY_CHARS),
+            ),
+        }
+    }
+
+    // 긴 문서의 파싱/정화가 메일박스를 막지 않도록 블로킹 스레드에서 렌더링
+    async fn render_offloaded(msg: RenderMarkdown) -> String {
+        tokio::task::spawn_blocking(move || Self::render(&msg))
+            .await
+            .unwrap_or_else(|e| {
+                debug_print!("Markdown rendering task failed: {}", e);
+                String::new()
+            })
+    }
+}
+
+#[async_trait]
+impl Handler<RenderMarkdown> for MarkdownActor {
+    type Result = String;
+
+    async fn handle(&mut self, msg: RenderMarkdown, _: &Context<Self>) -> Self::Result {
+        Self::render_offloaded(msg).await
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<RenderMarkdownRequest> for MarkdownActor {
+    async fn notify(&mut self, msg: RenderMarkdownRequest, _: &Context<Self>) {
+        let output = Self::render_offloaded(RenderMarkdown {
+            markdown: msg.markdown,
+            mode: msg.mode,
+            summary_max_chars: msg.summary_max_chars.map(|chars| chars as usize),
+        })
+        .await;
+
+        RenderedHtmlSignal {
+            request_id: msg.request_id,
+            mode: msg.mode,
+            output,
+        }
+        .emit();
+    }
+}
@@ -0,0 +1,586 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use reqwest::header::CONTENT_TYPE;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+use tokio::task::JoinSet;
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        CategoryUsage, ClearStorage, FetchData, FetchMode, GetMedia, IntegrityReport,
+        MeasureStorage, MediaCacheConfig, MediaCacheError, MediaEntry, PinMedia, StorageCategory,
+        StoreData, TrafficClass, VerifyBlobs, WipeUserData,
+    },
+    signals::{
+        EmitSignal, GetMediaRequest, MediaCacheStatusRequest, MediaCacheStatusSignal,
+        MediaReadySignal, PinMediaRequest, route_dart_signals,
+    },
+};
+
+use super::network::NetworkRequest;
+use super::{NetworkManagerActor, Priority, PriorityMailbox, StorageActor, TrustedClock};
+
+const MEDIA_INDEX_KEY: &str = "media_cache/index";
+const DOWNLOAD_TIMEOUT_MS: u64 = 60_000;
+
+// 임시 파일에 쓴 뒤 옮겨서 반쯤 쓴 파일이 캐시 항목으로 보이지 않게 한다
+fn write_file_blocking(cache_dir: &Path, name: &str, bytes: &[u8]) -> Result<(), MediaCacheError> {
+    fs::create_dir_all(cache_dir)?;
+    let temp_path = cache_dir.join(format!("{}.tmp", name));
+    fs::write(&temp_path, bytes)?;
+    fs::rename(temp_path, cache_dir.join(name))?;
+    Ok(())
+}
+
+fn remove_files_blocking(cache_dir: &Path, names: &[String]) {
+    for name in names {
+        match fs::remove_file(cache_dir.join(name)) {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => debug_print!("Failed to remove cached media {}: {}", name, e),
+        }
+    }
+}
+
+// files는 (URL, 파일 이름, 기록된 크기), 크기가 다른 파일은 쓰다 끊긴 것으로 보고 지운다
+fn verify_files_blocking(
+    cache_dir: &Path,
+    files: Vec<(String, String, u64)>,
+) -> Result<IntegrityReport, MediaCacheError> {
+    let mut report = IntegrityReport::default();
+    for (url, name, size) in files {
+        let path = cache_dir.join(&name);
+        let actual = match fs::metadata(&path) {
+            Ok(metadata) => metadata.len(),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e.into()),
+        };
+        report.checked += 1;
+        if actual != size {
+            fs::remove_file(&path)?;
+            report.repaired.push(format!("media {}", url));
+        }
+    }
+    Ok(report)
+}
+
+fn file_name_for(url: &str) -> String {
+    hex::encode(Sha256::digest(url.as_bytes()))
+}
+
+// 캐시 항목과 LRU 순서 (사용할 때마다 늘어나는 번호라 시계가 바뀌어도 순서가 흔들리지 않는다)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedMedia {
+    entry: MediaEntry,
+    file_name: String,
+    last_used: u64,
+}
+
+// 미디어 캐시 액터
+// 이미지와 오디오를 파일로 내려받아 두고, 바이트 한도를 넘으면 오래 쓰지 않은 항목부터 지운다.
+// 일반 키/값 캐시와 달리 사용자가 고정한 항목은 지우지 않으며, 색인은 저장소에 남겨 다음 실행에서 이어 쓴다.
+pub struct MediaCacheActor {
+    entries: HashMap<String, CachedMedia>,
+    use_counter: u64,
+    cache_dir: PathBuf,
+    quota_bytes: u64,
+    storage: Address<StorageActor>,
+    network_manager: PriorityMailbox<NetworkManagerActor>,
+    clock: TrustedClock,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for MediaCacheActor {}
+
+impl MediaCacheActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        config: MediaCacheConfig,
+        storage: Address<StorageActor>,
+        network_manager: PriorityMailbox<NetworkManagerActor>,
+        clock: TrustedClock,
+    ) -> Self {
+        let mut owned_tasks = route_dart_signals!(
+            self_addr,
+            [GetMediaRequest, PinMediaRequest, MediaCacheStatusRequest]
+        );
+        owned_tasks.spawn(Self::load_index(self_addr));
+
+        let cache_dir = config
+            .cache_dir
+            .map(PathBuf::from)
+            .unwrap_or_else(|| std::env::temp_dir().join("rinf_media"));
+
+        Self {
+            entries: HashMap::new(),
+            use_counter: 0,
+            cache_dir,
+            quota_bytes: config.quota_bytes,
+            storage,
+            network_manager,
+            clock,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    async fn load_index(mut self_addr: Address<Self>) {
+        let _ = self_addr.notify(LoadIndex).await;
+    }
+
+    fn local_path(&self, cached: &CachedMedia) -> String {
+        self.cache_dir
+            .join(&cached.file_name)
+            .to_string_lossy()
+            .to_string()
+    }
+
+    fn touch(&mut self, url: &str) -> Option<CachedMedia> {
+        self.use_counter += 1;
+        let cached = self.entries.get_mut(url)?;
+        cached.last_used = self.use_counter;
+        Some(cached.clone())
+    }
+
+    fn used_bytes(&self) -> u64 {
+        self.entries.values().map(|cached| cached.entry.size).sum()
+    }
+
+    fn status(&self) -> MediaCacheStatusSignal {
+        MediaCacheStatusSignal {
+            used_bytes: self.used_bytes(),
+            pinned_bytes: self
+                .entries
+                .values()
+                .filter(|cached| cached.entry.pinned)
+                .map(|cached| cached.entry.size)
+                .sum(),
+            quota_bytes: self.quota_bytes,
+            items: self.entries.len() as u64,
+        }
+    }
+
+    async fn persist_index(&mut self) -> Result<(), MediaCacheError> {
+        let request = StoreData {
+            key: MEDIA_INDEX_KEY.to_string(),
+            data: serde_json::to_vec(&self.entries)?,
+            user_id: None,
+            ttl: None,
+        };
+        self.storage.send(request).await??;
+        Ok(())
+    }
+
+    // 한도 안으로 들어올 때까지 고정되지 않은 항목을 오래된 순서로 지운다 (keep은 방금 쓴 항목)
+    async fn evict(&mut self, keep: &str) {
+        let mut used = self.used_bytes();
+        if used <= self.quota_bytes {
+            return;
+        }
+        let mut candidates: Vec<(u64, String)> = self
+            .entries
+            .iter()
+            .filter(|(url, cached)| !cached.entry.pinned && url.as_str() != keep)
+            .map(|(url, cached)| (cached.last_used, url.clone()))
+            .collect();
+        candidates.sort();
+
+        let mut removed = Vec::new();
+        for (_, url) in candidates {
+            if used <= self.quota_bytes {
+                break;
+            }
+            if let Some(cached) = self.entries.remove(&url) {
+                used -= cached.entry.size;
+                removed.push(cached.file_name);
+            }
+        }
+        debug_print!(
+            "Evicted {} media files, {} of {} bytes used",
+            removed.len(),
+            used,
+            self.quota_bytes
+        );
+        let cache_dir = self.cache_dir.clone();
+        let _ =
+            tokio::task::spawn_blocking(move || remove_files_blocking(&cache_dir, &removed)).await;
+    }
+
+    async fn download(
+        &mut self,
+        url: &str,
+        pinned: bool,
+        background: bool,
+    ) -> Result<CachedMedia, MediaCacheError> {
+        let (priority, traffic) = if background {
+            (Priority::Low, TrafficClass::Prefetch)
+        } else {
+            (Priority::High, TrafficClass::Downloads)
+        };
+        let request = NetworkRequest::new(url)
+            .timeout(DOWNLOAD_TIMEOUT_MS)
+            .traffic(traffic);
+        let response = self.network_manager.request(priority, request).await??;
+        if let Some(error) = response.error {
+            return Err(error.into());
+        }
+        if !response.is_success() {
+            return Err(format!("Media download failed: {}", response.status).into());
+        }
+        let mime_type = response
+            .headers
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.split(';').next())
+            .unwrap_or_default()
+            .trim()
+            .to_lowercase();
+        if !mime_type.starts_with("image/") && !mime_type.starts_with("audio/") {
+            return Err(format!("Not an image or audio file: {}", mime_type).into());
+        }
+
+        let file_name = file_name_for(url);
+        let cache_dir = self.cache_dir.clone();
+        let size = response.body.len() as u64;
+        let name = file_name.clone();
+        tokio::task::spawn_blocking(move || write_file_blocking(&cache_dir, &name, &response.body))
+            .await??;
+
+        self.use_counter += 1;
+        let cached = CachedMedia {
+            entry: MediaEntry {
+                url: url.to_string(),
+                mime_type,
+                size,
+                pinned,
+                fetched_at: self.clock.trusted_now(),
+            },
+            file_name,
+            last_used: self.use_counter,
+        };
+        self.entries.insert(url.to_string(), cached.clone());
+        self.evict(url).await;
+        self.persist_index().await?;
+        Ok(cached)
+    }
+
+    fn send_ready(&self, url: String, result: Result<CachedMedia, MediaCacheError>) {
+        match result {
+            Ok(cached) => {
+                MediaReadySignal {
+                    url,
+                    local_path: Some(self.local_path(&cached)),
+                    entry: Some(cached.entry),
+                    error: None,
+                }
+                .emit();
+            }
+            Err(e) => {
+                MediaReadySignal {
+                    url,
+                    local_path: None,
+                    entry: None,
+                    error: Some(e.to_string()),
+                }
+                .emit();
+            }
+        }
+    }
+
+    async fn get(&mut self, url: &str, background: bool) -> Result<CachedMedia, MediaCacheError> {
+        // 색인에 있어도 파일이 지워졌으면 다시 내려받는다
+        if let Some(cached) = self.touch(url) {
+            if self.cache_dir.join(&cached.file_name).exists() {
+                return Ok(cached);
+            }
+            self.entries.remove(url);
+            return self.download(url, cached.entry.pinned, background).await;
+        }
+        self.download(url, false, background).await
+    }
+
+    async fn pin(&mut self, url: &str, pinned: bool) -> Result<CachedMedia, MediaCacheError> {
+        let mut cached = self.get(url, false).await?;
+        if cached.entry.pinned != pinned {
+            cached.entry.pinned = pinned;
+            self.entries.insert(url.to_string(), cached.clone());
+            // 고정을 풀면 한도를 넘은 만큼 바로 정리한다
+            if !pinned {
+                self.evict(url).await;
+            }
+            self.persist_index().await?;
+        }
+        Ok(cached)
+    }
+}
+
+// 내부 메시지 정의
+struct LoadIndex;
+
+#[async_trait]
+impl Notifiable<LoadIndex> for MediaCacheActor {
+    async fn notify(&mut self, _: LoadIndex, _: &Context<Self>) {
+        let request = FetchData {
+            key: MEDIA_INDEX_KEY.to_string(),
+            user_id: None,
+            mode: FetchMode::CacheFirst,
+        };
+        match self.storage.send(request).await {
+            Ok(Ok(bytes)) => match serde_json::from_slice::<HashMap<String, CachedMedia>>(&bytes) {
+                Ok(entries) => {
+                    self.use_counter = entries
+                        .values()
+                        .map(|cached| cached.last_used)
+                        .max()
+                        .unwrap_or_default();
+                    self.entries = entries;
+                }
+                Err(e) => debug_print!("Invalid stored media index: {}", e),
+            },
+            // 저장된 색인이 없으면 빈 캐시
+            Ok(Err(_)) => {}
+            Err(e) => debug_print!("Failed to load media index: {}", e),
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<GetMedia> for MediaCacheActor {
+    type Result = Result<MediaEntry, MediaCacheError>;
+
+    async fn handle(&mut self, msg: GetMedia, _: &Context<Self>) -> Self::Result {
+        Ok(self.get(&msg.url, msg.background).await?.entry)
+    }
+}
+
+#[async_trait]
+impl Handler<PinMedia> for MediaCacheActor {
+    type Result = Result<MediaEntry, MediaCacheError>;
+
+    async fn handle(&mut self, msg: PinMedia, _: &Context<Self>) -> Self::Result {
+        Ok(self.pin(&msg.url, msg.pinned).await?.entry)
+    }
+}
+
+#[async_trait]
+impl Handler<MeasureStorage> for MediaCacheActor {
+    type Result = Result<Vec<CategoryUsage>, MediaCacheError>;
+
+    async fn handle(&mut self, _: MeasureStorage, _: &Context<Self>) -> Self::Result {
+        Ok(vec![CategoryUsage::new(
+            StorageCategory::Caches,
+            self.used_bytes(),
+            self.entries.len() as u64,
+        )])
+    }
+}
+
+#[async_trait]
+impl Handler<ClearStorage> for MediaCacheActor {
+    type Result = Result<usize, MediaCacheError>;
+
+    // 오프라인용으로 고정한 항목은 남긴다
+    async fn handle(&mut self, msg: ClearStorage, _: &Context<Self>) -> Self::Result {
+        if msg.category != StorageCategory::Caches {
+            return Ok(0);
+        }
+        let mut removed = Vec::new();
+        self.entries.retain(|_, cached| {
+            if !cached.entry.pinned {
+                removed.push(cached.file_name.clone());
+            }
+            cached.entry.pinned
+        });
+        if removed.is_empty() {
+            return Ok(0);
+        }
+
+        self.persist_index().await?;
+        let count = removed.len();
+        let cache_dir = self.cache_dir.clone();
+        tokio::task::spawn_blocking(move || remove_files_blocking(&cache_dir, &removed)).await?;
+        self.status().emit();
+        Ok(count)
+    }
+}
+
+// 색인 항목은 남기므로 다음 조회 때 파일이 없는 것을 보고 다시 내려받는다 (고정도 유지된다)
+#[async_trait]
+impl Handler<VerifyBlobs> for MediaCacheActor {
+    type Result = Result<IntegrityReport, MediaCacheError>;
+
+    async fn handle(&mut self, _: VerifyBlobs, _: &Context<Self>) -> Self::Result {
+        let files = self
+            .entries
+            .values()
+            .map(|cached| {
+                (
+                    cached.entry.url.clone(),
+                    cached.file_name.clone(),
+                    cached.entry.size,
+                )
+            })
+            .collect();
+        let cache_dir = self.cache_dir.clone();
+        tokio::task::spawn_blocking(move || verify_files_blocking(&cache_dir, files)).await?
+    }
+}
+
+#[async_trait]
+impl Handler<WipeUserData> for MediaCacheActor {
+    type Result = Result<(), MediaCacheError>;
+
+    async fn handle(&mut self, _: WipeUserData, _: &Context<Self>) -> Self::Result {
+        self.entries.clear();
+        #[cfg(not(target_family = "wasm"))]
+        let removed = tokio::fs::remove_dir_all(&self.cache_dir).await;
+        #[cfg(target_family = "wasm")]
+        let removed = std::fs::remove_dir_all(&self.cache_dir);
+        match removed {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<GetMediaRequest> for MediaCacheActor {
+    async fn notify(&mut self, msg: GetMediaRequest, _: &Context<Self>) {
+        let result = self.get(&msg.url, false).await;
+        self.send_ready(msg.url, result);
+    }
+}
+
+#[async_trait]
+impl Notifiable<PinMediaRequest> for MediaCacheActor {
+    async fn notify(&mut self, msg: PinMediaRequest, _: &Context<Self>) {
+        let result = self.pin(&msg.url, msg.pinned).await;
+        self.send_ready(msg.url, result);
+        self.status().emit();
+    }
+}
+
+#[async_trait]
+impl Notifiable<MediaCacheStatusRequest> for MediaCacheActor {
+    async fn notify(&mut self, _: MediaCacheStatusRequest, _: &Context<Self>) {
+        self.status().emit();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use messages::prelude::Context;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::study_actors::{
+        storage::MemoryStorage,
+        test_support::{TestActorHarness, settle, store_data},
+    };
+
+    const QUOTA_BYTES: u64 = 120;
+
+    fn cached(url: &str, pinned: bool, last_used: u64) -> CachedMedia {
+        CachedMedia {
+            entry: MediaEntry {
+                url: url.to_string(),
+                mime_type: "image/png".to_string(),
+                size: 40,
+                pinned,
+                fetched_at: 0,
+            },
+            file_name: file_name_for(url),
+            last_used,
+        }
+    }
+
+    // 네트워크 자리는 닫힌 주소라 파일과 색인을 미리 만들어 둔 캐시만 쓸 수 있다
+    fn start_cache(
+        storage: &TestActorHarness<StorageActor>,
+        cache_dir: &Path,
+    ) -> TestActorHarness<MediaCacheActor> {
+        let config = MediaCacheConfig {
+            cache_dir: Some(cache_dir.to_string_lossy().into_owned()),
+            quota_bytes: QUOTA_BYTES,
+        };
+        TestActorHarness::start(|addr| {
+            MediaCacheActor::new(
+                addr,
+                config,
+                storage.addr(),
+                PriorityMailbox::new(Context::new().address()),
+                TrustedClock::new(),
+            )
+        })
+    }
+
+    fn unpin(url: &str) -> PinMedia {
+        PinMedia {
+            url: url.to_string(),
+            pinned: false,
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn eviction_skips_pinned_media_and_removes_least_recently_used_first()
+    -> Result<(), MediaCacheError> {
+        let cache_dir = std::env::temp_dir().join(format!("rinf_media_{}", std::process::id()));
+        fs::create_dir_all(&cache_dir)?;
+        // 네 항목 160바이트가 한도를 넘지만 정리는 내려받거나 고정을 풀 때 한다
+        let entries: HashMap<String, CachedMedia> = [
+            cached("https://a", true, 1),
+            cached("https://b", false, 2),
+            cached("https://c", false, 3),
+            cached("https://d", true, 4),
+        ]
+        .into_iter()
+        .map(|cached| (cached.entry.url.clone(), cached))
+        .collect();
+        for cached in entries.values() {
+            fs::write(cache_dir.join(&cached.file_name), [0u8; 40])?;
+        }
+        let mut storage =
+            TestActorHarness::start(|_| StorageActor::new(Arc::new(MemoryStorage::new())));
+        let index = store_data(MEDIA_INDEX_KEY, &serde_json::to_vec(&entries)?);
+        storage.send(index).await??;
+        let mut cache = start_cache(&storage, &cache_dir);
+        settle().await;
+
+        // 고정을 푼 항목은 방금 쓴 것이라 남고, 가장 오래된 a는 고정되어 있어 건너뛴다
+        let entry = cache.send(unpin("https://d")).await??;
+        assert!(!entry.pinned);
+        let file_left = |url: &str| cache_dir.join(file_name_for(url)).exists();
+        let files = ["https://a", "https://b", "https://c", "https://d"].map(file_left);
+        let usage = cache.send(MeasureStorage).await??;
+        drop(cache);
+
+        // 정리된 색인은 다음 실행에서도 그대로다
+        let mut reloaded = start_cache(&storage, &cache_dir);
+        settle().await;
+        let cleared = reloaded
+            .send(ClearStorage {
+                category: StorageCategory::Caches,
+            })
+            .await??;
+        let after_clear = ["https://a", "https://b", "https://c", "https://d"].map(file_left);
+        fs::remove_dir_all(&cache_dir)?;
+
+        assert_eq!(files, [true, false, true, true]);
+        assert_eq!(usage[0].bytes, QUOTA_BYTES);
+        assert_eq!(usage[0].entries, 3);
+        // 비울 때도 고정된 항목은 남긴다
+        assert_eq!(cleared, 2);
+        assert_eq!(after_clear, [true, false, false, false]);
+        Ok(())
+    }
+}
@@ -0,0 +1,263 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use std::{collections::HashMap, sync::OnceLock, time::Duration};
+use tokio::{sync::mpsc, task::JoinSet};
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{GetLatencyStats, LatencyStat, MetricsConfig},
+    signals::{
+        EmitSignal, GetTraceRequest, LatencyStatsRequest, LatencyStatsSignal, TraceTimelineSignal,
+        route_dart_signals,
+    },
+};
+
+use super::{Instant, trace_timeline};
+
+// 측정값은 어느 액터에서든 동기적으로 남길 수 있도록 전역 채널로 모은다
+static RECORDER: OnceLock<mpsc::UnboundedSender<LatencySample>> = OnceLock::new();
+
+// 지연 측정 대상 메시지
+// 보낸 시각을 함께 실어 받는 쪽 메일박스에서 기다린 시간을 잴 수 있게 한다.
+pub struct Timed<M> {
+    pub message: M,
+    sent_at: Instant,
+}
+
+impl<M> Timed<M> {
+    pub fn new(message: M) -> Self {
+        Self {
+            message,
+            sent_at: Instant::now(),
+        }
+    }
+
+    // 처리를 시작할 때 호출해 메시지 본문과 처리 시간 측정기로 나눈다
+    pub fn start(self, actor: &'static str, message: &'static str) -> (M, Stopwatch) {
+        let started = Instant::now();
+        let stopwatch = Stopwatch {
+            actor,
+            message,
+            queued: started.duration_since(self.sent_at),
+            started,
+        };
+        (self.message, stopwatch)
+    }
+}
+
+pub struct Stopwatch {
+    actor: &'static str,
+    message: &'static str,
+    queued: Duration,
+    started: Instant,
+}
+
+impl Stopwatch {
+    pub fn finish(self) {
+        // 지표 액터가 없으면 측정값은 버려진다
+        if let Some(recorder) = RECORDER.get() {
+            let _ = recorder.send(LatencySample {
+                actor: self.actor,
+                message: self.message,
+                queued: self.queued,
+                processing: self.started.elapsed(),
+            });
+        }
+    }
+}
+
+// 기존 Handler/Notifiable 구현을 Timed<M>으로도 받을 수 있게 감싼다
+// 예: instrument!(CacheActor, handle: FetchData, CacheData);
+macro_rules! instrument {
+    ($actor:ty, handle: $($message:ty),+ $(,)?) => {$(
+        #[::async_trait::async_trait]
+        impl ::messages::prelude::Handler<$crate::study_actors::actors::Timed<$message>>
+            for $actor
+        {
+            type Result = <$actor as ::messages::prelude::Handler<$message>>::Result;
+
+            async fn handle(
+                &mut self,
+                msg: $crate::study_actors::actors::Timed<$message>,
+                ctx: &::messages::prelude::Context<Self>,
+            ) -> Self::Result {
+                let (msg, stopwatch) = msg.start(stringify!($actor), stringify!($message));
+                let result =
+                    <Self as ::messages::prelude::Handler<$message>>::handle(self, msg, ctx).await;
+                stopwatch.finish();
+                result
+            }
+        }
+    )+};
+    ($actor:ty, notify: $($message:ty),+ $(,)?) => {$(
+        #[::async_trait::async_trait]
+        impl ::messages::prelude::Notifiable<$crate::study_actors::actors::Timed<$message>>
+            for $actor
+        {
+            async fn notify(
+                &mut self,
+                msg: $crate::study_actors::actors::Timed<$message>,
+                ctx: &::messages::prelude::Context<Self>,
+            ) {
+                let (msg, stopwatch) = msg.start(stringify!($actor), stringify!($message));
+                <Self as ::messages::prelude::Notifiable<$message>>::notify(self, msg, ctx).await;
+                stopwatch.finish();
+            }
+        }
+    )+};
+}
+pub(crate) use instrument;
+
+#[derive(Debug)]
+struct LatencySample {
+    actor: &'static str,
+    message: &'static str,
+    queued: Duration,
+    processing: Duration,
+}
+
+#[derive(Default)]
+struct LatencyTotals {
+    count: u64,
+    total_processing: Duration,
+    max_processing: Duration,
+    total_queue: Duration,
+    max_queue: Duration,
+    over_budget: u64,
+}
+
+impl LatencyTotals {
+    fn to_stat(&self, actor: &str, message: &str) -> LatencyStat {
+        let count = self.count.max(1) as f64;
+        LatencyStat {
+            actor: actor.to_string(),
+            message: message.to_string(),
+            count: self.count,
+            avg_processing_ms: millis(self.total_processing) / count,
+            max_processing_ms: millis(self.max_processing),
+            avg_queue_ms: millis(self.total_queue) / count,
+            max_queue_ms: millis(self.max_queue),
+            over_budget: self.over_budget,
+        }
+    }
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+// 지표 액터
+// Timed<M>으로 보낸 메시지의 대기/처리 시간을 모아 예산 초과를 경고한다.
+pub struct MetricsActor {
+    budget: Duration,
+    totals: HashMap<(&'static str, &'static str), LatencyTotals>,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for MetricsActor {}
+
+impl MetricsActor {
+    pub fn new(self_addr: Address<Self>, config: MetricsConfig) -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        if RECORDER.set(sender).is_err() {
+            debug_print!("Latency recorder already installed, new samples are not collected");
+        }
+
+        let mut owned_tasks =
+            route_dart_signals!(self_addr, [LatencyStatsRequest, GetTraceRequest]);
+        owned_tasks.spawn(Self::listen_to_samples(self_addr.clone(), receiver));
+
+        Self {
+            budget: Duration::from_millis(config.budget_ms),
+            totals: HashMap::new(),
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    async fn listen_to_samples(
+        mut self_addr: Address<Self>,
+        mut receiver: mpsc::UnboundedReceiver<LatencySample>,
+    ) {
+        while let Some(sample) = receiver.recv().await {
+            let _ = self_addr.notify(SampleRecorded(sample)).await;
+        }
+    }
+
+    fn stats(&self) -> Vec<LatencyStat> {
+        let mut stats: Vec<LatencyStat> = self
+            .totals
+            .iter()
+            .map(|((actor, message), totals)| totals.to_stat(actor, message))
+            .collect();
+        stats.sort_by(|a, b| b.avg_processing_ms.total_cmp(&a.avg_processing_ms));
+        stats
+    }
+}
+
+// 내부 메시지 정의
+struct SampleRecorded(LatencySample);
+
+#[async_trait]
+impl Notifiable<SampleRecorded> for MetricsActor {
+    async fn notify(&mut self, msg: SampleRecorded, _: &Context<Self>) {
+        let sample = msg.0;
+        let totals = self
+            .totals
+            .entry((sample.actor, sample.message))
+            .or_default();
+        totals.count += 1;
+        totals.total_processing += sample.processing;
+        totals.max_processing = totals.max_processing.max(sample.processing);
+        totals.total_queue += sample.queued;
+        totals.max_queue = totals.max_queue.max(sample.queued);
+
+        if sample.queued + sample.processing > self.budget {
+            totals.over_budget += 1;
+            debug_print!(
+                "[latency] {}::{} exceeded {}ms budget (queued {:.1}ms, processing {:.1}ms)",
+                sample.actor,
+                sample.message,
+                self.budget.as_millis(),
+                millis(sample.queued),
+                millis(sample.processing)
+            );
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<GetLatencyStats> for MetricsActor {
+    type Result = Vec<LatencyStat>;
+
+    async fn handle(&mut self, _: GetLatencyStats, _: &Context<Self>) -> Self::Result {
+        self.stats()
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<LatencyStatsRequest> for MetricsActor {
+    async fn notify(&mut self, _: LatencyStatsRequest, _: &Context<Self>) {
+        LatencyStatsSignal {
+            stats: self.stats(),
+        }
+        .emit();
+    }
+}
+
+// 액터 간 메시지 흐름 조회 (추적 기록은 trace 모듈이 전역으로 보관)
+#[async_trait]
+impl Notifiable<GetTraceRequest> for MetricsActor {
+    async fn notify(&mut self, msg: GetTraceRequest, _: &Context<Self>) {
+        let hops = trace_timeline(msg.trace_id);
+        TraceTimelineSignal {
+            trace_id: msg.trace_id,
+            found: hops.is_some(),
+            hops: hops.unwrap_or_default(),
+        }
+        .emit();
+    }
+}
@@ -0,0 +1,166 @@
+use serde_json::Value;
+
+use crate::study_actors::logging::debug_print;
+use crate::study_actors::{messages::StorageError, storage::Storage};
+
+use super::id_gen::ulid_from_seed;
+
+const SCHEMA_VERSION_KEY: &str = "meta/schema_version";
+const SCHEMA_VERSION: u32 = 1;
+const LEGACY_ITEM_PREFIX: &str = "item_";
+
+// 저장소 형식 마이그레이션 (저장소 액터가 첫 요청을 처리하기 전에 한 번 실행)
+// 저장된 버전 다음 단계부터 차례로 실행하고 단계마다 버전을 기록한다.
+// 단계는 중간에 멈췄다가 다시 실행해도 결과가 같아야 한다.
+pub(super) async fn migrate(storage: &dyn Storage) -> Result<u32, StorageError> {
+    let current = match storage.load(SCHEMA_VERSION_KEY).await {
+        Ok(bytes) => {
+            serde_json::from_slice(&bytes).map_err(|e| StorageError::Migration(e.to_string()))?
+        }
+        Err(StorageError::NotFound(_)) => 0,
+        Err(e) => return Err(e),
+    };
+    for version in current..SCHEMA_VERSION {
+        debug_print!("Running storage migration {} -> {}", version, version + 1);
+        run_step(storage, version).await?;
+        storage
+            .save(SCHEMA_VERSION_KEY, (version + 1).to_string().as_bytes())
+            .await?;
+    }
+    Ok(current.max(SCHEMA_VERSION))
+}
+
+async fn run_step(storage: &dyn Storage, version: u32) -> Result<(), StorageError> {
+    match version {
+        0 => migrate_item_ids(storage).await,
+        _ => Ok(()),
+    }
+}
+
+// item_{밀리초} 형식이면 같은 시각의 ULID (제목 등 사용자 값과 헷갈리지 않도록 12자리 이상만)
+fn migrated_item_id(id: &str) -> Option<String> {
+    let digits = id.strip_prefix(LEGACY_ITEM_PREFIX)?;
+    if digits.len() < 12 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    Some(ulid_from_seed(digits.parse().ok()?, id))
+}
+
+// 예전 항목 ID와 똑같은 문자열 값과 객체 키를 바꾼다 (바뀐 것이 있으면 true)
+fn replace_item_ids(value: &mut Value) -> bool {
+    match value {
+        Value::String(text) => match migrated_item_id(text) {
+            Some(id) => {
+                *text = id;
+                true
+            }
+            None => false,
+        },
+        Value::Array(values) => {
+            let mut changed = false;
+            for value in values {
+                changed |= replace_item_ids(value);
+            }
+            changed
+        }
+        Value::Object(map) => {
+            let mut changed = false;
+            for (key, mut value) in std::mem::take(map) {
+                changed |= replace_item_ids(&mut value);
+                let key = match migrated_item_id(&key) {
+                    Some(id) => {
+                        changed = true;
+                        id
+                    }
+                    None => key,
+                };
+                map.insert(key, value);
+            }
+            changed
+        }
+        _ => false,
+    }
+}
+
+// 1단계: 빠르게 만들면 겹치던 item_{밀리초} 항목 ID를 ULID로 바꾼다
+// 키의 마지막 경로(items/, history/, collab/, embeddings/item/ 등)와
+// JSON 값 안의 ID(태그 색인의 키, 컬렉션의 item_ids 등)를 모두 바꾼다.
+async fn migrate_item_ids(storage: &dyn Storage) -> Result<(), StorageError> {
+    let mut migrated = 0;
+    for (key, data) in storage.scan_prefix("").await? {
+        let renamed = key.rsplit_once('/').and_then(|(prefix, last)| {
+            migrated_item_id(last).map(|id| format!("{}/{}", prefix, id))
+        });
+        let rewritten = match serde_json::from_slice::<Value>(&data) {
+            Ok(mut value) => match replace_item_ids(&mut value) {
+                true => Some(
+                    serde_json::to_vec(&value)
+                        .map_err(|e| StorageError::Migration(e.to_string()))?,
+                ),
+                false => None,
+            },
+            Err(_) => None,
+        };
+        if renamed.is_none() && rewritten.is_none() {
+            continue;
+        }
+
+        // 새 키에 먼저 쓰고 예전 키를 지운다 (중간에 멈추면 다음 실행에서 같은 ID로 다시 옮긴다)
+        let new_key = renamed.unwrap_or_else(|| key.clone());
+        storage
+            .save(&new_key, rewritten.as_deref().unwrap_or(&data))
+            .await?;
+        if new_key != key {
+            storage.delete(&key).await?;
+        }
+        migrated += 1;
+    }
+    if migrated > 0 {
+        debug_print!("Migrated item ids in {} storage entries", migrated);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+    use std::error::Error;
+
+    use super::migrate;
+    use crate::study_actors::storage::{MemoryStorage, Storage};
+
+    #[tokio::test]
+    async fn legacy_item_ids_are_rewritten_in_keys_and_values() -> Result<(), Box<dyn Error>> {
+        let storage = MemoryStorage::new();
+        let legacy = "item_1700000000000";
+        let item = json!({ "id": legacy, "title": "item_1", "content": "" });
+        storage
+            .save(&format!("items/{}", legacy), &serde_json::to_vec(&item)?)
+            .await?;
+        storage.save(&format!("history/{}", legacy), b"[]").await?;
+        let index = json!({ "item_tags": { legacy: ["todo"] } });
+        storage
+            .save("tags/index", &serde_json::to_vec(&index)?)
+            .await?;
+
+        assert_eq!(migrate(&storage).await?, 1);
+
+        let items = storage.scan_prefix("items/").await?;
+        assert_eq!(items.len(), 1);
+        let (key, data) = &items[0];
+        let id = key.trim_start_matches("items/");
+        assert_eq!(id.len(), 26);
+        let item: Value = serde_json::from_slice(data)?;
+        assert_eq!(item["id"], id);
+        assert_eq!(item["title"], "item_1");
+        assert!(storage.load(&format!("history/{}", id)).await.is_ok());
+        assert!(storage.load(&format!("history/{}", legacy)).await.is_err());
+        let index: Value = serde_json::from_slice(&storage.load("tags/index").await?)?;
+        assert_eq!(index["item_tags"][id], json!(["todo"]));
+
+        // 이미 마이그레이션했으면 다시 실행하지 않는다
+        assert_eq!(migrate(&storage).await?, 1);
+        assert_eq!(storage.scan_prefix("items/").await?, items);
+        Ok(())
+    }
+}
@@ -1,49 +1,228 @@
 mod auth;
 mod user;
 mod data;
+mod tags;
+mod history;
 mod network;
+mod api;
+mod circuit;
+mod speed_test;
 mod supervisor;
+mod config;
+mod i18n;
+mod notification;
+mod archive;
+mod hash;
+mod crypto;
+mod tabular;
+mod markdown;
+// MQTT(rumqttc)와 Opus 인코딩은 네이티브 전용
+#[cfg(not(target_family = "wasm"))]
+mod mqtt;
+mod sensitive;
+mod time;
+#[cfg(not(target_family = "wasm"))]
+mod audio;
+mod websocket;
+mod chat;
+mod presence;
+mod privacy;
+mod router;
+mod ocr;
+mod ranking;
+mod event_bus;
+#[cfg(feature = "ml")]
+mod embedding;
+mod automation;
+mod attachment;
+mod media_cache;
+mod prefetch;
+mod collab;
+mod metrics;
+mod lanes;
+mod startup;
+mod clock;
+mod platform;
+mod offload;
+mod trace;
+mod deadline;
+mod builder;
+mod validation;
+mod currency;
+mod recurrence;
+mod diff;
+mod fuzzy;
+mod search;
+mod inbox;
+mod feed;
+mod diagnostics;
+mod admin;
+#[cfg(all(feature = "scripting", not(target_family = "wasm")))]
+mod script;
+#[cfg(all(feature = "wasm-rules", not(target_family = "wasm")))]
+mod rules;
+mod quota;
+mod billing;
+mod mail;
+mod ingest;
+mod link_preview;
+mod feed_reader;
+mod calendar;
+mod contacts;
+mod dedup;
+mod storage_usage;
+mod maintenance;
+mod text_analysis;
+mod template;
+mod id_gen;
+mod migrations;
+mod user_lock;
+mod tracked_task;
+mod idempotency;
+mod sync_crypto;
+mod scheduler;
+mod governor;
+// 창을 닫은 동안의 백그라운드 모드와 서명된 로컬 명령 채널은 데스크톱 전용
+#[cfg(not(any(target_family = "wasm", target_os = "android", target_os = "ios")))]
+mod background;
+#[cfg(all(
+    feature = "command-channel",
+    not(any(target_family = "wasm", target_os = "android", target_os = "ios"))
+))]
+mod command_channel;
+mod handler_check;
 
 pub use auth::AuthActor;
 pub use user::{UserManagerActor, UserProfileActor};
 pub use data::{DataManagerActor, CacheActor, StorageActor};
-pub use network::NetworkManagerActor;
+pub use network::{NetworkManagerActor, StartNetworkMonitor};
+pub use api::ApiClient;
+pub use circuit::CircuitBreaker;
 pub use supervisor::AppSupervisor;
+pub use config::ConfigActor;
+pub use i18n::I18nActor;
+pub use notification::NotificationActor;
+pub use archive::ArchiveActor;
+pub use hash::HashActor;
+pub use crypto::CryptoActor;
+pub use tabular::TabularImportActor;
+pub use markdown::MarkdownActor;
+#[cfg(not(target_family = "wasm"))]
+pub use mqtt::MqttActor;
+pub use sensitive::SensitivePayloadActor;
+pub use time::{TimeActor, TrustedClock};
+#[cfg(not(target_family = "wasm"))]
+pub use audio::AudioActor;
+pub use websocket::WebSocketActor;
+pub use chat::ChatActor;
+pub use presence::PresenceActor;
+pub use privacy::PrivacyActor;
+pub use router::RouterActor;
+pub use ocr::OcrPrepActor;
+pub use ranking::RankingActor;
+pub use event_bus::EventBus;
+#[cfg(feature = "ml")]
+pub use embedding::EmbeddingActor;
+pub use automation::AutomationActor;
+pub use attachment::AttachmentActor;
+pub use media_cache::MediaCacheActor;
+pub use prefetch::PrefetchActor;
+pub use collab::CollabActor;
+pub use metrics::{MetricsActor, Timed};
+pub use lanes::{Prioritized, Priority, PriorityMailbox};
+pub use startup::{DeferredStart, StartupTimer};
+pub use clock::{Clock, Instant};
+#[cfg(feature = "simulation")]
+pub use clock::VirtualClock;
+pub use platform::PlatformActor;
+pub use offload::{OffloadError, WorkerJob, offload};
+#[cfg(not(target_family = "wasm"))]
+pub use offload::jobs_in_flight;
+pub use trace::{TraceId, Traced, trace_timeline};
+pub use deadline::Deadline;
+pub use builder::{ActorBuilder, ActorRegistry, BuildError};
+pub use validation::{ValidationActor, normalize_email, normalize_phone, validate_form};
+pub use currency::CurrencyActor;
+pub use recurrence::{RRuleActor, expand_recurrence};
+pub use diff::DiffActor;
+pub use fuzzy::{FuzzyMatchActor, fuzzy_match};
+pub use search::SearchCoordinatorActor;
+pub use inbox::InboxActor;
+pub use feed::FeedActor;
+pub use diagnostics::DiagnosticsActor;
+pub use admin::AdminActor;
+#[cfg(all(feature = "scripting", not(target_family = "wasm")))]
+pub use script::ScriptActor;
+#[cfg(all(feature = "wasm-rules", not(target_family = "wasm")))]
+pub use rules::RulesEngineActor;
+pub use quota::QuotaActor;
+pub use billing::BillingActor;
+pub use mail::MailActor;
+pub use ingest::IngestActor;
+pub use link_preview::LinkPreviewActor;
+pub use feed_reader::FeedReaderActor;
+pub use calendar::CalendarActor;
+pub use contacts::ContactsActor;
+pub use dedup::DedupActor;
+pub use storage_usage::StorageUsageActor;
+pub use maintenance::MaintenanceActor;
+use diagnostics::{actor_started, actor_stopped};
+pub use text_analysis::TextAnalysisActor;
+pub use template::TemplateActor;
+pub use id_gen::{IdGenActor, generate_id};
+pub use user_lock::UserLockMap;
+pub use tracked_task::TrackedTask;
+pub use sync_crypto::SyncCryptoActor;
+pub use scheduler::{SchedulerActor, Throttle};
+pub use governor::{PerformanceGovernorActor, performance_level, record_frame_time};
+#[cfg(not(any(target_family = "wasm", target_os = "android", target_os = "ios")))]
+pub use background::BackgroundModeActor;
+#[cfg(all(
+    feature = "command-channel",
+    not(any(target_family = "wasm", target_os = "android", target_os = "ios"))
+))]
+pub use command_channel::CommandChannelActor;
 
-use messages::prelude::{Address, Context};
-use rinf::debug_print;
-use tokio::spawn;
-
-use crate::study_actors::signals::{ActorsCreatedSignal, CreateActorsRequest};
+use crate::study_actors::logging::debug_print;
+use crate::study_actors::signals::{
+    ActorsCreatedSignal, CreateActorsRequest, EmitSignal, ReceiveSignal,
+};
 
 pub async fn create_actors() {
     // Dart 신호를 기다려 Actor 생성 시작
-    let receiver = CreateActorsRequest::get_dart_signal_receiver();
+    let receiver = CreateActorsRequest::signal_receiver();
     debug_print!("Waiting for CreateActorsRequest signal from Dart...");
     
     if let Some(signal_pack) = receiver.recv().await {
         let initialize_all = signal_pack.message.initialize_all;
+        let config_path = signal_pack.message.config_path;
         debug_print!("Received CreateActorsRequest: initialize_all={}", initialize_all);
+        #[cfg(not(target_family = "wasm"))]
+        crate::study_actors::runtime::mark_actors_started();
         
-        // 계층적으로 Actor 생성
-        let supervisor_context = Context::new();
-        let supervisor_addr = supervisor_context.address();
+        // 계층적으로 Actor 생성 (감독자가 하위 액터를 의존성 순서대로 만든다)
+        let supervisor_builder = ActorBuilder::new();
+        let supervisor = match AppSupervisor::new(
+            supervisor_builder.address(),
+            initialize_all,
+            config_path,
+        ) {
+            Ok(supervisor) => supervisor,
+            Err(e) => {
+                debug_print!("Failed to create actors: {}", e);
+                return;
+            }
+        };
         
-        // 감독자 Actor 생성 및 실행
-        let supervisor = AppSupervisor::new(supervisor_addr.clone(), initialize_all);
-        spawn(supervisor_context.run(supervisor));
+        // Dart에 Actor 생성 완료 신호 전송 (기능 플래그에 따라 감독자가 만든 액터가 달라짐)
+        let mut initialized_actors = vec!["AppSupervisor".to_string()];
+        initialized_actors.extend(supervisor.actor_names());
+        supervisor_builder.start(supervisor);
         
-        // Dart에 Actor 생성 완료 신호 전송
         ActorsCreatedSignal {
-            actor_count: 5, // 실제 생성된 Actor 수
-            initialized_actors: vec![
-                "AppSupervisor".to_string(),
-                "UserManagerActor".to_string(),
-                "DataManagerActor".to_string(),
-                "NetworkManagerActor".to_string(),
-                "AuthActor".to_string(),
-            ],
-        }.send_signal_to_dart();
+            actor_count: initialized_actors.len() as u64, // 실제 생성된 Actor 수
+            initialized_actors,
+        }.emit();
         
         debug_print!("Actors created and initialized successfully");
     }
@@ -0,0 +1,291 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use rumqttc::{AsyncClient, Event, EventLoop, MqttOptions, Packet, QoS};
+use std::{collections::HashMap, time::Duration};
+use tokio::task::{AbortHandle, JoinSet};
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{MqttError, MqttPublish, MqttQos, MqttSubscribe},
+    signals::{
+        EmitSignal, MqttConnectRequest, MqttConnectionSignal, MqttDisconnectRequest,
+        MqttMessageSignal, MqttPublishRequest, MqttSubscribeRequest, MqttUnsubscribeRequest,
+        ReceiveBinarySignal, route_dart_signals,
+    },
+};
+
+const REQUEST_CHANNEL_CAPACITY: usize = 64;
+const DEFAULT_KEEP_ALIVE_SECS: u64 = 30;
+const MAX_RECONNECT_DELAY_SECS: u64 = 30;
+
+fn to_qos(qos: MqttQos) -> QoS {
+    match qos {
+        MqttQos::AtMostOnce => QoS::AtMostOnce,
+        MqttQos::AtLeastOnce => QoS::AtLeastOnce,
+        MqttQos::ExactlyOnce => QoS::ExactlyOnce,
+    }
+}
+
+// MQTT 클라이언트 액터
+// Dart에서 연결을 요청하기 전까지는 아무 동작도 하지 않는다.
+pub struct MqttActor {
+    client: Option<AsyncClient>,
+    connected: bool,
+    subscriptions: HashMap<String, MqttQos>,
+    event_loop: Option<AbortHandle>,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for MqttActor {}
+
+impl MqttActor {
+    pub fn new(self_addr: Address<Self>) -> Self {
+        let mut owned_tasks = route_dart_signals!(
+            self_addr,
+            [
+                MqttConnectRequest,
+                MqttDisconnectRequest,
+                MqttSubscribeRequest,
+                MqttUnsubscribeRequest
+            ]
+        );
+        owned_tasks.spawn(Self::listen_to_publish_requests(self_addr));
+
+        Self {
+            client: None,
+            connected: false,
+            subscriptions: HashMap::new(),
+            event_loop: None,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    async fn listen_to_publish_requests(mut self_addr: Address<Self>) {
+        let receiver = MqttPublishRequest::binary_signal_receiver();
+        while let Some(signal_pack) = receiver.recv().await {
+            let _ = self_addr
+                .notify(DartPublish(signal_pack.message, signal_pack.binary))
+                .await;
+        }
+    }
+
+    // 이벤트 루프 폴링
+    // 오류가 나면 지수 백오프 후 다시 폴링하며, rumqttc가 재연결을 수행한다.
+    async fn run_event_loop(mut self_addr: Address<Self>, mut event_loop: EventLoop) {
+        let mut delay_secs = 1;
+        loop {
+            match event_loop.poll().await {
+                Ok(Event::Incoming(Packet::ConnAck(ack))) => {
+                    delay_secs = 1;
+                    let _ = self_addr
+                        .notify(ConnectionChanged {
+                            connected: true,
+                            session_present: ack.session_present,
+                            error: None,
+                        })
+                        .await;
+                }
+                Ok(Event::Incoming(Packet::Publish(publish))) => {
+                    MqttMessageSignal {
+                        topic: publish.topic,
+                        payload: publish.payload.to_vec(),
+                    }
+                    .emit();
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    let _ = self_addr
+                        .notify(ConnectionChanged {
+                            connected: false,
+                            session_present: false,
+                            error: Some(e.to_string()),
+                        })
+                        .await;
+                    tokio::time::sleep(Duration::from_secs(delay_secs)).await;
+                    delay_secs = (delay_secs * 2).min(MAX_RECONNECT_DELAY_SECS);
+                }
+            }
+        }
+    }
+
+    fn client(&self) -> Result<&AsyncClient, MqttError> {
+        self.client
+            .as_ref()
+            .ok_or_else(|| "MQTT client is not connected".into())
+    }
+
+    async fn disconnect(&mut self) {
+        if let Some(handle) = self.event_loop.take() {
+            handle.abort();
+        }
+        if let Some(client) = self.client.take() {
+            let _ = client.try_disconnect();
+        }
+        self.connected = false;
+    }
+}
+
+// 내부 메시지 정의
+struct ConnectionChanged {
+    connected: bool,
+    session_present: bool,
+    error: Option<String>,
+}
+
+struct DartPublish(MqttPublishRequest, Vec<u8>);
+
+#[async_trait]
+impl Notifiable<ConnectionChanged> for MqttActor {
+    async fn notify(&mut self, msg: ConnectionChanged, _: &Context<Self>) {
+        // 재연결 실패가 반복될 때는 상태가 바뀐 경우에만 Dart에 알림
+        if !msg.connected && !self.connected {
+            return;
+        }
+        self.connected = msg.connected;
+
+        // 브로커에 세션이 남아 있지 않으면 구독을 다시 등록
+        if msg.connected
+            && !msg.session_present
+            && let Some(client) = &self.client
+        {
+            for (topic, qos) in &self.subscriptions {
+                if let Err(e) = client.subscribe(topic.as_str(), to_qos(*qos)).await {
+                    debug_print!("Failed to resubscribe {}: {}", topic, e);
+                }
+            }
+        }
+
+        debug_print!("MQTT connection changed: connected={}", msg.connected);
+        MqttConnectionSignal {
+            connected: msg.connected,
+            session_present: msg.session_present,
+            error: msg.error,
+        }
+        .emit();
+    }
+}
+
+#[async_trait]
+impl Handler<MqttPublish> for MqttActor {
+    type Result = Result<(), MqttError>;
+
+    async fn handle(&mut self, msg: MqttPublish, _: &Context<Self>) -> Self::Result {
+        self.client()?
+            .publish(msg.topic, to_qos(msg.qos), msg.retain, msg.payload)
+            .await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Handler<MqttSubscribe> for MqttActor {
+    type Result = Result<(), MqttError>;
+
+    async fn handle(&mut self, msg: MqttSubscribe, _: &Context<Self>) -> Self::Result {
+        // 연결 전 구독은 기록만 해 두고 연결 시 등록
+        if self.connected {
+            self.client()?
+                .subscribe(msg.topic.as_str(), to_qos(msg.qos))
+                .await?;
+        }
+        self.subscriptions.insert(msg.topic, msg.qos);
+        Ok(())
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<MqttConnectRequest> for MqttActor {
+    async fn notify(&mut self, msg: MqttConnectRequest, ctx: &Context<Self>) {
+        self.disconnect().await;
+
+        let mut options = MqttOptions::new(msg.client_id, msg.host, msg.port);
+        options.set_keep_alive(Duration::from_secs(
+            msg.keep_alive_secs.unwrap_or(DEFAULT_KEEP_ALIVE_SECS),
+        ));
+        // 재연결 시 브로커 세션을 이어받기 위해 clean session 비활성화
+        options.set_clean_session(false);
+        if let (Some(username), Some(password)) = (msg.username, msg.password) {
+            options.set_credentials(username, password);
+        }
+
+        let (client, event_loop) = AsyncClient::new(options, REQUEST_CHANNEL_CAPACITY);
+        self.client = Some(client);
+        self.event_loop = Some(
+            self._owned_tasks
+                .spawn(Self::run_event_loop(ctx.address(), event_loop)),
+        );
+    }
+}
+
+#[async_trait]
+impl Notifiable<MqttDisconnectRequest> for MqttActor {
+    async fn notify(&mut self, _: MqttDisconnectRequest, _: &Context<Self>) {
+        let was_connected = self.connected;
+        self.disconnect().await;
+        if was_connected {
+            MqttConnectionSignal {
+                connected: false,
+                session_present: false,
+                error: None,
+            }
+            .emit();
+        }
+    }
+}
+
+#[async_trait]
+impl Notifiable<MqttSubscribeRequest> for MqttActor {
+    async fn notify(&mut self, msg: MqttSubscribeRequest, ctx: &Context<Self>) {
+        let topic = msg.topic.clone();
+        let result = self
+            .handle(
+                MqttSubscribe {
+                    topic: msg.topic,
+                    qos: msg.qos,
+                },
+                ctx,
+            )
+            .await;
+        if let Err(e) = result {
+            debug_print!("MQTT subscribe failed for {}: {}", topic, e);
+        }
+    }
+}
+
+#[async_trait]
+impl Notifiable<MqttUnsubscribeRequest> for MqttActor {
+    async fn notify(&mut self, msg: MqttUnsubscribeRequest, _: &Context<Self>) {
+        self.subscriptions.remove(&msg.topic);
+        if let (true, Some(client)) = (self.connected, &self.client)
+            && let Err(e) = client.unsubscribe(msg.topic.as_str()).await
+        {
+            debug_print!("MQTT unsubscribe failed for {}: {}", msg.topic, e);
+        }
+    }
+}
+
+#[async_trait]
+impl Notifiable<DartPublish> for MqttActor {
+    async fn notify(&mut self, msg: DartPublish, ctx: &Context<Self>) {
+        let DartPublish(request, payload) = msg;
+        let topic = request.topic.clone();
+        let result = self
+            .handle(
+                MqttPublish {
+                    topic: request.topic,
+                    payload,
+                    qos: request.qos,
+                    retain: request.retain,
+                },
+                ctx,
+            )
+            .await;
+        if let Err(e) = result {
+            debug_print!("MQTT publish failed for {}: {}", topic, e);
+        }
+    }
+}
@@ -1,18 +1,42 @@
 use async_trait::async_trait;
+#[cfg(not(target_family = "wasm"))]
+use hyper_util::client::legacy::connect::HttpInfo;
 use messages::{
     actor::Actor,
     prelude::{Address, Context, Handler, Notifiable},
 };
 use reqwest::{
-    self, Body, Error, Method, Response, StatusCode,
+    self, Body, Error, Method, RequestBuilder, Response, StatusCode, Version,
     header::{HeaderMap, HeaderName, HeaderValue},
 };
-use rinf::{RustSignal, debug_print};
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, str::FromStr, time::Duration};
-use tokio::task::JoinSet;
+use std::{collections::HashMap, net::SocketAddr, str::FromStr, time::Duration};
+use tokio::{
+    sync::{
+        broadcast::{self, error::RecvError},
+        oneshot,
+    },
+    task::JoinSet,
+};
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        ActorResult, AppEvent, BandwidthUsage, ConnectionDiagnostics, NetworkConfig, PersistState,
+        PersistedState, RestoreState, TrafficClass, UserError,
+    },
+    signals::{
+        BandwidthUsageRequest, BandwidthUsageSignal, EmitSignal, ForceHttp1Request, HostWarmup,
+        PrefetchHostsRequest, PrefetchHostsStatus, RunSpeedTestRequest, SetDataSaverRequest,
+        route_dart_signals,
+    },
+};
 
-use crate::study_actors::messages::UserError;
+use super::{
+    Clock, Instant, TrustedClock,
+    lanes::prioritize,
+    speed_test::{SpeedTest, SpeedTestUsage},
+};
 
 // 네트워크 요청 타입
 #[derive(Debug)]
@@ -23,6 +47,8 @@ pub struct NetworkRequest {
     pub body: Option<Body>,
     pub timeout_ms: Option<u64>,
     pub json: Option<serde_json::Value>,
+    pub traffic: TrafficClass,
+    pub max_body_bytes: Option<usize>,
 }
 
 impl NetworkRequest {
@@ -34,9 +60,17 @@ impl NetworkRequest {
             body: None,
             timeout_ms: None,
             json: None,
+            traffic: TrafficClass::Other,
+            max_body_bytes: None,
         }
     }
 
+    // 대역폭 집계 분류 (비필수 분류는 데이터 절약 모드에서 보내지 않는다)
+    pub fn traffic(mut self, traffic: TrafficClass) -> Self {
+        self.traffic = traffic;
+        self
+    }
+
     pub fn method(mut self, method: Method) -> Self {
         self.method = method;
         self
@@ -54,8 +88,9 @@ impl NetworkRequest {
         self
     }
 
-    pub fn body(mut self, body: impl Into<Body>) -> Self {
-        self.body = Some(body.into());
+    // 응답 본문을 앞에서부터 이만큼만 읽는다 (남은 부분은 받지 않고 연결을 끊는다)
+    pub fn max_body(mut self, bytes: usize) -> Self {
+        self.max_body_bytes = Some(bytes);
         self
     }
 
@@ -73,6 +108,20 @@ pub struct NetworkResponse {
     pub headers: HeaderMap,
     pub body: Vec<u8>,
     pub error: Option<String>,
+    pub version: Option<Version>,       // 웹에서는 알 수 없다
+    pub local_addr: Option<SocketAddr>, // 응답이 온 연결의 로컬 소켓 (웹에서는 알 수 없다)
+}
+
+// 응답은 HTTP 왕복을 마친 작업이 보낸다 (그동안 액터는 다음 요청을 받는다)
+pub type PendingResponse = oneshot::Receiver<ActorResult<NetworkResponse>>;
+
+// 액터 밖에서 보낼 요청과 끝난 뒤 집계에 쓸 정보
+struct OutgoingRequest {
+    builder: RequestBuilder,
+    domain: String,
+    traffic: TrafficClass,
+    bytes_sent: u64,
+    max_body: Option<usize>,
 }
 
 impl NetworkResponse {
@@ -83,51 +132,300 @@ impl NetworkResponse {
     pub fn json<T: for<'de> Deserialize<'de>>(&self) -> Result<T, serde_json::Error> {
         serde_json::from_slice(&self.body)
     }
+}
 
-    pub fn text(&self) -> Result<String, std::string::FromUtf8Error> {
-        String::from_utf8(self.body.clone())
-    }
+// 미리 연결할 때 호스트 하나에 허용하는 최대 시간
+const WARMUP_TIMEOUT: Duration = Duration::from_secs(5);
+
+// 공유 클라이언트의 유휴 연결 유지 시간 (연결 재사용 추정에도 쓴다)
+const POOL_IDLE_TIMEOUT: Duration = Duration::from_secs(90);
+
+// 종료 시 남기는 사용량 기록의 구조 버전
+const STATE_VERSION: u32 = 1;
+
+// 다음 실행에서 이어서 셀 사용량과 데이터 절약 설정
+#[derive(Serialize, Deserialize)]
+struct NetworkState {
+    usage: Vec<BandwidthUsage>,
+    usage_since: u64,
+    data_saver: bool,
+}
+
+// 호스트별 연결 진단과 응답이 온 로컬 소켓 (같은 소켓으로 다시 오면 풀의 연결을 재사용한 것이다)
+struct ConnectionTracker {
+    diagnostics: ConnectionDiagnostics,
+    sockets: HashMap<SocketAddr, Instant>,
 }
 
 // 네트워크 관리자 액터
 pub struct NetworkManagerActor {
+    // 연결 풀을 유지하도록 모든 요청이 하나의 클라이언트를 공유한다
+    client: reqwest::Client,
+    force_http1: bool,
+    connections: HashMap<String, ConnectionTracker>,
     connection_pool: HashMap<String, u32>, // 도메인별 연결 수 추적
     max_connections: usize,
+    monitor_interval_secs: u64,
+    clock: TrustedClock,
+    usage: HashMap<TrafficClass, BandwidthUsage>,
+    usage_since: u64,
+    data_saver: bool, // 사용자가 켠 데이터 절약 모드
+    metered: bool,    // Dart가 알려 준 현재 연결의 종량제 여부
+    speed_test_url: Option<String>,
+    speed_test_download_bytes: u64,
+    speed_test_upload_bytes: u64,
+    speed_test_running: bool,
     _owned_tasks: JoinSet<()>,
 }
 
 impl Actor for NetworkManagerActor {}
 
+// 화면이 기다리는 요청이 백그라운드 요청을 앞지르도록 우선순위 메일박스로도 받는다
+prioritize!(NetworkManagerActor, handle: NetworkRequest);
+
 impl NetworkManagerActor {
-    pub fn new() -> Self {
-        let owned_tasks = JoinSet::new();
+    pub fn new(
+        self_addr: Address<Self>,
+        config: NetworkConfig,
+        clock: TrustedClock,
+        events: broadcast::Receiver<AppEvent>,
+    ) -> Self {
+        let mut owned_tasks = route_dart_signals!(
+            self_addr,
+            [
+                PrefetchHostsRequest,
+                SetDataSaverRequest,
+                BandwidthUsageRequest,
+                ForceHttp1Request,
+                RunSpeedTestRequest
+            ]
+        );
+        owned_tasks.spawn(Self::listen_to_events(self_addr, events));
 
         Self {
+            client: Self::build_client(config.force_http1),
+            force_http1: config.force_http1,
+            connections: HashMap::new(),
             connection_pool: HashMap::new(),
-            max_connections: 10,
+            max_connections: config.max_connections,
+            monitor_interval_secs: config.monitor_interval_secs,
+            usage: HashMap::new(),
+            usage_since: clock.trusted_now(),
+            clock,
+            data_saver: false,
+            metered: false,
+            speed_test_url: config.speed_test_url,
+            speed_test_download_bytes: config.speed_test_download_bytes,
+            speed_test_upload_bytes: config.speed_test_upload_bytes,
+            speed_test_running: false,
             _owned_tasks: owned_tasks,
         }
     }
 
+    // 연결 상태 이벤트에서 종량제 여부만 가져온다
+    async fn listen_to_events(
+        mut self_addr: Address<Self>,
+        mut events: broadcast::Receiver<AppEvent>,
+    ) {
+        loop {
+            match events.recv().await {
+                Ok(AppEvent::ConnectivityChanged { metered, .. }) => {
+                    let _ = self_addr.notify(MeteredChanged(metered)).await;
+                }
+                Ok(_) | Err(RecvError::Lagged(_)) => {}
+                Err(RecvError::Closed) => break,
+            }
+        }
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn build_client(force_http1: bool) -> reqwest::Client {
+        let mut builder = reqwest::Client::builder()
+            .pool_idle_timeout(POOL_IDLE_TIMEOUT)
+            .min_tls_version(reqwest::tls::Version::TLS_1_2);
+        if force_http1 {
+            builder = builder.http1_only();
+        }
+        builder.build().unwrap_or_else(|e| {
+            debug_print!("Failed to build HTTP client, using defaults: {}", e);
+            reqwest::Client::new()
+        })
+    }
+
+    // 웹에서는 브라우저가 프로토콜과 연결을 정한다
+    #[cfg(target_family = "wasm")]
+    fn build_client(_force_http1: bool) -> reqwest::Client {
+        reqwest::Client::new()
+    }
+
+    fn track_request(&mut self, domain: &str) {
+        let tracker = self
+            .connections
+            .entry(domain.to_string())
+            .or_insert_with(|| ConnectionTracker {
+                diagnostics: ConnectionDiagnostics {
+                    host: domain.to_string(),
+                    tls_version: None, // reqwest가 협상된 버전을 알려주지 않는다
+                    ..Default::default()
+                },
+                sockets: HashMap::new(),
+            });
+        tracker.diagnostics.requests += 1;
+    }
+
+    // 응답이 온 연결로 새 연결인지 재사용인지 센다 (연결 정보가 없으면 세지 않는다)
+    fn record_connection(
+        &mut self,
+        domain: &str,
+        version: Option<Version>,
+        local_addr: Option<SocketAddr>,
+    ) {
+        let Some(tracker) = self.connections.get_mut(domain) else {
+            return;
+        };
+        // https면 ALPN으로 협상한 프로토콜이다
+        if let Some(version) = version {
+            tracker.diagnostics.protocol = Some(format!("{:?}", version));
+        }
+        let Some(local_addr) = local_addr else {
+            return;
+        };
+        let now = Instant::now();
+        // 유휴 시간이 지난 연결은 풀에서 닫혔으니 같은 포트가 다시 나와도 새 연결이다
+        tracker
+            .sockets
+            .retain(|_, last_used| now.saturating_duration_since(*last_used) <= POOL_IDLE_TIMEOUT);
+        let reused = tracker.sockets.insert(local_addr, now).is_some();
+        let diagnostics = &mut tracker.diagnostics;
+        *diagnostics.connections_opened.get_or_insert(0) += u64::from(!reused);
+        *diagnostics.reused.get_or_insert(0) += u64::from(reused);
+    }
+
+    fn data_saver_active(&self) -> bool {
+        self.data_saver && self.metered
+    }
+
+    fn usage_mut(&mut self, traffic: TrafficClass) -> &mut BandwidthUsage {
+        self.usage.entry(traffic).or_insert_with(|| BandwidthUsage {
+            class: traffic,
+            ..Default::default()
+        })
+    }
+
+    fn usage_signal(&self) -> BandwidthUsageSignal {
+        BandwidthUsageSignal {
+            usage: TrafficClass::ALL
+                .iter()
+                .filter_map(|traffic| self.usage.get(traffic).cloned())
+                .collect(),
+            since: self.usage_since,
+            data_saver: self.data_saver,
+            metered: self.metered,
+            connections: self
+                .connections
+                .values()
+                .map(|tracker| tracker.diagnostics.clone())
+                .collect(),
+            force_http1: self.force_http1,
+        }
+    }
+
     fn started(&mut self, ctx: &Context<Self>) {
         // actor가 인스턴스화 되고 context에서 주소를 얻는 방법이 일반적이다.
         let self_addr = ctx.address();
 
         // 네트워크 상태 모니터링 작업 시작
-        self._owned_tasks
-            .spawn(Self::monitor_network_status(self_addr));
+        self._owned_tasks.spawn(Self::monitor_network_status(
+            self_addr,
+            self.clock.clock().clone(),
+            self.monitor_interval_secs,
+        ));
     }
 
-    async fn monitor_network_status(_self_addr: Address<Self>) {
+    async fn monitor_network_status(_self_addr: Address<Self>, clock: Clock, interval_secs: u64) {
         // 실제 구현에서는 주기적으로 네트워크 상태 확인
-        let mut interval = tokio::time::interval(std::time::Duration::from_secs(30));
+        let mut interval = clock.interval(Duration::from_secs(interval_secs));
         loop {
             interval.tick().await;
             // 실제 구현에서는 self_addr.notify(CheckNetworkStatus).await 호출
         }
     }
 
-    fn extract_domain(&self, url: &str) -> String {
+    // 호스트들을 동시에 조회/연결하고 결과를 한 번에 알린다
+    // 연결은 공유 클라이언트의 풀에 남아 첫 요청이 재사용한다.
+    async fn warm_up_hosts(client: reqwest::Client, hosts: Vec<String>) {
+        let started = Instant::now();
+        let mut tasks = JoinSet::new();
+        for host in hosts {
+            tasks.spawn(Self::warm_up_host(client.clone(), host));
+        }
+
+        let mut results = Vec::new();
+        while let Some(joined) = tasks.join_next().await {
+            match joined {
+                Ok(result) => results.push(result),
+                Err(e) => debug_print!("Warm-up task failed: {}", e),
+            }
+        }
+
+        PrefetchHostsStatus {
+            hosts: results,
+            elapsed_ms: started.elapsed().as_millis() as u64,
+        }
+        .emit();
+    }
+
+    async fn warm_up_host(client: reqwest::Client, host: String) -> HostWarmup {
+        let mut warmup = HostWarmup {
+            host,
+            dns_ms: None,
+            connect_ms: None,
+            error: None,
+        };
+
+        // 브라우저에는 DNS 조회 API가 없으므로 웹에서는 연결 단계만 잰다
+        #[cfg(not(target_family = "wasm"))]
+        {
+            let dns_started = Instant::now();
+            match tokio::net::lookup_host((warmup.host.clone(), 443)).await {
+                Ok(_) => warmup.dns_ms = Some(dns_started.elapsed().as_millis() as u64),
+                Err(e) => {
+                    warmup.error = Some(format!("DNS lookup failed: {}", e));
+                    return warmup;
+                }
+            }
+        }
+
+        // 상태 코드와 상관없이 응답을 받았다면 TLS 연결은 끝난 것이다
+        let connect_started = Instant::now();
+        let url = format!("https://{}/", warmup.host);
+        let request = client.head(&url).timeout(WARMUP_TIMEOUT).send();
+        #[cfg(target_family = "wasm")]
+        let request = send_wrapper::SendWrapper::new(request);
+        match request.await {
+            Ok(_) => warmup.connect_ms = Some(connect_started.elapsed().as_millis() as u64),
+            Err(e) => warmup.error = Some(format!("Connection failed: {}", e)),
+        }
+        warmup
+    }
+
+    // 측정이 끝나면 결과를 알리고 사용한 트래픽을 액터에 돌려준다
+    async fn run_speed_test(mut self_addr: Address<Self>, test: SpeedTest) {
+        let run = test.run();
+        #[cfg(target_family = "wasm")]
+        let run = send_wrapper::SendWrapper::new(run);
+        let (result, usage) = run.await;
+        debug_print!(
+            "Speed test: {:?} ms, down {:?} Mbps, up {:?} Mbps",
+            result.latency_ms,
+            result.download_mbps,
+            result.upload_mbps
+        );
+        result.emit();
+        let _ = self_addr.notify(SpeedTestFinished(usage)).await;
+    }
+
+    fn extract_domain(url: &str) -> String {
         // 간단한 도메인 추출 (실제 구현에서는 더 정교한 방법 필요)
         url.split("://")
             .nth(1)
@@ -137,14 +435,16 @@ impl NetworkManagerActor {
             .unwrap_or(url)
             .to_string()
     }
-}
 
-#[async_trait]
-impl Handler<NetworkRequest> for NetworkManagerActor {
-    type Result = Result<NetworkResponse, UserError>;
+    // 데이터 절약과 연결 수를 확인하고 보낼 요청을 만든다
+    fn prepare_request(&mut self, msg: NetworkRequest) -> ActorResult<OutgoingRequest> {
+        let traffic = msg.traffic;
+        if self.data_saver_active() && !traffic.is_essential() {
+            self.usage_mut(traffic).deferred += 1;
+            return Err(UserError::DataSaverDeferred(format!("{:?}", traffic)));
+        }
 
-    async fn handle(&mut self, msg: NetworkRequest, _: &Context<Self>) -> Self::Result {
-        let domain = self.extract_domain(&msg.url);
+        let domain = Self::extract_domain(&msg.url);
 
         // 연결 수 증가
         let connection_count = self.connection_pool.entry(domain.clone()).or_insert(0);
@@ -153,64 +453,95 @@ impl Handler<NetworkRequest> for NetworkManagerActor {
         // 최대 연결 수 초과 확인
         if *connection_count > self.max_connections as u32 {
             *connection_count -= 1;
-            return Err(Box::new(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("Too many connections to domain: {}", domain),
-            )) as UserError);
+            return Err(UserError::TooManyConnections(domain));
         }
+        self.track_request(&domain);
 
         debug_print!("Sending {} request to {}", msg.method.as_str(), msg.url);
 
-        // reqwest 클라이언트 생성
-        let client = reqwest::Client::builder();
+        // 요청 생성
+        let mut builder = self.client.request(msg.method.clone(), &msg.url);
 
         // 타임아웃 설정
-        let client = if let Some(timeout) = msg.timeout_ms {
-            client.timeout(Duration::from_millis(timeout))
-        } else {
-            client
-        };
-
-        let client = client.build().map_err(|e| {
-            debug_print!("Failed to build HTTP client: {}", e);
-            Box::new(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("Network error: Failed to build HTTP client: {}", e),
-            )) as UserError
-        })?;
-
-        // 요청 생성
-        let mut request_builder = client.request(msg.method.clone(), &msg.url);
+        if let Some(timeout) = msg.timeout_ms {
+            builder = builder.timeout(Duration::from_millis(timeout));
+        }
 
         // 헤더 설정
-        request_builder = request_builder.headers(msg.headers.clone());
+        builder = builder.headers(msg.headers.clone());
 
-        // JSON 또는 바디 설정
+        // JSON 또는 바디 설정 (보낸 양은 본문 크기로 센다)
+        let mut bytes_sent = 0;
         if let Some(json) = msg.json {
-            request_builder = request_builder.json(&json);
+            bytes_sent = serde_json::to_vec(&json).map_or(0, |bytes| bytes.len());
+            builder = builder.json(&json);
         } else if let Some(body) = msg.body {
-            request_builder = request_builder.body(body);
+            bytes_sent = body.as_bytes().map_or(0, <[u8]>::len);
+            builder = builder.body(body);
         }
 
-        // 요청 실행
-        let result = match request_builder.send().await {
+        Ok(OutgoingRequest {
+            builder,
+            domain,
+            traffic,
+            bytes_sent: bytes_sent as u64,
+            max_body: msg.max_body_bytes,
+        })
+    }
+
+    // 응답을 받으면 집계를 먼저 맡긴 뒤 결과를 보낸다
+    // (요청한 쪽이 이어서 보내는 메시지는 집계가 끝난 뒤에 처리된다)
+    async fn send_request(
+        mut self_addr: Address<Self>,
+        request: OutgoingRequest,
+        reply: oneshot::Sender<ActorResult<NetworkResponse>>,
+    ) {
+        let response = Self::execute(request.builder, request.max_body).await;
+        let finished = RequestFinished {
+            domain: request.domain,
+            traffic: request.traffic,
+            bytes_sent: request.bytes_sent,
+            bytes_received: response.body.len() as u64,
+            version: response.version,
+            local_addr: response.local_addr,
+        };
+        let _ = self_addr.notify(finished).await;
+        let _ = reply.send(Ok(response));
+    }
+
+    // 요청을 보내고 응답 본문까지 읽는다 (네이티브는 reqwest, 웹은 브라우저 fetch)
+    async fn execute(request_builder: RequestBuilder, max_body: Option<usize>) -> NetworkResponse {
+        match request_builder.send().await {
             Ok(resp) => {
                 let status = resp.status();
                 let headers = resp.headers().clone();
+                #[cfg(not(target_family = "wasm"))]
+                let (version, local_addr) = (
+                    Some(resp.version()),
+                    resp.extensions()
+                        .get::<HttpInfo>()
+                        .map(HttpInfo::local_addr),
+                );
+                #[cfg(target_family = "wasm")]
+                let (version, local_addr) = (None, None);
 
                 // 응답 바디 읽기
-                match resp.bytes().await {
-                    Ok(bytes) => NetworkResponse {
+                match Self::read_body(resp, max_body).await {
+                    Ok(body) => NetworkResponse {
                         status,
                         headers,
-                        body: bytes.to_vec(),
+                        body,
                         error: None,
+                        version,
+                        local_addr,
                     },
                     Err(e) => NetworkResponse {
                         status,
                         headers,
                         body: Vec::new(),
                         error: Some(format!("Failed to read response body: {}", e)),
+                        version,
+                        local_addr,
                     },
                 }
             }
@@ -219,15 +550,119 @@ impl Handler<NetworkRequest> for NetworkManagerActor {
                 headers: HeaderMap::new(),
                 body: Vec::new(),
                 error: Some(format!("Request failed: {}", e)),
+                version: None,
+                local_addr: None,
             },
+        }
+    }
+
+    // 상한이 있으면 넘는 순간 읽기를 멈춘다 (웹의 fetch는 조각 읽기가 없어 다 받은 뒤 자른다)
+    async fn read_body(resp: Response, max_body: Option<usize>) -> Result<Vec<u8>, Error> {
+        let Some(max_body) = max_body else {
+            return Ok(Vec::from(resp.bytes().await?));
+        };
+        #[cfg(not(target_family = "wasm"))]
+        let mut body = {
+            let mut resp = resp;
+            let mut body = Vec::new();
+            while let Some(chunk) = resp.chunk().await? {
+                body.extend_from_slice(&chunk);
+                if body.len() >= max_body {
+                    break;
+                }
+            }
+            body
         };
+        #[cfg(target_family = "wasm")]
+        let mut body = Vec::from(resp.bytes().await?);
+        body.truncate(max_body);
+        Ok(body)
+    }
+}
+
+#[async_trait]
+impl Handler<NetworkRequest> for NetworkManagerActor {
+    type Result = PendingResponse;
+
+    // HTTP 왕복은 별도 작업에서 하므로 느린 요청이 뒤의 요청을 막지 않는다
+    async fn handle(&mut self, msg: NetworkRequest, ctx: &Context<Self>) -> Self::Result {
+        let (reply, pending) = oneshot::channel();
+        match self.prepare_request(msg) {
+            Ok(request) => {
+                while self._owned_tasks.try_join_next().is_some() {}
+                let task = Self::send_request(ctx.address(), request, reply);
+                // 웹의 fetch Future는 Send가 아니지만 웹에서는 한 스레드만 쓴다
+                #[cfg(target_family = "wasm")]
+                let task = send_wrapper::SendWrapper::new(task);
+                self._owned_tasks.spawn(task);
+            }
+            Err(e) => {
+                let _ = reply.send(Err(e));
+            }
+        }
+        pending
+    }
+}
+
+// 네트워크 상태 모니터링 시작 메시지 (감독자가 콜드 스타트 이후에 보낸다)
+pub struct StartNetworkMonitor;
+
+#[async_trait]
+impl Notifiable<StartNetworkMonitor> for NetworkManagerActor {
+    async fn notify(&mut self, _: StartNetworkMonitor, ctx: &Context<Self>) {
+        self.started(ctx);
+    }
+}
+
+// 내부 메시지 정의 (연결 상태 이벤트의 종량제 여부)
+struct MeteredChanged(bool);
 
+#[async_trait]
+impl Notifiable<MeteredChanged> for NetworkManagerActor {
+    async fn notify(&mut self, msg: MeteredChanged, _: &Context<Self>) {
+        if self.metered != msg.0 {
+            self.metered = msg.0;
+            debug_print!("Metered connection: {}", self.metered);
+        }
+    }
+}
+
+// 요청 하나가 끝나 연결 수와 사용량을 정리한다
+struct RequestFinished {
+    domain: String,
+    traffic: TrafficClass,
+    bytes_sent: u64,
+    bytes_received: u64,
+    version: Option<Version>,
+    local_addr: Option<SocketAddr>,
+}
+
+#[async_trait]
+impl Notifiable<RequestFinished> for NetworkManagerActor {
+    async fn notify(&mut self, msg: RequestFinished, _: &Context<Self>) {
         // 연결 수 감소
-        if let Some(count) = self.connection_pool.get_mut(&domain) {
+        if let Some(count) = self.connection_pool.get_mut(&msg.domain) {
             *count = count.saturating_sub(1);
         }
+        self.record_connection(&msg.domain, msg.version, msg.local_addr);
+
+        let usage = self.usage_mut(msg.traffic);
+        usage.requests += 1;
+        usage.bytes_sent += msg.bytes_sent;
+        usage.bytes_received += msg.bytes_received;
+    }
+}
+
+struct SpeedTestFinished(SpeedTestUsage);
 
-        Ok(result)
+#[async_trait]
+impl Notifiable<SpeedTestFinished> for NetworkManagerActor {
+    async fn notify(&mut self, msg: SpeedTestFinished, _: &Context<Self>) {
+        self.speed_test_running = false;
+        let usage = self.usage_mut(TrafficClass::Other);
+        usage.bytes_sent += msg.0.bytes_sent;
+        usage.bytes_received += msg.0.bytes_received;
+        usage.requests += msg.0.requests;
     }
 }
 
@@ -242,3 +677,262 @@ impl Notifiable<CheckNetworkStatus> for NetworkManagerActor {
         // 실제 구현에서는 네트워크 상태 확인 및 문제 해결
     }
 }
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<PrefetchHostsRequest> for NetworkManagerActor {
+    async fn notify(&mut self, msg: PrefetchHostsRequest, _: &Context<Self>) {
+        let mut hosts: Vec<String> = msg
+            .hosts
+            .iter()
+            .map(|host| Self::extract_domain(host))
+            .filter(|host| !host.is_empty())
+            .collect();
+        hosts.sort();
+        hosts.dedup();
+        // 미리 연결은 본문이 없으므로 요청 수만 센다
+        if self.data_saver_active() {
+            self.usage_mut(TrafficClass::Prefetch).deferred += hosts.len() as u64;
+            debug_print!(
+                "Data saver is on, skipping warm-up of {} hosts",
+                hosts.len()
+            );
+            return;
+        }
+        self.usage_mut(TrafficClass::Prefetch).requests += hosts.len() as u64;
+        debug_print!("Warming up {} hosts", hosts.len());
+
+        // 조회/연결이 끝날 때까지 다른 요청을 막지 않도록 별도 작업으로 돌린다
+        self._owned_tasks
+            .spawn(Self::warm_up_hosts(self.client.clone(), hosts));
+    }
+}
+
+#[async_trait]
+impl Notifiable<SetDataSaverRequest> for NetworkManagerActor {
+    async fn notify(&mut self, msg: SetDataSaverRequest, _: &Context<Self>) {
+        self.data_saver = msg.enabled;
+        debug_print!("Data saver: {}", msg.enabled);
+        self.usage_signal().emit();
+    }
+}
+
+#[async_trait]
+impl Notifiable<ForceHttp1Request> for NetworkManagerActor {
+    async fn notify(&mut self, msg: ForceHttp1Request, _: &Context<Self>) {
+        if msg.enabled != self.force_http1 {
+            debug_print!("Force HTTP/1.1: {}", msg.enabled);
+            self.force_http1 = msg.enabled;
+            self.client = Self::build_client(msg.enabled);
+            self.connections.clear();
+        }
+        self.usage_signal().emit();
+    }
+}
+
+#[async_trait]
+impl Notifiable<RunSpeedTestRequest> for NetworkManagerActor {
+    async fn notify(&mut self, msg: RunSpeedTestRequest, ctx: &Context<Self>) {
+        if self.speed_test_running {
+            debug_print!("Speed test already running");
+            return;
+        }
+        let Some(url) = msg.url.or_else(|| self.speed_test_url.clone()) else {
+            SpeedTest::failed(Some("No speed test endpoint configured".to_string())).emit();
+            return;
+        };
+        // 측정은 수 MB를 주고받으므로 종량제 연결에서는 보내지 않는다
+        if self.data_saver_active() {
+            self.usage_mut(TrafficClass::Other).deferred += 1;
+            SpeedTest::failed(Some("Data saver is on".to_string())).emit();
+            return;
+        }
+
+        self.speed_test_running = true;
+        let test = SpeedTest {
+            client: self.client.clone(),
+            url,
+            download_bytes: self.speed_test_download_bytes,
+            upload_bytes: self.speed_test_upload_bytes,
+        };
+        self._owned_tasks
+            .spawn(Self::run_speed_test(ctx.address(), test));
+    }
+}
+
+#[async_trait]
+impl Notifiable<BandwidthUsageRequest> for NetworkManagerActor {
+    async fn notify(&mut self, msg: BandwidthUsageRequest, _: &Context<Self>) {
+        self.usage_signal().emit();
+        if msg.reset {
+            self.usage.clear();
+            self.usage_since = self.clock.trusted_now();
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<PersistState> for NetworkManagerActor {
+    type Result = Option<PersistedState>;
+
+    async fn handle(&mut self, _: PersistState, _: &Context<Self>) -> Self::Result {
+        let state = NetworkState {
+            usage: self.usage.values().cloned().collect(),
+            usage_since: self.usage_since,
+            data_saver: self.data_saver,
+        };
+        PersistedState::new(STATE_VERSION, &state)
+    }
+}
+
+#[async_trait]
+impl Handler<RestoreState> for NetworkManagerActor {
+    type Result = bool;
+
+    async fn handle(&mut self, msg: RestoreState, _: &Context<Self>) -> Self::Result {
+        let Some(state) = msg.0.read::<NetworkState>(STATE_VERSION) else {
+            return false;
+        };
+        // 이번 실행에서 이미 센 사용량에 더한다
+        for previous in state.usage {
+            let usage = self.usage_mut(previous.class);
+            usage.bytes_sent += previous.bytes_sent;
+            usage.bytes_received += previous.bytes_received;
+            usage.requests += previous.requests;
+            usage.deferred += previous.deferred;
+        }
+        self.usage_since = self.usage_since.min(state.usage_since);
+        self.data_saver = state.data_saver;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{error::Error, sync::Arc, time::Duration};
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::{TcpListener, TcpStream},
+        sync::{Notify, broadcast},
+        time::timeout,
+    };
+
+    use super::{NetworkManagerActor, NetworkRequest};
+    use crate::study_actors::{
+        actors::{Priority, PriorityMailbox, TrustedClock},
+        messages::NetworkConfig,
+        signals::{BandwidthUsageRequest, BandwidthUsageSignal},
+        test_support::{TestActorHarness, settle},
+    };
+
+    // /slow는 release가 올 때까지 답하지 않고, 나머지 경로는 바로 답한다
+    async fn serve(listener: TcpListener, slow_started: Arc<Notify>, release: Arc<Notify>) {
+        while let Ok((stream, _)) = listener.accept().await {
+            tokio::spawn(answer(stream, slow_started.clone(), release.clone()));
+        }
+    }
+
+    // 연결을 닫지 않고 같은 연결로 오는 다음 요청에도 답한다 (본문 없는 GET만 받는다)
+    async fn answer(mut stream: TcpStream, slow_started: Arc<Notify>, release: Arc<Notify>) {
+        let mut buffer = [0; 1024];
+        loop {
+            let mut head = Vec::new();
+            while !head.windows(4).any(|window| window == b"\r\n\r\n") {
+                match stream.read(&mut buffer).await {
+                    Ok(0) | Err(_) => return,
+                    Ok(read) => head.extend_from_slice(&buffer[..read]),
+                }
+            }
+            let body: &[u8] = if head.starts_with(b"GET /slow ") {
+                slow_started.notify_one();
+                release.notified().await;
+                b"slow"
+            } else {
+                b"fast"
+            };
+            let head = format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n", body.len());
+            if stream.write_all(head.as_bytes()).await.is_err()
+                || stream.write_all(body).await.is_err()
+            {
+                return;
+            }
+        }
+    }
+
+    fn start_network() -> TestActorHarness<NetworkManagerActor> {
+        let (_events, receiver) = broadcast::channel(1);
+        TestActorHarness::start(|addr| {
+            NetworkManagerActor::new(
+                addr,
+                NetworkConfig::default(),
+                TrustedClock::new(),
+                receiver,
+            )
+        })
+    }
+
+    #[tokio::test]
+    async fn high_request_is_not_held_behind_a_slow_low_request() -> Result<(), Box<dyn Error>> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let base = format!("http://{}", listener.local_addr()?);
+        let (slow_started, release) = (Arc::new(Notify::new()), Arc::new(Notify::new()));
+        tokio::spawn(serve(listener, slow_started.clone(), release.clone()));
+
+        let network = start_network();
+        let lanes = PriorityMailbox::new(network.addr());
+
+        let slow = tokio::spawn({
+            let lanes = lanes.clone();
+            let request = NetworkRequest::new(format!("{}/slow", base));
+            async move { lanes.request(Priority::Low, request).await }
+        });
+        slow_started.notified().await;
+
+        // 저우선 요청이 서버에서 멈춰 있는 동안에도 고우선 요청은 먼저 끝나야 한다
+        let request = NetworkRequest::new(format!("{}/fast", base));
+        let fast = timeout(
+            Duration::from_secs(5),
+            lanes.request(Priority::High, request),
+        )
+        .await???;
+        assert_eq!(fast.body, b"fast");
+        assert!(!slow.is_finished());
+
+        release.notify_one();
+        let slow = slow.await???;
+        assert_eq!(slow.body, b"slow");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn connection_reuse_is_counted_from_the_response_socket() -> Result<(), Box<dyn Error>> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+        let (slow_started, release) = (Arc::new(Notify::new()), Arc::new(Notify::new()));
+        tokio::spawn(serve(listener, slow_started, release));
+
+        let mut network = start_network();
+        for _ in 0..3 {
+            let request = NetworkRequest::new(format!("http://{}/fast", address));
+            let response = network.send(request).await?.await??;
+            assert_eq!(response.body, b"fast");
+        }
+        network
+            .notify(BandwidthUsageRequest { reset: false })
+            .await?;
+        settle().await;
+
+        let usage = network.signals_of::<BandwidthUsageSignal>();
+        assert_eq!(usage.len(), 1);
+        let host = address.to_string();
+        let diagnostics = usage[0].connections.iter().find(|entry| entry.host == host);
+        let diagnostics = diagnostics.ok_or("no diagnostics for the test host")?;
+        assert_eq!(diagnostics.requests, 3);
+        assert_eq!(diagnostics.protocol.as_deref(), Some("HTTP/1.1"));
+        // 연결을 닫지 않는 서버라 첫 요청이 연 연결을 나머지가 그대로 쓴다
+        assert_eq!(diagnostics.connections_opened, Some(1));
+        assert_eq!(diagnostics.reused, Some(2));
+        assert_eq!(diagnostics.tls_version, None);
+        Ok(())
+    }
+}
@@ -0,0 +1,470 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use reqwest::Method;
+use serde_json::Value;
+use std::{collections::HashMap, time::Duration};
+use tokio::task::{AbortHandle, JoinSet};
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        AppEvent, InboxSource, NotificationError, PostNotification, RegisterPushToken,
+        ScheduleLocalNotification, SetSubsystemSuspended, SignedAdminCommand, WipeUserData,
+    },
+    signals::{
+        CancelLocalNotificationRequest, EmitSignal, FetchUserDataRequest,
+        LocalNotificationDueSignal, PushPayloadReceived, PushTokenRegisteredSignal,
+        RegisterPushTokenRequest, ScheduleLocalNotificationRequest, route_dart_signals,
+    },
+};
+
+use super::network::NetworkRequest;
+use super::{
+    DataManagerActor, EventBus, InboxActor, NetworkManagerActor, Priority, PriorityMailbox,
+    TrustedClock,
+};
+
+// 기다리는 동안 신뢰 시각이 서버 시각으로 맞춰지면 남은 시간이 바뀌므로 이 간격마다 다시 잰다
+const DUE_RECHECK: Duration = Duration::from_secs(60);
+
+// 푸시/로컬 알림 액터
+pub struct NotificationActor {
+    network_manager: PriorityMailbox<NetworkManagerActor>,
+    data_manager: Address<DataManagerActor>,
+    register_url: Option<String>,
+    registered_token: Option<String>,
+    scheduled: HashMap<String, ScheduledNotification>,
+    next_generation: u64,
+    held: Vec<LocalNotificationDueSignal>, // 백그라운드 모드에서 멈춘 동안 도착한 알림
+    suspended: bool,
+    event_bus: Option<EventBus>,
+    inbox: Option<Address<InboxActor>>, // 있으면 받은 알림을 알림함에도 남긴다
+    clock: TrustedClock,
+    _owned_tasks: JoinSet<()>,
+}
+
+// 예약된 로컬 알림의 대기 작업
+// 같은 ID로 다시 예약하면 세대가 바뀌므로, 교체되거나 취소되기 직전에 깨어난 작업의 알림은 버린다.
+struct ScheduledNotification {
+    generation: u64,
+    handle: AbortHandle,
+}
+
+impl Actor for NotificationActor {}
+
+impl NotificationActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        network_manager: PriorityMailbox<NetworkManagerActor>,
+        data_manager: Address<DataManagerActor>,
+        register_url: Option<String>,
+        clock: TrustedClock,
+    ) -> Self {
+        let owned_tasks = route_dart_signals!(
+            self_addr,
+            [
+                RegisterPushTokenRequest,
+                PushPayloadReceived,
+                ScheduleLocalNotificationRequest,
+                CancelLocalNotificationRequest
+            ]
+        );
+
+        Self {
+            network_manager,
+            data_manager,
+            register_url,
+            registered_token: None,
+            scheduled: HashMap::new(),
+            next_generation: 0,
+            held: Vec::new(),
+            suspended: false,
+            event_bus: None,
+            inbox: None,
+            clock,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    // 서버가 보낸 원격 삭제 요청을 이벤트 버스로 감독자에게 전달
+    pub fn set_event_bus(&mut self, event_bus: EventBus) {
+        self.event_bus = Some(event_bus);
+    }
+
+    pub fn set_inbox(&mut self, inbox: Address<InboxActor>) {
+        self.inbox = Some(inbox);
+    }
+
+    async fn post_to_inbox(&mut self, source: InboxSource, payload: &Value, body: &str) {
+        let Some(inbox) = self.inbox.as_mut() else {
+            return;
+        };
+        let request = PostNotification {
+            id: payload["id"].as_str().map(str::to_string),
+            source,
+            title: payload["title"].as_str().unwrap_or_default().to_string(),
+            body: body.to_string(),
+            link: payload["link"].as_str().map(str::to_string),
+        };
+        if let Ok(Err(e)) = inbox.send(request).await {
+            debug_print!("Failed to add notification to inbox: {}", e);
+        }
+    }
+
+    // 멈춘 동안에는 모아 두었다가 재개할 때 한꺼번에 보낸다
+    fn deliver(&mut self, signal: LocalNotificationDueSignal) {
+        if self.suspended {
+            self.held.push(signal);
+        } else {
+            signal.emit();
+        }
+    }
+
+    // 예약 시간(신뢰 시각 기준)까지 기다렸다가 액터에 알림
+    // 기기 시계를 바꿔도 앞당겨지거나 밀리지 않고, 시뮬레이션에서는 가상 시간으로 기다린다.
+    async fn wait_until_due(
+        mut self_addr: Address<Self>,
+        clock: TrustedClock,
+        notification: ScheduleLocalNotification,
+        generation: u64,
+    ) {
+        loop {
+            let wait_ms = notification.fire_at as i64 * 1000 - clock.now_millis();
+            if wait_ms <= 0 {
+                break;
+            }
+            let wait = Duration::from_millis(wait_ms as u64).min(DUE_RECHECK);
+            clock.clock().sleep(wait).await;
+        }
+        let fired = LocalNotificationFired {
+            notification,
+            generation,
+        };
+        let _ = self_addr.notify(fired).await;
+    }
+}
+
+// 내부 메시지 정의
+struct LocalNotificationFired {
+    notification: ScheduleLocalNotification,
+    generation: u64,
+}
+
+#[async_trait]
+impl Notifiable<LocalNotificationFired> for NotificationActor {
+    async fn notify(&mut self, msg: LocalNotificationFired, _: &Context<Self>) {
+        let notification = msg.notification;
+        // 지금 예약된 작업이 보낸 알림만 보낸다 (교체되거나 취소된 예약이면 버린다)
+        let current = self.scheduled.get(&notification.id);
+        if current.is_none_or(|scheduled| scheduled.generation != msg.generation) {
+            debug_print!("Dropping stale local notification: {}", notification.id);
+            return;
+        }
+        self.scheduled.remove(&notification.id);
+
+        self.deliver(LocalNotificationDueSignal {
+            id: notification.id,
+            title: notification.title,
+            body: notification.body,
+        });
+    }
+}
+
+#[async_trait]
+impl Handler<RegisterPushToken> for NotificationActor {
+    type Result = Result<(), NotificationError>;
+
+    async fn handle(&mut self, msg: RegisterPushToken, _: &Context<Self>) -> Self::Result {
+        let Some(url) = self.register_url.clone() else {
+            return Err("Push registration endpoint not configured".into());
+        };
+
+        let request = NetworkRequest::new(url)
+            .method(Method::POST)
+            .header("Content-Type", "application/json")
+            .json(&serde_json::json!({
+                "user_id": msg.user_id,
+                "platform": msg.platform,
+                "token": msg.token,
+            }));
+
+        let response = self
+            .network_manager
+            .request(Priority::Low, request)
+            .await??;
+        if !response.is_success() {
+            return Err(format!("Push token registration failed: {}", response.status).into());
+        }
+
+        debug_print!("Push token registered ({:?})", msg.platform);
+        self.registered_token = Some(msg.token);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Handler<ScheduleLocalNotification> for NotificationActor {
+    type Result = ();
+
+    async fn handle(
+        &mut self,
+        msg: ScheduleLocalNotification,
+        ctx: &Context<Self>,
+    ) -> Self::Result {
+        // 같은 ID로 이미 예약된 알림은 교체
+        if let Some(scheduled) = self.scheduled.remove(&msg.id) {
+            scheduled.handle.abort();
+        }
+
+        self.next_generation += 1;
+        let generation = self.next_generation;
+        let id = msg.id.clone();
+        let wait = Self::wait_until_due(ctx.address(), self.clock.clone(), msg, generation);
+        let handle = self._owned_tasks.spawn(wait);
+        self.scheduled
+            .insert(id, ScheduledNotification { generation, handle });
+    }
+}
+
+#[async_trait]
+impl Handler<WipeUserData> for NotificationActor {
+    type Result = Result<(), NotificationError>;
+
+    async fn handle(&mut self, _: WipeUserData, _: &Context<Self>) -> Self::Result {
+        // 예약된 로컬 알림 취소 및 등록된 토큰 정보 제거
+        for (_, scheduled) in self.scheduled.drain() {
+            scheduled.handle.abort();
+        }
+        self.held.clear();
+        self.registered_token = None;
+        if let Some(inbox) = self.inbox.as_mut() {
+            inbox.send(WipeUserData).await??;
+        }
+        Ok(())
+    }
+}
+
+// 백그라운드 모드 (창을 닫은 동안 받은 알림은 돌아왔을 때 보낸다)
+#[async_trait]
+impl Notifiable<SetSubsystemSuspended> for NotificationActor {
+    async fn notify(&mut self, msg: SetSubsystemSuspended, _: &Context<Self>) {
+        self.suspended = msg.suspended;
+        if !msg.suspended {
+            for signal in self.held.drain(..) {
+                signal.emit();
+            }
+        }
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<RegisterPushTokenRequest> for NotificationActor {
+    async fn notify(&mut self, msg: RegisterPushTokenRequest, ctx: &Context<Self>) {
+        let result = self
+            .handle(
+                RegisterPushToken {
+                    user_id: msg.user_id,
+                    platform: msg.platform,
+                    token: msg.token,
+                },
+                ctx,
+            )
+            .await;
+
+        PushTokenRegisteredSignal {
+            success: result.is_ok(),
+            error: result.err().map(|e| e.to_string()),
+        }
+        .emit();
+    }
+}
+
+#[async_trait]
+impl Notifiable<PushPayloadReceived> for NotificationActor {
+    async fn notify(&mut self, msg: PushPayloadReceived, _: &Context<Self>) {
+        let payload: Value = match serde_json::from_str(&msg.payload_json) {
+            Ok(payload) => payload,
+            Err(e) => {
+                debug_print!("Invalid push payload: {}", e);
+                return;
+            }
+        };
+
+        // 페이로드 타입에 따라 관련 액터 트리거
+        match payload["type"].as_str() {
+            Some("sync") | Some("data_changed") => {
+                if let Some(user_id) = payload["user_id"].as_str() {
+                    let _ = self
+                        .data_manager
+                        .notify(FetchUserDataRequest {
+                            user_id: user_id.to_string(),
+                            limit: None,
+                        })
+                        .await;
+                }
+                // 사용자에게 보여 줄 문구가 있는 동기화 알림만 알림함에 남긴다
+                if let Some(message) = payload["message"].as_str() {
+                    self.post_to_inbox(InboxSource::Sync, &payload, message)
+                        .await;
+                }
+            }
+            Some("notification") => {
+                let body = payload["body"].as_str().unwrap_or_default();
+                self.post_to_inbox(InboxSource::Push, &payload, body).await;
+                self.deliver(LocalNotificationDueSignal {
+                    id: payload["id"].as_str().unwrap_or_default().to_string(),
+                    title: payload["title"].as_str().unwrap_or_default().to_string(),
+                    body: body.to_string(),
+                });
+            }
+            // 관리 서버의 원격 명령 (서명 확인과 적용은 관리 액터가 맡는다)
+            // 원격 삭제도 서명된 명령으로만 받으며, 서명이 없거나 틀리면 관리 액터가 버린다.
+            Some("admin") | Some("remote_wipe") => {
+                let command = SignedAdminCommand {
+                    payload: payload["payload"].as_str().unwrap_or_default().to_string(),
+                    signature: payload["signature"]
+                        .as_str()
+                        .unwrap_or_default()
+                        .to_string(),
+                };
+                match &self.event_bus {
+                    Some(event_bus) => event_bus.publish(AppEvent::AdminCommandReceived(command)),
+                    None => debug_print!("Admin command received but no event bus is connected"),
+                }
+            }
+            // 배너 없이 알림함에만 남기는 공지
+            Some("system") => {
+                let body = payload["body"].as_str().unwrap_or_default();
+                self.post_to_inbox(InboxSource::System, &payload, body)
+                    .await;
+                if let Some(event_bus) = &self.event_bus {
+                    event_bus.publish(AppEvent::SystemNotice {
+                        id: payload["id"].as_str().map(str::to_string),
+                        title: payload["title"].as_str().unwrap_or_default().to_string(),
+                        body: body.to_string(),
+                    });
+                }
+            }
+            other => {
+                debug_print!("Unhandled push payload type: {:?}", other);
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Notifiable<ScheduleLocalNotificationRequest> for NotificationActor {
+    async fn notify(&mut self, msg: ScheduleLocalNotificationRequest, ctx: &Context<Self>) {
+        self.handle(
+            ScheduleLocalNotification {
+                id: msg.id,
+                title: msg.title,
+                body: msg.body,
+                fire_at: msg.fire_at,
+            },
+            ctx,
+        )
+        .await;
+    }
+}
+
+#[async_trait]
+impl Notifiable<CancelLocalNotificationRequest> for NotificationActor {
+    async fn notify(&mut self, msg: CancelLocalNotificationRequest, _: &Context<Self>) {
+        if let Some(scheduled) = self.scheduled.remove(&msg.id) {
+            scheduled.handle.abort();
+            debug_print!("Local notification cancelled: {}", msg.id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{error::Error, time::Duration};
+
+    use messages::prelude::Context;
+
+    use super::{LocalNotificationFired, NotificationActor};
+    use crate::study_actors::{
+        actors::{PriorityMailbox, TrustedClock},
+        messages::ScheduleLocalNotification,
+        signals::LocalNotificationDueSignal,
+        test_support::{TestActorHarness, settle},
+    };
+
+    // 네트워크와 데이터 관리자는 쓰지 않으므로 돌지 않는 주소만 넘긴다
+    fn start_notifications(clock: TrustedClock) -> TestActorHarness<NotificationActor> {
+        let network = PriorityMailbox::new(Context::new().address());
+        let data = Context::new().address();
+        TestActorHarness::start(|addr| NotificationActor::new(addr, network, data, None, clock))
+    }
+
+    fn local_notification(title: &str, fire_at: u64) -> ScheduleLocalNotification {
+        ScheduleLocalNotification {
+            id: "reminder".to_string(),
+            title: title.to_string(),
+            body: String::new(),
+            fire_at,
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn local_notification_fires_at_trusted_time() -> Result<(), Box<dyn Error>> {
+        let clock = TrustedClock::new();
+        let mut notifications = start_notifications(clock.clone());
+        let fire_at = clock.trusted_now() + 60 * 60;
+        notifications
+            .send(local_notification("title", fire_at))
+            .await?;
+
+        notifications.advance(Duration::from_secs(30 * 60)).await;
+        assert!(
+            notifications
+                .signals_of::<LocalNotificationDueSignal>()
+                .is_empty()
+        );
+
+        notifications.advance(Duration::from_secs(31 * 60)).await;
+        let due = notifications.signals_of::<LocalNotificationDueSignal>();
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].id, "reminder");
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn replaced_schedule_drops_the_old_fire() -> Result<(), Box<dyn Error>> {
+        let clock = TrustedClock::new();
+        let mut notifications = start_notifications(clock.clone());
+        let fire_at = clock.trusted_now() + 60;
+        notifications
+            .send(local_notification("old", fire_at))
+            .await?;
+        notifications
+            .send(local_notification("new", fire_at))
+            .await?;
+
+        // 교체되기 직전에 깨어난 첫 예약의 알림이 늦게 도착해도 새 예약을 지우지 않는다
+        notifications
+            .notify(LocalNotificationFired {
+                notification: local_notification("old", fire_at),
+                generation: 1,
+            })
+            .await?;
+        settle().await;
+        assert!(
+            notifications
+                .signals_of::<LocalNotificationDueSignal>()
+                .is_empty()
+        );
+
+        notifications.advance(Duration::from_secs(61)).await;
+        let due = notifications.signals_of::<LocalNotificationDueSignal>();
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].title, "new");
+        Ok(())
+    }
+}
@@ -0,0 +1,543 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use tokio::task::JoinSet;
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{FrameFormat, OcrError, PreprocessFrame, ProcessedFrame, QuadPoint},
+    signals::{EmitBinarySignal, OcrFrameRequest, OcrFrameResultSignal, ReceiveBinarySignal},
+};
+
+use super::performance_level;
+
+const MAX_FRAME_PIXELS: usize = 4096 * 4096;
+const MAX_SKEW_DEGREES: f32 = 15.0;
+const SKEW_STEP_DEGREES: f32 = 0.5;
+const MAX_SKEW_SAMPLES: usize = 100_000;
+const QUAD_BLOCK_SIZE: usize = 4;
+const MIN_QUAD_AREA_RATIO: f32 = 0.2;
+const MAX_QUAD_AREA_RATIO: f32 = 0.95; // 프레임 전체가 밝으면 문서 경계가 없는 것으로 본다
+const PAPER_WHITE: u8 = 255;
+
+// 8비트 그레이스케일 이미지
+struct GrayImage {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl GrayImage {
+    fn get(&self, x: usize, y: usize) -> u8 {
+        self.pixels[y * self.width + x]
+    }
+
+    // 이미지 밖은 종이 색(흰색)으로 채운다
+    fn sample_bilinear(&self, x: f32, y: f32) -> u8 {
+        if x < 0.0 || y < 0.0 || x > (self.width - 1) as f32 || y > (self.height - 1) as f32 {
+            return PAPER_WHITE;
+        }
+        let (x0, y0) = (x.floor() as usize, y.floor() as usize);
+        let (x1, y1) = ((x0 + 1).min(self.width - 1), (y0 + 1).min(self.height - 1));
+        let (fx, fy) = (x - x0 as f32, y - y0 as f32);
+        let top = self.get(x0, y0) as f32 * (1.0 - fx) + self.get(x1, y0) as f32 * fx;
+        let bottom = self.get(x0, y1) as f32 * (1.0 - fx) + self.get(x1, y1) as f32 * fx;
+        (top * (1.0 - fy) + bottom * fy).round() as u8
+    }
+}
+
+fn to_grayscale(
+    data: &[u8],
+    width: u32,
+    height: u32,
+    row_stride: u32,
+    format: FrameFormat,
+) -> Result<GrayImage, OcrError> {
+    let (width, height) = (width as usize, height as usize);
+    if width == 0 || height == 0 || width * height > MAX_FRAME_PIXELS {
+        return Err(format!("Unsupported frame size: {}x{}", width, height).into());
+    }
+
+    let bytes_per_pixel = match format {
+        FrameFormat::Gray8 | FrameFormat::Yuv420 => 1,
+        FrameFormat::Rgba8 | FrameFormat::Bgra8 => 4,
+    };
+    let row_bytes = width * bytes_per_pixel;
+    let stride = match row_stride as usize {
+        0 => row_bytes,
+        stride if stride >= row_bytes => stride,
+        stride => return Err(format!("Row stride {} is shorter than a row", stride).into()),
+    };
+    if data.len() < stride * (height - 1) + row_bytes {
+        return Err("Frame buffer is smaller than the declared size".into());
+    }
+
+    let mut pixels = Vec::with_capacity(width * height);
+    for row in data.chunks(stride).take(height) {
+        let row = &row[..row_bytes];
+        match format {
+            FrameFormat::Gray8 | FrameFormat::Yuv420 => pixels.extend_from_slice(row),
+            FrameFormat::Rgba8 => {
+                pixels.extend(row.chunks_exact(4).map(|p| luma(p[0], p[1], p[2])))
+            }
+            FrameFormat::Bgra8 => {
+                pixels.extend(row.chunks_exact(4).map(|p| luma(p[2], p[1], p[0])))
+            }
+        }
+    }
+    Ok(GrayImage {
+        width,
+        height,
+        pixels,
+    })
+}
+
+// BT.601 휘도 (정수 근사)
+fn luma(r: u8, g: u8, b: u8) -> u8 {
+    ((r as u32 * 77 + g as u32 * 150 + b as u32 * 29) >> 8) as u8
+}
+
+// Otsu 방식 전역 임계값
+fn otsu_threshold(pixels: &[u8]) -> u8 {
+    let mut histogram = [0u64; 256];
+    for &p in pixels {
+        histogram[p as usize] += 1;
+    }
+    let total = pixels.len() as f64;
+    let sum_all: f64 = histogram
+        .iter()
+        .enumerate()
+        .map(|(i, &count)| i as f64 * count as f64)
+        .sum();
+
+    let (mut weight_bg, mut sum_bg) = (0.0, 0.0);
+    let (mut best, mut best_variance) = (0u8, 0.0);
+    for (level, &count) in histogram.iter().enumerate() {
+        weight_bg += count as f64;
+        if weight_bg == 0.0 {
+            continue;
+        }
+        let weight_fg = total - weight_bg;
+        if weight_fg == 0.0 {
+            break;
+        }
+        sum_bg += level as f64 * count as f64;
+        let mean_bg = sum_bg / weight_bg;
+        let mean_fg = (sum_all - sum_bg) / weight_fg;
+        let variance = weight_bg * weight_fg * (mean_bg - mean_fg).powi(2);
+        if variance > best_variance {
+            best_variance = variance;
+            best = level as u8;
+        }
+    }
+    best
+}
+
+// 어두운 배경 위의 밝은 종이(영수증)를 찾아 네 꼭짓점을 반환
+// 블록 평균으로 줄인 뒤 x+y, x-y 극값을 꼭짓점으로 잡는다.
+fn detect_quad(image: &GrayImage) -> Option<[QuadPoint; 4]> {
+    let block = QUAD_BLOCK_SIZE;
+    let (small_w, small_h) = (image.width / block, image.height / block);
+    if small_w < 4 || small_h < 4 {
+        return None;
+    }
+
+    let mut small = Vec::with_capacity(small_w * small_h);
+    for by in 0..small_h {
+        for bx in 0..small_w {
+            let mut sum = 0u32;
+            for y in by * block..(by + 1) * block {
+                for x in bx * block..(bx + 1) * block {
+                    sum += image.get(x, y) as u32;
+                }
+            }
+            small.push((sum / (block * block) as u32) as u8);
+        }
+    }
+
+    let threshold = otsu_threshold(&small);
+    let mut corners: Option<[(i64, i64); 4]> = None;
+    for (i, &p) in small.iter().enumerate() {
+        if p <= threshold {
+            continue;
+        }
+        let (x, y) = ((i % small_w) as i64, (i / small_w) as i64);
+        let c = corners.get_or_insert([(x, y); 4]);
+        if x + y < c[0].0 + c[0].1 {
+            c[0] = (x, y);
+        }
+        if x - y > c[1].0 - c[1].1 {
+            c[1] = (x, y);
+        }
+        if x + y > c[2].0 + c[2].1 {
+            c[2] = (x, y);
+        }
+        if x - y < c[3].0 - c[3].1 {
+            c[3] = (x, y);
+        }
+    }
+
+    let scale = block as f32;
+    let quad = corners?.map(|(x, y)| QuadPoint {
+        x: (x as f32 + 0.5) * scale,
+        y: (y as f32 + 0.5) * scale,
+    });
+    let area_ratio = quad_area(&quad) / (image.width * image.height) as f32;
+    (MIN_QUAD_AREA_RATIO..=MAX_QUAD_AREA_RATIO)
+        .contains(&area_ratio)
+        .then_some(quad)
+}
+
+fn quad_area(quad: &[QuadPoint; 4]) -> f32 {
+    let mut twice_area = 0.0;
+    for i in 0..4 {
+        let (a, b) = (quad[i], quad[(i + 1) % 4]);
+        twice_area += a.x * b.y - b.x * a.y;
+    }
+    twice_area.abs() / 2.0
+}
+
+fn distance(a: QuadPoint, b: QuadPoint) -> f32 {
+    ((a.x - b.x).powi(2) + (a.y - b.y).powi(2)).sqrt()
+}
+
+// 출력 사각형 좌표 → 원본 사각형 좌표 호모그래피 (h33 = 1)
+fn solve_homography(from: &[(f64, f64); 4], to: &[(f64, f64); 4]) -> Option<[f64; 8]> {
+    let mut matrix = [[0.0f64; 9]; 8];
+    for i in 0..4 {
+        let ((x, y), (u, v)) = (from[i], to[i]);
+        matrix[2 * i] = [x, y, 1.0, 0.0, 0.0, 0.0, -u * x, -u * y, u];
+        matrix[2 * i + 1] = [0.0, 0.0, 0.0, x, y, 1.0, -v * x, -v * y, v];
+    }
+
+    // 부분 피벗 가우스 소거
+    for col in 0..8 {
+        let pivot =
+            (col..8).max_by(|&a, &b| matrix[a][col].abs().total_cmp(&matrix[b][col].abs()))?;
+        if matrix[pivot][col].abs() < 1e-9 {
+            return None;
+        }
+        matrix.swap(col, pivot);
+        for row in 0..8 {
+            if row != col {
+                let factor = matrix[row][col] / matrix[col][col];
+                let pivot = matrix[col];
+                for (value, pivot) in matrix[row].iter_mut().zip(pivot).skip(col) {
+                    *value -= factor * pivot;
+                }
+            }
+        }
+    }
+
+    let mut h = [0.0; 8];
+    for (i, value) in h.iter_mut().enumerate() {
+        *value = matrix[i][8] / matrix[i][i];
+    }
+    Some(h)
+}
+
+// 검출된 사각형을 정면에서 본 직사각형으로 펴서 잘라낸다
+fn warp_quad(image: &GrayImage, quad: &[QuadPoint; 4]) -> Option<GrayImage> {
+    let [tl, tr, br, bl] = *quad;
+    let width = distance(tl, tr).max(distance(bl, br)).round() as usize;
+    let height = distance(tl, bl).max(distance(tr, br)).round() as usize;
+    if width < 2 || height < 2 {
+        return None;
+    }
+
+    let (w, h) = ((width - 1) as f64, (height - 1) as f64);
+    let from = [(0.0, 0.0), (w, 0.0), (w, h), (0.0, h)];
+    let to = quad.map(|p| (p.x as f64, p.y as f64));
+    let m = solve_homography(&from, &to)?;
+
+    let mut pixels = Vec::with_capacity(width * height);
+    for y in 0..height {
+        for x in 0..width {
+            let (x, y) = (x as f64, y as f64);
+            let denom = m[6] * x + m[7] * y + 1.0;
+            let u = (m[0] * x + m[1] * y + m[2]) / denom;
+            let v = (m[3] * x + m[4] * y + m[5]) / denom;
+            pixels.push(image.sample_bilinear(u as f32, v as f32));
+        }
+    }
+    Some(GrayImage {
+        width,
+        height,
+        pixels,
+    })
+}
+
+// 투영 프로파일로 글자 줄의 기울기(도)를 추정
+// 줄 방향과 각도가 맞으면 행별 글자 픽셀 수가 뾰족해져 제곱합이 커진다.
+// 어두운 배경이 섞여도 글자만 남도록 지역 이진화 결과의 검은 픽셀만 쓴다.
+fn estimate_skew(image: &GrayImage, radius: usize, offset: i32) -> f32 {
+    let mut binary = GrayImage {
+        width: image.width,
+        height: image.height,
+        pixels: image.pixels.clone(),
+    };
+    adaptive_threshold(&mut binary, radius, offset);
+    let dark: Vec<(f32, f32)> = binary
+        .pixels
+        .iter()
+        .enumerate()
+        .filter(|&(_, &p)| p == 0)
+        .map(|(i, _)| ((i % image.width) as f32, (i / image.width) as f32))
+        .collect();
+    let step = dark.len().div_ceil(MAX_SKEW_SAMPLES).max(1);
+    let samples: Vec<(f32, f32)> = dark.into_iter().step_by(step).collect();
+    if samples.is_empty() {
+        return 0.0;
+    }
+
+    let bins = image.width + image.height;
+    let offset = image.width as f32; // y' 가 음수가 되지 않도록 이동
+    let mut profile = vec![0u32; bins * 2];
+    let (mut best_angle, mut best_score) = (0.0, 0u64);
+    let steps = (MAX_SKEW_DEGREES / SKEW_STEP_DEGREES) as i32;
+    for i in -steps..=steps {
+        let angle = i as f32 * SKEW_STEP_DEGREES;
+        let (sin, cos) = angle.to_radians().sin_cos();
+        profile.iter_mut().for_each(|bin| *bin = 0);
+        for &(x, y) in &samples {
+            let projected = (y * cos - x * sin + offset).round().max(0.0) as usize;
+            if let Some(bin) = profile.get_mut(projected) {
+                *bin += 1;
+            }
+        }
+        let score: u64 = profile.iter().map(|&c| c as u64 * c as u64).sum();
+        if score > best_score {
+            best_score = score;
+            best_angle = angle;
+        }
+    }
+    best_angle
+}
+
+// 중심 기준 회전으로 기울기 보정 (크기 유지, 빈 곳은 흰색)
+fn rotate(image: &GrayImage, degrees: f32) -> GrayImage {
+    let (sin, cos) = degrees.to_radians().sin_cos();
+    let (cx, cy) = (image.width as f32 / 2.0, image.height as f32 / 2.0);
+    let mut pixels = Vec::with_capacity(image.pixels.len());
+    for y in 0..image.height {
+        for x in 0..image.width {
+            let (dx, dy) = (x as f32 - cx, y as f32 - cy);
+            pixels.push(image.sample_bilinear(cx + dx * cos - dy * sin, cy + dx * sin + dy * cos));
+        }
+    }
+    GrayImage {
+        width: image.width,
+        height: image.height,
+        pixels,
+    }
+}
+
+// 적분 영상을 이용한 지역 평균 적응형 이진화 (글자 0, 배경 255)
+fn adaptive_threshold(image: &mut GrayImage, radius: usize, offset: i32) {
+    let (w, h) = (image.width, image.height);
+    let mut integral = vec![0u64; (w + 1) * (h + 1)];
+    for y in 0..h {
+        let mut row_sum = 0u64;
+        for x in 0..w {
+            row_sum += image.get(x, y) as u64;
+            integral[(y + 1) * (w + 1) + x + 1] = integral[y * (w + 1) + x + 1] + row_sum;
+        }
+    }
+
+    let radius = radius.max(1);
+    for y in 0..h {
+        let (y0, y1) = (y.saturating_sub(radius), (y + radius + 1).min(h));
+        for x in 0..w {
+            let (x0, x1) = (x.saturating_sub(radius), (x + radius + 1).min(w));
+            let sum = integral[y1 * (w + 1) + x1] + integral[y0 * (w + 1) + x0]
+                - integral[y0 * (w + 1) + x1]
+                - integral[y1 * (w + 1) + x0];
+            let mean = (sum / ((x1 - x0) * (y1 - y0)) as u64) as i32;
+            let index = y * w + x;
+            image.pixels[index] = if (image.pixels[index] as i32) < mean - offset {
+                0
+            } else {
+                PAPER_WHITE
+            };
+        }
+    }
+}
+
+// 블로킹 스레드에서 호출되는 전처리 파이프라인
+// 그레이스케일 → 사각형 잘라내기 → 기울기 보정 → 적응형 이진화
+pub fn preprocess_blocking(msg: &PreprocessFrame) -> Result<ProcessedFrame, OcrError> {
+    let options = &msg.options;
+    let mut image = to_grayscale(
+        &msg.pixels,
+        msg.width,
+        msg.height,
+        msg.row_stride,
+        msg.format,
+    )?;
+
+    let mut quad = None;
+    if options.crop_to_quad
+        && let Some(detected) = detect_quad(&image)
+        && let Some(warped) = warp_quad(&image, &detected)
+    {
+        image = warped;
+        quad = Some(detected);
+    }
+
+    let mut skew_degrees = 0.0;
+    if options.deskew {
+        skew_degrees = estimate_skew(
+            &image,
+            options.block_radius as usize,
+            options.threshold_offset,
+        );
+        if skew_degrees.abs() >= SKEW_STEP_DEGREES {
+            image = rotate(&image, skew_degrees);
+        }
+    }
+
+    if options.threshold {
+        adaptive_threshold(
+            &mut image,
+            options.block_radius as usize,
+            options.threshold_offset,
+        );
+    }
+
+    Ok(ProcessedFrame {
+        pixels: image.pixels,
+        width: image.width as u32,
+        height: image.height as u32,
+        skew_degrees,
+        quad,
+    })
+}
+
+// OCR 전처리 액터
+// 카메라 프레임은 계속 들어오므로 처리 중에는 가장 최근 프레임 하나만 대기시키고 나머지는 버린다.
+pub struct OcrPrepActor {
+    busy: bool,
+    pending: Option<DartOcrFrame>,
+    dropped_frames: u32,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for OcrPrepActor {}
+
+impl OcrPrepActor {
+    pub fn new(self_addr: Address<Self>) -> Self {
+        let mut owned_tasks = JoinSet::new();
+        owned_tasks.spawn(Self::listen_to_frames(self_addr));
+
+        Self {
+            busy: false,
+            pending: None,
+            dropped_frames: 0,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    async fn listen_to_frames(mut self_addr: Address<Self>) {
+        let receiver = OcrFrameRequest::binary_signal_receiver();
+        while let Some(signal_pack) = receiver.recv().await {
+            let _ = self_addr
+                .notify(DartOcrFrame(signal_pack.message, signal_pack.binary))
+                .await;
+        }
+    }
+
+    async fn preprocess(msg: PreprocessFrame) -> Result<ProcessedFrame, OcrError> {
+        tokio::task::spawn_blocking(move || preprocess_blocking(&msg)).await?
+    }
+
+    fn start_frame(&mut self, mut self_addr: Address<Self>, frame: DartOcrFrame) {
+        self.busy = true;
+        let dropped_frames = std::mem::take(&mut self.dropped_frames);
+        let DartOcrFrame(mut request, pixels) = frame;
+        // 부하가 높으면 성능 거버너가 정한 만큼 비싼 단계를 건너뛴다
+        let level = performance_level();
+        let mut options = request.options.take().unwrap_or_default();
+        options.deskew &= level.ocr_deskew();
+        options.crop_to_quad &= level.ocr_crop();
+
+        self._owned_tasks.spawn(async move {
+            let result = Self::preprocess(PreprocessFrame {
+                pixels,
+                width: request.width,
+                height: request.height,
+                row_stride: request.row_stride,
+                format: request.format,
+                options,
+            })
+            .await;
+
+            match result {
+                Ok(frame) => {
+                    OcrFrameResultSignal {
+                        request_id: request.request_id,
+                        width: frame.width,
+                        height: frame.height,
+                        skew_degrees: frame.skew_degrees,
+                        quad: frame.quad.map(|quad| quad.to_vec()),
+                        dropped_frames,
+                        error: None,
+                    }
+                    .emit_binary(frame.pixels);
+                }
+                Err(e) => {
+                    debug_print!("OCR preprocessing failed: {}", e);
+                    OcrFrameResultSignal {
+                        request_id: request.request_id,
+                        width: 0,
+                        height: 0,
+                        skew_degrees: 0.0,
+                        quad: None,
+                        dropped_frames,
+                        error: Some(e.to_string()),
+                    }
+                    .emit_binary(Vec::new());
+                }
+            }
+            let _ = self_addr.notify(FrameFinished).await;
+        });
+    }
+}
+
+// 내부 메시지 정의 (Dart 요청 + 프레임 바이트)
+struct DartOcrFrame(OcrFrameRequest, Vec<u8>);
+struct FrameFinished;
+
+#[async_trait]
+impl Notifiable<FrameFinished> for OcrPrepActor {
+    async fn notify(&mut self, _: FrameFinished, ctx: &Context<Self>) {
+        self.busy = false;
+        if let Some(frame) = self.pending.take() {
+            self.start_frame(ctx.address(), frame);
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<PreprocessFrame> for OcrPrepActor {
+    type Result = Result<ProcessedFrame, OcrError>;
+
+    async fn handle(&mut self, msg: PreprocessFrame, _: &Context<Self>) -> Self::Result {
+        Self::preprocess(msg).await
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<DartOcrFrame> for OcrPrepActor {
+    async fn notify(&mut self, msg: DartOcrFrame, ctx: &Context<Self>) {
+        if self.busy {
+            if self.pending.replace(msg).is_some() {
+                self.dropped_frames += 1;
+            }
+            return;
+        }
+        self.start_frame(ctx.address(), msg);
+    }
+}
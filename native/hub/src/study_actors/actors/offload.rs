@@ -0,0 +1,239 @@
+use serde::{Serialize, de::DeserializeOwned};
+#[cfg(not(target_family = "wasm"))]
+use std::sync::atomic::{AtomicUsize, Ordering};
+use thiserror::Error;
+
+// 메일박스를 막지 않도록 무거운 계산(프랙털 렌더링, 암호화 등)을 따로 실행한다
+// - 네이티브: spawn_blocking 스레드에서 실행
+// - 웹: 같은 wasm 모듈을 띄운 Web Worker에 작업을 직렬화해 보내고 결과를 돌려받는다.
+//   브라우저 메인 스레드는 렌더링도 맡으므로 여기서 계산하면 화면이 멈춘다.
+// 워커는 주소 공간을 공유하지 않으므로 작업은 클로저가 아니라 직렬화 가능한 값이어야 하고,
+// 워커 쪽에서 이름으로 찾을 수 있도록 아래 dispatch()에 등록해야 한다.
+pub trait WorkerJob: Serialize + DeserializeOwned + Send + 'static {
+    #[cfg_attr(not(target_family = "wasm"), allow(dead_code))]
+    const NAME: &'static str;
+    type Output: Serialize + DeserializeOwned + Send + 'static;
+
+    fn run(self) -> Self::Output;
+}
+
+#[derive(Debug, Error)]
+pub enum OffloadError {
+    #[error("Offloaded job failed: {0}")]
+    Failed(String),
+    #[cfg(target_family = "wasm")]
+    #[error("Unknown worker job: {0}")]
+    UnknownJob(String),
+    #[cfg(target_family = "wasm")]
+    #[error("Failed to encode worker message: {0}")]
+    Encoding(String),
+}
+
+// 넘겼지만 아직 끝나지 않은 작업 수 (런타임 통계용)
+#[cfg(not(target_family = "wasm"))]
+static IN_FLIGHT: AtomicUsize = AtomicUsize::new(0);
+
+#[cfg(not(target_family = "wasm"))]
+pub fn jobs_in_flight() -> usize {
+    IN_FLIGHT.load(Ordering::Relaxed)
+}
+
+// 작업이 끝나거나 실행되지 못하고 버려질 때 수를 줄인다
+#[cfg(not(target_family = "wasm"))]
+struct InFlight;
+
+#[cfg(not(target_family = "wasm"))]
+impl InFlight {
+    fn start() -> Self {
+        IN_FLIGHT.fetch_add(1, Ordering::Relaxed);
+        Self
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        IN_FLIGHT.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+pub async fn offload<J: WorkerJob>(job: J) -> Result<J::Output, OffloadError> {
+    let in_flight = InFlight::start();
+    tokio::task::spawn_blocking(move || {
+        let _in_flight = in_flight;
+        job.run()
+    })
+    .await
+    .map_err(|e| OffloadError::Failed(e.to_string()))
+}
+
+#[cfg(target_family = "wasm")]
+pub async fn offload<J: WorkerJob>(job: J) -> Result<J::Output, OffloadError> {
+    let input = bincode::serialize(&job).map_err(|e| OffloadError::Encoding(e.to_string()))?;
+    let reply = web_worker::post(J::NAME, &input)
+        .map_err(OffloadError::Failed)?
+        .await
+        .map_err(|_| OffloadError::Failed("Worker dropped the job".to_string()))?
+        .map_err(OffloadError::Failed)?;
+    bincode::deserialize(&reply).map_err(|e| OffloadError::Encoding(e.to_string()))
+}
+
+// 웹 워커가 이름으로 찾아 실행하는 작업 목록
+#[cfg(target_family = "wasm")]
+fn dispatch(name: &str, input: &[u8]) -> Result<Vec<u8>, OffloadError> {
+    use super::crypto::CipherJob;
+    use super::dedup::DedupJob;
+    use super::fuzzy::FuzzyMatchJob;
+    use super::sync_crypto::DeriveKekJob;
+    use super::text_analysis::TextAnalysisJob;
+    use crate::actors::{CountPrimesJob, RenderFrameJob};
+
+    if name == CipherJob::NAME {
+        run_encoded::<CipherJob>(input)
+    } else if name == RenderFrameJob::NAME {
+        run_encoded::<RenderFrameJob>(input)
+    } else if name == CountPrimesJob::NAME {
+        run_encoded::<CountPrimesJob>(input)
+    } else if name == TextAnalysisJob::NAME {
+        run_encoded::<TextAnalysisJob>(input)
+    } else if name == DeriveKekJob::NAME {
+        run_encoded::<DeriveKekJob>(input)
+    } else if name == FuzzyMatchJob::NAME {
+        run_encoded::<FuzzyMatchJob>(input)
+    } else if name == DedupJob::NAME {
+        run_encoded::<DedupJob>(input)
+    } else {
+        Err(OffloadError::UnknownJob(name.to_string()))
+    }
+}
+
+#[cfg(target_family = "wasm")]
+fn run_encoded<J: WorkerJob>(input: &[u8]) -> Result<Vec<u8>, OffloadError> {
+    let job: J = bincode::deserialize(input).map_err(|e| OffloadError::Encoding(e.to_string()))?;
+    bincode::serialize(&job.run()).map_err(|e| OffloadError::Encoding(e.to_string()))
+}
+
+// 워커 스크립트(web/offload_worker.js)가 호출하는 진입점
+#[cfg(target_family = "wasm")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub fn run_worker_job(name: &str, input: &[u8]) -> Result<Vec<u8>, wasm_bindgen::JsValue> {
+    dispatch(name, input).map_err(|e| wasm_bindgen::JsValue::from_str(&e.to_string()))
+}
+
+// 메인 스레드 쪽 메시지 다리
+// 워커 하나를 처음 쓸 때 띄우고, 요청마다 id를 붙여 보낸 뒤 응답의 id로 기다리는 쪽을 찾는다.
+#[cfg(target_family = "wasm")]
+mod web_worker {
+    use js_sys::{Object, Reflect, Uint8Array};
+    use std::{cell::RefCell, collections::HashMap};
+    use tokio::sync::oneshot;
+    use wasm_bindgen::{JsCast, JsValue, closure::Closure};
+    use web_sys::{ErrorEvent, MessageEvent, Worker, WorkerOptions, WorkerType};
+
+    // pkg/hub.js를 ES 모듈로 불러오므로 모듈 워커로 띄운다
+    const WORKER_SCRIPT: &str = "offload_worker.js";
+
+    type Reply = Result<Vec<u8>, String>;
+
+    struct Bridge {
+        worker: Worker,
+        next_id: u32,
+        pending: HashMap<u32, oneshot::Sender<Reply>>,
+        _on_message: Closure<dyn FnMut(MessageEvent)>,
+        _on_error: Closure<dyn FnMut(ErrorEvent)>,
+    }
+
+    // 웹에서는 한 스레드만 쓰므로 스레드 지역 변수에 둔다
+    thread_local! {
+        static BRIDGE: RefCell<Option<Bridge>> = const { RefCell::new(None) };
+    }
+
+    pub fn post(name: &str, input: &[u8]) -> Result<oneshot::Receiver<Reply>, String> {
+        BRIDGE.with(|cell| {
+            let mut slot = cell.borrow_mut();
+            if slot.is_none() {
+                *slot = Some(Bridge::spawn()?);
+            }
+            let bridge = slot
+                .as_mut()
+                .ok_or_else(|| "Worker is not running".to_string())?;
+
+            let id = bridge.next_id;
+            bridge.next_id = bridge.next_id.wrapping_add(1);
+            let message = Object::new();
+            Reflect::set(&message, &"id".into(), &id.into()).map_err(js_error)?;
+            Reflect::set(&message, &"name".into(), &name.into()).map_err(js_error)?;
+            Reflect::set(&message, &"input".into(), &Uint8Array::from(input).into())
+                .map_err(js_error)?;
+            bridge.worker.post_message(&message).map_err(js_error)?;
+
+            let (sender, receiver) = oneshot::channel();
+            bridge.pending.insert(id, sender);
+            Ok(receiver)
+        })
+    }
+
+    impl Bridge {
+        fn spawn() -> Result<Self, String> {
+            let options = WorkerOptions::new();
+            options.set_type(WorkerType::Module);
+            let worker = Worker::new_with_options(WORKER_SCRIPT, &options).map_err(js_error)?;
+            let on_message = Closure::<dyn FnMut(MessageEvent)>::new(|event: MessageEvent| {
+                let data = event.data();
+                let Some(id) = Reflect::get(&data, &"id".into())
+                    .ok()
+                    .and_then(|id| id.as_f64())
+                else {
+                    return;
+                };
+                let reply = match Reflect::get(&data, &"error".into())
+                    .ok()
+                    .and_then(|error| error.as_string())
+                {
+                    Some(error) => Err(error),
+                    None => Reflect::get(&data, &"output".into())
+                        .map(|output| Uint8Array::new(&output).to_vec())
+                        .map_err(js_error),
+                };
+                if let Some(sender) = take_pending(|pending| pending.remove(&(id as u32))) {
+                    let _ = sender.send(reply);
+                }
+            });
+            // 워커 스크립트를 못 읽었거나 작업 중 죽으면 기다리는 요청을 모두 실패시킨다
+            let on_error = Closure::<dyn FnMut(ErrorEvent)>::new(|event: ErrorEvent| {
+                let message = event.message();
+                while let Some(sender) = take_pending(|pending| {
+                    let id = pending.keys().next().copied()?;
+                    pending.remove(&id)
+                }) {
+                    let _ = sender.send(Err(message.clone()));
+                }
+            });
+            worker.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+            worker.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+
+            Ok(Self {
+                worker,
+                next_id: 0,
+                pending: HashMap::new(),
+                _on_message: on_message,
+                _on_error: on_error,
+            })
+        }
+    }
+
+    fn take_pending(
+        take: impl FnOnce(&mut HashMap<u32, oneshot::Sender<Reply>>) -> Option<oneshot::Sender<Reply>>,
+    ) -> Option<oneshot::Sender<Reply>> {
+        BRIDGE.with(|cell| {
+            cell.borrow_mut()
+                .as_mut()
+                .and_then(|bridge| take(&mut bridge.pending))
+        })
+    }
+
+    fn js_error(value: JsValue) -> String {
+        value.as_string().unwrap_or_else(|| format!("{:?}", value))
+    }
+}
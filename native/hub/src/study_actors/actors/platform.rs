@@ -0,0 +1,118 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use tokio::task::JoinSet;
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{GetPlatformCapabilities, PlatformCapabilities},
+    signals::{
+        EmitSignal, PlatformCapabilitiesRequest, PlatformCapabilitiesSignal, route_dart_signals,
+    },
+};
+
+// 플랫폼 기능 액터
+// 시작 시 현재 타깃에서 쓸 수 있는 하위 시스템을 감지해 Dart에 알리고,
+// 다른 액터는 이 값을 보고 실패하는 대신 대체 수단(메모리 비밀 저장소 등)으로 동작한다.
+pub struct PlatformActor {
+    capabilities: PlatformCapabilities,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for PlatformActor {}
+
+impl PlatformActor {
+    pub fn new(self_addr: Address<Self>, capabilities: PlatformCapabilities) -> Self {
+        debug_print!(
+            "Platform capabilities on {}: {:?}",
+            Self::os(),
+            capabilities
+        );
+        Self::signal(capabilities).emit();
+
+        let owned_tasks = route_dart_signals!(self_addr, [PlatformCapabilitiesRequest]);
+
+        Self {
+            capabilities,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    // 감독자가 액터를 구성하기 전에 동기적으로 호출한다
+    pub fn detect() -> PlatformCapabilities {
+        PlatformCapabilities {
+            keychain: Self::has_keychain(),
+            file_watching: cfg!(any(
+                target_os = "linux",
+                target_os = "macos",
+                target_os = "windows"
+            )),
+            sled: cfg!(not(target_family = "wasm")),
+            indexed_db: cfg!(target_family = "wasm"),
+            multithreading: Self::has_worker_threads(),
+        }
+    }
+
+    // keychain 기능으로 빌드했을 때만 쓴다 (Android 키스토어는 아직 연동하지 않았다)
+    // iOS/macOS/Windows는 항상 제공하고, Linux는 Secret Service가 도는 세션 버스가 있어야 한다
+    fn has_keychain() -> bool {
+        if !cfg!(feature = "keychain") {
+            return false;
+        }
+        if cfg!(any(
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "windows"
+        )) {
+            return true;
+        }
+        cfg!(target_os = "linux") && std::env::var_os("DBUS_SESSION_BUS_ADDRESS").is_some()
+    }
+
+    // multi-thread 기능을 켰더라도 실제로 도는 런타임을 기준으로 판단한다
+    #[cfg(not(target_family = "wasm"))]
+    fn has_worker_threads() -> bool {
+        tokio::runtime::Handle::try_current()
+            .map(|handle| handle.runtime_flavor() == tokio::runtime::RuntimeFlavor::MultiThread)
+            .unwrap_or(false)
+    }
+
+    #[cfg(target_family = "wasm")]
+    fn has_worker_threads() -> bool {
+        false
+    }
+
+    fn os() -> &'static str {
+        if cfg!(target_family = "wasm") {
+            "web"
+        } else {
+            std::env::consts::OS
+        }
+    }
+
+    fn signal(capabilities: PlatformCapabilities) -> PlatformCapabilitiesSignal {
+        PlatformCapabilitiesSignal {
+            os: Self::os().to_string(),
+            capabilities,
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<GetPlatformCapabilities> for PlatformActor {
+    type Result = PlatformCapabilities;
+
+    async fn handle(&mut self, _: GetPlatformCapabilities, _: &Context<Self>) -> Self::Result {
+        self.capabilities
+    }
+}
+
+// Dart 신호 처리 (시작 시 보낸 신호를 놓친 경우 다시 요청)
+#[async_trait]
+impl Notifiable<PlatformCapabilitiesRequest> for PlatformActor {
+    async fn notify(&mut self, _: PlatformCapabilitiesRequest, _: &Context<Self>) {
+        Self::signal(self.capabilities).emit();
+    }
+}
@@ -0,0 +1,207 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Notifiable},
+};
+use std::{collections::HashMap, time::Duration};
+use tokio::task::JoinSet;
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{DataItem, FetchData, FetchMode, FetchRecentData, GetMedia, GetProfile, UserId},
+    signals::{RoutePrefetchHint, route_dart_signals},
+};
+
+use super::{Clock, DataManagerActor, Instant, MediaCacheActor, TrackedTask, UserManagerActor};
+
+// 힌트가 연달아 오면 마지막 것만 처리하도록 잠시 기다린다
+const HINT_DELAY: Duration = Duration::from_millis(150);
+const FIRST_PAGE_SIZE: usize = 20;
+const MAX_THUMBNAILS: usize = 12;
+
+// 경로별로 미리 채울 캐시
+#[derive(Debug, Clone, PartialEq)]
+enum Warmup {
+    Profile(UserId),
+    FirstPage(UserId),
+    Item(String),
+    Thumbnails(Vec<String>),
+}
+
+// 라우터가 쓰는 경로(RouterActor::navigate 참고)를 미리 채울 캐시 목록으로 바꾼다
+// thumbnails 인자(쉼표로 구분한 URL)는 경로와 상관없이 미디어 캐시에 받아 둔다.
+fn plan(route: &str, params: &HashMap<String, String>, user_id: Option<&UserId>) -> Vec<Warmup> {
+    let mut warmups = Vec::new();
+    match (route, user_id) {
+        ("/", Some(user_id)) => {
+            warmups.push(Warmup::Profile(user_id.clone()));
+            warmups.push(Warmup::FirstPage(user_id.clone()));
+        }
+        ("/profile", Some(user_id)) => warmups.push(Warmup::Profile(user_id.clone())),
+        ("/items/detail", _) => warmups.extend(params.get("item_id").cloned().map(Warmup::Item)),
+        _ => {}
+    }
+    let thumbnails: Vec<String> = params
+        .get("thumbnails")
+        .into_iter()
+        .flat_map(|urls| urls.split(','))
+        .map(str::trim)
+        .filter(|url| !url.is_empty())
+        .take(MAX_THUMBNAILS)
+        .map(str::to_string)
+        .collect();
+    if !thumbnails.is_empty() {
+        warmups.push(Warmup::Thumbnails(thumbnails));
+    }
+    warmups
+}
+
+// 아직 내려받지 않은 원격 이미지 첨부
+fn remote_images(items: &[DataItem]) -> Vec<String> {
+    items
+        .iter()
+        .flat_map(|item| &item.attachments)
+        .filter(|attachment| {
+            attachment.blob_hash.is_none() && attachment.mime_type.starts_with("image/")
+        })
+        .filter_map(|attachment| attachment.remote_url.clone())
+        .take(MAX_THUMBNAILS)
+        .collect()
+}
+
+// 미리 불러오기 액터
+// 라우터 힌트를 받아 다음 화면이 쓸 프로필, 첫 페이지 항목, 썸네일을 캐시에 미리 채운다.
+// 한 번에 한 경로만 처리하며, 다른 경로의 힌트가 오면 진행 중인 작업을 취소한다.
+// 이미 대상 액터에 전달된 메시지는 취소되지 않지만 결과는 어차피 캐시에 남는다.
+pub struct PrefetchActor {
+    user_manager: Address<UserManagerActor>,
+    data: Address<DataManagerActor>,
+    media_cache: Address<MediaCacheActor>,
+    clock: Clock,
+    user_id: Option<UserId>, // 힌트에 user_id가 없으면 마지막으로 받은 값을 쓴다
+    current: Option<(Vec<Warmup>, TrackedTask)>,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for PrefetchActor {}
+
+impl PrefetchActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        user_manager: Address<UserManagerActor>,
+        data: Address<DataManagerActor>,
+        media_cache: Address<MediaCacheActor>,
+        clock: Clock,
+    ) -> Self {
+        let owned_tasks = route_dart_signals!(self_addr, [RoutePrefetchHint]);
+
+        Self {
+            user_manager,
+            data,
+            media_cache,
+            clock,
+            user_id: None,
+            current: None,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    async fn warm_up(
+        mut user_manager: Address<UserManagerActor>,
+        mut data: Address<DataManagerActor>,
+        mut media_cache: Address<MediaCacheActor>,
+        clock: Clock,
+        warmups: Vec<Warmup>,
+    ) {
+        clock.sleep(HINT_DELAY).await;
+        let started = Instant::now();
+        for warmup in &warmups {
+            // 목록에서 찾은 썸네일은 같은 작업 안에서 이어 받는다
+            let thumbnails = match warmup {
+                Warmup::Profile(user_id) => {
+                    let request = GetProfile {
+                        user_id: user_id.clone(),
+                    };
+                    if let Ok(Err(e)) = user_manager.send(request).await {
+                        debug_print!("Prefetch of profile failed: {}", e);
+                    }
+                    Vec::new()
+                }
+                Warmup::FirstPage(user_id) => {
+                    let request = FetchRecentData {
+                        user_id: user_id.clone(),
+                        limit: Some(FIRST_PAGE_SIZE),
+                        mode: FetchMode::CacheFirst,
+                    };
+                    match data.send(request).await {
+                        Ok(Ok(page)) => remote_images(&page.items),
+                        _ => Vec::new(),
+                    }
+                }
+                Warmup::Item(item_id) => {
+                    let request = FetchData {
+                        key: format!("items/{}", item_id),
+                        user_id: None,
+                        mode: FetchMode::CacheFirst,
+                    };
+                    match data.send(request).await {
+                        Ok(Ok(bytes)) => serde_json::from_slice::<DataItem>(&bytes)
+                            .map(|item| remote_images(&[item]))
+                            .unwrap_or_default(),
+                        _ => Vec::new(),
+                    }
+                }
+                Warmup::Thumbnails(urls) => urls.clone(),
+            };
+            for url in thumbnails {
+                let request = GetMedia {
+                    url,
+                    background: true,
+                };
+                if let Ok(Err(e)) = media_cache.send(request).await {
+                    debug_print!("Prefetch of thumbnail failed: {}", e);
+                }
+            }
+        }
+        debug_print!(
+            "Prefetched {} caches in {} ms",
+            warmups.len(),
+            started.elapsed().as_millis()
+        );
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<RoutePrefetchHint> for PrefetchActor {
+    async fn notify(&mut self, msg: RoutePrefetchHint, _: &Context<Self>) {
+        if let Some(user_id) = msg.params.get("user_id") {
+            self.user_id = Some(user_id.clone());
+        }
+        let warmups = plan(&msg.route, &msg.params, self.user_id.as_ref());
+
+        // 같은 경로를 이미 미리 불러오는 중이면 그대로 두고, 다른 경로면 취소한다
+        if let Some((current, task)) = &self.current {
+            if *current == warmups && !task.is_finished() {
+                return;
+            }
+            task.abort();
+        }
+        self.current = None;
+        if warmups.is_empty() {
+            return;
+        }
+
+        let task = TrackedTask::spawn(
+            &mut self._owned_tasks,
+            Self::warm_up(
+                self.user_manager.clone(),
+                self.data.clone(),
+                self.media_cache.clone(),
+                self.clock.clone(),
+                warmups.clone(),
+            ),
+        );
+        self.current = Some((warmups, task));
+    }
+}
@@ -0,0 +1,352 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
+use tokio::{
+    sync::broadcast,
+    task::{AbortHandle, JoinSet},
+};
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        AppLifecycleState, GetPresence, PresenceState, RealtimeEnvelope, RealtimeEvent,
+        SendRealtimeEnvelope, UserId,
+    },
+    signals::{
+        AppLifecycleChanged, EmitSignal, PresenceChangedSignal, StartPresenceRequest,
+        StopPresenceRequest, route_dart_signals,
+    },
+};
+
+use super::{Clock, TrustedClock, WebSocketActor};
+
+const PRESENCE_CHANNEL: &str = "presence";
+const CONTACT_DEBOUNCE_MS: u64 = 3_000;
+const AWAY_DELAY_SECS: u64 = 15; // 잠깐 다른 앱으로 전환하는 경우는 자리 비움으로 보지 않음
+const HEARTBEAT_SECS: u64 = 60;
+
+// 서버와 주고받는 상태 페이로드
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PresenceUpdate {
+    user_id: UserId,
+    state: PresenceState,
+    last_seen: u64,
+}
+
+// 프레즌스 액터
+// 연락처 상태는 짧은 시간 안에 오가는 변화(flapping)를 걸러낸 뒤 Dart에 전달한다.
+pub struct PresenceActor {
+    websocket: Address<WebSocketActor>,
+    clock: TrustedClock,
+    user_id: Option<UserId>,
+    local_state: PresenceState,
+    pending_local: Option<AbortHandle>,
+    contacts: HashSet<UserId>,
+    contact_states: HashMap<UserId, PresenceUpdate>,
+    pending_contacts: HashMap<UserId, AbortHandle>,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for PresenceActor {}
+
+impl PresenceActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        websocket: Address<WebSocketActor>,
+        realtime_events: broadcast::Receiver<RealtimeEvent>,
+        clock: TrustedClock,
+    ) -> Self {
+        // 데스크톱에서는 BackgroundModeActor가 수명 주기 신호를 받아 전달한다 (수신기는 하나만 받으므로)
+        #[cfg(any(target_family = "wasm", target_os = "android", target_os = "ios"))]
+        let mut owned_tasks = route_dart_signals!(
+            self_addr,
+            [
+                StartPresenceRequest,
+                StopPresenceRequest,
+                AppLifecycleChanged
+            ]
+        );
+        #[cfg(not(any(target_family = "wasm", target_os = "android", target_os = "ios")))]
+        let mut owned_tasks =
+            route_dart_signals!(self_addr, [StartPresenceRequest, StopPresenceRequest]);
+        owned_tasks.spawn(Self::listen_to_realtime(self_addr.clone(), realtime_events));
+        owned_tasks.spawn(Self::heartbeat(self_addr.clone(), clock.clock().clone()));
+
+        Self {
+            websocket,
+            clock,
+            user_id: None,
+            local_state: PresenceState::Offline,
+            pending_local: None,
+            contacts: HashSet::new(),
+            contact_states: HashMap::new(),
+            pending_contacts: HashMap::new(),
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    async fn listen_to_realtime(
+        mut self_addr: Address<Self>,
+        mut events: broadcast::Receiver<RealtimeEvent>,
+    ) {
+        loop {
+            match events.recv().await {
+                Ok(event) => {
+                    let _ = self_addr.notify(RealtimeEventReceived(event)).await;
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    debug_print!("Presence skipped {} realtime events", skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    }
+
+    // 서버가 끊긴 세션을 만료시킬 수 있도록 주기적으로 현재 상태 재전송
+    async fn heartbeat(mut self_addr: Address<Self>, clock: Clock) {
+        let mut interval = clock.interval(Duration::from_secs(HEARTBEAT_SECS));
+        loop {
+            interval.tick().await;
+            let _ = self_addr.notify(PublishHeartbeat).await;
+        }
+    }
+
+    async fn settle_contact_after(
+        mut self_addr: Address<Self>,
+        clock: Clock,
+        update: PresenceUpdate,
+    ) {
+        clock.sleep(Duration::from_millis(CONTACT_DEBOUNCE_MS)).await;
+        let _ = self_addr.notify(ContactSettled(update)).await;
+    }
+
+    async fn settle_local_after(
+        mut self_addr: Address<Self>,
+        clock: Clock,
+        state: PresenceState,
+        delay_secs: u64,
+    ) {
+        clock.sleep(Duration::from_secs(delay_secs)).await;
+        let _ = self_addr.notify(LocalStateSettled(state)).await;
+    }
+
+    async fn send_envelope<T: Serialize>(&mut self, kind: &str, data: &T) {
+        let Ok(data) = serde_json::to_value(data) else {
+            return;
+        };
+        let envelope = RealtimeEnvelope {
+            channel: PRESENCE_CHANNEL.to_string(),
+            kind: kind.to_string(),
+            data,
+        };
+        // 연결이 없으면 재연결 시 다시 게시하므로 실패는 무시
+        let _ = self.websocket.send(SendRealtimeEnvelope(envelope)).await;
+    }
+
+    async fn publish_local_state(&mut self) {
+        let Some(user_id) = self.user_id.clone() else {
+            return;
+        };
+        let update = PresenceUpdate {
+            user_id,
+            state: self.local_state,
+            last_seen: self.clock.trusted_now(),
+        };
+        self.send_envelope("update", &update).await;
+    }
+
+    async fn subscribe_contacts(&mut self) {
+        if self.contacts.is_empty() {
+            return;
+        }
+        let user_ids: Vec<UserId> = self.contacts.iter().cloned().collect();
+        self.send_envelope("subscribe", &serde_json::json!({ "user_ids": user_ids }))
+            .await;
+    }
+
+    fn cancel_pending_local(&mut self) {
+        if let Some(handle) = self.pending_local.take() {
+            handle.abort();
+        }
+    }
+
+    // 연락처 상태 변경은 디바운스 후 반영 (중간에 원래 상태로 돌아오면 취소)
+    fn schedule_contact(&mut self, update: PresenceUpdate, ctx: &Context<Self>) {
+        if let Some(handle) = self.pending_contacts.remove(&update.user_id) {
+            handle.abort();
+        }
+        let unchanged = self
+            .contact_states
+            .get(&update.user_id)
+            .is_some_and(|current| current.state == update.state);
+        if unchanged {
+            if let Some(current) = self.contact_states.get_mut(&update.user_id) {
+                current.last_seen = update.last_seen;
+            }
+            return;
+        }
+
+        let user_id = update.user_id.clone();
+        let handle = self._owned_tasks.spawn(Self::settle_contact_after(
+            ctx.address(),
+            self.clock.clock().clone(),
+            update,
+        ));
+        self.pending_contacts.insert(user_id, handle);
+    }
+}
+
+// 내부 메시지 정의
+struct RealtimeEventReceived(RealtimeEvent);
+struct ContactSettled(PresenceUpdate);
+struct LocalStateSettled(PresenceState);
+struct PublishHeartbeat;
+
+#[async_trait]
+impl Notifiable<RealtimeEventReceived> for PresenceActor {
+    async fn notify(&mut self, msg: RealtimeEventReceived, ctx: &Context<Self>) {
+        match msg.0 {
+            RealtimeEvent::Connected => {
+                self.publish_local_state().await;
+                self.subscribe_contacts().await;
+            }
+            RealtimeEvent::Disconnected => {}
+            RealtimeEvent::Envelope(envelope)
+                if envelope.channel == PRESENCE_CHANNEL && envelope.kind == "update" =>
+            {
+                match serde_json::from_value::<PresenceUpdate>(envelope.data) {
+                    Ok(update) if self.contacts.contains(&update.user_id) => {
+                        self.schedule_contact(update, ctx);
+                    }
+                    Ok(_) => {}
+                    Err(e) => debug_print!("Invalid presence update: {}", e),
+                }
+            }
+            RealtimeEvent::Envelope(_) => {}
+        }
+    }
+}
+
+#[async_trait]
+impl Notifiable<ContactSettled> for PresenceActor {
+    async fn notify(&mut self, msg: ContactSettled, _: &Context<Self>) {
+        let update = msg.0;
+        self.pending_contacts.remove(&update.user_id);
+
+        PresenceChangedSignal {
+            user_id: update.user_id.clone(),
+            state: update.state,
+            last_seen: update.last_seen,
+        }
+        .emit();
+        self.contact_states.insert(update.user_id.clone(), update);
+    }
+}
+
+#[async_trait]
+impl Notifiable<LocalStateSettled> for PresenceActor {
+    async fn notify(&mut self, msg: LocalStateSettled, _: &Context<Self>) {
+        self.pending_local = None;
+        if self.local_state != msg.0 {
+            self.local_state = msg.0;
+            self.publish_local_state().await;
+        }
+    }
+}
+
+#[async_trait]
+impl Notifiable<PublishHeartbeat> for PresenceActor {
+    async fn notify(&mut self, _: PublishHeartbeat, _: &Context<Self>) {
+        if self.local_state != PresenceState::Offline {
+            self.publish_local_state().await;
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<GetPresence> for PresenceActor {
+    type Result = Option<PresenceState>;
+
+    async fn handle(&mut self, msg: GetPresence, _: &Context<Self>) -> Self::Result {
+        self.contact_states
+            .get(&msg.user_id)
+            .map(|update| update.state)
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<StartPresenceRequest> for PresenceActor {
+    async fn notify(&mut self, msg: StartPresenceRequest, _: &Context<Self>) {
+        self.user_id = Some(msg.user_id);
+        self.contacts = msg.contacts.into_iter().collect();
+        self.contact_states
+            .retain(|user_id, _| self.contacts.contains(user_id));
+
+        self.cancel_pending_local();
+        self.local_state = PresenceState::Online;
+        self.publish_local_state().await;
+        self.subscribe_contacts().await;
+    }
+}
+
+#[async_trait]
+impl Notifiable<StopPresenceRequest> for PresenceActor {
+    async fn notify(&mut self, _: StopPresenceRequest, _: &Context<Self>) {
+        self.cancel_pending_local();
+        self.local_state = PresenceState::Offline;
+        self.publish_local_state().await;
+
+        for (_, handle) in self.pending_contacts.drain() {
+            handle.abort();
+        }
+        self.user_id = None;
+        self.contacts.clear();
+        self.contact_states.clear();
+    }
+}
+
+#[async_trait]
+impl Notifiable<AppLifecycleChanged> for PresenceActor {
+    async fn notify(&mut self, msg: AppLifecycleChanged, ctx: &Context<Self>) {
+        if self.user_id.is_none() {
+            return;
+        }
+
+        let target = match msg.state {
+            AppLifecycleState::Resumed => PresenceState::Online,
+            AppLifecycleState::Inactive | AppLifecycleState::Hidden | AppLifecycleState::Paused => {
+                PresenceState::Away
+            }
+            AppLifecycleState::Detached => PresenceState::Offline,
+        };
+
+        self.cancel_pending_local();
+        match target {
+            // 복귀와 종료는 즉시 반영
+            PresenceState::Online | PresenceState::Offline => {
+                if self.local_state != target {
+                    self.local_state = target;
+                    self.publish_local_state().await;
+                }
+            }
+            PresenceState::Away => {
+                if self.local_state == PresenceState::Online {
+                    self.pending_local = Some(self._owned_tasks.spawn(Self::settle_local_after(
+                        ctx.address(),
+                        self.clock.clock().clone(),
+                        target,
+                        AWAY_DELAY_SECS,
+                    )));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use std::collections::HashMap;
+use tokio::task::JoinSet;
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        CheckConsent, ConsentCategory, FetchData, FetchMode, PrivacyError, StoreData, WipeReport,
+        WipeUserData,
+    },
+    signals::{
+        ConsentStatusSignal, EmitSignal, GetConsentRequest, SetConsentRequest,
+        route_dart_signals,
+    },
+};
+
+#[cfg(feature = "ml")]
+use super::EmbeddingActor;
+use super::{
+    AttachmentActor, CacheActor, ChatActor, CollabActor, DataManagerActor, NotificationActor,
+    RankingActor, StorageActor,
+};
+
+const CONSENT_KEY: &str = "privacy/consent";
+
+// 개인정보 액터
+// 동의하지 않은 항목은 기본적으로 거부(opt-in)하며, 동의 상태는 저장소에 보관한다.
+pub struct PrivacyActor {
+    consents: HashMap<ConsentCategory, bool>,
+    storage: Address<StorageActor>,
+    cache: Address<CacheActor>,
+    chat: Address<ChatActor>,
+    notification: Address<NotificationActor>,
+    ranking: Address<RankingActor>,
+    data: Address<DataManagerActor>,
+    attachment: Address<AttachmentActor>,
+    collab: Address<CollabActor>,
+    #[cfg(feature = "ml")]
+    embedding: Option<Address<EmbeddingActor>>,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for PrivacyActor {}
+
+impl PrivacyActor {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        self_addr: Address<Self>,
+        storage: Address<StorageActor>,
+        cache: Address<CacheActor>,
+        chat: Address<ChatActor>,
+        notification: Address<NotificationActor>,
+        ranking: Address<RankingActor>,
+        data: Address<DataManagerActor>,
+        attachment: Address<AttachmentActor>,
+        collab: Address<CollabActor>,
+    ) -> Self {
+        let mut owned_tasks =
+            route_dart_signals!(self_addr, [SetConsentRequest, GetConsentRequest]);
+        owned_tasks.spawn(Self::load_consents(self_addr.clone()));
+
+        Self {
+            consents: HashMap::new(),
+            storage,
+            cache,
+            chat,
+            notification,
+            ranking,
+            data,
+            attachment,
+            collab,
+            #[cfg(feature = "ml")]
+            embedding: None,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    // 임베딩 액터는 ml 기능에서만 생성되므로 따로 주입한다
+    #[cfg(feature = "ml")]
+    pub fn with_embedding_manager(mut self, embedding: Address<EmbeddingActor>) -> Self {
+        self.embedding = Some(embedding);
+        self
+    }
+
+    async fn load_consents(mut self_addr: Address<Self>) {
+        let _ = self_addr.notify(LoadConsents).await;
+    }
+
+    fn is_granted(&self, category: ConsentCategory) -> bool {
+        self.consents.get(&category).copied().unwrap_or(false)
+    }
+
+    fn send_status(&self) {
+        ConsentStatusSignal {
+            analytics: self.is_granted(ConsentCategory::Analytics),
+            crash_reports: self.is_granted(ConsentCategory::CrashReports),
+            personalization: self.is_granted(ConsentCategory::Personalization),
+        }
+        .emit();
+    }
+
+    async fn persist_consents(&mut self) -> Result<(), PrivacyError> {
+        let request = StoreData {
+            key: CONSENT_KEY.to_string(),
+            data: serde_json::to_vec(&self.consents)?,
+            user_id: None,
+            ttl: None,
+        };
+        self.storage.send(request).await??;
+        Ok(())
+    }
+
+    // 대기열 → 캐시 → 저장소 순서로 비워 삭제 도중 다시 기록되는 일을 막는다
+    async fn wipe_all(&mut self) -> WipeReport {
+        let mut report = WipeReport::default();
+
+        #[cfg_attr(not(feature = "ml"), allow(unused_mut))]
+        let mut results = vec![
+            ("chat", flatten(self.chat.send(WipeUserData).await)),
+            ("collab", flatten(self.collab.send(WipeUserData).await)),
+            (
+                "notification",
+                flatten(self.notification.send(WipeUserData).await),
+            ),
+            ("ranking", flatten(self.ranking.send(WipeUserData).await)),
+            ("data", flatten(self.data.send(WipeUserData).await)),
+            (
+                "attachment",
+                flatten(self.attachment.send(WipeUserData).await),
+            ),
+            ("cache", flatten(self.cache.send(WipeUserData).await)),
+            ("storage", flatten(self.storage.send(WipeUserData).await)),
+        ];
+        #[cfg(feature = "ml")]
+        if let Some(embedding) = self.embedding.as_mut() {
+            results.push(("embedding", flatten(embedding.send(WipeUserData).await)));
+        }
+        for (target, result) in results {
+            match result {
+                Ok(()) => report.wiped.push(target.to_string()),
+                Err(e) => report.errors.push(format!("{}: {}", target, e)),
+            }
+        }
+
+        // 동의 상태도 사용자 데이터이므로 기본값(거부)으로 초기화
+        self.consents.clear();
+        report
+    }
+}
+
+fn flatten<F: Into<PrivacyError>, E: std::fmt::Display>(
+    result: Result<Result<(), F>, E>,
+) -> Result<(), PrivacyError> {
+    match result {
+        Ok(inner) => inner.map_err(Into::into),
+        Err(e) => Err(e.to_string().into()),
+    }
+}
+
+// 내부 메시지 정의
+struct LoadConsents;
+
+#[async_trait]
+impl Notifiable<LoadConsents> for PrivacyActor {
+    async fn notify(&mut self, _: LoadConsents, _: &Context<Self>) {
+        let request = FetchData {
+            key: CONSENT_KEY.to_string(),
+            user_id: None,
+            mode: FetchMode::CacheFirst,
+        };
+        if let Ok(Ok(bytes)) = self.storage.send(request).await {
+            match serde_json::from_slice(&bytes) {
+                Ok(consents) => self.consents = consents,
+                Err(e) => debug_print!("Invalid stored consent state: {}", e),
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<CheckConsent> for PrivacyActor {
+    type Result = bool;
+
+    async fn handle(&mut self, msg: CheckConsent, _: &Context<Self>) -> Self::Result {
+        self.is_granted(msg.category)
+    }
+}
+
+// 원격 삭제와 Dart의 전체 삭제 요청 때 감독자가 보낸다
+#[async_trait]
+impl Handler<WipeUserData> for PrivacyActor {
+    type Result = WipeReport;
+
+    async fn handle(&mut self, _: WipeUserData, _: &Context<Self>) -> Self::Result {
+        let report = self.wipe_all().await;
+        self.send_status();
+        report
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<SetConsentRequest> for PrivacyActor {
+    async fn notify(&mut self, msg: SetConsentRequest, _: &Context<Self>) {
+        self.consents.insert(msg.category, msg.granted);
+        if let Err(e) = self.persist_consents().await {
+            debug_print!("Failed to persist consent state: {}", e);
+        }
+        debug_print!("Consent for {:?} set to {}", msg.category, msg.granted);
+        self.send_status();
+    }
+}
+
+#[async_trait]
+impl Notifiable<GetConsentRequest> for PrivacyActor {
+    async fn notify(&mut self, _: GetConsentRequest, _: &Context<Self>) {
+        self.send_status();
+    }
+}
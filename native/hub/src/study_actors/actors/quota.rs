@@ -0,0 +1,435 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use tokio::{sync::broadcast, task::JoinSet};
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        AppEvent, CheckQuota, FetchData, FetchMode, GetPlanLimitsEndpoint, PlanLimits, QuotaConfig,
+        QuotaDecision, QuotaError, QuotaMetric, QuotaUsage, RecordUsage, RefreshPlanLimits,
+        ScanPrefix, StoreData, UserId,
+    },
+    signals::{
+        EmitSignal, QuotaStatusRequest, QuotaStatusSignal, QuotaWarningSignal, route_dart_signals,
+    },
+};
+
+use super::{ApiClient, Priority, StorageActor, TrustedClock};
+
+const QUOTA_KEY_PREFIX: &str = "quota/";
+const ITEM_KEY_PREFIX: &str = "items/";
+
+// 사용자별로 저장하는 한도와 사용량
+#[derive(Default, Serialize, Deserialize)]
+struct UserQuota {
+    limits: Option<PlanLimits>,
+    limits_checked_at: u64, // 마지막으로 한도를 물어본 시각 (실패해도 남긴다)
+    usage: QuotaUsage,
+    warned: HashSet<QuotaMetric>, // 이번 기간에 경고를 보낸 사용량
+    #[serde(skip)]
+    storage_measured: bool, // 항목이 바뀌면 다음 확인 때 다시 잰다
+}
+
+impl UserQuota {
+    // 기간이 지났으면 기간 사용량을 0으로 돌린다 (서버가 새 기간을 알려 주기 전까지는 같은 길이로 넘긴다)
+    fn roll_period(&mut self, now: u64) {
+        let Some(limits) = self.limits.as_mut() else {
+            return;
+        };
+        if now < limits.period_end {
+            return;
+        }
+        let length = limits.period_end.saturating_sub(limits.period_start).max(1);
+        let elapsed = now.saturating_sub(limits.period_start) / length;
+        limits.period_start += elapsed * length;
+        limits.period_end = limits.period_start + length;
+        self.usage.items_created = 0;
+        self.usage.api_calls = 0;
+        self.warned
+            .retain(|metric| *metric == QuotaMetric::StorageBytes);
+    }
+
+    fn decide(&self, metric: QuotaMetric, amount: u64) -> QuotaDecision {
+        let used = self.usage.used(metric);
+        let limit = self.limits.as_ref().and_then(|limits| limits.limit(metric));
+        QuotaDecision {
+            allowed: limit.is_none_or(|limit| used.saturating_add(amount) <= limit),
+            used,
+            limit,
+        }
+    }
+}
+
+// 사용량 한도 액터
+// 사용자별 항목 생성 수, 저장 용량, 서버 API 호출 수를 서버가 알려 준 요금제 한도와 비교한다.
+// 다른 액터는 비용이 큰 작업 전에 CheckQuota로 묻고, 한도에 가까워지면 Dart에 경고를 보낸다.
+pub struct QuotaActor {
+    users: HashMap<UserId, UserQuota>,
+    config: QuotaConfig,
+    api: Option<ApiClient>, // 없으면 한도를 모르므로 모두 허용한다
+    storage: Address<StorageActor>,
+    clock: TrustedClock,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for QuotaActor {}
+
+impl QuotaActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        config: QuotaConfig,
+        storage: Address<StorageActor>,
+        events: broadcast::Receiver<AppEvent>,
+        clock: TrustedClock,
+    ) -> Self {
+        let mut owned_tasks = route_dart_signals!(self_addr, [QuotaStatusRequest]);
+        owned_tasks.spawn(Self::listen_to_events(self_addr, events));
+
+        Self {
+            users: HashMap::new(),
+            config,
+            api: None,
+            storage,
+            clock,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    // 요금제 서비스 클라이언트 설정
+    pub fn set_api_client(&mut self, api: ApiClient) {
+        self.api = Some(api);
+    }
+
+    async fn listen_to_events(
+        mut self_addr: Address<Self>,
+        mut events: broadcast::Receiver<AppEvent>,
+    ) {
+        loop {
+            match events.recv().await {
+                Ok(event) => {
+                    let _ = self_addr.notify(AppEventReceived(event)).await;
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    debug_print!("Quota actor skipped {} app events", skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    }
+
+    fn quota_key(user_id: &str) -> String {
+        format!("{}{}", QUOTA_KEY_PREFIX, user_id)
+    }
+
+    // 처음 보는 사용자는 저장된 사용량을 불러오고, 한도가 오래됐으면 서버에 다시 묻는다
+    async fn load_user(&mut self, user_id: &str) -> &mut UserQuota {
+        if !self.users.contains_key(user_id) {
+            let request = FetchData {
+                key: Self::quota_key(user_id),
+                user_id: Some(user_id.to_string()),
+                mode: FetchMode::CacheFirst,
+            };
+            let stored = match self.storage.send(request).await {
+                Ok(Ok(bytes)) => serde_json::from_slice(&bytes).unwrap_or_else(|e| {
+                    debug_print!("Invalid stored quota for {}: {}", user_id, e);
+                    UserQuota::default()
+                }),
+                Ok(Err(_)) => UserQuota::default(),
+                Err(e) => {
+                    debug_print!("Failed to load quota for {}: {}", user_id, e);
+                    UserQuota::default()
+                }
+            };
+            self.users.insert(user_id.to_string(), stored);
+        }
+
+        let now = self.clock.trusted_now();
+        let stale = self.users.get(user_id).is_some_and(|quota| {
+            now.saturating_sub(quota.limits_checked_at) >= self.config.refresh_interval_secs
+        });
+        if stale && let Err(e) = self.refresh_limits(user_id).await {
+            debug_print!("Failed to fetch plan limits for {}: {}", user_id, e);
+        }
+
+        let quota = self.users.entry(user_id.to_string()).or_default();
+        quota.roll_period(now);
+        quota
+    }
+
+    // 실패해도 다음 간격까지 다시 묻지 않는다 (그동안은 예전 한도를 쓴다)
+    async fn refresh_limits(&mut self, user_id: &str) -> Result<PlanLimits, QuotaError> {
+        let now = self.clock.trusted_now();
+        if let Some(quota) = self.users.get_mut(user_id) {
+            quota.limits_checked_at = now;
+        }
+        let Some(api) = &self.api else {
+            return Err("Plan limits are not configured".into());
+        };
+        let endpoint = GetPlanLimitsEndpoint {
+            user_id: user_id.to_string(),
+        };
+        let limits = api.send(Priority::Low, &endpoint).await?;
+
+        let quota = self.users.entry(user_id.to_string()).or_default();
+        // 새 기간이 시작됐으면 기간 사용량은 서버 기준으로 다시 센다
+        if quota
+            .limits
+            .as_ref()
+            .is_some_and(|old| old.period_start < limits.period_start)
+        {
+            quota.usage.items_created = 0;
+            quota.usage.api_calls = 0;
+        }
+        if quota.limits.as_ref() != Some(&limits) {
+            quota.warned.clear();
+        }
+        quota.limits = Some(limits.clone());
+        self.persist(user_id).await?;
+        Ok(limits)
+    }
+
+    // 저장소는 로그인한 사용자의 항목만 담으므로 items/ 아래 크기를 모두 더한다
+    async fn measure_storage(&mut self, user_id: &str) -> Result<(), QuotaError> {
+        let scan = ScanPrefix {
+            prefix: ITEM_KEY_PREFIX.to_string(),
+        };
+        let entries = self.storage.send(scan).await??;
+        let bytes = entries.iter().map(|(_, data)| data.len() as u64).sum();
+        if let Some(quota) = self.users.get_mut(user_id) {
+            quota.usage.storage_bytes = bytes;
+            quota.storage_measured = true;
+        }
+        Ok(())
+    }
+
+    async fn persist(&mut self, user_id: &str) -> Result<(), QuotaError> {
+        let Some(quota) = self.users.get(user_id) else {
+            return Ok(());
+        };
+        let request = StoreData {
+            key: Self::quota_key(user_id),
+            data: serde_json::to_vec(quota)?,
+            user_id: Some(user_id.to_string()),
+            ttl: None,
+        };
+        self.storage.send(request).await??;
+        Ok(())
+    }
+
+    // 경고 비율을 처음 넘으면 한 번만 알린다
+    fn warn_if_near_limit(&mut self, user_id: &str, metric: QuotaMetric) {
+        let Some(quota) = self.users.get_mut(user_id) else {
+            return;
+        };
+        let Some(limit) = quota
+            .limits
+            .as_ref()
+            .and_then(|limits| limits.limit(metric))
+        else {
+            return;
+        };
+        let used = quota.usage.used(metric);
+        if (used as f64) < limit as f64 * self.config.warning_ratio || !quota.warned.insert(metric)
+        {
+            return;
+        }
+        debug_print!(
+            "Quota {:?} for {} is at {}/{}",
+            metric,
+            user_id,
+            used,
+            limit
+        );
+        QuotaWarningSignal {
+            user_id: user_id.to_string(),
+            metric,
+            used,
+            limit,
+            exceeded: false,
+        }
+        .emit();
+    }
+
+    async fn record(&mut self, user_id: &str, metric: QuotaMetric, amount: u64) {
+        let quota = self.load_user(user_id).await;
+        let used = quota.usage.used_mut(metric);
+        *used = used.saturating_add(amount);
+        self.warn_if_near_limit(user_id, metric);
+        if let Err(e) = self.persist(user_id).await {
+            debug_print!("Failed to save quota for {}: {}", user_id, e);
+        }
+    }
+
+    // 항목이 바뀌면 저장 용량을 다시 재도록 표시한다 (이미 불러온 사용자만)
+    fn invalidate_storage(&mut self, user_id: &str) {
+        if let Some(quota) = self.users.get_mut(user_id) {
+            quota.storage_measured = false;
+        }
+    }
+}
+
+// 내부 메시지 정의
+struct AppEventReceived(AppEvent);
+
+#[async_trait]
+impl Notifiable<AppEventReceived> for QuotaActor {
+    async fn notify(&mut self, msg: AppEventReceived, ctx: &Context<Self>) {
+        match msg.0 {
+            AppEvent::DataItemCreated { user_id, .. } => {
+                self.invalidate_storage(&user_id);
+                self.record(&user_id, QuotaMetric::ItemsCreated, 1).await;
+            }
+            AppEvent::DataItemUpdated { user_id, .. }
+            | AppEvent::DataItemDeleted { user_id, .. }
+            | AppEvent::DataItemsSynced { user_id, .. } => self.invalidate_storage(&user_id),
+            // 구독이 바뀌면 서버의 요금제 한도도 바뀌므로 바로 다시 받는다
+            AppEvent::EntitlementsChanged { user_id, .. } => {
+                let refresh = RefreshPlanLimits {
+                    user_id: user_id.clone(),
+                };
+                if let Err(e) = self.handle(refresh, ctx).await {
+                    debug_print!("Failed to refresh plan limits for {}: {}", user_id, e);
+                }
+            }
+            // 설정 화면에서 바꾼 경고 비율과 갱신 간격은 다음 확인부터 적용된다
+            AppEvent::ConfigChanged { key, config } => {
+                if key.split('.').next() == Some("quota") {
+                    self.config = config.quota.clone();
+                }
+            }
+            AppEvent::SystemNotice { .. }
+            | AppEvent::ConnectivityChanged { .. }
+            | AppEvent::AdminCommandReceived(_)
+            | AppEvent::RemoteWipeRequested { .. } => {}
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<CheckQuota> for QuotaActor {
+    type Result = QuotaDecision;
+
+    async fn handle(&mut self, msg: CheckQuota, _: &Context<Self>) -> Self::Result {
+        let measured = self.load_user(&msg.user_id).await.storage_measured;
+        if msg.metric == QuotaMetric::StorageBytes
+            && !measured
+            && let Err(e) = self.measure_storage(&msg.user_id).await
+        {
+            debug_print!("Failed to measure storage for {}: {}", msg.user_id, e);
+        }
+
+        let quota = self.users.entry(msg.user_id.clone()).or_default();
+        let decision = quota.decide(msg.metric, msg.amount);
+        if let (false, Some(limit)) = (decision.allowed, decision.limit) {
+            debug_print!(
+                "Quota {:?} for {} denied {} more ({}/{})",
+                msg.metric,
+                msg.user_id,
+                msg.amount,
+                decision.used,
+                limit
+            );
+            QuotaWarningSignal {
+                user_id: msg.user_id,
+                metric: msg.metric,
+                used: decision.used,
+                limit,
+                exceeded: true,
+            }
+            .emit();
+        }
+        decision
+    }
+}
+
+#[async_trait]
+impl Notifiable<RecordUsage> for QuotaActor {
+    async fn notify(&mut self, msg: RecordUsage, _: &Context<Self>) {
+        self.record(&msg.user_id, msg.metric, msg.amount).await;
+    }
+}
+
+#[async_trait]
+impl Handler<RefreshPlanLimits> for QuotaActor {
+    type Result = Result<PlanLimits, QuotaError>;
+
+    async fn handle(&mut self, msg: RefreshPlanLimits, _: &Context<Self>) -> Self::Result {
+        self.load_user(&msg.user_id).await;
+        let limits = self.refresh_limits(&msg.user_id).await?;
+        for metric in [
+            QuotaMetric::ItemsCreated,
+            QuotaMetric::StorageBytes,
+            QuotaMetric::ApiCalls,
+        ] {
+            self.warn_if_near_limit(&msg.user_id, metric);
+        }
+        Ok(limits)
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<QuotaStatusRequest> for QuotaActor {
+    async fn notify(&mut self, msg: QuotaStatusRequest, _: &Context<Self>) {
+        let measured = self.load_user(&msg.user_id).await.storage_measured;
+        if !measured && let Err(e) = self.measure_storage(&msg.user_id).await {
+            debug_print!("Failed to measure storage for {}: {}", msg.user_id, e);
+        }
+        let quota = self.users.entry(msg.user_id.clone()).or_default();
+        QuotaStatusSignal {
+            user_id: msg.user_id,
+            usage: quota.usage.clone(),
+            limits: quota.limits.clone(),
+        }
+        .emit();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limits(period_start: u64, period_end: u64) -> PlanLimits {
+        PlanLimits {
+            plan: "free".to_string(),
+            items_created: Some(10),
+            storage_bytes: None,
+            api_calls: Some(100),
+            period_start,
+            period_end,
+        }
+    }
+
+    #[test]
+    fn period_usage_resets_when_the_period_ends() {
+        let mut quota = UserQuota {
+            limits: Some(limits(0, 100)),
+            usage: QuotaUsage {
+                items_created: 10,
+                storage_bytes: 5_000,
+                api_calls: 40,
+            },
+            ..UserQuota::default()
+        };
+        assert!(!quota.decide(QuotaMetric::ItemsCreated, 1).allowed);
+        assert!(quota.decide(QuotaMetric::StorageBytes, 1_000_000).allowed);
+
+        quota.roll_period(250);
+        assert_eq!(quota.usage.items_created, 0);
+        assert_eq!(quota.usage.storage_bytes, 5_000);
+        assert_eq!(
+            quota
+                .limits
+                .as_ref()
+                .map(|l| (l.period_start, l.period_end)),
+            Some((200, 300))
+        );
+        assert!(quota.decide(QuotaMetric::ItemsCreated, 10).allowed);
+        assert!(!quota.decide(QuotaMetric::ItemsCreated, 11).allowed);
+    }
+}
@@ -0,0 +1,535 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
+use tokio::task::{AbortHandle, JoinSet};
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        DataItem, DeleteData, FetchData, FetchMode, FetchRecentData, QuerySuggestion, RankItems,
+        RankedItem, RankingError, RecordItemAccess, RecordSearchQuery, StoreData, SuggestQueries,
+        UserId, WipeUserData,
+    },
+    signals::{
+        ClearSearchHistoryRequest, EmitSignal, GetRecommendedItemsRequest, ItemAccessedRequest,
+        RecommendedItemsSignal, SearchSubmittedRequest, SearchSuggestionsRequest,
+        SearchSuggestionsSignal, route_dart_signals,
+    },
+};
+
+use super::{Clock, DataManagerActor, StorageActor, TrustedClock};
+
+const ACCESS_STATS_KEY: &str = "ranking/access";
+const FLUSH_INTERVAL_SECS: u64 = 30;
+const MAX_CANDIDATES: usize = 500;
+const DEFAULT_LIMIT: usize = 20;
+const RECENCY_HALF_LIFE_SECS: f32 = 14.0 * 24.0 * 3600.0; // 2주마다 점수 절반
+const TITLE_WEIGHT: f32 = 2.0;
+const QUERY_HISTORY_PREFIX: &str = "ranking/queries/";
+const MAX_QUERY_HISTORY: usize = 200; // 사용자별 보관 검색어 수 (넘으면 오래된 것부터 지움)
+const SUGGEST_DEBOUNCE: Duration = Duration::from_millis(120);
+const DEFAULT_SUGGESTIONS: usize = 8;
+const WORD_PREFIX_WEIGHT: f32 = 0.5; // 중간 단어가 접두어와 맞으면 앞부분이 맞는 것보다 낮게 본다
+
+// 항목별 열람 통계
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct AccessStats {
+    count: u32,
+    last_access: u64,
+}
+
+// 점수 가중치 (검색어가 있으면 본문 유사도를 가장 크게 본다)
+struct ScoreWeights {
+    similarity: f32,
+    frequency: f32,
+    recency: f32,
+}
+
+const BROWSE_WEIGHTS: ScoreWeights = ScoreWeights {
+    similarity: 0.0,
+    frequency: 0.6,
+    recency: 0.4,
+};
+const QUERY_WEIGHTS: ScoreWeights = ScoreWeights {
+    similarity: 0.6,
+    frequency: 0.25,
+    recency: 0.15,
+};
+// 검색어 추천에서는 접두어 일치 여부가 곱해지고 나머지는 빈도와 최근성으로 정한다
+const SUGGEST_WEIGHTS: ScoreWeights = ScoreWeights {
+    similarity: 1.0,
+    frequency: 0.5,
+    recency: 0.5,
+};
+
+fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(str::to_lowercase)
+}
+
+// 검색어 토큰이 항목에 얼마나 포함되는지 (0.0 ~ 1.0, 제목 일치에 가중치)
+fn text_similarity(query_tokens: &HashSet<String>, item: &DataItem) -> f32 {
+    if query_tokens.is_empty() {
+        return 0.0;
+    }
+    let title: HashSet<String> = tokenize(&item.title).collect();
+    let content: HashSet<String> = tokenize(&item.content).collect();
+
+    let matched: f32 = query_tokens
+        .iter()
+        .map(|token| {
+            if title.contains(token) {
+                TITLE_WEIGHT
+            } else if content.contains(token) {
+                1.0
+            } else {
+                0.0
+            }
+        })
+        .sum();
+    matched / (query_tokens.len() as f32 * TITLE_WEIGHT)
+}
+
+// 공백을 하나로 줄이고 소문자로 바꿔 같은 검색어를 하나로 센다
+fn normalize_query(query: &str) -> String {
+    query
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+fn prefix_match(query: &str, prefix: &str) -> Option<f32> {
+    if query.starts_with(prefix) {
+        Some(1.0)
+    } else if query.split(' ').any(|word| word.starts_with(prefix)) {
+        Some(WORD_PREFIX_WEIGHT)
+    } else {
+        None
+    }
+}
+
+fn recency_score(last_touched: u64, now: u64) -> f32 {
+    let age = now.saturating_sub(last_touched) as f32;
+    0.5f32.powf(age / RECENCY_HALF_LIFE_SECS)
+}
+
+// 로그 스케일 빈도 (가장 많이 본 항목 = 1.0)
+fn frequency_score(count: u32, max_count: u32) -> f32 {
+    if max_count == 0 {
+        return 0.0;
+    }
+    (1.0 + count as f32).ln() / (1.0 + max_count as f32).ln()
+}
+
+// 추천/랭킹 액터
+// 열람 통계는 메모리에 모았다가 주기적으로 저장소에 기록한다.
+pub struct RankingActor {
+    storage: Address<StorageActor>,
+    data: Address<DataManagerActor>,
+    clock: TrustedClock,
+    stats: HashMap<String, AccessStats>,
+    dirty: bool,
+    // 사용자별 검색어 기록 (처음 쓸 때 저장소에서 읽는다)
+    query_history: HashMap<UserId, HashMap<String, AccessStats>>,
+    pending_suggestions: Option<AbortHandle>,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for RankingActor {}
+
+impl RankingActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        storage: Address<StorageActor>,
+        data: Address<DataManagerActor>,
+        clock: TrustedClock,
+    ) -> Self {
+        let mut owned_tasks = route_dart_signals!(
+            self_addr,
+            [
+                ItemAccessedRequest,
+                GetRecommendedItemsRequest,
+                SearchSubmittedRequest,
+                SearchSuggestionsRequest,
+                ClearSearchHistoryRequest
+            ]
+        );
+        owned_tasks.spawn(Self::load_stats(self_addr.clone()));
+        owned_tasks.spawn(Self::flush_periodically(self_addr.clone(), clock.clock().clone()));
+
+        Self {
+            storage,
+            data,
+            clock,
+            stats: HashMap::new(),
+            dirty: false,
+            query_history: HashMap::new(),
+            pending_suggestions: None,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    async fn load_stats(mut self_addr: Address<Self>) {
+        let _ = self_addr.notify(LoadStats).await;
+    }
+
+    async fn flush_periodically(mut self_addr: Address<Self>, clock: Clock) {
+        let mut interval = clock.interval(Duration::from_secs(FLUSH_INTERVAL_SECS));
+        loop {
+            interval.tick().await;
+            let _ = self_addr.notify(FlushStats).await;
+        }
+    }
+
+    fn history_key(user_id: &str) -> String {
+        format!("{}{}", QUERY_HISTORY_PREFIX, user_id)
+    }
+
+    async fn history_mut(&mut self, user_id: &UserId) -> &mut HashMap<String, AccessStats> {
+        if !self.query_history.contains_key(user_id) {
+            let request = FetchData {
+                key: Self::history_key(user_id),
+                user_id: Some(user_id.clone()),
+                mode: FetchMode::CacheFirst,
+            };
+            // 저장된 기록이 없거나 읽지 못하면 빈 기록에서 시작한다
+            let history = match self.storage.send(request).await {
+                Ok(Ok(bytes)) => serde_json::from_slice(&bytes).unwrap_or_else(|e| {
+                    debug_print!("Invalid stored search history: {}", e);
+                    HashMap::new()
+                }),
+                _ => HashMap::new(),
+            };
+            self.query_history.insert(user_id.clone(), history);
+        }
+        self.query_history.entry(user_id.clone()).or_default()
+    }
+
+    async fn persist_history(&mut self, user_id: &UserId) -> Result<(), RankingError> {
+        let history = self.query_history.get(user_id).cloned().unwrap_or_default();
+        let request = StoreData {
+            key: Self::history_key(user_id),
+            data: serde_json::to_vec(&history)?,
+            user_id: Some(user_id.clone()),
+            ttl: None,
+        };
+        self.storage.send(request).await??;
+        Ok(())
+    }
+
+    fn rank(&self, items: Vec<DataItem>, query: Option<&str>, limit: usize) -> Vec<RankedItem> {
+        let query_tokens: HashSet<String> =
+            query.map(|q| tokenize(q).collect()).unwrap_or_default();
+        let weights = if query_tokens.is_empty() {
+            &BROWSE_WEIGHTS
+        } else {
+            &QUERY_WEIGHTS
+        };
+        let now = self.clock.trusted_now();
+        let max_count = self.stats.values().map(|s| s.count).max().unwrap_or(0);
+
+        let mut ranked: Vec<RankedItem> = items
+            .into_iter()
+            .filter_map(|item| {
+                let similarity = text_similarity(&query_tokens, &item);
+                // 검색어가 있으면 전혀 일치하지 않는 항목은 제외
+                if !query_tokens.is_empty() && similarity == 0.0 {
+                    return None;
+                }
+                let stats = self.stats.get(&item.id).cloned().unwrap_or_default();
+                let last_touched = item.updated_at.max(stats.last_access);
+                let score = weights.similarity * similarity
+                    + weights.frequency * frequency_score(stats.count, max_count)
+                    + weights.recency * recency_score(last_touched, now);
+                Some(RankedItem { item, score })
+            })
+            .collect();
+
+        ranked.sort_by(|a, b| b.score.total_cmp(&a.score));
+        ranked.truncate(limit);
+        ranked
+    }
+}
+
+// 내부 메시지 정의
+struct LoadStats;
+struct FlushStats;
+struct SuggestionsDue(SearchSuggestionsRequest);
+
+#[async_trait]
+impl Notifiable<LoadStats> for RankingActor {
+    async fn notify(&mut self, _: LoadStats, _: &Context<Self>) {
+        let request = FetchData {
+            key: ACCESS_STATS_KEY.to_string(),
+            user_id: None,
+            mode: FetchMode::CacheFirst,
+        };
+        if let Ok(Ok(bytes)) = self.storage.send(request).await {
+            match serde_json::from_slice::<HashMap<String, AccessStats>>(&bytes) {
+                // 로드 전에 들어온 열람 기록은 유지하고 합친다
+                Ok(stored) => {
+                    for (item_id, stored) in stored {
+                        let stats = self.stats.entry(item_id).or_default();
+                        stats.count += stored.count;
+                        stats.last_access = stats.last_access.max(stored.last_access);
+                    }
+                }
+                Err(e) => debug_print!("Invalid stored access stats: {}", e),
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Notifiable<FlushStats> for RankingActor {
+    async fn notify(&mut self, _: FlushStats, _: &Context<Self>) {
+        if !self.dirty {
+            return;
+        }
+        let data = match serde_json::to_vec(&self.stats) {
+            Ok(data) => data,
+            Err(e) => {
+                debug_print!("Failed to serialize access stats: {}", e);
+                return;
+            }
+        };
+        let request = StoreData {
+            key: ACCESS_STATS_KEY.to_string(),
+            data,
+            user_id: None,
+            ttl: None,
+        };
+        match self.storage.send(request).await {
+            Ok(Ok(())) => self.dirty = false,
+            Ok(Err(e)) => debug_print!("Failed to persist access stats: {}", e),
+            Err(e) => debug_print!("Failed to persist access stats: {}", e),
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<RecordItemAccess> for RankingActor {
+    type Result = ();
+
+    async fn handle(&mut self, msg: RecordItemAccess, _: &Context<Self>) -> Self::Result {
+        let stats = self.stats.entry(msg.item_id).or_default();
+        stats.count = stats.count.saturating_add(1);
+        stats.last_access = self.clock.trusted_now();
+        self.dirty = true;
+    }
+}
+
+#[async_trait]
+impl Handler<RankItems> for RankingActor {
+    type Result = Result<Vec<RankedItem>, RankingError>;
+
+    async fn handle(&mut self, msg: RankItems, _: &Context<Self>) -> Self::Result {
+        let candidates = self
+            .data
+            .send(FetchRecentData {
+                user_id: msg.user_id,
+                limit: Some(MAX_CANDIDATES),
+                mode: FetchMode::CacheFirst,
+            })
+            .await??;
+        Ok(self.rank(candidates.items, msg.query.as_deref(), msg.limit))
+    }
+}
+
+#[async_trait]
+impl Handler<RecordSearchQuery> for RankingActor {
+    type Result = Result<(), RankingError>;
+
+    async fn handle(&mut self, msg: RecordSearchQuery, _: &Context<Self>) -> Self::Result {
+        let query = normalize_query(&msg.query);
+        if query.is_empty() {
+            return Ok(());
+        }
+        let now = self.clock.trusted_now();
+        let history = self.history_mut(&msg.user_id).await;
+        let stats = history.entry(query).or_default();
+        stats.count = stats.count.saturating_add(1);
+        stats.last_access = now;
+        if history.len() > MAX_QUERY_HISTORY {
+            let oldest = history
+                .iter()
+                .min_by_key(|(_, stats)| stats.last_access)
+                .map(|(query, _)| query.clone());
+            if let Some(oldest) = oldest {
+                history.remove(&oldest);
+            }
+        }
+        self.persist_history(&msg.user_id).await
+    }
+}
+
+#[async_trait]
+impl Handler<SuggestQueries> for RankingActor {
+    type Result = Vec<QuerySuggestion>;
+
+    async fn handle(&mut self, msg: SuggestQueries, _: &Context<Self>) -> Self::Result {
+        let prefix = normalize_query(&msg.prefix);
+        let now = self.clock.trusted_now();
+        let history = self.history_mut(&msg.user_id).await;
+        let max_count = history.values().map(|s| s.count).max().unwrap_or(0);
+        let weights = &SUGGEST_WEIGHTS;
+
+        let mut suggestions: Vec<QuerySuggestion> = history
+            .iter()
+            .filter(|(query, _)| **query != prefix)
+            .filter_map(|(query, stats)| {
+                let matched = prefix_match(query, &prefix)?;
+                let score = weights.similarity
+                    * matched
+                    * (weights.frequency * frequency_score(stats.count, max_count)
+                        + weights.recency * recency_score(stats.last_access, now));
+                Some(QuerySuggestion {
+                    query: query.clone(),
+                    score,
+                    count: stats.count,
+                })
+            })
+            .collect();
+        suggestions.sort_by(|a, b| b.score.total_cmp(&a.score));
+        suggestions.truncate(msg.limit);
+        suggestions
+    }
+}
+
+#[async_trait]
+impl Handler<WipeUserData> for RankingActor {
+    type Result = Result<(), RankingError>;
+
+    async fn handle(&mut self, _: WipeUserData, _: &Context<Self>) -> Self::Result {
+        // 다음 주기 저장에서 지운 통계가 되살아나지 않도록 변경 표시도 해제
+        self.stats.clear();
+        self.dirty = false;
+        self.query_history.clear();
+        Ok(())
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<ItemAccessedRequest> for RankingActor {
+    async fn notify(&mut self, msg: ItemAccessedRequest, ctx: &Context<Self>) {
+        self.handle(
+            RecordItemAccess {
+                item_id: msg.item_id,
+            },
+            ctx,
+        )
+        .await;
+    }
+}
+
+#[async_trait]
+impl Notifiable<GetRecommendedItemsRequest> for RankingActor {
+    async fn notify(&mut self, msg: GetRecommendedItemsRequest, ctx: &Context<Self>) {
+        let result = self
+            .handle(
+                RankItems {
+                    user_id: msg.user_id,
+                    query: msg.query,
+                    limit: msg.limit.map_or(DEFAULT_LIMIT, |limit| limit as usize),
+                },
+                ctx,
+            )
+            .await;
+
+        match result {
+            Ok(items) => {
+                RecommendedItemsSignal {
+                    request_id: msg.request_id,
+                    items,
+                    error: None,
+                }
+                .emit();
+            }
+            Err(e) => {
+                RecommendedItemsSignal {
+                    request_id: msg.request_id,
+                    items: vec![],
+                    error: Some(e.to_string()),
+                }
+                .emit();
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Notifiable<SearchSubmittedRequest> for RankingActor {
+    async fn notify(&mut self, msg: SearchSubmittedRequest, ctx: &Context<Self>) {
+        let request = RecordSearchQuery {
+            user_id: msg.user_id,
+            query: msg.query,
+        };
+        if let Err(e) = self.handle(request, ctx).await {
+            debug_print!("Failed to record search query: {}", e);
+        }
+    }
+}
+
+// 새 요청이 오면 기다리던 요청은 버리고 마지막 입력에만 답한다
+#[async_trait]
+impl Notifiable<SearchSuggestionsRequest> for RankingActor {
+    async fn notify(&mut self, msg: SearchSuggestionsRequest, ctx: &Context<Self>) {
+        if let Some(pending) = self.pending_suggestions.take() {
+            pending.abort();
+        }
+        let mut self_addr = ctx.address();
+        let clock = self.clock.clock().clone();
+        let pending = self._owned_tasks.spawn(async move {
+            clock.sleep(SUGGEST_DEBOUNCE).await;
+            let _ = self_addr.notify(SuggestionsDue(msg)).await;
+        });
+        self.pending_suggestions = Some(pending);
+    }
+}
+
+#[async_trait]
+impl Notifiable<SuggestionsDue> for RankingActor {
+    async fn notify(&mut self, msg: SuggestionsDue, ctx: &Context<Self>) {
+        self.pending_suggestions = None;
+        let request = SuggestQueries {
+            user_id: msg.0.user_id,
+            prefix: msg.0.prefix.clone(),
+            limit: msg
+                .0
+                .limit
+                .map_or(DEFAULT_SUGGESTIONS, |limit| limit as usize),
+        };
+        let suggestions = self.handle(request, ctx).await;
+        SearchSuggestionsSignal {
+            prefix: msg.0.prefix,
+            suggestions,
+        }
+        .emit();
+    }
+}
+
+#[async_trait]
+impl Notifiable<ClearSearchHistoryRequest> for RankingActor {
+    async fn notify(&mut self, msg: ClearSearchHistoryRequest, _: &Context<Self>) {
+        self.query_history
+            .insert(msg.user_id.clone(), HashMap::new());
+        let request = DeleteData {
+            key: Self::history_key(&msg.user_id),
+        };
+        match self.storage.send(request).await {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => debug_print!("Failed to clear search history: {}", e),
+            Err(e) => debug_print!("Failed to clear search history: {}", e),
+        }
+    }
+}
@@ -0,0 +1,538 @@
+use async_trait::async_trait;
+use chrono::{
+    DateTime, Datelike, Days, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeDelta,
+    TimeZone, Weekday,
+};
+use chrono_tz::Tz;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use std::collections::HashSet;
+use tokio::task::JoinSet;
+
+use crate::study_actors::{
+    messages::{ActorResult, ExpandRecurrence, Occurrence, UserError},
+    signals::{ExpandRecurrenceRequest, respond_to_dart, route_dart_signals},
+};
+
+// 한 번에 돌려주는 발생 수와 훑어보는 주기 수의 상한 (끝없는 규칙이나 너무 넓은 기간 대비)
+const MAX_OCCURRENCES: usize = 1000;
+const MAX_PERIODS: u32 = 100_000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Until {
+    Utc(i64),
+    Local(NaiveDateTime), // 날짜만 있거나 Z가 없으면 현지 시각
+}
+
+// 지원하는 규칙 부분: FREQ, INTERVAL, COUNT, UNTIL, BYDAY, BYMONTHDAY, BYMONTH, BYSETPOS, WKST
+#[derive(Debug)]
+struct RecurrenceRule {
+    freq: Frequency,
+    interval: u32,
+    count: Option<u32>,
+    until: Option<Until>,
+    by_day: Vec<(Option<i32>, Weekday)>, // (순번, 요일) 예: -1FR → (Some(-1), Fri)
+    by_month_day: Vec<i32>,
+    by_month: Vec<u32>,
+    by_set_pos: Vec<i32>,
+    week_start: Weekday,
+}
+
+impl RecurrenceRule {
+    fn parse(text: &str) -> ActorResult<Self> {
+        let text = text.trim();
+        let text = text.strip_prefix("RRULE:").unwrap_or(text);
+        let mut rule = Self {
+            freq: Frequency::Daily,
+            interval: 1,
+            count: None,
+            until: None,
+            by_day: Vec::new(),
+            by_month_day: Vec::new(),
+            by_month: Vec::new(),
+            by_set_pos: Vec::new(),
+            week_start: Weekday::Mon,
+        };
+        let mut freq = None;
+
+        for part in text.split(';').filter(|part| !part.is_empty()) {
+            let (key, value) = part
+                .split_once('=')
+                .ok_or_else(|| invalid(format!("Malformed rule part: {}", part)))?;
+            match key.to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    freq = Some(match value.to_ascii_uppercase().as_str() {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        _ => return Err(invalid(format!("Unsupported FREQ: {}", value))),
+                    })
+                }
+                "INTERVAL" => rule.interval = parse_number(key, value, 1, 10_000)?,
+                "COUNT" => rule.count = Some(parse_number(key, value, 1, u32::MAX)?),
+                "UNTIL" => rule.until = Some(parse_until(value)?),
+                "BYDAY" => {
+                    rule.by_day = value
+                        .split(',')
+                        .map(parse_by_day)
+                        .collect::<ActorResult<_>>()?
+                }
+                "BYMONTHDAY" => rule.by_month_day = parse_list(key, value, 31)?,
+                "BYMONTH" => {
+                    rule.by_month = value
+                        .split(',')
+                        .map(|month| parse_number(key, month, 1, 12))
+                        .collect::<ActorResult<_>>()?
+                }
+                "BYSETPOS" => rule.by_set_pos = parse_list(key, value, 366)?,
+                "WKST" => rule.week_start = parse_weekday(value)?,
+                _ => return Err(invalid(format!("Unsupported rule part: {}", key))),
+            }
+        }
+
+        rule.freq = freq.ok_or_else(|| invalid("FREQ is required"))?;
+        if rule.count.is_some() && rule.until.is_some() {
+            return Err(invalid("COUNT and UNTIL cannot be used together"));
+        }
+        Ok(rule)
+    }
+
+    // period번째 주기의 첫날 (일: 그날, 주: WKST, 월: 1일, 연: 1월 1일)
+    fn period_start(&self, start: NaiveDate, period: u32) -> Option<NaiveDate> {
+        let step = period.checked_mul(self.interval)?;
+        match self.freq {
+            Frequency::Daily => start.checked_add_days(Days::new(step.into())),
+            Frequency::Weekly => {
+                let week = start.checked_sub_days(Days::new(
+                    start.weekday().days_since(self.week_start).into(),
+                ))?;
+                week.checked_add_days(Days::new(u64::from(step) * 7))
+            }
+            Frequency::Monthly => {
+                let months = start.month0().checked_add(step)?;
+                let year = start.year().checked_add(i32::try_from(months / 12).ok()?)?;
+                NaiveDate::from_ymd_opt(year, months % 12 + 1, 1)
+            }
+            Frequency::Yearly => {
+                NaiveDate::from_ymd_opt(start.year().checked_add(i32::try_from(step).ok()?)?, 1, 1)
+            }
+        }
+    }
+
+    // 한 주기 안에서 규칙에 맞는 날짜 (정렬, BYSETPOS 적용)
+    fn dates_in_period(&self, start: NaiveDate, first: NaiveDate) -> Vec<NaiveDate> {
+        let mut dates = match self.freq {
+            Frequency::Daily => {
+                let in_month = self.by_month_day.is_empty()
+                    || month_days(first.year(), first.month(), &self.by_month_day).contains(&first);
+                let on_weekday = self.by_day.is_empty()
+                    || self.by_day.iter().any(|(_, day)| *day == first.weekday());
+                if in_month && on_weekday && self.in_by_month(first) {
+                    vec![first]
+                } else {
+                    Vec::new()
+                }
+            }
+            Frequency::Weekly => (0..7)
+                .filter_map(|offset| first.checked_add_days(Days::new(offset)))
+                .filter(|date| {
+                    if self.by_day.is_empty() {
+                        date.weekday() == start.weekday()
+                    } else {
+                        self.by_day.iter().any(|(_, day)| *day == date.weekday())
+                    }
+                })
+                .filter(|date| self.in_by_month(*date))
+                .collect(),
+            Frequency::Monthly if self.in_by_month(first) => {
+                self.dates_in_month(start, first.year(), first.month())
+            }
+            Frequency::Monthly => Vec::new(),
+            Frequency::Yearly => {
+                let year = first.year();
+                if !self.by_day.is_empty()
+                    && self.by_month.is_empty()
+                    && self.by_month_day.is_empty()
+                {
+                    // 연 단위 순번 (예: FREQ=YEARLY;BYDAY=20MO → 그해 스무 번째 월요일)
+                    let days: Vec<NaiveDate> = first
+                        .iter_days()
+                        .take_while(|date| date.year() == year)
+                        .collect();
+                    weekday_matches(&days, &self.by_day)
+                } else {
+                    let months = if !self.by_month.is_empty() {
+                        self.by_month.clone()
+                    } else if !self.by_month_day.is_empty() {
+                        (1..=12).collect()
+                    } else {
+                        vec![start.month()]
+                    };
+                    months
+                        .into_iter()
+                        .flat_map(|month| self.dates_in_month(start, year, month))
+                        .collect()
+                }
+            }
+        };
+        dates.sort();
+        dates.dedup();
+
+        if self.by_set_pos.is_empty() {
+            return dates;
+        }
+        let mut selected: Vec<NaiveDate> = self
+            .by_set_pos
+            .iter()
+            .filter_map(|&pos| nth(&dates, pos).copied())
+            .collect();
+        selected.sort();
+        selected.dedup();
+        selected
+    }
+
+    fn dates_in_month(&self, start: NaiveDate, year: i32, month: u32) -> Vec<NaiveDate> {
+        if !self.by_month_day.is_empty() {
+            let mut dates = month_days(year, month, &self.by_month_day);
+            if !self.by_day.is_empty() {
+                dates.retain(|date| self.by_day.iter().any(|(_, day)| *day == date.weekday()));
+            }
+            dates
+        } else if !self.by_day.is_empty() {
+            weekday_matches(&month_days(year, month, &[]), &self.by_day)
+        } else {
+            // 시작일이 없는 달은 건너뛴다 (예: 31일 시작이면 30일까지인 달)
+            NaiveDate::from_ymd_opt(year, month, start.day())
+                .into_iter()
+                .collect()
+        }
+    }
+
+    fn in_by_month(&self, date: NaiveDate) -> bool {
+        self.by_month.is_empty() || self.by_month.contains(&date.month())
+    }
+}
+
+fn invalid(message: impl Into<String>) -> UserError {
+    UserError::InvalidInput(message.into())
+}
+
+fn parse_number(key: &str, value: &str, min: u32, max: u32) -> ActorResult<u32> {
+    value
+        .trim()
+        .parse::<u32>()
+        .ok()
+        .filter(|n| (min..=max).contains(n))
+        .ok_or_else(|| invalid(format!("Invalid {}: {}", key, value)))
+}
+
+// 0이 아니고 절댓값이 max 이하인 정수 목록 (음수는 끝에서부터)
+fn parse_list(key: &str, value: &str, max: i32) -> ActorResult<Vec<i32>> {
+    value
+        .split(',')
+        .map(|item| {
+            item.trim()
+                .parse::<i32>()
+                .ok()
+                .filter(|n| *n != 0 && n.abs() <= max)
+                .ok_or_else(|| invalid(format!("Invalid {}: {}", key, item)))
+        })
+        .collect()
+}
+
+fn parse_weekday(code: &str) -> ActorResult<Weekday> {
+    match code.trim().to_ascii_uppercase().as_str() {
+        "MO" => Ok(Weekday::Mon),
+        "TU" => Ok(Weekday::Tue),
+        "WE" => Ok(Weekday::Wed),
+        "TH" => Ok(Weekday::Thu),
+        "FR" => Ok(Weekday::Fri),
+        "SA" => Ok(Weekday::Sat),
+        "SU" => Ok(Weekday::Sun),
+        _ => Err(invalid(format!("Invalid weekday: {}", code))),
+    }
+}
+
+fn parse_by_day(item: &str) -> ActorResult<(Option<i32>, Weekday)> {
+    let item = item.trim();
+    let split = item.len().saturating_sub(2);
+    if !item.is_char_boundary(split) {
+        return Err(invalid(format!("Invalid BYDAY: {}", item)));
+    }
+    let (ordinal, day) = item.split_at(split);
+    let ordinal = match ordinal {
+        "" => None,
+        ordinal => Some(
+            ordinal
+                .parse::<i32>()
+                .ok()
+                .filter(|n| *n != 0 && n.abs() <= 53)
+                .ok_or_else(|| invalid(format!("Invalid BYDAY: {}", item)))?,
+        ),
+    };
+    Ok((ordinal, parse_weekday(day)?))
+}
+
+fn parse_until(value: &str) -> ActorResult<Until> {
+    let value = value.trim();
+    let parse = |text: &str| NaiveDateTime::parse_from_str(text, "%Y%m%dT%H%M%S").ok();
+    let until = if let Some(utc) = value.strip_suffix('Z') {
+        parse(utc).map(|until| Until::Utc(until.and_utc().timestamp()))
+    } else if value.contains('T') {
+        parse(value).map(Until::Local)
+    } else {
+        // 날짜만 있으면 그날 하루 전체를 포함한다
+        NaiveDate::parse_from_str(value, "%Y%m%d")
+            .ok()
+            .and_then(|date| date.and_hms_opt(23, 59, 59))
+            .map(Until::Local)
+    };
+    until.ok_or_else(|| invalid(format!("Invalid UNTIL: {}", value)))
+}
+
+// 그 달의 날짜 (days가 비었으면 모든 날, 아니면 해당 일자만, 음수는 말일부터)
+fn month_days(year: i32, month: u32, days: &[i32]) -> Vec<NaiveDate> {
+    let Some(first) = NaiveDate::from_ymd_opt(year, month, 1) else {
+        return Vec::new();
+    };
+    let all: Vec<NaiveDate> = first
+        .iter_days()
+        .take_while(|date| date.month() == month)
+        .collect();
+    if days.is_empty() {
+        return all;
+    }
+    days.iter()
+        .filter_map(|&day| nth(&all, day).copied())
+        .collect()
+}
+
+// 범위 안에서 BYDAY에 맞는 날짜 (순번이 있으면 n번째 또는 끝에서 n번째만)
+fn weekday_matches(scope: &[NaiveDate], by_day: &[(Option<i32>, Weekday)]) -> Vec<NaiveDate> {
+    by_day
+        .iter()
+        .flat_map(|&(ordinal, weekday)| {
+            let matching: Vec<NaiveDate> = scope
+                .iter()
+                .filter(|date| date.weekday() == weekday)
+                .copied()
+                .collect();
+            match ordinal {
+                None => matching,
+                Some(n) => nth(&matching, n).copied().into_iter().collect(),
+            }
+        })
+        .collect()
+}
+
+// 1부터 세는 순번 (음수는 끝에서부터)
+fn nth<T>(items: &[T], n: i32) -> Option<&T> {
+    let index = if n > 0 {
+        usize::try_from(n - 1).ok()?
+    } else {
+        items
+            .len()
+            .checked_sub(usize::try_from(n.unsigned_abs()).ok()?)?
+    };
+    items.get(index)
+}
+
+// 현지 시각을 시간대에 맞춰 확정한다
+// 겹치는 시각(서머타임 해제)은 앞쪽, 건너뛴 시각(서머타임 시작)은 건너뛰기 전 오프셋으로 해석한다 (RFC 5545 3.3.5).
+pub(crate) fn resolve_local(tz: &Tz, local: NaiveDateTime) -> Option<DateTime<Tz>> {
+    match tz.from_local_datetime(&local) {
+        LocalResult::Single(at) => Some(at),
+        LocalResult::Ambiguous(earliest, _) => Some(earliest),
+        LocalResult::None => {
+            let before = tz
+                .offset_from_local_datetime(&(local - TimeDelta::hours(3)))
+                .earliest()?;
+            let utc = local - TimeDelta::seconds(before.fix().local_minus_utc().into());
+            Some(tz.from_utc_datetime(&utc))
+        }
+    }
+}
+
+// 이 엔진이 펼칠 수 있는 규칙인지 확인 (iCalendar 가져오기가 저장 전에 쓴다)
+pub(crate) fn validate_rrule(text: &str) -> ActorResult<()> {
+    RecurrenceRule::parse(text).map(|_| ())
+}
+
+pub fn expand_recurrence(msg: &ExpandRecurrence) -> ActorResult<Vec<Occurrence>> {
+    let rule = RecurrenceRule::parse(&msg.rrule)?;
+    let tz: Tz = msg
+        .timezone
+        .parse()
+        .map_err(|_| invalid(format!("Unknown timezone: {}", msg.timezone)))?;
+    let dtstart = tz
+        .timestamp_opt(msg.dtstart, 0)
+        .single()
+        .ok_or_else(|| invalid(format!("Invalid dtstart: {}", msg.dtstart)))?;
+    if msg.window_end <= msg.window_start {
+        return Err(invalid("window_end must be after window_start"));
+    }
+
+    let start_local = dtstart.naive_local();
+    let (start, time) = (start_local.date(), start_local.time());
+    let until = match rule.until {
+        Some(Until::Utc(until)) => until,
+        Some(Until::Local(until)) => {
+            resolve_local(&tz, until).map_or(i64::MAX, |at| at.timestamp())
+        }
+        None => i64::MAX,
+    };
+    let last = msg.window_end.min(until.saturating_add(1));
+    let last_local = tz
+        .timestamp_opt(last, 0)
+        .earliest()
+        .map(|at| at.naive_local());
+    let exdates: HashSet<i64> = msg.exdates.iter().copied().collect();
+
+    let mut occurrences = Vec::new();
+    let mut generated = 0u32;
+    for period in 0..MAX_PERIODS {
+        let Some(first) = rule.period_start(start, period) else {
+            break;
+        };
+        // 주기가 기간 끝이나 UNTIL을 넘어가면 더 볼 필요가 없다
+        if last_local.is_some_and(|last| first.and_time(NaiveTime::MIN) > last) {
+            break;
+        }
+        for date in rule.dates_in_period(start, first) {
+            let local = date.and_time(time);
+            if local < start_local {
+                continue;
+            }
+            let Some(at) = resolve_local(&tz, local) else {
+                continue;
+            };
+            let timestamp = at.timestamp();
+            if timestamp >= last {
+                return Ok(occurrences);
+            }
+            generated += 1;
+            if timestamp >= msg.window_start && !exdates.contains(&timestamp) {
+                occurrences.push(Occurrence {
+                    timestamp,
+                    local_time: at.fixed_offset().to_rfc3339(),
+                });
+                if occurrences.len() >= MAX_OCCURRENCES {
+                    return Ok(occurrences);
+                }
+            }
+            if rule.count.is_some_and(|count| generated >= count) {
+                return Ok(occurrences);
+            }
+        }
+    }
+    Ok(occurrences)
+}
+
+// 반복 일정 액터
+// 스케줄러와 달력 화면이 반복 규칙을 발생 시각 목록으로 바꿀 때 쓴다.
+pub struct RRuleActor {
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for RRuleActor {}
+
+impl RRuleActor {
+    pub fn new(self_addr: Address<Self>) -> Self {
+        Self {
+            _owned_tasks: route_dart_signals!(self_addr, [ExpandRecurrenceRequest]),
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<ExpandRecurrence> for RRuleActor {
+    type Result = ActorResult<Vec<Occurrence>>;
+
+    async fn handle(&mut self, msg: ExpandRecurrence, _: &Context<Self>) -> Self::Result {
+        expand_recurrence(&msg)
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<ExpandRecurrenceRequest> for RRuleActor {
+    async fn notify(&mut self, msg: ExpandRecurrenceRequest, ctx: &Context<Self>) {
+        let _ = respond_to_dart(msg, |msg| async move {
+            let expand = ExpandRecurrence {
+                rrule: msg.rrule,
+                dtstart: msg.dtstart,
+                timezone: msg.timezone,
+                window_start: msg.window_start,
+                window_end: msg.window_end,
+                exdates: msg.exdates,
+            };
+            Ok(self.handle(expand, ctx).await?)
+        })
+        .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use super::expand_recurrence;
+    use crate::study_actors::messages::ExpandRecurrence;
+
+    fn local_times(
+        rrule: &str,
+        dtstart: i64,
+        window_end: i64,
+    ) -> Result<Vec<String>, Box<dyn Error>> {
+        let occurrences = expand_recurrence(&ExpandRecurrence {
+            rrule: rrule.to_string(),
+            dtstart,
+            timezone: "America/New_York".to_string(),
+            window_start: dtstart,
+            window_end,
+            exdates: Vec::new(),
+        })?;
+        Ok(occurrences.into_iter().map(|o| o.local_time).collect())
+    }
+
+    #[test]
+    fn expansion_keeps_local_time_across_dst() -> Result<(), Box<dyn Error>> {
+        // 2025-03-08 09:00 EST, 다음 날 서머타임 시작
+        let dtstart = 1_741_442_400;
+        let year_later = dtstart + 366 * 86_400;
+        assert_eq!(
+            local_times("FREQ=DAILY;COUNT=3", dtstart, year_later)?,
+            [
+                "2025-03-08T09:00:00-05:00",
+                "2025-03-09T09:00:00-04:00",
+                "2025-03-10T09:00:00-04:00",
+            ]
+        );
+        // 매월 마지막 금요일
+        assert_eq!(
+            local_times("RRULE:FREQ=MONTHLY;BYDAY=-1FR;COUNT=2", dtstart, year_later)?,
+            ["2025-03-28T09:00:00-04:00", "2025-04-25T09:00:00-04:00"]
+        );
+        // 격주 화·목 (시작 주의 화·목은 시작 전이라 빠진다), UNTIL 날짜 포함
+        assert_eq!(
+            local_times(
+                "FREQ=WEEKLY;INTERVAL=2;BYDAY=TU,TH;UNTIL=20250320",
+                dtstart,
+                year_later
+            )?,
+            ["2025-03-18T09:00:00-04:00", "2025-03-20T09:00:00-04:00"]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,315 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use reqwest::Url;
+use std::collections::HashMap;
+use tokio::task::JoinSet;
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        DataItem, DeepLinkConfig, DeepLinkRoute, ExchangeAuthCode, FetchData, FetchMode,
+        Navigation, ResolveDeepLink, RouterError,
+    },
+    signals::{
+        DeepLinkRejectedSignal, DeepLinkRequest, EmitSignal, NavigateSignal, route_dart_signals,
+    },
+};
+
+use super::{AuthActor, DataManagerActor};
+
+const MAX_URI_LEN: usize = 2048;
+
+// URI를 검증된 경로로 해석 (허용된 스킴/호스트만 통과)
+// 앱 스킴은 호스트를 첫 경로 조각으로 취급한다: rinfapp://items/1 == https://host/items/1
+pub fn parse_deep_link(uri: &str, config: &DeepLinkConfig) -> Result<DeepLinkRoute, RouterError> {
+    if uri.len() > MAX_URI_LEN {
+        return Err("URI too long".into());
+    }
+    let url = Url::parse(uri.trim())?;
+
+    let mut segments: Vec<String> = Vec::new();
+    if config.schemes.iter().any(|scheme| scheme == url.scheme()) {
+        segments.extend(url.host_str().map(str::to_string));
+    } else if url.scheme() == "https" {
+        let host = url.host_str().unwrap_or_default();
+        if !config.hosts.iter().any(|allowed| allowed == host) {
+            return Err(format!("Host not allowed: {}", host).into());
+        }
+    } else {
+        return Err(format!("Scheme not allowed: {}", url.scheme()).into());
+    }
+    segments.extend(
+        url.path_segments()
+            .into_iter()
+            .flatten()
+            .filter(|segment| !segment.is_empty())
+            .map(str::to_string),
+    );
+
+    let query: HashMap<String, String> = url.query_pairs().into_owned().collect();
+    let param = |name: &str| query.get(name).filter(|v| !v.is_empty()).cloned();
+
+    let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+    match segments.as_slice() {
+        [] | ["home"] => Ok(DeepLinkRoute::Home),
+        ["auth", "callback"] => match (param("code"), param("error")) {
+            (_, Some(error)) => Ok(DeepLinkRoute::OAuthError {
+                error,
+                description: param("error_description"),
+            }),
+            (Some(code), None) => Ok(DeepLinkRoute::OAuthCallback {
+                code,
+                state: param("state"),
+            }),
+            (None, None) => Err("OAuth callback without code".into()),
+        },
+        ["items", item_id] if is_valid_id(item_id) => Ok(DeepLinkRoute::Item {
+            item_id: item_id.to_string(),
+        }),
+        ["share"] => {
+            let route = DeepLinkRoute::Share {
+                text: param("text"),
+                url: param("url"),
+                subject: param("subject"),
+            };
+            match &route {
+                DeepLinkRoute::Share {
+                    text: None,
+                    url: None,
+                    ..
+                } => Err("Share intent without content".into()),
+                _ => Ok(route),
+            }
+        }
+        _ => Err(format!("Unknown route: /{}", segments.join("/")).into()),
+    }
+}
+
+fn is_valid_id(id: &str) -> bool {
+    id.len() <= 128
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+// 라우터 액터
+// 딥 링크를 검증하고 토큰 교환, 항목 조회 같은 Rust 측 작업을 끝낸 뒤 Flutter에 이동할 화면을 알린다.
+pub struct RouterActor {
+    config: DeepLinkConfig,
+    auth: Address<AuthActor>,
+    data: Address<DataManagerActor>,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for RouterActor {}
+
+impl RouterActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        auth: Address<AuthActor>,
+        data: Address<DataManagerActor>,
+        config: DeepLinkConfig,
+    ) -> Self {
+        let owned_tasks = route_dart_signals!(self_addr, [DeepLinkRequest]);
+
+        Self {
+            config,
+            auth,
+            data,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    async fn navigate(&mut self, route: DeepLinkRoute) -> Result<Navigation, RouterError> {
+        let mut args = HashMap::new();
+        let route = match route {
+            DeepLinkRoute::Home => "/",
+            DeepLinkRoute::OAuthCallback { code, state } => {
+                let result = self.auth.send(ExchangeAuthCode { code, state }).await?;
+                match result {
+                    Ok(auth) => {
+                        args.insert("user_id".to_string(), auth.user_id);
+                        "/"
+                    }
+                    Err(e) => {
+                        args.insert("error".to_string(), e.to_string());
+                        "/login"
+                    }
+                }
+            }
+            DeepLinkRoute::OAuthError { error, description } => {
+                args.insert("error".to_string(), description.unwrap_or(error));
+                "/login"
+            }
+            DeepLinkRoute::Item { item_id } => {
+                // 미리 불러올 수 있으면 제목을 함께 넘겨 화면 전환 직후 표시하게 한다
+                let fetched = self
+                    .data
+                    .send(FetchData {
+                        key: format!("items/{}", item_id),
+                        user_id: None,
+                        mode: FetchMode::CacheFirst,
+                    })
+                    .await;
+                if let Ok(Ok(bytes)) = fetched
+                    && let Ok(item) = serde_json::from_slice::<DataItem>(&bytes)
+                {
+                    args.insert("title".to_string(), item.title);
+                }
+                args.insert("item_id".to_string(), item_id);
+                "/items/detail"
+            }
+            DeepLinkRoute::Share { text, url, subject } => {
+                let fields = [("text", text), ("url", url), ("subject", subject)];
+                for (name, value) in fields {
+                    if let Some(value) = value {
+                        args.insert(name.to_string(), value);
+                    }
+                }
+                "/share"
+            }
+        };
+
+        Ok(Navigation {
+            route: route.to_string(),
+            args,
+        })
+    }
+}
+
+#[async_trait]
+impl Handler<ResolveDeepLink> for RouterActor {
+    type Result = Result<Navigation, RouterError>;
+
+    async fn handle(&mut self, msg: ResolveDeepLink, _: &Context<Self>) -> Self::Result {
+        let route = parse_deep_link(&msg.uri, &self.config)?;
+        self.navigate(route).await
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<DeepLinkRequest> for RouterActor {
+    async fn notify(&mut self, msg: DeepLinkRequest, ctx: &Context<Self>) {
+        let result = self
+            .handle(
+                ResolveDeepLink {
+                    uri: msg.uri.clone(),
+                },
+                ctx,
+            )
+            .await;
+
+        match result {
+            Ok(navigation) => {
+                NavigateSignal {
+                    route: navigation.route,
+                    args: navigation.args,
+                }
+                .emit();
+            }
+            Err(e) => {
+                debug_print!("Rejected deep link {}: {}", msg.uri, e);
+                DeepLinkRejectedSignal {
+                    uri: msg.uri,
+                    reason: e.to_string(),
+                }
+                .emit();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DeepLinkConfig, DeepLinkRoute, MAX_URI_LEN, RouterError, parse_deep_link};
+
+    fn parse(uri: &str) -> Result<DeepLinkRoute, RouterError> {
+        let config = DeepLinkConfig {
+            hosts: vec!["links.example.com".to_string()],
+            ..DeepLinkConfig::default()
+        };
+        parse_deep_link(uri, &config)
+    }
+
+    fn item(item_id: &str) -> DeepLinkRoute {
+        DeepLinkRoute::Item {
+            item_id: item_id.to_string(),
+        }
+    }
+
+    #[test]
+    fn app_scheme_and_allowed_host_resolve_to_the_same_route() -> Result<(), RouterError> {
+        assert_eq!(parse("rinfapp://items/item-1")?, item("item-1"));
+        assert_eq!(
+            parse("https://links.example.com/items/item-1")?,
+            item("item-1")
+        );
+        // 빈 경로 조각과 앞뒤 공백은 무시한다
+        assert_eq!(parse(" rinfapp://items//item-1/ ")?, item("item-1"));
+        assert_eq!(parse("rinfapp://home")?, DeepLinkRoute::Home);
+        assert_eq!(parse("https://links.example.com")?, DeepLinkRoute::Home);
+        Ok(())
+    }
+
+    #[test]
+    fn oauth_callback_prefers_the_error_and_needs_a_code() -> Result<(), RouterError> {
+        assert_eq!(
+            parse("rinfapp://auth/callback?code=abc&state=xyz")?,
+            DeepLinkRoute::OAuthCallback {
+                code: "abc".to_string(),
+                state: Some("xyz".to_string()),
+            }
+        );
+        assert_eq!(
+            parse(
+                "rinfapp://auth/callback?code=abc&error=access_denied&error_description=No%20thanks"
+            )?,
+            DeepLinkRoute::OAuthError {
+                error: "access_denied".to_string(),
+                description: Some("No thanks".to_string()),
+            }
+        );
+        // 빈 값은 없는 것으로 본다
+        assert!(parse("rinfapp://auth/callback?code=&state=xyz").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn share_needs_text_or_url() -> Result<(), RouterError> {
+        assert_eq!(
+            parse("rinfapp://share?url=https%3A%2F%2Fexample.com%2Fa&subject=")?,
+            DeepLinkRoute::Share {
+                text: None,
+                url: Some("https://example.com/a".to_string()),
+                subject: None,
+            }
+        );
+        assert!(parse("rinfapp://share?subject=hello").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn untrusted_or_malformed_links_are_rejected() {
+        let too_long = format!("rinfapp://items/{}", "a".repeat(MAX_URI_LEN));
+        let long_id = format!("rinfapp://items/{}", "a".repeat(129));
+        for uri in [
+            "otherapp://items/item-1",
+            "http://links.example.com/items/item-1",
+            "https://evil.example.com/items/item-1",
+            "https://links.example.com.evil.com/items/item-1",
+            "rinfapp://items/item%201",
+            "rinfapp://items/..%2Fsecrets",
+            "rinfapp://items/item-1/edit",
+            "rinfapp://settings",
+            "not a uri",
+            &too_long,
+            &long_id,
+        ] {
+            assert!(parse(uri).is_err(), "{uri} should be rejected");
+        }
+    }
+}
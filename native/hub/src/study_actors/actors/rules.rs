@@ -0,0 +1,550 @@
+use async_trait::async_trait;
+use ed25519_dalek::{Signature, VerifyingKey};
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use sha2::{Digest, Sha256};
+use std::time::Duration;
+use tokio::task::JoinSet;
+use wasmtime::{Config, Engine, Instance, Module, Store, StoreLimits, StoreLimitsBuilder};
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        CheckRulesUpdate, DataItem, FetchData, FetchMode, FieldError, GetRulesModule, RulesConfig,
+        RulesError, RulesManifest, RulesModuleInfo, RulesValidation, ScheduledTask, ScoreWithRules,
+        StoreData, TrafficClass, ValidateWithRules,
+    },
+    signals::{
+        EmitSignal, EvaluateRulesRequest, RulesEvaluationSignal, RulesStatusRequest,
+        RulesStatusSignal, route_dart_signals,
+    },
+};
+
+use super::admin::parse_public_key;
+use super::network::NetworkRequest;
+use super::{Clock, NetworkManagerActor, Priority, PriorityMailbox, StorageActor, TrustedClock};
+
+const MANIFEST_KEY: &str = "rules/manifest";
+const MODULE_KEY: &str = "rules/module";
+// 규칙 모듈이 내보내야 하는 것 (가져오는 것은 하나도 허용하지 않는다)
+// alloc(len) -> ptr, validate_item(ptr, len) -> (오류 JSON ptr << 32 | len, 0이면 통과), score_item(ptr, len) -> f64
+const REQUIRED_EXPORTS: [&str; 4] = ["memory", "alloc", "validate_item", "score_item"];
+
+// 모듈 호출 환경 (호출마다 새 인스턴스를 만들어 연산량과 메모리를 제한한다)
+#[derive(Clone)]
+struct RulesRuntime {
+    engine: Engine,
+    fuel_per_call: u64,
+    max_memory_bytes: usize,
+}
+
+impl RulesRuntime {
+    fn new(config: &RulesConfig) -> Self {
+        let mut wasm_config = Config::new();
+        wasm_config.consume_fuel(true);
+        let engine = Engine::new(&wasm_config).unwrap_or_else(|e| {
+            // 연산량 계측 없는 엔진에서는 호출마다 실패하므로 모듈이 실행되지 않는다
+            debug_print!("Failed to create rules engine: {}", e);
+            Engine::default()
+        });
+        Self {
+            engine,
+            fuel_per_call: config.fuel_per_call,
+            max_memory_bytes: config.max_memory_bytes,
+        }
+    }
+
+    fn compile(&self, bytes: &[u8]) -> Result<Module, RulesError> {
+        let module = Module::new(&self.engine, bytes)?;
+        if module.imports().next().is_some() {
+            return Err("Rules module must not import host functions".into());
+        }
+        if let Some(missing) = REQUIRED_EXPORTS
+            .iter()
+            .find(|name| module.get_export(name).is_none())
+        {
+            return Err(format!("Rules module does not export {}", missing).into());
+        }
+        Ok(module)
+    }
+
+    // 항목 JSON을 모듈 메모리에 써 넣은 인스턴스와 (ptr, len)
+    fn instantiate(
+        &self,
+        module: &Module,
+        item: &DataItem,
+    ) -> Result<(Store<StoreLimits>, Instance, i32, i32), RulesError> {
+        let limits = StoreLimitsBuilder::new()
+            .memory_size(self.max_memory_bytes)
+            .instances(1)
+            .build();
+        let mut store = Store::new(&self.engine, limits);
+        store.limiter(|limits| limits);
+        store.set_fuel(self.fuel_per_call)?;
+
+        let instance = Instance::new(&mut store, module, &[])?;
+        let memory = instance
+            .get_memory(&mut store, "memory")
+            .ok_or("Rules module has no memory")?;
+        let input = serde_json::to_vec(item)?;
+        let len = i32::try_from(input.len())?;
+        let alloc = instance.get_typed_func::<i32, i32>(&mut store, "alloc")?;
+        let ptr = alloc.call(&mut store, len)?;
+        memory.write(&mut store, ptr as u32 as usize, &input)?;
+        Ok((store, instance, ptr, len))
+    }
+
+    fn validate(&self, module: &Module, item: &DataItem) -> Result<Vec<FieldError>, RulesError> {
+        let (mut store, instance, ptr, len) = self.instantiate(module, item)?;
+        let validate = instance.get_typed_func::<(i32, i32), i64>(&mut store, "validate_item")?;
+        let packed = validate.call(&mut store, (ptr, len))?;
+        if packed == 0 {
+            return Ok(Vec::new());
+        }
+
+        let memory = instance
+            .get_memory(&mut store, "memory")
+            .ok_or("Rules module has no memory")?;
+        let out_ptr = (packed >> 32) as u32 as usize;
+        let out_len = (packed & 0xffff_ffff) as u32 as usize;
+        if out_len > self.max_memory_bytes {
+            return Err("Rules module returned an invalid result".into());
+        }
+        let mut output = vec![0; out_len];
+        memory.read(&store, out_ptr, &mut output)?;
+        Ok(serde_json::from_slice(&output)?)
+    }
+
+    fn score(&self, module: &Module, item: &DataItem) -> Result<f64, RulesError> {
+        let (mut store, instance, ptr, len) = self.instantiate(module, item)?;
+        let score = instance.get_typed_func::<(i32, i32), f64>(&mut store, "score_item")?;
+        Ok(score.call(&mut store, (ptr, len))?)
+    }
+}
+
+// 체크섬과 "{version}.{sha256}" 서명 확인
+fn verify_module(
+    key: &VerifyingKey,
+    manifest: &RulesManifest,
+    bytes: &[u8],
+) -> Result<String, RulesError> {
+    let digest = hex::encode(Sha256::digest(bytes));
+    if !digest.eq_ignore_ascii_case(manifest.sha256.trim()) {
+        return Err("Rules module checksum mismatch".into());
+    }
+    let signature = Signature::from_slice(&hex::decode(manifest.signature.trim())?)?;
+    let message = format!("{}.{}", manifest.version, digest);
+    key.verify_strict(message.as_bytes(), &signature)?;
+    Ok(digest)
+}
+
+struct LoadedRules {
+    info: RulesModuleInfo,
+    module: Module,
+}
+
+// 규칙 엔진 액터 (wasm-rules 기능)
+// 서명된 wasm 규칙 모듈을 내려받아 항목 검증과 점수 계산을 맡긴다.
+// 새 버전은 앱 배포 없이 실행 중에 바꿔 끼우고, 재시작하면 저장해 둔 모듈을 다시 확인해 불러온다.
+pub struct RulesEngineActor {
+    runtime: RulesRuntime,
+    public_key: Option<VerifyingKey>,
+    manifest_url: Option<String>,
+    active: Option<LoadedRules>,
+    last_error: Option<String>,
+    storage: Address<StorageActor>,
+    network_manager: PriorityMailbox<NetworkManagerActor>,
+    clock: TrustedClock,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for RulesEngineActor {}
+
+impl RulesEngineActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        config: RulesConfig,
+        storage: Address<StorageActor>,
+        network_manager: PriorityMailbox<NetworkManagerActor>,
+        clock: TrustedClock,
+    ) -> Self {
+        let public_key = config.public_key.as_deref().and_then(|key| {
+            parse_public_key(key)
+                .map_err(|e| debug_print!("Invalid rules public key: {}", e))
+                .ok()
+        });
+
+        let mut owned_tasks =
+            route_dart_signals!(self_addr, [EvaluateRulesRequest, RulesStatusRequest]);
+        owned_tasks.spawn(Self::load_rules(self_addr.clone()));
+        if public_key.is_some() && config.manifest_url.is_some() {
+            owned_tasks.spawn(Self::check_periodically(
+                self_addr,
+                clock.clock().clone(),
+                config.check_interval_secs,
+            ));
+        }
+
+        Self {
+            runtime: RulesRuntime::new(&config),
+            public_key,
+            manifest_url: config.manifest_url,
+            active: None,
+            last_error: None,
+            storage,
+            network_manager,
+            clock,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    async fn load_rules(mut self_addr: Address<Self>) {
+        let _ = self_addr.notify(LoadRules).await;
+    }
+
+    async fn check_periodically(mut self_addr: Address<Self>, clock: Clock, interval_secs: u64) {
+        let mut interval = clock
+            .interval(Duration::from_secs(interval_secs.max(60)))
+            .throttled(ScheduledTask::RulesUpdate);
+        loop {
+            interval.tick().await;
+            match self_addr.send(CheckRulesUpdate).await {
+                Ok(Ok(Some(version))) => debug_print!("Rules module updated to {}", version),
+                Ok(Ok(None)) => {}
+                Ok(Err(e)) => debug_print!("Rules update check failed: {}", e),
+                Err(_) => break,
+            }
+        }
+    }
+
+    async fn fetch(&mut self, request: NetworkRequest) -> Result<Vec<u8>, RulesError> {
+        let response = self
+            .network_manager
+            .request(Priority::Low, request)
+            .await??;
+        if !response.is_success() {
+            return Err(format!("Rules download failed: {}", response.status).into());
+        }
+        Ok(response.body)
+    }
+
+    // 서명을 확인하고 컴파일한 모듈 (컴파일은 블로킹 스레드에서)
+    async fn prepare(
+        &self,
+        manifest: &RulesManifest,
+        bytes: Vec<u8>,
+    ) -> Result<LoadedRules, RulesError> {
+        let key = self
+            .public_key
+            .as_ref()
+            .ok_or("Rules public key not configured")?;
+        let sha256 = verify_module(key, manifest, &bytes)?;
+        let runtime = self.runtime.clone();
+        let module = tokio::task::spawn_blocking(move || runtime.compile(&bytes)).await??;
+        Ok(LoadedRules {
+            info: RulesModuleInfo {
+                version: manifest.version,
+                sha256,
+                loaded_at: self.clock.trusted_now(),
+            },
+            module,
+        })
+    }
+
+    async fn check_update(&mut self) -> Result<Option<u64>, RulesError> {
+        let url = self
+            .manifest_url
+            .clone()
+            .ok_or("Rules manifest URL not configured")?;
+        let request = NetworkRequest::new(url).traffic(TrafficClass::Sync);
+        let manifest: RulesManifest = serde_json::from_slice(&self.fetch(request).await?)?;
+        if self
+            .active
+            .as_ref()
+            .is_some_and(|active| active.info.version >= manifest.version)
+        {
+            return Ok(None);
+        }
+
+        let request = NetworkRequest::new(manifest.url.clone()).traffic(TrafficClass::Downloads);
+        let bytes = self.fetch(request).await?;
+        let loaded = self.prepare(&manifest, bytes.clone()).await?;
+
+        // 저장한 뒤에 바꿔야 재시작 후에도 같은 버전을 쓴다
+        self.store(MODULE_KEY, bytes).await?;
+        self.store(MANIFEST_KEY, serde_json::to_vec(&manifest)?)
+            .await?;
+        let version = loaded.info.version;
+        self.active = Some(loaded);
+        Ok(Some(version))
+    }
+
+    async fn store(&mut self, key: &str, data: Vec<u8>) -> Result<(), RulesError> {
+        let request = StoreData {
+            key: key.to_string(),
+            data,
+            user_id: None,
+            ttl: None,
+        };
+        self.storage.send(request).await??;
+        Ok(())
+    }
+
+    async fn load_stored(&mut self) -> Result<Option<LoadedRules>, RulesError> {
+        let request = FetchData {
+            key: MANIFEST_KEY.to_string(),
+            user_id: None,
+            mode: FetchMode::CacheFirst,
+        };
+        // 저장된 모듈이 없으면 규칙 없이 시작
+        let Ok(manifest) = self.storage.send(request).await? else {
+            return Ok(None);
+        };
+        let manifest: RulesManifest = serde_json::from_slice(&manifest)?;
+        let request = FetchData {
+            key: MODULE_KEY.to_string(),
+            user_id: None,
+            mode: FetchMode::CacheFirst,
+        };
+        let bytes = self.storage.send(request).await??;
+        Ok(Some(self.prepare(&manifest, bytes.to_vec()).await?))
+    }
+
+    fn module(&self) -> Option<(u64, Module)> {
+        self.active
+            .as_ref()
+            .map(|active| (active.info.version, active.module.clone()))
+    }
+
+    fn send_status(&self) {
+        RulesStatusSignal {
+            module: self.active.as_ref().map(|active| active.info.clone()),
+            last_error: self.last_error.clone(),
+        }
+        .emit();
+    }
+}
+
+// 내부 메시지 정의
+struct LoadRules;
+
+#[async_trait]
+impl Notifiable<LoadRules> for RulesEngineActor {
+    async fn notify(&mut self, _: LoadRules, _: &Context<Self>) {
+        match self.load_stored().await {
+            // 불러오기 전에 내려받은 더 새 버전이 있으면 그대로 둔다
+            Ok(Some(loaded))
+                if self
+                    .active
+                    .as_ref()
+                    .is_none_or(|active| active.info.version < loaded.info.version) =>
+            {
+                self.active = Some(loaded);
+            }
+            Ok(_) => return,
+            Err(e) => {
+                debug_print!("Failed to load stored rules module: {}", e);
+                self.last_error = Some(e.to_string());
+            }
+        }
+        self.send_status();
+    }
+}
+
+#[async_trait]
+impl Handler<CheckRulesUpdate> for RulesEngineActor {
+    type Result = Result<Option<u64>, RulesError>;
+
+    async fn handle(&mut self, _: CheckRulesUpdate, _: &Context<Self>) -> Self::Result {
+        let result = self.check_update().await;
+        match &result {
+            Ok(None) => return result,
+            Ok(Some(_)) => self.last_error = None,
+            Err(e) => self.last_error = Some(e.to_string()),
+        }
+        self.send_status();
+        result
+    }
+}
+
+#[async_trait]
+impl Handler<ValidateWithRules> for RulesEngineActor {
+    type Result = Result<RulesValidation, RulesError>;
+
+    async fn handle(&mut self, msg: ValidateWithRules, _: &Context<Self>) -> Self::Result {
+        let Some((version, module)) = self.module() else {
+            return Ok(RulesValidation {
+                version: None,
+                errors: Vec::new(),
+            });
+        };
+        let runtime = self.runtime.clone();
+        let errors =
+            tokio::task::spawn_blocking(move || runtime.validate(&module, &msg.item)).await??;
+        Ok(RulesValidation {
+            version: Some(version),
+            errors,
+        })
+    }
+}
+
+#[async_trait]
+impl Handler<ScoreWithRules> for RulesEngineActor {
+    type Result = Result<Option<f64>, RulesError>;
+
+    async fn handle(&mut self, msg: ScoreWithRules, _: &Context<Self>) -> Self::Result {
+        let Some((_, module)) = self.module() else {
+            return Ok(None);
+        };
+        let runtime = self.runtime.clone();
+        let score =
+            tokio::task::spawn_blocking(move || runtime.score(&module, &msg.item)).await??;
+        Ok(Some(score))
+    }
+}
+
+#[async_trait]
+impl Handler<GetRulesModule> for RulesEngineActor {
+    type Result = Option<RulesModuleInfo>;
+
+    async fn handle(&mut self, _: GetRulesModule, _: &Context<Self>) -> Self::Result {
+        self.active.as_ref().map(|active| active.info.clone())
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<EvaluateRulesRequest> for RulesEngineActor {
+    async fn notify(&mut self, msg: EvaluateRulesRequest, ctx: &Context<Self>) {
+        let item_id = msg.item.id.clone();
+        let validation = self
+            .handle(
+                ValidateWithRules {
+                    item: msg.item.clone(),
+                },
+                ctx,
+            )
+            .await;
+        let score = self.handle(ScoreWithRules { item: msg.item }, ctx).await;
+        let signal = match (validation, score) {
+            (Ok(validation), Ok(score)) => RulesEvaluationSignal {
+                item_id,
+                version: validation.version,
+                errors: validation.errors,
+                score,
+                error: None,
+            },
+            (Err(e), _) | (_, Err(e)) => RulesEvaluationSignal {
+                item_id,
+                version: self.active.as_ref().map(|active| active.info.version),
+                errors: Vec::new(),
+                score: None,
+                error: Some(e.to_string()),
+            },
+        };
+        signal.emit();
+    }
+}
+
+#[async_trait]
+impl Notifiable<RulesStatusRequest> for RulesEngineActor {
+    async fn notify(&mut self, _: RulesStatusRequest, _: &Context<Self>) {
+        self.send_status();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    const RULES_WAT: &str = r#"
+        (module
+          (memory (export "memory") 8)
+          (global $next (mut i32) (i32.const 1024))
+          (data (i32.const 16) "[{\"field\":\"title\",\"code\":\"required\",\"message\":\"x\"}]")
+          (func (export "alloc") (param $len i32) (result i32)
+            (local $ptr i32)
+            (local.set $ptr (global.get $next))
+            (global.set $next (i32.add (global.get $next) (local.get $len)))
+            (local.get $ptr))
+          (func (export "validate_item") (param $ptr i32) (param $len i32) (result i64)
+            (i64.or (i64.shl (i64.const 16) (i64.const 32)) (i64.const 51)))
+          (func (export "score_item") (param $ptr i32) (param $len i32) (result f64)
+            (loop $spin (br_if $spin (i32.gt_u (local.get $len) (i32.const 100000))))
+            (f64.convert_i32_u (local.get $len))))
+    "#;
+
+    fn item(content: &str) -> DataItem {
+        DataItem {
+            id: "1".to_string(),
+            title: String::new(),
+            content: content.to_string(),
+            created_at: 0,
+            updated_at: 0,
+            tags: Vec::new(),
+            attachments: Vec::new(),
+            owner: String::new(),
+        }
+    }
+
+    fn runtime(fuel_per_call: u64) -> RulesRuntime {
+        RulesRuntime::new(&RulesConfig {
+            fuel_per_call,
+            ..RulesConfig::default()
+        })
+    }
+
+    #[test]
+    fn modules_run_sandboxed_within_fuel() -> Result<(), RulesError> {
+        let runtime = runtime(100_000);
+        let module = runtime.compile(RULES_WAT.as_bytes())?;
+
+        let errors = runtime.validate(&module, &item(""))?;
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "title");
+
+        let small = item("");
+        let expected = serde_json::to_vec(&small)?.len() as f64;
+        assert_eq!(runtime.score(&module, &small)?, expected);
+
+        // 끝나지 않는 호출은 연산량을 다 쓰면 멈춘다
+        let huge = item(&"x".repeat(200_000));
+        assert!(runtime.score(&module, &huge).is_err());
+
+        let importing = r#"(module (import "env" "f" (func)))"#;
+        assert!(runtime.compile(importing.as_bytes()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn modules_need_a_signature_over_version_and_checksum() -> Result<(), RulesError> {
+        let key = SigningKey::from_bytes(&[3; 32]);
+        let bytes = RULES_WAT.as_bytes();
+        let sha256 = hex::encode(Sha256::digest(bytes));
+        let sign = |version: u64| {
+            hex::encode(
+                key.sign(format!("{}.{}", version, sha256).as_bytes())
+                    .to_bytes(),
+            )
+        };
+        let manifest = RulesManifest {
+            version: 2,
+            url: "https://rules.example.com/2.wasm".to_string(),
+            sha256: sha256.clone(),
+            signature: sign(2),
+        };
+
+        assert!(verify_module(&key.verifying_key(), &manifest, bytes).is_ok());
+        assert!(verify_module(&key.verifying_key(), &manifest, b"(module)").is_err());
+        // 옛 버전의 서명으로 버전만 올릴 수 없다
+        let replayed = RulesManifest {
+            version: 3,
+            ..manifest
+        };
+        assert!(verify_module(&key.verifying_key(), &replayed, bytes).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,868 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use std::{collections::HashMap, sync::OnceLock, time::Duration};
+use tokio::{
+    sync::{broadcast, watch},
+    task::{AbortHandle, JoinSet},
+};
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        AppEvent, CancelReminder, DataItem, ExpandRecurrence, FetchData, FetchMode, PersistState,
+        PersistedState, Reminder, ReminderError, RestoreState, ScheduledTask, SetReminder,
+        SetSubsystemSuspended, SnoozeReminder, StoreData, SuspendReason, ThrottlePolicy, Translate,
+        WipeUserData,
+    },
+    signals::{
+        BatteryStateChanged, CancelReminderRequest, EmitSignal, ReminderChangedSignal,
+        ReminderDueSignal, SetReminderRequest, SetThrottlePolicyRequest, SnoozeReminderRequest,
+        TaskThrottle, ThrottleStatusSignal, route_dart_signals,
+    },
+};
+
+use super::{DataManagerActor, I18nActor, StorageActor, TrustedClock, expand_recurrence, i18n};
+
+// 충전 중이 아닐 때 이 아래로 떨어지면 저전력 모드가 아니어도 절전한다
+const CRITICAL_BATTERY_LEVEL: f64 = 0.1;
+// 종료 시 남기는 작업별 정책 기록의 구조 버전
+const STATE_VERSION: u32 = 1;
+const REMINDERS_KEY: &str = "scheduler/reminders";
+// 시계가 NTP로 보정되어도 크게 어긋나지 않도록 길게 자지 않고 이 간격마다 다시 확인한다
+const REMINDER_RECHECK: Duration = Duration::from_secs(60);
+// 예정 시각보다 이만큼 넘게 늦게 보내면 놓친 알림으로 표시한다 (앱이 닫혀 있던 경우 등)
+const MISSED_AFTER_SECS: u64 = 120;
+// 다음 반복을 이 기간 안에서 찾는다 (이보다 드문 반복은 끝난 것으로 본다)
+const REPEAT_LOOKAHEAD_SECS: i64 = 400 * 24 * 60 * 60;
+
+type ThrottleMap = HashMap<ScheduledTask, ThrottlePolicy>;
+
+// 지금 적용 중인 작업별 조절 (절전이 아니면 비어 있다)
+// 주기 작업은 여러 액터와 프랙털 스트림에 흩어져 있으므로 전역 채널로 나눠 본다.
+static THROTTLE: OnceLock<watch::Sender<ThrottleMap>> = OnceLock::new();
+
+fn throttle_sender() -> &'static watch::Sender<ThrottleMap> {
+    THROTTLE.get_or_init(|| watch::channel(HashMap::new()).0)
+}
+
+// 주기 작업 쪽에서 자기 작업의 조절 상태를 확인할 때 사용 (스케줄러가 없으면 항상 Normal)
+pub struct Throttle {
+    task: ScheduledTask,
+    updates: watch::Receiver<ThrottleMap>,
+}
+
+impl Throttle {
+    pub fn new(task: ScheduledTask) -> Self {
+        Self {
+            task,
+            updates: throttle_sender().subscribe(),
+        }
+    }
+
+    pub fn policy(&self) -> ThrottlePolicy {
+        self.updates
+            .borrow()
+            .get(&self.task)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    // 멈춘 동안은 기다렸다가, 다음 주기에 곱할 배수를 돌려준다
+    pub async fn wait(&mut self) -> u32 {
+        loop {
+            match self.policy() {
+                ThrottlePolicy::Normal => return 1,
+                ThrottlePolicy::Stretch(factor) => return factor.max(1),
+                ThrottlePolicy::Pause => {
+                    if self.updates.changed().await.is_err() {
+                        return 1;
+                    }
+                }
+            }
+        }
+    }
+}
+
+// after 이후의 다음 반복 발생 시각
+fn next_occurrence(reminder: &Reminder, after: u64) -> Result<Option<u64>, ReminderError> {
+    let Some(rrule) = &reminder.repeat else {
+        return Ok(None);
+    };
+    let request = ExpandRecurrence {
+        rrule: rrule.clone(),
+        dtstart: reminder.at as i64,
+        timezone: reminder.timezone.clone(),
+        window_start: after as i64 + 1,
+        window_end: after as i64 + 1 + REPEAT_LOOKAHEAD_SECS,
+        exdates: Vec::new(),
+    };
+    let occurrences = expand_recurrence(&request)?;
+    Ok(occurrences.first().map(|o| o.timestamp as u64))
+}
+
+// 배터리 상태에 따라 주기 작업을 늘리거나 멈추는 스케줄러 액터
+// 절전 중에는 작업별 정책대로 주기를 늘리거나 멈추고, 동기화는 데이터 액터에 미루도록 알린다.
+// 항목 알림도 맡아 저장소에 보관하고, 신뢰 시각(NTP 보정) 기준으로 때가 되면 Dart에 알린다.
+pub struct SchedulerActor {
+    policies: ThrottleMap,
+    battery: BatteryStateChanged,
+    low_power: bool,
+    sync_suspended: bool,
+    data: Address<DataManagerActor>,
+    storage: Address<StorageActor>,
+    clock: TrustedClock,
+    reminders: HashMap<String, Reminder>, // 항목 ID별
+    reminder_timer: Option<AbortHandle>,
+    i18n: Option<Address<I18nActor>>, // 없으면 알림 문구를 영어로 보낸다
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for SchedulerActor {}
+
+impl SchedulerActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        data: Address<DataManagerActor>,
+        storage: Address<StorageActor>,
+        events: broadcast::Receiver<AppEvent>,
+        clock: TrustedClock,
+    ) -> Self {
+        let mut owned_tasks = route_dart_signals!(
+            self_addr,
+            [
+                BatteryStateChanged,
+                SetThrottlePolicyRequest,
+                SetReminderRequest,
+                CancelReminderRequest,
+                SnoozeReminderRequest
+            ]
+        );
+        owned_tasks.spawn(Self::load_reminders(self_addr.clone()));
+        owned_tasks.spawn(Self::listen_to_events(self_addr, events));
+
+        Self {
+            policies: ScheduledTask::ALL
+                .into_iter()
+                .map(|task| (task, task.default_policy()))
+                .collect(),
+            // 배터리 상태를 받기 전에는 충전 중인 것으로 본다
+            battery: BatteryStateChanged {
+                level: 1.0,
+                is_charging: true,
+                low_power_mode: false,
+            },
+            low_power: false,
+            sync_suspended: false,
+            data,
+            storage,
+            clock,
+            reminders: HashMap::new(),
+            reminder_timer: None,
+            i18n: None,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    // 알림 문구를 사용자 로케일로 만든다
+    pub fn set_i18n(&mut self, i18n: Address<I18nActor>) {
+        self.i18n = Some(i18n);
+    }
+
+    async fn load_reminders(mut self_addr: Address<Self>) {
+        let _ = self_addr.notify(LoadReminders).await;
+    }
+
+    async fn listen_to_events(
+        mut self_addr: Address<Self>,
+        mut events: broadcast::Receiver<AppEvent>,
+    ) {
+        loop {
+            match events.recv().await {
+                Ok(AppEvent::DataItemDeleted { item_id, .. }) => {
+                    let _ = self_addr.notify(ItemDeleted(item_id)).await;
+                }
+                Ok(_) => {}
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    debug_print!("Scheduler skipped {} app events", skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    }
+
+    async fn persist_reminders(&mut self) -> Result<(), ReminderError> {
+        let request = StoreData {
+            key: REMINDERS_KEY.to_string(),
+            data: serde_json::to_vec(&self.reminders)?,
+            user_id: None,
+            ttl: None,
+        };
+        self.storage.send(request).await??;
+        Ok(())
+    }
+
+    // 가장 이른 알림 시각에 맞춰 타이머를 다시 건다
+    fn arm_reminders(&mut self, mut self_addr: Address<Self>) {
+        if let Some(timer) = self.reminder_timer.take() {
+            timer.abort();
+        }
+        let Some(due) = self.reminders.values().filter_map(Reminder::due_at).min() else {
+            return;
+        };
+        let wait_ms = (due as i64 * 1000 - self.clock.now_millis()).max(0) as u64;
+        let wait = Duration::from_millis(wait_ms).min(REMINDER_RECHECK);
+        let clock = self.clock.clock().clone();
+        let timer = self._owned_tasks.spawn(async move {
+            clock.sleep(wait).await;
+            let _ = self_addr.notify(RemindersDue).await;
+        });
+        self.reminder_timer = Some(timer);
+    }
+
+    // 알림에 보여 줄 문구 (번들에 없으면 영어 기본 문구)
+    async fn reminder_message(&mut self, title: Option<&str>, missed: bool) -> String {
+        let (key, fallback) = match (title.is_some(), missed) {
+            (true, false) => ("reminder.due", "Reminder: {title}"),
+            (true, true) => ("reminder.missed", "Missed reminder: {title}"),
+            (false, false) => ("reminder.due_untitled", "Reminder"),
+            (false, true) => ("reminder.missed_untitled", "Missed reminder"),
+        };
+        let mut args = HashMap::new();
+        if let Some(title) = title {
+            args.insert("title".to_string(), title.to_string());
+        }
+        let request = Translate {
+            key: key.to_string(),
+            args,
+            locale: None,
+            fallback: Some(fallback.to_string()),
+        };
+        i18n::translate(self.i18n.as_mut(), request).await
+    }
+
+    async fn item_title(&mut self, item_id: &str) -> Option<String> {
+        let request = FetchData {
+            key: format!("items/{}", item_id),
+            user_id: None,
+            mode: FetchMode::CacheFirst,
+        };
+        let bytes = self.data.send(request).await.ok()?.ok()?;
+        serde_json::from_slice::<DataItem>(&bytes)
+            .ok()
+            .map(|item| item.title)
+    }
+
+    // 때가 된 알림을 보내고 반복 알림은 다음 발생 시각으로 넘긴다
+    // 앱이 닫혀 있는 동안 여러 번 지났어도 한 번만 보내고 지금 이후의 발생으로 건너뛴다.
+    async fn fire_due_reminders(&mut self) {
+        let now = self.clock.trusted_now();
+        let mut fired = Vec::new();
+        for reminder in self.reminders.values_mut() {
+            let Some(due) = reminder.due_at().filter(|due| *due <= now) else {
+                continue;
+            };
+            if reminder.snoozed_until.is_some_and(|at| at <= now) {
+                reminder.snoozed_until = None;
+            }
+            if reminder.next_at.is_some_and(|at| at <= now) {
+                reminder.next_at = next_occurrence(reminder, now).unwrap_or_else(|e| {
+                    debug_print!("Invalid repeat for reminder {}: {}", reminder.item_id, e);
+                    None
+                });
+            }
+            fired.push((reminder.item_id.clone(), due, reminder.next_at));
+        }
+        if fired.is_empty() {
+            return;
+        }
+        self.reminders
+            .retain(|_, reminder| reminder.due_at().is_some());
+        if let Err(e) = self.persist_reminders().await {
+            debug_print!("Failed to save reminders: {}", e);
+        }
+
+        for (item_id, scheduled_at, next_at) in fired {
+            let title = self.item_title(&item_id).await;
+            let missed = now - scheduled_at > MISSED_AFTER_SECS;
+            ReminderDueSignal {
+                message: self.reminder_message(title.as_deref(), missed).await,
+                title,
+                item_id,
+                scheduled_at,
+                missed,
+                next_at,
+            }
+            .emit();
+        }
+    }
+
+    async fn set_reminder(&mut self, msg: SetReminder) -> Result<Reminder, ReminderError> {
+        let now = self.clock.trusted_now();
+        let mut reminder = Reminder {
+            item_id: msg.item_id,
+            at: msg.at,
+            repeat: msg.repeat.filter(|rule| !rule.trim().is_empty()),
+            timezone: msg.timezone.unwrap_or_else(|| "UTC".to_string()),
+            next_at: None,
+            snoozed_until: None,
+        };
+        // 반복 규칙은 첫 시각이 미래여도 여기서 검사한다
+        let next = next_occurrence(&reminder, now)?;
+        reminder.next_at = if reminder.at > now {
+            Some(reminder.at)
+        } else {
+            next
+        };
+        if reminder.next_at.is_none() {
+            return Err("Reminder time is in the past".into());
+        }
+        self.reminders
+            .insert(reminder.item_id.clone(), reminder.clone());
+        self.persist_reminders().await?;
+        Ok(reminder)
+    }
+
+    async fn snooze_reminder(&mut self, msg: SnoozeReminder) -> Result<Reminder, ReminderError> {
+        let until = self.clock.trusted_now() + u64::from(msg.minutes.max(1)) * 60;
+        let reminder = self
+            .reminders
+            .get_mut(&msg.item_id)
+            .ok_or_else(|| format!("No reminder for item {}", msg.item_id))?;
+        reminder.snoozed_until = Some(until);
+        let reminder = reminder.clone();
+        self.persist_reminders().await?;
+        Ok(reminder)
+    }
+
+    async fn cancel_reminder(&mut self, item_id: &str) -> bool {
+        if self.reminders.remove(item_id).is_none() {
+            return false;
+        }
+        if let Err(e) = self.persist_reminders().await {
+            debug_print!("Failed to save reminders: {}", e);
+        }
+        true
+    }
+
+    fn send_changed(item_id: String, result: Result<Option<Reminder>, ReminderError>) {
+        let (reminder, error) = match result {
+            Ok(reminder) => (reminder, None),
+            Err(e) => (None, Some(e.to_string())),
+        };
+        ReminderChangedSignal {
+            item_id,
+            reminder,
+            error,
+        }
+        .emit();
+    }
+
+    async fn apply(&mut self) {
+        let throttled: ThrottleMap = if self.low_power {
+            self.policies
+                .iter()
+                .filter(|(_, policy)| **policy != ThrottlePolicy::Normal)
+                .map(|(task, policy)| (*task, *policy))
+                .collect()
+        } else {
+            HashMap::new()
+        };
+
+        // 동기화는 주기 작업이 아니라 요청마다 돌므로 늘릴 수 없어 멈춤으로 처리한다
+        let suspend_sync = throttled.contains_key(&ScheduledTask::Sync);
+        if suspend_sync != self.sync_suspended {
+            let msg = SetSubsystemSuspended {
+                suspended: suspend_sync,
+                reason: SuspendReason::LowPower,
+            };
+            match self.data.notify(msg).await {
+                Ok(()) => self.sync_suspended = suspend_sync,
+                Err(e) => debug_print!("Failed to update sync for low power: {}", e),
+            }
+        }
+
+        ThrottleStatusSignal {
+            level: self.battery.level,
+            is_charging: self.battery.is_charging,
+            low_power: self.low_power,
+            policies: Self::sorted(&self.policies),
+            throttled: Self::sorted(&throttled),
+        }
+        .emit();
+        throttle_sender().send_replace(throttled);
+    }
+
+    fn sorted(policies: &ThrottleMap) -> Vec<TaskThrottle> {
+        ScheduledTask::ALL
+            .into_iter()
+            .filter_map(|task| {
+                let policy = *policies.get(&task)?;
+                Some(TaskThrottle { task, policy })
+            })
+            .collect()
+    }
+}
+
+// 내부 메시지 정의
+struct LoadReminders;
+struct RemindersDue;
+struct ItemDeleted(String);
+
+#[async_trait]
+impl Notifiable<LoadReminders> for SchedulerActor {
+    async fn notify(&mut self, _: LoadReminders, ctx: &Context<Self>) {
+        let request = FetchData {
+            key: REMINDERS_KEY.to_string(),
+            user_id: None,
+            mode: FetchMode::CacheFirst,
+        };
+        match self.storage.send(request).await {
+            Ok(Ok(bytes)) => match serde_json::from_slice::<HashMap<String, Reminder>>(&bytes) {
+                // 불러오기 전에 설정한 알림이 우선
+                Ok(stored) => {
+                    for (item_id, reminder) in stored {
+                        self.reminders.entry(item_id).or_insert(reminder);
+                    }
+                }
+                Err(e) => debug_print!("Invalid stored reminders: {}", e),
+            },
+            // 저장된 알림이 없으면 빈 목록
+            Ok(Err(_)) => {}
+            Err(e) => debug_print!("Failed to load reminders: {}", e),
+        }
+        // 닫혀 있는 동안 지난 알림은 바로 보낸다
+        self.fire_due_reminders().await;
+        self.arm_reminders(ctx.address());
+    }
+}
+
+#[async_trait]
+impl Notifiable<RemindersDue> for SchedulerActor {
+    async fn notify(&mut self, _: RemindersDue, ctx: &Context<Self>) {
+        self.fire_due_reminders().await;
+        self.arm_reminders(ctx.address());
+    }
+}
+
+#[async_trait]
+impl Notifiable<ItemDeleted> for SchedulerActor {
+    async fn notify(&mut self, msg: ItemDeleted, ctx: &Context<Self>) {
+        if self.cancel_reminder(&msg.0).await {
+            self.arm_reminders(ctx.address());
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<SetReminder> for SchedulerActor {
+    type Result = Result<Reminder, ReminderError>;
+
+    async fn handle(&mut self, msg: SetReminder, ctx: &Context<Self>) -> Self::Result {
+        let reminder = self.set_reminder(msg).await?;
+        self.arm_reminders(ctx.address());
+        Ok(reminder)
+    }
+}
+
+#[async_trait]
+impl Handler<SnoozeReminder> for SchedulerActor {
+    type Result = Result<Reminder, ReminderError>;
+
+    async fn handle(&mut self, msg: SnoozeReminder, ctx: &Context<Self>) -> Self::Result {
+        let reminder = self.snooze_reminder(msg).await?;
+        self.arm_reminders(ctx.address());
+        Ok(reminder)
+    }
+}
+
+#[async_trait]
+impl Handler<CancelReminder> for SchedulerActor {
+    type Result = bool;
+
+    async fn handle(&mut self, msg: CancelReminder, ctx: &Context<Self>) -> Self::Result {
+        let cancelled = self.cancel_reminder(&msg.item_id).await;
+        self.arm_reminders(ctx.address());
+        cancelled
+    }
+}
+
+#[async_trait]
+impl Handler<WipeUserData> for SchedulerActor {
+    type Result = Result<(), ReminderError>;
+
+    async fn handle(&mut self, _: WipeUserData, _: &Context<Self>) -> Self::Result {
+        // 저장된 알림은 저장소 삭제 단계에서 함께 지워진다
+        self.reminders.clear();
+        if let Some(timer) = self.reminder_timer.take() {
+            timer.abort();
+        }
+        Ok(())
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<BatteryStateChanged> for SchedulerActor {
+    async fn notify(&mut self, msg: BatteryStateChanged, _: &Context<Self>) {
+        let low_power =
+            msg.low_power_mode || (!msg.is_charging && msg.level <= CRITICAL_BATTERY_LEVEL);
+        self.battery = msg;
+        if low_power != self.low_power {
+            debug_print!("Low power throttling: {}", low_power);
+            self.low_power = low_power;
+        }
+        self.apply().await;
+    }
+}
+
+#[async_trait]
+impl Notifiable<SetThrottlePolicyRequest> for SchedulerActor {
+    async fn notify(&mut self, msg: SetThrottlePolicyRequest, _: &Context<Self>) {
+        self.policies.insert(msg.task, msg.policy);
+        self.apply().await;
+    }
+}
+
+#[async_trait]
+impl Notifiable<SetReminderRequest> for SchedulerActor {
+    async fn notify(&mut self, msg: SetReminderRequest, ctx: &Context<Self>) {
+        let item_id = msg.item_id.clone();
+        let request = SetReminder {
+            item_id: msg.item_id,
+            at: msg.at,
+            repeat: msg.repeat,
+            timezone: msg.timezone,
+        };
+        let result = self.handle(request, ctx).await.map(Some);
+        Self::send_changed(item_id, result);
+    }
+}
+
+#[async_trait]
+impl Notifiable<SnoozeReminderRequest> for SchedulerActor {
+    async fn notify(&mut self, msg: SnoozeReminderRequest, ctx: &Context<Self>) {
+        let item_id = msg.item_id.clone();
+        let request = SnoozeReminder {
+            item_id: msg.item_id,
+            minutes: msg.minutes,
+        };
+        let result = self.handle(request, ctx).await.map(Some);
+        Self::send_changed(item_id, result);
+    }
+}
+
+#[async_trait]
+impl Notifiable<CancelReminderRequest> for SchedulerActor {
+    async fn notify(&mut self, msg: CancelReminderRequest, ctx: &Context<Self>) {
+        let request = CancelReminder {
+            item_id: msg.item_id.clone(),
+        };
+        self.handle(request, ctx).await;
+        Self::send_changed(msg.item_id, Ok(None));
+    }
+}
+
+// Dart가 바꾼 작업별 정책은 다음 실행에도 유지한다 (배터리 상태는 다시 받는다)
+#[async_trait]
+impl Handler<PersistState> for SchedulerActor {
+    type Result = Option<PersistedState>;
+
+    async fn handle(&mut self, _: PersistState, _: &Context<Self>) -> Self::Result {
+        PersistedState::new(STATE_VERSION, &Self::sorted(&self.policies))
+    }
+}
+
+#[async_trait]
+impl Handler<RestoreState> for SchedulerActor {
+    type Result = bool;
+
+    async fn handle(&mut self, msg: RestoreState, _: &Context<Self>) -> Self::Result {
+        let Some(policies) = msg.0.read::<Vec<TaskThrottle>>(STATE_VERSION) else {
+            return false;
+        };
+        for TaskThrottle { task, policy } in policies {
+            self.policies.insert(task, policy);
+        }
+        self.apply().await;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use messages::prelude::Context;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::study_actors::{
+        actors::{ApiClient, CacheActor, EventBus, Instant, PriorityMailbox},
+        messages::{ApiConfig, FetchPagedRemote},
+        signals::UserDataResponse,
+        storage::MemoryStorage,
+        test_support::{TestActorHarness, settle},
+    };
+
+    // 항목 제목 없이 알림을 보내는 스케줄러 (데이터 관리자 자리는 닫힌 주소)
+    fn start_scheduler(
+        storage: &TestActorHarness<StorageActor>,
+        clock: &TrustedClock,
+    ) -> TestActorHarness<SchedulerActor> {
+        TestActorHarness::start(|addr| {
+            SchedulerActor::new(
+                addr,
+                Context::new().address(),
+                storage.addr(),
+                EventBus::new().subscribe(),
+                clock.clone(),
+            )
+        })
+    }
+
+    fn start_storage() -> TestActorHarness<StorageActor> {
+        TestActorHarness::start(|_| StorageActor::new(Arc::new(MemoryStorage::new())))
+    }
+
+    fn reminder(item_id: &str, at: u64, repeat: Option<&str>) -> SetReminder {
+        SetReminder {
+            item_id: item_id.to_string(),
+            at,
+            repeat: repeat.map(str::to_string),
+            timezone: None,
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn reminder_passed_while_closed_is_sent_once_as_missed() -> Result<(), ReminderError> {
+        let storage = start_storage();
+        let clock = TrustedClock::new();
+        let at = clock.trusted_now() + 60;
+        let mut first = start_scheduler(&storage, &clock);
+        settle().await;
+        first.send(reminder("item-1", at, None)).await??;
+        drop(first);
+
+        // 앱이 닫혀 있는 동안 예정 시각이 지나간다
+        storage.advance(Duration::from_secs(600)).await;
+        let mut restarted = start_scheduler(&storage, &clock);
+        settle().await;
+        let due = restarted.signals_of::<ReminderDueSignal>();
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].item_id, "item-1");
+        assert_eq!(due[0].scheduled_at, at);
+        assert!(due[0].missed);
+        assert_eq!(due[0].next_at, None);
+
+        // 보낸 일회성 알림은 지워져서 다시 시작해도 또 오지 않는다
+        let mut again = start_scheduler(&storage, &clock);
+        settle().await;
+        assert!(again.signals_of::<ReminderDueSignal>().is_empty());
+        let snoozed = again
+            .send(SnoozeReminder {
+                item_id: "item-1".to_string(),
+                minutes: 5,
+            })
+            .await?;
+        assert!(snoozed.is_err());
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn repeating_reminder_advances_to_the_next_occurrence() -> Result<(), ReminderError> {
+        const DAY: u64 = 24 * 60 * 60;
+        let storage = start_storage();
+        let clock = TrustedClock::new();
+        let at = clock.trusted_now() + 60;
+        let mut scheduler = start_scheduler(&storage, &clock);
+        settle().await;
+
+        // 지난 시각의 일회성 알림은 받지 않는다
+        let past = scheduler.send(reminder("item-0", at - 120, None)).await?;
+        assert!(past.is_err());
+
+        let set = scheduler
+            .send(reminder("item-1", at, Some("FREQ=DAILY")))
+            .await??;
+        assert_eq!(set.next_at, Some(at));
+        scheduler.advance(Duration::from_secs(61)).await;
+        let due = scheduler.signals_of::<ReminderDueSignal>();
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].scheduled_at, at);
+        assert!(!due[0].missed);
+        assert_eq!(due[0].next_at, Some(at + DAY));
+
+        // 다음 날에도 한 번만 보내고 그다음 발생으로 넘어간다
+        scheduler.advance(Duration::from_secs(DAY)).await;
+        let due = scheduler.signals_of::<ReminderDueSignal>();
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].scheduled_at, at + DAY);
+        assert_eq!(due[0].next_at, Some(at + 2 * DAY));
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn snoozed_reminder_is_sent_again_after_the_delay() -> Result<(), ReminderError> {
+        let storage = start_storage();
+        let clock = TrustedClock::new();
+        let at = clock.trusted_now() + 60;
+        let mut scheduler = start_scheduler(&storage, &clock);
+        settle().await;
+        scheduler
+            .send(reminder("item-1", at, Some("FREQ=DAILY")))
+            .await??;
+        scheduler.advance(Duration::from_secs(61)).await;
+        assert_eq!(scheduler.signals_of::<ReminderDueSignal>().len(), 1);
+
+        let snoozed = scheduler
+            .send(SnoozeReminder {
+                item_id: "item-1".to_string(),
+                minutes: 10,
+            })
+            .await??;
+        let until = snoozed.snoozed_until.ok_or("not snoozed")?;
+        assert_eq!(until, clock.trusted_now() + 600);
+
+        scheduler.advance(Duration::from_secs(599)).await;
+        assert!(scheduler.signals_of::<ReminderDueSignal>().is_empty());
+        scheduler.advance(Duration::from_secs(1)).await;
+        let due = scheduler.signals_of::<ReminderDueSignal>();
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].scheduled_at, until);
+        assert!(!due[0].missed);
+        // 미룬 알림을 보내도 반복 일정은 그대로다
+        assert_eq!(due[0].next_at, Some(at + 24 * 60 * 60));
+
+        let unknown = scheduler
+            .send(SnoozeReminder {
+                item_id: "item-2".to_string(),
+                minutes: 10,
+            })
+            .await?;
+        assert!(unknown.is_err());
+        Ok(())
+    }
+
+    // 서버 주소는 있지만 네트워크 액터가 없는 데이터 관리자 (원격 가져오기는 바로 실패를 알린다)
+    fn start_data(
+        cache: &TestActorHarness<CacheActor>,
+        storage: &TestActorHarness<StorageActor>,
+    ) -> TestActorHarness<DataManagerActor> {
+        TestActorHarness::start(|addr| {
+            let mut actor =
+                DataManagerActor::new(addr, cache.addr(), storage.addr(), 300, TrustedClock::new());
+            let config = ApiConfig {
+                base_url: Some("https://api.example.com".to_string()),
+                ..ApiConfig::default()
+            };
+            if let Some(api) =
+                ApiClient::new(PriorityMailbox::new(Context::new().address()), &config)
+            {
+                actor.set_api_client(api);
+            }
+            actor
+        })
+    }
+
+    fn battery(level: f64, is_charging: bool, low_power_mode: bool) -> BatteryStateChanged {
+        BatteryStateChanged {
+            level,
+            is_charging,
+            low_power_mode,
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn low_power_stretches_intervals_and_defers_sync_until_charging()
+    -> Result<(), ReminderError> {
+        let storage = start_storage();
+        let cache = TestActorHarness::start(|addr| CacheActor::new(addr, 60, TrustedClock::new()));
+        let mut data = start_data(&cache, &storage);
+        let clock = TrustedClock::new();
+        let mut scheduler = TestActorHarness::start(|addr| {
+            SchedulerActor::new(
+                addr,
+                data.addr(),
+                storage.addr(),
+                EventBus::new().subscribe(),
+                clock.clone(),
+            )
+        });
+        settle().await;
+        // 조절 상태는 전역이므로 다른 테스트의 주기 작업은 건드리지 않도록 재시도와 동기화만 조절한다
+        let tested = [ScheduledTask::ChatRetry, ScheduledTask::Sync];
+        for task in ScheduledTask::ALL {
+            if !tested.contains(&task) {
+                let policy = ThrottlePolicy::Normal;
+                scheduler
+                    .notify(SetThrottlePolicyRequest { task, policy })
+                    .await?;
+            }
+        }
+        let mut retries = clock
+            .clock()
+            .interval(Duration::from_secs(10))
+            .throttled(ScheduledTask::ChatRetry);
+        retries.tick().await;
+
+        // 충전 중에는 배터리가 거의 없어도 조절하지 않는다
+        scheduler.notify(battery(0.05, true, false)).await?;
+        settle().await;
+        let status = scheduler.signals_of::<ThrottleStatusSignal>().pop();
+        let status = status.ok_or("no throttle status")?;
+        assert!(!status.low_power && status.throttled.is_empty());
+
+        // 충전을 끊으면 절전한다 (재시도는 두 배로 늘리고, 동기화는 멈춘다)
+        scheduler.notify(battery(0.05, false, false)).await?;
+        settle().await;
+        let status = scheduler.signals_of::<ThrottleStatusSignal>().pop();
+        let status = status.ok_or("no throttle status")?;
+        assert!(status.low_power);
+        assert!(
+            status
+                .throttled
+                .iter()
+                .any(|t| t.task == ScheduledTask::ChatRetry
+                    && t.policy == ThrottlePolicy::Stretch(2))
+        );
+        assert_eq!(
+            Throttle::new(ScheduledTask::Sync).policy(),
+            ThrottlePolicy::Pause
+        );
+        let started = Instant::now();
+        retries.tick().await;
+        assert_eq!(started.elapsed(), Duration::from_secs(10));
+        retries.tick().await;
+        assert_eq!(started.elapsed(), Duration::from_secs(30));
+
+        // 절전 중의 원격 가져오기는 충전할 때까지 미룬다
+        data.notify(FetchPagedRemote {
+            user_id: "alice".to_string(),
+            page_size: None,
+        })
+        .await?;
+        settle().await;
+        assert!(data.signals_of::<UserDataResponse>().is_empty());
+
+        scheduler.notify(battery(0.06, true, false)).await?;
+        settle().await;
+        let status = scheduler.signals_of::<ThrottleStatusSignal>().pop();
+        let status = status.ok_or("no throttle status")?;
+        assert!(!status.low_power && status.throttled.is_empty());
+        assert_eq!(
+            Throttle::new(ScheduledTask::Sync).policy(),
+            ThrottlePolicy::Normal
+        );
+        let resumed = data.signals_of::<UserDataResponse>();
+        assert_eq!(resumed.len(), 1);
+        assert_eq!(resumed[0].user_id, "alice");
+
+        // 이미 늘어난 주기는 한 번 채우고 나면 원래 간격으로 돌아온다
+        retries.tick().await;
+        assert_eq!(started.elapsed(), Duration::from_secs(50));
+        retries.tick().await;
+        assert_eq!(started.elapsed(), Duration::from_secs(60));
+        Ok(())
+    }
+}
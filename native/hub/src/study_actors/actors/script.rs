@@ -0,0 +1,502 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use rhai::{AST, Dynamic, Engine, EvalAltResult, Scope, module_resolvers::DummyModuleResolver};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::{
+    collections::BTreeMap,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::{Duration, Instant},
+};
+use tokio::{runtime::Handle, sync::broadcast, task::JoinSet};
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        AppEvent, DataItem, DeleteData, FetchData, FetchMode, ListScripts, RegisterScript,
+        RemoveScript, ScanPrefix, ScriptConfig, ScriptError, ScriptInfo, ScriptTopic, StoreData,
+    },
+    signals::{
+        EmitSignal, ListScriptsRequest, RegisterScriptRequest, RemoveScriptRequest,
+        ScriptListSignal, ScriptOutputSignal, ScriptRegisteredSignal, route_dart_signals,
+    },
+};
+
+use super::{DataManagerActor, StorageActor};
+
+const SCRIPT_KEY_PREFIX: &str = "scripts/";
+// 한 번 실행에서 호스트 API를 부를 수 있는 횟수
+const MAX_ITEM_READS: usize = 50;
+const MAX_EMITS: usize = 20;
+const MAX_CALL_LEVELS: usize = 32;
+
+#[derive(Serialize, Deserialize)]
+struct StoredScript {
+    source: String,
+    topics: Vec<ScriptTopic>,
+}
+
+struct LoadedScript {
+    ast: Arc<AST>,
+    info: ScriptInfo,
+}
+
+// 스크립트가 emit으로 보낸 신호
+struct ScriptOutput {
+    name: String,
+    payload_json: String,
+}
+
+// 한 번 실행 동안 스크립트가 부를 수 있는 호스트 API
+struct HostApi {
+    script: String,
+    user_id: Option<String>, // 이벤트의 사용자 (항목 조회 범위)
+    data: Address<DataManagerActor>,
+    runtime: Handle,
+}
+
+// 사용자 스크립트 액터 (scripting 기능)
+// 이벤트 버스 주제를 구독한 rhai 스크립트를 연산 수, 시간, 크기 한도 안에서 실행한다.
+// 스크립트는 파일, 네트워크, 모듈 불러오기에 접근할 수 없고 항목 읽기와 Dart 신호 보내기만 할 수 있다.
+pub struct ScriptActor {
+    scripts: BTreeMap<String, LoadedScript>,
+    limits: ScriptConfig,
+    storage: Address<StorageActor>,
+    data: Address<DataManagerActor>,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for ScriptActor {}
+
+impl ScriptActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        limits: ScriptConfig,
+        storage: Address<StorageActor>,
+        data: Address<DataManagerActor>,
+        events: broadcast::Receiver<AppEvent>,
+    ) -> Self {
+        let mut owned_tasks = route_dart_signals!(
+            self_addr,
+            [
+                RegisterScriptRequest,
+                RemoveScriptRequest,
+                ListScriptsRequest
+            ]
+        );
+        owned_tasks.spawn(Self::load_scripts(self_addr.clone()));
+        owned_tasks.spawn(Self::listen_to_events(self_addr, events));
+
+        Self {
+            scripts: BTreeMap::new(),
+            limits,
+            storage,
+            data,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    async fn load_scripts(mut self_addr: Address<Self>) {
+        let _ = self_addr.notify(LoadScripts).await;
+    }
+
+    async fn listen_to_events(
+        mut self_addr: Address<Self>,
+        mut events: broadcast::Receiver<AppEvent>,
+    ) {
+        loop {
+            match events.recv().await {
+                Ok(event) => {
+                    let _ = self_addr.notify(AppEventReceived(event)).await;
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    debug_print!("Script actor skipped {} app events", skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    }
+
+    fn compile(&self, name: String, stored: &StoredScript) -> Result<LoadedScript, ScriptError> {
+        let ast = sandboxed_engine(&self.limits).compile(&stored.source)?;
+        Ok(LoadedScript {
+            ast: Arc::new(ast),
+            info: ScriptInfo {
+                name,
+                topics: stored.topics.clone(),
+                runs: 0,
+                failures: 0,
+                last_error: None,
+            },
+        })
+    }
+
+    fn list(&self) -> Vec<ScriptInfo> {
+        self.scripts.values().map(|s| s.info.clone()).collect()
+    }
+
+    // 한 번 실행은 블로킹 스레드에서 하고, 한도를 넘으면 스크립트 오류로 끝난다
+    async fn run(&mut self, name: &str, user_id: Option<String>, event: Value) {
+        let Some(script) = self.scripts.get(name) else {
+            return;
+        };
+        let ast = script.ast.clone();
+        let limits = self.limits.clone();
+        let host = HostApi {
+            script: name.to_string(),
+            user_id,
+            data: self.data.clone(),
+            runtime: Handle::current(),
+        };
+        let job = tokio::task::spawn_blocking(move || run_script(&ast, &limits, host, event));
+        let result = match job.await {
+            Ok(result) => result,
+            Err(e) => Err(e.into()),
+        };
+
+        let Some(script) = self.scripts.get_mut(name) else {
+            return;
+        };
+        script.info.runs += 1;
+        match result {
+            Ok(outputs) => {
+                script.info.last_error = None;
+                for output in outputs {
+                    ScriptOutputSignal {
+                        script: name.to_string(),
+                        name: output.name,
+                        payload_json: output.payload_json,
+                    }
+                    .emit();
+                }
+            }
+            Err(e) => {
+                debug_print!("Script {} failed: {}", name, e);
+                script.info.failures += 1;
+                script.info.last_error = Some(e.to_string());
+            }
+        }
+    }
+}
+
+// 파일 모듈, eval을 막고 크기 한도를 건 엔진
+fn sandboxed_engine(limits: &ScriptConfig) -> Engine {
+    let mut engine = Engine::new();
+    engine.set_module_resolver(DummyModuleResolver::new());
+    engine.disable_symbol("eval");
+    engine.set_max_operations(limits.max_operations);
+    engine.set_max_call_levels(MAX_CALL_LEVELS);
+    engine.set_max_string_size(limits.max_string_bytes);
+    engine.set_max_array_size(limits.max_collection_len);
+    engine.set_max_map_size(limits.max_collection_len);
+    engine
+}
+
+fn run_script(
+    ast: &AST,
+    limits: &ScriptConfig,
+    host: HostApi,
+    event: Value,
+) -> Result<Vec<ScriptOutput>, ScriptError> {
+    let mut engine = sandboxed_engine(limits);
+    let deadline = Instant::now() + Duration::from_millis(limits.timeout_ms);
+    engine.on_progress(move |_| (Instant::now() >= deadline).then(|| "timed out".into()));
+
+    let script = host.script.clone();
+    engine.on_print(move |text| debug_print!("[script {}] {}", script, text));
+
+    let outputs = Arc::new(Mutex::new(Vec::new()));
+    let emitted = outputs.clone();
+    engine.register_fn(
+        "emit",
+        move |name: &str, payload: Dynamic| -> Result<(), Box<EvalAltResult>> {
+            let mut emitted = emitted.lock().map_err(|_| "emit is unavailable")?;
+            if emitted.len() >= MAX_EMITS {
+                return Err(format!("more than {} signals emitted", MAX_EMITS).into());
+            }
+            emitted.push(ScriptOutput {
+                name: name.to_string(),
+                payload_json: serde_json::to_string(&payload).map_err(|e| e.to_string())?,
+            });
+            Ok(())
+        },
+    );
+
+    let reads = AtomicUsize::new(0);
+    engine.register_fn(
+        "get_item",
+        move |id: &str| -> Result<Dynamic, Box<EvalAltResult>> {
+            if reads.fetch_add(1, Ordering::Relaxed) >= MAX_ITEM_READS {
+                return Err(format!("more than {} items read", MAX_ITEM_READS).into());
+            }
+            let mut data = host.data.clone();
+            let request = FetchData {
+                key: format!("items/{}", id),
+                user_id: host.user_id.clone(),
+                mode: FetchMode::CacheFirst,
+            };
+            // 없는 항목은 ()
+            let Ok(Ok(bytes)) = host.runtime.block_on(data.send(request)) else {
+                return Ok(Dynamic::UNIT);
+            };
+            let item: DataItem = serde_json::from_slice(&bytes).map_err(|e| e.to_string())?;
+            rhai::serde::to_dynamic(item)
+        },
+    );
+
+    let mut scope = Scope::new();
+    scope.push_constant("event", rhai::serde::to_dynamic(event)?);
+    engine.run_ast_with_scope(&mut scope, ast)?;
+
+    let outputs = outputs
+        .lock()
+        .map(|mut outputs| std::mem::take(&mut *outputs))
+        .unwrap_or_default();
+    Ok(outputs)
+}
+
+// 스크립트에 보일 이벤트 (주제, 사용자, 이벤트 값)
+fn script_event(event: AppEvent) -> Option<(ScriptTopic, Option<String>, Value)> {
+    let (topic, user_id, mut value) = match event {
+        AppEvent::DataItemCreated {
+            user_id,
+            item,
+            tags,
+        } => (
+            ScriptTopic::ItemCreated,
+            Some(user_id),
+            json!({ "item": item, "tags": tags }),
+        ),
+        AppEvent::DataItemUpdated {
+            user_id,
+            item,
+            tags,
+        } => (
+            ScriptTopic::ItemUpdated,
+            Some(user_id),
+            json!({ "item": item, "tags": tags }),
+        ),
+        AppEvent::DataItemDeleted { user_id, item_id } => (
+            ScriptTopic::ItemDeleted,
+            Some(user_id),
+            json!({ "item_id": item_id }),
+        ),
+        AppEvent::DataItemsSynced { user_id, items } => (
+            ScriptTopic::ItemsSynced,
+            Some(user_id),
+            json!({ "items": items }),
+        ),
+        AppEvent::SystemNotice { id, title, body } => (
+            ScriptTopic::SystemNotice,
+            None,
+            json!({ "id": id, "title": title, "body": body }),
+        ),
+        AppEvent::ConnectivityChanged { online, metered } => (
+            ScriptTopic::ConnectivityChanged,
+            None,
+            json!({ "online": online, "metered": metered }),
+        ),
+        AppEvent::AdminCommandReceived(_)
+        | AppEvent::EntitlementsChanged { .. }
+        | AppEvent::RemoteWipeRequested { .. }
+        | AppEvent::ConfigChanged { .. } => return None,
+    };
+
+    value["topic"] = json!(topic);
+    value["user_id"] = json!(user_id);
+    Some((topic, user_id, value))
+}
+
+// 내부 메시지 정의
+struct LoadScripts;
+struct AppEventReceived(AppEvent);
+
+#[async_trait]
+impl Notifiable<LoadScripts> for ScriptActor {
+    async fn notify(&mut self, _: LoadScripts, _: &Context<Self>) {
+        let scan = ScanPrefix {
+            prefix: SCRIPT_KEY_PREFIX.to_string(),
+        };
+        let entries = match self.storage.send(scan).await {
+            Ok(Ok(entries)) => entries,
+            Ok(Err(e)) => {
+                debug_print!("Failed to load scripts: {}", e);
+                return;
+            }
+            Err(e) => {
+                debug_print!("Failed to load scripts: {}", e);
+                return;
+            }
+        };
+
+        for (key, bytes) in entries {
+            let name = key.trim_start_matches(SCRIPT_KEY_PREFIX).to_string();
+            // 불러오기 전에 등록한 스크립트가 우선
+            if self.scripts.contains_key(&name) {
+                continue;
+            }
+            let loaded = serde_json::from_slice::<StoredScript>(&bytes)
+                .map_err(ScriptError::from)
+                .and_then(|stored| self.compile(name.clone(), &stored));
+            match loaded {
+                Ok(loaded) => {
+                    self.scripts.insert(name, loaded);
+                }
+                Err(e) => debug_print!("Skipping stored script {}: {}", name, e),
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Notifiable<AppEventReceived> for ScriptActor {
+    async fn notify(&mut self, msg: AppEventReceived, _: &Context<Self>) {
+        let Some((topic, user_id, event)) = script_event(msg.0) else {
+            return;
+        };
+        let subscribed: Vec<String> = self
+            .scripts
+            .values()
+            .filter(|s| s.info.topics.contains(&topic))
+            .map(|s| s.info.name.clone())
+            .collect();
+        for name in subscribed {
+            self.run(&name, user_id.clone(), event.clone()).await;
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<RegisterScript> for ScriptActor {
+    type Result = Result<ScriptInfo, ScriptError>;
+
+    async fn handle(&mut self, msg: RegisterScript, _: &Context<Self>) -> Self::Result {
+        if msg.name.is_empty() || msg.name.contains('/') {
+            return Err(format!("Invalid script name: {:?}", msg.name).into());
+        }
+        if !self.scripts.contains_key(&msg.name) && self.scripts.len() >= self.limits.max_scripts {
+            return Err(format!(
+                "At most {} scripts can be registered",
+                self.limits.max_scripts
+            )
+            .into());
+        }
+
+        let stored = StoredScript {
+            source: msg.source,
+            topics: msg.topics,
+        };
+        let loaded = self.compile(msg.name.clone(), &stored)?;
+        let request = StoreData {
+            key: format!("{}{}", SCRIPT_KEY_PREFIX, msg.name),
+            data: serde_json::to_vec(&stored)?,
+            user_id: None,
+            ttl: None,
+        };
+        self.storage.send(request).await??;
+
+        let info = loaded.info.clone();
+        self.scripts.insert(msg.name, loaded);
+        Ok(info)
+    }
+}
+
+#[async_trait]
+impl Handler<RemoveScript> for ScriptActor {
+    type Result = Result<bool, ScriptError>;
+
+    async fn handle(&mut self, msg: RemoveScript, _: &Context<Self>) -> Self::Result {
+        if self.scripts.remove(&msg.name).is_none() {
+            return Ok(false);
+        }
+        let request = DeleteData {
+            key: format!("{}{}", SCRIPT_KEY_PREFIX, msg.name),
+        };
+        self.storage.send(request).await??;
+        Ok(true)
+    }
+}
+
+#[async_trait]
+impl Handler<ListScripts> for ScriptActor {
+    type Result = Vec<ScriptInfo>;
+
+    async fn handle(&mut self, _: ListScripts, _: &Context<Self>) -> Self::Result {
+        self.list()
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<RegisterScriptRequest> for ScriptActor {
+    async fn notify(&mut self, msg: RegisterScriptRequest, ctx: &Context<Self>) {
+        let name = msg.name.clone();
+        let request = RegisterScript {
+            name: msg.name,
+            source: msg.source,
+            topics: msg.topics,
+        };
+        let (script, error) = match self.handle(request, ctx).await {
+            Ok(info) => (Some(info), None),
+            Err(e) => (None, Some(e.to_string())),
+        };
+        ScriptRegisteredSignal {
+            name,
+            script,
+            error,
+        }
+        .emit();
+    }
+}
+
+#[async_trait]
+impl Notifiable<RemoveScriptRequest> for ScriptActor {
+    async fn notify(&mut self, msg: RemoveScriptRequest, ctx: &Context<Self>) {
+        let request = RemoveScript { name: msg.name };
+        if let Err(e) = self.handle(request, ctx).await {
+            debug_print!("Failed to remove script: {}", e);
+        }
+        ScriptListSignal {
+            scripts: self.list(),
+        }
+        .emit();
+    }
+}
+
+#[async_trait]
+impl Notifiable<ListScriptsRequest> for ScriptActor {
+    async fn notify(&mut self, _: ListScriptsRequest, _: &Context<Self>) {
+        ScriptListSignal {
+            scripts: self.list(),
+        }
+        .emit();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(source: &str) -> Result<Dynamic, Box<EvalAltResult>> {
+        let limits = ScriptConfig {
+            max_operations: 1_000,
+            ..ScriptConfig::default()
+        };
+        sandboxed_engine(&limits).eval::<Dynamic>(source)
+    }
+
+    #[test]
+    fn sandbox_stops_runaway_and_escaping_scripts() {
+        assert!(run("let total = 0; for i in 0..10 { total += i; } total").is_ok());
+        assert!(run("loop { }").is_err());
+        assert!(run("eval(\"40 + 2\")").is_err());
+        assert!(run("import \"secrets\" as s;").is_err());
+        assert!(run("let s = \"x\"; loop { s += s; }").is_err());
+    }
+}
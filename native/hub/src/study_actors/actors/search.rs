@@ -0,0 +1,301 @@
+use async_trait::async_trait;
+use futures_util::{StreamExt, future::BoxFuture, stream::FuturesUnordered};
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Notifiable},
+};
+use serde_json::Value;
+use std::fmt::Display;
+use tokio::task::{AbortHandle, JoinSet};
+
+use crate::study_actors::{
+    messages::{GetConfig, RankItems, SearchResult, SearchSource, SearchUsers},
+    signals::{EmitSignal, UnifiedSearchRequest, UnifiedSearchSignal, route_dart_signals},
+};
+
+use super::{ConfigActor, RankingActor, UserManagerActor, fuzzy_match};
+
+const DEFAULT_LIMIT: usize = 30;
+const SOURCE_LIMIT: usize = 20;
+// 디렉터리 서버가 이름/이메일 외의 이유로 찾은 사용자에게 주는 최소 관련도
+const UNMATCHED_RELEVANCE: f32 = 0.25;
+const SOURCE_STOPPED: &str = "Search source is not running";
+
+// 명령 팔레트에서 바로 실행할 수 있는 동작 (Dart가 id로 실행한다)
+const ACTIONS: &[(&str, &str)] = &[
+    ("sync_now", "Sync now"),
+    ("open_profile", "Open profile"),
+    ("export_archive", "Export data archive"),
+    ("run_speed_test", "Run network speed test"),
+    ("clear_search_history", "Clear search history"),
+    ("clear_media_cache", "Clear media cache"),
+    ("logout", "Log out"),
+];
+
+// 출처별 가중치 (관련도가 같으면 항목이 먼저 보인다)
+fn source_weight(source: SearchSource) -> f32 {
+    match source {
+        SearchSource::Items => 1.0,
+        SearchSource::Users => 0.9,
+        SearchSource::Actions => 0.85,
+        SearchSource::Settings => 0.7,
+    }
+}
+
+// 검색어와 제목의 퍼지 일치를 검색어 자신과의 일치 점수로 나눠 0~1로 맞춘다
+fn relevance(query: &str, title: &str) -> Option<(f32, Vec<u32>)> {
+    let best = fuzzy_match(query, &[query.to_string()], 1)
+        .first()
+        .map_or(1, |hit| hit.score.max(1));
+    let hit = fuzzy_match(query, &[title.to_string()], 1).pop()?;
+    let score = (hit.score as f32 / best as f32).clamp(0.0, 1.0);
+    Some((score, hit.positions))
+}
+
+// 설정 값을 점으로 구분한 키와 값 문자열로 편다 (비밀 값은 보여 주지 않는다)
+fn flatten_settings(prefix: &str, value: &Value, out: &mut Vec<(String, Option<String>)>) {
+    match value {
+        Value::Object(fields) => {
+            for (name, value) in fields {
+                let key = if prefix.is_empty() {
+                    name.clone()
+                } else {
+                    format!("{}.{}", prefix, name)
+                };
+                flatten_settings(&key, value, out);
+            }
+        }
+        _ if prefix.contains("secret") || prefix.contains("password") => {
+            out.push((prefix.to_string(), None));
+        }
+        _ => out.push((prefix.to_string(), Some(value.to_string()))),
+    }
+}
+
+// 출처 액터의 응답을 결과 목록이나 오류 문구로 바꾼다
+fn answered<T, E: Display, S>(response: Result<Result<T, E>, S>) -> Result<T, String> {
+    match response {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(e)) => Err(e.to_string()),
+        Err(_) => Err(SOURCE_STOPPED.to_string()),
+    }
+}
+
+type SourceSearch = (
+    SearchSource,
+    BoxFuture<'static, Result<Vec<SearchResult>, String>>,
+);
+
+// 통합 검색 액터
+// Spotlight처럼 항목, 사용자 디렉터리, 설정과 동작을 함께 찾아 관련도 순으로 합친다.
+// 출처마다 응답 시간이 달라 먼저 온 결과부터 보내고, 느린 출처가 뒤에 결과를 채운다.
+pub struct SearchCoordinatorActor {
+    ranking: Address<RankingActor>,
+    user_manager: Address<UserManagerActor>,
+    config: Address<ConfigActor>,
+    current: Option<AbortHandle>,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for SearchCoordinatorActor {}
+
+impl SearchCoordinatorActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        ranking: Address<RankingActor>,
+        user_manager: Address<UserManagerActor>,
+        config: Address<ConfigActor>,
+    ) -> Self {
+        Self {
+            ranking,
+            user_manager,
+            config,
+            current: None,
+            _owned_tasks: route_dart_signals!(self_addr, [UnifiedSearchRequest]),
+        }
+    }
+
+    fn sources(&self, msg: &UnifiedSearchRequest) -> Vec<SourceSearch> {
+        let query = msg.query.trim().to_string();
+        let mut sources: Vec<SourceSearch> = Vec::new();
+
+        if let Some(user_id) = msg.user_id.clone() {
+            let mut ranking = self.ranking.clone();
+            let query = query.clone();
+            sources.push((
+                SearchSource::Items,
+                Box::pin(async move {
+                    let request = RankItems {
+                        user_id,
+                        query: Some(query.clone()),
+                        limit: SOURCE_LIMIT,
+                    };
+                    answered(ranking.send(request).await).map(|ranked| {
+                        ranked
+                            .into_iter()
+                            .map(|ranked| {
+                                // 본문에서만 일치한 항목은 강조할 글자가 없다
+                                let positions = relevance(&query, &ranked.item.title)
+                                    .map(|(_, positions)| positions)
+                                    .unwrap_or_default();
+                                SearchResult {
+                                    source: SearchSource::Items,
+                                    id: ranked.item.id,
+                                    title: ranked.item.title,
+                                    subtitle: None,
+                                    score: ranked.score.clamp(0.0, 1.0),
+                                    positions,
+                                }
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                }),
+            ));
+        }
+
+        let mut user_manager = self.user_manager.clone();
+        let users_query = query.clone();
+        sources.push((
+            SearchSource::Users,
+            Box::pin(async move {
+                let request = SearchUsers {
+                    query: users_query.clone(),
+                    limit: SOURCE_LIMIT,
+                };
+                answered(user_manager.send(request).await).map(|profiles| {
+                    profiles
+                        .into_iter()
+                        .map(|profile| {
+                            let (score, positions) = relevance(&users_query, &profile.name)
+                                .unwrap_or((UNMATCHED_RELEVANCE, Vec::new()));
+                            SearchResult {
+                                source: SearchSource::Users,
+                                id: profile.user_id,
+                                title: profile.name,
+                                subtitle: Some(profile.email),
+                                score,
+                                positions,
+                            }
+                        })
+                        .collect::<Vec<_>>()
+                })
+            }),
+        ));
+
+        let mut config = self.config.clone();
+        let settings_query = query.clone();
+        sources.push((
+            SearchSource::Settings,
+            Box::pin(async move {
+                config
+                    .send(GetConfig)
+                    .await
+                    .map_err(|_| SOURCE_STOPPED.to_string())
+                    .and_then(|config| serde_json::to_value(config).map_err(|e| e.to_string()))
+                    .map(|value| {
+                        let mut settings = Vec::new();
+                        flatten_settings("", &value, &mut settings);
+                        settings
+                            .into_iter()
+                            .filter_map(|(key, current)| {
+                                let (score, positions) = relevance(&settings_query, &key)?;
+                                Some(SearchResult {
+                                    source: SearchSource::Settings,
+                                    id: key.clone(),
+                                    title: key,
+                                    subtitle: current,
+                                    score,
+                                    positions,
+                                })
+                            })
+                            .collect::<Vec<_>>()
+                    })
+            }),
+        ));
+
+        // 동작 목록은 고정되어 있어 바로 응답한다
+        sources.push((
+            SearchSource::Actions,
+            Box::pin(async move {
+                let results: Vec<SearchResult> = ACTIONS
+                    .iter()
+                    .filter_map(|(id, title)| {
+                        let (score, positions) = relevance(&query, title)?;
+                        Some(SearchResult {
+                            source: SearchSource::Actions,
+                            id: id.to_string(),
+                            title: title.to_string(),
+                            subtitle: None,
+                            score,
+                            positions,
+                        })
+                    })
+                    .collect();
+                Ok(results)
+            }),
+        ));
+        sources
+    }
+
+    async fn search(request_id: String, limit: usize, sources: Vec<SourceSearch>) {
+        let mut remaining: Vec<SearchSource> = sources.iter().map(|(source, _)| *source).collect();
+        let mut pending: FuturesUnordered<_> = sources
+            .into_iter()
+            .map(|(source, search)| async move { (source, search.await) })
+            .collect();
+        let mut merged: Vec<SearchResult> = Vec::new();
+        while let Some((source, results)) = pending.next().await {
+            remaining.retain(|other| *other != source);
+            let error = match results {
+                Ok(results) => {
+                    let weight = source_weight(source);
+                    merged.extend(results.into_iter().map(|mut result| {
+                        result.score *= weight;
+                        result
+                    }));
+                    merged.sort_by(|a, b| b.score.total_cmp(&a.score));
+                    None
+                }
+                Err(e) => Some(e),
+            };
+            UnifiedSearchSignal {
+                request_id: request_id.clone(),
+                results: merged.iter().take(limit).cloned().collect(),
+                source: Some(source),
+                error,
+                pending: remaining.clone(),
+                done: pending.is_empty(),
+            }
+            .emit();
+        }
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<UnifiedSearchRequest> for SearchCoordinatorActor {
+    async fn notify(&mut self, msg: UnifiedSearchRequest, _: &Context<Self>) {
+        if let Some(current) = self.current.take() {
+            current.abort();
+        }
+        if msg.query.trim().is_empty() {
+            UnifiedSearchSignal {
+                request_id: msg.request_id,
+                results: Vec::new(),
+                source: None,
+                error: None,
+                pending: Vec::new(),
+                done: true,
+            }
+            .emit();
+            return;
+        }
+
+        let sources = self.sources(&msg);
+        let limit = msg.limit.map_or(DEFAULT_LIMIT, |limit| limit as usize);
+        let task = self
+            ._owned_tasks
+            .spawn(Self::search(msg.request_id, limit, sources));
+        self.current = Some(task);
+    }
+}
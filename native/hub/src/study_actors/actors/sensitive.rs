@@ -0,0 +1,288 @@
+use aes_gcm::aead::{OsRng, rand_core::RngCore};
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use std::{collections::HashMap, time::Duration};
+use tokio::task::{AbortHandle, JoinSet};
+use zeroize::Zeroizing;
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{DiscardSensitivePayload, StoreSensitivePayload, WipeUserData},
+    signals::{
+        DiscardSecretRequest, EmitBinarySignal, EmitSignal, ExpireSecretSignal,
+        RevealSecretRequest, RevealedSecretSignal, SecretAvailableSignal, route_dart_signals,
+    },
+};
+
+use super::{Clock, TrustedClock};
+
+const DEFAULT_TTL_SECS: u64 = 60;
+
+// 보관 중인 민감 데이터 (drop 시 메모리를 0으로 덮어씀)
+struct SensitiveEntry {
+    secret: Zeroizing<Vec<u8>>,
+    expiry: AbortHandle,
+}
+
+// 민감 데이터 전달 액터
+// 값은 Rust 쪽에만 두고, Dart는 명시적인 요청으로만 짧게 받아간다.
+pub struct SensitivePayloadActor {
+    entries: HashMap<String, SensitiveEntry>,
+    clock: TrustedClock, // 만료 타이머와 Dart에 알리는 만료 시각
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for SensitivePayloadActor {}
+
+impl SensitivePayloadActor {
+    pub fn new(self_addr: Address<Self>, clock: TrustedClock) -> Self {
+        let owned_tasks =
+            route_dart_signals!(self_addr, [RevealSecretRequest, DiscardSecretRequest]);
+
+        Self {
+            entries: HashMap::new(),
+            clock,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    // 추측할 수 없는 임의 핸들 생성
+    fn new_handle() -> String {
+        let mut bytes = [0u8; 16];
+        OsRng.fill_bytes(&mut bytes);
+        hex::encode(bytes)
+    }
+
+    async fn expire_after(
+        mut self_addr: Address<Self>,
+        clock: Clock,
+        handle: String,
+        ttl_secs: u64,
+    ) {
+        clock.sleep(Duration::from_secs(ttl_secs)).await;
+        let _ = self_addr.notify(SecretExpired(handle)).await;
+    }
+
+    // 항목 제거 후 Dart에 만료 알림
+    fn remove(&mut self, handle: &str) -> bool {
+        let Some(entry) = self.entries.remove(handle) else {
+            return false;
+        };
+        entry.expiry.abort();
+
+        ExpireSecretSignal {
+            handle: handle.to_string(),
+        }
+        .emit();
+        true
+    }
+}
+
+// 내부 메시지 정의
+struct SecretExpired(String);
+
+#[async_trait]
+impl Notifiable<SecretExpired> for SensitivePayloadActor {
+    async fn notify(&mut self, msg: SecretExpired, _: &Context<Self>) {
+        if self.remove(&msg.0) {
+            debug_print!("Sensitive payload expired: {}", msg.0);
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<StoreSensitivePayload> for SensitivePayloadActor {
+    type Result = String;
+
+    async fn handle(&mut self, msg: StoreSensitivePayload, ctx: &Context<Self>) -> Self::Result {
+        let secret = Zeroizing::new(msg.secret);
+        let ttl_secs = msg.ttl_secs.unwrap_or(DEFAULT_TTL_SECS);
+        let handle = Self::new_handle();
+
+        let expiry = self._owned_tasks.spawn(Self::expire_after(
+            ctx.address(),
+            self.clock.clock().clone(),
+            handle.clone(),
+            ttl_secs,
+        ));
+        self.entries
+            .insert(handle.clone(), SensitiveEntry { secret, expiry });
+
+        SecretAvailableSignal {
+            handle: handle.clone(),
+            label: msg.label,
+            expires_at: self.clock.trusted_now() + ttl_secs,
+        }
+        .emit();
+        handle
+    }
+}
+
+#[async_trait]
+impl Handler<DiscardSensitivePayload> for SensitivePayloadActor {
+    type Result = bool;
+
+    async fn handle(&mut self, msg: DiscardSensitivePayload, _: &Context<Self>) -> Self::Result {
+        self.remove(&msg.handle)
+    }
+}
+
+#[async_trait]
+impl Handler<WipeUserData> for SensitivePayloadActor {
+    type Result = ();
+
+    async fn handle(&mut self, _: WipeUserData, _: &Context<Self>) -> Self::Result {
+        let handles: Vec<String> = self.entries.keys().cloned().collect();
+        for handle in handles {
+            self.remove(&handle);
+        }
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<RevealSecretRequest> for SensitivePayloadActor {
+    async fn notify(&mut self, msg: RevealSecretRequest, _: &Context<Self>) {
+        match self.entries.get(&msg.handle) {
+            Some(entry) => {
+                RevealedSecretSignal {
+                    handle: msg.handle,
+                    success: true,
+                    error: None,
+                }
+                .emit_binary(entry.secret.to_vec());
+            }
+            None => {
+                RevealedSecretSignal {
+                    handle: msg.handle,
+                    success: false,
+                    error: Some("Secret handle expired or unknown".to_string()),
+                }
+                .emit_binary(Vec::new());
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Notifiable<DiscardSecretRequest> for SensitivePayloadActor {
+    async fn notify(&mut self, msg: DiscardSecretRequest, _: &Context<Self>) {
+        self.remove(&msg.handle);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use super::*;
+    use crate::study_actors::test_support::{TestActorHarness, settle};
+
+    fn store(secret: &[u8], ttl_secs: Option<u64>) -> StoreSensitivePayload {
+        StoreSensitivePayload {
+            label: "recovery code".to_string(),
+            secret: secret.to_vec(),
+            ttl_secs,
+        }
+    }
+
+    // 핸들을 보여 달라고 요청하고 받은 응답과 페이로드를 돌려준다
+    async fn reveal(
+        harness: &mut TestActorHarness<SensitivePayloadActor>,
+        handle: &str,
+    ) -> Result<(RevealedSecretSignal, Vec<u8>), Box<dyn Error>> {
+        let request = RevealSecretRequest {
+            handle: handle.to_string(),
+        };
+        harness.notify(request).await?;
+        settle().await;
+        let revealed = harness
+            .drain_signals()
+            .into_iter()
+            .find(|signal| signal.is::<RevealedSecretSignal>())
+            .ok_or("no reveal response")?;
+        let signal = revealed.decode().ok_or("unreadable reveal response")?;
+        Ok((signal, revealed.binary))
+    }
+
+    fn expired(harness: &mut TestActorHarness<SensitivePayloadActor>) -> Vec<String> {
+        let signals = harness.signals_of::<ExpireSecretSignal>();
+        signals.into_iter().map(|signal| signal.handle).collect()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn handle_is_gone_after_the_ttl() -> Result<(), Box<dyn Error>> {
+        let clock = TrustedClock::new();
+        let mut harness =
+            TestActorHarness::start(|addr| SensitivePayloadActor::new(addr, clock.clone()));
+        let handle = harness.send(store(b"123-456", Some(30))).await?;
+        let available = harness.signals_of::<SecretAvailableSignal>();
+        assert_eq!(available.len(), 1);
+        assert_eq!(available[0].handle, handle);
+        assert_eq!(available[0].expires_at, clock.trusted_now() + 30);
+
+        harness.advance(Duration::from_secs(29)).await;
+        let (signal, secret) = reveal(&mut harness, &handle).await?;
+        assert!(signal.success);
+        assert_eq!(secret, b"123-456");
+
+        harness.advance(Duration::from_secs(1)).await;
+        assert_eq!(expired(&mut harness), [handle.as_str()]);
+        let (signal, secret) = reveal(&mut harness, &handle).await?;
+        assert!(!signal.success);
+        assert!(signal.error.is_some());
+        assert!(secret.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn discard_and_wipe_tell_dart_to_clear_the_handle() -> Result<(), Box<dyn Error>> {
+        let mut harness =
+            TestActorHarness::start(|addr| SensitivePayloadActor::new(addr, TrustedClock::new()));
+        let first = harness.send(store(b"first", None)).await?;
+        let second = harness.send(store(b"second", None)).await?;
+        assert_ne!(first, second);
+        harness.drain_signals();
+
+        let discard = |handle: &str| DiscardSensitivePayload {
+            handle: handle.to_string(),
+        };
+        assert!(harness.send(discard(&first)).await?);
+        assert_eq!(expired(&mut harness), [first.as_str()]);
+        // 이미 지운 핸들은 다시 알리지 않는다
+        assert!(!harness.send(discard(&first)).await?);
+        assert!(expired(&mut harness).is_empty());
+
+        harness.send(WipeUserData).await?;
+        assert_eq!(expired(&mut harness), [second.as_str()]);
+        let (signal, secret) = reveal(&mut harness, &second).await?;
+        assert!(!signal.success);
+        assert!(secret.is_empty());
+
+        // 지운 값의 만료 타이머는 멈춰서 기본 만료 시각이 지나도 다시 알리지 않는다
+        harness.advance(Duration::from_secs(DEFAULT_TTL_SECS)).await;
+        assert!(expired(&mut harness).is_empty());
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn unknown_handle_is_not_revealed() -> Result<(), Box<dyn Error>> {
+        let mut harness =
+            TestActorHarness::start(|addr| SensitivePayloadActor::new(addr, TrustedClock::new()));
+        harness.send(store(b"secret", None)).await?;
+        harness.drain_signals();
+
+        let (signal, secret) = reveal(&mut harness, "not-a-handle").await?;
+        assert_eq!(signal.handle, "not-a-handle");
+        assert!(!signal.success);
+        assert_eq!(
+            signal.error.as_deref(),
+            Some("Secret handle expired or unknown")
+        );
+        assert!(secret.is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,157 @@
+use std::time::Duration;
+
+use crate::study_actors::{
+    messages::{MediaQuality, SpeedTestPhase},
+    signals::{EmitSignal, SpeedTestProgressSignal, SpeedTestResultSignal},
+};
+
+use super::Instant;
+
+// 첫 요청은 연결을 맺느라 느리므로 버리고 그 뒤 표본만 쓴다
+const LATENCY_SAMPLES: usize = 5;
+// 진행 상황을 알릴 수 있도록 내려받기/올리기를 이만큼 나눠 보낸다
+const ROUNDS: u64 = 4;
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+// 측정에 쓴 트래픽 (대역폭 집계에 더한다)
+#[derive(Default)]
+pub struct SpeedTestUsage {
+    pub bytes_sent: u64,
+    pub bytes_received: u64,
+    pub requests: u64,
+}
+
+// 한 엔드포인트에 대한 지연 시간/내려받기/올리기 측정
+// 모든 요청이 같은 클라이언트를 써서 처리량 측정은 이미 열린 연결 위에서 이루어진다.
+pub struct SpeedTest {
+    pub client: reqwest::Client,
+    pub url: String,
+    pub download_bytes: u64,
+    pub upload_bytes: u64,
+}
+
+impl SpeedTest {
+    pub async fn run(self) -> (SpeedTestResultSignal, SpeedTestUsage) {
+        let started = Instant::now();
+        let mut usage = SpeedTestUsage::default();
+        let mut result = Self::failed(None);
+        if let Err(e) = self.measure(&mut result, &mut usage).await {
+            result.error = Some(format!("Speed test failed: {}", e));
+        }
+        result.quality = MediaQuality::from_speed(result.download_mbps, result.latency_ms);
+        result.elapsed_ms = started.elapsed().as_millis() as u64;
+        (result, usage)
+    }
+
+    pub fn failed(error: Option<String>) -> SpeedTestResultSignal {
+        SpeedTestResultSignal {
+            latency_ms: None,
+            jitter_ms: None,
+            download_mbps: None,
+            upload_mbps: None,
+            quality: MediaQuality::Low,
+            elapsed_ms: 0,
+            error,
+        }
+    }
+
+    async fn measure(
+        &self,
+        result: &mut SpeedTestResultSignal,
+        usage: &mut SpeedTestUsage,
+    ) -> reqwest::Result<()> {
+        // 지연 시간
+        let mut samples = Vec::with_capacity(LATENCY_SAMPLES);
+        self.download(0, usage).await?;
+        for sample in 1..=LATENCY_SAMPLES {
+            let started = Instant::now();
+            self.download(0, usage).await?;
+            let latency_ms = started.elapsed().as_millis() as u64;
+            samples.push(latency_ms);
+            progress(
+                SpeedTestPhase::Latency,
+                sample as f64 / LATENCY_SAMPLES as f64,
+                Some(latency_ms),
+                None,
+            );
+        }
+        let jitter: u64 = samples.windows(2).map(|w| w[0].abs_diff(w[1])).sum();
+        result.jitter_ms = Some(jitter / (LATENCY_SAMPLES as u64 - 1));
+        samples.sort_unstable();
+        result.latency_ms = Some(samples[LATENCY_SAMPLES / 2]);
+
+        // 내려받기
+        let chunk = (self.download_bytes / ROUNDS).max(1);
+        let (started, mut received) = (Instant::now(), 0);
+        for round in 1..=ROUNDS {
+            received += self.download(chunk, usage).await?;
+            let mbps = mbps(received, started.elapsed());
+            progress(
+                SpeedTestPhase::Download,
+                round as f64 / ROUNDS as f64,
+                None,
+                Some(mbps),
+            );
+            result.download_mbps = Some(mbps);
+        }
+
+        // 올리기
+        let chunk = (self.upload_bytes / ROUNDS).max(1);
+        let (started, mut sent) = (Instant::now(), 0);
+        for round in 1..=ROUNDS {
+            sent += self.upload(chunk, usage).await?;
+            let mbps = mbps(sent, started.elapsed());
+            progress(
+                SpeedTestPhase::Upload,
+                round as f64 / ROUNDS as f64,
+                None,
+                Some(mbps),
+            );
+            result.upload_mbps = Some(mbps);
+        }
+        Ok(())
+    }
+
+    async fn download(&self, bytes: u64, usage: &mut SpeedTestUsage) -> reqwest::Result<u64> {
+        usage.requests += 1;
+        let response = self
+            .client
+            .get(&self.url)
+            .query(&[("bytes", bytes)])
+            .timeout(REQUEST_TIMEOUT)
+            .send()
+            .await?
+            .error_for_status()?;
+        let received = response.bytes().await?.len() as u64;
+        usage.bytes_received += received;
+        Ok(received)
+    }
+
+    async fn upload(&self, bytes: u64, usage: &mut SpeedTestUsage) -> reqwest::Result<u64> {
+        usage.requests += 1;
+        usage.bytes_sent += bytes;
+        self.client
+            .post(&self.url)
+            .body(vec![0u8; bytes as usize])
+            .timeout(REQUEST_TIMEOUT)
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(bytes)
+    }
+}
+
+fn mbps(bytes: u64, elapsed: Duration) -> f64 {
+    let secs = elapsed.as_secs_f64().max(0.001);
+    bytes as f64 * 8.0 / secs / 1_000_000.0
+}
+
+fn progress(phase: SpeedTestPhase, progress: f64, latency_ms: Option<u64>, mbps: Option<f64>) {
+    SpeedTestProgressSignal {
+        phase,
+        progress,
+        latency_ms,
+        mbps,
+    }
+    .emit();
+}
@@ -0,0 +1,115 @@
+use std::time::Duration;
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{StartupConfig, StartupPhase, StartupStep},
+    signals::{ColdStartCompleteSignal, EmitSignal, ReceiveSignal, StartupReportSignal},
+};
+
+use super::Instant;
+
+// 시작 단계별 소요 시간 측정기
+pub struct StartupTimer {
+    started: Instant,
+    last_mark: Instant,
+    steps: Vec<StartupStep>,
+}
+
+impl StartupTimer {
+    pub fn new() -> Self {
+        let now = Instant::now();
+        Self {
+            started: now,
+            last_mark: now,
+            steps: Vec::new(),
+        }
+    }
+
+    // 직전 기록 이후 걸린 시간을 이 단계의 소요 시간으로 남긴다
+    pub fn mark(&mut self, name: &str) {
+        let now = Instant::now();
+        self.steps.push(StartupStep {
+            name: name.to_string(),
+            elapsed_ms: millis(now.duration_since(self.last_mark)),
+        });
+        self.last_mark = now;
+    }
+
+    pub fn report(
+        self,
+        phase: StartupPhase,
+        budget_ms: u64,
+        deferred: Vec<String>,
+    ) -> StartupReportSignal {
+        let total_ms = millis(self.started.elapsed());
+        let over_budget = total_ms > budget_ms as f64;
+        if over_budget {
+            debug_print!(
+                "[startup] {:?} phase took {:.1}ms, over {}ms budget",
+                phase,
+                total_ms,
+                budget_ms
+            );
+            for step in &self.steps {
+                debug_print!("[startup]   {}: {:.1}ms", step.name, step.elapsed_ms);
+            }
+        }
+        StartupReportSignal {
+            phase,
+            steps: self.steps,
+            total_ms,
+            budget_ms,
+            over_budget,
+            deferred,
+        }
+    }
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+type StartFn = Box<dyn FnOnce() + Send>;
+
+// 첫 화면 이후로 미룬 액터 시작 작업
+// 주소(메일박스)는 미리 만들어 두므로 그 전에 보낸 메시지는 시작될 때까지 대기열에서 기다린다.
+#[derive(Default)]
+pub struct DeferredStart {
+    jobs: Vec<(&'static str, StartFn)>,
+}
+
+impl DeferredStart {
+    pub fn defer(&mut self, name: &'static str, start: impl FnOnce() + Send + 'static) {
+        self.jobs.push((name, Box::new(start)));
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        self.jobs.iter().map(|(name, _)| name.to_string()).collect()
+    }
+
+    // Dart의 콜드 스타트 완료 신호와 대체 시간 중 먼저 오는 쪽에서 모두 시작한다
+    pub async fn run(self, config: StartupConfig) {
+        let receiver = ColdStartCompleteSignal::signal_receiver();
+        let fallback = Duration::from_millis(config.deferred_fallback_ms);
+        tokio::select! {
+            _ = receiver.recv() => {
+                debug_print!("Cold start complete, starting deferred actors");
+            }
+            _ = tokio::time::sleep(fallback) => {
+                debug_print!(
+                    "No cold start signal after {}ms, starting deferred actors",
+                    config.deferred_fallback_ms
+                );
+            }
+        }
+
+        let mut timer = StartupTimer::new();
+        for (name, start) in self.jobs {
+            start();
+            timer.mark(name);
+        }
+        timer
+            .report(StartupPhase::Deferred, config.budget_ms, Vec::new())
+            .emit();
+    }
+}
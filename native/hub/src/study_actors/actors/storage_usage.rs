@@ -0,0 +1,266 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use std::{collections::HashMap, fmt::Display};
+use tokio::task::JoinSet;
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        ActorResult, CategoryCleared, CategoryUsage, ClearStorage, ClearStorageCategory,
+        GetStorageUsage, MeasureStorage, ScanPrefix, StorageCategory, StorageUsageReport,
+        UserError,
+    },
+    signals::{ClearCategoryRequest, StorageUsageRequest, respond_to_dart, route_dart_signals},
+};
+
+use super::{AttachmentActor, CacheActor, DiagnosticsActor, MediaCacheActor, StorageActor};
+
+// 저장소 키의 첫 경로 부분으로 분류한다 (모르는 접두어는 Other라 실수로 비워지지 않는다)
+fn category_for_key(key: &str) -> StorageCategory {
+    match key.split('/').next().unwrap_or_default() {
+        "items" | "history" | "tags" | "collections" | "calendar" | "contacts" | "collab" => {
+            StorageCategory::Items
+        }
+        "attachments" => StorageCategory::Attachments,
+        "diagnostics" => StorageCategory::Logs,
+        "media_cache" => StorageCategory::Caches,
+        _ => StorageCategory::Other,
+    }
+}
+
+// 분류별로 합쳐 ALL 순서로 늘어놓는다 (아무것도 없는 분류도 0으로 보인다)
+fn build_report(usages: Vec<CategoryUsage>, errors: Vec<String>) -> StorageUsageReport {
+    let mut totals: HashMap<StorageCategory, (u64, u64)> = HashMap::new();
+    for usage in usages {
+        let total = totals.entry(usage.category).or_default();
+        total.0 += usage.bytes;
+        total.1 += usage.entries;
+    }
+    let categories: Vec<CategoryUsage> = StorageCategory::ALL
+        .into_iter()
+        .map(|category| {
+            let (bytes, entries) = totals.get(&category).copied().unwrap_or_default();
+            CategoryUsage::new(category, bytes, entries)
+        })
+        .collect();
+    StorageUsageReport {
+        total_bytes: categories.iter().map(|usage| usage.bytes).sum(),
+        categories,
+        errors,
+    }
+}
+
+fn category_bytes(report: &StorageUsageReport, category: StorageCategory) -> u64 {
+    report
+        .categories
+        .iter()
+        .find(|usage| usage.category == category)
+        .map_or(0, |usage| usage.bytes)
+}
+
+fn collect_usage<F: Display, E: Display>(
+    usages: &mut Vec<CategoryUsage>,
+    errors: &mut Vec<String>,
+    owner: &str,
+    result: Result<Result<Vec<CategoryUsage>, F>, E>,
+) {
+    match result {
+        Ok(Ok(owned)) => usages.extend(owned),
+        Ok(Err(e)) => errors.push(format!("{}: {}", owner, e)),
+        Err(e) => errors.push(format!("{}: {}", owner, e)),
+    }
+}
+
+// 저장 공간 사용량 액터
+// 저장소 키는 직접 훑어 분류하고, 블롭·캐시·로그는 그 데이터를 가진 액터에 묻는다.
+// 비우기도 가진 액터의 메일함을 거치므로 쓰는 중인 블롭이나 색인과 엇갈리지 않는다.
+pub struct StorageUsageActor {
+    storage: Address<StorageActor>,
+    attachment: Address<AttachmentActor>,
+    media_cache: Address<MediaCacheActor>,
+    cache: Address<CacheActor>,
+    diagnostics: Address<DiagnosticsActor>,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for StorageUsageActor {}
+
+impl StorageUsageActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        storage: Address<StorageActor>,
+        attachment: Address<AttachmentActor>,
+        media_cache: Address<MediaCacheActor>,
+        cache: Address<CacheActor>,
+        diagnostics: Address<DiagnosticsActor>,
+    ) -> Self {
+        let owned_tasks =
+            route_dart_signals!(self_addr, [StorageUsageRequest, ClearCategoryRequest]);
+
+        Self {
+            storage,
+            attachment,
+            media_cache,
+            cache,
+            diagnostics,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    // 응답하지 않는 액터가 있어도 나머지로 보고서를 만든다
+    async fn measure(&mut self) -> StorageUsageReport {
+        let mut usages = Vec::new();
+        let mut errors = Vec::new();
+
+        let scan = ScanPrefix {
+            prefix: String::new(),
+        };
+        let keys = self.storage.send(scan).await.map(|result| {
+            result.map(|entries| {
+                entries
+                    .iter()
+                    .map(|(key, data)| {
+                        CategoryUsage::new(category_for_key(key), data.len() as u64, 1)
+                    })
+                    .collect::<Vec<_>>()
+            })
+        });
+        collect_usage(&mut usages, &mut errors, "storage", keys);
+
+        let blobs = self.attachment.send(MeasureStorage).await;
+        collect_usage(&mut usages, &mut errors, "attachment", blobs);
+        let media = self.media_cache.send(MeasureStorage).await;
+        collect_usage(&mut usages, &mut errors, "media_cache", media);
+        let cached = self.cache.send(MeasureStorage).await;
+        collect_usage(&mut usages, &mut errors, "cache", cached);
+        let logs = self.diagnostics.send(MeasureStorage).await;
+        collect_usage(&mut usages, &mut errors, "diagnostics", logs);
+
+        build_report(usages, errors)
+    }
+}
+
+#[async_trait]
+impl Handler<GetStorageUsage> for StorageUsageActor {
+    type Result = ActorResult<StorageUsageReport>;
+
+    async fn handle(&mut self, _: GetStorageUsage, _: &Context<Self>) -> Self::Result {
+        Ok(self.measure().await)
+    }
+}
+
+#[async_trait]
+impl Handler<ClearStorageCategory> for StorageUsageActor {
+    type Result = ActorResult<CategoryCleared>;
+
+    async fn handle(&mut self, msg: ClearStorageCategory, _: &Context<Self>) -> Self::Result {
+        let category = msg.category;
+        if !category.clearable() {
+            return Err(UserError::InvalidInput(format!(
+                "{:?} storage cannot be cleared",
+                category
+            )));
+        }
+
+        let before = self.measure().await;
+        let request = ClearStorage { category };
+        let removed = match category {
+            StorageCategory::Attachments | StorageCategory::Thumbnails => self
+                .attachment
+                .send(request)
+                .await?
+                .map_err(|e| UserError::Upstream(e.to_string()))?,
+            StorageCategory::Logs => self
+                .diagnostics
+                .send(request)
+                .await?
+                .map_err(|e| UserError::Upstream(e.to_string()))?,
+            StorageCategory::Caches => {
+                let cached = self.cache.send(request.clone()).await??;
+                let media = self
+                    .media_cache
+                    .send(request)
+                    .await?
+                    .map_err(|e| UserError::Upstream(e.to_string()))?;
+                cached + media
+            }
+            StorageCategory::Items | StorageCategory::Other => 0,
+        };
+        debug_print!("Cleared {} entries from {:?} storage", removed, category);
+
+        let usage = self.measure().await;
+        Ok(CategoryCleared {
+            category,
+            freed_bytes: category_bytes(&before, category)
+                .saturating_sub(category_bytes(&usage, category)),
+            usage,
+        })
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<StorageUsageRequest> for StorageUsageActor {
+    async fn notify(&mut self, msg: StorageUsageRequest, ctx: &Context<Self>) {
+        let _ = respond_to_dart(msg, |_| async move {
+            Ok(self.handle(GetStorageUsage, ctx).await?)
+        })
+        .await;
+    }
+}
+
+#[async_trait]
+impl Notifiable<ClearCategoryRequest> for StorageUsageActor {
+    async fn notify(&mut self, msg: ClearCategoryRequest, ctx: &Context<Self>) {
+        let _ = respond_to_dart(msg, |msg| async move {
+            let request = ClearStorageCategory {
+                category: msg.category,
+            };
+            Ok(self.handle(request, ctx).await?)
+        })
+        .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_report, category_for_key};
+    use crate::study_actors::messages::{CategoryUsage, StorageCategory};
+    use std::error::Error;
+
+    #[test]
+    fn keys_and_owner_reports_are_summed_per_category() -> Result<(), Box<dyn Error>> {
+        let usages = vec![
+            CategoryUsage::new(category_for_key("items/u1/a"), 10, 1),
+            CategoryUsage::new(category_for_key("history/u1/a"), 5, 1),
+            CategoryUsage::new(category_for_key("collections"), 1, 1),
+            CategoryUsage::new(category_for_key("media_cache/index"), 3, 1),
+            CategoryUsage::new(category_for_key("sync_keyring"), 2, 1),
+            CategoryUsage::new(StorageCategory::Caches, 100, 4),
+            CategoryUsage::new(StorageCategory::Thumbnails, 7, 2),
+        ];
+
+        let report = build_report(usages, Vec::new());
+        let summary: Vec<_> = report
+            .categories
+            .iter()
+            .map(|usage| (usage.category, usage.bytes, usage.entries, usage.clearable))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (StorageCategory::Items, 16, 3, false),
+                (StorageCategory::Attachments, 0, 0, true),
+                (StorageCategory::Thumbnails, 7, 2, true),
+                (StorageCategory::Logs, 0, 0, true),
+                (StorageCategory::Caches, 103, 5, true),
+                (StorageCategory::Other, 2, 1, false),
+            ]
+        );
+        assert_eq!(report.total_bytes, 128);
+        Ok(())
+    }
+}
@@ -3,84 +3,809 @@ use messages::{
     actor::Actor,
     prelude::{Address, Context, Handler, Notifiable},
 };
-use rinf::{debug_print, RustSignal};
-use tokio::task::JoinSet;
+use std::{collections::HashMap, convert::Infallible, fmt::Display, sync::Arc};
+use tokio::{
+    sync::broadcast::{self, error::RecvError},
+    task::JoinSet,
+};
 
 use crate::study_actors::{
+    logging::debug_print,
     messages::{
-        AuthError, AuthResult, FetchRecentData, GetProfile, Login, ProcessLogin, UserId, UserError,
-        UserProfile,
+        ActorResult, AppConfig, AppEvent, CaptureSnapshot, DeleteData, FetchData, FetchMode,
+        FetchRecentData, GetProfile, Login, PersistState, PersistedState, ProcessLogin,
+        RealtimeEvent, RemoteWipe, RestoreState, RevokeAllSessions, SetStorageWritesPaused,
+        SignedAdminCommand, StartupPhase, StoreData, UserData, UserId, UserProfile, UserSession,
+        WipeOrigin, WipeReport, WipeUserData,
+    },
+    signals::{
+        AppInitializedSignal, AppSnapshotSignal, ConnectivityChanged, DeleteAllUserDataRequest,
+        EmitSignal, InitializeAppRequest, PersistStateRequest, ReceiveSignal,
+        RemoteWipeCompletedSignal, RemoteWipeRequest, StartSessionRequest, StatePersistedSignal,
+        StateSnapshotSignal, UserDataDeletedSignal, respond_to_dart,
     },
-    signals::{AppInitializedSignal, InitializeAppRequest},
+    storage::{MemorySecretStore, MemoryStorage, SealedSecretStore, SecretStore, Storage},
 };
+#[cfg(not(target_family = "wasm"))]
+use crate::study_actors::storage::SledStorage;
+#[cfg(target_family = "wasm")]
+use crate::study_actors::storage::IndexedDbStorage;
+#[cfg(all(
+    feature = "keychain",
+    not(any(target_family = "wasm", target_os = "android"))
+))]
+use crate::study_actors::storage::KeychainSecretStore;
 
-use super::{AuthActor, DataManagerActor, NetworkManagerActor, UserManagerActor};
-
-// 액터 타입 열거형
-pub enum ActorType {
-    Auth,
-    User,
-    Data,
-    Network,
-}
+use super::{
+    ActorBuilder, ActorRegistry, AdminActor, ApiClient, ArchiveActor, AttachmentActor, AuthActor,
+    AutomationActor, BillingActor, BuildError, CacheActor, CalendarActor, ChatActor, CollabActor,
+    ConfigActor, ContactsActor, CryptoActor, CurrencyActor, DataManagerActor, Deadline, DedupActor,
+    DeferredStart, DiagnosticsActor, DiffActor, EventBus, FeedActor, FeedReaderActor,
+    FuzzyMatchActor, HashActor, I18nActor, IdGenActor, InboxActor, IngestActor, Instant,
+    LinkPreviewActor, MailActor, MaintenanceActor, MarkdownActor, MediaCacheActor, MetricsActor,
+    NetworkManagerActor, NotificationActor, OcrPrepActor, PerformanceGovernorActor, PlatformActor,
+    PrefetchActor, PresenceActor, PriorityMailbox, PrivacyActor, QuotaActor, RRuleActor,
+    RankingActor, RouterActor, SchedulerActor, SearchCoordinatorActor, SensitivePayloadActor,
+    StartNetworkMonitor, StartupTimer, StorageActor, StorageUsageActor, SyncCryptoActor,
+    TabularImportActor, TemplateActor, TextAnalysisActor, TimeActor, TraceId, Traced, TrustedClock,
+    UserLockMap, UserManagerActor, ValidationActor, WebSocketActor,
+};
+#[cfg(feature = "ml")]
+use super::EmbeddingActor;
+#[cfg(not(target_family = "wasm"))]
+use super::{AudioActor, MqttActor};
+#[cfg(not(any(target_family = "wasm", target_os = "android", target_os = "ios")))]
+use super::BackgroundModeActor;
+#[cfg(all(
+    feature = "command-channel",
+    not(any(target_family = "wasm", target_os = "android", target_os = "ios"))
+))]
+use super::CommandChannelActor;
+#[cfg(all(feature = "scripting", not(target_family = "wasm")))]
+use super::ScriptActor;
+#[cfg(all(feature = "wasm-rules", not(target_family = "wasm")))]
+use super::RulesEngineActor;
 
-// 사용자 세션 구조체
-pub struct UserSession {
-    pub token: String,
-    pub profile: UserProfile,
-    pub recent_data: crate::study_actors::messages::UserData,
-}
+// 서버가 기기 단위 명령(원격 삭제 등)을 보내는 WebSocket 채널
+const DEVICE_CHANNEL: &str = "device";
+// 종료 시 액터별로 남기는 상태의 저장소 키 접두사
+const STATE_PREFIX: &str = "state/";
 
 // 앱 감독자 액터
+// 만든 액터의 주소는 등록부가 보관하고, 여기에는 감독자가 직접 메시지를 보내는 액터만 둔다.
 pub struct AppSupervisor {
+    config: AppConfig,
+    secret_store: Arc<dyn SecretStore>,
+    clock: TrustedClock,
+    // 사용자별 마지막으로 받은 프로필과 최근 항목 (해당 액터가 응답하지 않을 때 대신 쓴다)
+    stale_sessions: HashMap<UserId, (UserProfile, UserData)>,
     user_manager: Address<UserManagerActor>,
     data_manager: Address<DataManagerActor>,
     network_manager: Address<NetworkManagerActor>,
+    sensitive_manager: Address<SensitivePayloadActor>,
+    chat_manager: Address<ChatActor>,
+    privacy_manager: Address<PrivacyActor>,
+    automation_manager: Address<AutomationActor>,
+    sync_crypto_manager: Address<SyncCryptoActor>,
+    scheduler_manager: Address<SchedulerActor>,
+    media_cache_manager: Address<MediaCacheActor>,
+    feed_manager: Address<FeedActor>,
+    billing_manager: Address<BillingActor>,
+    mail_manager: Address<MailActor>,
+    feed_reader_manager: Address<FeedReaderActor>,
+    dedup_manager: Address<DedupActor>,
+    registry: ActorRegistry,
     _owned_tasks: JoinSet<()>,
 }
 
 impl Actor for AppSupervisor {}
 
 impl AppSupervisor {
-    pub fn new(self_addr: Address<Self>, initialize_all: bool) -> Self {
-        // 1. 네트워크 관리자 생성
-        let network_context = Context::new();
-        let network_addr = network_context.address();
-        let network_actor = NetworkManagerActor::new();
-        tokio::spawn(network_context.run(network_actor));
-        
-        // 2. 데이터 관리자 생성 (네트워크 의존성 주입)
-        let data_context = Context::new();
-        let data_addr = data_context.address();
-        let data_actor = DataManagerActor::new(network_addr.clone());
-        tokio::spawn(data_context.run(data_actor));
-        
-        // 3. 인증 액터 생성
-        let auth_context = Context::new();
-        let auth_addr = auth_context.address();
-        let auth_actor = AuthActor::new(auth_addr.clone());
-        tokio::spawn(auth_context.run(auth_actor));
-        
-        // 4. 사용자 관리자 생성 (인증 의존성 주입)
-        let user_context = Context::new();
-        let user_addr = user_context.address();
-        let user_actor = UserManagerActor::new(auth_addr);
-        tokio::spawn(user_context.run(user_actor));
-        
-        // 5. 감독자 구성
+    pub fn new(
+        self_addr: Address<Self>,
+        initialize_all: bool,
+        config_path: Option<String>,
+    ) -> Result<Self, BuildError> {
+        // 즉시 시작 단계 시간 측정 (첫 화면에 필요 없는 액터는 콜드 스타트 이후로 미룬다)
+        let mut timer = StartupTimer::new();
+        let mut deferred = DeferredStart::default();
+        // 만든 액터의 주소 등록부 (뒤에 만드는 액터의 의존성은 여기서 찾아 주입한다)
+        let mut registry = ActorRegistry::new();
+        
+        // 액터 간 앱 이벤트 전달 (발행: 데이터 관리자, 연결 상태, 설정 / 구독: 자동화 등)
+        let event_bus = EventBus::new();
+        
+        // 0. 설정 액터 생성 (다른 액터들이 사용할 설정값 로드, 실행 중 변경은 이벤트 버스로 알림)
+        let config_builder = ActorBuilder::new().register(&mut registry);
+        let mut config_actor = ConfigActor::new(config_builder.address(), config_path.as_deref());
+        config_actor.set_event_bus(event_bus.clone());
+        let config = config_actor.current();
+        config_builder.start(config_actor);
+        timer.mark("config");
+        
+        // 현재 타깃에서 쓸 수 있는 하위 시스템 감지 (없는 기능은 아래에서 대체 수단으로 구성)
+        #[cfg_attr(target_family = "wasm", allow(unused_mut))]
+        let mut capabilities = PlatformActor::detect();
+        
+        // 키 등 민감 정보를 보관하는 비밀 저장소
+        // 키체인이 있으면 데이터 키 묶음과 진행 중인 키 교체까지 OS 키체인에 두어 재시작 뒤에도 남고,
+        // 없으면 메모리 저장소를 써서 재시작하면 비밀 값과 함께 사라진다
+        #[cfg(all(
+            feature = "keychain",
+            not(any(target_family = "wasm", target_os = "android"))
+        ))]
+        let inner_secret_store: Arc<dyn SecretStore> = if capabilities.keychain {
+            Arc::new(KeychainSecretStore::new())
+        } else {
+            Arc::new(MemorySecretStore::new())
+        };
+        #[cfg(not(all(
+            feature = "keychain",
+            not(any(target_family = "wasm", target_os = "android"))
+        )))]
+        let inner_secret_store: Arc<dyn SecretStore> = Arc::new(MemorySecretStore::new());
+        if !capabilities.keychain {
+            debug_print!("No platform keychain, secrets are kept in memory only");
+        }
+        // 값은 데이터 키로 봉인해 넣고, 데이터 키 교체는 암호화 액터가 맡는다
+        let sealed_secret_store = Arc::new(SealedSecretStore::new(inner_secret_store));
+        let secret_store: Arc<dyn SecretStore> = sealed_secret_store.clone();
+        
+        // 영구 저장소 (DB를 열 수 없으면 메모리 저장소로 대체하고 sled 없음으로 보고)
+        #[cfg(not(target_family = "wasm"))]
+        let storage: Arc<dyn Storage> = match config.storage.db_path.as_deref() {
+            Some(path) if capabilities.sled => match SledStorage::open(path) {
+                Ok(storage) => Arc::new(storage),
+                Err(e) => {
+                    debug_print!("Failed to open storage at {}, using memory: {}", path, e);
+                    capabilities.sled = false;
+                    Arc::new(MemoryStorage::new())
+                }
+            },
+            _ => Arc::new(MemoryStorage::new()),
+        };
+        // 웹에서는 db_path를 IndexedDB 데이터베이스 이름으로 쓴다
+        #[cfg(target_family = "wasm")]
+        let storage: Arc<dyn Storage> = match config.storage.db_path.as_deref() {
+            Some(name) if capabilities.indexed_db => Arc::new(IndexedDbStorage::new(name)),
+            _ => Arc::new(MemoryStorage::new()),
+        };
+        
+        // 기기 시계 변경에 영향받지 않는 공용 시계 (시간 동기화 액터가 보정)
+        let clock = TrustedClock::new();
+        
+        // 사용자 관리자가 같은 사용자의 읽고 고쳐 쓰기를 차례로 실행할 때 잡는 잠금
+        let user_locks = UserLockMap::default();
+        timer.mark("storage");
+        
+        // 플랫폼 기능 액터 생성 (저장소 구성 결과까지 반영한 기능을 Dart에 알림)
+        ActorBuilder::new().spawn(&mut registry, |addr, ()| {
+            PlatformActor::new(addr, capabilities)
+        })?;
+        
+        // 1. 네트워크 관리자 생성 (종량제 연결 여부는 이벤트 버스로 받는다)
+        let network_addr = ActorBuilder::new().spawn(&mut registry, |addr, ()| {
+            NetworkManagerActor::new(
+                addr,
+                config.network.clone(),
+                clock.clone(),
+                event_bus.subscribe(),
+            )
+        })?;
+        // 화면 요청과 백그라운드 요청을 나눠 받는 우선순위 메일박스
+        let network_lanes = PriorityMailbox::new(network_addr.clone());
+        // 인증/프로필/데이터 서비스 클라이언트 (base_url이 없으면 None)
+        let api = ApiClient::new(network_lanes.clone(), &config.api);
+        // 네트워크 상태 모니터링은 콜드 스타트 이후 시작
+        deferred.defer("network monitor", {
+            let mut network_addr = network_addr.clone();
+            move || {
+                tokio::spawn(async move {
+                    let _ = network_addr.notify(StartNetworkMonitor).await;
+                });
+            }
+        });
+        timer.mark("network");
+        
+        // 2. 데이터 관리자 생성 (캐시, 저장소, API 클라이언트 의존성 주입)
+        ActorBuilder::new().spawn(&mut registry, |addr, ()| {
+            CacheActor::new(addr, config.cache.cleanup_interval_secs, clock.clone())
+        })?;
+        
+        let storage_addr =
+            ActorBuilder::new().spawn(&mut registry, |_, ()| StorageActor::new(storage.clone()))?;
+        
+        // 사용량 한도 (데이터 관리자가 항목을 만들거나 원격 페이지를 받기 전에 묻는다)
+        let quota_addr = ActorBuilder::new().spawn(&mut registry, |addr, storage| {
+            let mut quota_actor = QuotaActor::new(
+                addr,
+                config.quota.clone(),
+                storage,
+                event_bus.subscribe(),
+                clock.clone(),
+            );
+            if let Some(api) = api.clone() {
+                quota_actor.set_api_client(api);
+            }
+            quota_actor
+        })?;
+        
+        let data_builder = ActorBuilder::new().register(&mut registry);
+        let (cache, storage_actor) = data_builder.resolve(&registry)?;
+        let mut data_actor = DataManagerActor::new(
+            data_builder.address(),
+            cache,
+            storage_actor,
+            config.cache.default_ttl_secs,
+            clock.clone(),
+        );
+        if let Some(api) = api.clone() {
+            data_actor.set_api_client(api);
+        }
+        data_actor.set_event_bus(event_bus.clone());
+        data_actor.set_quota(quota_addr);
+        // 서버와 주고받는 항목을 봉인하고 푼다 (액터는 34번에서 시작한다)
+        let sync_crypto_builder = ActorBuilder::new().register(&mut registry);
+        data_actor.set_sync_crypto(sync_crypto_builder.address());
+        let data_lanes = data_actor.lanes();
+        let data_addr = data_builder.start(data_actor);
+        timer.mark("data");
+        
+        // 3. 인증 액터 생성 (시작 스냅샷용 마지막 세션을 남기도록 저장소 의존성 주입)
+        let auth_addr = ActorBuilder::new().spawn(&mut registry, |addr, storage| {
+            let mut auth_actor = AuthActor::new(addr, config.auth.clone(), clock.clone());
+            if let Some(api) = api.clone() {
+                auth_actor.set_api_client(api);
+            }
+            auth_actor.set_storage(storage);
+            auth_actor
+        })?;
+        timer.mark("auth");
+        
+        // 4. 사용자 관리자 생성 (인증, 저장소 의존성 주입)
+        let user_addr = ActorBuilder::new().spawn(&mut registry, |addr, (auth, storage)| {
+            let mut user_actor = UserManagerActor::new(addr, auth);
+            user_actor.set_user_locks(user_locks.clone());
+            user_actor.set_auth_deadline(Deadline::new(
+                clock.clock().clone(),
+                config.timeouts.auth_ms,
+            ));
+            if let Some(api) = api.clone() {
+                user_actor.set_api_client(api);
+            }
+            user_actor.set_storage(storage);
+            user_actor
+        })?;
+        timer.mark("user");
+        
+        // 5. 현지화 액터 생성 (원격 번들 로드를 위해 네트워크 의존성 주입, 로케일 설정은 실행 중에도 반영)
+        let i18n_addr = ActorBuilder::new().spawn(&mut registry, |addr, ()| {
+            I18nActor::new(
+                addr,
+                network_lanes.clone(),
+                config.i18n.clone(),
+                event_bus.subscribe(),
+            )
+        })?;
+        
+        // 6. 받은 알림함과 알림 액터 생성 (토큰 등록용 네트워크, 동기화 트리거용 데이터 의존성 주입)
+        // 푸시로 받은 원격 삭제 요청은 이벤트 버스로 감독자에 전달되고, 알림은 알림함에도 남는다
+        ActorBuilder::new().spawn(&mut registry, |addr, storage| {
+            InboxActor::new(addr, config.notification.clone(), storage, clock.clone())
+        })?;
+        ActorBuilder::new().spawn(&mut registry, |addr, (data, inbox)| {
+            let mut notification_actor = NotificationActor::new(
+                addr,
+                network_lanes.clone(),
+                data,
+                config.notification.register_url.clone(),
+                clock.clone(),
+            );
+            notification_actor.set_event_bus(event_bus.clone());
+            notification_actor.set_inbox(inbox);
+            notification_actor
+        })?;
+        
+        // 7. 압축 액터 생성
+        ActorBuilder::new().spawn(&mut registry, |addr, ()| ArchiveActor::new(addr))?;
+        
+        // 8. 해시 액터 생성
+        ActorBuilder::new().spawn(&mut registry, |addr, ()| HashActor::new(addr))?;
+        
+        // 9. 암호화 액터 생성 (비밀 저장소 의존성 주입)
+        ActorBuilder::new().spawn(&mut registry, |addr, ()| {
+            CryptoActor::new(addr, sealed_secret_store.clone())
+        })?;
+        
+        // 10. 표 형식 파일 가져오기 액터 생성 (데이터 관리자 의존성 주입)
+        ActorBuilder::new().spawn(&mut registry, |addr, ()| {
+            TabularImportActor::new(addr, data_lanes.clone())
+        })?;
+        
+        // 11. Markdown 렌더링 액터 생성
+        ActorBuilder::new().spawn(&mut registry, |addr, ()| MarkdownActor::new(addr))?;
+        
+        // 12. MQTT 클라이언트 액터 생성 (Dart 연결 요청 전까지 대기, 네이티브 전용)
+        #[cfg(not(target_family = "wasm"))]
+        ActorBuilder::new().spawn(&mut registry, |addr, ()| MqttActor::new(addr))?;
+        
+        // 13. 민감 데이터 전달 액터 생성
+        let sensitive_addr = ActorBuilder::new().spawn(&mut registry, |addr, ()| {
+            SensitivePayloadActor::new(addr, clock.clone())
+        })?;
+        
+        // 14. 시간 동기화 액터 생성 (Date 헤더 조회용 네트워크 의존성 주입, 콜드 스타트 이후 시작)
+        let time_builder = ActorBuilder::new().register(&mut registry);
+        deferred.defer("time sync", {
+            let network_lanes = network_lanes.clone();
+            let clock = clock.clone();
+            let time_config = config.time.clone();
+            move || {
+                let time_actor =
+                    TimeActor::new(time_builder.address(), network_lanes, clock, time_config);
+                time_builder.start(time_actor);
+            }
+        });
+        
+        // 15. 오디오 액터 생성 (Opus 인코더가 C 라이브러리라 네이티브 전용)
+        #[cfg(not(target_family = "wasm"))]
+        ActorBuilder::new().spawn(&mut registry, |addr, ()| AudioActor::new(addr))?;
+        
+        // 16. WebSocket 연결 액터 생성 (채팅/프레즌스 등의 실시간 전송 계층)
+        let websocket_builder = ActorBuilder::new().register(&mut registry);
+        let websocket_actor =
+            WebSocketActor::new(websocket_builder.address(), config.realtime.clone());
+        let chat_events = websocket_actor.subscribe();
+        let presence_events = websocket_actor.subscribe();
+        let collab_events = websocket_actor.subscribe();
+        let device_events = websocket_actor.subscribe();
+        websocket_builder.start(websocket_actor);
+        
+        // 17. 채팅 액터 생성 (저장소, WebSocket 의존성 주입)
+        let chat_addr =
+            ActorBuilder::new().spawn(&mut registry, |addr, (storage, websocket)| {
+                ChatActor::new(addr, storage, websocket, chat_events, clock.clone())
+            })?;
+        
+        // 18. 프레즌스 액터 생성 (WebSocket 의존성 주입)
+        ActorBuilder::new().spawn(&mut registry, |addr, websocket| {
+            PresenceActor::new(addr, websocket, presence_events, clock.clone())
+        })?;
+        
+        // 19. 랭킹 액터 생성 (열람 통계용 저장소, 후보 조회용 데이터 의존성 주입, 콜드 스타트 이후 시작)
+        let ranking_builder = ActorBuilder::new().register(&mut registry);
+        let (ranking_storage, ranking_data) = ranking_builder.resolve(&registry)?;
+        deferred.defer("ranking", {
+            let clock = clock.clone();
+            move || {
+                let ranking_actor = RankingActor::new(
+                    ranking_builder.address(),
+                    ranking_storage,
+                    ranking_data,
+                    clock,
+                );
+                ranking_builder.start(ranking_actor);
+            }
+        });
+        
+        // 임베딩 액터 생성 (ml 기능, 벡터 보관용 저장소, 색인 대상 조회용 데이터 의존성 주입, 콜드 스타트 이후 시작)
+        #[cfg(feature = "ml")]
+        let embedding_addr = {
+            let embedding_builder = ActorBuilder::new().register(&mut registry);
+            let embedding_addr = embedding_builder.address();
+            let (embedding_storage, embedding_data) = embedding_builder.resolve(&registry)?;
+            deferred.defer("search", {
+                let embedding_config = config.embedding.clone();
+                move || {
+                    let embedding_actor = EmbeddingActor::new(
+                        embedding_builder.address(),
+                        embedding_storage,
+                        embedding_data,
+                        embedding_config,
+                    );
+                    embedding_builder.start(embedding_actor);
+                }
+            });
+            embedding_addr
+        };
+        
+        // 20. 첨부 파일 액터 생성 (항목 기록, 원격 다운로드용 의존성 주입 및 이벤트 버스 구독)
+        ActorBuilder::new().spawn(&mut registry, |addr, (storage, data)| {
+            AttachmentActor::new(
+                addr,
+                config.attachment.clone(),
+                storage,
+                data,
+                network_lanes.clone(),
+                event_bus.subscribe(),
+                clock.clone(),
+            )
+        })?;
+        
+        // 21. 협업 편집 액터 생성 (문서 저장, 항목 본문 반영, WebSocket 의존성 주입)
+        ActorBuilder::new().spawn(&mut registry, |addr, (storage, data, websocket)| {
+            CollabActor::new(addr, storage, data, websocket, collab_events)
+        })?;
+        
+        // 22. 개인정보 액터 생성 (저장소, 캐시, 대기열 보유 액터 의존성 주입)
+        let privacy_builder = ActorBuilder::new().register(&mut registry);
+        let privacy_actor = {
+            let (storage, cache, chat, notification, ranking, data, attachment, collab) =
+                privacy_builder.resolve(&registry)?;
+            PrivacyActor::new(
+                privacy_builder.address(),
+                storage,
+                cache,
+                chat,
+                notification,
+                ranking,
+                data,
+                attachment,
+                collab,
+            )
+        };
+        #[cfg(feature = "ml")]
+        let privacy_actor = privacy_actor.with_embedding_manager(embedding_addr.clone());
+        let privacy_addr = privacy_builder.start(privacy_actor);
+        
+        // 23. 라우터 액터 생성 (토큰 교환용 인증, 항목 조회용 데이터 의존성 주입)
+        ActorBuilder::new().spawn(&mut registry, |addr, (auth, data)| {
+            RouterActor::new(addr, auth, data, config.deep_link.clone())
+        })?;
+        
+        // 24. OCR 전처리 액터 생성
+        ActorBuilder::new().spawn(&mut registry, |addr, ()| OcrPrepActor::new(addr))?;
+        
+        // 25. 자동화 액터 생성 (알림 예약, 동기화 트리거 대상 의존성 주입 및 이벤트 버스 구독)
+        let automation_addr =
+            ActorBuilder::new().spawn(&mut registry, |addr, (storage, notification, data)| {
+                AutomationActor::new(
+                    addr,
+                    storage,
+                    notification,
+                    data,
+                    event_bus.subscribe(),
+                    clock.clone(),
+                )
+            })?;
+        
+        // 26. 지표 액터 생성 (메시지 처리 지연 집계, 콜드 스타트 이후 시작)
+        let metrics_builder = ActorBuilder::new().register(&mut registry);
+        deferred.defer("metrics", {
+            let metrics_config = config.metrics.clone();
+            move || {
+                let metrics_actor = MetricsActor::new(metrics_builder.address(), metrics_config);
+                metrics_builder.start(metrics_actor);
+            }
+        });
+        
+        // 27. 폼 검증 액터 생성
+        ActorBuilder::new().spawn(&mut registry, |addr, ()| ValidationActor::new(addr))?;
+        
+        // 28. 통화 금액 액터 생성 (환율은 네트워크 관리자를 거쳐 받는다)
+        ActorBuilder::new().spawn(&mut registry, |addr, ()| {
+            CurrencyActor::new(
+                addr,
+                network_lanes.clone(),
+                config.currency.clone(),
+                clock.clock().clone(),
+            )
+        })?;
+        
+        // 29. 반복 일정 액터 생성
+        ActorBuilder::new().spawn(&mut registry, |addr, ()| RRuleActor::new(addr))?;
+        
+        // 30. 텍스트 비교/병합 액터 생성
+        ActorBuilder::new().spawn(&mut registry, |addr, ()| DiffActor::new(addr))?;
+        
+        // 31. 텍스트 분석 액터 생성
+        ActorBuilder::new().spawn(&mut registry, |addr, ()| {
+            TextAnalysisActor::new(addr, config.text_analysis.clone())
+        })?;
+        
+        // 32. 템플릿 렌더링 액터 생성
+        ActorBuilder::new().spawn(&mut registry, |addr, ()| {
+            TemplateActor::new(addr, config.template.clone())
+        })?;
+        
+        // 33. ID 생성 액터 생성
+        ActorBuilder::new().spawn(&mut registry, |addr, ()| IdGenActor::new(addr))?;
+        
+        // 34. 동기화 종단간 암호화 액터 생성
+        let sync_crypto_actor =
+            SyncCryptoActor::new(sync_crypto_builder.address(), storage_addr.clone());
+        let sync_crypto_addr = sync_crypto_builder.start(sync_crypto_actor);
+        
+        // 35. 백그라운드 모드 액터 생성 (데스크톱 전용, 창을 닫은 동안 멈추거나 유지할 하위 시스템 의존성 주입)
+        #[cfg(not(any(target_family = "wasm", target_os = "android", target_os = "ios")))]
+        ActorBuilder::new().spawn(
+            &mut registry,
+            |addr, (data, websocket, notification, presence)| {
+                BackgroundModeActor::new(addr, data, websocket, notification, presence)
+            },
+        )?;
+        
+        // 36. 로컬 명령 채널 액터 생성 (설정에서 켰을 때만, 라우터·데이터·감사 기록용 저장소 의존성 주입)
+        #[cfg(all(
+            feature = "command-channel",
+            not(any(target_family = "wasm", target_os = "android", target_os = "ios"))
+        ))]
+        if config.command_channel.enabled {
+            ActorBuilder::new().spawn(&mut registry, |addr, (router, data, storage)| {
+                CommandChannelActor::new(
+                    addr,
+                    config.command_channel.clone(),
+                    clock.clone(),
+                    router,
+                    data,
+                    storage,
+                    event_bus.clone(),
+                )
+            })?;
+        }
+        
+        // 37. 배터리 절전 스케줄러 생성 (절전 중 미룰 데이터 관리자, 항목 알림 저장소, 알림 문구용 현지화 의존성 주입)
+        let scheduler_addr =
+            ActorBuilder::new().spawn(&mut registry, |addr, (data, storage)| {
+                let mut scheduler_actor =
+                    SchedulerActor::new(addr, data, storage, event_bus.subscribe(), clock.clone());
+                scheduler_actor.set_i18n(i18n_addr.clone());
+                scheduler_actor
+            })?;
+        
+        // 38. 성능 거버너 생성 (부하에 따라 프랙털·OCR·검색 색인 품질을 조절)
+        ActorBuilder::new().spawn(&mut registry, |addr, ()| {
+            PerformanceGovernorActor::new(addr, clock.clock().clone())
+        })?;
+        
+        // 39. 미디어 캐시 액터 생성 (색인 저장소와 내려받기용 네트워크 관리자 의존성 주입)
+        let media_cache_addr = ActorBuilder::new().spawn(&mut registry, |addr, storage| {
+            MediaCacheActor::new(
+                addr,
+                config.media_cache.clone(),
+                storage,
+                network_lanes.clone(),
+                clock.clone(),
+            )
+        })?;
+        
+        // 40. 미리 불러오기 액터 생성 (프로필, 항목, 미디어 캐시 의존성 주입)
+        ActorBuilder::new().spawn(
+            &mut registry,
+            |addr, (user_manager, data, media_cache)| {
+                PrefetchActor::new(addr, user_manager, data, media_cache, clock.clock().clone())
+            },
+        )?;
+        
+        // 41. 퍼지 검색 액터 생성
+        ActorBuilder::new().spawn(&mut registry, |addr, ()| FuzzyMatchActor::new(addr))?;
+        
+        // 42. 통합 검색 액터 생성 (항목 랭킹, 사용자 디렉터리, 설정 의존성 주입)
+        ActorBuilder::new().spawn(
+            &mut registry,
+            |addr, (ranking, user_manager, config)| {
+                SearchCoordinatorActor::new(addr, ranking, user_manager, config)
+            },
+        )?;
+        
+        // 43. 활동 피드 생성 (항목 변경·동기화·공지 이벤트를 모아 저장소에 보관)
+        let feed_addr = ActorBuilder::new().spawn(&mut registry, |addr, storage| {
+            FeedActor::new(addr, storage, event_bus.subscribe(), clock.clone())
+        })?;
+        
+        // 44. 진단 액터 생성 (설정, 저장소 통계, 지연 지표, 수집 동의 확인을 위한 의존성 주입)
+        ActorBuilder::new().spawn(&mut registry, |addr, (config, storage, metrics, privacy)| {
+            DiagnosticsActor::new(addr, config, storage, metrics, privacy)
+        })?;
+        
+        // 45. 관리 액터 생성 (서명된 원격 명령을 푸시 이벤트와 폴링 엔드포인트로 받는다)
+        ActorBuilder::new().spawn(&mut registry, |addr, storage| {
+            AdminActor::new(
+                addr,
+                config.admin.clone(),
+                storage,
+                network_lanes.clone(),
+                event_bus.clone(),
+                clock.clone(),
+            )
+        })?;
+        
+        // 46. 결제 액터 생성 (영수증 확인용 API 클라이언트, 권한 캐시용 저장소 의존성 주입)
+        let billing_addr = ActorBuilder::new().spawn(&mut registry, |addr, storage| {
+            let mut billing_actor = BillingActor::new(
+                addr,
+                config.billing.clone(),
+                storage,
+                event_bus.clone(),
+                clock.clone(),
+            );
+            if let Some(api) = api.clone() {
+                billing_actor.set_api_client(api);
+            }
+            billing_actor
+        })?;
+        
+        // 47. 메일 액터 생성 (본문 렌더링용 템플릿, 발송 대기열용 저장소, 발송 API용 네트워크 의존성 주입)
+        let mail_addr = ActorBuilder::new().spawn(&mut registry, |addr, (storage, template)| {
+            MailActor::new(
+                addr,
+                config.mail.clone(),
+                storage,
+                template,
+                network_lanes.clone(),
+                clock.clone(),
+            )
+        })?;
+        
+        // 48. 링크 미리보기 액터 생성 (결과 보관용 캐시, 페이지 요청용 네트워크 의존성 주입)
+        ActorBuilder::new().spawn(&mut registry, |addr, cache| {
+            LinkPreviewActor::new(
+                addr,
+                config.link_preview.clone(),
+                cache,
+                network_lanes.clone(),
+            )
+        })?;
+        
+        // 49. 공유 시트 액터 생성 (항목 생성용 데이터, 첨부용 첨부 파일, 링크 제목용 미리보기 의존성 주입)
+        ActorBuilder::new().spawn(&mut registry, |addr, (data, attachment, link_preview)| {
+            IngestActor::new(addr, config.ingest.clone(), data, attachment, link_preview)
+        })?;
+        
+        // 50. 피드 구독 액터 생성 (구독과 글 보관용 저장소, 피드 요청용 네트워크 의존성 주입)
+        let feed_reader_addr = ActorBuilder::new().spawn(&mut registry, |addr, storage| {
+            FeedReaderActor::new(
+                addr,
+                config.feed_reader.clone(),
+                storage,
+                network_lanes.clone(),
+                clock.clone(),
+            )
+        })?;
+        
+        // 51. 달력 액터 생성 (일정 보관용 저장소, 항목 생성·조회용 데이터 의존성 주입)
+        ActorBuilder::new().spawn(&mut registry, |addr, (storage, data)| {
+            CalendarActor::new(addr, storage, data, clock.clone())
+        })?;
+        
+        // 52. 연락처 액터 생성 (연락처 보관용 저장소, 항목 생성용 데이터, 사진용 첨부 파일 의존성 주입)
+        ActorBuilder::new().spawn(&mut registry, |addr, (storage, data, attachment)| {
+            ContactsActor::new(addr, storage, data, attachment)
+        })?;
+        
+        // 53. 중복 항목 찾기 액터 생성 (항목 목록용 데이터 의존성 주입, 항목 변경 이벤트 구독)
+        let dedup_addr = ActorBuilder::new().spawn(&mut registry, |addr, data| {
+            DedupActor::new(addr, data, event_bus.subscribe(), clock.clock().clone())
+        })?;
+        
+        // 54. 저장 공간 사용량 액터 생성 (키 집계용 저장소, 분류별 측정·비우기용 첨부 파일·미디어 캐시·캐시·진단 의존성 주입)
+        ActorBuilder::new().spawn(
+            &mut registry,
+            |addr, (storage, attachment, media_cache, cache, diagnostics)| {
+                StorageUsageActor::new(addr, storage, attachment, media_cache, cache, diagnostics)
+            },
+        )?;
+        
+        // 55. 저장소 점검 액터 생성 (압축·전체 읽기용 저장소, 파일 대조용 첨부 파일·미디어 캐시 의존성 주입)
+        ActorBuilder::new().spawn(
+            &mut registry,
+            |addr, (storage, attachment, media_cache)| {
+                MaintenanceActor::new(
+                    addr,
+                    config.storage.clone(),
+                    storage,
+                    attachment,
+                    media_cache,
+                    clock.clone(),
+                )
+            },
+        )?;
+        
+        // 스크립트 액터 생성 (scripting 기능, 스크립트 보관용 저장소, 항목 조회용 데이터 의존성 주입)
+        #[cfg(all(feature = "scripting", not(target_family = "wasm")))]
+        ActorBuilder::new().spawn(&mut registry, |addr, (storage, data)| {
+            ScriptActor::new(addr, config.scripting.clone(), storage, data, event_bus.subscribe())
+        })?;
+        
+        // 규칙 엔진 생성 (wasm-rules 기능, 서명된 규칙 모듈을 내려받아 보관)
+        #[cfg(all(feature = "wasm-rules", not(target_family = "wasm")))]
+        ActorBuilder::new().spawn(&mut registry, |addr, storage| {
+            RulesEngineActor::new(
+                addr,
+                config.rules.clone(),
+                storage,
+                network_lanes.clone(),
+                clock.clone(),
+            )
+        })?;
+        timer.mark("features");
+        
+        // 56. 감독자 구성
         let mut owned_tasks = JoinSet::new();
         
+        // 네트워크를 기다리지 않고 저장된 상태만으로 첫 화면을 그리도록 스냅샷을 먼저 보낸다
+        owned_tasks.spawn(Self::publish_snapshot(
+            auth_addr.clone(),
+            user_addr.clone(),
+            data_addr.clone(),
+            chat_addr.clone(),
+        ));
+        
+        // 즉시 시작 단계 보고 후 미뤄 둔 액터는 콜드 스타트 완료를 기다려 시작
+        timer
+            .report(
+                StartupPhase::Eager,
+                config.startup.budget_ms,
+                deferred.names(),
+            )
+            .emit();
+        owned_tasks.spawn(deferred.run(config.startup.clone()));
+        
         if initialize_all {
             // 초기화 작업 시작
             owned_tasks.spawn(Self::initialize_system(self_addr.clone()));
         }
         
-        Self {
+        // Dart가 알려주는 네트워크 연결 변화를 이벤트 버스로 전달
+        owned_tasks.spawn(Self::forward_connectivity(event_bus.clone()));
+        
+        // 원격 삭제는 감독자가 순서를 정해 처리
+        // 서버(푸시, WebSocket)가 보낸 삭제는 관리 액터가 서명을 확인한 뒤에야 이벤트 버스로 온다.
+        owned_tasks.spawn(Self::listen_for_remote_wipe(
+            self_addr.clone(),
+            event_bus.subscribe(),
+        ));
+        owned_tasks.spawn(Self::forward_device_commands(
+            event_bus.clone(),
+            device_events,
+        ));
+        owned_tasks.spawn(Self::forward_remote_wipe_requests(self_addr.clone()));
+        owned_tasks.spawn(Self::forward_delete_all_requests(self_addr.clone()));
+        owned_tasks.spawn(Self::forward_session_requests(self_addr.clone()));
+        
+        // 이전 실행이 종료 직전에 남긴 상태를 이어받고, Dart가 앱을 닫기 전에 다시 남긴다
+        owned_tasks.spawn(Self::restore_state(
+            storage_addr.clone(),
+            network_addr.clone(),
+            data_addr.clone(),
+            scheduler_addr.clone(),
+            chat_addr.clone(),
+            mail_addr.clone(),
+        ));
+        owned_tasks.spawn(Self::forward_persist_requests(self_addr.clone()));
+        
+        Ok(Self {
+            config,
+            secret_store,
+            clock,
+            stale_sessions: HashMap::new(),
             user_manager: user_addr,
             data_manager: data_addr,
             network_manager: network_addr,
+            sensitive_manager: sensitive_addr,
+            chat_manager: chat_addr,
+            privacy_manager: privacy_addr,
+            automation_manager: automation_addr,
+            sync_crypto_manager: sync_crypto_addr,
+            scheduler_manager: scheduler_addr,
+            media_cache_manager: media_cache_addr,
+            feed_manager: feed_addr,
+            billing_manager: billing_addr,
+            mail_manager: mail_addr,
+            feed_reader_manager: feed_reader_addr,
+            dedup_manager: dedup_addr,
+            registry,
             _owned_tasks: owned_tasks,
-        }
+        })
+    }
+    
+    // 감독자가 만든 액터 이름 (등록 순서)
+    pub fn actor_names(&self) -> Vec<String> {
+        self.registry
+            .names()
+            .iter()
+            .map(|name| name.to_string())
+            .collect()
     }
     
     async fn initialize_system(_self_addr: Address<Self>) {
@@ -90,86 +815,279 @@ impl AppSupervisor {
         debug_print!("System initialized");
     }
     
-    async fn handle_actor_failure(&mut self, actor_type: ActorType) {
-        match actor_type {
-            ActorType::Network => {
-                debug_print!("Network actor failed, restarting...");
-                // 네트워크 액터 재시작 로직
-                let network_context = Context::new();
-                let network_addr = network_context.address();
-                let network_actor = NetworkManagerActor::new();
-                tokio::spawn(network_context.run(network_actor));
-                
-                // 의존성 업데이트
-                self.network_manager = network_addr.clone();
-                let _ = self
-                    .data_manager
-                    .notify(crate::study_actors::messages::UpdateNetworkDependency(
-                        network_addr,
-                    ))
-                    .await;
+    // 프로필은 마지막 사용자 기준이므로 인증 조각을 먼저 받고, 나머지는 함께 모은다
+    async fn publish_snapshot(
+        mut auth: Address<AuthActor>,
+        mut user: Address<UserManagerActor>,
+        mut data: Address<DataManagerActor>,
+        mut chat: Address<ChatActor>,
+    ) {
+        let started = Instant::now();
+        let mut parts = Vec::new();
+        let mut user_id = None;
+        match auth.send(CaptureSnapshot { user_id: None }).await {
+            Ok(Some(part)) => {
+                if let StateSnapshotSignal::Auth { user_id: last, .. } = &part {
+                    user_id = last.clone();
+                }
+                parts.push(part);
             }
-            ActorType::Data => {
-                debug_print!("Data actor failed, restarting...");
-                // 데이터 액터 재시작 로직
-                let data_context = Context::new();
-                let data_addr = data_context.address();
-                let data_actor = DataManagerActor::new(self.network_manager.clone());
-                tokio::spawn(data_context.run(data_actor));
-                
-                // 의존성 업데이트
-                self.data_manager = data_addr;
+            Ok(None) => {}
+            Err(e) => debug_print!("Auth snapshot failed: {}", e),
+        }
+        
+        let capture = CaptureSnapshot { user_id };
+        let (profile, items, outbox) = tokio::join!(
+            user.send(capture.clone()),
+            data.send(capture.clone()),
+            chat.send(capture),
+        );
+        for part in [profile, items, outbox] {
+            match part {
+                Ok(part) => parts.extend(part),
+                Err(e) => debug_print!("Snapshot part failed: {}", e),
             }
-            ActorType::User => {
-                debug_print!("User actor failed, restarting...");
-                // 사용자 액터 재시작 로직 (실제 구현에서는 AuthActor 주소 필요)
-                // 여기서는 간단히 처리
-                let user_context = Context::new();
-                let user_addr = user_context.address();
-                let user_actor = UserManagerActor::new(Address::<AuthActor>::default());
-                tokio::spawn(user_context.run(user_actor));
-                
-                // 의존성 업데이트
-                self.user_manager = user_addr;
+        }
+        
+        AppSnapshotSignal {
+            parts,
+            elapsed_ms: started.elapsed().as_secs_f64() * 1000.0,
+        }
+        .emit();
+    }
+    
+    async fn forward_connectivity(event_bus: EventBus) {
+        let receiver = ConnectivityChanged::signal_receiver();
+        while let Some(signal_pack) = receiver.recv().await {
+            let ConnectivityChanged { online, metered } = signal_pack.message;
+            debug_print!("Connectivity changed: online={} metered={}", online, metered);
+            event_bus.publish(AppEvent::ConnectivityChanged { online, metered });
+        }
+    }
+    
+    async fn listen_for_remote_wipe(
+        mut self_addr: Address<Self>,
+        mut events: broadcast::Receiver<AppEvent>,
+    ) {
+        loop {
+            let wipe = match events.recv().await {
+                Ok(AppEvent::RemoteWipeRequested { origin, reason }) => {
+                    RemoteWipe { origin, reason }
+                }
+                Ok(_) | Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => break,
+            };
+            let _ = self_addr.notify(wipe).await;
+        }
+    }
+    
+    // 기기 채널로 온 원격 삭제는 서명된 관리 명령이어야 한다 (서명이 없는 봉투는 버린다)
+    async fn forward_device_commands(
+        event_bus: EventBus,
+        mut device_events: broadcast::Receiver<RealtimeEvent>,
+    ) {
+        loop {
+            let envelope = match device_events.recv().await {
+                Ok(RealtimeEvent::Envelope(envelope))
+                    if envelope.channel == DEVICE_CHANNEL && envelope.kind == "remote_wipe" =>
+                {
+                    envelope
+                }
+                Ok(_) | Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => break,
+            };
+            match serde_json::from_value::<SignedAdminCommand>(envelope.data) {
+                Ok(command) => event_bus.publish(AppEvent::AdminCommandReceived(command)),
+                Err(e) => debug_print!("Dropped unsigned remote wipe envelope: {}", e),
             }
-            ActorType::Auth => {
-                debug_print!("Auth actor failed, cannot recover automatically");
-                // 인증 액터는 중요해서 자동 복구 안함 (실제 구현에서는 더 복잡한 복구 전략 필요)
+        }
+    }
+    
+    async fn forward_remote_wipe_requests(mut self_addr: Address<Self>) {
+        let receiver = RemoteWipeRequest::signal_receiver();
+        while let Some(signal_pack) = receiver.recv().await {
+            let wipe = RemoteWipe {
+                origin: WipeOrigin::User,
+                reason: signal_pack.message.reason,
+            };
+            let _ = self_addr.notify(wipe).await;
+        }
+    }
+    
+    // 설정 화면의 전체 삭제도 원격 삭제와 같은 순서로 모든 대상을 지운다
+    async fn forward_delete_all_requests(mut self_addr: Address<Self>) {
+        let receiver = DeleteAllUserDataRequest::signal_receiver();
+        while let Some(signal_pack) = receiver.recv().await {
+            let _ = self_addr.notify(signal_pack.message).await;
+        }
+    }
+    
+    async fn forward_session_requests(mut self_addr: Address<Self>) {
+        let receiver = StartSessionRequest::signal_receiver();
+        while let Some(signal_pack) = receiver.recv().await {
+            let _ = self_addr.notify(signal_pack.message).await;
+        }
+    }
+    
+    async fn forward_persist_requests(mut self_addr: Address<Self>) {
+        let receiver = PersistStateRequest::signal_receiver();
+        while let Some(signal_pack) = receiver.recv().await {
+            let _ = self_addr.notify(signal_pack.message).await;
+        }
+    }
+    
+    async fn restore_state(
+        mut storage: Address<StorageActor>,
+        mut network: Address<NetworkManagerActor>,
+        mut data: Address<DataManagerActor>,
+        mut scheduler: Address<SchedulerActor>,
+        mut chat: Address<ChatActor>,
+        mut mail: Address<MailActor>,
+    ) {
+        restore_actor(&mut storage, "network", &mut network).await;
+        restore_actor(&mut storage, "data", &mut data).await;
+        restore_actor(&mut storage, "scheduler", &mut scheduler).await;
+        restore_actor(&mut storage, "chat", &mut chat).await;
+        restore_actor(&mut storage, "mail", &mut mail).await;
+    }
+    
+    // 액터마다 남길 상태를 받아 저장하고, 남길 것이 없으면 이전 기록을 지운다
+    async fn persist_state(&mut self) -> (Vec<String>, Vec<String>) {
+        let (mut saved, mut failed) = (Vec::new(), Vec::new());
+        let Some(mut storage) = self.registry.get::<StorageActor>() else {
+            failed.push("storage: storage actor not found".to_string());
+            return (saved, failed);
+        };
+        let states = [
+            ("network", self.network_manager.send(PersistState).await),
+            ("data", self.data_manager.send(PersistState).await),
+            ("scheduler", self.scheduler_manager.send(PersistState).await),
+            ("chat", self.chat_manager.send(PersistState).await),
+            ("mail", self.mail_manager.send(PersistState).await),
+        ];
+        for (name, state) in states {
+            let key = format!("{}{}", STATE_PREFIX, name);
+            let stored = match state {
+                Ok(Some(state)) => store_state(&mut storage, key, &state).await,
+                Ok(None) => {
+                    let _ = storage.send(DeleteData { key }).await;
+                    continue;
+                }
+                Err(e) => Err(e.to_string()),
+            };
+            match stored {
+                Ok(()) => saved.push(name.to_string()),
+                Err(e) => failed.push(format!("{}: {}", name, e)),
             }
         }
+        (saved, failed)
     }
-}
-
-#[async_trait]
-impl Handler<ProcessLogin> for AppSupervisor {
-    type Response = Result<UserSession, AuthError>;
     
-    async fn handle(&mut self, msg: ProcessLogin, _: &Context<Self>) -> Self::Response {
-        // 1. 인증 처리
-        let auth_result = self
-            .user_manager
-            .send(Login {
-                username: msg.username,
-                password: msg.password,
-            })
+    // 원격 삭제 (기기 인증 해제)
+    // 저장소 쓰기를 먼저 막고 세션과 대기 작업을 끝낸 뒤 지우므로 삭제 도중 다시 기록되는 데이터가 없다.
+    // 실패한 대상이 있어도 나머지는 계속 지우고 보고서에 남긴다.
+    async fn remote_wipe(&mut self, msg: RemoteWipe) -> WipeReport {
+        debug_print!("Remote wipe requested by {:?}: {:?}", msg.origin, msg.reason);
+        let mut report = WipeReport::default();
+        let mut storage_actor = self.registry.get::<StorageActor>();
+        
+        // 1. 저장소 쓰기 중지
+        if let Some(storage_actor) = storage_actor.as_mut() {
+            let paused = storage_actor.send(SetStorageWritesPaused { paused: true }).await;
+            record_wipe(&mut report, "storage_writes", paused.map(Ok::<(), Infallible>));
+        }
+        
+        // 2. 세션 종료
+        match self.registry.get::<AuthActor>() {
+            Some(mut auth) => match auth.send(RevokeAllSessions).await {
+                Ok(user_ids) => {
+                    debug_print!("Revoked sessions for {:?}", user_ids);
+                    report.wiped.push("sessions".to_string());
+                }
+                Err(e) => report.errors.push(format!("sessions: {}", e)),
+            },
+            None => report.errors.push("sessions: auth actor not found".to_string()),
+        }
+        
+        // 3. 대기 작업 취소 (자동화 규칙, 항목 알림, 보관 중인 민감 데이터)
+        let automation = self.automation_manager.send(WipeUserData).await;
+        record_wipe(&mut report, "automation", automation);
+        let reminders = self.scheduler_manager.send(WipeUserData).await;
+        record_wipe(&mut report, "reminders", reminders);
+        let sensitive = self.sensitive_manager.send(WipeUserData).await;
+        record_wipe(&mut report, "sensitive", sensitive.map(Ok::<(), Infallible>));
+        
+        // 4. 사용자 데이터 (대기열 → 캐시 → 저장소 순서)
+        self.stale_sessions.clear();
+        match self.privacy_manager.send(WipeUserData).await {
+            Ok(privacy) => {
+                report.wiped.extend(privacy.wiped);
+                report.errors.extend(privacy.errors);
+            }
+            Err(e) => report.errors.push(format!("privacy: {}", e)),
+        }
+        let media_cache = self.media_cache_manager.send(WipeUserData).await;
+        record_wipe(&mut report, "media_cache", media_cache);
+        let feed = self.feed_manager.send(WipeUserData).await;
+        record_wipe(&mut report, "feed", feed);
+        let billing = self.billing_manager.send(WipeUserData).await;
+        record_wipe(&mut report, "billing", billing);
+        let mail = self.mail_manager.send(WipeUserData).await;
+        record_wipe(&mut report, "mail", mail);
+        let feed_reader = self.feed_reader_manager.send(WipeUserData).await;
+        record_wipe(&mut report, "feed_reader", feed_reader);
+        let dedup = self.dedup_manager.send(WipeUserData).await;
+        record_wipe(&mut report, "dedup", dedup.map(Ok::<(), Infallible>));
+        
+        // 5. 메모리의 동기화 키와 비밀 저장소
+        let sync_keys = self.sync_crypto_manager.send(WipeUserData).await;
+        record_wipe(&mut report, "sync_keys", sync_keys);
+        let secrets = self.secret_store.clear_secrets().await;
+        record_wipe(&mut report, "secrets", Ok::<_, Infallible>(secrets));
+        
+        // 6. 쓰기 재개 (다시 로그인하면 빈 저장소에서 시작한다)
+        if let Some(storage_actor) = storage_actor.as_mut() {
+            let _ = storage_actor
+                .send(SetStorageWritesPaused { paused: false })
+                .await;
+        }
+        report
+    }
+    
+    async fn process_login(&mut self, msg: ProcessLogin) -> ActorResult<UserSession> {
+        let clock = self.clock.clock().clone();
+        let timeouts = self.config.timeouts.clone();
+        
+        // 1. 인증 처리 (대신 쓸 값이 없으므로 시간이 지나면 실패)
+        let login = Traced::new(Login {
+            username: msg.username,
+            password: msg.password,
+        });
+        let auth_result = Deadline::new(clock.clone(), timeouts.login_ms)
+            .send(&mut self.user_manager, login)
             .await??;
+        let user_id = auth_result.user_id.clone();
+        let (stale_profile, stale_data) = self.stale_sessions.get(&user_id).cloned().unzip();
         
         // 2. 사용자 프로필 로드
-        let profile = self
-            .user_manager
-            .send(GetProfile {
-                user_id: auth_result.user_id.clone(),
-            })
-            .await??;
+        let get_profile = Traced::new(GetProfile {
+            user_id: user_id.clone(),
+        });
+        let profile = Deadline::new(clock.clone(), timeouts.profile_ms)
+            .send_or_stale(&mut self.user_manager, get_profile, stale_profile)
+            .await?;
         
         // 3. 최근 데이터 로드
-        let recent_data = self
-            .data_manager
-            .send(FetchRecentData {
-                user_id: auth_result.user_id.clone(),
-                limit: Some(5),
-            })
-            .await??;
+        let fetch_recent = Traced::new(FetchRecentData {
+            user_id: user_id.clone(),
+            limit: Some(5),
+            mode: FetchMode::StaleWhileRevalidate,
+        });
+        let recent_data = Deadline::new(clock, timeouts.data_ms)
+            .send_or_stale(&mut self.data_manager, fetch_recent, stale_data)
+            .await?;
+        self.stale_sessions
+            .insert(user_id, (profile.clone(), recent_data.clone()));
         
         // 4. 세션 생성 및 반환
         Ok(UserSession {
@@ -180,7 +1098,101 @@ impl Handler<ProcessLogin> for AppSupervisor {
     }
 }
 
+#[async_trait]
+impl Handler<ProcessLogin> for AppSupervisor {
+    type Result = ActorResult<UserSession>;
+    
+    async fn handle(&mut self, msg: ProcessLogin, _: &Context<Self>) -> Self::Result {
+        // 아래 단계들이 보내는 메시지는 모두 같은 추적 ID로 묶인다
+        let trace = TraceId::current_or_begin();
+        trace
+            .run("AppSupervisor", "ProcessLogin", self.process_login(msg))
+            .await
+    }
+}
+
+#[async_trait]
+impl Notifiable<RemoteWipe> for AppSupervisor {
+    async fn notify(&mut self, msg: RemoteWipe, _: &Context<Self>) {
+        let origin = msg.origin;
+        let report = self.remote_wipe(msg).await;
+        debug_print!(
+            "Remote wipe finished: {:?} (errors: {:?})",
+            report.wiped,
+            report.errors
+        );
+        
+        RemoteWipeCompletedSignal {
+            origin,
+            success: report.errors.is_empty(),
+            wiped: report.wiped,
+            errors: report.errors,
+        }
+        .emit();
+    }
+}
+
+#[async_trait]
+impl Notifiable<DeleteAllUserDataRequest> for AppSupervisor {
+    async fn notify(&mut self, _: DeleteAllUserDataRequest, _: &Context<Self>) {
+        let wipe = RemoteWipe {
+            origin: WipeOrigin::User,
+            reason: None,
+        };
+        let report = self.remote_wipe(wipe).await;
+        debug_print!(
+            "User data wiped: {:?} (errors: {:?})",
+            report.wiped,
+            report.errors
+        );
+        
+        UserDataDeletedSignal {
+            success: report.errors.is_empty(),
+            wiped: report.wiped,
+            errors: report.errors,
+        }
+        .emit();
+    }
+}
+
 // Dart 신호 처리
+#[async_trait]
+impl Notifiable<StartSessionRequest> for AppSupervisor {
+    async fn notify(&mut self, msg: StartSessionRequest, _: &Context<Self>) {
+        // 응답에 추적 ID가 실리도록 추적 안에서 응답까지 보낸다
+        let trace = TraceId::current_or_begin();
+        let _ = trace
+            .run(
+                "AppSupervisor",
+                "StartSessionRequest",
+                respond_to_dart(msg, |msg| async move {
+                    let login = ProcessLogin {
+                        username: msg.username,
+                        password: msg.password,
+                    };
+                    Ok(self.process_login(login).await?)
+                }),
+            )
+            .await;
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<PersistStateRequest> for AppSupervisor {
+    async fn notify(&mut self, _: PersistStateRequest, _: &Context<Self>) {
+        let started = Instant::now();
+        let (saved, failed) = self.persist_state().await;
+        debug_print!("State persisted: {:?} (failed: {:?})", saved, failed);
+        StatePersistedSignal {
+            saved,
+            failed,
+            elapsed_ms: started.elapsed().as_secs_f64() * 1000.0,
+        }
+        .emit();
+    }
+}
+
 #[async_trait]
 impl Notifiable<InitializeAppRequest> for AppSupervisor {
     async fn notify(&mut self, msg: InitializeAppRequest, _: &Context<Self>) {
@@ -196,6 +1208,65 @@ impl Notifiable<InitializeAppRequest> for AppSupervisor {
             version,
             initialized_at,
         }
-        .send_signal_to_dart();
+        .emit();
+    }
+}
+
+// 액터 하나의 상태를 저장한다 (오류는 보고용 문자열)
+async fn store_state(
+    storage: &mut Address<StorageActor>,
+    key: String,
+    state: &PersistedState,
+) -> Result<(), String> {
+    let request = StoreData {
+        key,
+        data: serde_json::to_vec(state).map_err(|e| e.to_string())?,
+        user_id: None,
+        ttl: None,
+    };
+    match storage.send(request).await {
+        Ok(Ok(())) => Ok(()),
+        Ok(Err(e)) => Err(e.to_string()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+// 이전 실행이 남긴 상태를 한 번만 이어받는다 (버전이 맞지 않거나 읽을 수 없으면 버린다)
+async fn restore_actor<A>(storage: &mut Address<StorageActor>, name: &str, actor: &mut Address<A>)
+where
+    A: Handler<RestoreState, Result = bool>,
+{
+    let key = format!("{}{}", STATE_PREFIX, name);
+    let request = FetchData {
+        key: key.clone(),
+        user_id: None,
+        mode: FetchMode::CacheFirst,
+    };
+    let Ok(Ok(bytes)) = storage.send(request).await else {
+        return;
+    };
+    // 비정상 종료 뒤 같은 상태를 두 번 이어받지 않도록 읽자마자 지운다
+    let _ = storage.send(DeleteData { key }).await;
+    let restored = match serde_json::from_slice::<PersistedState>(&bytes) {
+        Ok(state) => actor.send(RestoreState(state)).await.unwrap_or(false),
+        Err(_) => false,
+    };
+    if restored {
+        debug_print!("Restored {} state from last run", name);
+    } else {
+        debug_print!("Discarded incompatible {} state from last run", name);
+    }
+}
+
+// 대상 하나의 삭제 결과를 보고서에 기록 (바깥 오류: 전달 실패, 안쪽 오류: 삭제 실패)
+fn record_wipe<F: Display, E: Display>(
+    report: &mut WipeReport,
+    target: &str,
+    result: Result<Result<(), F>, E>,
+) {
+    match result {
+        Ok(Ok(())) => report.wiped.push(target.to_string()),
+        Ok(Err(e)) => report.errors.push(format!("{}: {}", target, e)),
+        Err(e) => report.errors.push(format!("{}: {}", target, e)),
     }
 }
@@ -0,0 +1,1093 @@
+use aes_gcm::{
+    Aes256Gcm, KeyInit, Nonce,
+    aead::{Aead, AeadCore, OsRng, Payload, rand_core::RngCore},
+};
+use argon2::{Algorithm, Argon2, Params, Version};
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use tokio::task::JoinSet;
+use zeroize::Zeroizing;
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        ActorResult, AuthError, ChangeSyncPassphrase, DataItem, ExportSyncKeyring, FetchData,
+        FetchMode, IdKind, ImportSyncKeyring, LockSyncEncryption, OpenSyncItems, RotateSyncKey,
+        SealSyncItems, SealedItem, SetupSyncEncryption, StorageError, StoreData, SyncPayload,
+        UnlockSyncEncryption, UserError, UserId, WipeUserData,
+    },
+    signals::{
+        ChangeSyncPassphraseRequest, ExportSyncKeyringRequest, ImportSyncKeyringRequest,
+        LockSyncEncryptionRequest, RotateSyncKeyRequest, SetupSyncEncryptionRequest,
+        UnlockSyncEncryptionRequest, respond_to_dart, route_dart_signals,
+    },
+};
+
+use super::{StorageActor, WorkerJob, generate_id, offload};
+
+const KEY_LEN: usize = 32;
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+const KEYRING_VERSION: u32 = 1;
+const MIN_PASSPHRASE_CHARS: usize = 8;
+// 가져온 묶음의 키 유도 비용 상한 (잠금 해제가 기기를 멈춰 세우지 않게)
+const MAX_KDF_MEMORY_KIB: u32 = 1 << 20;
+const MAX_KDF_ITERATIONS: u32 = 16;
+const MAX_KDF_PARALLELISM: u32 = 16;
+
+// Argon2id 비용 (키 묶음에 함께 저장하므로 기본값을 바꿔도 예전 묶음은 그대로 풀린다)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct KdfParams {
+    memory_kib: u32,
+    iterations: u32,
+    parallelism: u32,
+}
+
+impl Default for KdfParams {
+    fn default() -> Self {
+        Self {
+            memory_kib: Params::DEFAULT_M_COST,
+            iterations: Params::DEFAULT_T_COST,
+            parallelism: Params::DEFAULT_P_COST,
+        }
+    }
+}
+
+// 패스프레이즈 → 키 암호화 키 (일부러 느린 계산이라 offload로 실행)
+#[derive(Serialize, Deserialize)]
+pub(super) struct DeriveKekJob {
+    passphrase: String,
+    salt: Vec<u8>,
+    params: KdfParams,
+}
+
+impl WorkerJob for DeriveKekJob {
+    const NAME: &'static str = "sync/derive_kek";
+    type Output = Result<Vec<u8>, String>;
+
+    fn run(self) -> Self::Output {
+        let passphrase = Zeroizing::new(self.passphrase);
+        let params = Params::new(
+            self.params.memory_kib,
+            self.params.iterations,
+            self.params.parallelism,
+            Some(KEY_LEN),
+        )
+        .map_err(|e| e.to_string())?;
+        let mut key = vec![0u8; KEY_LEN];
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+            .hash_password_into(passphrase.as_bytes(), &self.salt, &mut key)
+            .map_err(|e| e.to_string())?;
+        Ok(key)
+    }
+}
+
+// AES-256-GCM (결과: nonce || ciphertext)
+// aad에 사용자와 키/항목 ID를 넣어 다른 사용자나 다른 항목 자리로 옮긴 암호문은 풀리지 않게 한다.
+fn seal(key: &[u8], plaintext: &[u8], aad: &str) -> ActorResult<Vec<u8>> {
+    let cipher = Aes256Gcm::new_from_slice(key)
+        .map_err(|_| UserError::InvalidInput("Invalid sync key length".to_string()))?;
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let payload = Payload {
+        msg: plaintext,
+        aad: aad.as_bytes(),
+    };
+    let ciphertext = cipher
+        .encrypt(&nonce, payload)
+        .map_err(|_| UserError::InvalidInput("Sync encryption failed".to_string()))?;
+
+    let mut output = nonce.to_vec();
+    output.extend_from_slice(&ciphertext);
+    Ok(output)
+}
+
+// 키가 틀렸거나 암호문이 바뀌었으면 None
+fn open(key: &[u8], sealed: &[u8], aad: &str) -> Option<Zeroizing<Vec<u8>>> {
+    if sealed.len() < NONCE_LEN {
+        return None;
+    }
+    let cipher = Aes256Gcm::new_from_slice(key).ok()?;
+    let (nonce, body) = sealed.split_at(NONCE_LEN);
+    let payload = Payload {
+        msg: body,
+        aad: aad.as_bytes(),
+    };
+    cipher
+        .decrypt(Nonce::from_slice(nonce), payload)
+        .ok()
+        .map(Zeroizing::new)
+}
+
+fn key_aad(user_id: &str, key_id: &str) -> String {
+    format!("sync-key/{}/{}", user_id, key_id)
+}
+
+// updated_at도 넣어 서버가 예전 암호문을 더 새로운 시각으로 바꿔 돌려주지 못하게 한다
+fn item_aad(user_id: &str, item_id: &str, updated_at: u64) -> String {
+    format!("sync-item/{}/{}/{}", user_id, item_id, updated_at)
+}
+
+// 패스프레이즈에서 유도한 키로 감싼 데이터 키
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct WrappedKey {
+    key_id: String,
+    wrapped: Vec<u8>,
+}
+
+// 영구 저장소에 보관하는 사용자별 키 묶음
+// 데이터 키는 패스프레이즈에서 유도한 키로 감싸 두므로 묶음만으로는 풀 수 없다.
+// 회전한 예전 키도 남겨 두어야 그 키로 올린 항목을 내려받을 때 풀 수 있다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SyncKeyring {
+    version: u32,
+    salt: Vec<u8>,
+    kdf: KdfParams,
+    active_key_id: String,
+    keys: Vec<WrappedKey>,
+}
+
+impl SyncKeyring {
+    // 가져온 묶음의 형식을 저장하기 전에 확인한다
+    // 키 유도 비용은 이 기기가 새 묶음에 쓰는 비용보다 낮거나 상한보다 높으면 받지 않는다.
+    fn validate(&self, min_kdf: KdfParams) -> ActorResult<()> {
+        let invalid = |reason: &str| {
+            Err(UserError::InvalidInput(format!(
+                "Invalid sync keyring: {}",
+                reason
+            )))
+        };
+        if self.version != KEYRING_VERSION {
+            return Err(UserError::InvalidInput(format!(
+                "Unsupported sync keyring version {}",
+                self.version
+            )));
+        }
+        if self.salt.len() != SALT_LEN {
+            return invalid("unexpected salt length");
+        }
+        let kdf = self.kdf;
+        if kdf.memory_kib < min_kdf.memory_kib
+            || kdf.iterations < min_kdf.iterations
+            || kdf.parallelism < min_kdf.parallelism
+        {
+            return invalid("key derivation cost is too low");
+        }
+        if kdf.memory_kib > MAX_KDF_MEMORY_KIB
+            || kdf.iterations > MAX_KDF_ITERATIONS
+            || kdf.parallelism > MAX_KDF_PARALLELISM
+        {
+            return invalid("key derivation cost is too high");
+        }
+        let mut key_ids = HashSet::new();
+        if !self
+            .keys
+            .iter()
+            .all(|key| key_ids.insert(key.key_id.as_str()))
+        {
+            return invalid("duplicate key id");
+        }
+        if !key_ids.contains(self.active_key_id.as_str()) {
+            return invalid("no active key");
+        }
+        Ok(())
+    }
+}
+
+// 잠금 해제된 사용자의 키 (잠그거나 액터가 내려가면 메모리를 0으로 덮어쓴다)
+struct UnlockedKeys {
+    kek: Zeroizing<Vec<u8>>,
+    active_key_id: String,
+    keys: HashMap<String, Zeroizing<Vec<u8>>>,
+}
+
+impl UnlockedKeys {
+    // 모든 데이터 키를 푼다 (하나라도 풀리지 않으면 패스프레이즈가 틀린 것)
+    fn open_keyring(
+        user_id: &str,
+        keyring: &SyncKeyring,
+        kek: Zeroizing<Vec<u8>>,
+    ) -> ActorResult<Self> {
+        let mut keys = HashMap::new();
+        for wrapped in &keyring.keys {
+            let key = open(&kek, &wrapped.wrapped, &key_aad(user_id, &wrapped.key_id))
+                .ok_or(AuthError::InvalidCredentials)?;
+            keys.insert(wrapped.key_id.clone(), key);
+        }
+        if !keys.contains_key(&keyring.active_key_id) {
+            return Err(UserError::InvalidInput(
+                "Sync keyring has no active key".to_string(),
+            ));
+        }
+        Ok(Self {
+            kek,
+            active_key_id: keyring.active_key_id.clone(),
+            keys,
+        })
+    }
+
+    fn active_key(&self) -> ActorResult<&[u8]> {
+        self.keys
+            .get(&self.active_key_id)
+            .map(|key| key.as_slice())
+            .ok_or_else(|| UserError::InvalidInput("Sync keyring has no active key".to_string()))
+    }
+
+    // 묶음에 없는 이 기기의 키를 묶음의 kek로 감싸 넣는다
+    // 이 기기에서만 회전한 키로 올린 항목도 가져온 뒤에 계속 풀 수 있다.
+    fn merge_into(
+        &mut self,
+        user_id: &str,
+        keyring: &mut SyncKeyring,
+        local: &HashMap<String, Zeroizing<Vec<u8>>>,
+    ) -> ActorResult<()> {
+        let mut missing: Vec<_> = local
+            .iter()
+            .filter(|(key_id, _)| !self.keys.contains_key(*key_id))
+            .collect();
+        missing.sort_by(|a, b| a.0.cmp(b.0));
+        for (key_id, key) in missing {
+            keyring.keys.push(WrappedKey {
+                key_id: key_id.clone(),
+                wrapped: seal(&self.kek, key, &key_aad(user_id, key_id))?,
+            });
+            self.keys.insert(key_id.clone(), key.clone());
+        }
+        Ok(())
+    }
+}
+
+fn random_secret(len: usize) -> Zeroizing<Vec<u8>> {
+    let mut bytes = Zeroizing::new(vec![0u8; len]);
+    OsRng.fill_bytes(&mut bytes);
+    bytes
+}
+
+fn check_passphrase(passphrase: &str) -> ActorResult<()> {
+    if passphrase.chars().count() < MIN_PASSPHRASE_CHARS {
+        return Err(UserError::InvalidInput(format!(
+            "Passphrase must be at least {} characters",
+            MIN_PASSPHRASE_CHARS
+        )));
+    }
+    Ok(())
+}
+
+// 사용자별 동기화 키 관리
+struct SyncKeys {
+    storage: Address<StorageActor>,
+    kdf: KdfParams, // 새로 만드는 묶음에 쓸 비용
+    unlocked: HashMap<UserId, UnlockedKeys>,
+    pending_imports: HashMap<UserId, SyncKeyring>, // 지금 키로 풀리지 않아 새 패스프레이즈를 기다리는 묶음
+}
+
+impl SyncKeys {
+    fn keyring_key(user_id: &str) -> String {
+        format!("sync_keyring/{}", user_id)
+    }
+
+    async fn load_keyring_bytes(&mut self, user_id: &str) -> ActorResult<Option<Vec<u8>>> {
+        let request = FetchData {
+            key: Self::keyring_key(user_id),
+            user_id: Some(user_id.to_string()),
+            mode: FetchMode::CacheFirst,
+        };
+        match self.storage.send(request).await? {
+            Ok(bytes) => Ok(Some(bytes.to_vec())),
+            Err(StorageError::NotFound(_)) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn load_keyring(&mut self, user_id: &str) -> ActorResult<Option<SyncKeyring>> {
+        let Some(bytes) = self.load_keyring_bytes(user_id).await? else {
+            return Ok(None);
+        };
+        Ok(Some(serde_json::from_slice(&bytes)?))
+    }
+
+    async fn save_keyring(&mut self, user_id: &str, keyring: &SyncKeyring) -> ActorResult<()> {
+        let request = StoreData {
+            key: Self::keyring_key(user_id),
+            data: serde_json::to_vec(keyring)?,
+            user_id: Some(user_id.to_string()),
+            ttl: None,
+        };
+        Ok(self.storage.send(request).await??)
+    }
+
+    async fn derive_kek(
+        passphrase: String,
+        salt: &[u8],
+        params: KdfParams,
+    ) -> ActorResult<Zeroizing<Vec<u8>>> {
+        let job = DeriveKekJob {
+            passphrase,
+            salt: salt.to_vec(),
+            params,
+        };
+        let kek = offload(job)
+            .await
+            .map_err(|e| UserError::Upstream(e.to_string()))?
+            .map_err(|e| UserError::InvalidInput(format!("Key derivation failed: {}", e)))?;
+        Ok(Zeroizing::new(kek))
+    }
+
+    fn unlocked_keys(&self, user_id: &str) -> ActorResult<&UnlockedKeys> {
+        self.unlocked.get(user_id).ok_or_else(|| {
+            UserError::InvalidInput(format!("Sync encryption is locked for user {}", user_id))
+        })
+    }
+
+    // 데이터 키 하나를 만들어 묶음에 넣고 활성 키로 바꾼다
+    fn add_key(
+        user_id: &str,
+        keyring: &mut SyncKeyring,
+        kek: &[u8],
+    ) -> ActorResult<(String, Zeroizing<Vec<u8>>)> {
+        let key_id = generate_id(IdKind::Ulid);
+        let key = random_secret(KEY_LEN);
+        keyring.keys.push(WrappedKey {
+            key_id: key_id.clone(),
+            wrapped: seal(kek, &key, &key_aad(user_id, &key_id))?,
+        });
+        keyring.active_key_id = key_id.clone();
+        Ok((key_id, key))
+    }
+
+    async fn setup(&mut self, msg: SetupSyncEncryption) -> ActorResult<String> {
+        check_passphrase(&msg.passphrase)?;
+        if self.load_keyring(&msg.user_id).await?.is_some() {
+            return Err(UserError::InvalidInput(format!(
+                "Sync encryption is already set up for user {}",
+                msg.user_id
+            )));
+        }
+
+        let salt = random_secret(SALT_LEN);
+        let kek = Self::derive_kek(msg.passphrase, &salt, self.kdf).await?;
+        let mut keyring = SyncKeyring {
+            version: KEYRING_VERSION,
+            salt: salt.to_vec(),
+            kdf: self.kdf,
+            active_key_id: String::new(),
+            keys: Vec::new(),
+        };
+        let (key_id, key) = Self::add_key(&msg.user_id, &mut keyring, &kek)?;
+        self.save_keyring(&msg.user_id, &keyring).await?;
+
+        self.unlocked.insert(
+            msg.user_id,
+            UnlockedKeys {
+                kek,
+                active_key_id: key_id.clone(),
+                keys: HashMap::from([(key_id.clone(), key)]),
+            },
+        );
+        Ok(key_id)
+    }
+
+    async fn unlock(&mut self, msg: UnlockSyncEncryption) -> ActorResult<String> {
+        if let Some(key_id) = self.apply_pending_import(&msg).await? {
+            return Ok(key_id);
+        }
+        let keyring = self
+            .load_keyring(&msg.user_id)
+            .await?
+            .ok_or_else(|| UserError::NotFound(format!("sync keyring for {}", msg.user_id)))?;
+        let kek = Self::derive_kek(msg.passphrase, &keyring.salt, keyring.kdf).await?;
+        let keys = UnlockedKeys::open_keyring(&msg.user_id, &keyring, kek)?;
+        let key_id = keys.active_key_id.clone();
+        self.unlocked.insert(msg.user_id, keys);
+        Ok(key_id)
+    }
+
+    // 보류한 묶음이 이 패스프레이즈로 풀리면 지금 키를 합쳐 저장하고 그 묶음으로 잠금 해제한다
+    // 풀리지 않으면 None을 돌려주고 이 기기의 묶음으로 잠금 해제를 이어 간다.
+    async fn apply_pending_import(
+        &mut self,
+        msg: &UnlockSyncEncryption,
+    ) -> ActorResult<Option<String>> {
+        let Some(mut keyring) = self.pending_imports.get(&msg.user_id).cloned() else {
+            return Ok(None);
+        };
+        let kek = Self::derive_kek(msg.passphrase.clone(), &keyring.salt, keyring.kdf).await?;
+        let Ok(mut keys) = UnlockedKeys::open_keyring(&msg.user_id, &keyring, kek) else {
+            return Ok(None);
+        };
+        if let Some(current) = self.unlocked.get(&msg.user_id) {
+            keys.merge_into(&msg.user_id, &mut keyring, &current.keys)?;
+        }
+        self.save_keyring(&msg.user_id, &keyring).await?;
+
+        self.pending_imports.remove(&msg.user_id);
+        let key_id = keys.active_key_id.clone();
+        self.unlocked.insert(msg.user_id.clone(), keys);
+        Ok(Some(key_id))
+    }
+
+    // 예전 키는 지우지 않는다 (서버에 그 키로 올린 항목이 남아 있을 수 있다)
+    async fn rotate(&mut self, user_id: &str) -> ActorResult<String> {
+        let mut keyring = self
+            .load_keyring(user_id)
+            .await?
+            .ok_or_else(|| UserError::NotFound(format!("sync keyring for {}", user_id)))?;
+        let unlocked = self.unlocked_keys(user_id)?;
+        let (key_id, key) = Self::add_key(user_id, &mut keyring, &unlocked.kek)?;
+        self.save_keyring(user_id, &keyring).await?;
+
+        if let Some(unlocked) = self.unlocked.get_mut(user_id) {
+            unlocked.keys.insert(key_id.clone(), key);
+            unlocked.active_key_id = key_id.clone();
+        }
+        debug_print!("Rotated sync key for {}: {}", user_id, key_id);
+        Ok(key_id)
+    }
+
+    // 솔트도 새로 만들어 예전 패스프레이즈로 유도한 키로는 새 묶음을 풀 수 없게 한다
+    async fn change_passphrase(&mut self, msg: ChangeSyncPassphrase) -> ActorResult<String> {
+        check_passphrase(&msg.new_passphrase)?;
+        let unlocked = self.unlocked_keys(&msg.user_id)?;
+        let salt = random_secret(SALT_LEN);
+        let kek = Self::derive_kek(msg.new_passphrase, &salt, self.kdf).await?;
+
+        let mut keys = Vec::with_capacity(unlocked.keys.len());
+        for (key_id, key) in &unlocked.keys {
+            keys.push(WrappedKey {
+                key_id: key_id.clone(),
+                wrapped: seal(&kek, key, &key_aad(&msg.user_id, key_id))?,
+            });
+        }
+        keys.sort_by(|a, b| a.key_id.cmp(&b.key_id));
+        let keyring = SyncKeyring {
+            version: KEYRING_VERSION,
+            salt: salt.to_vec(),
+            kdf: self.kdf,
+            active_key_id: unlocked.active_key_id.clone(),
+            keys,
+        };
+        self.save_keyring(&msg.user_id, &keyring).await?;
+
+        if let Some(unlocked) = self.unlocked.get_mut(&msg.user_id) {
+            unlocked.kek = kek;
+        }
+        Ok(keyring.active_key_id)
+    }
+
+    // 잠금 해제되지 않은 사용자가 암호화를 켰는지 (켰으면 평문으로 주고받으면 안 된다)
+    async fn encryption_enabled(&mut self, user_id: &str) -> ActorResult<bool> {
+        Ok(self.unlocked.contains_key(user_id) || self.load_keyring(user_id).await?.is_some())
+    }
+
+    async fn seal_items(&mut self, msg: SealSyncItems) -> ActorResult<Vec<SyncPayload>> {
+        if !self.encryption_enabled(&msg.user_id).await? {
+            return Ok(msg.items.into_iter().map(SyncPayload::Plain).collect());
+        }
+        let unlocked = self.unlocked_keys(&msg.user_id)?;
+        let key = unlocked.active_key()?;
+        msg.items
+            .iter()
+            .map(|item| {
+                let plaintext = Zeroizing::new(serde_json::to_vec(item)?);
+                Ok(SyncPayload::Sealed(SealedItem {
+                    id: item.id.clone(),
+                    updated_at: item.updated_at,
+                    key_id: unlocked.active_key_id.clone(),
+                    payload: seal(
+                        key,
+                        &plaintext,
+                        &item_aad(&msg.user_id, &item.id, item.updated_at),
+                    )?,
+                }))
+            })
+            .collect()
+    }
+
+    async fn open_items(&mut self, msg: OpenSyncItems) -> ActorResult<Vec<DataItem>> {
+        if !self.encryption_enabled(&msg.user_id).await? {
+            return msg
+                .items
+                .into_iter()
+                .map(|payload| match payload {
+                    SyncPayload::Plain(item) => Ok(item),
+                    SyncPayload::Sealed(sealed) => Err(UserError::InvalidInput(format!(
+                        "Sync item {} is encrypted but sync encryption is not set up",
+                        sealed.id
+                    ))),
+                })
+                .collect();
+        }
+
+        let unlocked = self.unlocked_keys(&msg.user_id)?;
+        let mut items = Vec::with_capacity(msg.items.len());
+        for payload in msg.items {
+            let sealed = match payload {
+                SyncPayload::Sealed(sealed) => sealed,
+                SyncPayload::Plain(item) => {
+                    debug_print!(
+                        "Dropped plaintext sync item {} for {}",
+                        item.id,
+                        msg.user_id
+                    );
+                    continue;
+                }
+            };
+            // 다른 기기가 회전한 키면 최신 키 묶음을 가져와야 한다
+            let key = unlocked.keys.get(&sealed.key_id).ok_or_else(|| {
+                UserError::NotFound(format!(
+                    "sync key {} (import the latest keyring)",
+                    sealed.key_id
+                ))
+            })?;
+            let aad = item_aad(&msg.user_id, &sealed.id, sealed.updated_at);
+            let plaintext = open(key, &sealed.payload, &aad).ok_or_else(|| {
+                UserError::InvalidInput(format!(
+                    "Sync item {} could not be decrypted (tampered or wrong key)",
+                    sealed.id
+                ))
+            })?;
+            let item: DataItem = serde_json::from_slice(&plaintext)?;
+            if item.id != sealed.id || item.updated_at != sealed.updated_at {
+                return Err(UserError::InvalidInput(format!(
+                    "Sync item {} does not match its envelope",
+                    sealed.id
+                )));
+            }
+            items.push(item);
+        }
+        Ok(items)
+    }
+
+    async fn export_keyring(&mut self, user_id: &str) -> ActorResult<Vec<u8>> {
+        self.load_keyring_bytes(user_id)
+            .await?
+            .ok_or_else(|| UserError::NotFound(format!("sync keyring for {}", user_id)))
+    }
+
+    // 새 묶음을 확인하고 지금 키로 풀어 본 뒤에만 저장한다 (풀리면 활성 키 ID를 돌려준다)
+    // 이 기기에만 있는 키는 합쳐 저장하므로 가져오기로 키를 잃지 않는다.
+    // 다른 기기에서 패스프레이즈를 바꿨으면 풀리지 않으므로 저장하지 않고 보류했다가
+    // 새 패스프레이즈로 잠금 해제할 때 합친다. 이 기기에 묶음이 있는데 잠겨 있으면 풀어 볼 수 없어 받지 않는다.
+    async fn import_keyring(&mut self, msg: ImportSyncKeyring) -> ActorResult<Option<String>> {
+        let mut keyring: SyncKeyring = serde_json::from_slice(&msg.keyring)?;
+        keyring.validate(self.kdf)?;
+
+        let Some(current) = self.unlocked.get(&msg.user_id) else {
+            if self.load_keyring(&msg.user_id).await?.is_some() {
+                return Err(UserError::InvalidInput(format!(
+                    "Unlock sync encryption for user {} before importing a keyring",
+                    msg.user_id
+                )));
+            }
+            self.save_keyring(&msg.user_id, &keyring).await?;
+            return Ok(None);
+        };
+        let Ok(mut keys) = UnlockedKeys::open_keyring(&msg.user_id, &keyring, current.kek.clone())
+        else {
+            debug_print!(
+                "Sync keyring changed elsewhere, waiting for {}",
+                msg.user_id
+            );
+            self.pending_imports.insert(msg.user_id, keyring);
+            return Ok(None);
+        };
+        keys.merge_into(&msg.user_id, &mut keyring, &current.keys)?;
+        self.save_keyring(&msg.user_id, &keyring).await?;
+
+        self.pending_imports.remove(&msg.user_id);
+        let key_id = keys.active_key_id.clone();
+        self.unlocked.insert(msg.user_id, keys);
+        Ok(Some(key_id))
+    }
+}
+
+// 동기화 종단간 암호화 액터
+// 데이터 관리자는 서버에 올리기 직전에 SealSyncItems로 항목을 암호화하고, 내려받은 뒤 OpenSyncItems로 푼다.
+// 데이터 키는 사용자마다 만들고 패스프레이즈에서 유도한 키(Argon2id)로 감싸 보관하므로
+// 서버와 Dart는 평문 항목도 데이터 키도 보지 못한다.
+// 암호화를 켰지만 잠금 해제 전인 사용자의 요청은 실패하므로, 데이터 관리자는 평문으로 올리지 않고 멈춘다.
+pub struct SyncCryptoActor {
+    keys: SyncKeys,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for SyncCryptoActor {}
+
+impl SyncCryptoActor {
+    pub fn new(self_addr: Address<Self>, storage: Address<StorageActor>) -> Self {
+        let owned_tasks = route_dart_signals!(
+            self_addr,
+            [
+                SetupSyncEncryptionRequest,
+                UnlockSyncEncryptionRequest,
+                LockSyncEncryptionRequest,
+                RotateSyncKeyRequest,
+                ChangeSyncPassphraseRequest,
+                ExportSyncKeyringRequest,
+                ImportSyncKeyringRequest
+            ]
+        );
+
+        Self {
+            keys: SyncKeys {
+                storage,
+                kdf: KdfParams::default(),
+                unlocked: HashMap::new(),
+                pending_imports: HashMap::new(),
+            },
+            _owned_tasks: owned_tasks,
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<SetupSyncEncryption> for SyncCryptoActor {
+    type Result = ActorResult<String>;
+
+    async fn handle(&mut self, msg: SetupSyncEncryption, _: &Context<Self>) -> Self::Result {
+        self.keys.setup(msg).await
+    }
+}
+
+#[async_trait]
+impl Handler<UnlockSyncEncryption> for SyncCryptoActor {
+    type Result = ActorResult<String>;
+
+    async fn handle(&mut self, msg: UnlockSyncEncryption, _: &Context<Self>) -> Self::Result {
+        self.keys.unlock(msg).await
+    }
+}
+
+#[async_trait]
+impl Handler<LockSyncEncryption> for SyncCryptoActor {
+    type Result = ActorResult<()>;
+
+    async fn handle(&mut self, msg: LockSyncEncryption, _: &Context<Self>) -> Self::Result {
+        self.keys.unlocked.remove(&msg.user_id);
+        self.keys.pending_imports.remove(&msg.user_id);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Handler<RotateSyncKey> for SyncCryptoActor {
+    type Result = ActorResult<String>;
+
+    async fn handle(&mut self, msg: RotateSyncKey, _: &Context<Self>) -> Self::Result {
+        self.keys.rotate(&msg.user_id).await
+    }
+}
+
+#[async_trait]
+impl Handler<ChangeSyncPassphrase> for SyncCryptoActor {
+    type Result = ActorResult<String>;
+
+    async fn handle(&mut self, msg: ChangeSyncPassphrase, _: &Context<Self>) -> Self::Result {
+        self.keys.change_passphrase(msg).await
+    }
+}
+
+#[async_trait]
+impl Handler<SealSyncItems> for SyncCryptoActor {
+    type Result = ActorResult<Vec<SyncPayload>>;
+
+    async fn handle(&mut self, msg: SealSyncItems, _: &Context<Self>) -> Self::Result {
+        self.keys.seal_items(msg).await
+    }
+}
+
+#[async_trait]
+impl Handler<OpenSyncItems> for SyncCryptoActor {
+    type Result = ActorResult<Vec<DataItem>>;
+
+    async fn handle(&mut self, msg: OpenSyncItems, _: &Context<Self>) -> Self::Result {
+        self.keys.open_items(msg).await
+    }
+}
+
+#[async_trait]
+impl Handler<ExportSyncKeyring> for SyncCryptoActor {
+    type Result = ActorResult<Vec<u8>>;
+
+    async fn handle(&mut self, msg: ExportSyncKeyring, _: &Context<Self>) -> Self::Result {
+        self.keys.export_keyring(&msg.user_id).await
+    }
+}
+
+#[async_trait]
+impl Handler<ImportSyncKeyring> for SyncCryptoActor {
+    type Result = ActorResult<Option<String>>;
+
+    async fn handle(&mut self, msg: ImportSyncKeyring, _: &Context<Self>) -> Self::Result {
+        self.keys.import_keyring(msg).await
+    }
+}
+
+#[async_trait]
+impl Handler<WipeUserData> for SyncCryptoActor {
+    type Result = ActorResult<()>;
+
+    async fn handle(&mut self, _: WipeUserData, _: &Context<Self>) -> Self::Result {
+        // 키 묶음은 영구 저장소와 함께 지워지므로 메모리의 키만 지운다
+        self.keys.unlocked.clear();
+        self.keys.pending_imports.clear();
+        Ok(())
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<SetupSyncEncryptionRequest> for SyncCryptoActor {
+    async fn notify(&mut self, msg: SetupSyncEncryptionRequest, ctx: &Context<Self>) {
+        let _ = respond_to_dart(msg, |msg| async move {
+            let setup = SetupSyncEncryption {
+                user_id: msg.user_id,
+                passphrase: msg.passphrase,
+            };
+            Ok(Some(self.handle(setup, ctx).await?))
+        })
+        .await;
+    }
+}
+
+#[async_trait]
+impl Notifiable<UnlockSyncEncryptionRequest> for SyncCryptoActor {
+    async fn notify(&mut self, msg: UnlockSyncEncryptionRequest, ctx: &Context<Self>) {
+        let _ = respond_to_dart(msg, |msg| async move {
+            let unlock = UnlockSyncEncryption {
+                user_id: msg.user_id,
+                passphrase: msg.passphrase,
+            };
+            Ok(Some(self.handle(unlock, ctx).await?))
+        })
+        .await;
+    }
+}
+
+#[async_trait]
+impl Notifiable<LockSyncEncryptionRequest> for SyncCryptoActor {
+    async fn notify(&mut self, msg: LockSyncEncryptionRequest, ctx: &Context<Self>) {
+        let _ = respond_to_dart(msg, |msg| async move {
+            let lock = LockSyncEncryption {
+                user_id: msg.user_id,
+            };
+            self.handle(lock, ctx).await?;
+            Ok(None)
+        })
+        .await;
+    }
+}
+
+#[async_trait]
+impl Notifiable<RotateSyncKeyRequest> for SyncCryptoActor {
+    async fn notify(&mut self, msg: RotateSyncKeyRequest, ctx: &Context<Self>) {
+        let _ = respond_to_dart(msg, |msg| async move {
+            let rotate = RotateSyncKey {
+                user_id: msg.user_id,
+            };
+            Ok(Some(self.handle(rotate, ctx).await?))
+        })
+        .await;
+    }
+}
+
+#[async_trait]
+impl Notifiable<ChangeSyncPassphraseRequest> for SyncCryptoActor {
+    async fn notify(&mut self, msg: ChangeSyncPassphraseRequest, ctx: &Context<Self>) {
+        let _ = respond_to_dart(msg, |msg| async move {
+            let change = ChangeSyncPassphrase {
+                user_id: msg.user_id,
+                new_passphrase: msg.new_passphrase,
+            };
+            Ok(Some(self.handle(change, ctx).await?))
+        })
+        .await;
+    }
+}
+
+#[async_trait]
+impl Notifiable<ExportSyncKeyringRequest> for SyncCryptoActor {
+    async fn notify(&mut self, msg: ExportSyncKeyringRequest, ctx: &Context<Self>) {
+        let _ = respond_to_dart(msg, |msg| async move {
+            let export = ExportSyncKeyring {
+                user_id: msg.user_id,
+            };
+            Ok(self.handle(export, ctx).await?)
+        })
+        .await;
+    }
+}
+
+#[async_trait]
+impl Notifiable<ImportSyncKeyringRequest> for SyncCryptoActor {
+    async fn notify(&mut self, msg: ImportSyncKeyringRequest, ctx: &Context<Self>) {
+        let _ = respond_to_dart(msg, |msg| async move {
+            let import = ImportSyncKeyring {
+                user_id: msg.user_id,
+                keyring: msg.keyring,
+            };
+            Ok(self.handle(import, ctx).await?)
+        })
+        .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use messages::prelude::Address;
+    use std::{collections::HashMap, error::Error, sync::Arc};
+
+    use super::{KdfParams, SyncKeyring, SyncKeys};
+    use crate::study_actors::{
+        actors::StorageActor,
+        messages::{
+            ChangeSyncPassphrase, DataItem, ImportSyncKeyring, OpenSyncItems, SealSyncItems,
+            SetupSyncEncryption, SyncPayload, UnlockSyncEncryption,
+        },
+        storage::MemoryStorage,
+        test_support::TestActorHarness,
+    };
+
+    fn storage() -> TestActorHarness<StorageActor> {
+        TestActorHarness::start(|_| StorageActor::new(Arc::new(MemoryStorage::new())))
+    }
+
+    fn sync_keys(storage: Address<StorageActor>) -> SyncKeys {
+        SyncKeys {
+            storage,
+            // 테스트가 느려지지 않도록 가장 낮은 비용을 쓴다
+            kdf: KdfParams {
+                memory_kib: 8,
+                iterations: 1,
+                parallelism: 1,
+            },
+            unlocked: HashMap::new(),
+            pending_imports: HashMap::new(),
+        }
+    }
+
+    fn item(id: &str) -> DataItem {
+        DataItem {
+            id: id.to_string(),
+            title: "secret title".to_string(),
+            content: "secret content".to_string(),
+            created_at: 1,
+            updated_at: 2,
+            tags: vec![],
+            attachments: vec![],
+            owner: "user_1".to_string(),
+        }
+    }
+
+    fn sealed(payloads: &[SyncPayload]) -> Vec<&[u8]> {
+        payloads
+            .iter()
+            .filter_map(|payload| match payload {
+                SyncPayload::Sealed(sealed) => Some(sealed.payload.as_slice()),
+                SyncPayload::Plain(_) => None,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn items_round_trip_across_rotation_and_passphrase_change() -> Result<(), Box<dyn Error>>
+    {
+        let storage = storage();
+        let mut keys = sync_keys(storage.addr());
+        let user = "alice".to_string();
+        keys.setup(SetupSyncEncryption {
+            user_id: user.clone(),
+            passphrase: "correct horse".to_string(),
+        })
+        .await?;
+
+        let seal = |ids: &[&str]| SealSyncItems {
+            user_id: user.clone(),
+            items: ids.iter().map(|id| item(id)).collect(),
+        };
+        let old = keys.seal_items(seal(&["a"])).await?;
+        assert_eq!(sealed(&old).len(), 1);
+        assert!(!String::from_utf8_lossy(sealed(&old)[0]).contains("secret"));
+
+        keys.rotate(&user).await?;
+        keys.change_passphrase(ChangeSyncPassphrase {
+            user_id: user.clone(),
+            new_passphrase: "battery staple".to_string(),
+        })
+        .await?;
+        let new = keys.seal_items(seal(&["b"])).await?;
+        let (SyncPayload::Sealed(old_item), SyncPayload::Sealed(new_item)) = (&old[0], &new[0])
+        else {
+            return Err("items were not sealed".into());
+        };
+        assert_ne!(old_item.key_id, new_item.key_id);
+
+        // 다른 기기: 묶음을 받아 새 패스프레이즈로 풀면 두 키로 올린 항목을 모두 푼다
+        let exported = keys.export_keyring(&user).await?;
+        let other_storage = self::storage();
+        let mut other = sync_keys(other_storage.addr());
+        other
+            .import_keyring(ImportSyncKeyring {
+                user_id: user.clone(),
+                keyring: exported,
+            })
+            .await?;
+        let unlock = |passphrase: &str| UnlockSyncEncryption {
+            user_id: user.clone(),
+            passphrase: passphrase.to_string(),
+        };
+        assert!(other.unlock(unlock("correct horse")).await.is_err());
+        other.unlock(unlock("battery staple")).await?;
+
+        let opened = other
+            .open_items(OpenSyncItems {
+                user_id: user.clone(),
+                items: [old.clone(), new].concat(),
+            })
+            .await?;
+        assert_eq!(opened.len(), 2);
+        assert_eq!(opened[0].content, "secret content");
+
+        // 다른 항목 자리로 옮긴 암호문은 풀리지 않는다
+        let mut moved = old_item.clone();
+        moved.id = "b".to_string();
+        let moved = OpenSyncItems {
+            user_id: user.clone(),
+            items: vec![SyncPayload::Sealed(moved)],
+        };
+        assert!(other.open_items(moved).await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn keyring_survives_restart_and_plaintext_follows_setup() -> Result<(), Box<dyn Error>> {
+        let storage = storage();
+        let user = "bob".to_string();
+        let plain = OpenSyncItems {
+            user_id: user.clone(),
+            items: vec![SyncPayload::Plain(item("a"))],
+        };
+
+        // 암호화를 켜기 전에는 평문으로 주고받는다
+        let mut keys = sync_keys(storage.addr());
+        let payloads = keys
+            .seal_items(SealSyncItems {
+                user_id: user.clone(),
+                items: vec![item("a")],
+            })
+            .await?;
+        assert!(matches!(payloads[..], [SyncPayload::Plain(_)]));
+        assert_eq!(keys.open_items(plain.clone()).await?.len(), 1);
+
+        keys.setup(SetupSyncEncryption {
+            user_id: user.clone(),
+            passphrase: "correct horse".to_string(),
+        })
+        .await?;
+
+        // 재시작: 키 묶음은 저장소에 남고, 잠금 해제 전에는 평문으로 올리지 않는다
+        let mut restarted = sync_keys(storage.addr());
+        let locked = SealSyncItems {
+            user_id: user.clone(),
+            items: vec![item("b")],
+        };
+        assert!(restarted.seal_items(locked.clone()).await.is_err());
+        restarted
+            .unlock(UnlockSyncEncryption {
+                user_id: user.clone(),
+                passphrase: "correct horse".to_string(),
+            })
+            .await?;
+        let payloads = restarted.seal_items(locked).await?;
+        assert_eq!(sealed(&payloads).len(), 1);
+
+        // 암호화를 켠 뒤 서버가 끼워 넣은 평문 항목은 버린다
+        assert!(restarted.open_items(plain).await?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn import_keeps_local_keys_and_rejects_weak_or_replayed_input()
+    -> Result<(), Box<dyn Error>> {
+        let user = "carol".to_string();
+        let unlock = |passphrase: &str| UnlockSyncEncryption {
+            user_id: user.clone(),
+            passphrase: passphrase.to_string(),
+        };
+        let import = |keyring: Vec<u8>| ImportSyncKeyring {
+            user_id: user.clone(),
+            keyring,
+        };
+        let seal = |id: &str| SealSyncItems {
+            user_id: user.clone(),
+            items: vec![item(id)],
+        };
+
+        let first_storage = storage();
+        let mut first = sync_keys(first_storage.addr());
+        first
+            .setup(SetupSyncEncryption {
+                user_id: user.clone(),
+                passphrase: "correct horse".to_string(),
+            })
+            .await?;
+        let old = first.seal_items(seal("a")).await?;
+
+        // 두 번째 기기는 묶음을 받아 잠금 해제한 뒤 이 기기에서만 키를 회전한다
+        let second_storage = storage();
+        let mut second = sync_keys(second_storage.addr());
+        second
+            .import_keyring(import(first.export_keyring(&user).await?))
+            .await?;
+        second.unlock(unlock("correct horse")).await?;
+        second.rotate(&user).await?;
+        let local_only = second.seal_items(seal("b")).await?;
+
+        // 첫 기기에서 패스프레이즈를 바꾼 묶음은 보류했다가 새 패스프레이즈로 풀 때 합친다
+        first
+            .change_passphrase(ChangeSyncPassphrase {
+                user_id: user.clone(),
+                new_passphrase: "battery staple".to_string(),
+            })
+            .await?;
+        let changed = first.export_keyring(&user).await?;
+        assert_eq!(second.import_keyring(import(changed)).await?, None);
+        assert!(second.unlock(unlock("wrong passphrase")).await.is_err());
+        second.unlock(unlock("battery staple")).await?;
+        let opened = second
+            .open_items(OpenSyncItems {
+                user_id: user.clone(),
+                items: [old.clone(), local_only].concat(),
+            })
+            .await?;
+        assert_eq!(opened.len(), 2);
+
+        // 재시작해도 합친 묶음이 남아 있다
+        let mut restarted = sync_keys(second_storage.addr());
+        restarted.unlock(unlock("battery staple")).await?;
+        assert_eq!(restarted.unlocked_keys(&user)?.keys.len(), 2);
+
+        // 예전 암호문을 더 새로운 시각으로 바꿔 돌려주면 풀리지 않는다
+        let SyncPayload::Sealed(mut replayed) = old[0].clone() else {
+            return Err("item was not sealed".into());
+        };
+        replayed.updated_at += 1;
+        let replayed = OpenSyncItems {
+            user_id: user.clone(),
+            items: vec![SyncPayload::Sealed(replayed)],
+        };
+        assert!(restarted.open_items(replayed).await.is_err());
+
+        // 키 유도 비용을 낮춘 묶음은 받지 않는다
+        let mut weak: SyncKeyring =
+            serde_json::from_slice(&restarted.export_keyring(&user).await?)?;
+        weak.kdf.iterations = 0;
+        let weak = serde_json::to_vec(&weak)?;
+        assert!(restarted.import_keyring(import(weak)).await.is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,376 @@
+use async_trait::async_trait;
+use calamine::{Data, Reader, open_workbook_auto};
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Notifiable},
+};
+use std::collections::HashMap;
+use tokio::{sync::mpsc, task::JoinSet};
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        CellKind, CellValue, ImportError, RowError, TabularFormat, TabularImportTarget, TabularRow,
+    },
+    signals::{
+        CreateDataItemRequest, EmitSignal, ParseTabularFileRequest, TabularParseCompletedSignal,
+        TabularRowsBatchSignal, route_dart_signals,
+    },
+};
+
+use super::{DataManagerActor, Priority, PriorityMailbox, Timed};
+
+const BATCH_SIZE: usize = 500;
+const CHANNEL_CAPACITY: usize = 4;
+
+// 블로킹 파서에서 비동기 태스크로 전달되는 배치
+struct TabularBatch {
+    headers: Vec<String>,
+    rows: Vec<TabularRow>,
+    errors: Vec<RowError>,
+}
+
+#[derive(Default)]
+struct ParseSummary {
+    total_rows: usize,
+    error_rows: usize,
+    column_kinds: Vec<HashMap<CellKind, usize>>,
+}
+
+impl ParseSummary {
+    // 열마다 가장 많이 나온 (빈 값 제외) 타입을 열 타입으로 추론
+    fn column_types(&self) -> Vec<CellKind> {
+        self.column_kinds
+            .iter()
+            .map(|counts| {
+                counts
+                    .iter()
+                    .filter(|(kind, _)| **kind != CellKind::Empty)
+                    .max_by_key(|(_, count)| **count)
+                    .map(|(kind, _)| *kind)
+                    .unwrap_or(CellKind::Empty)
+            })
+            .collect()
+    }
+}
+
+// 행을 모아 일정 크기마다 채널로 내보내는 도우미
+struct BatchWriter {
+    headers: Vec<String>,
+    rows: Vec<TabularRow>,
+    errors: Vec<RowError>,
+    sender: mpsc::Sender<TabularBatch>,
+    summary: ParseSummary,
+}
+
+impl BatchWriter {
+    fn new(sender: mpsc::Sender<TabularBatch>) -> Self {
+        Self {
+            headers: Vec::new(),
+            rows: Vec::new(),
+            errors: Vec::new(),
+            sender,
+            summary: ParseSummary::default(),
+        }
+    }
+
+    fn push_row(&mut self, row: TabularRow) -> Result<(), ImportError> {
+        if !self.headers.is_empty() && row.cells.len() != self.headers.len() {
+            return self.push_error(RowError {
+                row_number: row.row_number,
+                message: format!(
+                    "Expected {} columns but found {}",
+                    self.headers.len(),
+                    row.cells.len()
+                ),
+            });
+        }
+
+        for (index, cell) in row.cells.iter().enumerate() {
+            if self.summary.column_kinds.len() <= index {
+                self.summary.column_kinds.push(HashMap::new());
+            }
+            *self.summary.column_kinds[index]
+                .entry(cell.kind())
+                .or_insert(0) += 1;
+        }
+
+        self.summary.total_rows += 1;
+        self.rows.push(row);
+        self.flush_if_full()
+    }
+
+    fn push_error(&mut self, error: RowError) -> Result<(), ImportError> {
+        self.summary.total_rows += 1;
+        self.summary.error_rows += 1;
+        self.errors.push(error);
+        self.flush_if_full()
+    }
+
+    fn flush_if_full(&mut self) -> Result<(), ImportError> {
+        if self.rows.len() + self.errors.len() >= BATCH_SIZE {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), ImportError> {
+        if self.rows.is_empty() && self.errors.is_empty() {
+            return Ok(());
+        }
+        let batch = TabularBatch {
+            headers: self.headers.clone(),
+            rows: std::mem::take(&mut self.rows),
+            errors: std::mem::take(&mut self.errors),
+        };
+        self.sender
+            .blocking_send(batch)
+            .map_err(|_| "Parse cancelled: receiver dropped".into())
+    }
+
+    fn finish(mut self) -> Result<ParseSummary, ImportError> {
+        self.flush()?;
+        Ok(self.summary)
+    }
+}
+
+// 문자열 셀의 타입 추론
+pub fn infer_cell(raw: &str) -> CellValue {
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        return CellValue::Empty;
+    }
+    if trimmed.eq_ignore_ascii_case("true") {
+        return CellValue::Bool(true);
+    }
+    if trimmed.eq_ignore_ascii_case("false") {
+        return CellValue::Bool(false);
+    }
+    if let Ok(value) = trimmed.parse::<i64>() {
+        return CellValue::Int(value);
+    }
+    if let Ok(value) = trimmed.parse::<f64>()
+        && value.is_finite()
+    {
+        return CellValue::Float(value);
+    }
+    if chrono::DateTime::parse_from_rfc3339(trimmed).is_ok()
+        || chrono::NaiveDate::parse_from_str(trimmed, "%Y-%m-%d").is_ok()
+    {
+        return CellValue::DateTime(trimmed.to_string());
+    }
+    CellValue::Text(raw.to_string())
+}
+
+fn parse_csv(
+    path: &str,
+    has_header: bool,
+    mut writer: BatchWriter,
+) -> Result<ParseSummary, ImportError> {
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(has_header)
+        .flexible(true)
+        .from_path(path)?;
+
+    if has_header {
+        writer.headers = reader.headers()?.iter().map(str::to_string).collect();
+    }
+
+    let first_row = if has_header { 2 } else { 1 };
+    for (index, record) in reader.records().enumerate() {
+        let row_number = (index + first_row) as u64;
+        match record {
+            Ok(record) => writer.push_row(TabularRow {
+                row_number,
+                cells: record.iter().map(infer_cell).collect(),
+            })?,
+            Err(e) => writer.push_error(RowError {
+                row_number,
+                message: e.to_string(),
+            })?,
+        }
+    }
+
+    writer.finish()
+}
+
+fn convert_excel_cell(cell: &Data) -> Result<CellValue, String> {
+    Ok(match cell {
+        Data::Empty => CellValue::Empty,
+        Data::Bool(value) => CellValue::Bool(*value),
+        Data::Int(value) => CellValue::Int(*value),
+        Data::Float(value) => CellValue::Float(*value),
+        Data::String(value) => infer_cell(value),
+        Data::DateTime(value) => match value.as_datetime() {
+            Some(datetime) => CellValue::DateTime(datetime.to_string()),
+            None => CellValue::Float(value.as_f64()),
+        },
+        Data::DateTimeIso(value) | Data::DurationIso(value) => CellValue::DateTime(value.clone()),
+        Data::Error(e) => return Err(format!("Cell error: {:?}", e)),
+    })
+}
+
+fn parse_xlsx(
+    path: &str,
+    has_header: bool,
+    mut writer: BatchWriter,
+) -> Result<ParseSummary, ImportError> {
+    let mut workbook = open_workbook_auto(path)?;
+    let range = workbook
+        .worksheet_range_at(0)
+        .ok_or("Workbook has no sheets")??;
+
+    let mut rows = range.rows().enumerate();
+    if has_header && let Some((_, header_row)) = rows.next() {
+        writer.headers = header_row.iter().map(|cell| cell.to_string()).collect();
+    }
+
+    for (index, row) in rows {
+        let row_number = index as u64 + 1;
+        let cells: Result<Vec<CellValue>, String> = row.iter().map(convert_excel_cell).collect();
+        match cells {
+            Ok(cells) => writer.push_row(TabularRow { row_number, cells })?,
+            Err(message) => writer.push_error(RowError {
+                row_number,
+                message,
+            })?,
+        }
+    }
+
+    writer.finish()
+}
+
+// 표 형식 파일 가져오기 액터
+// 큰 파일도 UI를 막지 않도록 블로킹 스레드에서 파싱하고 배치 단위로 Dart에 전달한다.
+// 가져온 행은 화면 요청을 막지 않도록 데이터 관리자의 저우선 갈래로 보낸다.
+pub struct TabularImportActor {
+    data_manager: PriorityMailbox<DataManagerActor>,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for TabularImportActor {}
+
+impl TabularImportActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        data_manager: PriorityMailbox<DataManagerActor>,
+    ) -> Self {
+        let owned_tasks = route_dart_signals!(self_addr, [ParseTabularFileRequest]);
+
+        Self {
+            data_manager,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    async fn run_import(
+        data_manager: PriorityMailbox<DataManagerActor>,
+        msg: ParseTabularFileRequest,
+    ) {
+        let (sender, mut receiver) = mpsc::channel(CHANNEL_CAPACITY);
+        let writer = BatchWriter::new(sender);
+        let (path, format, has_header) = (msg.path.clone(), msg.format, msg.has_header);
+
+        let parser = tokio::task::spawn_blocking(move || match format {
+            TabularFormat::Csv => parse_csv(&path, has_header, writer),
+            TabularFormat::Xlsx => parse_xlsx(&path, has_header, writer),
+        });
+
+        let mut batch_index = 0;
+        let mut imported_items = 0;
+        while let Some(batch) = receiver.recv().await {
+            if let Some(target) = &msg.import_target {
+                imported_items += Self::import_rows(&data_manager, target, &batch.rows).await;
+            }
+
+            TabularRowsBatchSignal {
+                request_id: msg.request_id.clone(),
+                batch_index,
+                headers: batch.headers,
+                rows: batch.rows,
+                errors: batch.errors,
+            }
+            .emit();
+            batch_index += 1;
+        }
+
+        let result = match parser.await {
+            Ok(result) => result,
+            Err(e) => Err(e.into()),
+        };
+
+        match result {
+            Ok(summary) => {
+                debug_print!(
+                    "Parsed {} rows ({} errors) from {}",
+                    summary.total_rows,
+                    summary.error_rows,
+                    msg.path
+                );
+                TabularParseCompletedSignal {
+                    request_id: msg.request_id,
+                    total_rows: summary.total_rows as u64,
+                    error_rows: summary.error_rows as u64,
+                    column_types: summary.column_types(),
+                    imported_items,
+                    error: None,
+                }
+                .emit();
+            }
+            Err(e) => {
+                TabularParseCompletedSignal {
+                    request_id: msg.request_id,
+                    total_rows: 0,
+                    error_rows: 0,
+                    column_types: Vec::new(),
+                    imported_items,
+                    error: Some(e.to_string()),
+                }
+                .emit();
+            }
+        }
+    }
+
+    // 매핑된 열로 DataItem 생성 요청을 데이터 관리자에 전달
+    async fn import_rows(
+        data_manager: &PriorityMailbox<DataManagerActor>,
+        target: &TabularImportTarget,
+        rows: &[TabularRow],
+    ) -> u64 {
+        let mut imported = 0;
+        for row in rows {
+            let (Some(title), Some(content)) = (
+                row.cells.get(target.title_column as usize),
+                row.cells.get(target.content_column as usize),
+            ) else {
+                continue;
+            };
+
+            let request = CreateDataItemRequest {
+                user_id: target.user_id.clone(),
+                title: title.as_text(),
+                content: content.as_text(),
+                tags: Vec::new(),
+                temp_id: None,
+                idempotency_key: None,
+            };
+            if data_manager
+                .notify(Priority::Low, Timed::new(request))
+                .await
+                .is_ok()
+            {
+                imported += 1;
+            }
+        }
+        imported
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<ParseTabularFileRequest> for TabularImportActor {
+    async fn notify(&mut self, msg: ParseTabularFileRequest, _: &Context<Self>) {
+        self._owned_tasks
+            .spawn(Self::run_import(self.data_manager.clone(), msg));
+    }
+}
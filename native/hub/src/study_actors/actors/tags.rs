@@ -0,0 +1,212 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::study_actors::messages::{TagCount, UserError};
+
+const MAX_TAG_LEN: usize = 64;
+
+// 항목 ↔ 태그 양방향 색인
+// 저장은 항목 → 태그 방향만 하고, 태그 → 항목 방향은 불러올 때 다시 만든다.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(super) struct TagIndex {
+    item_tags: BTreeMap<String, BTreeSet<String>>,
+    #[serde(skip)]
+    tag_items: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl TagIndex {
+    pub(super) fn from_bytes(bytes: &[u8]) -> Result<Self, UserError> {
+        let mut index: Self = serde_json::from_slice(bytes)?;
+        index.rebuild();
+        Ok(index)
+    }
+
+    fn rebuild(&mut self) {
+        for (item_id, tags) in &self.item_tags {
+            for tag in tags {
+                self.tag_items
+                    .entry(tag.clone())
+                    .or_default()
+                    .insert(item_id.clone());
+            }
+        }
+    }
+
+    // 앞뒤 공백 제거 + 소문자화, 빈 태그와 너무 긴 태그는 거부
+    pub(super) fn normalize(tag: &str) -> Result<String, UserError> {
+        let tag = tag.trim().to_lowercase();
+        if tag.is_empty() {
+            return Err(UserError::InvalidInput("Tag must not be empty".to_string()));
+        }
+        if tag.chars().count() > MAX_TAG_LEN {
+            return Err(UserError::InvalidInput(format!(
+                "Tag longer than {} characters",
+                MAX_TAG_LEN
+            )));
+        }
+        Ok(tag)
+    }
+
+    // 새로 추가되었으면 true
+    pub(super) fn add(&mut self, item_id: &str, tag: &str) -> bool {
+        let added = self
+            .item_tags
+            .entry(item_id.to_string())
+            .or_default()
+            .insert(tag.to_string());
+        self.tag_items
+            .entry(tag.to_string())
+            .or_default()
+            .insert(item_id.to_string());
+        added
+    }
+
+    // 실제로 제거되었으면 true
+    pub(super) fn remove(&mut self, item_id: &str, tag: &str) -> bool {
+        let removed = match self.item_tags.get_mut(item_id) {
+            Some(tags) => {
+                let removed = tags.remove(tag);
+                if tags.is_empty() {
+                    self.item_tags.remove(item_id);
+                }
+                removed
+            }
+            None => false,
+        };
+        if let Some(items) = self.tag_items.get_mut(tag) {
+            items.remove(item_id);
+            if items.is_empty() {
+                self.tag_items.remove(tag);
+            }
+        }
+        removed
+    }
+
+    // 항목 삭제 시 모든 태그에서 제거
+    pub(super) fn remove_item(&mut self, item_id: &str) -> bool {
+        let Some(tags) = self.item_tags.remove(item_id) else {
+            return false;
+        };
+        for tag in tags {
+            if let Some(items) = self.tag_items.get_mut(&tag) {
+                items.remove(item_id);
+                if items.is_empty() {
+                    self.tag_items.remove(&tag);
+                }
+            }
+        }
+        true
+    }
+
+    pub(super) fn tags_of(&self, item_id: &str) -> Vec<String> {
+        self.item_tags
+            .get(item_id)
+            .map(|tags| tags.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    // 항목 ID 순으로 정렬되어 있어 페이지 구간이 요청마다 안정적이다
+    pub(super) fn items_with(&self, tag: &str) -> Vec<String> {
+        self.tag_items
+            .get(tag)
+            .map(|items| items.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    // 항목 id와 그 태그 (예전 전역 색인을 사용자별로 나눌 때 쓴다)
+    pub(super) fn entries(&self) -> impl Iterator<Item = (&String, &BTreeSet<String>)> {
+        self.item_tags.iter()
+    }
+
+    pub(super) fn counts(&self) -> Vec<TagCount> {
+        self.tag_items
+            .iter()
+            .map(|(tag, items)| TagCount {
+                tag: tag.clone(),
+                count: items.len() as u64,
+            })
+            .collect()
+    }
+}
+
+// 사용자별 태그 색인
+// 같은 태그를 써도 다른 사용자의 항목은 찾거나 세지 않는다.
+#[derive(Debug, Default)]
+pub(super) struct UserTagIndex {
+    users: BTreeMap<String, TagIndex>,
+}
+
+impl UserTagIndex {
+    pub(super) fn from_bytes(bytes: &[u8]) -> Result<Self, UserError> {
+        let mut users: BTreeMap<String, TagIndex> = serde_json::from_slice(bytes)?;
+        users.values_mut().for_each(TagIndex::rebuild);
+        Ok(Self { users })
+    }
+
+    pub(super) fn to_bytes(&self) -> Result<Vec<u8>, UserError> {
+        Ok(serde_json::to_vec(&self.users)?)
+    }
+
+    pub(super) fn user_mut(&mut self, user_id: &str) -> &mut TagIndex {
+        self.users.entry(user_id.to_string()).or_default()
+    }
+
+    pub(super) fn tags_of(&self, user_id: &str, item_id: &str) -> Vec<String> {
+        self.users
+            .get(user_id)
+            .map(|index| index.tags_of(item_id))
+            .unwrap_or_default()
+    }
+
+    pub(super) fn items_with(&self, user_id: &str, tag: &str) -> Vec<String> {
+        self.users
+            .get(user_id)
+            .map(|index| index.items_with(tag))
+            .unwrap_or_default()
+    }
+
+    pub(super) fn counts(&self, user_id: &str) -> Vec<TagCount> {
+        self.users
+            .get(user_id)
+            .map(TagIndex::counts)
+            .unwrap_or_default()
+    }
+
+    // 다른 사용자에게 넘긴 항목의 태그를 옮긴다 (옮긴 것이 있으면 true)
+    pub(super) fn transfer(&mut self, from: &str, to: &str) -> bool {
+        let Some(index) = self.users.remove(from) else {
+            return false;
+        };
+        let target = self.user_mut(to);
+        for (item_id, tags) in index.entries() {
+            for tag in tags {
+                target.add(item_id, tag);
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UserTagIndex;
+
+    #[test]
+    fn tags_are_kept_apart_per_user() -> Result<(), Box<dyn std::error::Error>> {
+        let mut index = UserTagIndex::default();
+        index.user_mut("alice").add("a", "work");
+        index.user_mut("bob").add("b", "work");
+        assert_eq!(index.items_with("alice", "work"), ["a"]);
+        assert_eq!(index.counts("bob")[0].count, 1);
+        assert!(index.tags_of("bob", "a").is_empty());
+
+        let restored = UserTagIndex::from_bytes(&index.to_bytes()?)?;
+        assert_eq!(restored.items_with("bob", "work"), ["b"]);
+
+        let mut index = restored;
+        assert!(index.transfer("bob", "alice"));
+        assert_eq!(index.items_with("alice", "work"), ["a", "b"]);
+        assert!(index.items_with("bob", "work").is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,367 @@
+use async_trait::async_trait;
+use handlebars::{Handlebars, no_escape};
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use std::{
+    collections::{HashMap, HashSet},
+    io,
+};
+use tokio::task::JoinSet;
+
+use crate::study_actors::{
+    messages::{
+        ActorResult, RegisterTemplate, RenderTemplate, TemplateConfig, TemplateFormat, UserError,
+    },
+    signals::{
+        RegisterTemplateRequest, RenderTemplateRequest, respond_to_dart, route_dart_signals,
+    },
+};
+
+// 부분 템플릿 참조 찾기
+// 이름을 렌더링할 때 정하는 동적 부분 템플릿({{> (식)}})과 인라인 정의({{#*inline}})는
+// 순환 여부를 미리 알 수 없으므로 받지 않는다 (순환하면 렌더링이 스택을 넘친다).
+fn partial_references(source: &str) -> ActorResult<Vec<String>> {
+    let mut names = Vec::new();
+    for tag in source.split("{{").skip(1) {
+        let tag = tag.trim_start_matches(['{', '~']);
+        let tag = tag.strip_prefix('#').unwrap_or(tag);
+        if tag.starts_with('*') {
+            return Err(UserError::InvalidInput(
+                "Inline partials and decorators are not allowed in templates".to_string(),
+            ));
+        }
+        let Some(rest) = tag.strip_prefix('>') else {
+            continue;
+        };
+        let rest = rest.trim_start();
+        if rest.starts_with('(') {
+            return Err(UserError::InvalidInput(
+                "Dynamic partials are not allowed in templates".to_string(),
+            ));
+        }
+        let name = rest
+            .split(|c: char| c.is_whitespace() || matches!(c, '}' | '~'))
+            .next()
+            .unwrap_or_default()
+            .trim_matches(['"', '\'', '[', ']']);
+        // @partial-block은 부분 템플릿을 부른 쪽의 블록이다
+        if !name.is_empty() && !name.starts_with('@') {
+            names.push(name.to_string());
+        }
+    }
+    Ok(names)
+}
+
+// 제한을 넘으면 쓰기를 실패시켜 렌더링을 바로 멈춘다
+struct LimitedOutput {
+    buffer: Vec<u8>,
+    limit: usize,
+    exceeded: bool,
+}
+
+impl io::Write for LimitedOutput {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.buffer.len() + buf.len() > self.limit {
+            self.exceeded = true;
+            return Err(io::Error::other("Template output limit exceeded"));
+        }
+        self.buffer.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+// 형식 하나의 템플릿 모음
+struct Registry {
+    handlebars: Handlebars<'static>,
+    partials: HashMap<String, Vec<String>>, // 템플릿 이름 → 가리키는 부분 템플릿 이름
+}
+
+impl Registry {
+    fn new(format: TemplateFormat, strict: bool) -> Self {
+        let mut handlebars = Handlebars::new();
+        handlebars.set_strict_mode(strict);
+        if format == TemplateFormat::Text {
+            handlebars.register_escape_fn(no_escape);
+        }
+        Self {
+            handlebars,
+            partials: HashMap::new(),
+        }
+    }
+
+    // name을 partials로 (다시) 등록하면 부분 템플릿을 따라가다 name으로 돌아오는지
+    // 기존 그래프에는 순환이 없으므로 새 순환은 항상 name을 지난다.
+    fn creates_cycle(&self, name: &str, partials: &[String]) -> bool {
+        let mut stack: Vec<&str> = partials.iter().map(String::as_str).collect();
+        let mut visited = HashSet::new();
+        while let Some(current) = stack.pop() {
+            if current == name {
+                return true;
+            }
+            if visited.insert(current)
+                && let Some(next) = self.partials.get(current)
+            {
+                stack.extend(next.iter().map(String::as_str));
+            }
+        }
+        false
+    }
+}
+
+// 등록한 템플릿 전체 (이름은 형식과 상관없이 하나만 쓸 수 있다)
+struct Templates {
+    html: Registry,
+    text: Registry,
+    formats: HashMap<String, TemplateFormat>,
+    config: TemplateConfig,
+}
+
+impl Templates {
+    fn new(config: TemplateConfig) -> Self {
+        Self {
+            html: Registry::new(TemplateFormat::Html, config.strict),
+            text: Registry::new(TemplateFormat::Text, config.strict),
+            formats: HashMap::new(),
+            config,
+        }
+    }
+
+    fn registry(&self, format: TemplateFormat) -> &Registry {
+        match format {
+            TemplateFormat::Html => &self.html,
+            TemplateFormat::Text => &self.text,
+        }
+    }
+
+    fn registry_mut(&mut self, format: TemplateFormat) -> &mut Registry {
+        match format {
+            TemplateFormat::Html => &mut self.html,
+            TemplateFormat::Text => &mut self.text,
+        }
+    }
+
+    fn register(&mut self, msg: RegisterTemplate) -> ActorResult<()> {
+        let name = msg.name.trim();
+        if name.is_empty() {
+            return Err(UserError::InvalidInput(
+                "Template name is required".to_string(),
+            ));
+        }
+        if msg.source.len() > self.config.max_template_bytes {
+            return Err(UserError::InvalidInput(format!(
+                "Template {} is larger than {} bytes",
+                name, self.config.max_template_bytes
+            )));
+        }
+        if !self.formats.contains_key(name) && self.formats.len() >= self.config.max_templates {
+            return Err(UserError::InvalidInput(format!(
+                "Too many templates (max {})",
+                self.config.max_templates
+            )));
+        }
+
+        let partials = partial_references(&msg.source)?;
+        let registry = self.registry_mut(msg.format);
+        if registry.creates_cycle(name, &partials) {
+            return Err(UserError::InvalidInput(format!(
+                "Template {} includes itself through partials",
+                name
+            )));
+        }
+        registry
+            .handlebars
+            .register_template_string(name, &msg.source)
+            .map_err(|e| UserError::InvalidInput(format!("Invalid template {}: {}", name, e)))?;
+        registry.partials.insert(name.to_string(), partials);
+
+        // 다른 형식으로 다시 등록하면 이전 형식 쪽은 지운다
+        if let Some(previous) = self.formats.insert(name.to_string(), msg.format)
+            && previous != msg.format
+        {
+            let registry = self.registry_mut(previous);
+            registry.handlebars.unregister_template(name);
+            registry.partials.remove(name);
+        }
+        Ok(())
+    }
+
+    fn render(&self, msg: &RenderTemplate) -> ActorResult<String> {
+        let format = self
+            .formats
+            .get(&msg.name)
+            .ok_or_else(|| UserError::NotFound(format!("template {}", msg.name)))?;
+        let mut output = LimitedOutput {
+            buffer: Vec::new(),
+            limit: self.config.max_output_bytes,
+            exceeded: false,
+        };
+        let result =
+            self.registry(*format)
+                .handlebars
+                .render_to_write(&msg.name, &msg.context, &mut output);
+        if output.exceeded {
+            return Err(UserError::InvalidInput(format!(
+                "Rendered template {} is larger than {} bytes",
+                msg.name, self.config.max_output_bytes
+            )));
+        }
+        result.map_err(|e| {
+            UserError::InvalidInput(format!("Failed to render template {}: {}", msg.name, e))
+        })?;
+        String::from_utf8(output.buffer)
+            .map_err(|e| UserError::InvalidInput(format!("Rendered template is not UTF-8: {}", e)))
+    }
+}
+
+// 템플릿 렌더링 액터
+// Dart가 등록한 Handlebars 템플릿으로 보고서와 보낼 메일/메시지 본문을 만든다.
+// Handlebars는 로직이 없는 문법이라 템플릿이 파일, 환경 변수, 네트워크에 닿을 수 없고
+// 반복은 컨텍스트 데이터 크기만큼만 돈다. 남은 위험(부분 템플릿 순환, 큰 출력)은 위에서 막는다.
+pub struct TemplateActor {
+    templates: Templates,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for TemplateActor {}
+
+impl TemplateActor {
+    pub fn new(self_addr: Address<Self>, config: TemplateConfig) -> Self {
+        Self {
+            templates: Templates::new(config),
+            _owned_tasks: route_dart_signals!(
+                self_addr,
+                [RegisterTemplateRequest, RenderTemplateRequest]
+            ),
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<RegisterTemplate> for TemplateActor {
+    type Result = ActorResult<()>;
+
+    async fn handle(&mut self, msg: RegisterTemplate, _: &Context<Self>) -> Self::Result {
+        self.templates.register(msg)
+    }
+}
+
+#[async_trait]
+impl Handler<RenderTemplate> for TemplateActor {
+    type Result = ActorResult<String>;
+
+    async fn handle(&mut self, msg: RenderTemplate, _: &Context<Self>) -> Self::Result {
+        self.templates.render(&msg)
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<RegisterTemplateRequest> for TemplateActor {
+    async fn notify(&mut self, msg: RegisterTemplateRequest, ctx: &Context<Self>) {
+        let _ = respond_to_dart(msg, |msg| async move {
+            let register = RegisterTemplate {
+                name: msg.name,
+                source: msg.source,
+                format: msg.format,
+            };
+            Ok(self.handle(register, ctx).await?)
+        })
+        .await;
+    }
+}
+
+#[async_trait]
+impl Notifiable<RenderTemplateRequest> for TemplateActor {
+    async fn notify(&mut self, msg: RenderTemplateRequest, ctx: &Context<Self>) {
+        let _ = respond_to_dart(msg, |msg| async move {
+            let context = serde_json::from_str(&msg.context_json).map_err(|e| {
+                UserError::InvalidInput(format!("context_json is not valid JSON: {}", e))
+            })?;
+            let render = RenderTemplate {
+                name: msg.name,
+                context,
+            };
+            Ok(self.handle(render, ctx).await?)
+        })
+        .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use std::error::Error;
+
+    use super::Templates;
+    use crate::study_actors::messages::{
+        RegisterTemplate, RenderTemplate, TemplateConfig, TemplateFormat,
+    };
+
+    fn template(name: &str, source: &str, format: TemplateFormat) -> RegisterTemplate {
+        RegisterTemplate {
+            name: name.to_string(),
+            source: source.to_string(),
+            format,
+        }
+    }
+
+    #[test]
+    fn renders_partials_and_rejects_cycles_and_large_output() -> Result<(), Box<dyn Error>> {
+        let mut templates = Templates::new(TemplateConfig {
+            max_output_bytes: 64,
+            ..TemplateConfig::default()
+        });
+        templates.register(template("footer", "-- {{team}}", TemplateFormat::Text))?;
+        templates.register(template(
+            "mail",
+            "Hi {{name}}\n{{> footer}}",
+            TemplateFormat::Text,
+        ))?;
+        templates.register(template("page", "<p>{{name}}</p>", TemplateFormat::Html))?;
+
+        let context = json!({ "name": "<Kim>", "team": "Ops" });
+        let mail = RenderTemplate {
+            name: "mail".to_string(),
+            context: context.clone(),
+        };
+        assert_eq!(templates.render(&mail)?, "Hi <Kim>\n-- Ops");
+        let page = RenderTemplate {
+            name: "page".to_string(),
+            context,
+        };
+        assert_eq!(templates.render(&page)?, "<p>&lt;Kim&gt;</p>");
+
+        assert!(
+            templates
+                .register(template("footer", "{{> mail}}", TemplateFormat::Text))
+                .is_err()
+        );
+        assert!(
+            templates
+                .register(template(
+                    "loop",
+                    "{{> (lookup . \"x\")}}",
+                    TemplateFormat::Text
+                ))
+                .is_err()
+        );
+
+        templates.register(template(
+            "list",
+            "{{#each items}}{{this}}{{/each}}",
+            TemplateFormat::Text,
+        ))?;
+        let list = RenderTemplate {
+            name: "list".to_string(),
+            context: json!({ "items": vec!["0123456789"; 10] }),
+        };
+        assert!(templates.render(&list).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,300 @@
+use async_trait::async_trait;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use serde::{Deserialize, Serialize};
+use tokio::task::JoinSet;
+use whatlang::Lang;
+
+use crate::study_actors::{
+    messages::{
+        AnalyzeText, TextAnalysis, TextAnalysisConfig, TextAnalysisError, TextAnnotation, TextStats,
+    },
+    signals::{AnalyzeTextRequest, EmitSignal, TextAnalysisSignal, route_dart_signals},
+};
+
+use super::{WorkerJob, offload};
+
+const SENTENCE_ENDINGS: [char; 6] = ['.', '!', '?', '。', '！', '？'];
+
+// 텍스트 분석 액터
+// 긴 본문과 사전 검사는 offload로 넘겨 메일박스와 화면을 막지 않는다.
+pub struct TextAnalysisActor {
+    config: TextAnalysisConfig,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for TextAnalysisActor {}
+
+impl TextAnalysisActor {
+    pub fn new(self_addr: Address<Self>, config: TextAnalysisConfig) -> Self {
+        Self {
+            config,
+            _owned_tasks: route_dart_signals!(self_addr, [AnalyzeTextRequest]),
+        }
+    }
+
+    fn job(&self, msg: AnalyzeText) -> TextAnalysisJob {
+        TextAnalysisJob {
+            text: msg.text,
+            language: msg.language,
+            spell_check: msg.spell_check,
+            dictionary_dir: self.config.dictionary_dir.clone(),
+            words_per_minute: self.config.words_per_minute,
+            max_suggestions: self.config.max_suggestions,
+        }
+    }
+}
+
+// 분석 본문 (offload로 실행)
+#[derive(Serialize, Deserialize)]
+pub(super) struct TextAnalysisJob {
+    text: String,
+    language: Option<String>,
+    spell_check: bool,
+    dictionary_dir: Option<String>,
+    words_per_minute: u32,
+    max_suggestions: usize,
+}
+
+impl WorkerJob for TextAnalysisJob {
+    const NAME: &'static str = "text/analyze";
+    type Output = TextAnalysis;
+
+    fn run(self) -> Self::Output {
+        let detected = whatlang::detect(&self.text);
+        let detected_language = detected
+            .as_ref()
+            .filter(|info| info.is_reliable())
+            .map(|info| language_code(info.lang()));
+        let language = self.language.clone().or(detected_language.clone());
+
+        let annotations = match (&language, &self.dictionary_dir) {
+            (Some(language), Some(dir)) if self.spell_check => {
+                spelling::check(&self.text, dir, language, self.max_suggestions)
+            }
+            _ => None,
+        };
+
+        let words = self
+            .text
+            .split_whitespace()
+            .filter(|token| token.chars().any(char::is_alphanumeric))
+            .count() as u32;
+        TextAnalysis {
+            stats: TextStats {
+                language: detected_language,
+                language_confidence: detected.as_ref().map_or(0.0, |info| info.confidence()),
+                words,
+                characters: self.text.chars().count() as u32,
+                characters_no_spaces: self.text.chars().filter(|c| !c.is_whitespace()).count()
+                    as u32,
+                sentences: count_sentences(&self.text, words),
+                reading_time_secs: (words * 60).div_ceil(self.words_per_minute.max(1)),
+            },
+            spell_checked: annotations.is_some(),
+            annotations: annotations.unwrap_or_default(),
+        }
+    }
+}
+
+// ISO 639-1 코드 (사전 파일 이름과 맞춘다, 표에 없으면 whatlang의 639-3 코드)
+fn language_code(lang: Lang) -> String {
+    let code = match lang {
+        Lang::Eng => "en",
+        Lang::Kor => "ko",
+        Lang::Jpn => "ja",
+        Lang::Cmn => "zh",
+        Lang::Deu => "de",
+        Lang::Fra => "fr",
+        Lang::Spa => "es",
+        Lang::Ita => "it",
+        Lang::Por => "pt",
+        Lang::Nld => "nl",
+        Lang::Rus => "ru",
+        Lang::Ukr => "uk",
+        Lang::Pol => "pl",
+        Lang::Tur => "tr",
+        Lang::Vie => "vi",
+        Lang::Tha => "th",
+        Lang::Ind => "id",
+        other => other.code(),
+    };
+    code.to_string()
+}
+
+// 문장 끝 기호 다음이 공백이거나 본문 끝이면 한 문장으로 센다 (끝 기호가 없어도 단어가 있으면 한 문장)
+fn count_sentences(text: &str, words: u32) -> u32 {
+    let mut chars = text.chars().peekable();
+    let mut sentences = 0;
+    while let Some(c) = chars.next() {
+        if SENTENCE_ENDINGS.contains(&c) && chars.peek().is_none_or(|next| next.is_whitespace()) {
+            sentences += 1;
+        }
+    }
+    if sentences == 0 && words > 0 {
+        1
+    } else {
+        sentences
+    }
+}
+
+// 단어 위치 (UTF-16 코드 단위)
+#[cfg(feature = "spellcheck")]
+struct Word<'a> {
+    text: &'a str,
+    start: usize,
+    end: usize,
+}
+
+// 글자와 단어 안쪽 아포스트로피를 한 단어로 묶는다 (숫자, 기호에서 끊는다)
+#[cfg(feature = "spellcheck")]
+fn words(text: &str) -> Vec<Word<'_>> {
+    let mut words = Vec::new();
+    let mut current: Option<(usize, usize)> = None;
+    let mut offset = 0;
+    for (index, c) in text.char_indices() {
+        let in_word = c.is_alphabetic() || (current.is_some() && matches!(c, '\'' | '’'));
+        match (in_word, current) {
+            (true, None) => current = Some((index, offset)),
+            (false, Some((start, start_offset))) => {
+                words.push(word(&text[start..index], start_offset));
+                current = None;
+            }
+            _ => {}
+        }
+        offset += c.len_utf16();
+    }
+    if let Some((start, start_offset)) = current {
+        words.push(word(&text[start..], start_offset));
+    }
+    words
+}
+
+#[cfg(feature = "spellcheck")]
+fn word(text: &str, start: usize) -> Word<'_> {
+    let text = text.trim_end_matches(['\'', '’']);
+    Word {
+        text,
+        start,
+        end: start + text.encode_utf16().count(),
+    }
+}
+
+#[cfg(feature = "spellcheck")]
+mod spelling {
+    use spellbook::Dictionary;
+    use std::{
+        collections::HashMap,
+        sync::{Arc, LazyLock, Mutex},
+    };
+
+    use super::{TextAnnotation, words};
+    use crate::study_actors::logging::debug_print;
+
+    // 사전은 "디렉터리/언어"마다 처음 쓸 때 한 번만 읽는다 (없는 사전도 기억해 다시 찾지 않는다)
+    static DICTIONARIES: LazyLock<Mutex<HashMap<String, Option<Arc<Dictionary>>>>> =
+        LazyLock::new(Mutex::default);
+
+    pub(super) fn check(
+        text: &str,
+        dir: &str,
+        language: &str,
+        max_suggestions: usize,
+    ) -> Option<Vec<TextAnnotation>> {
+        let dictionary = dictionary(dir, language)?;
+        let annotations = words(text)
+            .into_iter()
+            // 한 글자 단어와 약어(모두 대문자)는 건너뛴다
+            .filter(|word| {
+                word.text.chars().nth(1).is_some() && word.text.chars().any(char::is_lowercase)
+            })
+            .filter(|word| !dictionary.check(word.text))
+            .map(|word| {
+                let mut suggestions = Vec::new();
+                dictionary.suggest(word.text, &mut suggestions);
+                suggestions.truncate(max_suggestions);
+                TextAnnotation {
+                    start: word.start as u32,
+                    end: word.end as u32,
+                    word: word.text.to_string(),
+                    suggestions,
+                }
+            })
+            .collect();
+        Some(annotations)
+    }
+
+    // "en_US"에 맞는 사전이 없으면 "en"도 찾아본다
+    fn dictionary(dir: &str, language: &str) -> Option<Arc<Dictionary>> {
+        let mut cache = DICTIONARIES.lock().ok()?;
+        cache
+            .entry(format!("{}/{}", dir, language))
+            .or_insert_with(|| {
+                let base = language.split(['_', '-']).next().unwrap_or(language);
+                load(dir, language).or_else(|| load(dir, base))
+            })
+            .clone()
+    }
+
+    fn load(dir: &str, name: &str) -> Option<Arc<Dictionary>> {
+        let aff = std::fs::read_to_string(format!("{}/{}.aff", dir, name)).ok()?;
+        let dic = std::fs::read_to_string(format!("{}/{}.dic", dir, name)).ok()?;
+        match Dictionary::new(&aff, &dic) {
+            Ok(dictionary) => Some(Arc::new(dictionary)),
+            Err(e) => {
+                debug_print!("Invalid spelling dictionary {}: {}", name, e);
+                None
+            }
+        }
+    }
+}
+
+// spellcheck 기능을 끄고 빌드하면 맞춤법 검사는 하지 않는다
+#[cfg(not(feature = "spellcheck"))]
+mod spelling {
+    use super::TextAnnotation;
+
+    pub(super) fn check(
+        _text: &str,
+        _dir: &str,
+        _language: &str,
+        _max_suggestions: usize,
+    ) -> Option<Vec<TextAnnotation>> {
+        None
+    }
+}
+
+#[async_trait]
+impl Handler<AnalyzeText> for TextAnalysisActor {
+    type Result = Result<TextAnalysis, TextAnalysisError>;
+
+    async fn handle(&mut self, msg: AnalyzeText, _: &Context<Self>) -> Self::Result {
+        Ok(offload(self.job(msg)).await?)
+    }
+}
+
+// Dart 신호 처리 (분석하는 동안 다음 요청을 받을 수 있게 작업으로 띄운다)
+#[async_trait]
+impl Notifiable<AnalyzeTextRequest> for TextAnalysisActor {
+    async fn notify(&mut self, msg: AnalyzeTextRequest, _: &Context<Self>) {
+        let job = self.job(AnalyzeText {
+            text: msg.text,
+            language: msg.language,
+            spell_check: msg.spell_check,
+        });
+        self._owned_tasks.spawn(async move {
+            let (analysis, error) = match offload(job).await {
+                Ok(analysis) => (Some(analysis), None),
+                Err(e) => (None, Some(e.to_string())),
+            };
+            TextAnalysisSignal {
+                request_id: msg.request_id,
+                analysis,
+                error,
+            }
+            .emit();
+        });
+    }
+}
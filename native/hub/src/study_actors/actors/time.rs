@@ -0,0 +1,282 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use reqwest::Method;
+use std::{
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+#[cfg(not(target_family = "wasm"))]
+use tokio::net::UdpSocket;
+use tokio::task::JoinSet;
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{ClockSource, ScheduledTask, SyncClock, TimeConfig, TimeError},
+    signals::{ClockDriftSignal, EmitSignal, SyncClockRequest, route_dart_signals},
+};
+
+use super::{Clock, Instant, NetworkManagerActor, Priority, PriorityMailbox};
+use super::network::NetworkRequest;
+
+#[cfg(not(target_family = "wasm"))]
+const NTP_PACKET_LEN: usize = 48;
+#[cfg(not(target_family = "wasm"))]
+const NTP_UNIX_EPOCH_DELTA_SECS: i64 = 2_208_988_800; // 1900-01-01 ~ 1970-01-01
+#[cfg(not(target_family = "wasm"))]
+const NTP_TIMEOUT: Duration = Duration::from_secs(5);
+
+// 동기화 시점의 신뢰 시각과 단조 시계 기준점
+// 네이티브에서는 tokio Instant를 쓰므로 테스트에서 시간을 멈추고 앞당기면 신뢰 시각도 함께 움직인다.
+#[derive(Clone, Copy)]
+struct ClockAnchor {
+    instant: Instant,
+    trusted_millis: i64,
+    synced: bool, // 한 번도 동기화하지 않았으면 기기 시계를 그대로 쓴다
+}
+
+// 신뢰할 수 있는 현재 시각
+// 기준점 이후로는 단조 시계로 경과 시간을 더하므로 사용자가 기기 시계를 바꿔도 영향을 받지 않는다.
+// 경과 시간과 주기 작업은 주입된 Clock을 따르므로 시뮬레이션에서는 가상 시간으로 움직인다.
+#[derive(Clone)]
+pub struct TrustedClock {
+    anchor: Arc<RwLock<ClockAnchor>>,
+    clock: Clock,
+}
+
+impl Default for TrustedClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TrustedClock {
+    pub fn new() -> Self {
+        Self::with_clock(Clock::system())
+    }
+
+    pub fn with_clock(clock: Clock) -> Self {
+        Self {
+            anchor: Arc::new(RwLock::new(ClockAnchor {
+                instant: clock.now(),
+                trusted_millis: Utc::now().timestamp_millis(),
+                synced: false,
+            })),
+            clock,
+        }
+    }
+
+    // 주기 작업에 넘겨줄 시계
+    pub fn clock(&self) -> &Clock {
+        &self.clock
+    }
+
+    pub fn now_millis(&self) -> i64 {
+        match self.anchor.read() {
+            Ok(anchor) => {
+                let elapsed = self.clock.now().saturating_duration_since(anchor.instant);
+                anchor.trusted_millis + elapsed.as_millis() as i64
+            }
+            Err(_) => Utc::now().timestamp_millis(),
+        }
+    }
+
+    // 유닉스 타임스탬프 (초)
+    pub fn trusted_now(&self) -> u64 {
+        (self.now_millis() / 1000).max(0) as u64
+    }
+
+    // 신뢰 시각 - 기기 시각
+    pub fn offset_millis(&self) -> i64 {
+        self.now_millis() - Utc::now().timestamp_millis()
+    }
+
+    // NTP나 서버 시각으로 한 번이라도 맞췄는지 (아니면 offset_millis는 의미가 없다)
+    pub fn is_synced(&self) -> bool {
+        self.anchor.read().is_ok_and(|anchor| anchor.synced)
+    }
+
+    // received_at에 서버 시각이 trusted_millis였다면, 그 이후 흐른 만큼 더해 지금을 기준점으로 삼는다
+    fn set_anchor(&self, received_at: Instant, trusted_millis: i64) {
+        let since_received = received_at.elapsed().as_millis() as i64;
+        if let Ok(mut anchor) = self.anchor.write() {
+            *anchor = ClockAnchor {
+                instant: self.clock.now(),
+                trusted_millis: trusted_millis + since_received,
+                synced: true,
+            };
+        }
+    }
+}
+
+// 시간 동기화 액터
+pub struct TimeActor {
+    clock: TrustedClock,
+    network_manager: PriorityMailbox<NetworkManagerActor>,
+    config: TimeConfig,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for TimeActor {}
+
+impl TimeActor {
+    pub fn new(
+        self_addr: Address<Self>,
+        network_manager: PriorityMailbox<NetworkManagerActor>,
+        clock: TrustedClock,
+        config: TimeConfig,
+    ) -> Self {
+        let mut owned_tasks = route_dart_signals!(self_addr, [SyncClockRequest]);
+        owned_tasks.spawn(Self::periodic_sync(
+            self_addr,
+            clock.clock().clone(),
+            config.sync_interval_secs,
+        ));
+
+        Self {
+            clock,
+            network_manager,
+            config,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    // 시작 직후 한 번, 이후 설정된 주기마다 동기화
+    // 기기 절전 중에는 단조 시계가 멈출 수 있으므로 주기적인 재동기화가 필요하다.
+    async fn periodic_sync(mut self_addr: Address<Self>, clock: Clock, interval_secs: u64) {
+        let mut interval = clock
+            .interval(Duration::from_secs(interval_secs.max(1)))
+            .throttled(ScheduledTask::ClockSync);
+        loop {
+            interval.tick().await;
+            let _ = self_addr.notify(SyncClockRequest {}).await;
+        }
+    }
+
+    // SNTP 질의: 왕복 지연의 절반을 보정한 (수신 시점 Instant, 서버 시각 ms) 반환
+    #[cfg(not(target_family = "wasm"))]
+    async fn query_ntp(server: &str) -> Result<(Instant, i64), TimeError> {
+        let socket = UdpSocket::bind("0.0.0.0:0").await?;
+        socket.connect(server).await?;
+
+        let mut request = [0u8; NTP_PACKET_LEN];
+        request[0] = 0x1B; // LI = 0, VN = 3, Mode = 3 (client)
+
+        let sent_at = Instant::now();
+        socket.send(&request).await?;
+        let mut response = [0u8; NTP_PACKET_LEN];
+        let received = tokio::time::timeout(NTP_TIMEOUT, socket.recv(&mut response)).await??;
+        let received_at = Instant::now();
+        if received < NTP_PACKET_LEN {
+            return Err("Short NTP response".into());
+        }
+
+        // 전송 타임스탬프 (bytes 40..48): 1900년 기준 초 + 2^-32 단위 소수부
+        let seconds = u32::from_be_bytes([response[40], response[41], response[42], response[43]]);
+        let fraction = u32::from_be_bytes([response[44], response[45], response[46], response[47]]);
+        if seconds == 0 {
+            return Err("NTP server returned an empty timestamp".into());
+        }
+
+        let server_millis = (seconds as i64 - NTP_UNIX_EPOCH_DELTA_SECS) * 1000
+            + ((fraction as u64 * 1000) >> 32) as i64;
+        let half_round_trip = (received_at - sent_at).as_millis() as i64 / 2;
+        Ok((received_at, server_millis + half_round_trip))
+    }
+
+    // 브라우저는 UDP 소켓을 열 수 없으므로 웹에서는 항상 Date 헤더로 대체한다
+    #[cfg(target_family = "wasm")]
+    async fn query_ntp(_server: &str) -> Result<(Instant, i64), TimeError> {
+        Err("NTP is not available on the web".into())
+    }
+
+    // NTP를 쓸 수 없는 환경에서 서버 응답의 Date 헤더로 대체 (초 단위 정밀도)
+    async fn query_http_date(&mut self, url: &str) -> Result<(Instant, i64), TimeError> {
+        let sent_at = Instant::now();
+        let response = self
+            .network_manager
+            .request(Priority::Low, NetworkRequest::new(url).method(Method::HEAD).timeout(5_000))
+            .await??;
+        let received_at = Instant::now();
+
+        let date = response
+            .headers
+            .get(reqwest::header::DATE)
+            .and_then(|value| value.to_str().ok())
+            .ok_or("Response has no Date header")?;
+        let server_millis = DateTime::parse_from_rfc2822(date)?.timestamp_millis();
+        let half_round_trip = (received_at - sent_at).as_millis() as i64 / 2;
+        Ok((received_at, server_millis + half_round_trip))
+    }
+
+    async fn sync(&mut self) -> Result<(i64, ClockSource), TimeError> {
+        let mut last_error: TimeError = "No time source configured".into();
+
+        if let Some(server) = self.config.ntp_server.clone() {
+            match Self::query_ntp(&server).await {
+                Ok((instant, millis)) => {
+                    self.clock.set_anchor(instant, millis);
+                    return Ok((self.clock.offset_millis(), ClockSource::Ntp));
+                }
+                Err(e) => {
+                    debug_print!("NTP sync via {} failed: {}", server, e);
+                    last_error = e;
+                }
+            }
+        }
+
+        if let Some(url) = self.config.time_url.clone() {
+            match self.query_http_date(&url).await {
+                Ok((instant, millis)) => {
+                    self.clock.set_anchor(instant, millis);
+                    return Ok((self.clock.offset_millis(), ClockSource::HttpDate));
+                }
+                Err(e) => {
+                    debug_print!("HTTP Date sync via {} failed: {}", url, e);
+                    last_error = e;
+                }
+            }
+        }
+
+        Err(last_error)
+    }
+}
+
+#[async_trait]
+impl Handler<SyncClock> for TimeActor {
+    type Result = Result<i64, TimeError>;
+
+    async fn handle(&mut self, _: SyncClock, _: &Context<Self>) -> Self::Result {
+        self.sync().await.map(|(offset, _)| offset)
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<SyncClockRequest> for TimeActor {
+    async fn notify(&mut self, _: SyncClockRequest, _: &Context<Self>) {
+        let (source, error) = match self.sync().await {
+            Ok((_, source)) => (source, None),
+            // 동기화 실패 시 기존 기준점(마지막 동기화 또는 기기 시계)을 유지
+            Err(e) => (ClockSource::Device, Some(e.to_string())),
+        };
+
+        let offset_ms = self.clock.offset_millis();
+        let exceeds_warning = offset_ms.abs() > self.config.drift_warning_ms;
+        if exceeds_warning {
+            debug_print!("Device clock drift detected: {} ms", offset_ms);
+        }
+
+        ClockDriftSignal {
+            offset_ms,
+            source,
+            synced_at: self.clock.trusted_now(),
+            exceeds_warning,
+            error,
+        }
+        .emit();
+    }
+}
@@ -0,0 +1,293 @@
+use std::{
+    cell::Cell,
+    collections::VecDeque,
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::{
+        Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+    task::{Context, Poll},
+};
+
+use crate::study_actors::logging::{debug_print, trace_print};
+use crate::study_actors::messages::TraceHop;
+
+use super::Instant;
+
+// 최근 추적만 보관한다 (오래된 것부터 버림)
+const MAX_TRACES: usize = 256;
+const MAX_HOPS_PER_TRACE: usize = 64;
+
+static NEXT_TRACE_ID: AtomicU64 = AtomicU64::new(1);
+static TRACES: Mutex<VecDeque<TraceRecord>> = Mutex::new(VecDeque::new());
+
+thread_local! {
+    // 지금 폴링 중인 작업이 속한 추적 (Scoped가 폴링하는 동안에만 설정된다)
+    static CURRENT_TRACE: Cell<Option<TraceId>> = const { Cell::new(None) };
+}
+
+struct TraceRecord {
+    id: TraceId,
+    started: Instant,
+    hops: Vec<TraceHop>,
+}
+
+// Dart 신호 하나에서 시작된 액터 간 메시지 흐름을 묶는 ID
+// 예: ProcessLogin → Login(UserManager) → Login(Auth) → GetProfile → FetchRecentData → ScanPrefix
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TraceId(u64);
+
+impl fmt::Display for TraceId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "trace-{}", self.0)
+    }
+}
+
+impl From<TraceId> for u64 {
+    fn from(id: TraceId) -> Self {
+        id.0
+    }
+}
+
+impl TraceId {
+    // 처리 중인 추적이 있으면 이어받고, 없으면(Dart 신호에서 시작) 새로 연다
+    pub fn current_or_begin() -> Self {
+        Self::current().unwrap_or_else(Self::begin)
+    }
+
+    pub fn current() -> Option<Self> {
+        CURRENT_TRACE.get()
+    }
+
+    fn begin() -> Self {
+        let id = Self(NEXT_TRACE_ID.fetch_add(1, Ordering::Relaxed));
+        if let Ok(mut traces) = TRACES.lock() {
+            if traces.len() >= MAX_TRACES {
+                traces.pop_front();
+            }
+            traces.push_back(TraceRecord {
+                id,
+                started: Instant::now(),
+                hops: Vec::new(),
+            });
+        }
+        id
+    }
+
+    // 이 추적 안에서 작업을 실행하고 한 단계(hop)로 기록한다
+    // 작업 중에 Traced::new로 보내는 메시지는 같은 추적을 이어받는다.
+    pub async fn run<F>(self, actor: &'static str, message: &'static str, work: F) -> F::Output
+    where
+        F: Future,
+        F::Output: HopOutcome,
+    {
+        let started = Instant::now();
+        let output = Scoped {
+            trace: self,
+            work: Box::pin(work),
+        }
+        .await;
+        self.record(actor, message, started, output.error());
+        output
+    }
+
+    fn record(self, actor: &str, message: &str, started: Instant, error: Option<String>) {
+        let duration_ms = started.elapsed().as_secs_f64() * 1000.0;
+        // 성공한 단계는 메시지마다 찍히므로 로그 상세도가 Trace일 때만 남긴다
+        match &error {
+            Some(error) => debug_print!(
+                "[{}] {} {} failed after {:.2}ms: {}",
+                self,
+                actor,
+                message,
+                duration_ms,
+                error
+            ),
+            None => trace_print!("[{}] {} {} {:.2}ms", self, actor, message, duration_ms),
+        }
+
+        let Ok(mut traces) = TRACES.lock() else {
+            return;
+        };
+        let Some(trace) = traces.iter_mut().find(|trace| trace.id == self) else {
+            return;
+        };
+        if trace.hops.len() < MAX_HOPS_PER_TRACE {
+            trace.hops.push(TraceHop {
+                actor: actor.to_string(),
+                message: message.to_string(),
+                start_ms: started
+                    .saturating_duration_since(trace.started)
+                    .as_secs_f64()
+                    * 1000.0,
+                duration_ms,
+                error,
+            });
+        }
+    }
+}
+
+// 작업을 폴링하는 동안만 현재 추적을 설정한다
+// 작업 스레드가 여럿이어도 폴링 단위로 설정·복원하므로 다른 작업으로 새지 않는다 (웹에서도 동작).
+struct Scoped<F> {
+    trace: TraceId,
+    work: Pin<Box<F>>,
+}
+
+impl<F: Future> Future for Scoped<F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let _restore = RestoreTrace(CURRENT_TRACE.replace(Some(self.trace)));
+        self.work.as_mut().poll(cx)
+    }
+}
+
+// 작업이 패닉해도 이전 추적으로 되돌린다
+struct RestoreTrace(Option<TraceId>);
+
+impl Drop for RestoreTrace {
+    fn drop(&mut self) {
+        CURRENT_TRACE.set(self.0);
+    }
+}
+
+// 기록된 단계를 시작 순서로 돌려준다 (보관 기간이 지났으면 None)
+pub fn trace_timeline(trace_id: u64) -> Option<Vec<TraceHop>> {
+    let traces = TRACES.lock().ok()?;
+    let trace = traces.iter().find(|trace| trace.id.0 == trace_id)?;
+    let mut hops = trace.hops.clone();
+    // 같은 시각에 시작했다면 바깥 단계(더 오래 걸린 쪽)가 먼저
+    hops.sort_by(|a, b| {
+        a.start_ms
+            .total_cmp(&b.start_ms)
+            .then(b.duration_ms.total_cmp(&a.duration_ms))
+    });
+    Some(hops)
+}
+
+// 단계 기록에 남길 실패 내용
+pub trait HopOutcome {
+    fn error(&self) -> Option<String>;
+}
+
+impl<T, E: fmt::Display> HopOutcome for Result<T, E> {
+    fn error(&self) -> Option<String> {
+        self.as_ref().err().map(|e| e.to_string())
+    }
+}
+
+impl HopOutcome for () {
+    fn error(&self) -> Option<String> {
+        None
+    }
+}
+
+// 추적 대상 메시지
+// 보내는 쪽의 추적 ID를 함께 실어 받는 쪽이 같은 추적 안에서 처리하게 한다.
+pub struct Traced<M> {
+    pub message: M,
+    trace: TraceId,
+}
+
+impl<M> Traced<M> {
+    pub fn new(message: M) -> Self {
+        Self {
+            message,
+            trace: TraceId::current_or_begin(),
+        }
+    }
+
+    pub fn trace_id(&self) -> TraceId {
+        self.trace
+    }
+}
+
+// 기존 Handler/Notifiable 구현을 Traced<M>으로도 받을 수 있게 감싼다
+// 예: traced!(StorageActor, handle: ScanPrefix);
+macro_rules! traced {
+    ($actor:ty, handle: $($message:ty),+ $(,)?) => {$(
+        #[::async_trait::async_trait]
+        impl ::messages::prelude::Handler<$crate::study_actors::actors::Traced<$message>>
+            for $actor
+        {
+            type Result = <$actor as ::messages::prelude::Handler<$message>>::Result;
+
+            async fn handle(
+                &mut self,
+                msg: $crate::study_actors::actors::Traced<$message>,
+                ctx: &::messages::prelude::Context<Self>,
+            ) -> Self::Result {
+                let trace = msg.trace_id();
+                trace
+                    .run(
+                        stringify!($actor),
+                        stringify!($message),
+                        <Self as ::messages::prelude::Handler<$message>>::handle(
+                            self,
+                            msg.message,
+                            ctx,
+                        ),
+                    )
+                    .await
+            }
+        }
+    )+};
+    ($actor:ty, notify: $($message:ty),+ $(,)?) => {$(
+        #[::async_trait::async_trait]
+        impl ::messages::prelude::Notifiable<$crate::study_actors::actors::Traced<$message>>
+            for $actor
+        {
+            async fn notify(
+                &mut self,
+                msg: $crate::study_actors::actors::Traced<$message>,
+                ctx: &::messages::prelude::Context<Self>,
+            ) {
+                let trace = msg.trace_id();
+                trace
+                    .run(
+                        stringify!($actor),
+                        stringify!($message),
+                        <Self as ::messages::prelude::Notifiable<$message>>::notify(
+                            self,
+                            msg.message,
+                            ctx,
+                        ),
+                    )
+                    .await
+            }
+        }
+    )+};
+}
+pub(crate) use traced;
+
+#[cfg(test)]
+mod tests {
+    use super::{TraceId, Traced, trace_timeline};
+
+    #[tokio::test]
+    async fn nested_hops_share_the_trace_and_keep_start_order() {
+        let trace = TraceId::current_or_begin();
+        let inner: Result<TraceId, &str> = trace
+            .run("OuterActor", "Outer", async {
+                let traced = Traced::new(());
+                let inner = traced.trace_id();
+                let failed: Result<(), &str> = inner
+                    .run("InnerActor", "Inner", async { Err("boom") })
+                    .await;
+                assert!(failed.is_err());
+                Ok(inner)
+            })
+            .await;
+        assert_eq!(inner, Ok(trace));
+
+        let hops = trace_timeline(trace.into()).unwrap_or_default();
+        let names: Vec<_> = hops.iter().map(|hop| hop.actor.as_str()).collect();
+        assert_eq!(names, ["OuterActor", "InnerActor"]);
+        assert_eq!(hops[1].error.as_deref(), Some("boom"));
+        // 추적 밖에서는 이어받을 추적이 없다
+        assert_eq!(TraceId::current(), None);
+    }
+}
@@ -0,0 +1,40 @@
+use std::{
+    future::Future,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+use tokio::task::{AbortHandle, JoinSet};
+
+// 끝났는지 물어볼 수 있는 작업 핸들
+// 웹의 AbortHandle(tokio_with_wasm)에는 is_finished가 없어 작업이 끝날 때 직접 표시한다.
+pub struct TrackedTask {
+    handle: AbortHandle,
+    finished: Arc<AtomicBool>,
+}
+
+impl TrackedTask {
+    // 액터의 JoinSet에 넣어 액터가 멈추면 같이 취소되게 한다
+    pub fn spawn<F>(tasks: &mut JoinSet<()>, task: F) -> Self
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let finished = Arc::new(AtomicBool::new(false));
+        let done = finished.clone();
+        let handle = tasks.spawn(async move {
+            task.await;
+            done.store(true, Ordering::Release);
+        });
+        Self { handle, finished }
+    }
+
+    // 취소된 작업은 끝까지 실행되지 않았으므로 false
+    pub fn is_finished(&self) -> bool {
+        self.finished.load(Ordering::Acquire)
+    }
+
+    pub fn abort(&self) {
+        self.handle.abort();
+    }
+}
@@ -3,37 +3,59 @@ use messages::{
     actor::Actor,
     prelude::{Address, Context, Handler, Notifiable},
 };
-use rinf::{debug_print, RustSignal};
 use std::collections::HashMap;
 use tokio::task::JoinSet;
 
 use crate::study_actors::{
+    logging::debug_print,
     messages::{
-        AuthResult, GetProfile, Login, UpdateProfile, UserError, UserId, UserEvent, UserProfile,
-        UserPreferences, UpdateProfileCache,
+        ActorResult, AuthResult, CaptureSnapshot, FetchData, FetchMode, GetProfile,
+        GetProfileEndpoint, Login, SearchUsers, SearchUsersEndpoint, StoreData, TimeoutConfig,
+        UpdateProfile, UpdateProfileCache, UpdateProfileEndpoint, UserError, UserEvent, UserId,
+        UserPreferences, UserProfile, UserSearchQuery,
     },
     signals::{
-        GetUserProfileRequest, ProfileUpdatedSignal, UpdatePreferencesRequest,
-        PreferencesUpdatedSignal, UserProfileResponse,
+        EmitSignal, GetUserProfileRequest, ProfileUpdatedSignal, UpdatePreferencesRequest,
+        PreferencesUpdatedSignal, StateSnapshotSignal, respond_to_dart, route_dart_signals,
     },
 };
 
-use super::AuthActor;
+use super::{
+    ActorBuilder, ApiClient, AuthActor, Clock, Deadline, Priority, StorageActor, Traced,
+    UserLockMap, fuzzy_match, trace::traced, validate_form,
+};
+
+// 시작 스냅샷용 마지막 프로필 (사용자별)
+const SNAPSHOT_PROFILE_PREFIX: &str = "snapshot/profile/";
 
 pub struct UserManagerActor {
     auth_actor: Address<AuthActor>,
+    auth_deadline: Deadline,
     profile_actors: HashMap<UserId, Address<UserProfileActor>>,
+    user_locks: UserLockMap,
+    api: Option<ApiClient>,
+    storage: Option<Address<StorageActor>>, // 없으면 마지막 프로필을 남기지 않는다
     _owned_tasks: JoinSet<()>,
 }
 
 impl Actor for UserManagerActor {}
 
+// 감독자가 Traced로 보내는 로그인 흐름 메시지
+traced!(UserManagerActor, handle: Login, GetProfile);
+
 impl UserManagerActor {
-    pub fn new(auth_actor: Address<AuthActor>) -> Self {
+    pub fn new(self_addr: Address<Self>, auth_actor: Address<AuthActor>) -> Self {
+        let owned_tasks =
+            route_dart_signals!(self_addr, [GetUserProfileRequest, UpdatePreferencesRequest]);
+
         Self {
             auth_actor,
+            auth_deadline: Deadline::new(Clock::system(), TimeoutConfig::default().auth_ms),
             profile_actors: HashMap::new(),
-            _owned_tasks: JoinSet::new(),
+            user_locks: UserLockMap::default(),
+            api: None,
+            storage: None,
+            _owned_tasks: owned_tasks,
         }
     }
     
@@ -42,26 +64,87 @@ impl UserManagerActor {
             return addr.clone();
         }
         
-        // 새 프로필 액터 생성
-        let context = Context::new();
-        let addr = context.address();
-        let actor = UserProfileActor::new(user_id.clone());
-        
-        // 액터 실행 및 저장
-        tokio::spawn(context.run(actor));
+        // 새 프로필 액터 생성 및 실행 (사용자마다 하나라 등록부에는 올리지 않는다)
+        let addr =
+            ActorBuilder::new().start(UserProfileActor::new(user_id.clone(), self.api.clone()));
         self.profile_actors.insert(user_id.clone(), addr.clone());
         
         addr
     }
+    
+    // 감독자가 만든 잠금을 넘겨받는다 (테스트는 같은 잠금을 밖에서 잡아 본다)
+    pub fn set_user_locks(&mut self, user_locks: UserLockMap) {
+        self.user_locks = user_locks;
+    }
+    
+    // 인증 액터가 멈췄을 때 로그인을 포기할 때까지의 시간
+    pub fn set_auth_deadline(&mut self, deadline: Deadline) {
+        self.auth_deadline = deadline;
+    }
+    
+    // 설정되어 있으면 프로필을 프로필 서비스에서 읽고 쓴다
+    pub fn set_api_client(&mut self, api: ApiClient) {
+        self.api = Some(api);
+    }
+    
+    // 설정되어 있으면 마지막 프로필을 저장해 다음 시작 때 첫 화면에 쓴다
+    pub fn set_storage(&mut self, storage: Address<StorageActor>) {
+        self.storage = Some(storage);
+    }
+    
+    async fn remember_profile(&mut self, user_id: &UserId, profile: &UserProfile) {
+        let Some(storage) = self.storage.as_mut() else {
+            return;
+        };
+        let Ok(data) = serde_json::to_vec(profile) else {
+            return;
+        };
+        let request = StoreData {
+            key: format!("{}{}", SNAPSHOT_PROFILE_PREFIX, user_id),
+            data,
+            user_id: Some(user_id.clone()),
+            ttl: None,
+        };
+        if !matches!(storage.send(request).await, Ok(Ok(()))) {
+            debug_print!("Failed to remember profile for {}", user_id);
+        }
+    }
+    
+    async fn update_profile(
+        &mut self,
+        msg: UpdateProfile,
+        ctx: &Context<Self>,
+    ) -> ActorResult<()> {
+        // Dart 프로필 폼과 같은 규칙으로 저장 전에 검사
+        let values = serde_json::to_value(&msg.profile)?;
+        if let Some(error) = validate_form("user_profile", &values)?.into_iter().next() {
+            return Err(UserError::InvalidInput(format!(
+                "{}: {}",
+                error.field, error.message
+            )));
+        }
+        
+        let mut profile_actor = self.get_or_create_profile_actor(&msg.user_id).await;
+        let user_id = msg.user_id.clone();
+        let profile = profile_actor.send(msg).await??;
+        
+        // 프로필 업데이트 이벤트 발행 (자기 메일박스를 거치지 않고 바로 처리)
+        self.notify(UserEvent::ProfileUpdated(user_id, profile), ctx)
+            .await;
+        Ok(())
+    }
 }
 
 #[async_trait]
 impl Handler<Login> for UserManagerActor {
-    type Response = Result<AuthResult, UserError>;
+    type Result = ActorResult<AuthResult>;
     
-    async fn handle(&mut self, msg: Login, _: &Context<Self>) -> Self::Response {
+    async fn handle(&mut self, msg: Login, _: &Context<Self>) -> Self::Result {
         // 인증 액터에 로그인 요청 전달
-        let auth_result = self.auth_actor.send(msg).await??;
+        let auth_result = self
+            .auth_deadline
+            .send(&mut self.auth_actor, Traced::new(msg))
+            .await??;
         
         // 사용자 프로필 액터 생성 (없는 경우)
         self.get_or_create_profile_actor(&auth_result.user_id).await;
@@ -72,28 +155,60 @@ impl Handler<Login> for UserManagerActor {
 
 #[async_trait]
 impl Handler<GetProfile> for UserManagerActor {
-    type Response = Result<UserProfile, UserError>;
+    type Result = ActorResult<UserProfile>;
     
-    async fn handle(&mut self, msg: GetProfile, _: &Context<Self>) -> Self::Response {
-        let profile_actor = self.get_or_create_profile_actor(&msg.user_id).await;
-        profile_actor.send(msg).await?
+    async fn handle(&mut self, msg: GetProfile, _: &Context<Self>) -> Self::Result {
+        let user_id = msg.user_id.clone();
+        let mut profile_actor = self.get_or_create_profile_actor(&user_id).await;
+        let profile = profile_actor.send(Traced::new(msg)).await??;
+        self.remember_profile(&user_id, &profile).await;
+        Ok(profile)
     }
 }
 
 #[async_trait]
 impl Handler<UpdateProfile> for UserManagerActor {
-    type Response = Result<(), UserError>;
+    type Result = ActorResult<()>;
     
-    async fn handle(&mut self, msg: UpdateProfile, _: &Context<Self>) -> Self::Response {
-        let profile_actor = self.get_or_create_profile_actor(&msg.user_id).await;
-        let result = profile_actor.send(msg.clone()).await?;
-        
-        if result.is_ok() {
-            // 프로필 업데이트 이벤트 발행
-            let _ = self.notify(UserEvent::ProfileUpdated(msg.user_id, msg.profile)).await;
+    async fn handle(&mut self, msg: UpdateProfile, ctx: &Context<Self>) -> Self::Result {
+        self.update_profile(msg, ctx).await
+    }
+}
+
+#[async_trait]
+impl Handler<SearchUsers> for UserManagerActor {
+    type Result = ActorResult<Vec<UserProfile>>;
+    
+    async fn handle(&mut self, msg: SearchUsers, _: &Context<Self>) -> Self::Result {
+        if let Some(api) = &self.api {
+            let endpoint = SearchUsersEndpoint {
+                query: UserSearchQuery {
+                    q: msg.query,
+                    limit: msg.limit,
+                },
+            };
+            return api.send(Priority::High, &endpoint).await;
         }
         
-        result
+        // 디렉터리 서비스가 없으면 이 기기에서 불러온 프로필 중에서 찾는다
+        let mut profiles = Vec::new();
+        for (user_id, profile_actor) in self.profile_actors.iter_mut() {
+            let request = GetProfile {
+                user_id: user_id.clone(),
+            };
+            if let Ok(Ok(profile)) = profile_actor.send(request).await {
+                profiles.push(profile);
+            }
+        }
+        let candidates: Vec<String> = profiles
+            .iter()
+            .map(|profile| format!("{} {}", profile.name, profile.email))
+            .collect();
+        let hits = fuzzy_match(&msg.query, &candidates, msg.limit);
+        Ok(hits
+            .into_iter()
+            .map(|hit| profiles[hit.index as usize].clone())
+            .collect())
     }
 }
 
@@ -105,15 +220,16 @@ impl Notifiable<UserEvent> for UserManagerActor {
                 debug_print!("Profile updated for user: {}", user_id);
                 
                 // 프로필 캐시 업데이트
-                if let Some(addr) = self.profile_actors.get(&user_id) {
+                if let Some(addr) = self.profile_actors.get_mut(&user_id) {
                     let _ = addr.notify(UpdateProfileCache(profile.clone())).await;
                 }
+                self.remember_profile(&user_id, &profile).await;
                 
                 // Dart에 알림
                 ProfileUpdatedSignal {
                     user_id,
                     profile,
-                }.send_signal_to_dart();
+                }.emit();
             },
             UserEvent::PreferencesChanged(user_id, preferences) => {
                 debug_print!("Preferences changed for user: {}", user_id);
@@ -122,7 +238,7 @@ impl Notifiable<UserEvent> for UserManagerActor {
                 PreferencesUpdatedSignal {
                     user_id,
                     preferences,
-                }.send_signal_to_dart();
+                }.emit();
             },
             UserEvent::LoggedIn(user_id) => {
                 debug_print!("User logged in: {}", user_id);
@@ -137,52 +253,62 @@ impl Notifiable<UserEvent> for UserManagerActor {
     }
 }
 
+// 저장된 마지막 프로필 (로그아웃 상태이거나 기록이 없으면 None)
+#[async_trait]
+impl Handler<CaptureSnapshot> for UserManagerActor {
+    type Result = Option<StateSnapshotSignal>;
+    
+    async fn handle(&mut self, msg: CaptureSnapshot, _: &Context<Self>) -> Self::Result {
+        let user_id = msg.user_id?;
+        let request = FetchData {
+            key: format!("{}{}", SNAPSHOT_PROFILE_PREFIX, user_id),
+            user_id: Some(user_id),
+            mode: FetchMode::CacheFirst,
+        };
+        let bytes = self.storage.as_mut()?.send(request).await.ok()?.ok()?;
+        let profile = serde_json::from_slice(&bytes).ok()?;
+        Some(StateSnapshotSignal::Profile { profile })
+    }
+}
+
 // Dart 신호 처리
 #[async_trait]
 impl Notifiable<GetUserProfileRequest> for UserManagerActor {
     async fn notify(&mut self, msg: GetUserProfileRequest, ctx: &Context<Self>) {
-        let profile_result = self
-            .handle(
-                GetProfile {
-                    user_id: msg.user_id,
-                },
-                ctx,
-            )
-            .await;
-        
-        match profile_result {
-            Ok(profile) => {
-                UserProfileResponse {
-                    profile: Some(profile),
-                    error: None,
-                }
-                .send_signal_to_dart();
-            }
-            Err(e) => {
-                UserProfileResponse {
-                    profile: None,
-                    error: Some(e.to_string()),
-                }
-                .send_signal_to_dart();
-            }
-        }
+        let _ = respond_to_dart(msg, |msg| async move {
+            let get_profile = GetProfile {
+                user_id: msg.user_id,
+            };
+            Ok(self.handle(get_profile, ctx).await?)
+        })
+        .await;
     }
 }
 
 #[async_trait]
 impl Notifiable<UpdatePreferencesRequest> for UserManagerActor {
     async fn notify(&mut self, msg: UpdatePreferencesRequest, ctx: &Context<Self>) {
-        // 먼저 현재 프로필 가져오기
-        let profile_result = self
-            .handle(
-                GetProfile {
-                    user_id: msg.user_id.clone(),
-                },
-                ctx,
-            )
-            .await;
+        let user_id = msg.user_id.clone();
+        
+        // 프로필 서비스에서 읽어 오는 동안 잠금을 쥐지 않도록 먼저 캐시를 채운다
+        let get_profile = GetProfile {
+            user_id: user_id.clone(),
+        };
+        if self.handle(get_profile, ctx).await.is_err() {
+            return;
+        }
         
-        if let Ok(mut profile) = profile_result {
+        // 캐시된 프로필을 읽고 고치는 동안만 같은 사용자의 다른 변경이 끼어들지 않게 한다
+        let profile = {
+            let _lock = self.user_locks.lock(&user_id).await;
+            let mut profile_actor = self.get_or_create_profile_actor(&user_id).await;
+            let get_profile = GetProfile {
+                user_id: user_id.clone(),
+            };
+            let Ok(Ok(mut profile)) = profile_actor.send(get_profile).await else {
+                return;
+            };
+            
             // 선택적 필드 업데이트
             if let Some(theme) = msg.theme {
                 profile.preferences.theme = theme;
@@ -195,18 +321,13 @@ impl Notifiable<UpdatePreferencesRequest> for UserManagerActor {
             if let Some(language) = msg.language {
                 profile.preferences.language = language;
             }
-            
-            // 프로필 업데이트
-            let _ = self
-                .handle(
-                    UpdateProfile {
-                        user_id: msg.user_id,
-                        profile,
-                    },
-                    ctx,
-                )
-                .await;
-        }
+            profile
+        };
+        
+        // 서버에 보내는 동안에는 잠금을 놓는다
+        let _ = self
+            .update_profile(UpdateProfile { user_id, profile }, ctx)
+            .await;
     }
 }
 
@@ -214,15 +335,19 @@ impl Notifiable<UpdatePreferencesRequest> for UserManagerActor {
 pub struct UserProfileActor {
     user_id: UserId,
     profile: Option<UserProfile>,
+    api: Option<ApiClient>,
 }
 
 impl Actor for UserProfileActor {}
 
+traced!(UserProfileActor, handle: GetProfile);
+
 impl UserProfileActor {
-    pub fn new(user_id: UserId) -> Self {
+    pub fn new(user_id: UserId, api: Option<ApiClient>) -> Self {
         Self {
             user_id,
             profile: None,
+            api,
         }
     }
     
@@ -243,26 +368,45 @@ impl UserProfileActor {
 
 #[async_trait]
 impl Handler<GetProfile> for UserProfileActor {
-    type Response = Result<UserProfile, UserError>;
+    type Result = ActorResult<UserProfile>;
     
-    async fn handle(&mut self, _: GetProfile, _: &Context<Self>) -> Self::Response {
-        // 프로필이 없으면 기본값 생성
-        if self.profile.is_none() {
-            self.profile = Some(self.create_default_profile());
+    async fn handle(&mut self, _: GetProfile, _: &Context<Self>) -> Self::Result {
+        if let Some(profile) = &self.profile {
+            return Ok(profile.clone());
         }
         
-        Ok(self.profile.clone().unwrap())
+        // 프로필 서비스가 없으면 기본값 생성
+        let profile = match &self.api {
+            Some(api) => {
+                let endpoint = GetProfileEndpoint {
+                    user_id: self.user_id.clone(),
+                };
+                api.send(Priority::High, &endpoint).await?
+            }
+            None => self.create_default_profile(),
+        };
+        self.profile = Some(profile.clone());
+        Ok(profile)
     }
 }
 
 #[async_trait]
 impl Handler<UpdateProfile> for UserProfileActor {
-    type Response = Result<(), UserError>;
+    type Result = ActorResult<UserProfile>;
     
-    async fn handle(&mut self, msg: UpdateProfile, _: &Context<Self>) -> Self::Response {
-        // 프로필 업데이트
-        self.profile = Some(msg.profile);
-        Ok(())
+    async fn handle(&mut self, msg: UpdateProfile, _: &Context<Self>) -> Self::Result {
+        // 서버가 정규화한 값을 그대로 보관한다
+        let profile = match &self.api {
+            Some(api) => {
+                let endpoint = UpdateProfileEndpoint {
+                    profile: msg.profile,
+                };
+                api.send(Priority::High, &endpoint).await?
+            }
+            None => msg.profile,
+        };
+        self.profile = Some(profile.clone());
+        Ok(profile)
     }
 }
 
@@ -273,3 +417,65 @@ impl Notifiable<UpdateProfileCache> for UserProfileActor {
         self.profile = Some(msg.0);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{error::Error, time::Duration};
+    use tokio::time::timeout;
+
+    use super::UserManagerActor;
+    use crate::study_actors::{
+        actors::{AuthActor, TrustedClock, UserLockMap},
+        messages::{AuthConfig, GetProfile},
+        signals::{ProfileUpdatedSignal, UpdatePreferencesRequest},
+        test_support::{TestActorHarness, settle},
+    };
+
+    fn theme_request(user_id: &str, theme: &str) -> UpdatePreferencesRequest {
+        UpdatePreferencesRequest {
+            user_id: user_id.to_string(),
+            theme: Some(theme.to_string()),
+            notifications_enabled: None,
+            language: None,
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn other_user_updates_while_one_user_is_locked() -> Result<(), Box<dyn Error>> {
+        let auth = TestActorHarness::start(|addr| {
+            AuthActor::new(addr, AuthConfig::default(), TrustedClock::new())
+        });
+        let locks = UserLockMap::default();
+        let (auth_addr, shared) = (auth.addr(), locks.clone());
+        let mut users = TestActorHarness::start(|addr| {
+            let mut actor = UserManagerActor::new(addr, auth_addr);
+            actor.set_user_locks(shared);
+            actor
+        });
+        let alice = locks.lock("alice").await;
+
+        users.notify(theme_request("bob", "dark")).await?;
+        settle().await;
+        let updated = users.signals_of::<ProfileUpdatedSignal>();
+        assert_eq!(updated.len(), 1);
+        assert_eq!(updated[0].user_id, "bob");
+        assert_eq!(updated[0].profile.preferences.theme, "dark");
+
+        // 잠긴 사용자의 변경은 잠금이 풀릴 때까지 기다린다
+        users.notify(theme_request("alice", "dark")).await?;
+        settle().await;
+        assert!(users.signals_of::<ProfileUpdatedSignal>().is_empty());
+
+        drop(alice);
+        settle().await;
+        let updated = users.signals_of::<ProfileUpdatedSignal>();
+        assert_eq!(updated.len(), 1);
+        assert_eq!(updated[0].user_id, "alice");
+        let request = GetProfile {
+            user_id: "alice".to_string(),
+        };
+        let profile = timeout(Duration::from_secs(1), users.send(request)).await???;
+        assert_eq!(profile.preferences.theme, "dark");
+        Ok(())
+    }
+}
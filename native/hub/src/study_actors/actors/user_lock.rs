@@ -0,0 +1,65 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, PoisonError, Weak},
+};
+use tokio::sync::{Mutex as AsyncMutex, OwnedMutexGuard};
+
+use crate::study_actors::messages::UserId;
+
+// 사용자별 비동기 잠금
+// 같은 사용자의 읽고 고쳐 쓰기를 하나씩 실행하고, 다른 사용자의 작업은 기다리지 않는다.
+// 쥐고 있는 동안 메일박스가 멈추므로 네트워크 왕복 없이 로컬 작업에만 잡는다.
+// (메일박스가 이미 요청을 차례로 처리하는 데이터 관리자는 잡지 않는다)
+#[derive(Clone, Default)]
+pub struct UserLockMap {
+    locks: Arc<Mutex<HashMap<UserId, Weak<AsyncMutex<()>>>>>,
+}
+
+// 놓으면 잠금이 풀린다
+pub struct UserLockGuard {
+    _guard: OwnedMutexGuard<()>,
+}
+
+impl UserLockMap {
+    pub async fn lock(&self, user_id: &str) -> UserLockGuard {
+        let lock = {
+            let mut locks = self.locks.lock().unwrap_or_else(PoisonError::into_inner);
+            // 쥐고 있거나 기다리는 쪽이 없는 사용자의 잠금은 치운다
+            locks.retain(|_, lock| lock.strong_count() > 0);
+            match locks.get(user_id).and_then(Weak::upgrade) {
+                Some(lock) => lock,
+                None => {
+                    let lock = Arc::new(AsyncMutex::new(()));
+                    locks.insert(user_id.to_string(), Arc::downgrade(&lock));
+                    lock
+                }
+            }
+        };
+        UserLockGuard {
+            _guard: lock.lock_owned().await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use tokio::time::timeout;
+
+    use super::UserLockMap;
+
+    #[tokio::test(start_paused = true)]
+    async fn same_user_waits_while_other_users_proceed() {
+        let locks = UserLockMap::default();
+        let guard = locks.lock("alice").await;
+
+        let other = timeout(Duration::from_millis(10), locks.lock("bob")).await;
+        assert!(other.is_ok());
+        let same = timeout(Duration::from_millis(10), locks.lock("alice")).await;
+        assert!(same.is_err());
+
+        drop(guard);
+        let same = timeout(Duration::from_millis(10), locks.lock("alice")).await;
+        assert!(same.is_ok());
+    }
+}
@@ -0,0 +1,582 @@
+use async_trait::async_trait;
+use chrono::DateTime;
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use phonenumber::{Mode, country};
+use regex::Regex;
+use serde_json::Value;
+use std::{collections::HashMap, sync::LazyLock};
+use tokio::task::JoinSet;
+
+use crate::study_actors::{
+    messages::{
+        ActorResult, ContactFieldKind, FieldError, NormalizeContactField, NormalizedContactField,
+        UserError, ValidateForm,
+    },
+    signals::{
+        NormalizeContactFieldRequest, ValidateFormRequest, respond_to_dart, route_dart_signals,
+    },
+};
+
+const EMAIL_PATTERN: &str = r"^[^@\s]+@[^@\s]+\.[^@\s]+$";
+
+// 스키마는 처음 쓸 때 한 번 만든다 (정규식 컴파일 포함)
+static SCHEMAS: LazyLock<Result<HashMap<&'static str, FormSchema>, regex::Error>> =
+    LazyLock::new(built_in_schemas);
+
+// Rust에 정의한 폼 스키마 (Dart 폼 검증과 저장 전 검증이 모두 여기를 거친다)
+// - user_profile: 프로필 편집 (UpdateProfile 저장 전에도 검사)
+// - sign_up: 회원 가입
+// - reminder: 알림 예약 (종료/반복 종료 시각은 시작 시각 이후)
+fn built_in_schemas() -> Result<HashMap<&'static str, FormSchema>, regex::Error> {
+    let user_profile = FormSchema {
+        fields: vec![
+            FieldRule::required("name").length(1, 100),
+            FieldRule::required("email")
+                .length(3, 254)
+                .pattern(EMAIL_PATTERN, "Enter a valid email address")?,
+            FieldRule::optional("avatar_url")
+                .pattern(r"^https://\S+$", "Avatar URL must start with https://")?,
+            FieldRule::required("preferences.theme").one_of(&["light", "dark", "system"]),
+            FieldRule::required("preferences.language").pattern(
+                r"^[a-z]{2}(-[A-Z]{2})?$",
+                "Use a language code such as en or ko-KR",
+            )?,
+        ],
+        cross_field: Vec::new(),
+    };
+
+    let sign_up = FormSchema {
+        fields: vec![
+            FieldRule::required("email")
+                .length(3, 254)
+                .pattern(EMAIL_PATTERN, "Enter a valid email address")?,
+            FieldRule::required("password")
+                .length(8, 128)
+                .pattern(r"\d", "Include at least one number")?,
+            FieldRule::required("password_confirm"),
+        ],
+        cross_field: vec![CrossFieldRule::Matches {
+            field: "password_confirm",
+            other: "password",
+        }],
+    };
+
+    let reminder = FormSchema {
+        fields: vec![
+            FieldRule::required("title").length(1, 200),
+            FieldRule::required("starts_at").timestamp(),
+            FieldRule::optional("ends_at").timestamp(),
+            FieldRule::optional("repeat_every_days").range(1.0, 365.0),
+            FieldRule::optional("repeat_until").timestamp(),
+        ],
+        cross_field: vec![
+            CrossFieldRule::NotBefore {
+                field: "ends_at",
+                other: "starts_at",
+            },
+            CrossFieldRule::RequiredWith {
+                field: "repeat_every_days",
+                other: "repeat_until",
+            },
+            CrossFieldRule::NotBefore {
+                field: "repeat_until",
+                other: "starts_at",
+            },
+        ],
+    };
+
+    Ok(HashMap::from([
+        ("user_profile", user_profile),
+        ("sign_up", sign_up),
+        ("reminder", reminder),
+    ]))
+}
+
+// 스키마 이름으로 값을 검증해 필드 오류 목록을 돌려준다 (비었으면 통과)
+// 저장 경로에서는 액터를 거치지 않고 바로 불러 Dart 폼과 같은 규칙을 적용한다.
+pub fn validate_form(schema_name: &str, values: &Value) -> ActorResult<Vec<FieldError>> {
+    let schemas = SCHEMAS
+        .as_ref()
+        .map_err(|e| UserError::InvalidInput(format!("Invalid form schema: {}", e)))?;
+    let schema = schemas
+        .get(schema_name)
+        .ok_or_else(|| UserError::NotFound(format!("Form schema {}", schema_name)))?;
+    if !values.is_object() {
+        return Err(UserError::InvalidInput(
+            "Form values must be a JSON object".to_string(),
+        ));
+    }
+    Ok(schema.validate(values))
+}
+
+struct FormSchema {
+    fields: Vec<FieldRule>,
+    cross_field: Vec<CrossFieldRule>,
+}
+
+impl FormSchema {
+    fn validate(&self, values: &Value) -> Vec<FieldError> {
+        let mut errors: Vec<FieldError> = self
+            .fields
+            .iter()
+            .filter_map(|rule| rule.validate(field_value(values, rule.field)))
+            .collect();
+
+        // 교차 필드 규칙은 관련 필드가 각자의 규칙을 통과했을 때만 본다
+        for rule in &self.cross_field {
+            let (field, other) = rule.fields();
+            if errors.iter().any(|e| e.field == field || e.field == other) {
+                continue;
+            }
+            if let Some(error) = rule.validate(values) {
+                errors.push(error);
+            }
+        }
+        errors
+    }
+}
+
+// 필드 하나의 규칙 (값이 있을 때 검사를 순서대로 적용하고 첫 오류만 보고한다)
+struct FieldRule {
+    field: &'static str,
+    required: bool,
+    checks: Vec<Check>,
+}
+
+impl FieldRule {
+    fn required(field: &'static str) -> Self {
+        Self {
+            field,
+            required: true,
+            checks: Vec::new(),
+        }
+    }
+
+    fn optional(field: &'static str) -> Self {
+        Self {
+            required: false,
+            ..Self::required(field)
+        }
+    }
+
+    fn length(mut self, min: usize, max: usize) -> Self {
+        self.checks.push(Check::Length { min, max });
+        self
+    }
+
+    fn pattern(mut self, pattern: &str, message: &'static str) -> Result<Self, regex::Error> {
+        self.checks.push(Check::Pattern {
+            regex: Regex::new(pattern)?,
+            message,
+        });
+        Ok(self)
+    }
+
+    fn range(mut self, min: f64, max: f64) -> Self {
+        self.checks.push(Check::Range { min, max });
+        self
+    }
+
+    fn one_of(mut self, allowed: &'static [&'static str]) -> Self {
+        self.checks.push(Check::OneOf(allowed));
+        self
+    }
+
+    fn timestamp(mut self) -> Self {
+        self.checks.push(Check::Timestamp);
+        self
+    }
+
+    fn validate(&self, value: Option<&Value>) -> Option<FieldError> {
+        let Some(value) = value.filter(|value| !is_blank(value)) else {
+            return self
+                .required
+                .then(|| field_error(self.field, "required", "This field is required"));
+        };
+        self.checks.iter().find_map(|check| {
+            check
+                .validate(value)
+                .map(|(code, message)| field_error(self.field, code, message))
+        })
+    }
+}
+
+enum Check {
+    // 글자 수
+    Length { min: usize, max: usize },
+    Pattern { regex: Regex, message: &'static str },
+    // 숫자 또는 숫자 문자열 (Flutter 입력란은 문자열로 보낸다)
+    Range { min: f64, max: f64 },
+    OneOf(&'static [&'static str]),
+    // RFC 3339 날짜·시각
+    Timestamp,
+}
+
+impl Check {
+    // 실패하면 (오류 코드, 메시지)
+    fn validate(&self, value: &Value) -> Option<(&'static str, String)> {
+        match self {
+            Self::Length { min, max } => {
+                let Some(text) = value.as_str() else {
+                    return Some(("type", "Must be text".to_string()));
+                };
+                let length = text.chars().count();
+                if length < *min {
+                    Some(("too_short", format!("Must be at least {} characters", min)))
+                } else if length > *max {
+                    Some(("too_long", format!("Must be at most {} characters", max)))
+                } else {
+                    None
+                }
+            }
+            Self::Pattern { regex, message } => match value.as_str() {
+                Some(text) if regex.is_match(text) => None,
+                Some(_) => Some(("pattern", message.to_string())),
+                None => Some(("type", "Must be text".to_string())),
+            },
+            Self::Range { min, max } => match as_number(value) {
+                Some(number) if (*min..=*max).contains(&number) => None,
+                Some(_) => Some((
+                    "out_of_range",
+                    format!("Must be between {} and {}", min, max),
+                )),
+                None => Some(("type", "Must be a number".to_string())),
+            },
+            Self::OneOf(allowed) => match value.as_str() {
+                Some(text) if allowed.contains(&text) => None,
+                _ => Some(("one_of", format!("Must be one of: {}", allowed.join(", ")))),
+            },
+            Self::Timestamp => match value.as_str().map(DateTime::parse_from_rfc3339) {
+                Some(Ok(_)) => None,
+                _ => Some(("timestamp", "Must be a date and time".to_string())),
+            },
+        }
+    }
+}
+
+// 여러 필드를 함께 보는 규칙 (오류는 field에 붙는다)
+enum CrossFieldRule {
+    // 두 값이 같아야 한다 (비밀번호 확인 등)
+    Matches {
+        field: &'static str,
+        other: &'static str,
+    },
+    // field가 other보다 앞설 수 없다 (숫자 또는 날짜·시각, 둘 다 있을 때만)
+    NotBefore {
+        field: &'static str,
+        other: &'static str,
+    },
+    // other에 값이 있으면 field도 필수
+    RequiredWith {
+        field: &'static str,
+        other: &'static str,
+    },
+}
+
+impl CrossFieldRule {
+    fn fields(&self) -> (&'static str, &'static str) {
+        match self {
+            Self::Matches { field, other }
+            | Self::NotBefore { field, other }
+            | Self::RequiredWith { field, other } => (*field, *other),
+        }
+    }
+
+    fn validate(&self, values: &Value) -> Option<FieldError> {
+        let (field, other) = self.fields();
+        let value = field_value(values, field).filter(|value| !is_blank(value));
+        let other_value = field_value(values, other).filter(|value| !is_blank(value));
+        match self {
+            Self::Matches { .. } => (value != other_value)
+                .then(|| field_error(field, "mismatch", format!("Must match {}", other))),
+            Self::NotBefore { .. } => {
+                let (value, other_value) = (as_ordinal(value?)?, as_ordinal(other_value?)?);
+                (value < other_value)
+                    .then(|| field_error(field, "before", format!("Can't be before {}", other)))
+            }
+            Self::RequiredWith { .. } => (value.is_none() && other_value.is_some())
+                .then(|| field_error(field, "required", format!("Required when {} is set", other))),
+        }
+    }
+}
+
+// "preferences.theme"은 평평한 키로 먼저 찾고, 없으면 중첩 객체에서 찾는다
+fn field_value<'a>(values: &'a Value, field: &str) -> Option<&'a Value> {
+    values
+        .get(field)
+        .or_else(|| values.pointer(&format!("/{}", field.replace('.', "/"))))
+}
+
+fn is_blank(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(text) => text.trim().is_empty(),
+        _ => false,
+    }
+}
+
+fn as_number(value: &Value) -> Option<f64> {
+    value
+        .as_f64()
+        .or_else(|| value.as_str()?.trim().parse().ok())
+}
+
+// 크기 비교용 값 (숫자, 숫자 문자열, 날짜·시각은 밀리초)
+fn as_ordinal(value: &Value) -> Option<f64> {
+    as_number(value).or_else(|| {
+        let timestamp = DateTime::parse_from_rfc3339(value.as_str()?).ok()?;
+        Some(timestamp.timestamp_millis() as f64)
+    })
+}
+
+fn field_error(field: &str, code: &str, message: impl Into<String>) -> FieldError {
+    FieldError {
+        field: field.to_string(),
+        code: code.to_string(),
+        message: message.into(),
+    }
+}
+
+// 국가 번호가 있거나 default_region으로 읽을 수 있는 유효한 번호만 받는다
+pub fn normalize_phone(
+    value: &str,
+    default_region: Option<&str>,
+) -> ActorResult<NormalizedContactField> {
+    let region = default_region
+        .map(|region| {
+            region
+                .trim()
+                .to_ascii_uppercase()
+                .parse::<country::Id>()
+                .map_err(|_| UserError::InvalidInput(format!("Unknown region: {}", region)))
+        })
+        .transpose()?;
+    let invalid = || UserError::InvalidInput(format!("Not a valid phone number: {}", value));
+    let number = phonenumber::parse(region, value.trim()).map_err(|_| invalid())?;
+    if !phonenumber::is_valid(&number) {
+        return Err(invalid());
+    }
+    let e164 = number.format().mode(Mode::E164).to_string();
+    Ok(NormalizedContactField {
+        value: e164.clone(),
+        display: number.format().mode(Mode::International).to_string(),
+        match_key: e164,
+        region: number.country().id().map(|id| id.as_ref().to_string()),
+    })
+}
+
+// 도메인은 IDNA로 ASCII(punycode) 소문자로 바꾸고, 로컬 부분은 입력 그대로 둔다
+// 비교용 키는 로컬 부분을 소문자로 하고 +태그를 떼며, Gmail은 점도 무시한다.
+pub fn normalize_email(value: &str) -> ActorResult<NormalizedContactField> {
+    let invalid = || UserError::InvalidInput(format!("Not a valid email address: {}", value));
+    let value = value.trim();
+    let value = value.strip_prefix("mailto:").unwrap_or(value);
+    let (local, domain) = value.rsplit_once('@').ok_or_else(invalid)?;
+    if local.is_empty()
+        || local.len() > 64
+        || local.contains('@')
+        || local.chars().any(|c| c.is_whitespace() || c.is_control())
+    {
+        return Err(invalid());
+    }
+    let domain = idna::domain_to_ascii(domain.trim_end_matches('.')).map_err(|_| invalid())?;
+    if !domain.contains('.') || domain.split('.').any(str::is_empty) {
+        return Err(invalid());
+    }
+    let normalized = format!("{}@{}", local, domain);
+    if normalized.len() > 254 {
+        return Err(invalid());
+    }
+
+    let mut key_local = local.to_lowercase();
+    if let Some((base, _tag)) = key_local.split_once('+')
+        && !base.is_empty()
+    {
+        key_local = base.to_string();
+    }
+    let key_domain = match domain.as_str() {
+        "gmail.com" | "googlemail.com" => {
+            key_local.retain(|c| c != '.');
+            "gmail.com"
+        }
+        domain => domain,
+    };
+    let (unicode_domain, _) = idna::domain_to_unicode(&domain);
+    Ok(NormalizedContactField {
+        display: format!("{}@{}", local, unicode_domain),
+        match_key: format!("{}@{}", key_local, key_domain),
+        value: normalized,
+        region: None,
+    })
+}
+
+pub fn normalize_contact_field(msg: &NormalizeContactField) -> ActorResult<NormalizedContactField> {
+    match msg.kind {
+        ContactFieldKind::Phone => normalize_phone(&msg.value, msg.default_region.as_deref()),
+        ContactFieldKind::Email => normalize_email(&msg.value),
+    }
+}
+
+// 폼 검증 액터
+// 폼 검증과 함께 전화번호·이메일 정규화도 맡는다 (연락처 중복 판단과 입력란이 같은 결과를 보도록).
+pub struct ValidationActor {
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for ValidationActor {}
+
+impl ValidationActor {
+    pub fn new(self_addr: Address<Self>) -> Self {
+        let owned_tasks = route_dart_signals!(
+            self_addr,
+            [ValidateFormRequest, NormalizeContactFieldRequest]
+        );
+
+        Self {
+            _owned_tasks: owned_tasks,
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<ValidateForm> for ValidationActor {
+    type Result = ActorResult<Vec<FieldError>>;
+
+    async fn handle(&mut self, msg: ValidateForm, _: &Context<Self>) -> Self::Result {
+        validate_form(&msg.schema_name, &msg.values)
+    }
+}
+
+#[async_trait]
+impl Handler<NormalizeContactField> for ValidationActor {
+    type Result = ActorResult<NormalizedContactField>;
+
+    async fn handle(&mut self, msg: NormalizeContactField, _: &Context<Self>) -> Self::Result {
+        normalize_contact_field(&msg)
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<ValidateFormRequest> for ValidationActor {
+    async fn notify(&mut self, msg: ValidateFormRequest, ctx: &Context<Self>) {
+        let _ = respond_to_dart(msg, |msg| async move {
+            let values = serde_json::from_str(&msg.values_json).map_err(|e| {
+                UserError::InvalidInput(format!("values_json is not valid JSON: {}", e))
+            })?;
+            let validate = ValidateForm {
+                schema_name: msg.schema_name,
+                values,
+            };
+            Ok(self.handle(validate, ctx).await?)
+        })
+        .await;
+    }
+}
+
+#[async_trait]
+impl Notifiable<NormalizeContactFieldRequest> for ValidationActor {
+    async fn notify(&mut self, msg: NormalizeContactFieldRequest, ctx: &Context<Self>) {
+        let _ = respond_to_dart(msg, |msg| async move {
+            let normalize = NormalizeContactField {
+                kind: msg.kind,
+                value: msg.value,
+                default_region: msg.default_region,
+            };
+            Ok(self.handle(normalize, ctx).await?)
+        })
+        .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use std::error::Error;
+
+    use super::{SCHEMAS, normalize_email, normalize_phone, validate_form};
+
+    #[test]
+    fn field_and_cross_field_rules_report_per_field_errors() -> Result<(), Box<dyn Error>> {
+        assert!(SCHEMAS.is_ok());
+
+        let errors = validate_form(
+            "sign_up",
+            &json!({
+                "email": "not-an-email",
+                "password": "abcdefgh1",
+                "password_confirm": "abcdefgh2",
+            }),
+        )?;
+        let failed: Vec<_> = errors
+            .iter()
+            .map(|e| (e.field.as_str(), e.code.as_str()))
+            .collect();
+        assert_eq!(
+            failed,
+            [("email", "pattern"), ("password_confirm", "mismatch")]
+        );
+
+        // Flutter 입력란처럼 숫자도 문자열로 온다
+        let errors = validate_form(
+            "reminder",
+            &json!({
+                "title": " ",
+                "starts_at": "2026-03-01T09:00:00+09:00",
+                "ends_at": "2026-03-01T08:00:00+09:00",
+                "repeat_every_days": "400",
+            }),
+        )?;
+        let failed: Vec<_> = errors
+            .iter()
+            .map(|e| (e.field.as_str(), e.code.as_str()))
+            .collect();
+        assert_eq!(
+            failed,
+            [
+                ("title", "required"),
+                ("repeat_every_days", "out_of_range"),
+                ("ends_at", "before"),
+            ]
+        );
+
+        // 중첩 필드는 점으로 가리킨다
+        let profile = json!({
+            "name": "Kim",
+            "email": "kim@example.com",
+            "avatar_url": null,
+            "preferences": { "theme": "dark", "language": "ko-KR" },
+        });
+        assert!(validate_form("user_profile", &profile)?.is_empty());
+        assert!(validate_form("unknown", &profile).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn phone_numbers_and_emails_normalize_to_shared_keys() -> Result<(), Box<dyn Error>> {
+        let local = normalize_phone("(201) 555-0123", Some("us"))?;
+        let international = normalize_phone("+1 201 555 0123", None)?;
+        assert_eq!(local.value, "+12015550123");
+        assert_eq!(local.match_key, international.match_key);
+        assert_eq!(local.region.as_deref(), Some("US"));
+        assert!(normalize_phone("010-1234", Some("KR")).is_err());
+        assert!(normalize_phone("2015550123", None).is_err());
+
+        let tagged = normalize_email(" Kim.Min+news@GMail.com ")?;
+        assert_eq!(tagged.value, "Kim.Min+news@gmail.com");
+        assert_eq!(
+            tagged.match_key,
+            normalize_email("kimmin@googlemail.com")?.match_key
+        );
+
+        let idn = normalize_email("user@Bücher.example")?;
+        assert_eq!(idn.value, "user@xn--bcher-kva.example");
+        assert_eq!(idn.display, "user@bücher.example");
+        assert!(normalize_email("no-at-sign").is_err());
+        assert!(normalize_email("a b@example.com").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,298 @@
+use async_trait::async_trait;
+use futures_util::{SinkExt, StreamExt};
+#[cfg(target_family = "wasm")]
+use gloo_net::websocket::{Message, futures::WebSocket};
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable},
+};
+use std::time::Duration;
+use tokio::{
+    sync::{broadcast, mpsc},
+    task::{AbortHandle, JoinSet},
+};
+#[cfg(not(target_family = "wasm"))]
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+
+use crate::study_actors::{
+    logging::debug_print,
+    messages::{
+        RealtimeConfig, RealtimeEnvelope, RealtimeError, RealtimeEvent, SendRealtimeEnvelope,
+        SetSubsystemSuspended,
+    },
+    signals::{
+        ConnectRealtimeRequest, DisconnectRealtimeRequest, EmitSignal, RealtimeConnectionSignal,
+        route_dart_signals,
+    },
+};
+
+const EVENT_CHANNEL_CAPACITY: usize = 256;
+
+// WebSocket 연결 액터
+// 수신한 봉투는 broadcast 채널로 채팅/프레즌스 등 구독 액터에 전달된다.
+pub struct WebSocketActor {
+    config: RealtimeConfig,
+    events: broadcast::Sender<RealtimeEvent>,
+    outgoing: Option<mpsc::UnboundedSender<String>>,
+    connection: Option<AbortHandle>,
+    url: Option<String>, // 연결할 주소 (백그라운드 모드에서 멈췄다가 재개할 때 다시 연결)
+    suspended: bool,
+    _owned_tasks: JoinSet<()>,
+}
+
+impl Actor for WebSocketActor {}
+
+impl WebSocketActor {
+    pub fn new(self_addr: Address<Self>, config: RealtimeConfig) -> Self {
+        let mut owned_tasks = route_dart_signals!(
+            self_addr,
+            [ConnectRealtimeRequest, DisconnectRealtimeRequest]
+        );
+
+        let (events, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+
+        // 설정에 주소가 있으면 바로 연결
+        let url = config.url.clone();
+        let connection = url.clone().map(|url| {
+            owned_tasks.spawn(Self::run_connection(
+                self_addr,
+                url,
+                events.clone(),
+                config.reconnect_max_delay_secs,
+            ))
+        });
+
+        Self {
+            config,
+            events,
+            outgoing: None,
+            connection,
+            url,
+            suspended: false,
+            _owned_tasks: owned_tasks,
+        }
+    }
+
+    // 액터 실행 전에 다른 액터를 구성할 때 호출
+    pub fn subscribe(&self) -> broadcast::Receiver<RealtimeEvent> {
+        self.events.subscribe()
+    }
+
+    // 연결이 끊기면 지수 백오프로 재연결
+    async fn run_connection(
+        mut self_addr: Address<Self>,
+        url: String,
+        events: broadcast::Sender<RealtimeEvent>,
+        max_delay_secs: u64,
+    ) {
+        let mut delay_secs = 1;
+        loop {
+            let session = Self::run_session(&mut self_addr, &url, &events);
+            // 브라우저 WebSocket은 Send가 아니지만 웹에서는 한 스레드만 쓴다
+            #[cfg(target_family = "wasm")]
+            let session = send_wrapper::SendWrapper::new(session);
+            match session.await {
+                Ok(error) => {
+                    delay_secs = 1;
+                    let _ = self_addr.notify(LinkChanged(None, error)).await;
+                    let _ = events.send(RealtimeEvent::Disconnected);
+                }
+                Err(e) => {
+                    debug_print!("Realtime connection to {} failed: {}", url, e);
+                }
+            }
+
+            tokio::time::sleep(Duration::from_secs(delay_secs)).await;
+            delay_secs = (delay_secs * 2).min(max_delay_secs.max(1));
+        }
+    }
+
+    // 한 번 연결해 끊길 때까지 주고받는다 (연결 실패는 Err, 끊긴 뒤에는 끊긴 이유를 돌려준다)
+    // 네이티브는 tokio-tungstenite, 웹은 브라우저 WebSocket을 쓴다.
+    async fn run_session(
+        self_addr: &mut Address<Self>,
+        url: &str,
+        events: &broadcast::Sender<RealtimeEvent>,
+    ) -> Result<Option<String>, String> {
+        #[cfg(not(target_family = "wasm"))]
+        let (stream, _) = connect_async(url).await.map_err(|e| e.to_string())?;
+        #[cfg(target_family = "wasm")]
+        let stream = WebSocket::open(url).map_err(|e| e.to_string())?;
+
+        let (mut sink, mut source) = stream.split();
+        let (outgoing_tx, mut outgoing_rx) = mpsc::unbounded_channel::<String>();
+        let _ = self_addr.notify(LinkChanged(Some(outgoing_tx), None)).await;
+        let _ = events.send(RealtimeEvent::Connected);
+
+        let error = loop {
+            tokio::select! {
+                outgoing = outgoing_rx.recv() => {
+                    let Some(text) = outgoing else {
+                        break None;
+                    };
+                    if let Err(e) = sink.send(text_frame(text)).await {
+                        break Some(e.to_string());
+                    }
+                }
+                incoming = source.next() => match incoming {
+                    Some(Ok(message)) if is_close_frame(&message) => break None,
+                    Some(Ok(message)) => {
+                        let Some(text) = frame_text(&message) else {
+                            continue;
+                        };
+                        match serde_json::from_str::<RealtimeEnvelope>(text) {
+                            Ok(envelope) => {
+                                let _ = events.send(RealtimeEvent::Envelope(envelope));
+                            }
+                            Err(e) => debug_print!("Invalid realtime frame: {}", e),
+                        }
+                    }
+                    None => break None,
+                    Some(Err(e)) => break Some(e.to_string()),
+                }
+            }
+        };
+        Ok(error)
+    }
+
+    // 멈춘 동안에는 주소만 기억해 두고 재개할 때 연결한다
+    fn connect(&mut self, self_addr: Address<Self>, url: String) {
+        self.disconnect();
+        self.url = Some(url.clone());
+        if self.suspended {
+            return;
+        }
+        self.connection = Some(self._owned_tasks.spawn(Self::run_connection(
+            self_addr,
+            url,
+            self.events.clone(),
+            self.config.reconnect_max_delay_secs,
+        )));
+    }
+
+    fn disconnect(&mut self) {
+        if let Some(handle) = self.connection.take() {
+            handle.abort();
+        }
+        if self.outgoing.take().is_some() {
+            let _ = self.events.send(RealtimeEvent::Disconnected);
+        }
+    }
+}
+
+// 전송 계층별 프레임 변환
+#[cfg(not(target_family = "wasm"))]
+fn text_frame(text: String) -> Message {
+    Message::text(text)
+}
+
+#[cfg(target_family = "wasm")]
+fn text_frame(text: String) -> Message {
+    Message::Text(text)
+}
+
+fn frame_text(message: &Message) -> Option<&str> {
+    match message {
+        Message::Text(text) => Some(text.as_str()),
+        _ => None,
+    }
+}
+
+// 브라우저 WebSocket은 닫기 프레임을 넘겨주지 않고 스트림을 끝낸다
+#[cfg(not(target_family = "wasm"))]
+fn is_close_frame(message: &Message) -> bool {
+    matches!(message, Message::Close(_))
+}
+
+#[cfg(target_family = "wasm")]
+fn is_close_frame(_: &Message) -> bool {
+    false
+}
+
+// 내부 메시지 정의 (연결 시 송신 채널, 끊김 시 오류)
+struct LinkChanged(Option<mpsc::UnboundedSender<String>>, Option<String>);
+
+#[async_trait]
+impl Notifiable<LinkChanged> for WebSocketActor {
+    async fn notify(&mut self, msg: LinkChanged, _: &Context<Self>) {
+        let LinkChanged(outgoing, error) = msg;
+        let connected = outgoing.is_some();
+        self.outgoing = outgoing;
+
+        debug_print!("Realtime connection changed: connected={}", connected);
+        RealtimeConnectionSignal { connected, error }.emit();
+    }
+}
+
+#[async_trait]
+impl Handler<SendRealtimeEnvelope> for WebSocketActor {
+    type Result = Result<(), RealtimeError>;
+
+    async fn handle(&mut self, msg: SendRealtimeEnvelope, _: &Context<Self>) -> Self::Result {
+        let outgoing = self
+            .outgoing
+            .as_ref()
+            .ok_or("Realtime connection is not available")?;
+        let text = serde_json::to_string(&msg.0)?;
+        outgoing
+            .send(text)
+            .map_err(|_| "Realtime connection closed".into())
+    }
+}
+
+// Dart 신호 처리
+#[async_trait]
+impl Notifiable<ConnectRealtimeRequest> for WebSocketActor {
+    async fn notify(&mut self, msg: ConnectRealtimeRequest, ctx: &Context<Self>) {
+        let Some(url) = msg.url.or_else(|| self.config.url.clone()) else {
+            RealtimeConnectionSignal {
+                connected: false,
+                error: Some("Realtime endpoint not configured".to_string()),
+            }
+            .emit();
+            return;
+        };
+
+        self.connect(ctx.address(), url);
+    }
+}
+
+#[async_trait]
+impl Notifiable<DisconnectRealtimeRequest> for WebSocketActor {
+    async fn notify(&mut self, _: DisconnectRealtimeRequest, _: &Context<Self>) {
+        let was_connected = self.outgoing.is_some();
+        self.disconnect();
+        self.url = None;
+        if was_connected {
+            RealtimeConnectionSignal {
+                connected: false,
+                error: None,
+            }
+            .emit();
+        }
+    }
+}
+
+// 백그라운드 모드 (창을 닫은 동안 연결을 끊었다가 돌아오면 같은 주소로 다시 연결)
+#[async_trait]
+impl Notifiable<SetSubsystemSuspended> for WebSocketActor {
+    async fn notify(&mut self, msg: SetSubsystemSuspended, ctx: &Context<Self>) {
+        if self.suspended == msg.suspended {
+            return;
+        }
+        self.suspended = msg.suspended;
+        if msg.suspended {
+            let was_connected = self.outgoing.is_some();
+            self.disconnect();
+            if was_connected {
+                RealtimeConnectionSignal {
+                    connected: false,
+                    error: None,
+                }
+                .emit();
+            }
+        } else if let Some(url) = self.url.clone() {
+            self.connect(ctx.address(), url);
+        }
+    }
+}
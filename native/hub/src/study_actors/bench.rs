@@ -0,0 +1,211 @@
+use messages::prelude::{Context, SendError};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt,
+    hint::black_box,
+    path::PathBuf,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use thiserror::Error;
+
+use crate::study_actors::{
+    actors::StorageActor,
+    messages::{DataItem, StorageError, StoreData},
+    signals::{CreateDataItemRequest, DataItemCreatedSignal},
+    storage::{MemoryStorage, SledStorage, Storage},
+};
+
+// 데이터 경로 처리량 측정 (bench 기능)
+// Dart 신호 해석 → 액터 전달 → 저장소 기록 → Dart 신호 직렬화를 FFI 없이 그대로 반복한다.
+// 신호는 rinf와 같은 bincode 형식으로 주고받는다.
+
+#[derive(Debug, Error)]
+pub enum BenchError {
+    #[error("Signal codec error: {0}")]
+    Codec(#[from] bincode::Error),
+    #[error("Item serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+    #[error("Actor unavailable: {0}")]
+    Actor(#[from] SendError),
+    #[error(transparent)]
+    Storage(#[from] StorageError),
+}
+
+// 측정할 메시지 크기와 반복 횟수
+#[derive(Debug, Clone, Copy)]
+pub struct BenchCase {
+    pub name: &'static str,
+    pub payload_bytes: usize,
+    pub iterations: usize,
+}
+
+pub const DEFAULT_CASES: [BenchCase; 3] = [
+    BenchCase {
+        name: "small",
+        payload_bytes: 256,
+        iterations: 5_000,
+    },
+    BenchCase {
+        name: "medium",
+        payload_bytes: 16 * 1024,
+        iterations: 1_000,
+    },
+    BenchCase {
+        name: "large",
+        payload_bytes: 1024 * 1024,
+        iterations: 50,
+    },
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchResult {
+    pub case: String,
+    pub backend: String,
+    pub payload_bytes: usize,
+    pub iterations: usize,
+    pub total_ms: f64,
+}
+
+impl BenchResult {
+    pub fn avg_micros(&self) -> f64 {
+        self.total_ms * 1000.0 / self.iterations.max(1) as f64
+    }
+
+    pub fn messages_per_sec(&self) -> f64 {
+        self.iterations as f64 / (self.total_ms / 1000.0).max(f64::EPSILON)
+    }
+
+    pub fn megabytes_per_sec(&self) -> f64 {
+        self.messages_per_sec() * self.payload_bytes as f64 / (1024.0 * 1024.0)
+    }
+}
+
+impl fmt::Display for BenchResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:<8} {:<7} {:>9} B  {:>10.1} msg/s  {:>8.1} MB/s  {:>9.1} us/msg",
+            self.case,
+            self.backend,
+            self.payload_bytes,
+            self.messages_per_sec(),
+            self.megabytes_per_sec(),
+            self.avg_micros()
+        )
+    }
+}
+
+// 모든 크기를 메모리, sled 저장소에서 각각 측정한다
+pub async fn run(cases: &[BenchCase]) -> Result<Vec<BenchResult>, BenchError> {
+    let mut results = Vec::new();
+    for case in cases {
+        let memory: Arc<dyn Storage> = Arc::new(MemoryStorage::new());
+        results.push(run_case(memory, "memory", case).await?);
+
+        let path = temp_db_path(case.name);
+        let sled: Arc<dyn Storage> = Arc::new(SledStorage::open(&path.to_string_lossy())?);
+        let result = run_case(sled, "sled", case).await;
+        let _ = std::fs::remove_dir_all(&path);
+        results.push(result?);
+    }
+    Ok(results)
+}
+
+async fn run_case(
+    storage: Arc<dyn Storage>,
+    backend: &str,
+    case: &BenchCase,
+) -> Result<BenchResult, BenchError> {
+    let storage_context = Context::new();
+    let mut storage_addr = storage_context.address();
+    let storage_task = tokio::spawn(storage_context.run(StorageActor::new(storage)));
+
+    // Dart가 보낸 것과 같은 바이트열을 미리 만들어 둔다
+    let request = CreateDataItemRequest {
+        user_id: "bench-user".to_string(),
+        title: format!("{} payload", case.name),
+        content: "x".repeat(case.payload_bytes),
+        tags: vec!["bench".to_string()],
+        temp_id: None,
+        idempotency_key: None,
+    };
+    let request_bytes = bincode::serialize(&request)?;
+
+    let started = Instant::now();
+    for index in 0..case.iterations {
+        let request: CreateDataItemRequest = bincode::deserialize(&request_bytes)?;
+        let item = DataItem {
+            id: format!("bench-{}", index),
+            title: request.title,
+            content: request.content,
+            created_at: 0,
+            updated_at: 0,
+            tags: request.tags,
+            attachments: Vec::new(),
+            owner: request.user_id.clone(),
+        };
+
+        storage_addr
+            .send(StoreData {
+                key: format!("item:{}", item.id),
+                data: serde_json::to_vec(&item)?,
+                user_id: Some(request.user_id.clone()),
+                ttl: None,
+            })
+            .await??;
+
+        let signal = DataItemCreatedSignal {
+            user_id: request.user_id,
+            item,
+        };
+        black_box(bincode::serialize(&signal)?);
+    }
+    let total = started.elapsed();
+
+    // 저장소(sled 파일 잠금 포함)가 확실히 닫히도록 액터 종료를 기다린다
+    storage_task.abort();
+    let _ = storage_task.await;
+    Ok(BenchResult {
+        case: case.name.to_string(),
+        backend: backend.to_string(),
+        payload_bytes: case.payload_bytes,
+        iterations: case.iterations,
+        total_ms: millis(total),
+    })
+}
+
+// 기준 결과보다 처리량이 허용 비율 이상 떨어진 항목을 찾는다 (예: tolerance 0.1 = 10%)
+pub fn regressions(
+    baseline: &[BenchResult],
+    current: &[BenchResult],
+    tolerance: f64,
+) -> Vec<String> {
+    current
+        .iter()
+        .filter_map(|result| {
+            let base = baseline
+                .iter()
+                .find(|base| base.case == result.case && base.backend == result.backend)?;
+            let ratio = result.messages_per_sec() / base.messages_per_sec().max(f64::EPSILON);
+            (ratio < 1.0 - tolerance).then(|| {
+                format!(
+                    "{}/{}: {:.1} msg/s -> {:.1} msg/s ({:.0}% slower)",
+                    result.case,
+                    result.backend,
+                    base.messages_per_sec(),
+                    result.messages_per_sec(),
+                    (1.0 - ratio) * 100.0
+                )
+            })
+        })
+        .collect()
+}
+
+fn temp_db_path(case: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("hub-bench-{}-{}", std::process::id(), case))
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
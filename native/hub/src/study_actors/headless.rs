@@ -0,0 +1,249 @@
+use rinf::{DartSignalPack, SignalReceiver};
+use serde::{Serialize, de::DeserializeOwned};
+use std::{
+    any::{Any, TypeId, type_name},
+    collections::HashMap,
+    sync::{Mutex, OnceLock},
+    thread::ThreadId,
+    time::Duration,
+};
+use thiserror::Error;
+use tokio::sync::{
+    Mutex as AsyncMutex,
+    broadcast::{self, error::RecvError},
+    mpsc,
+};
+
+use crate::study_actors::signals::{
+    ActorsCreatedSignal, CapturedSignal, CreateActorsRequest, FetchUserDataRequest, LoginRequest,
+    LoginResponse, UserDataResponse,
+};
+
+// Flutter 없이 액터 트리를 돌리기 위한 신호 주입/수집 (headless 기능)
+// - inject: 해당 타입을 듣는 수신기에 Dart가 보낸 것처럼 신호를 넣는다.
+// - subscribe: Dart로 나가는 신호를 FFI 대신 받아 본다.
+
+const LISTENER_WAIT: Duration = Duration::from_secs(5);
+const LISTENER_POLL: Duration = Duration::from_millis(10);
+const SIGNAL_WAIT: Duration = Duration::from_secs(10);
+const OUTGOING_CAPACITY: usize = 1024;
+
+// 테스트는 병렬로 돌므로 스레드(= 단일 스레드 테스트 런타임)별로 수신기를 나눈다
+type InjectorKey = (TypeId, Option<ThreadId>);
+type Injectors = Mutex<HashMap<InjectorKey, Box<dyn Any + Send>>>;
+
+static INJECTORS: OnceLock<Injectors> = OnceLock::new();
+static OUTGOING: OnceLock<broadcast::Sender<CapturedSignal>> = OnceLock::new();
+
+#[derive(Debug, Error)]
+pub enum InjectError {
+    #[error("No listener for {0}")]
+    NoListener(&'static str),
+    #[error("Listener for {0} has stopped")]
+    ListenerClosed(&'static str),
+}
+
+#[derive(Debug, Error)]
+pub enum ScenarioError {
+    #[error(transparent)]
+    Inject(#[from] InjectError),
+    #[error("Timed out waiting for {0}")]
+    Timeout(&'static str),
+    #[error("Login failed: {0}")]
+    LoginFailed(String),
+    #[error("Fetching user data failed: {0}")]
+    FetchFailed(String),
+}
+
+// rinf 수신기와 주입 채널을 함께 기다리는 수신기
+pub struct HeadlessReceiver<T> {
+    dart: SignalReceiver<DartSignalPack<T>>,
+    injected: AsyncMutex<mpsc::UnboundedReceiver<DartSignalPack<T>>>,
+}
+
+impl<T> HeadlessReceiver<T> {
+    pub async fn recv(&self) -> Option<DartSignalPack<T>> {
+        let mut injected = self.injected.lock().await;
+        tokio::select! {
+            Some(pack) = injected.recv() => Some(pack),
+            pack = self.dart.recv() => pack,
+        }
+    }
+}
+
+fn injectors() -> &'static Injectors {
+    INJECTORS.get_or_init(Default::default)
+}
+
+fn injector_key<T: 'static>() -> InjectorKey {
+    #[cfg(test)]
+    let scope = Some(std::thread::current().id());
+    #[cfg(not(test))]
+    let scope = None;
+    (TypeId::of::<T>(), scope)
+}
+
+// rinf와 마찬가지로 가장 나중에 만든 수신기만 신호를 받는다
+pub(crate) fn attach<T: Send + 'static>(
+    dart: SignalReceiver<DartSignalPack<T>>,
+) -> HeadlessReceiver<T> {
+    let (sender, receiver) = mpsc::unbounded_channel::<DartSignalPack<T>>();
+    if let Ok(mut injectors) = injectors().lock() {
+        injectors.insert(injector_key::<T>(), Box::new(sender));
+    }
+    HeadlessReceiver {
+        dart,
+        injected: AsyncMutex::new(receiver),
+    }
+}
+
+// 액터가 아직 뜨는 중일 수 있으므로 수신기가 생길 때까지 잠시 기다린다
+async fn wait_for_listener<T: Send + 'static>()
+-> Result<mpsc::UnboundedSender<DartSignalPack<T>>, InjectError> {
+    let deadline = tokio::time::Instant::now() + LISTENER_WAIT;
+    loop {
+        let sender = injectors().lock().ok().and_then(|injectors| {
+            injectors
+                .get(&injector_key::<T>())
+                .and_then(|sender| {
+                    sender.downcast_ref::<mpsc::UnboundedSender<DartSignalPack<T>>>()
+                })
+                .cloned()
+        });
+        if let Some(sender) = sender.filter(|sender| !sender.is_closed()) {
+            return Ok(sender);
+        }
+        if tokio::time::Instant::now() >= deadline {
+            return Err(InjectError::NoListener(type_name::<T>()));
+        }
+        tokio::time::sleep(LISTENER_POLL).await;
+    }
+}
+
+pub async fn inject<T: Send + 'static>(message: T) -> Result<(), InjectError> {
+    inject_binary(message, Vec::new()).await
+}
+
+pub async fn inject_binary<T: Send + 'static>(
+    message: T,
+    binary: Vec<u8>,
+) -> Result<(), InjectError> {
+    let sender = wait_for_listener::<T>().await?;
+    sender
+        .send(DartSignalPack { message, binary })
+        .map_err(|_| InjectError::ListenerClosed(type_name::<T>()))
+}
+
+fn outgoing() -> &'static broadcast::Sender<CapturedSignal> {
+    OUTGOING.get_or_init(|| broadcast::channel(OUTGOING_CAPACITY).0)
+}
+
+// 구독자가 있을 때만 가로챈다 (없으면 평소처럼 FFI로 보낸다)
+pub(crate) fn capture<T: Serialize>(signal: &T, binary: &[u8]) -> bool {
+    let outgoing = outgoing();
+    if outgoing.receiver_count() == 0 {
+        return false;
+    }
+    let _ = outgoing.send(CapturedSignal::new(signal, binary));
+    true
+}
+
+pub fn subscribe() -> broadcast::Receiver<CapturedSignal> {
+    outgoing().subscribe()
+}
+
+// T 타입 신호가 나올 때까지 기다린다 (다른 신호는 건너뛴다)
+pub async fn expect_signal<T: DeserializeOwned>(
+    outgoing: &mut broadcast::Receiver<CapturedSignal>,
+    timeout: Duration,
+) -> Option<T> {
+    let wait = async {
+        loop {
+            match outgoing.recv().await {
+                Ok(signal) => {
+                    if let Some(decoded) = signal.decode::<T>() {
+                        return Some(decoded);
+                    }
+                }
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    };
+    tokio::time::timeout(timeout, wait).await.ok().flatten()
+}
+
+#[derive(Debug)]
+pub struct ScenarioReport {
+    pub actor_count: usize,
+    pub user_id: String,
+    pub item_count: usize,
+}
+
+// 감독자 트리 생성 → 로그인 → 사용자 데이터 조회를 Dart 없이 수행한다
+pub async fn run_login_scenario(
+    username: &str,
+    password: &str,
+) -> Result<ScenarioReport, ScenarioError> {
+    let mut outgoing = subscribe();
+    tokio::spawn(crate::study_actors::initialize());
+
+    inject(CreateActorsRequest {
+        initialize_all: false,
+        config_path: None,
+    })
+    .await?;
+    let created = expect_signal::<ActorsCreatedSignal>(&mut outgoing, SIGNAL_WAIT)
+        .await
+        .ok_or(ScenarioError::Timeout("ActorsCreatedSignal"))?;
+
+    inject(LoginRequest {
+        correlation_id: 1,
+        username: username.to_string(),
+        password: password.to_string(),
+        idempotency_key: None,
+    })
+    .await?;
+    let login = expect_signal::<LoginResponse>(&mut outgoing, SIGNAL_WAIT)
+        .await
+        .ok_or(ScenarioError::Timeout("LoginResponse"))?;
+    let user_id = match (login.success, login.user_id) {
+        (true, Some(user_id)) => user_id,
+        _ => {
+            let error = login.error.map(|error| error.message).unwrap_or_default();
+            return Err(ScenarioError::LoginFailed(error));
+        }
+    };
+
+    inject(FetchUserDataRequest {
+        user_id: user_id.clone(),
+        limit: Some(10),
+    })
+    .await?;
+    let user_data = expect_signal::<UserDataResponse>(&mut outgoing, SIGNAL_WAIT)
+        .await
+        .ok_or(ScenarioError::Timeout("UserDataResponse"))?;
+    if let Some(error) = user_data.error {
+        return Err(ScenarioError::FetchFailed(error));
+    }
+
+    Ok(ScenarioReport {
+        actor_count: created.actor_count as usize,
+        user_id,
+        item_count: user_data.items.len(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ScenarioError, run_login_scenario};
+
+    #[tokio::test]
+    async fn login_and_fetch_through_supervisor() -> Result<(), ScenarioError> {
+        let report = run_login_scenario("demo", "password").await?;
+        assert!(report.actor_count > 0);
+        assert_eq!(report.user_id, "user_1");
+        assert_eq!(report.item_count, 10);
+        Ok(())
+    }
+}
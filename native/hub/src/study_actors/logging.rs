@@ -0,0 +1,108 @@
+use chrono::Utc;
+use serde::Serialize;
+use std::{
+    collections::VecDeque,
+    sync::{
+        Mutex,
+        atomic::{AtomicU8, Ordering},
+    },
+};
+
+use crate::study_actors::messages::LogLevel;
+
+// 진단 묶음에 담을 최근 로그 줄 수
+const MAX_LOG_LINES: usize = 2_000;
+
+static RECENT_LOGS: Mutex<VecDeque<LogLine>> = Mutex::new(VecDeque::new());
+// 관리 명령으로 잠시 올리거나 내릴 수 있다 (AdminActor)
+static LOG_LEVEL: AtomicU8 = AtomicU8::new(LogLevel::Debug as u8);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LogLine {
+    pub at_ms: i64,
+    pub message: String,
+}
+
+pub fn record_log(message: String) {
+    if let Ok(mut lines) = RECENT_LOGS.lock() {
+        if lines.len() == MAX_LOG_LINES {
+            lines.pop_front();
+        }
+        lines.push_back(LogLine {
+            at_ms: Utc::now().timestamp_millis(),
+            message,
+        });
+    }
+}
+
+pub fn log_level() -> LogLevel {
+    match LOG_LEVEL.load(Ordering::Relaxed) {
+        0 => LogLevel::Info,
+        1 => LogLevel::Debug,
+        _ => LogLevel::Trace,
+    }
+}
+
+pub fn set_log_level(level: LogLevel) {
+    LOG_LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn log_enabled(level: LogLevel) -> bool {
+    level <= log_level()
+}
+
+// 오래된 줄부터
+pub fn recent_logs() -> Vec<LogLine> {
+    RECENT_LOGS
+        .lock()
+        .map(|lines| lines.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+// 저장 공간 화면에 보일 최근 로그의 (바이트, 줄 수)
+pub fn recent_log_usage() -> (u64, usize) {
+    RECENT_LOGS
+        .lock()
+        .map(|lines| {
+            let bytes = lines.iter().map(|line| line.message.len() as u64).sum();
+            (bytes, lines.len())
+        })
+        .unwrap_or_default()
+}
+
+// 지운 줄 수
+pub fn clear_logs() -> usize {
+    RECENT_LOGS
+        .lock()
+        .map(|mut lines| {
+            let count = lines.len();
+            lines.clear();
+            count
+        })
+        .unwrap_or_default()
+}
+
+// rinf의 debug_print!와 같지만 마지막 줄들을 메모리에도 남긴다
+// rinf는 릴리스 빌드에서 출력하지 않으므로, 지원 요청에 붙일 로그는 여기서만 얻을 수 있다.
+macro_rules! debug_print {
+    ($($arg:tt)*) => {{
+        if $crate::study_actors::logging::log_enabled($crate::study_actors::messages::LogLevel::Debug) {
+            let line = format!($($arg)*);
+            rinf::debug_print!("{}", line);
+            $crate::study_actors::logging::record_log(line);
+        }
+    }};
+}
+pub(crate) use debug_print;
+
+// 로그 상세도를 Trace로 올렸을 때만 남기는 줄 (메시지마다 찍히는 자세한 기록)
+macro_rules! trace_print {
+    ($($arg:tt)*) => {{
+        if $crate::study_actors::logging::log_enabled($crate::study_actors::messages::LogLevel::Trace) {
+            let line = format!($($arg)*);
+            rinf::debug_print!("{}", line);
+            $crate::study_actors::logging::record_log(line);
+        }
+    }};
+}
+pub(crate) use trace_print;
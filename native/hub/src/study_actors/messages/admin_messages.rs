@@ -0,0 +1,60 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+// 로그 상세도 (debug_print!는 Debug, 메시지 단계별 소요 시간은 Trace에서 남는다)
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize, SignalPiece,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum LogLevel {
+    Info,
+    #[default]
+    Debug,
+    Trace,
+}
+
+// 관리 서버가 서명해 푸시나 폴링 엔드포인트로 보내는 원격 명령
+// payload 원문 바이트에 대한 Ed25519 서명을 admin.public_key로 확인한 뒤에만 적용한다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignedAdminCommand {
+    pub payload: String,   // AdminCommand JSON
+    pub signature: String, // hex
+}
+
+// 모든 원격 명령은 expires_at에 저절로 되돌려진다
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdminCommand {
+    pub id: String, // 같은 명령을 두 번 적용하지 않도록 확인
+    pub issued_at: u64,
+    pub expires_at: u64, // admin.max_duration_secs보다 길게 잡을 수 없다
+    pub action: AdminAction,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, SignalPiece)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AdminAction {
+    SetLogLevel { level: LogLevel },
+    RecordSignals, // 보낸 Dart 신호를 진단 묶음에 남긴다
+    SetFeatureFlag { flag: String, enabled: bool },
+    // 기기 데이터와 비밀 값을 모두 지운다 (되돌릴 것이 없으므로 적용 중인 명령으로 남지 않는다)
+    RemoteWipe { reason: Option<String> },
+}
+
+// 적용 중인 원격 명령
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct AdminOverride {
+    pub command_id: String,
+    pub action: AdminAction,
+    pub applied_at: u64,
+    pub expires_at: u64,
+}
+
+// 서명을 확인하고 명령을 적용한다
+#[derive(Debug, Clone)]
+pub struct ApplyAdminCommand {
+    pub command: SignedAdminCommand,
+}
+
+// 적용 중인 원격 명령 (먼저 끝나는 순)
+#[derive(Debug, Clone)]
+pub struct ListAdminOverrides;
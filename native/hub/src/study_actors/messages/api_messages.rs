@@ -0,0 +1,303 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+
+use super::{
+    Entitlement, PlanLimits, PurchaseReceipt, SyncPayload, UserId, user_messages::UserProfile,
+};
+
+// 서버 API 엔드포인트 정의
+// PATH의 {name} 자리는 path_params 값으로 채우고(퍼센트 인코딩), 쿼리는 Query를 직렬화해 붙인다.
+// 응답 본문이 비어 있으면 null로 해석하므로 Response = ()인 엔드포인트도 쓸 수 있다.
+pub trait Endpoint {
+    type Query: Serialize;
+    type Body: Serialize;
+    type Response: DeserializeOwned;
+
+    const METHOD: Method;
+    const PATH: &'static str;
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        Vec::new()
+    }
+
+    fn query(&self) -> Option<&Self::Query> {
+        None
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        None
+    }
+}
+
+// 로그인/인가 코드 교환의 공통 응답
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenResponse {
+    pub user_id: UserId,
+    pub access_token: String,
+    pub expires_in: u64, // 초
+}
+
+// 인증 서비스
+#[derive(Debug, Clone, Serialize)]
+pub struct LoginEndpoint {
+    pub username: String,
+    pub password: String,
+}
+
+impl Endpoint for LoginEndpoint {
+    type Query = ();
+    type Body = Self;
+    type Response = TokenResponse;
+
+    const METHOD: Method = Method::POST;
+    const PATH: &'static str = "/auth/login";
+
+    fn body(&self) -> Option<&Self::Body> {
+        Some(self)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExchangeAuthCodeEndpoint {
+    pub code: String,
+    pub state: Option<String>,
+}
+
+impl Endpoint for ExchangeAuthCodeEndpoint {
+    type Query = ();
+    type Body = Self;
+    type Response = TokenResponse;
+
+    const METHOD: Method = Method::POST;
+    const PATH: &'static str = "/auth/token";
+
+    fn body(&self) -> Option<&Self::Body> {
+        Some(self)
+    }
+}
+
+// 프로필 서비스
+#[derive(Debug, Clone)]
+pub struct GetProfileEndpoint {
+    pub user_id: UserId,
+}
+
+impl Endpoint for GetProfileEndpoint {
+    type Query = ();
+    type Body = ();
+    type Response = UserProfile;
+
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/users/{user_id}/profile";
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("user_id", &self.user_id)]
+    }
+}
+
+// 서버가 정규화한 프로필을 돌려준다
+#[derive(Debug, Clone)]
+pub struct UpdateProfileEndpoint {
+    pub profile: UserProfile,
+}
+
+impl Endpoint for UpdateProfileEndpoint {
+    type Query = ();
+    type Body = UserProfile;
+    type Response = UserProfile;
+
+    const METHOD: Method = Method::PUT;
+    const PATH: &'static str = "/users/{user_id}/profile";
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("user_id", &self.profile.user_id)]
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        Some(&self.profile)
+    }
+}
+
+// 사용자 디렉터리
+#[derive(Debug, Clone, Serialize)]
+pub struct UserSearchQuery {
+    pub q: String,
+    pub limit: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchUsersEndpoint {
+    pub query: UserSearchQuery,
+}
+
+impl Endpoint for SearchUsersEndpoint {
+    type Query = UserSearchQuery;
+    type Body = ();
+    type Response = Vec<UserProfile>;
+
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/users/search";
+
+    fn query(&self) -> Option<&Self::Query> {
+        Some(&self.query)
+    }
+}
+
+// 데이터 서비스
+#[derive(Debug, Clone, Serialize)]
+pub struct ItemListQuery {
+    pub cursor: Option<String>,
+    pub limit: usize,
+}
+
+// 서버에 따라 다음 페이지를 커서나 링크로 알려 준다 (둘 다 없으면 마지막 페이지)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteItemPage {
+    pub items: Vec<SyncPayload>,
+    #[serde(default)]
+    pub next_cursor: Option<String>,
+    #[serde(default)]
+    pub next_page: Option<String>, // 절대 URL 또는 API 기본 URL 기준 상대 경로
+}
+
+#[derive(Debug, Clone)]
+pub struct ListItemsEndpoint {
+    pub user_id: UserId,
+    pub query: ItemListQuery,
+}
+
+impl Endpoint for ListItemsEndpoint {
+    type Query = ItemListQuery;
+    type Body = ();
+    type Response = RemoteItemPage;
+
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/users/{user_id}/items";
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("user_id", &self.user_id)]
+    }
+
+    fn query(&self) -> Option<&Self::Query> {
+        Some(&self.query)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GetItemEndpoint {
+    pub user_id: UserId,
+    pub item_id: String,
+}
+
+impl Endpoint for GetItemEndpoint {
+    type Query = ();
+    type Body = ();
+    type Response = SyncPayload;
+
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/users/{user_id}/items/{item_id}";
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("user_id", &self.user_id), ("item_id", &self.item_id)]
+    }
+}
+
+// 지운 항목의 삭제 표시 (서버는 deleted_at보다 오래된 사본을 지운다)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeletedItem {
+    pub id: String,
+    pub deleted_at: u64,
+}
+
+// 항목을 올린다 (서버는 id별로 updated_at이 더 새로운 쪽을 남긴다)
+#[derive(Debug, Clone, Serialize)]
+pub struct PushItemsBody {
+    pub items: Vec<SyncPayload>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub deleted: Vec<DeletedItem>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PushItemsEndpoint {
+    pub user_id: UserId,
+    pub body: PushItemsBody,
+}
+
+impl Endpoint for PushItemsEndpoint {
+    type Query = ();
+    type Body = PushItemsBody;
+    type Response = ();
+
+    const METHOD: Method = Method::POST;
+    const PATH: &'static str = "/users/{user_id}/items";
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("user_id", &self.user_id)]
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        Some(&self.body)
+    }
+}
+
+// 요금제 서비스
+#[derive(Debug, Clone)]
+pub struct GetPlanLimitsEndpoint {
+    pub user_id: UserId,
+}
+
+impl Endpoint for GetPlanLimitsEndpoint {
+    type Query = ();
+    type Body = ();
+    type Response = PlanLimits;
+
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/users/{user_id}/plan";
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("user_id", &self.user_id)]
+    }
+}
+
+// 결제 서비스 (서버가 스토어에 영수증을 확인하고 사용자의 권한 전체를 돌려준다)
+#[derive(Debug, Clone)]
+pub struct ValidateReceiptEndpoint {
+    pub user_id: UserId,
+    pub receipt: PurchaseReceipt,
+}
+
+impl Endpoint for ValidateReceiptEndpoint {
+    type Query = ();
+    type Body = PurchaseReceipt;
+    type Response = Vec<Entitlement>;
+
+    const METHOD: Method = Method::POST;
+    const PATH: &'static str = "/users/{user_id}/receipts";
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("user_id", &self.user_id)]
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        Some(&self.receipt)
+    }
+}
+
+// 영수증 없이 서버에 남은 권한을 다시 확인한다 (구독 갱신, 환불 반영)
+#[derive(Debug, Clone)]
+pub struct GetEntitlementsEndpoint {
+    pub user_id: UserId,
+}
+
+impl Endpoint for GetEntitlementsEndpoint {
+    type Query = ();
+    type Body = ();
+    type Response = Vec<Entitlement>;
+
+    const METHOD: Method = Method::GET;
+    const PATH: &'static str = "/users/{user_id}/entitlements";
+
+    fn path_params(&self) -> Vec<(&'static str, &str)> {
+        vec![("user_id", &self.user_id)]
+    }
+}
@@ -0,0 +1,26 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+// Flutter AppLifecycleState와 대응
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub enum AppLifecycleState {
+    Resumed,
+    Inactive,
+    Hidden,
+    Paused,
+    Detached,
+}
+
+// 시작 단계 구분 (즉시 시작 / Dart 콜드 스타트 완료 후 지연 시작)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub enum StartupPhase {
+    Eager,
+    Deferred,
+}
+
+// 시작 단계별 소요 시간 (밀리초)
+#[derive(Debug, Clone, Serialize, Deserialize, SignalPiece)]
+pub struct StartupStep {
+    pub name: String,
+    pub elapsed_ms: f64,
+}
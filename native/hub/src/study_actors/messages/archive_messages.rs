@@ -0,0 +1,40 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ArchiveFormat {
+    Zip,
+    TarGz,
+}
+
+impl ArchiveFormat {
+    // 파일 확장자로 압축 형식 판별
+    pub fn from_path(path: &str) -> Option<Self> {
+        let lower = path.to_lowercase();
+        if lower.ends_with(".zip") {
+            Some(Self::Zip)
+        } else if lower.ends_with(".tar.gz") || lower.ends_with(".tgz") {
+            Some(Self::TarGz)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub enum ArchiveOperation {
+    Create,
+    Extract,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateArchive {
+    pub paths: Vec<String>,
+    pub dest: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtractArchive {
+    pub archive: String,
+    pub dest: String,
+}
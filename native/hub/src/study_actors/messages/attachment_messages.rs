@@ -0,0 +1,59 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+// 항목에 연결된 첨부 파일 메타데이터
+// 파일 본문은 내용 해시(SHA-256)를 이름으로 하는 블롭으로 보관한다.
+#[derive(Debug, Clone, Serialize, Deserialize, SignalPiece)]
+pub struct Attachment {
+    pub id: String,
+    pub item_id: String,
+    pub file_name: String,
+    pub mime_type: String,
+    pub size: u64,
+    pub blob_hash: Option<String>, // None이면 아직 내려받지 않은 원격 첨부
+    pub thumbnail_hash: Option<String>,
+    pub remote_url: Option<String>,
+    pub created_at: u64,
+}
+
+// 로컬 파일을 블롭 저장소로 복사해 항목에 첨부
+#[derive(Debug, Clone)]
+pub struct AttachFile {
+    pub item_id: String,
+    pub path: String,
+}
+
+// 메모리에 있는 내용을 블롭 저장소에 써서 항목에 첨부 (공유 시트로 받은 이미지 등)
+// mime_type이 없으면 파일 이름의 확장자로 정한다.
+#[derive(Debug, Clone)]
+pub struct AttachBytes {
+    pub item_id: String,
+    pub file_name: String,
+    pub mime_type: Option<String>,
+    pub bytes: Vec<u8>,
+}
+
+// 원격 파일은 메타데이터만 기록하고 처음 열 때 내려받는다
+#[derive(Debug, Clone)]
+pub struct AttachRemoteFile {
+    pub item_id: String,
+    pub url: String,
+    pub file_name: String,
+    pub size: u64,
+}
+
+// 로컬 블롭이 준비된 첨부 반환 (원격이면 이때 내려받음)
+#[derive(Debug, Clone)]
+pub struct OpenAttachment {
+    pub attachment_id: String,
+}
+
+// 어떤 첨부에서도 참조하지 않는 블롭 삭제, 결과는 삭제된 파일 수
+#[derive(Debug, Clone)]
+pub struct CollectOrphanedBlobs;
+
+// 데이터 관리자에 항목의 첨부 목록 갱신 요청 (같은 id면 교체)
+#[derive(Debug, Clone)]
+pub struct RecordItemAttachment {
+    pub attachment: Attachment,
+}
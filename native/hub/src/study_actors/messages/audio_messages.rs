@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+// UI 파형 표시용 구간별 최대 진폭 (0.0 ~ 1.0)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComputeWaveform {
+    pub path: String,
+    pub bucket_count: usize,
+}
+
+// 업로드 전 음성 녹음을 Ogg/Opus로 변환 (결과: 변환된 바이트)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TranscodeToOpus {
+    pub path: String,
+    pub bitrate_bps: Option<i32>,
+}
@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use super::UserId;
+use super::{UserData, UserId, UserProfile};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Login {
@@ -18,15 +18,34 @@ pub struct VerifyToken {
     pub token: String,
 }
 
+// OAuth 리다이렉트로 받은 인가 코드를 세션으로 교환
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExchangeAuthCode {
+    pub code: String,
+    pub state: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProcessLogin {
     pub username: String,
     pub password: String,
 }
 
+// 로그인한 뒤 받은 프로필과 최근 항목 (ProcessLogin의 결과)
+#[derive(Debug, Clone)]
+pub struct UserSession {
+    pub token: String,
+    pub profile: UserProfile,
+    pub recent_data: UserData,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuthResult {
     pub user_id: UserId,
     pub token: String,
     pub expires_at: u64,
 }
+
+// 모든 세션을 끝낸다 (원격 삭제용, 결과: 세션이 끝난 사용자)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevokeAllSessions;
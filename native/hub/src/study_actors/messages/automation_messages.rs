@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+
+use super::UserId;
+
+// 자동화 규칙 DSL (Dart에서 JSON으로 직렬화해 전달)
+// 예: {"id":"r1","name":"태그 알림","trigger":{"type":"data_item_created"},
+//      "condition":{"type":"has_tag","tag":"todo"},
+//      "actions":[{"type":"schedule_notification","title":"{title}","body":"확인하세요","delay_secs":3600}]}
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutomationRule {
+    pub id: String,
+    pub name: String,
+    pub trigger: RuleTrigger,
+    #[serde(default)]
+    pub condition: RuleCondition,
+    pub actions: Vec<RuleAction>,
+    #[serde(default = "enabled_by_default")]
+    pub enabled: bool,
+}
+
+fn enabled_by_default() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RuleTrigger {
+    DataItemCreated,
+    DataItemUpdated,
+    DataItemDeleted,
+    ConnectivityRestored,
+    ConnectivityLost,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RuleCondition {
+    #[default]
+    Always,
+    HasTag {
+        tag: String,
+    },
+    TitleContains {
+        text: String,
+    },
+    All {
+        conditions: Vec<RuleCondition>,
+    },
+    Any {
+        conditions: Vec<RuleCondition>,
+    },
+    Not {
+        condition: Box<RuleCondition>,
+    },
+}
+
+// 알림 제목/본문의 {title}, {item_id}는 이벤트 항목 값으로 치환된다
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RuleAction {
+    ScheduleNotification {
+        title: String,
+        body: String,
+        delay_secs: u64,
+    },
+    TriggerSync {
+        user_id: Option<UserId>,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegisterRule(pub AutomationRule);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoveRule {
+    pub rule_id: String,
+}
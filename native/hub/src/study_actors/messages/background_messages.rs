@@ -0,0 +1,37 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+// 창을 닫아도 계속 돌릴 수 있는 하위 시스템 (데스크톱 백그라운드 모드)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, SignalPiece)]
+pub enum BackgroundSubsystem {
+    Sync,          // 원격 항목 가져오기
+    Realtime,      // WebSocket 연결
+    Notifications, // 로컬/푸시 알림 전달
+}
+
+// 백그라운드 모드가 없는 타깃에서는 쓰지 않는다
+#[cfg_attr(
+    any(target_family = "wasm", target_os = "android", target_os = "ios"),
+    allow(dead_code)
+)]
+impl BackgroundSubsystem {
+    pub const ALL: [Self; 3] = [Self::Sync, Self::Realtime, Self::Notifications];
+}
+
+// 하위 시스템을 멈춘 주체 (둘 다 풀려야 다시 돈다)
+#[cfg_attr(
+    any(target_family = "wasm", target_os = "android", target_os = "ios"),
+    allow(dead_code)
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SuspendReason {
+    Background, // 데스크톱 백그라운드 모드
+    LowPower,   // 배터리 절전
+}
+
+// 멈출 하위 시스템 액터에 보낸다 (포그라운드로 돌아오거나 절전이 끝나면 suspended: false)
+#[derive(Debug, Clone, Copy)]
+pub struct SetSubsystemSuspended {
+    pub suspended: bool,
+    pub reason: SuspendReason,
+}
@@ -0,0 +1,42 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+use super::UserId;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, SignalPiece)]
+#[serde(rename_all = "snake_case")]
+pub enum PurchaseStore {
+    AppStore,
+    GooglePlay,
+}
+
+// 스토어가 구매 후 앱에 넘겨준 영수증 (App Store는 영수증 데이터, Google Play는 구매 토큰)
+#[derive(Debug, Clone, Serialize, Deserialize, SignalPiece)]
+pub struct PurchaseReceipt {
+    pub store: PurchaseStore,
+    pub product_id: String,
+    pub receipt: String,
+}
+
+// 서버가 영수증을 스토어에 확인한 뒤 열어 준 권한
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct Entitlement {
+    pub id: String, // 예: "premium"
+    pub product_id: String,
+    #[serde(default)]
+    pub features: Vec<String>, // 켜지는 기능 플래그
+    pub expires_at: Option<u64>, // None이면 평생 구매
+}
+
+// 영수증을 서버로 확인하고 사용자의 권한 전체를 새로 받는다
+#[derive(Debug, Clone)]
+pub struct ValidateReceipt {
+    pub user_id: UserId,
+    pub receipt: PurchaseReceipt,
+}
+
+// 지금 유효한 권한
+#[derive(Debug, Clone)]
+pub struct GetEntitlements {
+    pub user_id: UserId,
+}
@@ -0,0 +1,40 @@
+use super::UserId;
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+// 항목에 붙은 일정 정보 (제목과 설명은 항목의 title, content)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct CalendarEvent {
+    pub item_id: String,
+    pub uid: String,      // iCalendar UID (다시 가져올 때 같은 일정인지 판단)
+    pub start: i64,       // 유닉스 타임스탬프 (초)
+    pub end: Option<i64>, // 하루 종일 일정이면 다음 날 0시 (RFC 5545처럼 끝은 포함하지 않는다)
+    pub all_day: bool,
+    pub timezone: String,      // IANA 이름, 반복과 내보내기의 현지 시각 기준
+    pub rrule: Option<String>, // 예: "FREQ=WEEKLY;BYDAY=MO"
+    pub exdates: Vec<i64>,     // 제외할 발생 시각
+    pub location: Option<String>,
+}
+
+// RFC 5545 텍스트의 VEVENT를 항목으로 가져온다
+// TZID가 없는 현지 시각과 하루 종일 날짜는 timezone(IANA 이름) 기준으로 해석한다.
+#[derive(Debug, Clone)]
+pub struct ImportIcs {
+    pub user_id: UserId,
+    pub ics: String,
+    pub timezone: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct IcsImport {
+    pub imported: Vec<CalendarEvent>,
+    pub duplicates: u64,      // 이미 가져온 UID라 건너뛴 일정 수
+    pub skipped: Vec<String>, // 읽지 못한 일정과 그 이유
+}
+
+// item_ids가 없으면 사용자의 모든 일정을 내보낸다 (지워진 항목은 빠진다)
+#[derive(Debug, Clone)]
+pub struct ExportIcs {
+    pub user_id: UserId,
+    pub item_ids: Option<Vec<String>>,
+}
@@ -0,0 +1,40 @@
+use super::UserId;
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+// 선언 순서대로 진행되며 상태는 뒤로 돌아가지 않는다
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize, SignalPiece)]
+pub enum DeliveryStatus {
+    Pending, // 오프라인 등으로 발신 대기열에 있음
+    Sent,    // 서버가 수신 확인
+    Delivered,
+    Read,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SignalPiece)]
+pub struct Conversation {
+    pub id: String,
+    pub title: String,
+    pub participants: Vec<UserId>,
+    pub last_message_preview: Option<String>,
+    pub unread_count: u32,
+    pub updated_at: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SignalPiece)]
+pub struct ChatMessage {
+    pub id: String,
+    pub conversation_id: String,
+    pub sender_id: UserId,
+    pub body: String,
+    pub sent_at: u64,
+    pub status: DeliveryStatus,
+}
+
+// 다른 액터에서 채팅 메시지를 보낼 때 사용 (결과: 대기열에 추가된 메시지)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SendChatMessage {
+    pub user_id: UserId,
+    pub conversation_id: String,
+    pub body: String,
+}
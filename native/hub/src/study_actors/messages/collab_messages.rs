@@ -0,0 +1,30 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+// 텍스트 편집 하나 (index/delete는 Dart 문자열과 같은 UTF-16 단위)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, SignalPiece)]
+pub struct TextEdit {
+    pub index: u32,
+    pub delete: u32,
+    pub insert: String,
+}
+
+// 로컬 편집을 순서대로 적용하고 다른 기기로 업데이트 전송
+#[derive(Debug, Clone)]
+pub struct ApplyEdit {
+    pub item_id: String,
+    pub edits: Vec<TextEdit>,
+}
+
+// 원격 yrs 업데이트(v1 인코딩) 병합, 결과는 바뀐 구간
+#[derive(Debug, Clone)]
+pub struct MergeRemoteUpdate {
+    pub item_id: String,
+    pub update: Vec<u8>,
+}
+
+// 문서를 열고 현재 본문 반환 (저장된 상태가 없으면 항목 본문으로 시작)
+#[derive(Debug, Clone)]
+pub struct OpenDocument {
+    pub item_id: String,
+}
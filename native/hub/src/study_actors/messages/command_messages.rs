@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+use super::UserId;
+
+// 로컬 명령 채널로 받을 수 있는 명령 (요청 본문 JSON의 "type"으로 구분)
+// 설정의 allowed_commands에는 type 이름을 적는다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum InboundCommand {
+    // 딥 링크를 해석해 앱 화면을 이동한다
+    OpenDeepLink {
+        uri: String,
+    },
+    // 서버 항목 목록을 다시 받는다
+    RefreshRemote {
+        user_id: UserId,
+        page_size: Option<usize>,
+    },
+    // 기기 데이터를 모두 지운다 (감독자의 원격 삭제 흐름을 그대로 탄다)
+    RemoteWipe {
+        reason: Option<String>,
+    },
+}
+
+impl InboundCommand {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::OpenDeepLink { .. } => "open_deep_link",
+            Self::RefreshRemote { .. } => "refresh_remote",
+            Self::RemoteWipe { .. } => "remote_wipe",
+        }
+    }
+}
+
+// 서명 확인 전의 명령 요청 (리스너가 HTTP 요청에서 꺼내 전달)
+// signature = hex(HMAC-SHA256(secret, "{timestamp}.{body}")), "sha256=" 접두사는 있어도 된다.
+#[derive(Debug, Clone)]
+pub struct SignedCommand {
+    pub peer: String,
+    pub timestamp: String,
+    pub signature: String,
+    pub body: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommandReceipt {
+    pub command: String,
+    pub detail: Option<String>, // open_deep_link는 이동한 경로
+}
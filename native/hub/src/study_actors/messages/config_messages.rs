@@ -0,0 +1,629 @@
+use serde::{Deserialize, Serialize};
+
+// 앱 전체 설정 (기본값 < 설정 파일 < Dart 오버라이드 순으로 병합됨)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AppConfig {
+    pub network: NetworkConfig,
+    pub api: ApiConfig,
+    pub cache: CacheConfig,
+    pub auth: AuthConfig,
+    pub i18n: I18nConfig,
+    pub notification: NotificationConfig,
+    pub time: TimeConfig,
+    pub storage: StorageConfig,
+    pub realtime: RealtimeConfig,
+    pub deep_link: DeepLinkConfig,
+    pub command_channel: CommandChannelConfig,
+    pub admin: AdminConfig,
+    pub scripting: ScriptConfig,
+    pub rules: RulesConfig,
+    pub quota: QuotaConfig,
+    pub billing: BillingConfig,
+    pub embedding: EmbeddingConfig,
+    pub attachment: AttachmentConfig,
+    pub media_cache: MediaCacheConfig,
+    pub metrics: MetricsConfig,
+    pub startup: StartupConfig,
+    pub timeouts: TimeoutConfig,
+    pub currency: CurrencyConfig,
+    pub text_analysis: TextAnalysisConfig,
+    pub template: TemplateConfig,
+    pub mail: MailConfig,
+    pub ingest: IngestConfig,
+    pub link_preview: LinkPreviewConfig,
+    pub feed_reader: FeedReaderConfig,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NetworkConfig {
+    pub max_connections: usize,
+    pub monitor_interval_secs: u64,
+    pub force_http1: bool, // HTTP/2를 잘못 다루는 프록시를 디버깅할 때 사용
+    // GET ?bytes=N에 N바이트로 답하고 POST 본문은 받아서 버리는 엔드포인트
+    pub speed_test_url: Option<String>,
+    pub speed_test_download_bytes: u64,
+    pub speed_test_upload_bytes: u64,
+}
+
+impl Default for NetworkConfig {
+    fn default() -> Self {
+        Self {
+            max_connections: 10,
+            monitor_interval_secs: 30,
+            force_http1: false,
+            speed_test_url: None,
+            speed_test_download_bytes: 4_000_000,
+            speed_test_upload_bytes: 1_000_000,
+        }
+    }
+}
+
+// 서버 API (base_url이 없으면 인증/프로필/데이터는 기기 안에서만 처리한다)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ApiConfig {
+    pub base_url: Option<String>, // 예: "https://api.example.com/v1"
+    pub timeout_ms: u64,
+    pub circuit_failure_threshold: u32, // 이만큼 연속으로 실패하면 호스트 차단
+    pub circuit_cooldown_ms: u64,       // 차단 후 확인 요청을 보내기까지 쉬는 시간
+}
+
+impl Default for ApiConfig {
+    fn default() -> Self {
+        Self {
+            base_url: None,
+            timeout_ms: 15_000,
+            circuit_failure_threshold: 5,
+            circuit_cooldown_ms: 30_000,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CacheConfig {
+    pub default_ttl_secs: u64,
+    pub cleanup_interval_secs: u64,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            default_ttl_secs: 3600, // 1시간 캐시
+            cleanup_interval_secs: 60,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AuthConfig {
+    pub token_ttl_secs: u64,
+    pub expiry_check_interval_secs: u64,
+}
+
+impl Default for AuthConfig {
+    fn default() -> Self {
+        Self {
+            token_ttl_secs: 3600, // 1시간 후 만료
+            expiry_check_interval_secs: 60,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct I18nConfig {
+    pub default_locale: String,
+    pub fallback_locale: String,
+    pub bundle_dir: Option<String>,
+    pub cdn_base_url: Option<String>,
+}
+
+impl Default for I18nConfig {
+    fn default() -> Self {
+        Self {
+            default_locale: "en".to_string(),
+            fallback_locale: "en".to_string(),
+            bundle_dir: None,
+            cdn_base_url: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NotificationConfig {
+    pub register_url: Option<String>,
+    pub inbox_max_items: usize,     // 넘으면 오래된 알림부터 지운다
+    pub inbox_retention_days: u64, // 이보다 오래된 알림은 읽지 않았어도 지운다
+}
+
+impl Default for NotificationConfig {
+    fn default() -> Self {
+        Self {
+            register_url: None,
+            inbox_max_items: 500,
+            inbox_retention_days: 30,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TimeConfig {
+    pub ntp_server: Option<String>,
+    pub time_url: Option<String>, // NTP를 쓸 수 없을 때 Date 헤더를 읽을 서버
+    pub sync_interval_secs: u64,
+    pub drift_warning_ms: i64,
+}
+
+impl Default for TimeConfig {
+    fn default() -> Self {
+        Self {
+            ntp_server: Some("pool.ntp.org:123".to_string()),
+            time_url: None,
+            sync_interval_secs: 3600,
+            drift_warning_ms: 2000,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct StorageConfig {
+    pub db_path: Option<String>, // 없으면 메모리 저장소 사용 (웹에서는 IndexedDB 이름)
+    pub maintenance_interval_secs: u64, // 압축과 무결성 검사 주기 (0이면 요청할 때만)
+}
+
+impl Default for StorageConfig {
+    fn default() -> Self {
+        Self {
+            db_path: None,
+            maintenance_interval_secs: 24 * 3600,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RealtimeConfig {
+    pub url: Option<String>,
+    pub reconnect_max_delay_secs: u64,
+}
+
+impl Default for RealtimeConfig {
+    fn default() -> Self {
+        Self {
+            url: None,
+            reconnect_max_delay_secs: 30,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DeepLinkConfig {
+    pub schemes: Vec<String>, // 앱 전용 스킴 (예: rinfapp://items/1)
+    pub hosts: Vec<String>,   // 유니버설/앱 링크로 허용할 https 호스트
+}
+
+impl Default for DeepLinkConfig {
+    fn default() -> Self {
+        Self {
+            schemes: vec!["rinfapp".to_string()],
+            hosts: Vec::new(),
+        }
+    }
+}
+
+// 로컬 명령 채널 (command-channel 기능을 켠 데스크톱 빌드에서만 사용)
+// 요청 본문은 secret으로 서명해야 하고 allowed_commands에 있는 명령만 실행한다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CommandChannelConfig {
+    pub enabled: bool,
+    pub listen: String,         // "127.0.0.1:47800" 또는 "unix:/경로/app.sock"
+    pub secret: Option<String>, // HMAC-SHA256 키 (hex), 없으면 채널을 열지 않는다
+    pub allowed_commands: Vec<String>, // 명령 type 이름 (비어 있으면 모두 거부)
+    pub max_skew_secs: u64,     // 서명 시각 허용 오차 (재전송 방지 기간)
+}
+
+impl Default for CommandChannelConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            listen: "127.0.0.1:47800".to_string(),
+            secret: None,
+            allowed_commands: Vec::new(),
+            max_skew_secs: 300,
+        }
+    }
+}
+
+// 관리 서버가 보내는 원격 명령 (로그 상세도, 신호 기록, 기능 플래그)
+// public_key가 없으면 푸시로 온 명령도 모두 거부하고 폴링도 하지 않는다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AdminConfig {
+    pub public_key: Option<String>, // 명령 서명을 확인할 Ed25519 공개 키 (hex)
+    pub poll_url: Option<String>,   // 서명된 명령 목록(JSON 배열)을 돌려주는 엔드포인트
+    pub poll_interval_secs: u64,
+    pub max_duration_secs: u64, // 명령 하나가 유지될 수 있는 최대 기간
+    pub max_skew_secs: u64,     // 발행 시각이 기기 시계보다 앞서도 되는 정도
+}
+
+impl Default for AdminConfig {
+    fn default() -> Self {
+        Self {
+            public_key: None,
+            poll_url: None,
+            poll_interval_secs: 900,
+            max_duration_secs: 24 * 60 * 60,
+            max_skew_secs: 300,
+        }
+    }
+}
+
+// 사용자 스크립트 실행 한도 (scripting 기능을 켜고 빌드했을 때만 사용)
+// 스크립트 엔진은 전체 메모리 사용량을 잴 수 없으므로 문자열, 배열, 맵 크기로 제한한다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ScriptConfig {
+    pub max_scripts: usize,
+    pub max_operations: u64, // 한 번 실행에 허용하는 연산 수
+    pub timeout_ms: u64,     // 한 번 실행에 허용하는 시간
+    pub max_string_bytes: usize,
+    pub max_collection_len: usize, // 배열, 맵 원소 수
+}
+
+impl Default for ScriptConfig {
+    fn default() -> Self {
+        Self {
+            max_scripts: 32,
+            max_operations: 100_000,
+            timeout_ms: 250,
+            max_string_bytes: 64 * 1024,
+            max_collection_len: 10_000,
+        }
+    }
+}
+
+// 원격 규칙 모듈 (wasm-rules 기능을 켜고 빌드했을 때만 사용)
+// manifest_url과 public_key가 모두 있어야 내려받고, 서명이 맞지 않는 모듈은 불러오지 않는다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RulesConfig {
+    pub manifest_url: Option<String>,
+    pub public_key: Option<String>, // 모듈 서명을 확인할 Ed25519 공개 키 (hex)
+    pub check_interval_secs: u64,
+    pub fuel_per_call: u64, // 호출 한 번에 허용하는 wasm 연산량
+    pub max_memory_bytes: usize,
+}
+
+impl Default for RulesConfig {
+    fn default() -> Self {
+        Self {
+            manifest_url: None,
+            public_key: None,
+            check_interval_secs: 6 * 60 * 60,
+            fuel_per_call: 10_000_000,
+            max_memory_bytes: 16 * 1024 * 1024,
+        }
+    }
+}
+
+// 요금제 사용량 한도 (한도 자체는 서버의 /users/{user_id}/plan이 알려 준다)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct QuotaConfig {
+    pub warning_ratio: f64,         // 한도의 이 비율을 넘으면 경고 신호를 보낸다
+    pub refresh_interval_secs: u64, // 요금제 한도를 다시 받는 간격
+}
+
+impl Default for QuotaConfig {
+    fn default() -> Self {
+        Self {
+            warning_ratio: 0.8,
+            refresh_interval_secs: 6 * 60 * 60,
+        }
+    }
+}
+
+// 구독 권한 캐시 (서버에 확인하지 못하는 동안 저장된 권한을 얼마나 믿을지)
+// 기기 시계가 신뢰 시각과 max_clock_skew_secs 넘게 다르거나 뒤로 돌아가면 유예 기간을 주지 않는다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BillingConfig {
+    pub offline_grace_secs: u64, // 마지막 확인 후 이 기간이 지나면 저장된 권한을 쓰지 않는다
+    pub expiry_grace_secs: u64,  // 만료 뒤 갱신을 확인하지 못했을 때 더 쓰게 두는 기간
+    pub revalidate_interval_secs: u64,
+    pub max_clock_skew_secs: u64,
+}
+
+impl Default for BillingConfig {
+    fn default() -> Self {
+        Self {
+            offline_grace_secs: 7 * 24 * 60 * 60,
+            expiry_grace_secs: 3 * 24 * 60 * 60,
+            revalidate_interval_secs: 12 * 60 * 60,
+            max_clock_skew_secs: 10 * 60,
+        }
+    }
+}
+
+// 의미 검색용 문장 임베딩 모델 (ml 기능을 켜고 빌드했을 때만 사용)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct EmbeddingConfig {
+    pub model_path: Option<String>, // ONNX 모델 (예: all-MiniLM-L6-v2)
+    pub vocab_path: Option<String>, // WordPiece vocab.txt
+    pub max_seq_len: usize,
+}
+
+impl Default for EmbeddingConfig {
+    fn default() -> Self {
+        Self {
+            model_path: None,
+            vocab_path: None,
+            max_seq_len: 128,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AttachmentConfig {
+    pub blob_dir: Option<String>, // 없으면 임시 디렉터리 아래에 보관
+    pub thumbnail_size: u32,
+    pub gc_interval_secs: u64,
+}
+
+impl Default for AttachmentConfig {
+    fn default() -> Self {
+        Self {
+            blob_dir: None,
+            thumbnail_size: 256,
+            gc_interval_secs: 3600,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct MediaCacheConfig {
+    pub cache_dir: Option<String>, // 없으면 임시 디렉터리 아래에 보관
+    pub quota_bytes: u64,          // 넘으면 오래 쓰지 않은 고정되지 않은 항목부터 지운다
+}
+
+impl Default for MediaCacheConfig {
+    fn default() -> Self {
+        Self {
+            cache_dir: None,
+            quota_bytes: 256 * 1024 * 1024,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct MetricsConfig {
+    pub budget_ms: u64, // 대기 + 처리 시간이 이보다 길면 경고 로그
+}
+
+impl Default for MetricsConfig {
+    fn default() -> Self {
+        Self { budget_ms: 16 }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct StartupConfig {
+    pub budget_ms: u64,            // 즉시 시작 단계가 이보다 길면 경고 로그
+    pub deferred_fallback_ms: u64, // 콜드 스타트 완료 신호가 오지 않아도 지연 액터를 시작하는 시간
+}
+
+impl Default for StartupConfig {
+    fn default() -> Self {
+        Self {
+            budget_ms: 50,
+            deferred_fallback_ms: 3000,
+        }
+    }
+}
+
+// 액터 간 요청 제한 시간 (원격 로그인이 끝날 수 있도록 인증은 API 제한 시간보다 길게 잡는다)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TimeoutConfig {
+    pub login_ms: u64,   // 감독자 → 사용자 관리자 (인증 포함)
+    pub auth_ms: u64,    // 사용자 관리자 → 인증
+    pub profile_ms: u64, // 넘으면 마지막으로 받은 프로필을 쓴다
+    pub data_ms: u64,    // 넘으면 마지막으로 받은 최근 항목을 쓴다
+}
+
+impl Default for TimeoutConfig {
+    fn default() -> Self {
+        Self {
+            login_ms: 25_000,
+            auth_ms: 20_000,
+            profile_ms: 5_000,
+            data_ms: 3_000,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CurrencyConfig {
+    pub rates_url: String,   // {base}를 기준 통화 코드로 바꾼다 (응답: {"rates": {"EUR": 0.92, ...}})
+    pub rates_ttl_secs: u64, // 이 시간이 지나면 환율을 다시 받는다
+}
+
+impl Default for CurrencyConfig {
+    fn default() -> Self {
+        Self {
+            rates_url: "https://open.er-api.com/v6/latest/{base}".to_string(),
+            rates_ttl_secs: 3600,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TextAnalysisConfig {
+    pub dictionary_dir: Option<String>, // {언어}.aff / {언어}.dic (예: en_US.aff, en.aff)
+    pub words_per_minute: u32,          // 읽기 시간 계산 기준
+    pub max_suggestions: usize,
+}
+
+impl Default for TextAnalysisConfig {
+    fn default() -> Self {
+        Self {
+            dictionary_dir: None,
+            words_per_minute: 230,
+            max_suggestions: 5,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TemplateConfig {
+    pub max_templates: usize,
+    pub max_template_bytes: usize,
+    pub max_output_bytes: usize, // 넘으면 렌더링을 멈추고 오류로 답한다
+    pub strict: bool,            // 컨텍스트에 없는 값을 쓰면 빈 문자열 대신 오류
+}
+
+impl Default for TemplateConfig {
+    fn default() -> Self {
+        Self {
+            max_templates: 200,
+            max_template_bytes: 64 * 1024,
+            max_output_bytes: 1024 * 1024,
+            strict: false,
+        }
+    }
+}
+
+// 메일 발송 (피드백 양식, 초대 메일)
+// smtp가 있고 smtp 기능을 켠 데스크톱 빌드면 SMTP로, 아니면 api_url로 보낸다. 둘 다 없으면 대기열에만 쌓인다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct MailConfig {
+    pub from: String,            // 예: "Study <noreply@example.com>"
+    pub api_url: Option<String>, // 메일 JSON을 POST로 받는 발송 API
+    pub api_key: Option<String>, // Authorization: Bearer로 보낸다
+    pub smtp: Option<SmtpConfig>,
+    pub max_queue: usize,
+    pub max_attempts: u32,
+    pub retry_base_secs: u64, // 실패할 때마다 두 배로 늘린다
+    pub retry_max_secs: u64,
+}
+
+impl Default for MailConfig {
+    fn default() -> Self {
+        Self {
+            from: String::new(),
+            api_url: None,
+            api_key: None,
+            smtp: None,
+            max_queue: 100,
+            max_attempts: 8,
+            retry_base_secs: 30,
+            retry_max_secs: 6 * 60 * 60,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SmtpConfig {
+    pub host: String,
+    #[serde(default = "SmtpConfig::default_port")]
+    pub port: u16,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    #[serde(default)]
+    pub implicit_tls: bool, // true면 465 포트식 TLS, 아니면 STARTTLS
+}
+
+impl SmtpConfig {
+    fn default_port() -> u16 {
+        587
+    }
+}
+
+// 공유 시트로 들어온 내용 처리
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct IngestConfig {
+    pub max_bytes: u64, // 넘는 파일은 받지 않는다
+}
+
+impl Default for IngestConfig {
+    fn default() -> Self {
+        Self {
+            max_bytes: 25 * 1024 * 1024,
+        }
+    }
+}
+
+// 링크 미리보기 (채팅, 노트의 링크 카드)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LinkPreviewConfig {
+    pub max_page_bytes: usize, // 메타 태그를 찾을 때 읽는 페이지 앞부분
+    pub fetch_timeout_ms: u64,
+    pub cache_ttl_secs: u64,
+}
+
+impl Default for LinkPreviewConfig {
+    fn default() -> Self {
+        Self {
+            max_page_bytes: 512 * 1024,
+            fetch_timeout_ms: 8_000,
+            cache_ttl_secs: 24 * 60 * 60,
+        }
+    }
+}
+
+// RSS/Atom/JSON Feed 구독
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FeedReaderConfig {
+    pub poll_interval_secs: u64, // 구독마다 이 간격으로 확인
+    pub max_backoff_secs: u64,   // 실패가 이어질 때 늘어나는 확인 간격의 상한
+    pub max_subscriptions: usize,
+    pub max_articles_per_feed: usize, // 넘으면 오래된 글부터 버린다
+    pub max_feed_bytes: usize,
+    pub fetch_timeout_ms: u64,
+}
+
+impl Default for FeedReaderConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval_secs: 30 * 60,
+            max_backoff_secs: 24 * 60 * 60,
+            max_subscriptions: 100,
+            max_articles_per_feed: 200,
+            max_feed_bytes: 2 * 1024 * 1024,
+            fetch_timeout_ms: 15_000,
+        }
+    }
+}
+
+// 현재 병합된 설정 조회
+#[derive(Debug, Clone)]
+pub struct GetConfig;
+
+// 점(.)으로 구분된 키에 JSON 값을 덮어쓰기 (예: "network.max_connections" = "20")
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetConfigValue {
+    pub key: String,
+    pub value_json: String,
+}
@@ -0,0 +1,47 @@
+use super::UserId;
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+// 이메일, 전화번호, 주소 하나 (label은 vCard TYPE, 예: "home", "work", "cell")
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct ContactField {
+    pub label: Option<String>,
+    pub value: String,
+}
+
+// 항목에 붙은 연락처 정보 (항목 제목은 표시 이름, 본문은 검색용 요약)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct Contact {
+    pub item_id: String,
+    pub uid: Option<String>,
+    pub display_name: String,
+    pub given_name: Option<String>,
+    pub family_name: Option<String>,
+    pub organization: Option<String>,
+    pub job_title: Option<String>,
+    pub emails: Vec<ContactField>,
+    pub phones: Vec<ContactField>,
+    pub addresses: Vec<ContactField>,
+    pub urls: Vec<String>,
+    pub birthday: Option<String>, // YYYY-MM-DD (연도가 없으면 --MM-DD)
+    pub note: Option<String>,
+    pub photo_attachment_id: Option<String>, // 파일에 들어 있던 사진 (첨부 액터가 썸네일을 만든다)
+    pub photo_url: Option<String>,           // 사진이 링크로만 있으면 그 주소
+}
+
+// vCard 3.0/4.0 파일의 연락처를 항목으로 가져온다
+// 이미 있는 연락처와 같은 사람이면(이메일이 겹치거나 이름이 거의 같으면) 새로 만들지 않고 합친다.
+// correlation_id는 진행 신호에 실어 보내는 요청 번호다.
+#[derive(Debug, Clone)]
+pub struct ImportVcard {
+    pub correlation_id: u64,
+    pub user_id: UserId,
+    pub vcf: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct VcardImport {
+    pub imported: Vec<Contact>,
+    pub merged: u64,          // 기존 연락처에 합친 카드 수
+    pub skipped: Vec<String>, // 읽지 못한 카드와 그 이유
+}
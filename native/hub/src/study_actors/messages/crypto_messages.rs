@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+// 키 식별자로 지정된 AES-256-GCM 키로 암호화 (결과: nonce || ciphertext)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Encrypt {
+    pub key_id: String,
+    pub plaintext: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Decrypt {
+    pub key_id: String,
+    pub ciphertext: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenerateKey {
+    pub key_id: String,
+}
+
+// 새 데이터 키를 만들고 저장된 비밀 값을 모두 그 키로 다시 봉인한다 (결과: 새 키 id)
+// 다시 봉인은 백그라운드로 이어지고, 다 끝나야 새 키가 활성 키가 된다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RotateEncryptionKey;
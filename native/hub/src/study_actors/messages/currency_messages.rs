@@ -0,0 +1,64 @@
+use rinf::SignalPiece;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+// 금액 연산 (덧셈/뺄셈은 같은 통화 금액끼리, 곱셈/나눗셈의 right는 배수·비율)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub enum ArithmeticOp {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub enum RoundingMode {
+    HalfEven, // 은행가 반올림 (기본값)
+    HalfUp,   // 0.5는 0에서 먼 쪽으로
+    Down,     // 0 쪽으로 버림
+    Up,       // 0에서 먼 쪽으로 올림
+    Floor,
+    Ceiling,
+}
+
+// 반올림 규칙
+// increment가 있으면 그 단위로 맞춘다 (예: 스위스 프랑 현금 "0.05"), 없으면 통화의 소수 자릿수로 맞춘다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct RoundingRule {
+    pub mode: RoundingMode,
+    pub increment: Option<String>,
+}
+
+// 통화 금액 계산 (결과는 rounding 또는 통화 기본 규칙으로 반올림)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalculateMoney {
+    pub currency: String,
+    pub op: ArithmeticOp,
+    pub left: Decimal,
+    pub right: Decimal,
+    pub rounding: Option<RoundingRule>,
+}
+
+// 환율 변환 (환율은 NetworkManagerActor로 받아 기준 통화별로 캐시)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConvertCurrency {
+    pub amount: Decimal,
+    pub from: String,
+    pub to: String,
+    pub rounding: Option<RoundingRule>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Conversion {
+    pub amount: Decimal,
+    pub rate: Decimal,
+    pub rates_fetched_at: i64, // 환율 기준 시각 (유닉스 초)
+}
+
+// 로케일 형식으로 금액 표시 (예: en "$1,234.50", de "1.234,50 €", ko "₩1,235")
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FormatMoney {
+    pub amount: Decimal,
+    pub currency: String,
+    pub locale: String,
+}
@@ -1,11 +1,24 @@
-use super::UserId;
-use messages::prelude::Address;
+use super::{Attachment, UserId};
+use rinf::SignalPiece;
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FetchData {
     pub key: String,
     pub user_id: Option<UserId>,
+    #[serde(default)]
+    pub mode: FetchMode,
+}
+
+// 데이터 관리자의 조회 방식 (캐시와 저장소 액터는 무시한다)
+// StaleWhileRevalidate: 캐시 TTL이 지났으면 저장된 값을 바로 돌려주고, 항목 키면 서버에서 다시 받아
+// 더 새로울 때 DataRefreshedSignal로 알린다. 목록과 상세 화면이 네트워크를 기다리지 않게 한다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FetchMode {
+    #[default]
+    CacheFirst,
+    StaleWhileRevalidate,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -19,7 +32,7 @@ pub struct StoreData {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CacheData {
     pub key: String,
-    pub data: Vec<u8>,
+    pub data: Arc<[u8]>,
     pub ttl: Option<u64>, // 초 단위 TTL
 }
 
@@ -27,6 +40,7 @@ pub struct CacheData {
 pub struct FetchRecentData {
     pub user_id: UserId,
     pub limit: Option<usize>,
+    pub mode: FetchMode, // StaleWhileRevalidate면 캐시 TTL이 지난 항목을 서버에서 다시 받는다
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -36,14 +50,176 @@ pub struct UserData {
     pub last_updated: u64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, SignalPiece)]
 pub struct DataItem {
     pub id: String,
     pub title: String,
     pub content: String,
     pub created_at: u64,
     pub updated_at: u64,
+    #[serde(default)]
+    pub tags: Vec<String>, // 태그 색인에서 채워짐
+    #[serde(default)]
+    pub attachments: Vec<Attachment>,
+    #[serde(default)]
+    pub owner: UserId, // 만든 사용자 (예전 형식으로 저장된 항목은 비어 있어 누구의 목록에도 나오지 않는다)
+}
+
+// 낙관적 변경의 종류 (Dart가 임시 id를 붙여 보낸 생성/수정/삭제)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, SignalPiece)]
+pub enum MutationKind {
+    Create,
+    Update,
+    Delete,
 }
 
-#[derive(Debug, Clone)]
-pub struct UpdateNetworkDependency(pub Address<super::super::actors::NetworkManagerActor>);
+// 저장소/캐시에서 키 삭제
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteData {
+    pub key: String,
+}
+
+// 저장소에서 접두사로 시작하는 항목 조회 (결과는 키 순서의 (키, 값) 목록)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScanPrefix {
+    pub prefix: String,
+}
+
+// 항목 태그 추가/제거 (태그는 소문자로 정규화되며, 결과는 항목의 현재 태그 목록)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddTag {
+    pub user_id: UserId,
+    pub item_id: String,
+    pub tag: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoveTag {
+    pub user_id: UserId,
+    pub item_id: String,
+    pub tag: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateCollection {
+    pub user_id: UserId,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddItemToCollection {
+    pub user_id: UserId,
+    pub collection_id: String,
+    pub item_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SignalPiece)]
+pub struct Collection {
+    pub id: String,
+    pub name: String,
+    pub item_ids: Vec<String>,
+    pub created_at: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SignalPiece)]
+pub struct TagCount {
+    pub tag: String,
+    pub count: u64,
+}
+
+// 태그로 항목 조회 (offset/limit 페이지)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemsByTag {
+    pub user_id: UserId,
+    pub tag: String,
+    pub offset: usize,
+    pub limit: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemPage {
+    pub items: Vec<DataItem>,
+    pub total: usize,
+    pub next_offset: Option<usize>, // 마지막 페이지면 None
+}
+
+// 서버의 항목 목록을 페이지 단위로 끝까지 받아 저장한다
+// 페이지마다 UserDataResponse(has_more)를 보내므로 Dart는 받는 대로 목록을 그린다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FetchPagedRemote {
+    pub user_id: UserId,
+    pub page_size: Option<usize>,
+}
+
+// 마지막으로 올린 뒤 이 기기에서 바뀌거나 지운 항목을 서버에 올린다 (암호화를 켰으면 봉인해서)
+// full이면 전부 다시 올린다 (암호화를 켠 직후 서버의 평문 사본을 봉인본으로 바꿀 때).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PushLocalChanges {
+    pub user_id: UserId,
+    pub full: bool,
+}
+
+// 항목 수정 직전 상태의 스냅샷 (revision은 항목마다 1부터 증가)
+#[derive(Debug, Clone, Serialize, Deserialize, SignalPiece)]
+pub struct ItemRevision {
+    pub revision: u64,
+    pub title: String,
+    pub content: String,
+    pub updated_at: u64,
+}
+
+// 오래된 것부터 정렬된 리비전 목록
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetItemHistory {
+    pub user_id: UserId,
+    pub item_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevertItemToRevision {
+    pub user_id: UserId,
+    pub item_id: String,
+    pub revision: u64,
+}
+
+// 사용자의 마지막 변경 취소/다시 실행 (결과는 바뀐 항목)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UndoLastChange {
+    pub user_id: UserId,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RedoLastChange {
+    pub user_id: UserId,
+}
+
+// 협업 문서에서 병합된 본문을 항목에 반영 (리비전/실행 취소 기록 없음)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetItemContent {
+    pub item_id: String,
+    pub content: String,
+}
+
+// 새 항목 만들기 (한도를 넘으면 UserError::QuotaExceeded, 결과는 만든 항목)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateDataItem {
+    pub user_id: UserId,
+    pub title: String,
+    pub content: String,
+    pub tags: Vec<String>,
+}
+
+// 사용자의 모든 항목을 최근 수정 순으로 (FetchRecentData와 달리 개수 제한이 없다)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListItems {
+    pub user_id: UserId,
+}
+
+// 중복 항목을 keep_id 항목 하나로 합친다 (결과는 합친 항목)
+// 제목은 남길 항목의 것을 쓰고, 다른 항목의 본문·태그·첨부·리비전을 옮긴 뒤 그 항목들을 지운다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergeItems {
+    pub user_id: UserId,
+    pub keep_id: String,
+    pub merge_ids: Vec<String>,
+}
@@ -0,0 +1,18 @@
+use super::UserId;
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+// 내용이 거의 같은 항목 묶음
+// item_ids는 최근 수정 순이며 첫 항목을 남길 항목으로 제안한다.
+// similarity는 묶음에서 가장 먼 두 지문 기준 (1.0이면 지문이 같다).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct DuplicateGroup {
+    pub item_ids: Vec<String>,
+    pub similarity: f64,
+}
+
+// 사용자의 항목에서 중복 후보를 찾는다 (결과는 큰 묶음부터)
+#[derive(Debug, Clone)]
+pub struct FindDuplicates {
+    pub user_id: UserId,
+}
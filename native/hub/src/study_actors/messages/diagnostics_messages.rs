@@ -0,0 +1,41 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, SignalPiece)]
+pub enum ActorHealth {
+    Running,
+    Stopped,
+    Panicked,
+}
+
+// 액터 실행 상태 (since는 상태가 바뀐 유닉스 타임스탬프, 초)
+#[derive(Debug, Clone, Serialize, Deserialize, SignalPiece)]
+pub struct ActorStatus {
+    pub name: String,
+    pub health: ActorHealth,
+    pub since: u64,
+}
+
+// 액터 실행 루프가 패닉으로 끝난 기록 (이전 실행의 기록도 남는다)
+#[derive(Debug, Clone, Serialize, Deserialize, SignalPiece)]
+pub struct CrashReport {
+    pub actor: String,
+    pub message: String,
+    pub at: u64,
+}
+
+// 지원 요청에 붙일 진단 묶음을 path에 zip으로 만든다
+// 로그, 액터 상태, 지연 지표, 설정, 저장소 통계, 패닉 기록을 담고 비밀 값과 개인 정보를 가린다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateDiagnosticBundle {
+    pub path: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SignalPiece)]
+pub struct DiagnosticBundle {
+    pub path: String,
+    pub files: Vec<String>,
+    pub size_bytes: u64,
+    pub redactions: u64,     // 가린 값의 수
+    pub errors: Vec<String>, // 모으지 못한 항목 (나머지는 그대로 담는다)
+}
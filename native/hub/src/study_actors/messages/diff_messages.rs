@@ -0,0 +1,47 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+// 줄 단위 비교 (결과는 unified diff와 같은 헝크 목록)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiffText {
+    pub old: String,
+    pub new: String,
+}
+
+// base에서 갈라진 두 수정본을 합친다 (겹치지 않는 수정만 자동 병합)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreeWayMerge {
+    pub base: String,
+    pub ours: String,
+    pub theirs: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub enum DiffLineKind {
+    Context,
+    Insert,
+    Delete,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct DiffLine {
+    pub kind: DiffLineKind,
+    pub text: String, // 줄바꿈 제외
+}
+
+// 시작 줄 번호는 1부터 센다 (줄 수가 0이면 그 줄 바로 뒤)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct DiffHunk {
+    pub old_start: u32,
+    pub old_lines: u32,
+    pub new_start: u32,
+    pub new_lines: u32,
+    pub lines: Vec<DiffLine>,
+}
+
+// conflicts가 0이면 text가 병합 결과, 아니면 충돌 구간을 <<<<<<< ours / ======= / >>>>>>> theirs로 표시한 텍스트
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct MergeOutcome {
+    pub text: String,
+    pub conflicts: u32,
+}
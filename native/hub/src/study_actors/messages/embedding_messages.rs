@@ -0,0 +1,30 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+use super::DataItem;
+
+// 문장 임베딩 계산 (결과: L2 정규화된 벡터)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmbedText {
+    pub text: String,
+}
+
+// 새로 생겼거나 수정된 항목만 임베딩해 저장 (결과: 새로 색인한 항목 수)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexItems {
+    pub items: Vec<DataItem>,
+}
+
+// 코사인 유사도 순 검색
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SemanticSearch {
+    pub query: String,
+    pub limit: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SignalPiece)]
+pub struct SemanticMatch {
+    pub item_id: String,
+    pub title: String,
+    pub score: f32,
+}
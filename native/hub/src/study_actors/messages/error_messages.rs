@@ -0,0 +1,217 @@
+use messages::prelude::SendError;
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+// 저장소/캐시 오류
+#[derive(Debug, Error)]
+pub enum StorageError {
+    #[error("Key not found: {0}")]
+    NotFound(String),
+    #[error("Cache entry expired: {0}")]
+    Expired(String),
+    #[error("Storage lock poisoned")]
+    LockPoisoned,
+    #[error("Storage migration failed: {0}")]
+    Migration(String),
+    #[error("Storage writes are paused while data is wiped")]
+    WritesPaused,
+    #[error("Sealed secret error: {0}")]
+    Sealed(String),
+    #[cfg(all(
+        feature = "keychain",
+        not(any(target_family = "wasm", target_os = "android"))
+    ))]
+    #[error("Keychain error: {0}")]
+    Keychain(String),
+    #[cfg(not(target_family = "wasm"))]
+    #[error("Database error: {0}")]
+    Database(#[from] sled::Error),
+    #[cfg(target_family = "wasm")]
+    #[error("IndexedDB error: {0}")]
+    IndexedDb(String),
+}
+
+// 인증 오류
+#[derive(Debug, Error)]
+pub enum AuthError {
+    #[error("Invalid username or password")]
+    InvalidCredentials,
+    #[error("Missing authorization code")]
+    MissingAuthCode,
+    #[error("Invalid or expired token")]
+    InvalidToken,
+    #[error("Token expired")]
+    TokenExpired,
+}
+
+// 서버 API 호출 오류 (ApiClient)
+#[derive(Debug, Error)]
+pub enum ApiError {
+    #[error("API base URL is not configured")]
+    NotConfigured,
+    #[error("Invalid API URL: {0}")]
+    InvalidUrl(String),
+    #[error("Missing path parameter: {0}")]
+    MissingPathParam(String),
+    #[error("API request failed: {0}")]
+    Transport(String),
+    #[error("API returned status {status}: {message}")]
+    Status { status: u16, message: String },
+    #[error("Unexpected API response: {0}")]
+    Decode(String),
+    #[error("{host} is unavailable, retrying in {retry_in_ms}ms")]
+    ServiceUnavailable { host: String, retry_in_ms: u64 },
+}
+
+// 로컬 명령 채널 오류 (리스너가 HTTP 상태 코드로 바꿔 응답)
+#[cfg(all(
+    feature = "command-channel",
+    not(any(target_family = "wasm", target_os = "android", target_os = "ios"))
+))]
+#[derive(Debug, Error)]
+pub enum CommandError {
+    #[error("Malformed request: {0}")]
+    Malformed(String),
+    #[error("Invalid signature")]
+    BadSignature,
+    #[error("Request timestamp is outside the allowed window")]
+    Stale,
+    #[error("Request was already received")]
+    Replayed,
+    #[error("Command not allowed: {0}")]
+    NotAllowed(String),
+    #[error("Command rejected: {0}")]
+    Rejected(String),
+    #[error("Actor unavailable: {0}")]
+    Unavailable(String),
+}
+
+#[cfg(all(
+    feature = "command-channel",
+    not(any(target_family = "wasm", target_os = "android", target_os = "ios"))
+))]
+impl CommandError {
+    pub fn status(&self) -> u16 {
+        match self {
+            Self::Malformed(_) => 400,
+            Self::BadSignature | Self::Stale => 401,
+            Self::NotAllowed(_) => 403,
+            Self::Replayed => 409,
+            Self::Rejected(_) => 422,
+            Self::Unavailable(_) => 503,
+        }
+    }
+}
+
+// 사용자/데이터 처리 오류
+// 하위 도메인 오류는 #[from]으로 감싸 호출자가 원인별로 분기할 수 있게 한다.
+#[derive(Debug, Error)]
+pub enum UserError {
+    #[error("Data not found: {0}")]
+    NotFound(String),
+    #[error("Unknown item: {0}")]
+    UnknownItem(String),
+    #[error("Unknown collection: {0}")]
+    UnknownCollection(String),
+    #[error("Unknown revision: {0}")]
+    UnknownRevision(u64),
+    #[error("Invalid input: {0}")]
+    InvalidInput(String),
+    #[error("Nothing to undo")]
+    NothingToUndo,
+    #[error("Nothing to redo")]
+    NothingToRedo,
+    #[error("Too many connections to domain: {0}")]
+    TooManyConnections(String),
+    #[error("Deferred by data saver: {0}")]
+    DataSaverDeferred(String),
+    #[error("Quota exceeded: {0}")]
+    QuotaExceeded(String),
+    #[error("Network error: {0}")]
+    Network(#[from] reqwest::Error),
+    #[error("Upstream service error: {0}")]
+    Upstream(String),
+    #[error(transparent)]
+    Api(#[from] ApiError),
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error(transparent)]
+    Storage(#[from] StorageError),
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+    #[error("Actor unavailable: {0}")]
+    ActorUnavailable(#[from] SendError),
+    #[error("{actor} did not respond within {timeout_ms}ms")]
+    Timeout {
+        actor: &'static str,
+        timeout_ms: u64,
+    },
+}
+
+// Dart 요청(ask) 처리 오류
+// respond_to_dart가 응답 신호의 error 필드(ErrorEnvelope)로 바꿔 보낸다.
+#[derive(Debug, Error)]
+pub enum AppError {
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error(transparent)]
+    User(#[from] UserError),
+    #[error("Actor unavailable: {0}")]
+    ActorUnavailable(#[from] SendError),
+    #[error("Request handler panicked: {0}")]
+    Panicked(String),
+}
+
+impl AppError {
+    // Dart가 메시지 문자열 대신 분기에 쓰는 안정된 오류 코드
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Auth(e) | Self::User(UserError::Auth(e)) => match e {
+                AuthError::InvalidCredentials => "invalid_credentials",
+                AuthError::MissingAuthCode => "invalid_input",
+                AuthError::InvalidToken | AuthError::TokenExpired => "unauthorized",
+            },
+            Self::User(
+                UserError::NotFound(_)
+                | UserError::UnknownItem(_)
+                | UserError::UnknownCollection(_)
+                | UserError::UnknownRevision(_),
+            ) => "not_found",
+            Self::User(UserError::InvalidInput(_)) => "invalid_input",
+            Self::User(UserError::Api(ApiError::Status { status, .. })) => match status {
+                401 | 403 => "unauthorized",
+                404 => "not_found",
+                400 | 409 | 422 => "invalid_input",
+                _ => "network",
+            },
+            Self::User(UserError::Api(ApiError::NotConfigured)) => "unavailable",
+            Self::User(UserError::Api(ApiError::ServiceUnavailable { .. })) => {
+                "service_unavailable"
+            }
+            Self::User(UserError::Api(ApiError::Transport(_) | ApiError::Decode(_))) => "network",
+            Self::User(UserError::Network(_) | UserError::Upstream(_)) => "network",
+            Self::User(UserError::DataSaverDeferred(_)) => "deferred",
+            Self::User(UserError::QuotaExceeded(_)) => "quota_exceeded",
+            Self::User(UserError::ActorUnavailable(_)) | Self::ActorUnavailable(_) => "unavailable",
+            Self::User(UserError::Timeout { .. }) => "timeout",
+            Self::User(_) | Self::Panicked(_) => "internal",
+        }
+    }
+}
+
+// 응답 신호에 실어 보내는 오류 (code로 분기하고 message는 로그/표시용)
+#[derive(Debug, Clone, Serialize, Deserialize, SignalPiece)]
+pub struct ErrorEnvelope {
+    pub code: String,
+    pub message: String,
+}
+
+impl From<&AppError> for ErrorEnvelope {
+    fn from(error: &AppError) -> Self {
+        Self {
+            code: error.code().to_string(),
+            message: error.to_string(),
+        }
+    }
+}
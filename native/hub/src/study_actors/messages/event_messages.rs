@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use super::{AppConfig, DataItem, Entitlement, SignedAdminCommand, UserId, WipeOrigin};
+
+// 이벤트 버스로 모든 구독 액터에게 전달되는 앱 이벤트
+#[derive(Debug, Clone)]
+pub enum AppEvent {
+    DataItemCreated {
+        user_id: UserId,
+        item: DataItem,
+        tags: Vec<String>,
+    },
+    DataItemUpdated {
+        user_id: UserId,
+        item: DataItem,
+        tags: Vec<String>,
+    },
+    DataItemDeleted {
+        user_id: UserId,
+        item_id: String,
+    },
+    // 다른 기기에서 바뀌어 동기화로 내려받아 저장한 항목 (이 기기에서 더 나중에 고친 항목은 빠진다)
+    DataItemsSynced {
+        user_id: UserId,
+        items: Vec<DataItem>,
+    },
+    // 푸시로 받은 시스템 공지
+    SystemNotice {
+        id: Option<String>,
+        title: String,
+        body: String,
+    },
+    ConnectivityChanged {
+        online: bool,
+        metered: bool, // 셀룰러 등 종량제 연결 (데이터 절약 모드 판단)
+    },
+    // 푸시로 받은 서명된 관리 명령 (관리 액터가 서명을 확인해 적용)
+    AdminCommandReceived(SignedAdminCommand),
+    // 결제 액터가 확인한 사용자의 권한이 바뀌었다 (한도 액터와 기능 플래그가 구독)
+    EntitlementsChanged {
+        user_id: UserId,
+        entitlements: Vec<Entitlement>, // 지금 유효한 권한만
+    },
+    // 서명을 확인한 서버의 원격 삭제 명령이나 명령 채널의 삭제 요청 (감독자가 구독해 처리)
+    RemoteWipeRequested {
+        origin: WipeOrigin,
+        reason: Option<String>,
+    },
+    // 실행 중에 바꿀 수 있는 설정이 바뀌었다 (그 설정을 쓰는 액터가 구독해 바로 적용)
+    ConfigChanged {
+        key: String,
+        config: Arc<AppConfig>,
+    },
+}
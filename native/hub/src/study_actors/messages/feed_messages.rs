@@ -0,0 +1,50 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+use super::UserId;
+
+// 활동이 일어난 곳
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, SignalPiece)]
+pub enum FeedSource {
+    Local,  // 이 기기에서 고친 항목
+    Sync,   // 다른 기기에서 고쳐 동기화로 받은 항목
+    System, // 시스템 공지
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, SignalPiece)]
+pub enum FeedAction {
+    Created,
+    Updated,
+    Deleted,
+    Notice,
+}
+
+// 활동 피드 한 줄
+// 짧은 시간에 잇따른 같은 종류의 활동은 한 줄로 묶이고 count가 늘어난다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct FeedEntry {
+    pub id: String,
+    pub source: FeedSource,
+    pub action: FeedAction,
+    pub user_id: Option<UserId>, // 공지는 모든 사용자에게 보인다
+    pub item_ids: Vec<String>,   // 묶인 항목 (공지는 비어 있음)
+    pub title: String,           // 마지막 항목의 제목 또는 공지 제목
+    pub body: Option<String>,
+    pub count: u32, // 묶인 활동 수
+    pub first_at: u64,
+    pub last_at: u64, // 유닉스 타임스탬프 (초), 피드는 이 값의 최신 순
+}
+
+// 최신 순 페이지 조회 (user_id가 있으면 그 사용자의 활동과 공지만)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FetchFeedPage {
+    pub user_id: Option<UserId>,
+    pub offset: usize,
+    pub limit: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeedPage {
+    pub entries: Vec<FeedEntry>,
+    pub total: usize,
+}
@@ -0,0 +1,45 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+// 구독 중인 RSS/Atom/JSON Feed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct FeedSubscription {
+    pub id: String,
+    pub url: String,
+    pub title: Option<String>, // 피드가 알려 준 제목
+    pub site_url: Option<String>,
+    pub last_polled_at: Option<u64>, // 유닉스 타임스탬프 (초)
+    pub last_error: Option<String>,  // 마지막 확인이 실패했으면 그 이유
+}
+
+// 피드 글 하나 (같은 구독 안에서 id가 같으면 같은 글)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct FeedArticle {
+    pub id: String,
+    pub subscription_id: String,
+    pub title: Option<String>,
+    pub url: Option<String>,
+    pub summary: Option<String>, // 태그를 걷어 낸 요약
+    pub author: Option<String>,
+    pub published_at: Option<u64>, // 유닉스 타임스탬프 (초)
+    pub fetched_at: u64,
+}
+
+// 피드를 처음 한 번 가져와 읽을 수 있어야 구독된다 (이미 구독한 주소면 그 구독을 돌려준다)
+#[derive(Debug, Clone)]
+pub struct SubscribeFeed {
+    pub url: String,
+}
+
+// 구독과 받아 둔 글을 함께 지운다
+#[derive(Debug, Clone)]
+pub struct UnsubscribeFeed {
+    pub subscription_id: String,
+}
+
+// 최신 순 (subscription_id가 없으면 모든 구독의 글)
+#[derive(Debug, Clone)]
+pub struct ListFeedArticles {
+    pub subscription_id: Option<String>,
+    pub limit: usize,
+}
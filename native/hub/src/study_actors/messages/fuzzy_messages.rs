@@ -0,0 +1,19 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+// 후보 문자열을 검색어와의 퍼지 일치 점수 순으로 정렬 (명령 팔레트, 선택 목록용)
+// 검색어에 대문자가 있으면 대소문자를 구분한다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FuzzyMatch {
+    pub query: String,
+    pub candidates: Vec<String>,
+    pub limit: usize,
+}
+
+// 일치한 글자 위치는 UTF-16 코드 단위라 Flutter TextSpan 강조에 그대로 쓸 수 있다
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct FuzzyHit {
+    pub index: u32, // candidates 안의 위치
+    pub score: i32,
+    pub positions: Vec<u32>,
+}
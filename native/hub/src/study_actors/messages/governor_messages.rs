@@ -0,0 +1,43 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+// 부하에 따라 CPU를 많이 쓰는 작업의 품질을 낮추는 단계 (Full이 평소 상태)
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize, SignalPiece,
+)]
+pub enum PerformanceLevel {
+    #[default]
+    Full,
+    Reduced,
+    Minimal,
+}
+
+impl PerformanceLevel {
+    // 프랙털 프레임 한 변의 픽셀 수
+    pub fn fractal_size(self) -> u32 {
+        match self {
+            Self::Full => 256,
+            Self::Reduced => 192,
+            Self::Minimal => 128,
+        }
+    }
+
+    // OCR 전처리에서 가장 비싼 기울기 보정을 할지
+    pub fn ocr_deskew(self) -> bool {
+        self == Self::Full
+    }
+
+    // OCR 전처리에서 문서 경계를 찾아 잘라낼지
+    pub fn ocr_crop(self) -> bool {
+        self != Self::Minimal
+    }
+
+    // 검색 색인에서 한 번에 임베딩할 항목 수 (배치 사이에 다른 작업이 돌 수 있다)
+    pub fn index_batch_size(self) -> usize {
+        match self {
+            Self::Full => 64,
+            Self::Reduced => 16,
+            Self::Minimal => 4,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub enum HashAlgorithm {
+    Sha256,
+    Blake3,
+    Crc32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SignalPiece)]
+pub enum HashSource {
+    Bytes(Vec<u8>),
+    FilePath(String),
+}
+
+// 다운로드 검증이나 변경 감지 등 다른 액터에서 사용
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComputeHash {
+    pub algo: HashAlgorithm,
+    pub source: HashSource,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerifyHash {
+    pub algo: HashAlgorithm,
+    pub source: HashSource,
+    pub expected_hex: String,
+}
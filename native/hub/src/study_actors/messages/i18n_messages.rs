@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// 번역 번들을 가져올 위치
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum BundleSource {
+    File(String),
+    Remote(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoadLocaleBundle {
+    pub locale: String,
+    pub source: BundleSource,
+}
+
+// 다른 액터가 에러/알림 문자열을 현지화할 때 사용
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Translate {
+    pub key: String,
+    pub args: HashMap<String, String>,
+    pub locale: Option<String>,
+    pub fallback: Option<String>, // 번역이 없을 때 쓸 기본 문구 (없으면 키를 그대로 쓴다)
+}
@@ -0,0 +1,19 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+// 만들 ID 종류
+// - Ulid: 생성 시각 순으로 정렬되는 26자 문자열 (항목, 컬렉션, 첨부 ID)
+// - UuidV7: 생성 시각 순으로 정렬되는 UUID (UUID를 요구하는 서버와 주고받을 때)
+// - Token: 256비트 보안 난수의 16진 문자열 (초대 코드처럼 추측되면 안 되는 값)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, SignalPiece)]
+pub enum IdKind {
+    Ulid,
+    UuidV7,
+    Token,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenerateIds {
+    pub count: u32,
+    pub kind: IdKind,
+}
@@ -0,0 +1,53 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+// 받은 알림함에 들어온 경로
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, SignalPiece)]
+pub enum InboxSource {
+    Push,
+    Sync,
+    System,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SignalPiece)]
+pub struct InboxNotification {
+    pub id: String,
+    pub source: InboxSource,
+    pub title: String,
+    pub body: String,
+    pub link: Option<String>, // 누르면 열 딥 링크
+    pub received_at: u64,     // 유닉스 타임스탬프 (초)
+    pub read: bool,
+}
+
+// 알림함에 추가 (결과는 저장된 알림)
+// 같은 id의 알림이 이미 있으면 내용만 바꾸고 읽음 상태는 그대로 둔다. id가 없으면 새로 만든다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PostNotification {
+    pub id: Option<String>,
+    pub source: InboxSource,
+    pub title: String,
+    pub body: String,
+    pub link: Option<String>,
+}
+
+// 최신 순 페이지 조회
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListNotifications {
+    pub offset: usize,
+    pub limit: usize,
+    pub unread_only: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationPage {
+    pub notifications: Vec<InboxNotification>,
+    pub total: usize,
+    pub unread: usize, // 배지 숫자
+}
+
+// 읽음 표시 (ids가 비어 있으면 모두), 결과는 남은 안 읽은 알림 수
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarkNotificationsRead {
+    pub ids: Vec<String>,
+}
@@ -0,0 +1,31 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+use super::{DataItem, UserId};
+
+// 공유된 내용을 살펴 정한 처리 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, SignalPiece)]
+pub enum SharedContentKind {
+    Image, // 썸네일을 만든 첨부가 달린 항목
+    File,  // 첨부만 달린 항목
+    Url,   // 페이지 제목과 설명을 가져온 항목
+    Text,  // 첫 줄을 제목으로 한 항목
+}
+
+// 운영체제 공유 시트로 들어온 내용을 종류에 맞게 처리해 새 항목으로 만든다
+// 파일은 path나 bytes 중 하나로 받는다. mime은 공유한 앱이 알려 준 값이라 내용으로 다시 확인한다.
+#[derive(Debug, Clone)]
+pub struct IngestSharedContent {
+    pub user_id: UserId,
+    pub mime: Option<String>,
+    pub path: Option<String>,
+    pub bytes: Vec<u8>, // 비어 있으면 파일 내용 없음
+    pub file_name: Option<String>,
+    pub text: Option<String>, // 파일과 함께 오면 항목 본문으로 쓴다
+}
+
+#[derive(Debug, Clone)]
+pub struct IngestedContent {
+    pub kind: SharedContentKind,
+    pub item: DataItem,
+}
@@ -0,0 +1,20 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+// 페이지의 Open Graph·Twitter 메타 태그로 링크 카드 내용을 만든다
+// 페이지를 가져오지 못하면 UserError::Upstream, 태그가 없으면 해당 필드만 비어 있다.
+#[derive(Debug, Clone)]
+pub struct FetchLinkPreview {
+    pub url: String,
+}
+
+// 주소 필드는 모두 절대 주소로 바꿔 둔다
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct LinkPreview {
+    pub url: String, // 조각(#...)을 뗀 요청 주소
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub image_url: Option<String>,
+    pub site_name: Option<String>,
+    pub favicon_url: Option<String>,
+}
@@ -0,0 +1,24 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+// 템플릿으로 메일을 만들어 발송 대기열에 넣는다 (피드백 양식, 초대 메일)
+// 본문은 대기열에 넣을 때 렌더링하므로 재시작 뒤 템플릿을 다시 등록하지 않아도 보낼 수 있다.
+// 돌려주는 값은 MailStatusSignal에 실리는 메일 ID다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComposeMail {
+    pub to: Vec<String>,
+    pub reply_to: Option<String>,
+    pub subject: String,
+    pub text_template: String,         // Text 형식으로 등록한 템플릿
+    pub html_template: Option<String>, // Html 형식으로 등록한 템플릿 (있으면 text와 함께 보낸다)
+    pub context: serde_json::Value,
+}
+
+// Queued → (Retrying →)* Sent | Failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, SignalPiece)]
+pub enum MailStatus {
+    Queued,
+    Retrying, // 일시적으로 실패해 다음 시도를 기다린다
+    Sent,
+    Failed, // 영구 오류이거나 시도 횟수를 다 썼다 (대기열에서 빠진다)
+}
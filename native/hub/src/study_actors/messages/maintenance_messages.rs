@@ -0,0 +1,47 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+// 저장소 압축 전후의 디스크 크기
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, SignalPiece)]
+pub struct CompactionStats {
+    pub bytes_before: u64,
+    pub bytes_after: u64,
+}
+
+// 기록된 해시·크기와 실제 파일을 대조한 결과 (목록은 사람이 읽을 설명)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct IntegrityReport {
+    pub checked: u64,
+    pub repaired: Vec<String>, // 지운 뒤 다시 받거나 다시 만들 수 있는 파일
+    pub quarantined: Vec<String>, // 되살릴 원본이 없어 격리 디렉터리로 옮긴 파일
+}
+
+impl IntegrityReport {
+    pub fn merge(&mut self, other: IntegrityReport) {
+        self.checked += other.checked;
+        self.repaired.extend(other.repaired);
+        self.quarantined.extend(other.quarantined);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct MaintenanceReport {
+    pub started_at: u64,
+    pub duration_ms: u64,
+    pub storage_entries: u64, // 끝까지 읽어 본 저장소 키 수 (읽다 실패하면 0이고 errors에 남는다)
+    pub compaction: Option<CompactionStats>, // 저장소가 공간을 스스로 관리하면 None
+    pub integrity: IntegrityReport,
+    pub errors: Vec<String>, // 끝내지 못한 단계 (나머지 단계는 그대로 진행한다)
+}
+
+// 저장소 압축과 무결성 검사를 지금 실행한다 (주기 실행과 같은 작업)
+#[derive(Debug, Clone)]
+pub struct RunMaintenance;
+
+// 저장소 액터에 디스크 공간 회수를 요청
+#[derive(Debug, Clone)]
+pub struct CompactStorage;
+
+// 파일을 가진 액터에 색인과 실제 파일을 대조하고 손상된 것을 고치게 한다
+#[derive(Debug, Clone)]
+pub struct VerifyBlobs;
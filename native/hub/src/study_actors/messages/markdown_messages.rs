@@ -0,0 +1,20 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub enum RenderMode {
+    // 정제된 HTML (화면 표시용)
+    Html,
+    // 서식을 제거한 일반 텍스트 (검색 색인용)
+    PlainText,
+    // 일반 텍스트의 앞부분 요약 (목록 미리보기용)
+    Summary,
+}
+
+// DataItem.content 렌더링 등 다른 액터에서 사용
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RenderMarkdown {
+    pub markdown: String,
+    pub mode: RenderMode,
+    pub summary_max_chars: Option<usize>,
+}
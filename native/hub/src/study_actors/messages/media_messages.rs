@@ -0,0 +1,26 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+// 미디어 캐시에 내려받아 둔 이미지/오디오 (파일은 URL 해시를 이름으로 보관한다)
+#[derive(Debug, Clone, Serialize, Deserialize, SignalPiece)]
+pub struct MediaEntry {
+    pub url: String,
+    pub mime_type: String,
+    pub size: u64,
+    pub pinned: bool, // 사용자가 오프라인용으로 고정한 항목은 한도를 넘어도 지우지 않는다
+    pub fetched_at: u64,
+}
+
+// 캐시에 있으면 바로 반환하고, 없으면 내려받아 캐시에 넣는다
+#[derive(Debug, Clone)]
+pub struct GetMedia {
+    pub url: String,
+    pub background: bool, // 미리 받기는 저우선 통로로 보내고 데이터 절약 모드에서는 건너뛴다
+}
+
+// 고정/해제 (캐시에 없는 항목을 고정하면 이때 내려받는다)
+#[derive(Debug, Clone)]
+pub struct PinMedia {
+    pub url: String,
+    pub pinned: bool,
+}
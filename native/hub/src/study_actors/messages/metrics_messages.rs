@@ -0,0 +1,29 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+// 액터·메시지 종류별 처리 지연 집계 (시간 단위는 밀리초)
+#[derive(Debug, Clone, Serialize, Deserialize, SignalPiece)]
+pub struct LatencyStat {
+    pub actor: String,
+    pub message: String,
+    pub count: u64,
+    pub avg_processing_ms: f64,
+    pub max_processing_ms: f64,
+    pub avg_queue_ms: f64,
+    pub max_queue_ms: f64,
+    pub over_budget: u64, // 대기 + 처리 시간이 예산을 넘긴 횟수
+}
+
+// 평균 처리 시간이 긴 순서로 정렬된 집계 조회
+#[derive(Debug, Clone)]
+pub struct GetLatencyStats;
+
+// 추적 한 단계 (시작 시각은 추적이 열린 시점 기준, 단위는 밀리초)
+#[derive(Debug, Clone, Serialize, Deserialize, SignalPiece)]
+pub struct TraceHop {
+    pub actor: String,
+    pub message: String,
+    pub start_ms: f64,
+    pub duration_ms: f64,
+    pub error: Option<String>,
+}
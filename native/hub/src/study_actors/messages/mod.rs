@@ -1,13 +1,291 @@
 mod auth_messages;
 mod user_messages;
 mod data_messages;
+mod config_messages;
+mod i18n_messages;
+mod notification_messages;
+mod archive_messages;
+mod hash_messages;
+mod crypto_messages;
+mod tabular_messages;
+mod markdown_messages;
+#[cfg_attr(target_family = "wasm", allow(dead_code))]
+mod mqtt_messages;
+mod sensitive_messages;
+mod time_messages;
+#[cfg(not(target_family = "wasm"))]
+mod audio_messages;
+mod realtime_messages;
+mod chat_messages;
+mod app_messages;
+mod presence_messages;
+mod privacy_messages;
+mod router_messages;
+mod ocr_messages;
+mod ranking_messages;
+#[cfg_attr(not(feature = "ml"), allow(dead_code))]
+mod embedding_messages;
+mod event_messages;
+mod automation_messages;
+mod attachment_messages;
+mod collab_messages;
+mod error_messages;
+mod metrics_messages;
+mod platform_messages;
+mod validation_messages;
+mod currency_messages;
+mod recurrence_messages;
+mod diff_messages;
+mod text_analysis_messages;
+mod template_messages;
+mod id_messages;
+mod sync_crypto_messages;
+mod api_messages;
+#[cfg(all(
+    feature = "command-channel",
+    not(any(target_family = "wasm", target_os = "android", target_os = "ios"))
+))]
+mod command_messages;
+mod background_messages;
+mod network_messages;
+mod scheduler_messages;
+mod governor_messages;
+mod snapshot_messages;
+mod media_messages;
+mod fuzzy_messages;
+mod search_messages;
+mod inbox_messages;
+mod feed_messages;
+mod diagnostics_messages;
+mod admin_messages;
+#[cfg_attr(not(all(feature = "scripting", not(target_family = "wasm"))), allow(dead_code))]
+mod script_messages;
+#[cfg_attr(not(all(feature = "wasm-rules", not(target_family = "wasm"))), allow(dead_code))]
+mod rules_messages;
+mod quota_messages;
+mod billing_messages;
+mod mail_messages;
+mod ingest_messages;
+mod link_preview_messages;
+mod feed_reader_messages;
+mod calendar_messages;
+mod contact_messages;
+mod dedup_messages;
+mod storage_usage_messages;
+mod maintenance_messages;
 
-pub use auth_messages::{Login, Logout, VerifyToken, ProcessLogin, AuthResult};
-pub use user_messages::{GetProfile, UpdateProfile, UserEvent};
-pub use data_messages::{FetchData, StoreData, CacheData, FetchRecentData};
+pub use auth_messages::{
+    Login, Logout, VerifyToken, ProcessLogin, UserSession, AuthResult, ExchangeAuthCode,
+    RevokeAllSessions,
+};
+pub use user_messages::{
+    GetProfile, SearchUsers, UpdateProfile, UpdateProfileCache, UserEvent, UserPreferences,
+    UserProfile,
+};
+pub use data_messages::{
+    FetchData, StoreData, CacheData, DeleteData, ScanPrefix, FetchRecentData, DataItem, UserData, AddTag,
+    RemoveTag, CreateCollection, AddItemToCollection, Collection, TagCount, ItemsByTag, ItemPage,
+    ItemRevision, GetItemHistory, RevertItemToRevision, UndoLastChange, RedoLastChange,
+    SetItemContent, FetchPagedRemote, PushLocalChanges, CreateDataItem, ListItems, MergeItems,
+    FetchMode, MutationKind,
+};
+pub use config_messages::{
+    AdminConfig, ApiConfig, AppConfig, AttachmentConfig, AuthConfig, BillingConfig,
+    CurrencyConfig, DeepLinkConfig, FeedReaderConfig, GetConfig, I18nConfig, IngestConfig,
+    LinkPreviewConfig, MailConfig, MediaCacheConfig, MetricsConfig, NetworkConfig,
+    NotificationConfig, QuotaConfig, RealtimeConfig, SetConfigValue, StartupConfig,
+    StorageConfig, TemplateConfig, TextAnalysisConfig, TimeConfig, TimeoutConfig,
+};
+// 기능을 끄고 빌드해도 설정 구조는 그대로 두고, 쓰는 액터가 있을 때만 내보낸다
+#[cfg(all(
+    feature = "command-channel",
+    not(any(target_family = "wasm", target_os = "android", target_os = "ios"))
+))]
+pub use config_messages::CommandChannelConfig;
+#[cfg(feature = "ml")]
+pub use config_messages::EmbeddingConfig;
+#[cfg(all(feature = "wasm-rules", not(target_family = "wasm")))]
+pub use config_messages::RulesConfig;
+#[cfg(all(feature = "scripting", not(target_family = "wasm")))]
+pub use config_messages::ScriptConfig;
+#[cfg(all(
+    feature = "smtp",
+    not(any(target_family = "wasm", target_os = "android", target_os = "ios"))
+))]
+pub use config_messages::SmtpConfig;
+pub use i18n_messages::{BundleSource, LoadLocaleBundle, Translate};
+pub use notification_messages::{PushPlatform, RegisterPushToken, ScheduleLocalNotification};
+pub use archive_messages::{ArchiveFormat, ArchiveOperation, CreateArchive, ExtractArchive};
+pub use hash_messages::{ComputeHash, HashAlgorithm, HashSource, VerifyHash};
+pub use crypto_messages::{Decrypt, Encrypt, GenerateKey, RotateEncryptionKey};
+pub use tabular_messages::{
+    CellKind, CellValue, RowError, TabularFormat, TabularImportTarget, TabularRow,
+};
+pub use markdown_messages::{RenderMarkdown, RenderMode};
+pub use mqtt_messages::MqttQos;
+#[cfg(not(target_family = "wasm"))]
+pub use mqtt_messages::{MqttPublish, MqttSubscribe};
+pub use sensitive_messages::{DiscardSensitivePayload, StoreSensitivePayload};
+pub use time_messages::{ClockSource, SyncClock};
+#[cfg(not(target_family = "wasm"))]
+pub use audio_messages::{ComputeWaveform, TranscodeToOpus};
+pub use realtime_messages::{RealtimeEnvelope, RealtimeEvent, SendRealtimeEnvelope};
+pub use chat_messages::{ChatMessage, Conversation, DeliveryStatus, SendChatMessage};
+pub use app_messages::{AppLifecycleState, StartupPhase, StartupStep};
+pub use presence_messages::{GetPresence, PresenceState};
+pub use privacy_messages::{
+    CheckConsent, ConsentCategory, RemoteWipe, SetStorageWritesPaused, WipeOrigin, WipeReport,
+    WipeUserData,
+};
+pub use router_messages::{DeepLinkRoute, Navigation, ResolveDeepLink};
+pub use ocr_messages::{FrameFormat, OcrPrepOptions, PreprocessFrame, ProcessedFrame, QuadPoint};
+pub use ranking_messages::{
+    QuerySuggestion, RankItems, RankedItem, RecordItemAccess, RecordSearchQuery, SuggestQueries,
+};
+pub use embedding_messages::SemanticMatch;
+#[cfg(feature = "ml")]
+pub use embedding_messages::{EmbedText, IndexItems, SemanticSearch};
+pub use event_messages::AppEvent;
+pub use automation_messages::{
+    AutomationRule, RegisterRule, RemoveRule, RuleAction, RuleCondition, RuleTrigger,
+};
+pub use attachment_messages::{
+    AttachBytes, AttachFile, AttachRemoteFile, Attachment, CollectOrphanedBlobs, OpenAttachment,
+    RecordItemAttachment,
+};
+pub use collab_messages::{ApplyEdit, MergeRemoteUpdate, OpenDocument, TextEdit};
+pub use error_messages::{ApiError, AppError, AuthError, ErrorEnvelope, StorageError, UserError};
+#[cfg(all(
+    feature = "command-channel",
+    not(any(target_family = "wasm", target_os = "android", target_os = "ios"))
+))]
+pub use error_messages::CommandError;
+pub use metrics_messages::{GetLatencyStats, LatencyStat, TraceHop};
+pub use platform_messages::{GetPlatformCapabilities, PlatformCapabilities};
+pub use validation_messages::{
+    ContactFieldKind, FieldError, NormalizeContactField, NormalizedContactField, ValidateForm,
+};
+pub use currency_messages::{
+    ArithmeticOp, CalculateMoney, Conversion, ConvertCurrency, FormatMoney, RoundingMode,
+    RoundingRule,
+};
+pub use recurrence_messages::{ExpandRecurrence, Occurrence};
+pub use diff_messages::{DiffHunk, DiffLine, DiffLineKind, DiffText, MergeOutcome, ThreeWayMerge};
+pub use text_analysis_messages::{AnalyzeText, TextAnalysis, TextAnnotation, TextStats};
+pub use template_messages::{RegisterTemplate, RenderTemplate, TemplateFormat};
+pub use id_messages::{GenerateIds, IdKind};
+pub use sync_crypto_messages::{
+    ChangeSyncPassphrase, ExportSyncKeyring, ImportSyncKeyring, LockSyncEncryption, OpenSyncItems,
+    RotateSyncKey, SealSyncItems, SealedItem, SetupSyncEncryption, SyncPayload,
+    UnlockSyncEncryption,
+};
+pub use api_messages::{
+    DeletedItem, Endpoint, ExchangeAuthCodeEndpoint, GetEntitlementsEndpoint,
+    GetItemEndpoint, GetPlanLimitsEndpoint, GetProfileEndpoint, ItemListQuery, ListItemsEndpoint,
+    LoginEndpoint, PushItemsBody, PushItemsEndpoint, RemoteItemPage, SearchUsersEndpoint,
+    TokenResponse, UpdateProfileEndpoint, UserSearchQuery, ValidateReceiptEndpoint,
+};
+#[cfg(all(
+    feature = "command-channel",
+    not(any(target_family = "wasm", target_os = "android", target_os = "ios"))
+))]
+pub use command_messages::{CommandReceipt, InboundCommand, SignedCommand};
+pub use background_messages::{BackgroundSubsystem, SetSubsystemSuspended, SuspendReason};
+pub use network_messages::{
+    BandwidthUsage, CircuitState, ConnectionDiagnostics, MediaQuality, SpeedTestPhase, TrafficClass,
+};
+pub use scheduler_messages::{
+    CancelReminder, Reminder, ScheduledTask, SetReminder, SnoozeReminder, ThrottlePolicy,
+};
+pub use governor_messages::PerformanceLevel;
+pub use snapshot_messages::{CaptureSnapshot, PersistState, PersistedState, RestoreState};
+pub use media_messages::{GetMedia, MediaEntry, PinMedia};
+pub use fuzzy_messages::{FuzzyHit, FuzzyMatch};
+pub use search_messages::{SearchResult, SearchSource};
+pub use inbox_messages::{
+    InboxNotification, InboxSource, ListNotifications, MarkNotificationsRead, NotificationPage,
+    PostNotification,
+};
+pub use feed_messages::{FeedAction, FeedEntry, FeedPage, FeedSource, FetchFeedPage};
+pub use diagnostics_messages::{
+    ActorHealth, ActorStatus, CrashReport, CreateDiagnosticBundle, DiagnosticBundle,
+};
+pub use admin_messages::{
+    AdminAction, AdminCommand, AdminOverride, ApplyAdminCommand, ListAdminOverrides, LogLevel,
+    SignedAdminCommand,
+};
+pub use script_messages::{ScriptInfo, ScriptTopic};
+#[cfg(all(feature = "scripting", not(target_family = "wasm")))]
+pub use script_messages::{ListScripts, RegisterScript, RemoveScript};
+pub use rules_messages::RulesModuleInfo;
+#[cfg(all(feature = "wasm-rules", not(target_family = "wasm")))]
+pub use rules_messages::{
+    CheckRulesUpdate, GetRulesModule, RulesManifest, RulesValidation, ScoreWithRules,
+    ValidateWithRules,
+};
+pub use quota_messages::{
+    CheckQuota, PlanLimits, QuotaDecision, QuotaMetric, QuotaUsage, RecordUsage, RefreshPlanLimits,
+};
+pub use billing_messages::{
+    Entitlement, GetEntitlements, PurchaseReceipt, ValidateReceipt,
+};
+pub use mail_messages::{ComposeMail, MailStatus};
+pub use ingest_messages::{IngestSharedContent, IngestedContent, SharedContentKind};
+pub use link_preview_messages::{FetchLinkPreview, LinkPreview};
+pub use feed_reader_messages::{
+    FeedArticle, FeedSubscription, ListFeedArticles, SubscribeFeed, UnsubscribeFeed,
+};
+pub use calendar_messages::{CalendarEvent, ExportIcs, IcsImport, ImportIcs};
+pub use contact_messages::{Contact, ContactField, ImportVcard, VcardImport};
+pub use dedup_messages::{DuplicateGroup, FindDuplicates};
+pub use storage_usage_messages::{
+    CategoryCleared, CategoryUsage, ClearStorage, ClearStorageCategory, GetStorageUsage,
+    MeasureStorage, StorageCategory, StorageUsageReport,
+};
+pub use maintenance_messages::{
+    CompactStorage, CompactionStats, IntegrityReport, MaintenanceReport, RunMaintenance,
+    VerifyBlobs,
+};
 
 // 공통 타입 정의
 pub type UserId = String;
-pub type UserError = Box<dyn std::error::Error + Send + Sync>;
-pub type AuthError = Box<dyn std::error::Error + Send + Sync>;
-pub type StorageError = Box<dyn std::error::Error + Send + Sync>;
+// 사용자·데이터 액터 핸들러의 공통 결과 타입
+pub type ActorResult<T> = Result<T, UserError>;
+pub type ConfigError = Box<dyn std::error::Error + Send + Sync>;
+pub type I18nError = Box<dyn std::error::Error + Send + Sync>;
+pub type NotificationError = Box<dyn std::error::Error + Send + Sync>;
+pub type ArchiveError = Box<dyn std::error::Error + Send + Sync>;
+pub type HashError = Box<dyn std::error::Error + Send + Sync>;
+pub type CryptoError = Box<dyn std::error::Error + Send + Sync>;
+pub type ImportError = Box<dyn std::error::Error + Send + Sync>;
+#[cfg(not(target_family = "wasm"))]
+pub type MqttError = Box<dyn std::error::Error + Send + Sync>;
+pub type TimeError = Box<dyn std::error::Error + Send + Sync>;
+#[cfg(not(target_family = "wasm"))]
+pub type AudioError = Box<dyn std::error::Error + Send + Sync>;
+pub type RealtimeError = Box<dyn std::error::Error + Send + Sync>;
+pub type ChatError = Box<dyn std::error::Error + Send + Sync>;
+pub type PrivacyError = Box<dyn std::error::Error + Send + Sync>;
+pub type RouterError = Box<dyn std::error::Error + Send + Sync>;
+pub type OcrError = Box<dyn std::error::Error + Send + Sync>;
+pub type RankingError = Box<dyn std::error::Error + Send + Sync>;
+#[cfg(feature = "ml")]
+pub type EmbeddingError = Box<dyn std::error::Error + Send + Sync>;
+pub type AutomationError = Box<dyn std::error::Error + Send + Sync>;
+pub type AttachmentError = Box<dyn std::error::Error + Send + Sync>;
+pub type MediaCacheError = Box<dyn std::error::Error + Send + Sync>;
+pub type InboxError = Box<dyn std::error::Error + Send + Sync>;
+pub type ReminderError = Box<dyn std::error::Error + Send + Sync>;
+pub type FeedError = Box<dyn std::error::Error + Send + Sync>;
+pub type DiagnosticsError = Box<dyn std::error::Error + Send + Sync>;
+pub type AdminError = Box<dyn std::error::Error + Send + Sync>;
+#[cfg(all(feature = "scripting", not(target_family = "wasm")))]
+pub type ScriptError = Box<dyn std::error::Error + Send + Sync>;
+#[cfg(all(feature = "wasm-rules", not(target_family = "wasm")))]
+pub type RulesError = Box<dyn std::error::Error + Send + Sync>;
+pub type QuotaError = Box<dyn std::error::Error + Send + Sync>;
+pub type BillingError = Box<dyn std::error::Error + Send + Sync>;
+pub type MailError = Box<dyn std::error::Error + Send + Sync>;
+pub type FeedReaderError = Box<dyn std::error::Error + Send + Sync>;
+pub type CollabError = Box<dyn std::error::Error + Send + Sync>;
+pub type TextAnalysisError = Box<dyn std::error::Error + Send + Sync>;
@@ -0,0 +1,26 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+// MQTT 명세의 QoS 이름을 그대로 쓴다
+#[allow(clippy::enum_variant_names)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub enum MqttQos {
+    AtMostOnce,
+    AtLeastOnce,
+    ExactlyOnce,
+}
+
+// 다른 액터에서 브로커로 메시지를 발행할 때 사용
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MqttPublish {
+    pub topic: String,
+    pub payload: Vec<u8>,
+    pub qos: MqttQos,
+    pub retain: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MqttSubscribe {
+    pub topic: String,
+    pub qos: MqttQos,
+}
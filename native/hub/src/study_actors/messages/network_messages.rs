@@ -0,0 +1,98 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+// 대역폭 집계와 데이터 절약 모드에서 쓰는 요청 분류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, SignalPiece)]
+pub enum TrafficClass {
+    Sync,      // 서버 API (인증, 프로필, 항목 동기화)
+    Downloads, // 첨부 파일, 번역 번들 등 내려받기
+    Analytics,
+    Images, // 썸네일 등 미리보기 이미지
+    Prefetch,
+    #[default]
+    Other,
+}
+
+impl TrafficClass {
+    pub const ALL: [Self; 6] = [
+        Self::Sync,
+        Self::Downloads,
+        Self::Analytics,
+        Self::Images,
+        Self::Prefetch,
+        Self::Other,
+    ];
+
+    // 데이터 절약 모드에서도 보내야 하는 트래픽 (나머지는 종량제 연결이 끝날 때까지 미룬다)
+    pub fn is_essential(self) -> bool {
+        !matches!(self, Self::Analytics | Self::Images | Self::Prefetch)
+    }
+}
+
+// 분류별 누적 사용량 (본문 크기 기준)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, SignalPiece)]
+pub struct BandwidthUsage {
+    pub class: TrafficClass,
+    pub bytes_sent: u64,
+    pub bytes_received: u64,
+    pub requests: u64,
+    pub deferred: u64, // 데이터 절약 모드로 보내지 않은 요청 수
+}
+
+// 호스트별 연결 진단
+// 연결 수는 응답이 온 로컬 소켓으로 센다 (웹이나 연결 정보가 없는 응답만 받았으면 None).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, SignalPiece)]
+pub struct ConnectionDiagnostics {
+    pub host: String,
+    pub protocol: Option<String>, // 마지막 응답의 HTTP 버전 (웹에서는 브라우저가 알려주지 않는다)
+    pub requests: u64,
+    pub connections_opened: Option<u64>,
+    pub reused: Option<u64>,
+    // 협상된 TLS 버전
+    // reqwest는 응답에 인증서만 붙이고 TLS 버전은 알려주지 않아 지금은 항상 None이다.
+    pub tls_version: Option<String>,
+}
+
+// 호스트별 차단기 상태 (Open이면 서버에 보내지 않고 바로 실패한다)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, SignalPiece)]
+pub enum CircuitState {
+    #[default]
+    Closed,
+    Open,
+    HalfOpen, // 쉬는 시간이 지나 요청 하나로 복구 여부를 확인하는 중
+}
+
+// 속도 측정 단계
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, SignalPiece)]
+pub enum SpeedTestPhase {
+    Latency,
+    Download,
+    Upload,
+}
+
+// 측정한 속도로 고른 미디어 품질 단계 (측정하지 못했으면 Low)
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize, SignalPiece,
+)]
+pub enum MediaQuality {
+    #[default]
+    Low,
+    Medium,
+    High,
+}
+
+impl MediaQuality {
+    pub fn from_speed(download_mbps: Option<f64>, latency_ms: Option<u64>) -> Self {
+        let quality = match download_mbps {
+            Some(mbps) if mbps >= 10.0 => Self::High,
+            Some(mbps) if mbps >= 3.0 => Self::Medium,
+            _ => Self::Low,
+        };
+        // 지연이 크면 버퍼링이 잦으므로 한 단계 낮춘다
+        match quality {
+            Self::High if latency_ms.is_some_and(|latency| latency > 300) => Self::Medium,
+            Self::Medium if latency_ms.is_some_and(|latency| latency > 300) => Self::Low,
+            _ => quality,
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use super::UserId;
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, SignalPiece)]
+pub enum PushPlatform {
+    Fcm,
+    Apns,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegisterPushToken {
+    pub user_id: Option<UserId>,
+    pub platform: PushPlatform,
+    pub token: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduleLocalNotification {
+    pub id: String,
+    pub title: String,
+    pub body: String,
+    pub fire_at: u64, // 유닉스 타임스탬프 (초)
+}
@@ -0,0 +1,58 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+// 카메라 프레임 픽셀 형식 (Yuv420은 Y 평면만 사용)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, SignalPiece)]
+pub enum FrameFormat {
+    Gray8,
+    Rgba8,
+    Bgra8,
+    Yuv420,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SignalPiece)]
+pub struct OcrPrepOptions {
+    pub crop_to_quad: bool,
+    pub deskew: bool,
+    pub threshold: bool,
+    pub block_radius: u32, // 적응형 이진화 창 반경 (픽셀)
+    pub threshold_offset: i32,
+}
+
+impl Default for OcrPrepOptions {
+    fn default() -> Self {
+        Self {
+            crop_to_quad: true,
+            deskew: true,
+            threshold: true,
+            block_radius: 12,
+            threshold_offset: 10,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct QuadPoint {
+    pub x: f32,
+    pub y: f32,
+}
+
+// 한 프레임 전처리 (결과: 8비트 그레이스케일 이미지)
+#[derive(Debug, Clone)]
+pub struct PreprocessFrame {
+    pub pixels: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+    pub row_stride: u32, // 0이면 width * 픽셀 크기
+    pub format: FrameFormat,
+    pub options: OcrPrepOptions,
+}
+
+#[derive(Debug, Clone)]
+pub struct ProcessedFrame {
+    pub pixels: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+    pub skew_degrees: f32,
+    pub quad: Option<[QuadPoint; 4]>, // 좌상, 우상, 우하, 좌하 (원본 좌표)
+}
@@ -0,0 +1,16 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+// 현재 타깃에서 쓸 수 있는 하위 시스템 (없는 기능은 액터가 대체 수단으로 동작한다)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, SignalPiece)]
+pub struct PlatformCapabilities {
+    pub keychain: bool,       // OS 키체인/키스토어 (없으면 비밀 값을 메모리에 보관)
+    pub file_watching: bool,  // 파일 변경 감시
+    pub sled: bool,           // sled 디스크 DB (없으면 IndexedDB 또는 메모리 저장소)
+    pub indexed_db: bool,     // 브라우저 IndexedDB
+    pub multithreading: bool, // 워커 스레드 풀 런타임
+}
+
+// 시작 시 감지한 기능 조회
+#[derive(Debug, Clone)]
+pub struct GetPlatformCapabilities;
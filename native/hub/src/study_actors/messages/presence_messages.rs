@@ -0,0 +1,16 @@
+use super::UserId;
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub enum PresenceState {
+    Online,
+    Away,
+    Offline,
+}
+
+// 다른 액터가 상대방의 현재 상태를 조회할 때 사용
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetPresence {
+    pub user_id: UserId,
+}
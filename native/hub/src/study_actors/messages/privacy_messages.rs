@@ -0,0 +1,47 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, SignalPiece)]
+pub enum ConsentCategory {
+    Analytics,
+    CrashReports,
+    Personalization,
+}
+
+// 분석/크래시 리포트 등은 외부로 전송하기 전에 반드시 이 메시지로 동의 여부를 확인해야 한다
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckConsent {
+    pub category: ConsentCategory,
+}
+
+// 사용자 데이터 전체 삭제 시 각 액터에 전달 (저장소, 캐시, 대기열 비우기)
+#[derive(Debug, Clone)]
+pub struct WipeUserData;
+
+// 원격 삭제를 요청한 쪽 (서버: 푸시/WebSocket, 사용자: Dart)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, SignalPiece)]
+pub enum WipeOrigin {
+    Server,
+    User,
+}
+
+// 기기의 세션, 저장소, 비밀 값, 대기 작업을 모두 지운다
+// 감독자가 순서를 조정하므로 다른 액터에 직접 보내지 않는다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteWipe {
+    pub origin: WipeOrigin,
+    pub reason: Option<String>,
+}
+
+// 삭제 결과 (지운 대상 이름과 실패한 대상의 "이름: 오류")
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WipeReport {
+    pub wiped: Vec<String>,
+    pub errors: Vec<String>,
+}
+
+// 삭제하는 동안 저장소 쓰기를 막는다 (막힌 쓰기는 StorageError::WritesPaused로 실패)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct SetStorageWritesPaused {
+    pub paused: bool,
+}
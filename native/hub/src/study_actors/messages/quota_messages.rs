@@ -0,0 +1,90 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+use super::UserId;
+
+// 요금제 한도를 두는 사용량
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, SignalPiece)]
+#[serde(rename_all = "snake_case")]
+pub enum QuotaMetric {
+    ItemsCreated, // 이번 기간에 만든 항목 수
+    StorageBytes, // 기기에 저장된 항목 크기
+    ApiCalls,     // 이번 기간의 서버 API 호출 수
+}
+
+// 서버가 알려 주는 요금제 한도 (None은 무제한)
+// 항목 수와 API 호출 수는 period_end에 0으로 돌아간다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct PlanLimits {
+    pub plan: String,
+    pub items_created: Option<u64>,
+    pub storage_bytes: Option<u64>,
+    pub api_calls: Option<u64>,
+    pub period_start: u64,
+    pub period_end: u64,
+}
+
+impl PlanLimits {
+    pub fn limit(&self, metric: QuotaMetric) -> Option<u64> {
+        match metric {
+            QuotaMetric::ItemsCreated => self.items_created,
+            QuotaMetric::StorageBytes => self.storage_bytes,
+            QuotaMetric::ApiCalls => self.api_calls,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct QuotaUsage {
+    pub items_created: u64,
+    pub storage_bytes: u64,
+    pub api_calls: u64,
+}
+
+impl QuotaUsage {
+    pub fn used(&self, metric: QuotaMetric) -> u64 {
+        match metric {
+            QuotaMetric::ItemsCreated => self.items_created,
+            QuotaMetric::StorageBytes => self.storage_bytes,
+            QuotaMetric::ApiCalls => self.api_calls,
+        }
+    }
+
+    pub fn used_mut(&mut self, metric: QuotaMetric) -> &mut u64 {
+        match metric {
+            QuotaMetric::ItemsCreated => &mut self.items_created,
+            QuotaMetric::StorageBytes => &mut self.storage_bytes,
+            QuotaMetric::ApiCalls => &mut self.api_calls,
+        }
+    }
+}
+
+// 비용이 큰 작업 전에 amount만큼 더 써도 되는지 확인한다 (사용량은 늘리지 않는다)
+// 한도를 아직 모르면(서버 미설정, 오프라인) 허용한다.
+#[derive(Debug, Clone)]
+pub struct CheckQuota {
+    pub user_id: UserId,
+    pub metric: QuotaMetric,
+    pub amount: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuotaDecision {
+    pub allowed: bool,
+    pub used: u64,
+    pub limit: Option<u64>,
+}
+
+// 이벤트 버스로 볼 수 없는 사용량 (예: 서버 API 호출)
+#[derive(Debug, Clone)]
+pub struct RecordUsage {
+    pub user_id: UserId,
+    pub metric: QuotaMetric,
+    pub amount: u64,
+}
+
+// 서버에서 요금제 한도를 다시 받는다
+#[derive(Debug, Clone)]
+pub struct RefreshPlanLimits {
+    pub user_id: UserId,
+}
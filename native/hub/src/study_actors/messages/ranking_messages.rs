@@ -0,0 +1,46 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+use super::{DataItem, UserId};
+
+// 항목 열람 기록 (빈도/최근성 점수에 반영)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordItemAccess {
+    pub item_id: String,
+}
+
+// 사용자 항목을 관련도 순으로 정렬 (query가 있으면 본문 유사도도 반영)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RankItems {
+    pub user_id: UserId,
+    pub query: Option<String>,
+    pub limit: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SignalPiece)]
+pub struct RankedItem {
+    pub item: DataItem,
+    pub score: f32,
+}
+
+// 검색창에서 제출한 검색어 기록 (입력 중인 글자는 기록하지 않는다)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordSearchQuery {
+    pub user_id: UserId,
+    pub query: String,
+}
+
+// 입력 중인 접두어로 이전 검색어 추천 (빈도와 최근성 순)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuggestQueries {
+    pub user_id: UserId,
+    pub prefix: String,
+    pub limit: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SignalPiece)]
+pub struct QuerySuggestion {
+    pub query: String,
+    pub score: f32,
+    pub count: u32,
+}
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+// WebSocket으로 주고받는 공용 봉투 (channel로 채팅/프레즌스 등 도메인 구분)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RealtimeEnvelope {
+    pub channel: String,
+    pub kind: String,
+    pub data: Value,
+}
+
+// 구독 중인 액터들에게 브로드캐스트되는 이벤트
+#[derive(Debug, Clone)]
+pub enum RealtimeEvent {
+    Connected,
+    Disconnected,
+    Envelope(RealtimeEnvelope),
+}
+
+// 연결되지 않은 상태면 에러를 반환하므로 호출자가 재전송 여부를 결정한다
+#[derive(Debug, Clone)]
+pub struct SendRealtimeEnvelope(pub RealtimeEnvelope);
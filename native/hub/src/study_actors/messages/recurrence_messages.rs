@@ -0,0 +1,22 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+// RFC 5545 반복 규칙을 기간 [window_start, window_end) 안의 발생 시각으로 펼친다
+// 예: "FREQ=MONTHLY;BYDAY=-1FR;COUNT=6" (매월 마지막 금요일, 6번)
+// 시각은 모두 유닉스 초이고, 반복은 timezone(IANA 이름)의 현지 시각 기준이라
+// 일광 절약 시간이 바뀌어도 같은 현지 시각에 발생한다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExpandRecurrence {
+    pub rrule: String,
+    pub dtstart: i64,
+    pub timezone: String,
+    pub window_start: i64,
+    pub window_end: i64,
+    pub exdates: Vec<i64>, // 제외할 발생 시각 (COUNT에는 포함된다)
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct Occurrence {
+    pub timestamp: i64,
+    pub local_time: String, // RFC 3339, 현지 UTC 오프셋 포함
+}
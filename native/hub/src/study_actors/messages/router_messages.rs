@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// 검증을 통과한 딥 링크 경로
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum DeepLinkRoute {
+    OAuthCallback {
+        code: String,
+        state: Option<String>,
+    },
+    OAuthError {
+        error: String,
+        description: Option<String>,
+    },
+    Item {
+        item_id: String,
+    },
+    Share {
+        text: Option<String>,
+        url: Option<String>,
+        subject: Option<String>,
+    },
+    Home,
+}
+
+// URI를 해석하고 필요한 Rust 측 작업까지 마친 뒤 이동할 화면을 반환
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResolveDeepLink {
+    pub uri: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Navigation {
+    pub route: String,
+    pub args: HashMap<String, String>,
+}
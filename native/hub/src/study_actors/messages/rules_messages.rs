@@ -0,0 +1,47 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+use super::{DataItem, FieldError};
+
+// 규칙 모듈 배포 정보 (rules.manifest_url이 돌려준다)
+// signature는 "{version}.{sha256}" 바이트에 대한 Ed25519 서명(hex)이라 옛 모듈을 새 버전으로 속일 수 없다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RulesManifest {
+    pub version: u64,
+    pub url: String,
+    pub sha256: String,
+    pub signature: String,
+}
+
+// 적용 중인 규칙 모듈
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct RulesModuleInfo {
+    pub version: u64,
+    pub sha256: String,
+    pub loaded_at: u64,
+}
+
+// 규칙 모듈로 항목 검증 (모듈이 없으면 오류 없음)
+#[derive(Debug, Clone)]
+pub struct ValidateWithRules {
+    pub item: DataItem,
+}
+
+// 규칙 모듈로 항목 점수 계산 (모듈이 없으면 None)
+#[derive(Debug, Clone)]
+pub struct ScoreWithRules {
+    pub item: DataItem,
+}
+
+#[derive(Debug, Clone)]
+pub struct RulesValidation {
+    pub version: Option<u64>,
+    pub errors: Vec<FieldError>,
+}
+
+// 매니페스트를 확인해 더 새 버전이 있으면 내려받아 바꾼다 (바꾼 버전을 돌려준다)
+#[derive(Debug, Clone)]
+pub struct CheckRulesUpdate;
+
+#[derive(Debug, Clone)]
+pub struct GetRulesModule;
@@ -0,0 +1,112 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+// 배터리 상태에 따라 조절하는 주기 작업
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, SignalPiece)]
+pub enum ScheduledTask {
+    Sync,          // 원격 항목 가져오기
+    FractalStream, // 프랙털 프레임 스트림
+    FrameStats,    // 프레임 버퍼 통계 신호
+    CacheCleanup,
+    ClockSync,
+    AttachmentGc,
+    ChatRetry,        // 보내지 못한 채팅 메시지 재전송
+    AdminPoll,        // 관리 서버의 원격 명령 확인
+    RulesUpdate,      // 규칙 모듈 새 버전 확인
+    EntitlementCheck, // 구독 권한 만료 확인과 조용한 재확인
+    MailRetry,        // 보내지 못한 메일 재발송
+    FeedPoll,         // 구독한 피드의 새 글 확인
+    DedupScan,        // 바뀐 항목이 있는 사용자의 중복 후보 찾기
+    Maintenance,      // 저장소 압축과 블롭 무결성 검사
+}
+
+impl ScheduledTask {
+    pub const ALL: [Self; 14] = [
+        Self::Sync,
+        Self::FractalStream,
+        Self::FrameStats,
+        Self::CacheCleanup,
+        Self::ClockSync,
+        Self::AttachmentGc,
+        Self::ChatRetry,
+        Self::AdminPoll,
+        Self::RulesUpdate,
+        Self::EntitlementCheck,
+        Self::MailRetry,
+        Self::FeedPoll,
+        Self::DedupScan,
+        Self::Maintenance,
+    ];
+
+    // Dart가 따로 정하지 않았을 때 절전 중 적용할 정책
+    pub fn default_policy(self) -> ThrottlePolicy {
+        match self {
+            Self::Sync
+            | Self::FractalStream
+            | Self::FrameStats
+            | Self::DedupScan
+            | Self::Maintenance => ThrottlePolicy::Pause,
+            Self::ChatRetry | Self::MailRetry => ThrottlePolicy::Stretch(2),
+            Self::CacheCleanup
+            | Self::ClockSync
+            | Self::AttachmentGc
+            | Self::AdminPoll
+            | Self::RulesUpdate
+            | Self::EntitlementCheck
+            | Self::FeedPoll => ThrottlePolicy::Stretch(4),
+        }
+    }
+}
+
+// 절전 중 작업에 적용할 정책
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, SignalPiece)]
+pub enum ThrottlePolicy {
+    #[default]
+    Normal, // 그대로 둔다
+    Stretch(u32), // 주기를 n배로 늘린다
+    Pause,        // 절전이 끝날 때까지 멈춘다
+}
+
+// 항목에 붙인 알림 (항목마다 하나, 시각은 모두 유닉스 초)
+// repeat는 RFC 5545 반복 규칙이며 at을 첫 발생 시각(DTSTART)으로 timezone의 현지 시각 기준으로 펼친다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct Reminder {
+    pub item_id: String,
+    pub at: u64,
+    pub repeat: Option<String>, // 예: "FREQ=WEEKLY;BYDAY=MO,WE"
+    pub timezone: String,
+    pub next_at: Option<u64>,       // 다음 발생 시각 (반복이 끝났으면 None)
+    pub snoozed_until: Option<u64>, // 미룬 알림을 다시 보낼 시각
+}
+
+impl Reminder {
+    // 다음에 알림을 보낼 시각 (None이면 더 보낼 알림이 없다)
+    pub fn due_at(&self) -> Option<u64> {
+        match (self.next_at, self.snoozed_until) {
+            (Some(next), Some(snoozed)) => Some(next.min(snoozed)),
+            (next, snoozed) => next.or(snoozed),
+        }
+    }
+}
+
+// 항목 알림 설정 (같은 항목의 기존 알림은 바뀐다)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetReminder {
+    pub item_id: String,
+    pub at: u64,
+    pub repeat: Option<String>,
+    pub timezone: Option<String>, // 없으면 UTC
+}
+
+// 결과는 알림이 있었는지
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CancelReminder {
+    pub item_id: String,
+}
+
+// 지금부터 minutes분 뒤에 다시 알림 (반복 일정은 그대로 이어진다)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnoozeReminder {
+    pub item_id: String,
+    pub minutes: u32,
+}
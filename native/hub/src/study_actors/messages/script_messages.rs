@@ -0,0 +1,41 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+// 스크립트가 구독할 수 있는 이벤트 버스 주제
+// 원격 삭제, 관리 명령처럼 기기 보안에 관한 이벤트는 스크립트에 보이지 않는다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, SignalPiece)]
+#[serde(rename_all = "snake_case")]
+pub enum ScriptTopic {
+    ItemCreated,
+    ItemUpdated,
+    ItemDeleted,
+    ItemsSynced,
+    SystemNotice,
+    ConnectivityChanged,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct ScriptInfo {
+    pub name: String,
+    pub topics: Vec<ScriptTopic>,
+    pub runs: u64,
+    pub failures: u64, // 한도 초과, 실행 오류를 포함
+    pub last_error: Option<String>,
+}
+
+// 스크립트를 컴파일해 등록한다 (같은 이름이 있으면 바꾼다)
+// 스크립트는 event 상수로 이벤트를 받고 get_item(id), emit(name, payload), print(text)만 호출할 수 있다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegisterScript {
+    pub name: String,
+    pub source: String,
+    pub topics: Vec<ScriptTopic>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoveScript {
+    pub name: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ListScripts;
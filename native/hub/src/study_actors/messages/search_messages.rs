@@ -0,0 +1,23 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+// 통합 검색 결과의 출처 (Dart는 출처별 아이콘과 동작을 고른다)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, SignalPiece)]
+pub enum SearchSource {
+    Items,
+    Users,
+    Settings,
+    Actions,
+}
+
+// 출처를 가리지 않고 관련도 순으로 합친 검색 결과
+// id는 출처 안의 식별자 (항목 id, 사용자 id, 점으로 구분한 설정 키, 동작 이름)
+#[derive(Debug, Clone, Serialize, Deserialize, SignalPiece)]
+pub struct SearchResult {
+    pub source: SearchSource,
+    pub id: String,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub score: f32,          // 0~1, 출처별 가중치 반영
+    pub positions: Vec<u32>, // title 안에서 일치한 글자 위치 (UTF-16)
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+// 다른 액터가 토큰/복구 코드 등 민감한 값을 보관할 때 사용 (결과: 핸들)
+// 값은 액터 내부에서 zeroize 메모리로 옮겨지며 만료 시 지워진다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoreSensitivePayload {
+    pub label: String,
+    pub secret: Vec<u8>,
+    pub ttl_secs: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiscardSensitivePayload {
+    pub handle: String,
+}
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+
+use super::UserId;
+
+// 시작 스냅샷 조각 수집 (네트워크 없이 저장해 둔 상태만 읽어 돌려준다)
+#[derive(Debug, Clone)]
+pub struct CaptureSnapshot {
+    pub user_id: Option<UserId>, // 인증 조각에서 얻은 마지막 사용자
+}
+
+// 종료 직전에 액터가 남기는 상태 (다음 실행에서 이어서 쓴다)
+// 상태 구조를 바꾸면 액터가 버전을 올리고, 버전이 다른 기록은 읽지 않고 버린다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersistedState {
+    pub version: u32,
+    pub state: serde_json::Value,
+}
+
+impl PersistedState {
+    pub fn new<T: Serialize>(version: u32, state: &T) -> Option<Self> {
+        let state = serde_json::to_value(state).ok()?;
+        Some(Self { version, state })
+    }
+
+    // 버전이 다르거나 지금 구조로 읽을 수 없으면 None
+    pub fn read<T: DeserializeOwned>(self, version: u32) -> Option<T> {
+        if self.version != version {
+            return None;
+        }
+        serde_json::from_value(self.state).ok()
+    }
+}
+
+// 종료 전에 남길 상태 요청 (남길 것이 없으면 None)
+#[derive(Debug, Clone)]
+pub struct PersistState;
+
+// 이전 실행이 남긴 상태 복원 (버전이 맞지 않거나 읽을 수 없으면 false를 돌려주고 무시한다)
+#[derive(Debug, Clone)]
+pub struct RestoreState(pub PersistedState);
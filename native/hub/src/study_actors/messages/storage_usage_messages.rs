@@ -0,0 +1,84 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+// 설정 화면에 보여 줄 저장 공간 분류
+// Items와 Other는 사용자 데이터라 여기서 지울 수 없다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, SignalPiece)]
+pub enum StorageCategory {
+    Items,       // 항목, 리비전, 태그, 모음, 일정, 연락처
+    Attachments, // 첨부 원본 블롭과 색인 (비우면 어떤 첨부도 쓰지 않는 블롭만 지운다)
+    Thumbnails,  // 첨부 썸네일 (지워도 첨부를 열 때 다시 만든다)
+    Logs,        // 최근 로그와 패닉 기록
+    Caches,      // 미디어 캐시와 메모리 캐시
+    Other,       // 설정, 대기열, 동기화 상태 등
+}
+
+impl StorageCategory {
+    pub const ALL: [StorageCategory; 6] = [
+        StorageCategory::Items,
+        StorageCategory::Attachments,
+        StorageCategory::Thumbnails,
+        StorageCategory::Logs,
+        StorageCategory::Caches,
+        StorageCategory::Other,
+    ];
+
+    pub fn clearable(self) -> bool {
+        !matches!(self, StorageCategory::Items | StorageCategory::Other)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct CategoryUsage {
+    pub category: StorageCategory,
+    pub bytes: u64,
+    pub entries: u64, // 저장소 키, 파일, 캐시 항목, 로그 줄 수의 합
+    pub clearable: bool,
+}
+
+impl CategoryUsage {
+    pub fn new(category: StorageCategory, bytes: u64, entries: u64) -> Self {
+        Self {
+            category,
+            bytes,
+            entries,
+            clearable: category.clearable(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct StorageUsageReport {
+    pub categories: Vec<CategoryUsage>, // StorageCategory::ALL 순서
+    pub total_bytes: u64,
+    pub errors: Vec<String>, // 응답하지 않은 액터 (그 몫은 빠진 채로 합친다)
+}
+
+// 저장소 키와 블롭/캐시 디렉터리를 훑어 분류별 사용량을 모은다
+#[derive(Debug, Clone)]
+pub struct GetStorageUsage;
+
+// 분류 하나를 비운다 (해당 데이터를 가진 액터가 직접 지운다)
+#[derive(Debug, Clone)]
+pub struct ClearStorageCategory {
+    pub category: StorageCategory,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct CategoryCleared {
+    pub category: StorageCategory,
+    pub freed_bytes: u64,
+    pub usage: StorageUsageReport, // 비운 뒤 다시 잰 사용량
+}
+
+// 데이터를 가진 액터에 자기 몫의 사용량을 묻는다 (WipeUserData처럼 여러 액터가 처리한다)
+// 저장소 키는 StorageUsageActor가 직접 세므로 디렉터리와 메모리에 있는 것만 답한다.
+#[derive(Debug, Clone)]
+pub struct MeasureStorage;
+
+// 데이터를 가진 액터에 분류 하나를 비우게 한다, 결과는 지운 파일/항목 수
+// 자기 분류가 아니면 아무것도 하지 않는다.
+#[derive(Debug, Clone)]
+pub struct ClearStorage {
+    pub category: StorageCategory,
+}
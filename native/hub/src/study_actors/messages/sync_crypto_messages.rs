@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+
+use super::{DataItem, UserId};
+
+// 동기화 서버에 올리는 암호화된 항목
+// id와 updated_at은 서버가 병합/정렬에 쓰도록 평문으로 두고,
+// 나머지(제목, 본문, 태그, 첨부 목록)는 payload(nonce || ciphertext) 안에만 있다.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SealedItem {
+    pub id: String,
+    pub updated_at: u64,
+    pub key_id: String,
+    pub payload: Vec<u8>,
+}
+
+// 동기화 서버와 주고받는 항목
+// 암호화를 켜지 않은 사용자의 항목은 평문 그대로, 켠 사용자의 항목은 SealedItem으로 오간다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SyncPayload {
+    Sealed(SealedItem),
+    Plain(DataItem),
+}
+
+// 사용자의 동기화 암호화를 켠다 (데이터 키를 만들고 패스프레이즈에서 유도한 키로 감싸 보관)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetupSyncEncryption {
+    pub user_id: UserId,
+    pub passphrase: String,
+}
+
+// 보관된 데이터 키를 풀어 메모리에 올린다 (잠글 때까지 유지)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnlockSyncEncryption {
+    pub user_id: UserId,
+    pub passphrase: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LockSyncEncryption {
+    pub user_id: UserId,
+}
+
+// 새 데이터 키를 만들어 이후 업로드에 쓴다 (예전 키는 내려받은 항목을 풀 때 계속 쓴다)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RotateSyncKey {
+    pub user_id: UserId,
+}
+
+// 모든 데이터 키를 새 패스프레이즈로 다시 감싼다 (잠금 해제된 상태여야 한다)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangeSyncPassphrase {
+    pub user_id: UserId,
+    pub new_passphrase: String,
+}
+
+// 업로드 직전에 항목 암호화 (결과: 같은 순서의 SyncPayload)
+// 암호화를 켜지 않은 사용자는 평문으로, 켰지만 잠긴 사용자는 실패한다 (평문으로 올리지 않는다).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SealSyncItems {
+    pub user_id: UserId,
+    pub items: Vec<DataItem>,
+}
+
+// 내려받은 항목 복호화 (결과: DataItem 목록)
+// 암호화를 켠 사용자에게 온 평문 항목은 서버가 끼워 넣었을 수 있으므로 버린다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenSyncItems {
+    pub user_id: UserId,
+    pub items: Vec<SyncPayload>,
+}
+
+// 감싼 키 묶음을 주고받는다 (다른 기기가 회전한 키를 받아오거나 서버에 백업할 때)
+// 묶음에는 감싼 키와 솔트만 있으므로 서버에 올려도 된다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportSyncKeyring {
+    pub user_id: UserId,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportSyncKeyring {
+    pub user_id: UserId,
+    pub keyring: Vec<u8>,
+}
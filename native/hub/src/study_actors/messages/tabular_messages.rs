@@ -0,0 +1,73 @@
+use super::UserId;
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub enum TabularFormat {
+    Csv,
+    Xlsx,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, SignalPiece)]
+pub enum CellKind {
+    Empty,
+    Bool,
+    Int,
+    Float,
+    DateTime,
+    Text,
+}
+
+// 타입 추론이 적용된 셀 값
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub enum CellValue {
+    Empty,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    DateTime(String),
+    Text(String),
+}
+
+impl CellValue {
+    pub fn kind(&self) -> CellKind {
+        match self {
+            Self::Empty => CellKind::Empty,
+            Self::Bool(_) => CellKind::Bool,
+            Self::Int(_) => CellKind::Int,
+            Self::Float(_) => CellKind::Float,
+            Self::DateTime(_) => CellKind::DateTime,
+            Self::Text(_) => CellKind::Text,
+        }
+    }
+
+    pub fn as_text(&self) -> String {
+        match self {
+            Self::Empty => String::new(),
+            Self::Bool(value) => value.to_string(),
+            Self::Int(value) => value.to_string(),
+            Self::Float(value) => value.to_string(),
+            Self::DateTime(value) | Self::Text(value) => value.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SignalPiece)]
+pub struct TabularRow {
+    pub row_number: u64, // 파일 기준 1부터 시작
+    pub cells: Vec<CellValue>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SignalPiece)]
+pub struct RowError {
+    pub row_number: u64,
+    pub message: String,
+}
+
+// 파싱된 행을 DataItem으로 가져올 때의 열 매핑
+#[derive(Debug, Clone, Serialize, Deserialize, SignalPiece)]
+pub struct TabularImportTarget {
+    pub user_id: UserId,
+    pub title_column: u64,
+    pub content_column: u64,
+}
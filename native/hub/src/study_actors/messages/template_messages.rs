@@ -0,0 +1,25 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+// Html은 {{값}}을 HTML 이스케이프하고, Text(메일/메시지 본문)는 그대로 넣는다
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, SignalPiece)]
+pub enum TemplateFormat {
+    Html,
+    Text,
+}
+
+// Handlebars 템플릿 등록 (같은 이름이면 교체)
+// 부분 템플릿({{> 이름}})은 같은 형식으로 등록한 템플릿만 가리킬 수 있다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegisterTemplate {
+    pub name: String,
+    pub source: String,
+    pub format: TemplateFormat,
+}
+
+// 등록한 템플릿을 JSON 컨텍스트로 렌더링 (보고서 생성, 보낼 메일/메시지 작성)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RenderTemplate {
+    pub name: String,
+    pub context: serde_json::Value,
+}
@@ -0,0 +1,40 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+// 본문 분석 (언어 감지, 단어/글자 수, 읽기 시간, 맞춤법)
+// language가 없으면 감지한 언어를 쓰고, 맞춤법 검사는 그 언어의 사전이 있을 때만 한다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnalyzeText {
+    pub text: String,
+    pub language: Option<String>, // 예: "en_US" (사전 파일 이름과 같게)
+    pub spell_check: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct TextStats {
+    pub language: Option<String>, // ISO 639-1 (없으면 639-3), 감지가 불확실하면 None
+    pub language_confidence: f64,
+    pub words: u32,
+    pub characters: u32,
+    pub characters_no_spaces: u32,
+    pub sentences: u32,
+    pub reading_time_secs: u32,
+}
+
+// 틀린 단어의 위치와 추천 단어
+// 위치는 UTF-16 코드 단위 [start, end)라 Flutter TextRange에 그대로 쓸 수 있다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct TextAnnotation {
+    pub start: u32,
+    pub end: u32,
+    pub word: String,
+    pub suggestions: Vec<String>,
+}
+
+// spell_checked: 사전을 찾아 맞춤법 검사를 했는지 (아니면 annotations는 비어 있다)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct TextAnalysis {
+    pub stats: TextStats,
+    pub annotations: Vec<TextAnnotation>,
+    pub spell_checked: bool,
+}
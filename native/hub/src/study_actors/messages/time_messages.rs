@@ -0,0 +1,13 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub enum ClockSource {
+    Ntp,
+    HttpDate,
+    Device,
+}
+
+// 즉시 시간 동기화 (결과: 기기 시계 대비 오프셋 ms)
+#[derive(Debug, Clone)]
+pub struct SyncClock;
@@ -1,7 +1,8 @@
+use rinf::SignalPiece;
 use serde::{Deserialize, Serialize};
 use super::UserId;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, SignalPiece)]
 pub struct UserProfile {
     pub user_id: UserId,
     pub name: String,
@@ -10,7 +11,7 @@ pub struct UserProfile {
     pub preferences: UserPreferences,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, SignalPiece)]
 pub struct UserPreferences {
     pub theme: String,
     pub notifications_enabled: bool,
@@ -22,6 +23,13 @@ pub struct GetProfile {
     pub user_id: UserId,
 }
 
+// 이름이나 이메일로 사용자 찾기 (서버가 없으면 이 기기에서 불러온 프로필 중에서 찾는다)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchUsers {
+    pub query: String,
+    pub limit: usize,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdateProfile {
     pub user_id: UserId,
@@ -0,0 +1,41 @@
+use rinf::SignalPiece;
+use serde::{Deserialize, Serialize};
+
+// 스키마 이름으로 폼 값 검증 (Dart 폼과 Rust 쪽 저장 경로가 같은 규칙을 쓴다)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidateForm {
+    pub schema_name: String,
+    pub values: serde_json::Value,
+}
+
+// 필드 하나의 검증 오류
+// code로 분기/현지화하고 message는 기본 표시용 (교차 필드 규칙은 뒤쪽 필드에 붙는다)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct FieldError {
+    pub field: String,
+    pub code: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub enum ContactFieldKind {
+    Phone,
+    Email,
+}
+
+// 연락처 값 하나를 저장·비교용 형태로 (Flutter 입력란과 Rust 중복 판단·동기화가 같은 규칙을 쓴다)
+// 국가 번호 없이 입력한 전화번호는 default_region(ISO 3166-1 alpha-2, 예: "KR") 기준으로 읽는다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NormalizeContactField {
+    pub kind: ContactFieldKind,
+    pub value: String,
+    pub default_region: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SignalPiece)]
+pub struct NormalizedContactField {
+    pub value: String,   // 저장용 (전화번호는 E.164, 이메일은 도메인을 ASCII 소문자로)
+    pub display: String, // 표시용 (국제 형식 전화번호, 유니코드 도메인 이메일)
+    pub match_key: String, // 같은 연락처인지 비교하는 값 (이메일은 +태그와 Gmail의 점을 뺀다)
+    pub region: Option<String>, // 전화번호의 국가
+}
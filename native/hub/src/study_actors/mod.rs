@@ -2,11 +2,17 @@ pub mod actors;
 pub mod messages;
 pub mod signals;
 pub mod storage;
+pub mod logging;
+#[cfg(not(target_family = "wasm"))]
+pub mod runtime;
+#[cfg(feature = "bench")]
+pub mod bench;
+#[cfg(feature = "headless")]
+pub mod headless;
+#[cfg(test)]
+pub mod test_support;
 
-use messages::prelude::Address;
-use rinf::debug_print;
-
-use self::actors::AppSupervisor;
+use self::logging::debug_print;
 
 pub async fn initialize() {
     debug_print!("Initializing study_actors module...");
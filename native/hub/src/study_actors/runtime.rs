@@ -0,0 +1,159 @@
+use std::{
+    future::Future,
+    io,
+    sync::atomic::{AtomicBool, Ordering},
+};
+use tokio::runtime::{Builder, Handle, Runtime};
+
+use crate::study_actors::{
+    actors::jobs_in_flight,
+    logging::debug_print,
+    signals::{
+        ConfigureRuntimeRequest, EmitSignal, ReceiveSignal, RuntimeStatsRequest, RuntimeStatsSignal,
+    },
+};
+
+// tokio 기본값과 같은 블로킹 풀 상한
+const DEFAULT_BLOCKING_THREADS: usize = 512;
+
+// 액터가 만들어진 뒤에는 런타임을 다시 만들면 액터가 모두 사라지므로 설정 요청을 받지 않는다
+static ACTORS_STARTED: AtomicBool = AtomicBool::new(false);
+
+pub(crate) fn mark_actors_started() {
+    ACTORS_STARTED.store(true, Ordering::Release);
+}
+
+// Dart가 정하는 tokio 런타임 설정 (저사양 기기에서 스레드 수를 줄이는 등)
+#[derive(Debug, Clone, Default)]
+pub struct RuntimeSettings {
+    worker_threads: Option<usize>,
+    blocking_threads: Option<usize>,
+    io_uring: bool,
+}
+
+impl From<ConfigureRuntimeRequest> for RuntimeSettings {
+    fn from(request: ConfigureRuntimeRequest) -> Self {
+        Self {
+            worker_threads: request
+                .worker_threads
+                .map(|threads| threads.max(1) as usize),
+            blocking_threads: request
+                .blocking_threads
+                .map(|threads| threads.max(1) as usize),
+            io_uring: request.enable_io_uring,
+        }
+    }
+}
+
+impl RuntimeSettings {
+    fn build(&self) -> io::Result<Runtime> {
+        // multi-thread 기능이 켜져 있어도 작업 스레드 1개를 요청하면 단일 스레드 런타임을 쓴다
+        #[cfg(feature = "multi-thread")]
+        let mut builder = match self.worker_threads {
+            Some(1) => Builder::new_current_thread(),
+            Some(threads) => {
+                let mut builder = Builder::new_multi_thread();
+                builder.worker_threads(threads);
+                builder
+            }
+            None => Builder::new_multi_thread(),
+        };
+        #[cfg(not(feature = "multi-thread"))]
+        let mut builder = {
+            if self.worker_threads.is_some_and(|threads| threads > 1) {
+                debug_print!("multi-thread feature is off, ignoring worker_threads");
+            }
+            Builder::new_current_thread()
+        };
+
+        // tokio의 io_uring 지원은 tokio_unstable 빌드에서만 쓸 수 있다
+        if self.io_uring {
+            debug_print!("io_uring is not available in this build, using the default driver");
+        }
+        builder
+            .max_blocking_threads(self.blocking_threads.unwrap_or(DEFAULT_BLOCKING_THREADS))
+            .enable_all()
+            .build()
+    }
+
+    fn stats(&self) -> RuntimeStatsSignal {
+        let metrics = Handle::current().metrics();
+        RuntimeStatsSignal {
+            worker_threads: metrics.num_workers() as u64,
+            alive_tasks: metrics.num_alive_tasks() as u64,
+            global_queue_depth: metrics.global_queue_depth() as u64,
+            blocking_threads: self.blocking_threads.unwrap_or(DEFAULT_BLOCKING_THREADS) as u64,
+            blocking_jobs: jobs_in_flight() as u64,
+            io_uring: false,
+        }
+    }
+}
+
+// 런타임을 직접 만들어 앱을 실행한다 (네이티브 main에서 호출)
+// 액터 생성 전에 ConfigureRuntimeRequest가 오면 지금 런타임을 내리고 그 설정으로 다시 만든다.
+pub fn run<F, Fut>(app: F)
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = ()>,
+{
+    let mut settings = RuntimeSettings::default();
+    loop {
+        let runtime = match settings.build() {
+            Ok(runtime) => runtime,
+            Err(e) => {
+                debug_print!(
+                    "Failed to build runtime with {:?}, using defaults: {}",
+                    settings,
+                    e
+                );
+                settings = RuntimeSettings::default();
+                match settings.build() {
+                    Ok(runtime) => runtime,
+                    Err(e) => {
+                        debug_print!("Failed to build default runtime: {}", e);
+                        return;
+                    }
+                }
+            }
+        };
+
+        let next = runtime.block_on(async {
+            // 설정이 적용되었음을 알리는 응답을 겸한다
+            settings.stats().emit();
+            tokio::spawn(serve_stats(settings.clone()));
+            tokio::select! {
+                _ = app() => None,
+                next = reconfigure_requested() => Some(next),
+            }
+        });
+        // 남은 작업(블로킹 작업 포함)을 기다리지 않고 내린다
+        runtime.shutdown_background();
+
+        match next {
+            Some(next) => {
+                debug_print!("Rebuilding runtime with {:?}", next);
+                settings = next;
+            }
+            None => return,
+        }
+    }
+}
+
+async fn reconfigure_requested() -> RuntimeSettings {
+    let receiver = ConfigureRuntimeRequest::signal_receiver();
+    while let Some(signal_pack) = receiver.recv().await {
+        if ACTORS_STARTED.load(Ordering::Acquire) {
+            debug_print!("Runtime configuration must arrive before CreateActorsRequest, ignoring");
+            continue;
+        }
+        return signal_pack.message.into();
+    }
+    std::future::pending().await
+}
+
+async fn serve_stats(settings: RuntimeSettings) {
+    let receiver = RuntimeStatsRequest::signal_receiver();
+    while receiver.recv().await.is_some() {
+        settings.stats().emit();
+    }
+}
@@ -0,0 +1,16 @@
+use super::super::messages::{AdminOverride, LogLevel};
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct AdminStateRequest {}
+
+// 원격 명령이 적용되거나 만료될 때마다 보낸다
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct AdminStateSignal {
+    pub log_level: LogLevel,
+    pub recording_signals: bool,
+    pub feature_flags: HashMap<String, bool>,
+    pub overrides: Vec<AdminOverride>,
+}
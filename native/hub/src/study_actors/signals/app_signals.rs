@@ -1,3 +1,4 @@
+use super::super::messages::{AppLifecycleState, StartupPhase, StartupStep};
 use rinf::{DartSignal, RustSignal};
 use serde::{Deserialize, Serialize};
 
@@ -22,10 +23,61 @@ pub struct StateChangedSignal {
 #[derive(DartSignal, Serialize, Deserialize, Debug)]
 pub struct CreateActorsRequest {
     pub initialize_all: bool,
+    pub config_path: Option<String>,
 }
 
 #[derive(RustSignal, Serialize, Deserialize, Debug)]
 pub struct ActorsCreatedSignal {
-    pub actor_count: usize,
+    pub actor_count: u64,
     pub initialized_actors: Vec<String>,
 }
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct AppLifecycleChanged {
+    pub state: AppLifecycleState,
+}
+
+// 기기 네트워크 연결 상태 (connectivity_plus 등에서 전달)
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct ConnectivityChanged {
+    pub online: bool,
+    #[serde(default)]
+    pub metered: bool, // 셀룰러 등 종량제 연결 (데이터 절약 모드 판단)
+}
+
+// 첫 화면이 그려진 뒤 Dart가 보내며, 미뤄 둔 액터들이 이때 시작된다
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct ColdStartCompleteSignal {}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct StartupReportSignal {
+    pub phase: StartupPhase,
+    pub steps: Vec<StartupStep>,
+    pub total_ms: f64,
+    pub budget_ms: u64,
+    pub over_budget: bool,
+    pub deferred: Vec<String>, // 아직 시작되지 않은 단계 (지연 시작 보고에서는 비어 있음)
+}
+
+// tokio 런타임 설정 (네이티브 전용, CreateActorsRequest보다 먼저 보내야 적용된다)
+// 적용되면 새 런타임에서 RuntimeStatsSignal이 한 번 나가므로 그 뒤에 액터 생성을 요청한다.
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct ConfigureRuntimeRequest {
+    pub worker_threads: Option<u64>, // multi-thread 기능이 꺼져 있으면 무시
+    pub blocking_threads: Option<u64>,
+    pub enable_io_uring: bool,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct RuntimeStatsRequest {}
+
+#[cfg_attr(target_family = "wasm", allow(dead_code))]
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct RuntimeStatsSignal {
+    pub worker_threads: u64,
+    pub alive_tasks: u64,
+    pub global_queue_depth: u64,
+    pub blocking_threads: u64, // 블로킹 풀 상한
+    pub blocking_jobs: u64,    // offload()로 넘겨 아직 끝나지 않은 작업 수
+    pub io_uring: bool,
+}
@@ -0,0 +1,33 @@
+use super::super::messages::ArchiveOperation;
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct CreateArchiveRequest {
+    pub paths: Vec<String>,
+    pub dest: String,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct ExtractArchiveRequest {
+    pub archive: String,
+    pub dest: String,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct ArchiveProgressSignal {
+    pub operation: ArchiveOperation,
+    pub target: String,
+    pub processed: u64,
+    pub total: u64,
+    pub current_entry: String,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct ArchiveCompletedSignal {
+    pub operation: ArchiveOperation,
+    pub target: String,
+    pub success: bool,
+    pub entry_count: u64,
+    pub error: Option<String>,
+}
@@ -0,0 +1,59 @@
+use futures_util::FutureExt;
+use std::{any::Any, future::Future, panic::AssertUnwindSafe};
+
+use super::{EmitSignal, ReceiveSignal};
+use crate::study_actors::logging::debug_print;
+use crate::study_actors::messages::{AppError, ErrorEnvelope};
+
+// 응답을 기다리는 Dart 요청 (Dart가 붙인 correlation_id로 응답을 짝짓는다)
+pub trait AskSignal: ReceiveSignal {
+    type Answer: AnswerSignal;
+
+    fn correlation_id(&self) -> u64;
+}
+
+// 요청에 대한 응답 신호 (성공 값 또는 오류를 같은 correlation_id로 돌려준다)
+pub trait AnswerSignal: EmitSignal {
+    type Payload;
+
+    fn answer(correlation_id: u64, result: Result<Self::Payload, ErrorEnvelope>) -> Self;
+}
+
+// 요청을 처리하고 결과를 짝이 되는 응답 신호로 보낸다
+// 오류는 ErrorEnvelope로, 처리 중 패닉은 AppError::Panicked로 바꿔 응답하므로 Dart가 기다리다 멈추지 않는다.
+// 반환값은 이미 보낸 응답의 결과다 (추적 기록 등에 쓴다).
+pub async fn respond_to_dart<Req, F, Fut>(request: Req, work: F) -> Result<(), AppError>
+where
+    Req: AskSignal,
+    F: FnOnce(Req) -> Fut,
+    Fut: Future<Output = Result<<Req::Answer as AnswerSignal>::Payload, AppError>>,
+{
+    let correlation_id = request.correlation_id();
+    let result = AssertUnwindSafe(work(request))
+        .catch_unwind()
+        .await
+        .unwrap_or_else(|panic| Err(AppError::Panicked(panic_message(panic.as_ref()))));
+
+    match result {
+        Ok(payload) => {
+            Req::Answer::answer(correlation_id, Ok(payload)).emit();
+            Ok(())
+        }
+        Err(e) => {
+            debug_print!("Request {} failed: {}", correlation_id, e);
+            Req::Answer::answer(correlation_id, Err(ErrorEnvelope::from(&e))).emit();
+            Err(e)
+        }
+    }
+}
+
+// 패닉 값에서 메시지를 꺼낸다 (액터 실행 루프의 패닉 기록에도 쓴다)
+pub(crate) fn panic_message(panic: &(dyn Any + Send)) -> String {
+    if let Some(message) = panic.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = panic.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
@@ -0,0 +1,38 @@
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+use super::super::messages::Attachment;
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct AttachFileRequest {
+    pub item_id: String,
+    pub path: String,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct AttachRemoteFileRequest {
+    pub item_id: String,
+    pub url: String,
+    pub file_name: String,
+    pub size: u64,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct AttachmentAddedSignal {
+    pub item_id: String,
+    pub attachment: Option<Attachment>,
+    pub thumbnail_path: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct OpenAttachmentRequest {
+    pub attachment_id: String,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct AttachmentReadySignal {
+    pub attachment_id: String,
+    pub local_path: Option<String>,
+    pub thumbnail_path: Option<String>,
+    pub error: Option<String>,
+}
@@ -0,0 +1,39 @@
+use rinf::{DartSignal, RustSignal, RustSignalBinary};
+use serde::{Deserialize, Serialize};
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct WaveformRequest {
+    pub request_id: String,
+    pub path: String,
+    pub bucket_count: u64,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct WaveformSignal {
+    pub request_id: String,
+    pub peaks: Vec<f32>,
+    pub duration_ms: u64,
+    pub error: Option<String>,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct TranscodeAudioRequest {
+    pub request_id: String,
+    pub path: String,
+    pub bitrate_bps: Option<i32>,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct AudioProgressSignal {
+    pub request_id: String,
+    pub progress: f32, // 0.0 ~ 1.0 (전체 길이를 모르면 0)
+}
+
+// 변환된 Ogg/Opus 바이트는 바이너리 페이로드로 전달
+#[derive(RustSignalBinary, Serialize, Deserialize, Debug)]
+pub struct TranscodedAudioSignal {
+    pub request_id: String,
+    pub success: bool,
+    pub duration_ms: u64,
+    pub error: Option<String>,
+}
@@ -1,29 +1,150 @@
 use rinf::{DartSignal, RustSignal};
 use serde::{Deserialize, Serialize};
-use super::super::messages::{UserId, AuthResult};
+use super::super::messages::{
+    UserId, AuthResult, ErrorEnvelope, DataItem, UserProfile, UserSession,
+};
+use super::super::actors::TraceId;
+use super::{AnswerSignal, AskSignal};
 
 #[derive(DartSignal, Serialize, Deserialize, Debug)]
 pub struct LoginRequest {
+    pub correlation_id: u64,
     pub username: String,
     pub password: String,
+    pub idempotency_key: Option<String>, // 같은 키가 다시 오면 새 세션 대신 앞서 만든 세션으로 답한다
 }
 
 #[derive(RustSignal, Serialize, Deserialize, Debug)]
 pub struct LoginResponse {
+    pub correlation_id: u64,
     pub success: bool,
     pub user_id: Option<UserId>,
     pub token: Option<String>,
-    pub error: Option<String>,
+    pub error: Option<ErrorEnvelope>,
+    pub trace_id: u64, // GetTraceRequest로 처리 경로 조회
+}
+
+impl AskSignal for LoginRequest {
+    type Answer = LoginResponse;
+
+    fn correlation_id(&self) -> u64 {
+        self.correlation_id
+    }
+}
+
+impl AnswerSignal for LoginResponse {
+    type Payload = AuthResult;
+
+    // 로그인은 추적 안에서 처리되므로 응답 시점의 추적 ID를 함께 보낸다
+    fn answer(correlation_id: u64, result: Result<AuthResult, ErrorEnvelope>) -> Self {
+        let trace_id = TraceId::current().map_or(0, u64::from);
+        match result {
+            Ok(auth) => Self {
+                correlation_id,
+                success: true,
+                user_id: Some(auth.user_id),
+                token: Some(auth.token),
+                error: None,
+                trace_id,
+            },
+            Err(error) => Self {
+                correlation_id,
+                success: false,
+                user_id: None,
+                token: None,
+                error: Some(error),
+                trace_id,
+            },
+        }
+    }
+}
+
+// 로그인하고 첫 화면에 필요한 프로필과 최근 항목까지 한 번에 받는다 (감독자가 처리)
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct StartSessionRequest {
+    pub correlation_id: u64,
+    pub username: String,
+    pub password: String,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct SessionStartedResponse {
+    pub correlation_id: u64,
+    pub success: bool,
+    pub token: Option<String>,
+    pub profile: Option<UserProfile>,
+    pub recent_items: Vec<DataItem>, // 프로필이나 항목이 늦으면 같은 사용자의 마지막 값이 온다
+    pub error: Option<ErrorEnvelope>,
+    pub trace_id: u64,
+}
+
+impl AskSignal for StartSessionRequest {
+    type Answer = SessionStartedResponse;
+
+    fn correlation_id(&self) -> u64 {
+        self.correlation_id
+    }
+}
+
+impl AnswerSignal for SessionStartedResponse {
+    type Payload = UserSession;
+
+    fn answer(correlation_id: u64, result: Result<UserSession, ErrorEnvelope>) -> Self {
+        let trace_id = TraceId::current().map_or(0, u64::from);
+        match result {
+            Ok(session) => Self {
+                correlation_id,
+                success: true,
+                token: Some(session.token),
+                profile: Some(session.profile),
+                recent_items: session.recent_data.items,
+                error: None,
+                trace_id,
+            },
+            Err(error) => Self {
+                correlation_id,
+                success: false,
+                token: None,
+                profile: None,
+                recent_items: Vec::new(),
+                error: Some(error),
+                trace_id,
+            },
+        }
+    }
 }
 
 #[derive(DartSignal, Serialize, Deserialize, Debug)]
 pub struct LogoutRequest {
+    pub correlation_id: u64,
     pub user_id: UserId,
 }
 
 #[derive(RustSignal, Serialize, Deserialize, Debug)]
 pub struct LogoutResponse {
+    pub correlation_id: u64,
     pub success: bool,
+    pub error: Option<ErrorEnvelope>,
+}
+
+impl AskSignal for LogoutRequest {
+    type Answer = LogoutResponse;
+
+    fn correlation_id(&self) -> u64 {
+        self.correlation_id
+    }
+}
+
+impl AnswerSignal for LogoutResponse {
+    type Payload = ();
+
+    fn answer(correlation_id: u64, result: Result<(), ErrorEnvelope>) -> Self {
+        Self {
+            correlation_id,
+            success: result.is_ok(),
+            error: result.err(),
+        }
+    }
 }
 
 #[derive(RustSignal, Serialize, Deserialize, Debug)]
@@ -0,0 +1,37 @@
+use rinf::{DartSignal, RustSignal, SignalPiece};
+use serde::{Deserialize, Serialize};
+
+// 규칙 본문은 AutomationRule JSON 문자열
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct RegisterRuleRequest {
+    pub rule_json: String,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct RemoveRuleRequest {
+    pub rule_id: String,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct ListRulesRequest {}
+
+#[derive(Serialize, Deserialize, Debug, SignalPiece)]
+pub struct RuleSummary {
+    pub id: String,
+    pub name: String,
+    pub enabled: bool,
+    pub rule_json: String,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct RulesSignal {
+    pub rules: Vec<RuleSummary>,
+    pub error: Option<String>,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct RuleExecutedSignal {
+    pub rule_id: String,
+    pub actions_run: u64,
+    pub errors: Vec<String>,
+}
@@ -0,0 +1,16 @@
+use super::super::messages::BackgroundSubsystem;
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+
+// 창이 닫히거나 숨겨진 동안 계속 돌릴 하위 시스템 (목록에 없는 것은 포그라운드로 돌아올 때까지 멈춘다)
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct SetBackgroundPolicyRequest {
+    pub keep_alive: Vec<BackgroundSubsystem>,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct BackgroundModeSignal {
+    pub in_background: bool,
+    pub keep_alive: Vec<BackgroundSubsystem>,
+    pub suspended: Vec<BackgroundSubsystem>,
+}
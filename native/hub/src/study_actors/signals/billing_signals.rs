@@ -0,0 +1,28 @@
+use super::super::messages::{Entitlement, PurchaseReceipt, UserId};
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct ValidateReceiptRequest {
+    pub user_id: UserId,
+    pub receipt: PurchaseReceipt,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct EntitlementsRequest {
+    pub user_id: UserId,
+}
+
+// 권한이 바뀌거나 요청을 받을 때 보낸다 (지금 유효한 권한만)
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct EntitlementsChangedSignal {
+    pub user_id: UserId,
+    pub entitlements: Vec<Entitlement>,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct ReceiptRejectedSignal {
+    pub user_id: UserId,
+    pub product_id: String,
+    pub error: String,
+}
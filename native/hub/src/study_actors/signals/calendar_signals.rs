@@ -0,0 +1,88 @@
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+use super::super::messages::{ErrorEnvelope, IcsImport};
+use super::{AnswerSignal, AskSignal};
+
+// timezone은 기기 시간대 (TZID가 없는 시각을 해석할 때 쓴다)
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct ImportIcsRequest {
+    pub correlation_id: u64,
+    pub user_id: String,
+    pub ics: String,
+    pub timezone: String,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct IcsImportedResponse {
+    pub correlation_id: u64,
+    pub report: Option<IcsImport>,
+    pub error: Option<ErrorEnvelope>,
+}
+
+impl AskSignal for ImportIcsRequest {
+    type Answer = IcsImportedResponse;
+
+    fn correlation_id(&self) -> u64 {
+        self.correlation_id
+    }
+}
+
+impl AnswerSignal for IcsImportedResponse {
+    type Payload = IcsImport;
+
+    fn answer(correlation_id: u64, result: Result<IcsImport, ErrorEnvelope>) -> Self {
+        match result {
+            Ok(report) => Self {
+                correlation_id,
+                report: Some(report),
+                error: None,
+            },
+            Err(error) => Self {
+                correlation_id,
+                report: None,
+                error: Some(error),
+            },
+        }
+    }
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct ExportIcsRequest {
+    pub correlation_id: u64,
+    pub user_id: String,
+    pub item_ids: Option<Vec<String>>,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct IcsExportedResponse {
+    pub correlation_id: u64,
+    pub ics: Option<String>,
+    pub error: Option<ErrorEnvelope>,
+}
+
+impl AskSignal for ExportIcsRequest {
+    type Answer = IcsExportedResponse;
+
+    fn correlation_id(&self) -> u64 {
+        self.correlation_id
+    }
+}
+
+impl AnswerSignal for IcsExportedResponse {
+    type Payload = String;
+
+    fn answer(correlation_id: u64, result: Result<String, ErrorEnvelope>) -> Self {
+        match result {
+            Ok(ics) => Self {
+                correlation_id,
+                ics: Some(ics),
+                error: None,
+            },
+            Err(error) => Self {
+                correlation_id,
+                ics: None,
+                error: Some(error),
+            },
+        }
+    }
+}
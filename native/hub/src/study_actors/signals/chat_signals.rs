@@ -0,0 +1,74 @@
+use super::super::messages::{ChatMessage, Conversation, DeliveryStatus, UserId};
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct LoadConversationsRequest {}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct ConversationListSignal {
+    pub conversations: Vec<Conversation>,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct CreateConversationRequest {
+    pub title: String,
+    pub participants: Vec<UserId>,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct ConversationUpdatedSignal {
+    pub conversation: Conversation,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct LoadMessagesRequest {
+    pub conversation_id: String,
+    pub limit: Option<u64>,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct ConversationMessagesSignal {
+    pub conversation_id: String,
+    pub messages: Vec<ChatMessage>,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct SendChatMessageRequest {
+    pub user_id: UserId,
+    pub conversation_id: String,
+    pub body: String,
+}
+
+// 내가 보낸 메시지(대기열 추가 포함)와 상대가 보낸 메시지 모두 전달
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct MessageReceivedSignal {
+    pub message: ChatMessage,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct MessageStatusSignal {
+    pub conversation_id: String,
+    pub message_id: String,
+    pub status: DeliveryStatus,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct TypingRequest {
+    pub user_id: UserId,
+    pub conversation_id: String,
+    pub is_typing: bool,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct TypingIndicatorSignal {
+    pub conversation_id: String,
+    pub user_id: UserId,
+    pub is_typing: bool,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct MarkConversationReadRequest {
+    pub user_id: UserId,
+    pub conversation_id: String,
+}
@@ -0,0 +1,34 @@
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+use super::super::messages::TextEdit;
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct OpenDocumentRequest {
+    pub item_id: String,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct CloseDocumentRequest {
+    pub item_id: String,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct ApplyEditRequest {
+    pub item_id: String,
+    pub edits: Vec<TextEdit>,
+}
+
+// 문서를 열었을 때, 또는 로컬 편집이 거부되어 Dart 쪽 본문을 다시 맞춰야 할 때
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct DocumentSnapshotSignal {
+    pub item_id: String,
+    pub content: String,
+    pub error: Option<String>,
+}
+
+// 원격 업데이트 병합으로 바뀐 구간
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct DocumentChangedSignal {
+    pub item_id: String,
+    pub edits: Vec<TextEdit>,
+}
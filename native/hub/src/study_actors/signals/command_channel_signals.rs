@@ -0,0 +1,10 @@
+use rinf::RustSignal;
+use serde::{Deserialize, Serialize};
+
+// 로컬 명령 채널을 열었거나 열지 못했을 때 한 번 보낸다
+// (설정에서 켰는데 비밀 키나 주소가 잘못돼 열지 못하면 error에 이유가 담긴다)
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct CommandChannelStatusSignal {
+    pub listening: Option<String>,
+    pub error: Option<String>,
+}
@@ -0,0 +1,16 @@
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct SetConfigRequest {
+    pub key: String,
+    pub value_json: String,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct ConfigUpdatedSignal {
+    pub key: String,
+    pub success: bool,
+    pub config_json: String,
+    pub error: Option<String>,
+}
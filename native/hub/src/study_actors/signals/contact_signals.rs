@@ -0,0 +1,53 @@
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+use super::super::messages::{ErrorEnvelope, VcardImport};
+use super::{AnswerSignal, AskSignal};
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct ImportVcardRequest {
+    pub correlation_id: u64,
+    pub user_id: String,
+    pub vcf: String,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct VcardImportedResponse {
+    pub correlation_id: u64,
+    pub report: Option<VcardImport>,
+    pub error: Option<ErrorEnvelope>,
+}
+
+impl AskSignal for ImportVcardRequest {
+    type Answer = VcardImportedResponse;
+
+    fn correlation_id(&self) -> u64 {
+        self.correlation_id
+    }
+}
+
+impl AnswerSignal for VcardImportedResponse {
+    type Payload = VcardImport;
+
+    fn answer(correlation_id: u64, result: Result<VcardImport, ErrorEnvelope>) -> Self {
+        match result {
+            Ok(report) => Self {
+                correlation_id,
+                report: Some(report),
+                error: None,
+            },
+            Err(error) => Self {
+                correlation_id,
+                report: None,
+                error: Some(error),
+            },
+        }
+    }
+}
+
+// 주소록이 크면 가져오는 동안 몇 장마다 보낸다 (끝나면 processed == total)
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct VcardImportProgressSignal {
+    pub correlation_id: u64,
+    pub processed: u64,
+    pub total: u64,
+}
@@ -0,0 +1,75 @@
+use rinf::{DartSignal, DartSignalBinary, RustSignal, RustSignalBinary};
+use serde::{Deserialize, Serialize};
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct GenerateKeyRequest {
+    pub key_id: String,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct KeyReadySignal {
+    pub key_id: String,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+// 평문은 바이너리 페이로드로 전달
+#[derive(DartSignalBinary, Serialize, Deserialize, Debug)]
+pub struct EncryptRequest {
+    pub request_id: String,
+    pub key_id: String,
+}
+
+// 암호문은 바이너리 페이로드로 전달
+#[derive(DartSignalBinary, Serialize, Deserialize, Debug)]
+pub struct DecryptRequest {
+    pub request_id: String,
+    pub key_id: String,
+}
+
+// 결과 바이트는 바이너리 페이로드로 반환 (실패 시 비어 있음)
+#[derive(RustSignalBinary, Serialize, Deserialize, Debug)]
+pub struct CryptoResultSignal {
+    pub request_id: String,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct CreateKeyPairRequest {
+    pub key_pair_id: String,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct PublicKeySignal {
+    pub key_pair_id: String,
+    pub public_key: Vec<u8>,
+    pub error: Option<String>,
+}
+
+// 상대방 공개키와 키 합의 후 공유 AES 키를 key_id로 저장
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct DeriveSharedKeyRequest {
+    pub key_pair_id: String,
+    pub peer_public_key: Vec<u8>,
+    pub key_id: String,
+}
+
+// 저장된 비밀 값을 새 데이터 키로 다시 봉인한다 (진행 상황은 EncryptionKeyRotationSignal)
+// 대상은 비밀 저장소의 값(aes_key/, x25519/)뿐이다. 동기화 키 묶음(sync_keyring/)은 패스프레이즈로
+// 감싸므로 ChangeSyncPassphraseRequest로 바꾸고, 영구 저장소의 항목과 첨부 파일은 저장할 때
+// 암호화하지 않으므로 교체하지 않는다. OS 키체인이 없으면(keychain 기능 없이 빌드했거나
+// PlatformCapabilitiesSignal.keychain이 false) 키 묶음과 교체가 메모리에만 있어 재시작하면 사라진다.
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct RotateEncryptionKeyRequest {}
+
+// 값 하나를 다시 봉인할 때마다 보낸다 (finished면 새 키가 활성 키가 됐고 예전 키는 지워졌다)
+// 앱을 다시 켜면 끝나지 않은 교체를 이어 가며 다시 보낸다.
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct EncryptionKeyRotationSignal {
+    pub key_id: String,
+    pub done: u64,
+    pub total: u64,
+    pub finished: bool,
+    pub error: Option<String>,
+}
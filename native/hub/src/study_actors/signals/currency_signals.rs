@@ -0,0 +1,135 @@
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+use super::super::messages::{ArithmeticOp, Conversion, ErrorEnvelope, RoundingRule};
+use super::{AnswerSignal, AskSignal};
+
+// 금액은 정밀도를 잃지 않도록 10진수 문자열로 주고받는다 (예: "1234.50")
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct CalculateMoneyRequest {
+    pub correlation_id: u64,
+    pub currency: String,
+    pub op: ArithmeticOp,
+    pub left: String,
+    pub right: String,
+    pub rounding: Option<RoundingRule>,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct MoneyAmountResponse {
+    pub correlation_id: u64,
+    pub amount: Option<String>,
+    pub error: Option<ErrorEnvelope>,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct ConvertCurrencyRequest {
+    pub correlation_id: u64,
+    pub amount: String,
+    pub from: String,
+    pub to: String,
+    pub rounding: Option<RoundingRule>,
+}
+
+// rate: from 1단위당 to 금액
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct CurrencyConversionResponse {
+    pub correlation_id: u64,
+    pub amount: Option<String>,
+    pub rate: Option<String>,
+    pub rates_fetched_at: Option<i64>,
+    pub error: Option<ErrorEnvelope>,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct FormatMoneyRequest {
+    pub correlation_id: u64,
+    pub amount: String,
+    pub currency: String,
+    pub locale: String,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct FormattedMoneyResponse {
+    pub correlation_id: u64,
+    pub text: Option<String>,
+    pub error: Option<ErrorEnvelope>,
+}
+
+impl AskSignal for CalculateMoneyRequest {
+    type Answer = MoneyAmountResponse;
+
+    fn correlation_id(&self) -> u64 {
+        self.correlation_id
+    }
+}
+
+impl AnswerSignal for MoneyAmountResponse {
+    type Payload = String;
+
+    fn answer(correlation_id: u64, result: Result<String, ErrorEnvelope>) -> Self {
+        let (amount, error) = match result {
+            Ok(amount) => (Some(amount), None),
+            Err(error) => (None, Some(error)),
+        };
+        Self {
+            correlation_id,
+            amount,
+            error,
+        }
+    }
+}
+
+impl AskSignal for ConvertCurrencyRequest {
+    type Answer = CurrencyConversionResponse;
+
+    fn correlation_id(&self) -> u64 {
+        self.correlation_id
+    }
+}
+
+impl AnswerSignal for CurrencyConversionResponse {
+    type Payload = Conversion;
+
+    fn answer(correlation_id: u64, result: Result<Conversion, ErrorEnvelope>) -> Self {
+        match result {
+            Ok(conversion) => Self {
+                correlation_id,
+                amount: Some(conversion.amount.to_string()),
+                rate: Some(conversion.rate.to_string()),
+                rates_fetched_at: Some(conversion.rates_fetched_at),
+                error: None,
+            },
+            Err(error) => Self {
+                correlation_id,
+                amount: None,
+                rate: None,
+                rates_fetched_at: None,
+                error: Some(error),
+            },
+        }
+    }
+}
+
+impl AskSignal for FormatMoneyRequest {
+    type Answer = FormattedMoneyResponse;
+
+    fn correlation_id(&self) -> u64 {
+        self.correlation_id
+    }
+}
+
+impl AnswerSignal for FormattedMoneyResponse {
+    type Payload = String;
+
+    fn answer(correlation_id: u64, result: Result<String, ErrorEnvelope>) -> Self {
+        let (text, error) = match result {
+            Ok(text) => (Some(text), None),
+            Err(error) => (None, Some(error)),
+        };
+        Self {
+            correlation_id,
+            text,
+            error,
+        }
+    }
+}
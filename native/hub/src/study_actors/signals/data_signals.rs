@@ -1,11 +1,36 @@
 use rinf::{DartSignal, RustSignal};
 use serde::{Deserialize, Serialize};
-use super::super::messages::{UserId, DataItem, UserData};
+use super::super::messages::{
+    UserId, DataItem, Collection, TagCount, ItemRevision, MutationKind,
+};
 
 #[derive(DartSignal, Serialize, Deserialize, Debug)]
 pub struct FetchUserDataRequest {
     pub user_id: UserId,
-    pub limit: Option<usize>,
+    pub limit: Option<u64>,
+}
+
+// 서버 목록을 페이지 단위로 받아 배치마다 UserDataResponse를 보낸다
+// 직전 응답의 has_more가 true였으면 이어 붙이고, 아니면 새 목록으로 바꾼다.
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct FetchPagedRemoteRequest {
+    pub user_id: UserId,
+    pub page_size: Option<u64>,
+}
+
+// 이 기기에서 바뀐 항목을 서버에 올린다 (full이면 전부 다시)
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct PushLocalChangesRequest {
+    pub user_id: UserId,
+    pub full: bool,
+}
+
+// pushed: 올린 항목 수 (실패하면 error, 다음 요청에서 같은 항목을 다시 올린다)
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct LocalChangesPushedSignal {
+    pub user_id: UserId,
+    pub pushed: u64,
+    pub error: Option<String>,
 }
 
 #[derive(RustSignal, Serialize, Deserialize, Debug)]
@@ -14,6 +39,8 @@ pub struct UserDataResponse {
     pub items: Vec<DataItem>,
     pub last_updated: u64,
     pub error: Option<String>,
+    pub trace_id: u64,  // GetTraceRequest로 처리 경로 조회
+    pub has_more: bool, // 같은 요청의 다음 배치가 이어서 온다
 }
 
 #[derive(DartSignal, Serialize, Deserialize, Debug)]
@@ -21,6 +48,9 @@ pub struct CreateDataItemRequest {
     pub user_id: UserId,
     pub title: String,
     pub content: String,
+    pub tags: Vec<String>,
+    pub temp_id: Option<String>, // 낙관적 모드: 저장 전에 이 id로 DataChangedSignal을 먼저 보낸다
+    pub idempotency_key: Option<String>, // 재전송이나 두 번 누르기로 같은 키가 다시 오면 무시한다
 }
 
 #[derive(RustSignal, Serialize, Deserialize, Debug)]
@@ -35,6 +65,9 @@ pub struct UpdateDataItemRequest {
     pub item_id: String,
     pub title: Option<String>,
     pub content: Option<String>,
+    pub base_updated_at: Option<u64>, // 수정을 시작한 시점의 updated_at (동기화 충돌 검사용)
+    pub temp_id: Option<String>,      // 낙관적 모드에서 이 수정을 가리키는 임시 id
+    pub idempotency_key: Option<String>,
 }
 
 #[derive(RustSignal, Serialize, Deserialize, Debug)]
@@ -43,10 +76,30 @@ pub struct DataItemUpdatedSignal {
     pub item: DataItem,
 }
 
+// 만료된 값을 먼저 보여 준 항목을 서버에서 다시 받았더니 더 새로웠을 때 (이미 저장됨)
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct DataRefreshedSignal {
+    pub user_id: UserId,
+    pub key: String,
+    pub item: DataItem,
+}
+
+// 다른 기기의 수정과 본문이 겹쳐 자동 병합하지 못했을 때 (수정은 저장하지 않는다)
+// merged: 충돌 표시가 들어간 병합본 (기준 리비전이 기록에 없으면 None)
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct DataItemConflictSignal {
+    pub user_id: UserId,
+    pub current: DataItem,
+    pub incoming_content: String,
+    pub merged: Option<String>,
+}
+
 #[derive(DartSignal, Serialize, Deserialize, Debug)]
 pub struct DeleteDataItemRequest {
     pub user_id: UserId,
     pub item_id: String,
+    pub temp_id: Option<String>, // 낙관적 모드에서 이 삭제를 가리키는 임시 id
+    pub idempotency_key: Option<String>,
 }
 
 #[derive(RustSignal, Serialize, Deserialize, Debug)]
@@ -54,3 +107,152 @@ pub struct DataItemDeletedSignal {
     pub user_id: UserId,
     pub item_id: String,
 }
+
+// 낙관적 변경을 저장하기 전에 바로 되돌려 보내는 예상 결과 (삭제면 item은 None)
+// 생성이면 item_id는 temp_id이고, 수정/삭제면 대상 항목의 id다.
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct DataChangedSignal {
+    pub user_id: UserId,
+    pub temp_id: String,
+    pub kind: MutationKind,
+    pub item_id: String,
+    pub item: Option<DataItem>,
+}
+
+// 낙관적 변경이 저장됐을 때 임시 id를 실제 항목 id에 잇는다 (저장된 항목은 기존 신호로 온다)
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct MutationConfirmedSignal {
+    pub user_id: UserId,
+    pub temp_id: String,
+    pub item_id: String,
+}
+
+// 낙관적 변경을 저장하지 못했을 때 (restored: 되돌릴 이전 항목, 생성이었으면 None이라 지우면 된다)
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct MutationRolledBackSignal {
+    pub user_id: UserId,
+    pub temp_id: String,
+    pub kind: MutationKind,
+    pub restored: Option<DataItem>,
+    pub error: String,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct AddTagRequest {
+    pub user_id: UserId,
+    pub item_id: String,
+    pub tag: String,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct RemoveTagRequest {
+    pub user_id: UserId,
+    pub item_id: String,
+    pub tag: String,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct ItemTagsChangedSignal {
+    pub item_id: String,
+    pub tags: Vec<String>,
+    pub error: Option<String>,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct ListTagsRequest {
+    pub user_id: UserId,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct TagListSignal {
+    pub tags: Vec<TagCount>,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct CreateCollectionRequest {
+    pub user_id: UserId,
+    pub name: String,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct AddItemToCollectionRequest {
+    pub user_id: UserId,
+    pub collection_id: String,
+    pub item_id: String,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct CollectionUpdatedSignal {
+    pub collection: Option<Collection>,
+    pub error: Option<String>,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct ListCollectionsRequest {
+    pub user_id: UserId,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct CollectionListSignal {
+    pub collections: Vec<Collection>,
+}
+
+// limit이 0이면 기본 페이지 크기 사용
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct ItemsByTagRequest {
+    pub request_id: String,
+    pub user_id: UserId,
+    pub tag: String,
+    pub offset: u64,
+    pub limit: u64,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct ItemsByTagSignal {
+    pub request_id: String,
+    pub tag: String,
+    pub items: Vec<DataItem>,
+    pub total: u64,
+    pub next_offset: Option<u64>,
+    pub error: Option<String>,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct GetItemHistoryRequest {
+    pub user_id: UserId,
+    pub item_id: String,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct ItemHistorySignal {
+    pub item_id: String,
+    pub revisions: Vec<ItemRevision>,
+    pub error: Option<String>,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct RevertItemToRevisionRequest {
+    pub user_id: UserId,
+    pub item_id: String,
+    pub revision: u64,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct UndoRequest {
+    pub user_id: UserId,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct RedoRequest {
+    pub user_id: UserId,
+}
+
+// 되돌리기/실행 취소/다시 실행 결과와 이후 가능 여부
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct HistoryActionSignal {
+    pub user_id: UserId,
+    pub item: Option<DataItem>,
+    pub can_undo: bool,
+    pub can_redo: bool,
+    pub error: Option<String>,
+}
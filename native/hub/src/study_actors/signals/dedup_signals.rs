@@ -0,0 +1,34 @@
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+use super::super::messages::{DataItem, DuplicateGroup, UserId};
+
+// 바로 중복 후보를 찾는다 (결과는 DuplicateCandidatesSignal)
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct FindDuplicatesRequest {
+    pub user_id: UserId,
+}
+
+// 요청에 대한 답이거나, 백그라운드 검사에서 후보가 바뀌었을 때 보낸다
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct DuplicateCandidatesSignal {
+    pub user_id: UserId,
+    pub groups: Vec<DuplicateGroup>,
+    pub error: Option<String>,
+}
+
+// 사용자가 고른 항목 하나에 나머지를 합친다
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct MergeItemsRequest {
+    pub user_id: UserId,
+    pub keep_id: String,
+    pub merge_ids: Vec<String>,
+}
+
+// 합친 항목 (지워진 항목마다 DataItemDeletedSignal도 따로 간다)
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct ItemsMergedSignal {
+    pub user_id: UserId,
+    pub item: Option<DataItem>,
+    pub merged_ids: Vec<String>,
+    pub error: Option<String>,
+}
@@ -0,0 +1,15 @@
+use super::super::messages::DiagnosticBundle;
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct CreateDiagnosticBundleRequest {
+    pub path: String,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct DiagnosticBundleSignal {
+    pub path: String,
+    pub bundle: Option<DiagnosticBundle>,
+    pub error: Option<String>,
+}
@@ -0,0 +1,84 @@
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+use super::super::messages::{DiffHunk, ErrorEnvelope, MergeOutcome};
+use super::{AnswerSignal, AskSignal};
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct DiffTextRequest {
+    pub correlation_id: u64,
+    pub old: String,
+    pub new: String,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct TextDiffResponse {
+    pub correlation_id: u64,
+    pub hunks: Vec<DiffHunk>,
+    pub error: Option<ErrorEnvelope>,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct ThreeWayMergeRequest {
+    pub correlation_id: u64,
+    pub base: String,
+    pub ours: String,
+    pub theirs: String,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct ThreeWayMergeResponse {
+    pub correlation_id: u64,
+    pub outcome: Option<MergeOutcome>,
+    pub error: Option<ErrorEnvelope>,
+}
+
+impl AskSignal for DiffTextRequest {
+    type Answer = TextDiffResponse;
+
+    fn correlation_id(&self) -> u64 {
+        self.correlation_id
+    }
+}
+
+impl AnswerSignal for TextDiffResponse {
+    type Payload = Vec<DiffHunk>;
+
+    fn answer(correlation_id: u64, result: Result<Vec<DiffHunk>, ErrorEnvelope>) -> Self {
+        match result {
+            Ok(hunks) => Self {
+                correlation_id,
+                hunks,
+                error: None,
+            },
+            Err(error) => Self {
+                correlation_id,
+                hunks: Vec::new(),
+                error: Some(error),
+            },
+        }
+    }
+}
+
+impl AskSignal for ThreeWayMergeRequest {
+    type Answer = ThreeWayMergeResponse;
+
+    fn correlation_id(&self) -> u64 {
+        self.correlation_id
+    }
+}
+
+impl AnswerSignal for ThreeWayMergeResponse {
+    type Payload = MergeOutcome;
+
+    fn answer(correlation_id: u64, result: Result<MergeOutcome, ErrorEnvelope>) -> Self {
+        let (outcome, error) = match result {
+            Ok(outcome) => (Some(outcome), None),
+            Err(error) => (None, Some(error)),
+        };
+        Self {
+            correlation_id,
+            outcome,
+            error,
+        }
+    }
+}
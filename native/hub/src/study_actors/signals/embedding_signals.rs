@@ -0,0 +1,32 @@
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+use super::super::messages::{SemanticMatch, UserId};
+
+// ml 기능 없이 빌드하면 아래 요청에 응답하는 액터가 없다
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct IndexItemsForSearchRequest {
+    pub user_id: UserId,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct SemanticIndexSignal {
+    pub indexed: u64,
+    pub total: u64,
+    pub error: Option<String>,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct SemanticSearchRequest {
+    pub request_id: String,
+    pub query: String,
+    pub limit: Option<u64>,
+}
+
+// 결과는 점수 순으로 나누어 전송되며 마지막 조각에 done = true
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct SemanticSearchResultSignal {
+    pub request_id: String,
+    pub matches: Vec<SemanticMatch>,
+    pub done: bool,
+    pub error: Option<String>,
+}
@@ -0,0 +1,82 @@
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+use super::super::messages::{ErrorEnvelope, FeedArticle, FeedSubscription};
+use super::{AnswerSignal, AskSignal};
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct SubscribeFeedRequest {
+    pub correlation_id: u64,
+    pub url: String,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct FeedSubscribedResponse {
+    pub correlation_id: u64,
+    pub subscription: Option<FeedSubscription>,
+    pub error: Option<ErrorEnvelope>,
+}
+
+impl AskSignal for SubscribeFeedRequest {
+    type Answer = FeedSubscribedResponse;
+
+    fn correlation_id(&self) -> u64 {
+        self.correlation_id
+    }
+}
+
+impl AnswerSignal for FeedSubscribedResponse {
+    type Payload = FeedSubscription;
+
+    fn answer(correlation_id: u64, result: Result<FeedSubscription, ErrorEnvelope>) -> Self {
+        match result {
+            Ok(subscription) => Self {
+                correlation_id,
+                subscription: Some(subscription),
+                error: None,
+            },
+            Err(error) => Self {
+                correlation_id,
+                subscription: None,
+                error: Some(error),
+            },
+        }
+    }
+}
+
+// 처리 후 FeedSubscriptionsSignal로 남은 구독 목록을 보낸다
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct UnsubscribeFeedRequest {
+    pub subscription_id: String,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct FeedSubscriptionsRequest {}
+
+// 때가 되지 않은 구독까지 지금 확인 (당겨서 새로 고침)
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct RefreshFeedsRequest {}
+
+// 구독이 바뀌거나 확인이 한 바퀴 끝날 때마다 보낸다
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct FeedSubscriptionsSignal {
+    pub subscriptions: Vec<FeedSubscription>,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct FeedArticlesRequest {
+    pub subscription_id: Option<String>,
+    pub limit: u64,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct FeedArticlesSignal {
+    pub subscription_id: Option<String>,
+    pub articles: Vec<FeedArticle>,
+}
+
+// 확인하다 새로 받은 글 (구독 하나씩)
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct NewFeedArticlesSignal {
+    pub subscription_id: String,
+    pub articles: Vec<FeedArticle>,
+}
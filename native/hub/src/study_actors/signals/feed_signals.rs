@@ -0,0 +1,24 @@
+use super::super::messages::FeedEntry;
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct FeedPageRequest {
+    pub user_id: Option<String>,
+    pub offset: u64,
+    pub limit: u64,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct FeedPageSignal {
+    pub user_id: Option<String>,
+    pub offset: u64,
+    pub entries: Vec<FeedEntry>,
+    pub total: u64,
+}
+
+// 새 활동이 들어오거나 기존 줄에 묶였을 때 (같은 id의 줄을 바꿔 맨 위로 올린다)
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct FeedEntrySignal {
+    pub entry: FeedEntry,
+}
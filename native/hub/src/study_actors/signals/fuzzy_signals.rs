@@ -0,0 +1,18 @@
+use super::super::messages::FuzzyHit;
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct FuzzyMatchRequest {
+    pub request_id: String,
+    pub query: String,
+    pub candidates: Vec<String>,
+    pub limit: Option<u64>,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct FuzzyMatchSignal {
+    pub request_id: String,
+    pub hits: Vec<FuzzyHit>,
+    pub error: Option<String>,
+}
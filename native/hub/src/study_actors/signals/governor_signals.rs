@@ -0,0 +1,16 @@
+use super::super::messages::PerformanceLevel;
+use rinf::RustSignal;
+use serde::{Deserialize, Serialize};
+
+// 성능 조절 단계가 바뀔 때마다 보낸다 (무엇을 얼마나 낮췄는지 포함)
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct PerformanceGovernorSignal {
+    pub level: PerformanceLevel,
+    pub reason: String,
+    pub queue_depth: u64, // 블로킹 스레드에 넘겨 아직 끝나지 않은 작업 수
+    pub frame_ms: f64,    // 최근 프랙털 프레임 평균 렌더링 시간
+    pub fractal_size: u32,
+    pub ocr_deskew: bool,
+    pub ocr_crop: bool,
+    pub index_batch_size: u64,
+}
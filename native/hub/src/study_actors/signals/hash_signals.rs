@@ -0,0 +1,34 @@
+use super::super::messages::{HashAlgorithm, HashSource};
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct HashRequest {
+    pub request_id: String,
+    pub algo: HashAlgorithm,
+    pub source: HashSource,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct HashResultSignal {
+    pub request_id: String,
+    pub algo: HashAlgorithm,
+    pub hex_digest: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct VerifyHashRequest {
+    pub request_id: String,
+    pub algo: HashAlgorithm,
+    pub source: HashSource,
+    pub expected_hex: String,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct HashVerifiedSignal {
+    pub request_id: String,
+    pub matches: bool,
+    pub actual_hex: Option<String>,
+    pub error: Option<String>,
+}
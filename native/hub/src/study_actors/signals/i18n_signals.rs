@@ -0,0 +1,32 @@
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct LoadLocaleBundleRequest {
+    pub locale: String,
+    pub path: Option<String>,
+    pub url: Option<String>,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct LocaleBundleLoadedSignal {
+    pub locale: String,
+    pub success: bool,
+    pub key_count: u64,
+    pub error: Option<String>,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct TranslateRequest {
+    pub key: String,
+    pub args: HashMap<String, String>,
+    pub locale: Option<String>,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct TranslationResponse {
+    pub key: String,
+    pub text: String,
+    pub resolved_locale: Option<String>,
+}
@@ -0,0 +1,45 @@
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+use super::super::messages::{ErrorEnvelope, IdKind};
+use super::{AnswerSignal, AskSignal};
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct GenerateIdsRequest {
+    pub correlation_id: u64,
+    pub count: u32,
+    pub kind: IdKind,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct GeneratedIdsResponse {
+    pub correlation_id: u64,
+    pub ids: Vec<String>,
+    pub error: Option<ErrorEnvelope>,
+}
+
+impl AskSignal for GenerateIdsRequest {
+    type Answer = GeneratedIdsResponse;
+
+    fn correlation_id(&self) -> u64 {
+        self.correlation_id
+    }
+}
+
+impl AnswerSignal for GeneratedIdsResponse {
+    type Payload = Vec<String>;
+
+    fn answer(correlation_id: u64, result: Result<Vec<String>, ErrorEnvelope>) -> Self {
+        match result {
+            Ok(ids) => Self {
+                correlation_id,
+                ids,
+                error: None,
+            },
+            Err(error) => Self {
+                correlation_id,
+                ids: Vec::new(),
+                error: Some(error),
+            },
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use messages::{
+    actor::Actor,
+    prelude::{Address, Handler, Notifiable},
+};
+use rinf::{DartSignal, DartSignalBinary};
+
+use crate::study_actors::actors::{Prioritized, Priority, PriorityMailbox, Timed};
+
+// Dart 신호 수신의 공통 입구
+// headless 기능을 켜면 Flutter 없이 headless::inject로 넣은 신호도 같은 수신기로 받는다.
+pub trait ReceiveSignal: Sized {
+    fn signal_receiver() -> SignalInbox<Self>;
+}
+
+pub trait ReceiveBinarySignal: Sized {
+    fn binary_signal_receiver() -> SignalInbox<Self>;
+}
+
+#[cfg(not(feature = "headless"))]
+pub type SignalInbox<T> = rinf::SignalReceiver<rinf::DartSignalPack<T>>;
+
+#[cfg(feature = "headless")]
+pub use crate::study_actors::headless::HeadlessReceiver as SignalInbox;
+
+impl<T: DartSignal + Send + 'static> ReceiveSignal for T {
+    fn signal_receiver() -> SignalInbox<Self> {
+        #[cfg(feature = "headless")]
+        {
+            crate::study_actors::headless::attach(T::get_dart_signal_receiver())
+        }
+        #[cfg(not(feature = "headless"))]
+        {
+            T::get_dart_signal_receiver()
+        }
+    }
+}
+
+impl<T: DartSignalBinary + Send + 'static> ReceiveBinarySignal for T {
+    fn binary_signal_receiver() -> SignalInbox<Self> {
+        #[cfg(feature = "headless")]
+        {
+            crate::study_actors::headless::attach(T::get_dart_signal_receiver())
+        }
+        #[cfg(not(feature = "headless"))]
+        {
+            T::get_dart_signal_receiver()
+        }
+    }
+}
+
+// Dart 신호를 그대로 액터에 넘기는 수신 루프 (route_dart_signals!가 신호마다 하나씩 띄운다)
+pub async fn forward_dart_signal<S, A>(mut address: Address<A>)
+where
+    S: ReceiveSignal + Send + 'static,
+    A: Actor + Notifiable<S>,
+{
+    let receiver = S::signal_receiver();
+    while let Some(signal_pack) = receiver.recv().await {
+        if address.notify(signal_pack.message).await.is_err() {
+            // 액터가 멈췄으면 더 받을 이유가 없다
+            break;
+        }
+    }
+}
+
+// 우선순위 메일박스의 한 갈래로 넘기는 수신 루프
+// 갈래에서 기다린 시간도 처리 지연에 들어가도록 Timed로 감싸 보낸다
+// (액터는 instrument!와 prioritize!로 Timed<신호>를 받아야 한다).
+pub async fn forward_dart_signal_to_lane<S, A>(lanes: PriorityMailbox<A>, priority: Priority)
+where
+    S: ReceiveSignal + Send + 'static,
+    A: Actor + Handler<Prioritized<Timed<S>>>,
+    <A as Handler<Prioritized<Timed<S>>>>::Result: Send + Sync,
+{
+    let receiver = S::signal_receiver();
+    while let Some(signal_pack) = receiver.recv().await {
+        if lanes
+            .notify(priority, Timed::new(signal_pack.message))
+            .await
+            .is_err()
+        {
+            break;
+        }
+    }
+}
+
+// 신호마다 수신 루프를 띄워 액터의 Notifiable<신호> 구현으로 전달하고, 루프를 담은 JoinSet을 돌려준다
+// 예: let mut owned_tasks = route_dart_signals!(self_addr, [LoginRequest, LogoutRequest]);
+// 우선순위 메일박스에는 신호마다 갈래를 붙여 넘긴다.
+// 예: route_dart_signals!(lanes, [FetchUserDataRequest => Priority::High, PushLocalChangesRequest => Priority::Low]);
+// 바이너리 신호처럼 전달 방식이 다른 경우에는 루프를 직접 작성한다.
+macro_rules! route_dart_signals {
+    ($lanes:expr, [$($signal:ty => $priority:expr),+ $(,)?]) => {{
+        let mut tasks = ::tokio::task::JoinSet::new();
+        $(
+            tasks.spawn($crate::study_actors::signals::forward_dart_signal_to_lane::<$signal, _>(
+                $lanes.clone(),
+                $priority,
+            ));
+        )+
+        tasks
+    }};
+    ($address:expr, [$($signal:ty),+ $(,)?]) => {{
+        let mut tasks = ::tokio::task::JoinSet::new();
+        $(
+            tasks.spawn($crate::study_actors::signals::forward_dart_signal::<$signal, _>(
+                $address.clone(),
+            ));
+        )+
+        tasks
+    }};
+}
+pub(crate) use route_dart_signals;
@@ -0,0 +1,36 @@
+use super::super::messages::InboxNotification;
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct ListNotificationsRequest {
+    pub offset: u64,
+    pub limit: u64,
+    pub unread_only: bool,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct NotificationListSignal {
+    pub notifications: Vec<InboxNotification>,
+    pub total: u64,
+    pub unread: u64,
+}
+
+// 알림함에 새 알림이 들어오거나 같은 id의 알림이 바뀌었을 때
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct NotificationReceivedSignal {
+    pub notification: InboxNotification,
+    pub unread: u64,
+}
+
+// ids가 비어 있으면 모두 읽음으로 표시
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct MarkReadRequest {
+    pub ids: Vec<String>,
+}
+
+// 안 읽은 알림 수가 바뀔 때마다 (앱 아이콘 배지용)
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct InboxBadgeSignal {
+    pub unread: u64,
+}
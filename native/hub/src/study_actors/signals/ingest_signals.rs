@@ -0,0 +1,23 @@
+use rinf::{DartSignalBinary, RustSignal};
+use serde::{Deserialize, Serialize};
+use super::super::messages::{DataItem, ErrorEnvelope, SharedContentKind, UserId};
+
+// 공유 시트로 받은 내용 (메모리에 있는 파일 내용은 바이너리 페이로드로 전달)
+#[derive(DartSignalBinary, Serialize, Deserialize, Debug)]
+pub struct IngestSharedContentRequest {
+    pub correlation_id: u64,
+    pub user_id: UserId,
+    pub mime: Option<String>,
+    pub path: Option<String>,
+    pub file_name: Option<String>,
+    pub text: Option<String>,
+}
+
+// 만든 항목 또는 실패 이유 (한도를 넘었으면 error.code가 quota_exceeded)
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct SharedContentIngestedSignal {
+    pub correlation_id: u64,
+    pub kind: Option<SharedContentKind>,
+    pub item: Option<DataItem>,
+    pub error: Option<ErrorEnvelope>,
+}
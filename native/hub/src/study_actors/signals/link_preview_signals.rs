@@ -0,0 +1,60 @@
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+use super::super::messages::{ErrorEnvelope, LinkPreview};
+use super::{AnswerSignal, AskSignal};
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct LinkPreviewRequest {
+    pub correlation_id: u64,
+    pub url: String,
+}
+
+// 링크 카드 내용 (가져오지 못했으면 error만 채워진다)
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct LinkPreviewSignal {
+    pub correlation_id: u64,
+    pub url: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub image_url: Option<String>,
+    pub site_name: Option<String>,
+    pub favicon_url: Option<String>,
+    pub error: Option<ErrorEnvelope>,
+}
+
+impl AskSignal for LinkPreviewRequest {
+    type Answer = LinkPreviewSignal;
+
+    fn correlation_id(&self) -> u64 {
+        self.correlation_id
+    }
+}
+
+impl AnswerSignal for LinkPreviewSignal {
+    type Payload = LinkPreview;
+
+    fn answer(correlation_id: u64, result: Result<LinkPreview, ErrorEnvelope>) -> Self {
+        match result {
+            Ok(preview) => Self {
+                correlation_id,
+                url: preview.url,
+                title: preview.title,
+                description: preview.description,
+                image_url: preview.image_url,
+                site_name: preview.site_name,
+                favicon_url: preview.favicon_url,
+                error: None,
+            },
+            Err(error) => Self {
+                correlation_id,
+                url: String::new(),
+                title: None,
+                description: None,
+                image_url: None,
+                site_name: None,
+                favicon_url: None,
+                error: Some(error),
+            },
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+use super::super::messages::{ErrorEnvelope, MailStatus};
+use super::{AnswerSignal, AskSignal};
+
+// context_json: 템플릿에 넘길 JSON 값 (보통 객체)
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct SendMailRequest {
+    pub correlation_id: u64,
+    pub to: Vec<String>,
+    pub reply_to: Option<String>,
+    pub subject: String,
+    pub text_template: String,
+    pub html_template: Option<String>,
+    pub context_json: String,
+}
+
+// 대기열에 넣었으면 mail_id가 오고, 이후 진행은 MailStatusSignal로 알린다
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct MailQueuedResponse {
+    pub correlation_id: u64,
+    pub mail_id: Option<String>,
+    pub error: Option<ErrorEnvelope>,
+}
+
+impl AskSignal for SendMailRequest {
+    type Answer = MailQueuedResponse;
+
+    fn correlation_id(&self) -> u64 {
+        self.correlation_id
+    }
+}
+
+impl AnswerSignal for MailQueuedResponse {
+    type Payload = String;
+
+    fn answer(correlation_id: u64, result: Result<String, ErrorEnvelope>) -> Self {
+        match result {
+            Ok(mail_id) => Self {
+                correlation_id,
+                mail_id: Some(mail_id),
+                error: None,
+            },
+            Err(error) => Self {
+                correlation_id,
+                mail_id: None,
+                error: Some(error),
+            },
+        }
+    }
+}
+
+// 메일 발송 진행 상황
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct MailStatusSignal {
+    pub mail_id: String,
+    pub status: MailStatus,
+    pub attempts: u32,
+    pub error: Option<String>,        // 마지막 실패 이유
+    pub next_attempt_at: Option<i64>, // Retrying일 때 다음 시도 시각 (유닉스 밀리초)
+}
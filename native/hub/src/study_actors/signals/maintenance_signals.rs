@@ -0,0 +1,13 @@
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+use super::super::messages::MaintenanceReport;
+
+// 설정 화면의 "저장소 점검" (결과는 MaintenanceReportSignal)
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct RunMaintenanceRequest {}
+
+// 요청에 대한 답이거나, 주기 점검이 끝났을 때 보낸다
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct MaintenanceReportSignal {
+    pub report: MaintenanceReport,
+}
@@ -0,0 +1,18 @@
+use super::super::messages::RenderMode;
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct RenderMarkdownRequest {
+    pub request_id: String,
+    pub markdown: String,
+    pub mode: RenderMode,
+    pub summary_max_chars: Option<u64>,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct RenderedHtmlSignal {
+    pub request_id: String,
+    pub mode: RenderMode,
+    pub output: String,
+}
@@ -0,0 +1,35 @@
+use super::super::messages::MediaEntry;
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct GetMediaRequest {
+    pub url: String,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct PinMediaRequest {
+    pub url: String,
+    pub pinned: bool,
+}
+
+// GetMediaRequest와 PinMediaRequest의 결과
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct MediaReadySignal {
+    pub url: String,
+    pub local_path: Option<String>,
+    pub entry: Option<MediaEntry>,
+    pub error: Option<String>,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct MediaCacheStatusRequest {}
+
+// 고정 항목도 사용량에 포함된다 (고정 항목만으로 한도를 넘을 수 있다)
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct MediaCacheStatusSignal {
+    pub used_bytes: u64,
+    pub pinned_bytes: u64,
+    pub quota_bytes: u64,
+    pub items: u64,
+}
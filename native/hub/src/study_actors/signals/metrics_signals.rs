@@ -0,0 +1,24 @@
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+use super::super::messages::{LatencyStat, TraceHop};
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct LatencyStatsRequest {}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct LatencyStatsSignal {
+    pub stats: Vec<LatencyStat>,
+}
+
+// 응답 신호에 실린 trace_id로 액터 간 메시지 흐름을 조회
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct GetTraceRequest {
+    pub trace_id: u64,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct TraceTimelineSignal {
+    pub trace_id: u64,
+    pub found: bool, // 보관 기간(최근 256개)이 지났으면 false
+    pub hops: Vec<TraceHop>,
+}
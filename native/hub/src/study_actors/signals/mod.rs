@@ -2,8 +2,153 @@ mod auth_signals;
 mod user_signals;
 mod data_signals;
 mod app_signals;
+mod config_signals;
+mod i18n_signals;
+mod notification_signals;
+mod archive_signals;
+mod hash_signals;
+mod crypto_signals;
+mod tabular_signals;
+mod markdown_signals;
+// 기능이나 타깃 때문에 응답할 액터가 없어도 Dart 쪽 신호 목록은 같게 둔다
+#[cfg_attr(target_family = "wasm", allow(dead_code))]
+mod mqtt_signals;
+mod sensitive_signals;
+mod time_signals;
+#[cfg_attr(target_family = "wasm", allow(dead_code))]
+mod audio_signals;
+mod realtime_signals;
+mod chat_signals;
+mod presence_signals;
+mod privacy_signals;
+mod router_signals;
+mod ocr_signals;
+mod ranking_signals;
+#[cfg_attr(not(feature = "ml"), allow(dead_code))]
+mod embedding_signals;
+mod automation_signals;
+mod attachment_signals;
+mod collab_signals;
+mod metrics_signals;
+mod network_signals;
+mod platform_signals;
+mod validation_signals;
+mod currency_signals;
+mod recurrence_signals;
+mod diff_signals;
+mod text_analysis_signals;
+mod template_signals;
+mod id_signals;
+mod sync_crypto_signals;
+#[cfg_attr(any(target_family = "wasm", target_os = "android", target_os = "ios"), allow(dead_code))]
+mod background_signals;
+mod scheduler_signals;
+mod governor_signals;
+mod snapshot_signals;
+mod media_signals;
+mod fuzzy_signals;
+mod search_signals;
+mod inbox_signals;
+mod feed_signals;
+mod diagnostics_signals;
+mod admin_signals;
+#[cfg_attr(not(all(feature = "scripting", not(target_family = "wasm"))), allow(dead_code))]
+mod script_signals;
+#[cfg_attr(not(all(feature = "wasm-rules", not(target_family = "wasm"))), allow(dead_code))]
+mod rules_signals;
+mod quota_signals;
+mod billing_signals;
+mod mail_signals;
+mod ingest_signals;
+mod link_preview_signals;
+mod feed_reader_signals;
+mod calendar_signals;
+mod contact_signals;
+mod dedup_signals;
+mod storage_usage_signals;
+mod maintenance_signals;
+#[cfg_attr(not(all(feature = "command-channel", not(any(target_family = "wasm", target_os = "android", target_os = "ios")))), allow(dead_code))]
+mod command_channel_signals;
+mod outbox;
+mod inbox;
+mod ask;
 
 pub use auth_signals::*;
 pub use user_signals::*;
 pub use data_signals::*;
 pub use app_signals::*;
+pub use config_signals::*;
+pub use i18n_signals::*;
+pub use notification_signals::*;
+pub use archive_signals::*;
+pub use hash_signals::*;
+pub use crypto_signals::*;
+pub use tabular_signals::*;
+pub use markdown_signals::*;
+#[cfg_attr(target_family = "wasm", allow(unused_imports))]
+pub use mqtt_signals::*;
+pub use sensitive_signals::*;
+pub use time_signals::*;
+#[cfg_attr(target_family = "wasm", allow(unused_imports))]
+pub use audio_signals::*;
+pub use realtime_signals::*;
+pub use chat_signals::*;
+pub use presence_signals::*;
+pub use privacy_signals::*;
+pub use router_signals::*;
+pub use ocr_signals::*;
+pub use ranking_signals::*;
+#[cfg_attr(not(feature = "ml"), allow(unused_imports))]
+pub use embedding_signals::*;
+pub use automation_signals::*;
+pub use attachment_signals::*;
+pub use collab_signals::*;
+pub use metrics_signals::*;
+pub use network_signals::*;
+pub use platform_signals::*;
+pub use validation_signals::*;
+pub use currency_signals::*;
+pub use recurrence_signals::*;
+pub use diff_signals::*;
+pub use text_analysis_signals::*;
+pub use template_signals::*;
+pub use id_signals::*;
+pub use sync_crypto_signals::*;
+#[cfg_attr(any(target_family = "wasm", target_os = "android", target_os = "ios"), allow(unused_imports))]
+pub use background_signals::*;
+pub use scheduler_signals::*;
+pub use governor_signals::*;
+pub use snapshot_signals::*;
+pub use media_signals::*;
+pub use fuzzy_signals::*;
+pub use search_signals::*;
+pub use inbox_signals::*;
+pub use feed_signals::*;
+pub use diagnostics_signals::*;
+pub use admin_signals::*;
+#[cfg_attr(not(all(feature = "scripting", not(target_family = "wasm"))), allow(unused_imports))]
+pub use script_signals::*;
+#[cfg_attr(not(all(feature = "wasm-rules", not(target_family = "wasm"))), allow(unused_imports))]
+pub use rules_signals::*;
+pub use quota_signals::*;
+pub use billing_signals::*;
+pub use mail_signals::*;
+pub use ingest_signals::*;
+pub use link_preview_signals::*;
+pub use feed_reader_signals::*;
+pub use calendar_signals::*;
+pub use contact_signals::*;
+pub use dedup_signals::*;
+pub use storage_usage_signals::*;
+pub use maintenance_signals::*;
+#[cfg_attr(not(all(feature = "command-channel", not(any(target_family = "wasm", target_os = "android", target_os = "ios")))), allow(unused_imports))]
+pub use command_channel_signals::*;
+pub use outbox::{EmitBinarySignal, EmitSignal, recorded_signals, set_signal_recording};
+#[cfg(any(test, feature = "headless"))]
+pub use outbox::CapturedSignal;
+pub use inbox::{
+    ReceiveBinarySignal, ReceiveSignal, forward_dart_signal, forward_dart_signal_to_lane,
+};
+pub(crate) use inbox::route_dart_signals;
+pub use ask::{AnswerSignal, AskSignal, respond_to_dart};
+pub(crate) use ask::panic_message;
@@ -0,0 +1,48 @@
+use super::super::messages::MqttQos;
+use rinf::{DartSignal, DartSignalBinary, RustSignal};
+use serde::{Deserialize, Serialize};
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct MqttConnectRequest {
+    pub host: String,
+    pub port: u16,
+    pub client_id: String,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub keep_alive_secs: Option<u64>,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct MqttDisconnectRequest {}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct MqttSubscribeRequest {
+    pub topic: String,
+    pub qos: MqttQos,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct MqttUnsubscribeRequest {
+    pub topic: String,
+}
+
+// 페이로드는 바이너리로 전달
+#[derive(DartSignalBinary, Serialize, Deserialize, Debug)]
+pub struct MqttPublishRequest {
+    pub topic: String,
+    pub qos: MqttQos,
+    pub retain: bool,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct MqttMessageSignal {
+    pub topic: String,
+    pub payload: Vec<u8>,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct MqttConnectionSignal {
+    pub connected: bool,
+    pub session_present: bool,
+    pub error: Option<String>,
+}
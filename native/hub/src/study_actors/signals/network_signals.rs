@@ -0,0 +1,87 @@
+use super::super::messages::{
+    BandwidthUsage, CircuitState, ConnectionDiagnostics, MediaQuality, SpeedTestPhase,
+};
+use rinf::{DartSignal, RustSignal, SignalPiece};
+use serde::{Deserialize, Serialize};
+
+// 스플래시 화면 동안 미리 연결해 둘 API 호스트 (호스트 이름 또는 URL)
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct PrefetchHostsRequest {
+    pub hosts: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, SignalPiece)]
+pub struct HostWarmup {
+    pub host: String,
+    pub dns_ms: Option<u64>,     // DNS 조회 시간
+    pub connect_ms: Option<u64>, // TCP/TLS 연결 및 첫 응답까지 걸린 시간
+    pub error: Option<String>,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct PrefetchHostsStatus {
+    pub hosts: Vec<HostWarmup>,
+    pub elapsed_ms: u64,
+}
+
+// 데이터 절약 모드 (종량제 연결에서만 적용되며 썸네일, 분석, 미리 연결 등 비필수 트래픽을 미룬다)
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct SetDataSaverRequest {
+    pub enabled: bool,
+}
+
+// 분류별 사용량 조회 (reset이면 보낸 뒤 0부터 다시 센다)
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct BandwidthUsageRequest {
+    pub reset: bool,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct BandwidthUsageSignal {
+    pub usage: Vec<BandwidthUsage>,
+    pub since: u64, // 집계 시작 시각 (유닉스 초)
+    pub data_saver: bool,
+    pub metered: bool,
+    pub connections: Vec<ConnectionDiagnostics>,
+    pub force_http1: bool,
+}
+
+// HTTP/1.1만 쓰도록 공유 클라이언트를 다시 만든다 (연결 진단도 새로 센다)
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct ForceHttp1Request {
+    pub enabled: bool,
+}
+
+// 서버 호스트의 차단기 상태 변화 (Open이면 UI가 제한 모드 배너를 띄운다)
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct CircuitStateChangedSignal {
+    pub host: String,
+    pub state: CircuitState,
+    pub retry_in_ms: Option<u64>, // Open일 때 다음 확인 요청까지 남은 시간
+}
+
+// 지연 시간과 처리량 측정 (url이 없으면 설정의 speed_test_url을 쓴다)
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct RunSpeedTestRequest {
+    pub url: Option<String>,
+}
+
+// 단계마다 여러 번 알린다 (mbps는 지금까지 보낸/받은 양 기준)
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct SpeedTestProgressSignal {
+    pub phase: SpeedTestPhase,
+    pub progress: f64, // 이 단계의 진행률 (0.0 ~ 1.0)
+    pub latency_ms: Option<u64>,
+    pub mbps: Option<f64>,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct SpeedTestResultSignal {
+    pub latency_ms: Option<u64>, // 왕복 시간 중앙값
+    pub jitter_ms: Option<u64>,  // 연속한 왕복 시간 차이의 평균
+    pub download_mbps: Option<f64>,
+    pub upload_mbps: Option<f64>,
+    pub quality: MediaQuality,
+    pub elapsed_ms: u64,
+    pub error: Option<String>, // 중간에 실패하면 그 전까지 잰 값만 채운다
+}
@@ -0,0 +1,41 @@
+use super::super::messages::{PushPlatform, UserId};
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct RegisterPushTokenRequest {
+    pub user_id: Option<UserId>,
+    pub platform: PushPlatform,
+    pub token: String,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct PushTokenRegisteredSignal {
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct PushPayloadReceived {
+    pub payload_json: String,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct ScheduleLocalNotificationRequest {
+    pub id: String,
+    pub title: String,
+    pub body: String,
+    pub fire_at: u64,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct CancelLocalNotificationRequest {
+    pub id: String,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct LocalNotificationDueSignal {
+    pub id: String,
+    pub title: String,
+    pub body: String,
+}
@@ -0,0 +1,26 @@
+use rinf::{DartSignalBinary, RustSignalBinary};
+use serde::{Deserialize, Serialize};
+use super::super::messages::{FrameFormat, OcrPrepOptions, QuadPoint};
+
+// 카메라 프레임 픽셀은 바이너리 페이로드로 전달
+#[derive(DartSignalBinary, Serialize, Deserialize, Debug)]
+pub struct OcrFrameRequest {
+    pub request_id: String,
+    pub width: u32,
+    pub height: u32,
+    pub row_stride: u32,
+    pub format: FrameFormat,
+    pub options: Option<OcrPrepOptions>,
+}
+
+// 정리된 그레이스케일 이미지는 바이너리 페이로드로 전달
+#[derive(RustSignalBinary, Serialize, Deserialize, Debug)]
+pub struct OcrFrameResultSignal {
+    pub request_id: String,
+    pub width: u32,
+    pub height: u32,
+    pub skew_degrees: f32,
+    pub quad: Option<Vec<QuadPoint>>,
+    pub dropped_frames: u32, // 처리 중에 도착해 건너뛴 프레임 수
+    pub error: Option<String>,
+}
@@ -0,0 +1,133 @@
+use chrono::Utc;
+use rinf::{RustSignal, RustSignalBinary};
+use serde::Serialize;
+#[cfg(any(test, feature = "headless"))]
+use serde::de::DeserializeOwned;
+use std::{
+    collections::VecDeque,
+    sync::{
+        Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+// 진단 묶음에 담을 최근 신호 수
+const MAX_RECORDED_SIGNALS: usize = 500;
+
+// 관리 명령으로 잠시 켜는 신호 기록 (AdminActor)
+static RECORDING: AtomicBool = AtomicBool::new(false);
+static RECORDED: Mutex<VecDeque<RecordedSignal>> = Mutex::new(VecDeque::new());
+
+// Dart로 보내는 신호의 공통 출구
+// 테스트 하네스나 headless 구독자가 있으면 FFI 대신 그쪽으로 보낸다.
+pub trait EmitSignal {
+    fn emit(self);
+}
+
+impl<T: RustSignal + Serialize> EmitSignal for T {
+    fn emit(self) {
+        if RECORDING.load(Ordering::Relaxed) {
+            record_signal(&self);
+        }
+        #[cfg(test)]
+        if crate::study_actors::test_support::capture(&self, &[]) {
+            return;
+        }
+        #[cfg(feature = "headless")]
+        if crate::study_actors::headless::capture(&self, &[]) {
+            return;
+        }
+        self.send_signal_to_dart();
+    }
+}
+
+// 바이너리 페이로드를 함께 보내는 신호의 출구
+// 페이로드에는 비밀 값이나 원본 미디어가 실리므로 진단용 신호 기록에는 남기지 않는다.
+pub trait EmitBinarySignal {
+    fn emit_binary(self, binary: Vec<u8>);
+}
+
+impl<T: RustSignalBinary + Serialize> EmitBinarySignal for T {
+    fn emit_binary(self, binary: Vec<u8>) {
+        #[cfg(test)]
+        if crate::study_actors::test_support::capture(&self, &binary) {
+            return;
+        }
+        #[cfg(feature = "headless")]
+        if crate::study_actors::headless::capture(&self, &binary) {
+            return;
+        }
+        self.send_signal_to_dart(binary);
+    }
+}
+
+// 기록해 둔 Dart 신호
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordedSignal {
+    pub at_ms: i64,
+    pub name: &'static str,
+    pub value: serde_json::Value,
+}
+
+// 꺼도 기록해 둔 신호는 남아 이후에 만드는 진단 묶음에 담긴다
+pub fn set_signal_recording(enabled: bool) {
+    RECORDING.store(enabled, Ordering::Relaxed);
+}
+
+// 오래된 것부터
+pub fn recorded_signals() -> Vec<RecordedSignal> {
+    RECORDED
+        .lock()
+        .map(|recorded| recorded.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+fn record_signal<T: Serialize>(signal: &T) {
+    let entry = RecordedSignal {
+        at_ms: Utc::now().timestamp_millis(),
+        name: short_type_name::<T>(),
+        value: serde_json::to_value(signal).unwrap_or_default(),
+    };
+    if let Ok(mut recorded) = RECORDED.lock() {
+        if recorded.len() == MAX_RECORDED_SIGNALS {
+            recorded.pop_front();
+        }
+        recorded.push_back(entry);
+    }
+}
+
+// 가로챈 Dart 신호 (타입 이름과 JSON 값, 바이너리 신호면 페이로드)
+#[cfg(any(test, feature = "headless"))]
+#[derive(Debug, Clone)]
+pub struct CapturedSignal {
+    pub name: &'static str,
+    pub value: serde_json::Value,
+    pub binary: Vec<u8>,
+}
+
+#[cfg(any(test, feature = "headless"))]
+impl CapturedSignal {
+    pub fn new<T: Serialize>(signal: &T, binary: &[u8]) -> Self {
+        Self {
+            name: short_type_name::<T>(),
+            value: serde_json::to_value(signal).unwrap_or_default(),
+            binary: binary.to_vec(),
+        }
+    }
+
+    pub fn is<T>(&self) -> bool {
+        self.name == short_type_name::<T>()
+    }
+
+    pub fn decode<T: DeserializeOwned>(&self) -> Option<T> {
+        if !self.is::<T>() {
+            return None;
+        }
+        serde_json::from_value(self.value.clone()).ok()
+    }
+}
+
+fn short_type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
@@ -0,0 +1,12 @@
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+use super::super::messages::PlatformCapabilities;
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct PlatformCapabilitiesRequest {}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct PlatformCapabilitiesSignal {
+    pub os: String, // std::env::consts::OS (웹은 "web")
+    pub capabilities: PlatformCapabilities,
+}
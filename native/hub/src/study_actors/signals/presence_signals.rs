@@ -0,0 +1,20 @@
+use super::super::messages::{PresenceState, UserId};
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+
+// 로그인 후 내 ID와 상태를 추적할 연락처 목록 등록 (다시 보내면 교체)
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct StartPresenceRequest {
+    pub user_id: UserId,
+    pub contacts: Vec<UserId>,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct StopPresenceRequest {}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct PresenceChangedSignal {
+    pub user_id: UserId,
+    pub state: PresenceState,
+    pub last_seen: u64,
+}
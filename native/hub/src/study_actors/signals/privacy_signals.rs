@@ -0,0 +1,44 @@
+use super::super::messages::{ConsentCategory, WipeOrigin};
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct SetConsentRequest {
+    pub category: ConsentCategory,
+    pub granted: bool,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct GetConsentRequest {}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct ConsentStatusSignal {
+    pub analytics: bool,
+    pub crash_reports: bool,
+    pub personalization: bool,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct DeleteAllUserDataRequest {}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct UserDataDeletedSignal {
+    pub success: bool,
+    pub wiped: Vec<String>,
+    pub errors: Vec<String>,
+}
+
+// 이 기기의 세션, 데이터, 비밀 값을 모두 지운다 (기기 인증 해제)
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct RemoteWipeRequest {
+    pub reason: Option<String>,
+}
+
+// 원격 삭제 완료 (서버가 요청한 경우에도 보낸다)
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct RemoteWipeCompletedSignal {
+    pub origin: WipeOrigin,
+    pub success: bool,
+    pub wiped: Vec<String>,
+    pub errors: Vec<String>,
+}
@@ -0,0 +1,25 @@
+use super::super::messages::{PlanLimits, QuotaMetric, QuotaUsage, UserId};
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct QuotaStatusRequest {
+    pub user_id: UserId,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct QuotaStatusSignal {
+    pub user_id: UserId,
+    pub usage: QuotaUsage,
+    pub limits: Option<PlanLimits>, // 아직 서버에서 받지 못했으면 None
+}
+
+// 사용량이 quota.warning_ratio를 넘으면 기간마다 한 번, 한도 때문에 거부할 때마다 보낸다
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct QuotaWarningSignal {
+    pub user_id: UserId,
+    pub metric: QuotaMetric,
+    pub used: u64,
+    pub limit: u64,
+    pub exceeded: bool, // 요청한 작업을 거부했다
+}
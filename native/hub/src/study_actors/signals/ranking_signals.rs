@@ -0,0 +1,48 @@
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+use super::super::messages::{QuerySuggestion, RankedItem, UserId};
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct ItemAccessedRequest {
+    pub item_id: String,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct GetRecommendedItemsRequest {
+    pub request_id: String,
+    pub user_id: UserId,
+    pub query: Option<String>,
+    pub limit: Option<u64>,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct RecommendedItemsSignal {
+    pub request_id: String,
+    pub items: Vec<RankedItem>,
+    pub error: Option<String>,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct SearchSubmittedRequest {
+    pub user_id: UserId,
+    pub query: String,
+}
+
+// 입력할 때마다 보내도 된다 (Rust에서 잠시 기다렸다가 마지막 요청에만 답한다)
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct SearchSuggestionsRequest {
+    pub user_id: UserId,
+    pub prefix: String,
+    pub limit: Option<u64>,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct SearchSuggestionsSignal {
+    pub prefix: String,
+    pub suggestions: Vec<QuerySuggestion>,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct ClearSearchHistoryRequest {
+    pub user_id: UserId,
+}
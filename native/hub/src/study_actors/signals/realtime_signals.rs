@@ -0,0 +1,17 @@
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+
+// url이 없으면 설정의 realtime.url 사용
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct ConnectRealtimeRequest {
+    pub url: Option<String>,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct DisconnectRealtimeRequest {}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct RealtimeConnectionSignal {
+    pub connected: bool,
+    pub error: Option<String>,
+}
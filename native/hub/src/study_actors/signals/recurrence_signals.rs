@@ -0,0 +1,50 @@
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+use super::super::messages::{ErrorEnvelope, Occurrence};
+use super::{AnswerSignal, AskSignal};
+
+// 필드 의미는 ExpandRecurrence와 같다
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct ExpandRecurrenceRequest {
+    pub correlation_id: u64,
+    pub rrule: String,
+    pub dtstart: i64,
+    pub timezone: String,
+    pub window_start: i64,
+    pub window_end: i64,
+    pub exdates: Vec<i64>,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct RecurrenceExpansionResponse {
+    pub correlation_id: u64,
+    pub occurrences: Vec<Occurrence>,
+    pub error: Option<ErrorEnvelope>,
+}
+
+impl AskSignal for ExpandRecurrenceRequest {
+    type Answer = RecurrenceExpansionResponse;
+
+    fn correlation_id(&self) -> u64 {
+        self.correlation_id
+    }
+}
+
+impl AnswerSignal for RecurrenceExpansionResponse {
+    type Payload = Vec<Occurrence>;
+
+    fn answer(correlation_id: u64, result: Result<Vec<Occurrence>, ErrorEnvelope>) -> Self {
+        match result {
+            Ok(occurrences) => Self {
+                correlation_id,
+                occurrences,
+                error: None,
+            },
+            Err(error) => Self {
+                correlation_id,
+                occurrences: Vec::new(),
+                error: Some(error),
+            },
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct DeepLinkRequest {
+    pub uri: String,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct NavigateSignal {
+    pub route: String,
+    pub args: HashMap<String, String>,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct DeepLinkRejectedSignal {
+    pub uri: String,
+    pub reason: String,
+}
+
+// Flutter 라우터가 이동할 것 같은 화면을 알려준다 (마우스 올림, 목록 스크롤 등)
+// 다른 경로의 힌트가 오면 이전 미리 불러오기는 취소된다.
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct RoutePrefetchHint {
+    pub route: String,
+    pub params: HashMap<String, String>,
+}
@@ -0,0 +1,27 @@
+use super::super::messages::{DataItem, FieldError, RulesModuleInfo};
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct EvaluateRulesRequest {
+    pub item: DataItem,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct RulesStatusRequest {}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct RulesEvaluationSignal {
+    pub item_id: String,
+    pub version: Option<u64>,
+    pub errors: Vec<FieldError>,
+    pub score: Option<f64>,
+    pub error: Option<String>,
+}
+
+// 규칙 모듈을 불러오거나 바꿀 때마다 보낸다
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct RulesStatusSignal {
+    pub module: Option<RulesModuleInfo>,
+    pub last_error: Option<String>,
+}
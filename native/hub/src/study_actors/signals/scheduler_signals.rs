@@ -0,0 +1,73 @@
+use super::super::messages::{Reminder, ScheduledTask, ThrottlePolicy};
+use rinf::{DartSignal, RustSignal, SignalPiece};
+use serde::{Deserialize, Serialize};
+
+// 기기 배터리 상태 (battery_plus 등에서 전달)
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct BatteryStateChanged {
+    pub level: f64, // 0.0 ~ 1.0
+    pub is_charging: bool,
+    pub low_power_mode: bool, // iOS 저전력 모드, 안드로이드 절전 모드
+}
+
+// 절전 중 작업별 정책 변경 (Normal이면 절전 중에도 그대로 둔다)
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct SetThrottlePolicyRequest {
+    pub task: ScheduledTask,
+    pub policy: ThrottlePolicy,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, SignalPiece)]
+pub struct TaskThrottle {
+    pub task: ScheduledTask,
+    pub policy: ThrottlePolicy,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct ThrottleStatusSignal {
+    pub level: f64,
+    pub is_charging: bool,
+    // 절전 중인지 (저전력 모드이거나 충전 없이 배터리가 거의 없을 때)
+    pub low_power: bool,
+    pub policies: Vec<TaskThrottle>,  // 절전 중 적용할 정책
+    pub throttled: Vec<TaskThrottle>, // 지금 조절 중인 작업
+}
+
+// 항목 알림 설정 (repeat는 RRULE, timezone이 없으면 UTC)
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct SetReminderRequest {
+    pub item_id: String,
+    pub at: u64,
+    pub repeat: Option<String>,
+    pub timezone: Option<String>,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct CancelReminderRequest {
+    pub item_id: String,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct SnoozeReminderRequest {
+    pub item_id: String,
+    pub minutes: u32,
+}
+
+// 알림을 설정·미루기·취소한 결과 (취소했거나 실패하면 reminder가 None)
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct ReminderChangedSignal {
+    pub item_id: String,
+    pub reminder: Option<Reminder>,
+    pub error: Option<String>,
+}
+
+// 알림 시각이 되었을 때 (앱이 닫혀 있는 동안 지난 알림은 missed로 한 번만 보낸다)
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct ReminderDueSignal {
+    pub item_id: String,
+    pub title: Option<String>, // 항목을 찾지 못하면 None
+    pub message: String,       // 현지화한 알림 문구 (알림 배너에 그대로 쓴다)
+    pub scheduled_at: u64,
+    pub missed: bool,
+    pub next_at: Option<u64>, // 반복 알림의 다음 발생 시각
+}
@@ -0,0 +1,39 @@
+use super::super::messages::{ScriptInfo, ScriptTopic};
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct RegisterScriptRequest {
+    pub name: String,
+    pub source: String,
+    pub topics: Vec<ScriptTopic>,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct RemoveScriptRequest {
+    pub name: String,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct ListScriptsRequest {}
+
+// 등록 결과 (컴파일 오류는 error로)
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct ScriptRegisteredSignal {
+    pub name: String,
+    pub script: Option<ScriptInfo>,
+    pub error: Option<String>,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct ScriptListSignal {
+    pub scripts: Vec<ScriptInfo>,
+}
+
+// 스크립트가 emit(name, payload)로 보낸 신호
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct ScriptOutputSignal {
+    pub script: String,
+    pub name: String,
+    pub payload_json: String,
+}
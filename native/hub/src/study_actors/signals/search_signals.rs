@@ -0,0 +1,24 @@
+use super::super::messages::{SearchResult, SearchSource, UserId};
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+
+// 항목, 사용자 디렉터리, 설정과 동작을 한 번에 검색 (user_id가 없으면 항목은 찾지 않는다)
+// 새 요청이 오면 진행 중인 검색은 취소된다.
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct UnifiedSearchRequest {
+    pub request_id: String,
+    pub user_id: Option<UserId>,
+    pub query: String,
+    pub limit: Option<u64>,
+}
+
+// 출처 하나가 응답할 때마다 지금까지 합친 결과를 보낸다 (done이면 마지막 신호)
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct UnifiedSearchSignal {
+    pub request_id: String,
+    pub results: Vec<SearchResult>,
+    pub source: Option<SearchSource>, // 이번에 응답한 출처 (검색할 출처가 없었으면 None)
+    pub error: Option<String>,        // 이번 출처가 실패한 이유
+    pub pending: Vec<SearchSource>,
+    pub done: bool,
+}
@@ -0,0 +1,34 @@
+use rinf::{DartSignal, RustSignal, RustSignalBinary};
+use serde::{Deserialize, Serialize};
+
+// 값 없이 핸들만 Dart에 알림
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct SecretAvailableSignal {
+    pub handle: String,
+    pub label: String,
+    pub expires_at: u64, // 유닉스 타임스탬프 (초)
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct RevealSecretRequest {
+    pub handle: String,
+}
+
+// 비밀 값은 Dart 문자열이 아닌 바이너리 페이로드로 전달
+#[derive(RustSignalBinary, Serialize, Deserialize, Debug)]
+pub struct RevealedSecretSignal {
+    pub handle: String,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct DiscardSecretRequest {
+    pub handle: String,
+}
+
+// UI가 입력 필드와 표시 중인 값을 지우도록 알림
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct ExpireSecretSignal {
+    pub handle: String,
+}
@@ -0,0 +1,42 @@
+use super::super::messages::{DataItem, UserId, UserProfile};
+use rinf::{DartSignal, RustSignal, SignalPiece};
+use serde::{Deserialize, Serialize};
+
+// 액터 하나가 시작 스냅샷에 보태는 조각
+#[derive(Serialize, Deserialize, Debug, Clone, SignalPiece)]
+pub enum StateSnapshotSignal {
+    // 마지막 세션 (만료 여부는 expires_at으로 판단, 토큰은 싣지 않는다)
+    Auth {
+        user_id: Option<UserId>,
+        expires_at: Option<u64>,
+    },
+    Profile {
+        profile: UserProfile,
+    },
+    RecentItems {
+        items: Vec<DataItem>,
+    },
+    PendingJobs {
+        source: String,
+        count: u64,
+    },
+}
+
+// 네트워크를 쓰기 전에 저장된 상태로 첫 화면을 그릴 수 있도록 시작할 때 한 번 보낸다
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct AppSnapshotSignal {
+    pub parts: Vec<StateSnapshotSignal>,
+    pub elapsed_ms: f64,
+}
+
+// Dart가 앱을 닫기 전에 보낸다 (StatePersistedSignal을 받은 뒤 종료한다)
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct PersistStateRequest {}
+
+// 종료 전 상태 저장 결과 (남길 것이 없던 액터는 어느 쪽에도 없다)
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct StatePersistedSignal {
+    pub saved: Vec<String>,
+    pub failed: Vec<String>,
+    pub elapsed_ms: f64,
+}
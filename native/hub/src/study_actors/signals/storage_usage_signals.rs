@@ -0,0 +1,85 @@
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+use super::super::messages::{CategoryCleared, ErrorEnvelope, StorageCategory, StorageUsageReport};
+use super::{AnswerSignal, AskSignal};
+
+// 설정 화면의 저장 공간 사용량
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct StorageUsageRequest {
+    pub correlation_id: u64,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct StorageUsageResponse {
+    pub correlation_id: u64,
+    pub report: Option<StorageUsageReport>,
+    pub error: Option<ErrorEnvelope>,
+}
+
+impl AskSignal for StorageUsageRequest {
+    type Answer = StorageUsageResponse;
+
+    fn correlation_id(&self) -> u64 {
+        self.correlation_id
+    }
+}
+
+impl AnswerSignal for StorageUsageResponse {
+    type Payload = StorageUsageReport;
+
+    fn answer(correlation_id: u64, result: Result<StorageUsageReport, ErrorEnvelope>) -> Self {
+        match result {
+            Ok(report) => Self {
+                correlation_id,
+                report: Some(report),
+                error: None,
+            },
+            Err(error) => Self {
+                correlation_id,
+                report: None,
+                error: Some(error),
+            },
+        }
+    }
+}
+
+// 분류별 "비우기" 버튼 (Items와 Other는 InvalidInput 오류로 답한다)
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct ClearCategoryRequest {
+    pub correlation_id: u64,
+    pub category: StorageCategory,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct CategoryClearedResponse {
+    pub correlation_id: u64,
+    pub result: Option<CategoryCleared>,
+    pub error: Option<ErrorEnvelope>,
+}
+
+impl AskSignal for ClearCategoryRequest {
+    type Answer = CategoryClearedResponse;
+
+    fn correlation_id(&self) -> u64 {
+        self.correlation_id
+    }
+}
+
+impl AnswerSignal for CategoryClearedResponse {
+    type Payload = CategoryCleared;
+
+    fn answer(correlation_id: u64, result: Result<CategoryCleared, ErrorEnvelope>) -> Self {
+        match result {
+            Ok(cleared) => Self {
+                correlation_id,
+                result: Some(cleared),
+                error: None,
+            },
+            Err(error) => Self {
+                correlation_id,
+                result: None,
+                error: Some(error),
+            },
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+use super::super::messages::ErrorEnvelope;
+use super::{AnswerSignal, AskSignal};
+
+// 패스프레이즈는 키 유도에만 쓰고 저장하지 않는다
+// 켠 뒤에는 PushLocalChangesRequest(full)로 서버의 평문 사본을 봉인본으로 바꾼다.
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct SetupSyncEncryptionRequest {
+    pub correlation_id: u64,
+    pub user_id: String,
+    pub passphrase: String,
+}
+
+// 패스프레이즈가 틀리면 error.code가 "invalid_credentials"다
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct UnlockSyncEncryptionRequest {
+    pub correlation_id: u64,
+    pub user_id: String,
+    pub passphrase: String,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct LockSyncEncryptionRequest {
+    pub correlation_id: u64,
+    pub user_id: String,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct RotateSyncKeyRequest {
+    pub correlation_id: u64,
+    pub user_id: String,
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct ChangeSyncPassphraseRequest {
+    pub correlation_id: u64,
+    pub user_id: String,
+    pub new_passphrase: String,
+}
+
+// 위 요청들의 공통 응답
+// 감싼 키 묶음을 서버 백업이나 다른 기기로 옮길 때 (묶음만으로는 키를 풀 수 없다)
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct ExportSyncKeyringRequest {
+    pub correlation_id: u64,
+    pub user_id: String,
+}
+
+// 응답의 active_key_id는 묶음을 지금 키로 풀었을 때만 온다 (아니면 다시 잠금 해제해야 한다)
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct ImportSyncKeyringRequest {
+    pub correlation_id: u64,
+    pub user_id: String,
+    pub keyring: Vec<u8>,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct SyncKeyringResponse {
+    pub correlation_id: u64,
+    pub keyring: Option<Vec<u8>>,
+    pub error: Option<ErrorEnvelope>,
+}
+
+// active_key_id: 이후 업로드에 쓸 데이터 키 ID (잠갔거나 실패하면 None). 키 자체는 Dart로 오지 않는다.
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct SyncEncryptionResponse {
+    pub correlation_id: u64,
+    pub active_key_id: Option<String>,
+    pub error: Option<ErrorEnvelope>,
+}
+
+impl AskSignal for SetupSyncEncryptionRequest {
+    type Answer = SyncEncryptionResponse;
+
+    fn correlation_id(&self) -> u64 {
+        self.correlation_id
+    }
+}
+
+impl AskSignal for UnlockSyncEncryptionRequest {
+    type Answer = SyncEncryptionResponse;
+
+    fn correlation_id(&self) -> u64 {
+        self.correlation_id
+    }
+}
+
+impl AskSignal for LockSyncEncryptionRequest {
+    type Answer = SyncEncryptionResponse;
+
+    fn correlation_id(&self) -> u64 {
+        self.correlation_id
+    }
+}
+
+impl AskSignal for RotateSyncKeyRequest {
+    type Answer = SyncEncryptionResponse;
+
+    fn correlation_id(&self) -> u64 {
+        self.correlation_id
+    }
+}
+
+impl AskSignal for ChangeSyncPassphraseRequest {
+    type Answer = SyncEncryptionResponse;
+
+    fn correlation_id(&self) -> u64 {
+        self.correlation_id
+    }
+}
+
+impl AskSignal for ExportSyncKeyringRequest {
+    type Answer = SyncKeyringResponse;
+
+    fn correlation_id(&self) -> u64 {
+        self.correlation_id
+    }
+}
+
+impl AskSignal for ImportSyncKeyringRequest {
+    type Answer = SyncEncryptionResponse;
+
+    fn correlation_id(&self) -> u64 {
+        self.correlation_id
+    }
+}
+
+impl AnswerSignal for SyncEncryptionResponse {
+    type Payload = Option<String>;
+
+    fn answer(correlation_id: u64, result: Result<Option<String>, ErrorEnvelope>) -> Self {
+        match result {
+            Ok(active_key_id) => Self {
+                correlation_id,
+                active_key_id,
+                error: None,
+            },
+            Err(error) => Self {
+                correlation_id,
+                active_key_id: None,
+                error: Some(error),
+            },
+        }
+    }
+}
+
+impl AnswerSignal for SyncKeyringResponse {
+    type Payload = Vec<u8>;
+
+    fn answer(correlation_id: u64, result: Result<Vec<u8>, ErrorEnvelope>) -> Self {
+        match result {
+            Ok(keyring) => Self {
+                correlation_id,
+                keyring: Some(keyring),
+                error: None,
+            },
+            Err(error) => Self {
+                correlation_id,
+                keyring: None,
+                error: Some(error),
+            },
+        }
+    }
+}
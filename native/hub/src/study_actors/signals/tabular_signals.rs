@@ -0,0 +1,31 @@
+use super::super::messages::{CellKind, RowError, TabularFormat, TabularImportTarget, TabularRow};
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct ParseTabularFileRequest {
+    pub request_id: String,
+    pub path: String,
+    pub format: TabularFormat,
+    pub has_header: bool,
+    pub import_target: Option<TabularImportTarget>,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct TabularRowsBatchSignal {
+    pub request_id: String,
+    pub batch_index: u64,
+    pub headers: Vec<String>,
+    pub rows: Vec<TabularRow>,
+    pub errors: Vec<RowError>,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct TabularParseCompletedSignal {
+    pub request_id: String,
+    pub total_rows: u64,
+    pub error_rows: u64,
+    pub column_types: Vec<CellKind>,
+    pub imported_items: u64,
+    pub error: Option<String>,
+}
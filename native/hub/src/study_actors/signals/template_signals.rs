@@ -0,0 +1,82 @@
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+use super::super::messages::{ErrorEnvelope, TemplateFormat};
+use super::{AnswerSignal, AskSignal};
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct RegisterTemplateRequest {
+    pub correlation_id: u64,
+    pub name: String,
+    pub source: String,
+    pub format: TemplateFormat,
+}
+
+// 문법 오류, 크기 제한, 부분 템플릿 순환이면 success가 false이고 error에 이유가 온다
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct TemplateRegisteredResponse {
+    pub correlation_id: u64,
+    pub success: bool,
+    pub error: Option<ErrorEnvelope>,
+}
+
+impl AskSignal for RegisterTemplateRequest {
+    type Answer = TemplateRegisteredResponse;
+
+    fn correlation_id(&self) -> u64 {
+        self.correlation_id
+    }
+}
+
+impl AnswerSignal for TemplateRegisteredResponse {
+    type Payload = ();
+
+    fn answer(correlation_id: u64, result: Result<(), ErrorEnvelope>) -> Self {
+        Self {
+            correlation_id,
+            success: result.is_ok(),
+            error: result.err(),
+        }
+    }
+}
+
+// context_json: 템플릿에 넘길 JSON 값 (보통 객체)
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct RenderTemplateRequest {
+    pub correlation_id: u64,
+    pub name: String,
+    pub context_json: String,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct RenderedTemplateResponse {
+    pub correlation_id: u64,
+    pub output: Option<String>,
+    pub error: Option<ErrorEnvelope>,
+}
+
+impl AskSignal for RenderTemplateRequest {
+    type Answer = RenderedTemplateResponse;
+
+    fn correlation_id(&self) -> u64 {
+        self.correlation_id
+    }
+}
+
+impl AnswerSignal for RenderedTemplateResponse {
+    type Payload = String;
+
+    fn answer(correlation_id: u64, result: Result<String, ErrorEnvelope>) -> Self {
+        match result {
+            Ok(output) => Self {
+                correlation_id,
+                output: Some(output),
+                error: None,
+            },
+            Err(error) => Self {
+                correlation_id,
+                output: None,
+                error: Some(error),
+            },
+        }
+    }
+}
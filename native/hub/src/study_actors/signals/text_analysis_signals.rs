@@ -0,0 +1,19 @@
+use super::super::messages::TextAnalysis;
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+
+// 편집기 본문(DataItem.content) 분석 요청
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct AnalyzeTextRequest {
+    pub request_id: String,
+    pub text: String,
+    pub language: Option<String>,
+    pub spell_check: bool,
+}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct TextAnalysisSignal {
+    pub request_id: String,
+    pub analysis: Option<TextAnalysis>,
+    pub error: Option<String>,
+}
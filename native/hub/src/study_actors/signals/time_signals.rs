@@ -0,0 +1,15 @@
+use super::super::messages::ClockSource;
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct SyncClockRequest {}
+
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct ClockDriftSignal {
+    pub offset_ms: i64, // 신뢰 시각 - 기기 시각
+    pub source: ClockSource,
+    pub synced_at: u64,
+    pub exceeds_warning: bool,
+    pub error: Option<String>,
+}
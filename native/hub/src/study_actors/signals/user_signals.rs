@@ -1,16 +1,43 @@
 use rinf::{DartSignal, RustSignal};
 use serde::{Deserialize, Serialize};
-use super::super::messages::{UserId, UserProfile, UserPreferences};
+use super::super::messages::{UserId, UserProfile, UserPreferences, ErrorEnvelope};
+use super::{AnswerSignal, AskSignal};
 
 #[derive(DartSignal, Serialize, Deserialize, Debug)]
 pub struct GetUserProfileRequest {
+    pub correlation_id: u64,
     pub user_id: UserId,
 }
 
 #[derive(RustSignal, Serialize, Deserialize, Debug)]
 pub struct UserProfileResponse {
+    pub correlation_id: u64,
     pub profile: Option<UserProfile>,
-    pub error: Option<String>,
+    pub error: Option<ErrorEnvelope>,
+}
+
+impl AskSignal for GetUserProfileRequest {
+    type Answer = UserProfileResponse;
+
+    fn correlation_id(&self) -> u64 {
+        self.correlation_id
+    }
+}
+
+impl AnswerSignal for UserProfileResponse {
+    type Payload = UserProfile;
+
+    fn answer(correlation_id: u64, result: Result<UserProfile, ErrorEnvelope>) -> Self {
+        let (profile, error) = match result {
+            Ok(profile) => (Some(profile), None),
+            Err(error) => (None, Some(error)),
+        };
+        Self {
+            correlation_id,
+            profile,
+            error,
+        }
+    }
 }
 
 #[derive(DartSignal, Serialize, Deserialize, Debug)]
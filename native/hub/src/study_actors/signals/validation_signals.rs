@@ -0,0 +1,94 @@
+use rinf::{DartSignal, RustSignal};
+use serde::{Deserialize, Serialize};
+use super::super::messages::{ContactFieldKind, ErrorEnvelope, FieldError, NormalizedContactField};
+use super::{AnswerSignal, AskSignal};
+
+// values_json: 필드 이름 → 값 JSON 객체 (중첩 필드는 "preferences.theme"처럼 점으로 가리킨다)
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct ValidateFormRequest {
+    pub correlation_id: u64,
+    pub schema_name: String,
+    pub values_json: String,
+}
+
+// 검증을 마쳤으면 error는 None이고 field_errors가 비었을 때만 valid
+// (알 수 없는 스키마, 잘못된 JSON이면 error로 답한다)
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct FormValidationResponse {
+    pub correlation_id: u64,
+    pub valid: bool,
+    pub field_errors: Vec<FieldError>,
+    pub error: Option<ErrorEnvelope>,
+}
+
+impl AskSignal for ValidateFormRequest {
+    type Answer = FormValidationResponse;
+
+    fn correlation_id(&self) -> u64 {
+        self.correlation_id
+    }
+}
+
+impl AnswerSignal for FormValidationResponse {
+    type Payload = Vec<FieldError>;
+
+    fn answer(correlation_id: u64, result: Result<Vec<FieldError>, ErrorEnvelope>) -> Self {
+        match result {
+            Ok(field_errors) => Self {
+                correlation_id,
+                valid: field_errors.is_empty(),
+                field_errors,
+                error: None,
+            },
+            Err(error) => Self {
+                correlation_id,
+                valid: false,
+                field_errors: Vec::new(),
+                error: Some(error),
+            },
+        }
+    }
+}
+
+#[derive(DartSignal, Serialize, Deserialize, Debug)]
+pub struct NormalizeContactFieldRequest {
+    pub correlation_id: u64,
+    pub kind: ContactFieldKind,
+    pub value: String,
+    pub default_region: Option<String>,
+}
+
+// 읽을 수 없는 값이면 error (UserError::InvalidInput)
+#[derive(RustSignal, Serialize, Deserialize, Debug)]
+pub struct ContactFieldNormalizedResponse {
+    pub correlation_id: u64,
+    pub field: Option<NormalizedContactField>,
+    pub error: Option<ErrorEnvelope>,
+}
+
+impl AskSignal for NormalizeContactFieldRequest {
+    type Answer = ContactFieldNormalizedResponse;
+
+    fn correlation_id(&self) -> u64 {
+        self.correlation_id
+    }
+}
+
+impl AnswerSignal for ContactFieldNormalizedResponse {
+    type Payload = NormalizedContactField;
+
+    fn answer(correlation_id: u64, result: Result<NormalizedContactField, ErrorEnvelope>) -> Self {
+        match result {
+            Ok(field) => Self {
+                correlation_id,
+                field: Some(field),
+                error: None,
+            },
+            Err(error) => Self {
+                correlation_id,
+                field: None,
+                error: Some(error),
+            },
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use async_trait::async_trait;
+use js_sys::Uint8Array;
+use rexie::{
+    Direction, KeyRange, ObjectStore, Rexie, Transaction, TransactionMode, TransactionResult,
+};
+use send_wrapper::SendWrapper;
+use tokio::sync::OnceCell;
+use wasm_bindgen::JsValue;
+
+use super::Storage;
+use crate::study_actors::logging::debug_print;
+use crate::study_actors::messages::StorageError;
+
+const DB_VERSION: u32 = 1;
+const STORE_NAME: &str = "entries";
+// IndexedDB는 문자열 키를 UTF-16 코드 단위로 비교하므로 접두사 뒤에 가장 큰 단위를 붙여 범위의 끝으로 쓴다
+const PREFIX_UPPER_BOUND: char = '\u{ffff}';
+
+// IndexedDB 기반 저장소 (웹 타깃)
+// 감독자는 동기적으로 구성되므로 DB는 처음 사용할 때 연다.
+// 브라우저 객체는 스레드 간에 보낼 수 없지만 웹에서는 한 스레드만 쓰므로 SendWrapper로 감싼다.
+pub struct IndexedDbStorage {
+    name: String,
+    db: SendWrapper<OnceCell<Rexie>>,
+}
+
+impl IndexedDbStorage {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            db: SendWrapper::new(OnceCell::new()),
+        }
+    }
+
+    async fn db(&self) -> Result<&Rexie, StorageError> {
+        self.db
+            .get_or_try_init(|| async {
+                debug_print!("Opening IndexedDB database: {}", self.name);
+                Rexie::builder(&self.name)
+                    .version(DB_VERSION)
+                    .add_object_store(ObjectStore::new(STORE_NAME))
+                    .build()
+                    .await
+            })
+            .await
+            .map_err(indexed_db_error)
+    }
+
+    async fn save_entry(&self, key: &str, data: &[u8]) -> Result<(), StorageError> {
+        let db = self.db().await?;
+        let transaction = db
+            .transaction(&[STORE_NAME], TransactionMode::ReadWrite)
+            .map_err(indexed_db_error)?;
+        let store = transaction.store(STORE_NAME).map_err(indexed_db_error)?;
+        let value: JsValue = Uint8Array::from(data).into();
+        store
+            .put(&value, Some(&JsValue::from_str(key)))
+            .await
+            .map_err(indexed_db_error)?;
+        commit(transaction).await
+    }
+
+    async fn load_entry(&self, key: &str) -> Result<Vec<u8>, StorageError> {
+        let db = self.db().await?;
+        let transaction = db
+            .transaction(&[STORE_NAME], TransactionMode::ReadOnly)
+            .map_err(indexed_db_error)?;
+        let store = transaction.store(STORE_NAME).map_err(indexed_db_error)?;
+        match store
+            .get(JsValue::from_str(key))
+            .await
+            .map_err(indexed_db_error)?
+        {
+            Some(value) => Ok(Uint8Array::new(&value).to_vec()),
+            None => Err(StorageError::NotFound(key.to_string())),
+        }
+    }
+
+    async fn delete_entry(&self, key: &str) -> Result<(), StorageError> {
+        let db = self.db().await?;
+        let transaction = db
+            .transaction(&[STORE_NAME], TransactionMode::ReadWrite)
+            .map_err(indexed_db_error)?;
+        let store = transaction.store(STORE_NAME).map_err(indexed_db_error)?;
+        store
+            .delete(JsValue::from_str(key))
+            .await
+            .map_err(indexed_db_error)?;
+        commit(transaction).await
+    }
+
+    // 접두사 조회는 [prefix, prefix + U+FFFF] 키 범위로 흉내 낸다 (결과는 키 오름차순)
+    async fn scan_entries(&self, prefix: &str) -> Result<Vec<(String, Vec<u8>)>, StorageError> {
+        let db = self.db().await?;
+        let transaction = db
+            .transaction(&[STORE_NAME], TransactionMode::ReadOnly)
+            .map_err(indexed_db_error)?;
+        let store = transaction.store(STORE_NAME).map_err(indexed_db_error)?;
+        let upper = format!("{}{}", prefix, PREFIX_UPPER_BOUND);
+        let range = KeyRange::bound(
+            &JsValue::from_str(prefix),
+            &JsValue::from_str(&upper),
+            Some(false),
+            Some(false),
+        )
+        .map_err(indexed_db_error)?;
+        let entries = store
+            .scan(Some(range), None, None, Some(Direction::Next))
+            .await
+            .map_err(indexed_db_error)?;
+        Ok(entries
+            .into_iter()
+            .filter_map(|(key, value)| Some((key.as_string()?, Uint8Array::new(&value).to_vec())))
+            .filter(|(key, _)| key.starts_with(prefix))
+            .collect())
+    }
+
+    async fn clear_entries(&self) -> Result<(), StorageError> {
+        let db = self.db().await?;
+        let transaction = db
+            .transaction(&[STORE_NAME], TransactionMode::ReadWrite)
+            .map_err(indexed_db_error)?;
+        let store = transaction.store(STORE_NAME).map_err(indexed_db_error)?;
+        store.clear().await.map_err(indexed_db_error)?;
+        commit(transaction).await
+    }
+}
+
+// 중단된 트랜잭션은 아무것도 반영되지 않았으므로 실패로 돌려준다
+async fn commit(transaction: Transaction) -> Result<(), StorageError> {
+    match transaction.done().await.map_err(indexed_db_error)? {
+        TransactionResult::Committed => Ok(()),
+        TransactionResult::Aborted => Err(StorageError::IndexedDb(
+            "Transaction was aborted".to_string(),
+        )),
+    }
+}
+
+// 키 범위(KeyRange)는 idb 오류를 그대로 돌려준다
+fn indexed_db_error(error: impl Into<rexie::Error>) -> StorageError {
+    StorageError::IndexedDb(error.into().to_string())
+}
+
+#[async_trait]
+impl Storage for IndexedDbStorage {
+    async fn save(&self, key: &str, data: &[u8]) -> Result<(), StorageError> {
+        SendWrapper::new(self.save_entry(key, data)).await
+    }
+
+    async fn load(&self, key: &str) -> Result<Vec<u8>, StorageError> {
+        SendWrapper::new(self.load_entry(key)).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), StorageError> {
+        SendWrapper::new(self.delete_entry(key)).await
+    }
+
+    async fn clear(&self) -> Result<(), StorageError> {
+        SendWrapper::new(self.clear_entries()).await
+    }
+
+    async fn scan_prefix(&self, prefix: &str) -> Result<Vec<(String, Vec<u8>)>, StorageError> {
+        SendWrapper::new(self.scan_entries(prefix)).await
+    }
+}
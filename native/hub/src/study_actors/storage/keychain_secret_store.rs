@@ -0,0 +1,112 @@
+use async_trait::async_trait;
+use keyring::{Entry, Error as KeyringError};
+use tokio::sync::Mutex;
+use zeroize::Zeroizing;
+
+use crate::study_actors::messages::StorageError;
+
+use super::SecretStore;
+
+// 키체인 항목의 서비스 이름 (이름은 항목의 계정 자리에 들어간다)
+const SERVICE: &str = "rinf_experiment.study_actors";
+// 키체인은 항목 목록을 돌려주지 않으므로 저장한 이름을 이 항목에 따로 적어 둔다
+const NAMES_ENTRY: &str = "\0secret_names";
+
+// OS 키체인(macOS/iOS 키체인, Windows 자격 증명 관리자, Linux Secret Service) 기반 비밀 저장소
+// 앱을 다시 켜도 남으므로 봉인 저장소의 데이터 키 묶음과 진행 중인 키 교체도 재시작 뒤에 이어진다.
+// 키체인 호출은 블로킹이라 spawn_blocking에서 한다.
+#[derive(Default)]
+pub struct KeychainSecretStore {
+    // 이름 목록을 읽고 고쳐 쓰는 사이에 다른 쓰기가 끼어들지 않도록 쓰기를 차례로 한다
+    names: Mutex<()>,
+}
+
+impl KeychainSecretStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    async fn blocking<T, F>(task: F) -> Result<T, StorageError>
+    where
+        T: Send + 'static,
+        F: FnOnce() -> Result<T, KeyringError> + Send + 'static,
+    {
+        tokio::task::spawn_blocking(task)
+            .await
+            .map_err(|e| StorageError::Keychain(e.to_string()))?
+            .map_err(|e| StorageError::Keychain(e.to_string()))
+    }
+
+    // 없는 항목이면 None
+    async fn read(name: &str) -> Result<Option<Vec<u8>>, StorageError> {
+        let name = name.to_string();
+        Self::blocking(move || match Entry::new(SERVICE, &name)?.get_secret() {
+            Ok(secret) => Ok(Some(secret)),
+            Err(KeyringError::NoEntry) => Ok(None),
+            Err(e) => Err(e),
+        })
+        .await
+    }
+
+    async fn write(name: &str, secret: &[u8]) -> Result<(), StorageError> {
+        let name = name.to_string();
+        let secret = Zeroizing::new(secret.to_vec());
+        Self::blocking(move || Entry::new(SERVICE, &name)?.set_secret(&secret)).await
+    }
+
+    async fn remove(name: &str) -> Result<(), StorageError> {
+        let name = name.to_string();
+        Self::blocking(
+            move || match Entry::new(SERVICE, &name)?.delete_credential() {
+                Ok(()) | Err(KeyringError::NoEntry) => Ok(()),
+                Err(e) => Err(e),
+            },
+        )
+        .await
+    }
+
+    async fn load_names() -> Result<Vec<String>, StorageError> {
+        match Self::read(NAMES_ENTRY).await? {
+            Some(bytes) => {
+                serde_json::from_slice(&bytes).map_err(|e| StorageError::Keychain(e.to_string()))
+            }
+            None => Ok(Vec::new()),
+        }
+    }
+
+    async fn save_names(names: &[String]) -> Result<(), StorageError> {
+        let bytes = serde_json::to_vec(names).map_err(|e| StorageError::Keychain(e.to_string()))?;
+        Self::write(NAMES_ENTRY, &bytes).await
+    }
+}
+
+#[async_trait]
+impl SecretStore for KeychainSecretStore {
+    // 이름을 먼저 목록에 넣으므로 중간에 끊겨도 키 교체와 삭제가 놓치는 값은 생기지 않는다
+    // (값 없이 목록에만 남은 이름은 읽으면 None이라 건너뛴다)
+    async fn put_secret(&self, name: &str, secret: &[u8]) -> Result<(), StorageError> {
+        let _names = self.names.lock().await;
+        let mut names = Self::load_names().await?;
+        if let Err(index) = names.binary_search_by(|known| known.as_str().cmp(name)) {
+            names.insert(index, name.to_string());
+            Self::save_names(&names).await?;
+        }
+        Self::write(name, secret).await
+    }
+
+    async fn get_secret(&self, name: &str) -> Result<Option<Vec<u8>>, StorageError> {
+        Self::read(name).await
+    }
+
+    async fn secret_names(&self) -> Result<Vec<String>, StorageError> {
+        Self::load_names().await
+    }
+
+    async fn clear_secrets(&self) -> Result<(), StorageError> {
+        let _names = self.names.lock().await;
+        for name in Self::load_names().await? {
+            Self::remove(&name).await?;
+        }
+        Self::remove(NAMES_ENTRY).await
+    }
+}
@@ -0,0 +1,71 @@
+use async_trait::async_trait;
+use std::{collections::BTreeMap, sync::Mutex};
+
+use super::Storage;
+use crate::study_actors::messages::StorageError;
+
+// 메모리 기반 저장소 (디스크 DB를 열 수 없을 때의 대체 수단, 앱 재시작 시 사라짐)
+// 다른 저장소와 같이 접두사 조회를 키 순서로 돌려주도록 정렬된 맵을 쓴다
+#[derive(Default)]
+pub struct MemoryStorage {
+    entries: Mutex<BTreeMap<String, Vec<u8>>>,
+}
+
+impl MemoryStorage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl Storage for MemoryStorage {
+    async fn save(&self, key: &str, data: &[u8]) -> Result<(), StorageError> {
+        let mut entries = self
+            .entries
+            .lock()
+            .map_err(|_| StorageError::LockPoisoned)?;
+        entries.insert(key.to_string(), data.to_vec());
+        Ok(())
+    }
+
+    async fn load(&self, key: &str) -> Result<Vec<u8>, StorageError> {
+        let entries = self
+            .entries
+            .lock()
+            .map_err(|_| StorageError::LockPoisoned)?;
+        entries
+            .get(key)
+            .cloned()
+            .ok_or_else(|| StorageError::NotFound(key.to_string()))
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), StorageError> {
+        let mut entries = self
+            .entries
+            .lock()
+            .map_err(|_| StorageError::LockPoisoned)?;
+        entries.remove(key);
+        Ok(())
+    }
+
+    async fn clear(&self) -> Result<(), StorageError> {
+        let mut entries = self
+            .entries
+            .lock()
+            .map_err(|_| StorageError::LockPoisoned)?;
+        entries.clear();
+        Ok(())
+    }
+
+    async fn scan_prefix(&self, prefix: &str) -> Result<Vec<(String, Vec<u8>)>, StorageError> {
+        let entries = self
+            .entries
+            .lock()
+            .map_err(|_| StorageError::LockPoisoned)?;
+        Ok(entries
+            .range(prefix.to_string()..)
+            .take_while(|(key, _)| key.starts_with(prefix))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect())
+    }
+}
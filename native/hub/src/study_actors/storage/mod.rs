@@ -1,13 +1,41 @@
+#[cfg(not(target_family = "wasm"))]
 mod sled_storage;
+#[cfg(target_family = "wasm")]
+mod indexed_db_storage;
+#[cfg(all(
+    feature = "keychain",
+    not(any(target_family = "wasm", target_os = "android"))
+))]
+mod keychain_secret_store;
+mod memory_storage;
+mod sealed_secret_store;
+mod secret_store;
+#[cfg(not(target_family = "wasm"))]
 pub use sled_storage::SledStorage;
+#[cfg(target_family = "wasm")]
+pub use indexed_db_storage::IndexedDbStorage;
+#[cfg(all(
+    feature = "keychain",
+    not(any(target_family = "wasm", target_os = "android"))
+))]
+pub use keychain_secret_store::KeychainSecretStore;
+pub use memory_storage::MemoryStorage;
+pub use sealed_secret_store::SealedSecretStore;
+pub use secret_store::{MemorySecretStore, SecretStore};
 
 use async_trait::async_trait;
-use crate::study_actors::messages::StorageError;
+use crate::study_actors::messages::{CompactionStats, StorageError};
 
 #[async_trait]
 pub trait Storage: Send + Sync + 'static {
     async fn save(&self, key: &str, data: &[u8]) -> Result<(), StorageError>;
     async fn load(&self, key: &str) -> Result<Vec<u8>, StorageError>;
     async fn delete(&self, key: &str) -> Result<(), StorageError>;
-    async fn exists(&self, key: &str) -> Result<bool, StorageError>;
+    async fn clear(&self) -> Result<(), StorageError>;
+    // 접두사로 시작하는 모든 항목 (키 순서)
+    async fn scan_prefix(&self, prefix: &str) -> Result<Vec<(String, Vec<u8>)>, StorageError>;
+    // 지운 항목이 차지하던 디스크 공간 회수 (디스크를 쓰지 않거나 공간을 직접 다룰 수 없으면 None)
+    async fn compact(&self) -> Result<Option<CompactionStats>, StorageError> {
+        Ok(None)
+    }
 }
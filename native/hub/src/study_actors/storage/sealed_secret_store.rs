@@ -0,0 +1,454 @@
+use aes_gcm::{
+    Aes256Gcm, KeyInit, Nonce,
+    aead::{Aead, AeadCore, OsRng, Payload, rand_core::RngCore},
+};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, sync::Arc};
+use tokio::sync::Mutex;
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::study_actors::messages::StorageError;
+
+use super::SecretStore;
+
+// 데이터 키 묶음은 봉인하지 않고 안쪽 저장소(플랫폼 키체인)에 그대로 둔다
+const KEYRING_NAME: &str = "data_keyring";
+// 봉인한 값: MAGIC || 키 id 길이(1바이트) || 키 id || nonce || ciphertext
+const MAGIC: &[u8] = b"\0SK1";
+const NONCE_LEN: usize = 12;
+
+#[derive(Clone, Serialize, Deserialize)]
+struct DataKeyring {
+    active_key_id: String,
+    keys: BTreeMap<String, Vec<u8>>,
+    // 진행 중인 키 교체 (재시작하면 커서 다음 이름부터 이어 간다)
+    rotation: Option<Rotation>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct Rotation {
+    key_id: String,
+    cursor: Option<String>, // 마지막으로 다시 봉인한 이름
+}
+
+impl DataKeyring {
+    fn new() -> Self {
+        let key_id = new_key_id();
+        Self {
+            active_key_id: key_id.clone(),
+            keys: BTreeMap::from([(key_id, Aes256Gcm::generate_key(OsRng).to_vec())]),
+            rotation: None,
+        }
+    }
+
+    // 교체 중에는 새로 쓰는 값도 새 키로 봉인해 커서가 지나간 이름에 예전 키가 다시 생기지 않게 한다
+    fn write_key_id(&self) -> &str {
+        self.rotation
+            .as_ref()
+            .map_or(&self.active_key_id, |rotation| &rotation.key_id)
+    }
+
+    fn cipher(&self, key_id: &str) -> Result<Aes256Gcm, StorageError> {
+        let key = self
+            .keys
+            .get(key_id)
+            .ok_or_else(|| StorageError::Sealed(format!("Unknown data key: {}", key_id)))?;
+        Aes256Gcm::new_from_slice(key)
+            .map_err(|_| StorageError::Sealed("Invalid data key length".to_string()))
+    }
+
+    // aad에 이름을 넣어 다른 이름 자리로 옮긴 값은 풀리지 않게 한다
+    fn seal(&self, name: &str, plaintext: &[u8]) -> Result<Vec<u8>, StorageError> {
+        let key_id = self.write_key_id();
+        let key_id_len = u8::try_from(key_id.len())
+            .map_err(|_| StorageError::Sealed("Data key id too long".to_string()))?;
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let payload = Payload {
+            msg: plaintext,
+            aad: name.as_bytes(),
+        };
+        let ciphertext = self
+            .cipher(key_id)?
+            .encrypt(&nonce, payload)
+            .map_err(|_| StorageError::Sealed("Encryption failed".to_string()))?;
+
+        let mut output = MAGIC.to_vec();
+        output.push(key_id_len);
+        output.extend_from_slice(key_id.as_bytes());
+        output.extend_from_slice(&nonce);
+        output.extend_from_slice(&ciphertext);
+        Ok(output)
+    }
+
+    fn open(&self, name: &str, value: &[u8]) -> Result<Vec<u8>, StorageError> {
+        let Some((key_id, body)) = sealed_parts(value)? else {
+            return Ok(value.to_vec());
+        };
+        let (nonce, ciphertext) = body.split_at(NONCE_LEN);
+        let payload = Payload {
+            msg: ciphertext,
+            aad: name.as_bytes(),
+        };
+        self.cipher(key_id)?
+            .decrypt(Nonce::from_slice(nonce), payload)
+            .map_err(|_| StorageError::Sealed(format!("Cannot open secret: {}", name)))
+    }
+}
+
+impl Drop for DataKeyring {
+    fn drop(&mut self) {
+        for key in self.keys.values_mut() {
+            key.zeroize();
+        }
+    }
+}
+
+fn new_key_id() -> String {
+    let mut bytes = [0u8; 8];
+    OsRng.fill_bytes(&mut bytes);
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+// 봉인한 값이면 (키 id, nonce || ciphertext), 봉인을 도입하기 전에 저장된 평문이면 None
+fn sealed_parts(value: &[u8]) -> Result<Option<(&str, &[u8])>, StorageError> {
+    let Some(rest) = value.strip_prefix(MAGIC) else {
+        return Ok(None);
+    };
+    let corrupted = || StorageError::Sealed("Corrupted sealed secret".to_string());
+    let (&key_id_len, rest) = rest.split_first().ok_or_else(corrupted)?;
+    if rest.len() < usize::from(key_id_len) + NONCE_LEN {
+        return Err(corrupted());
+    }
+    let (key_id, body) = rest.split_at(usize::from(key_id_len));
+    let key_id = std::str::from_utf8(key_id).map_err(|_| corrupted())?;
+    Ok(Some((key_id, body)))
+}
+
+// 키 교체 진행 상황 (finished면 새 키가 활성 키가 됐고 예전 키는 지워졌다)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyRotationProgress {
+    pub key_id: String,
+    pub done: u64,
+    pub total: u64,
+    pub finished: bool,
+}
+
+// 값을 데이터 키(AES-256-GCM)로 봉인해 안쪽 저장소에 넣는 비밀 저장소
+// 데이터 키를 교체해도 값마다 키 id가 붙어 있어 교체가 끝나기 전의 값도 풀린다.
+// 데이터 키 묶음은 안쪽 저장소에 그대로 두므로 저장된 값의 보호와 재시작 후 교체 이어 가기는
+// 안쪽 저장소가 플랫폼 키체인(KeychainSecretStore)일 때만 의미가 있다.
+// 키체인이 없으면 메모리 저장소를 쓰고, 재시작하면 키 묶음, 진행 중인 교체, 봉인한 값이 함께 사라진다.
+// 키 묶음을 봉인한 값과 같은 영구 저장소에 평문으로 두면 봉인이 아무것도 막지 못하므로 그렇게 하지 않는다.
+// 영구 저장소의 항목과 첨부 파일은 이 키로 봉인하지 않는다 (키를 잃으면 데이터도 읽을 수 없게 된다).
+pub struct SealedSecretStore {
+    inner: Arc<dyn SecretStore>,
+    // 교체 한 단계가 값과 커서를 바꾸는 사이에 다른 쓰기가 끼어들지 않도록 모든 작업을 차례로 한다
+    keyring: Mutex<Option<DataKeyring>>,
+}
+
+impl SealedSecretStore {
+    pub fn new(inner: Arc<dyn SecretStore>) -> Self {
+        Self {
+            inner,
+            keyring: Mutex::new(None),
+        }
+    }
+
+    async fn load_keyring(&self, cached: &mut Option<DataKeyring>) -> Result<(), StorageError> {
+        if cached.is_none()
+            && let Some(bytes) = self.inner.get_secret(KEYRING_NAME).await?
+        {
+            let bytes = Zeroizing::new(bytes);
+            let keyring =
+                serde_json::from_slice(&bytes).map_err(|e| StorageError::Sealed(e.to_string()))?;
+            *cached = Some(keyring);
+        }
+        Ok(())
+    }
+
+    async fn save_keyring(&self, keyring: &DataKeyring) -> Result<(), StorageError> {
+        let bytes = Zeroizing::new(
+            serde_json::to_vec(keyring).map_err(|e| StorageError::Sealed(e.to_string()))?,
+        );
+        self.inner.put_secret(KEYRING_NAME, &bytes).await
+    }
+
+    // 키 묶음이 없으면 첫 데이터 키를 만든다
+    async fn keyring_for_write<'a>(
+        &self,
+        cached: &'a mut Option<DataKeyring>,
+    ) -> Result<&'a mut DataKeyring, StorageError> {
+        self.load_keyring(cached).await?;
+        if cached.is_none() {
+            let keyring = DataKeyring::new();
+            self.save_keyring(&keyring).await?;
+            *cached = Some(keyring);
+        }
+        cached
+            .as_mut()
+            .ok_or_else(|| StorageError::NotFound(KEYRING_NAME.to_string()))
+    }
+
+    // 새 데이터 키로 교체를 시작하고 그 키 id를 반환 (이미 진행 중이면 그 교체를 이어 간다)
+    // 새 키는 지금부터 쓰는 값에 바로 쓰이지만, 활성 키는 모든 값을 다시 봉인한 뒤에야 바뀐다.
+    pub async fn begin_rotation(&self) -> Result<String, StorageError> {
+        let mut cached = self.keyring.lock().await;
+        let keyring = self.keyring_for_write(&mut cached).await?;
+        if let Some(rotation) = &keyring.rotation {
+            return Ok(rotation.key_id.clone());
+        }
+
+        let key_id = new_key_id();
+        let mut updated = keyring.clone();
+        updated
+            .keys
+            .insert(key_id.clone(), Aes256Gcm::generate_key(OsRng).to_vec());
+        updated.rotation = Some(Rotation {
+            key_id: key_id.clone(),
+            cursor: None,
+        });
+        self.save_keyring(&updated).await?;
+        *keyring = updated;
+        Ok(key_id)
+    }
+
+    // 끝나지 않은 교체의 새 키 id (재시작 후 이어 가기용)
+    pub async fn pending_rotation(&self) -> Result<Option<String>, StorageError> {
+        let mut cached = self.keyring.lock().await;
+        self.load_keyring(&mut cached).await?;
+        Ok(cached
+            .as_ref()
+            .and_then(|keyring| keyring.rotation.as_ref())
+            .map(|rotation| rotation.key_id.clone()))
+    }
+
+    // 커서 다음 값 하나를 새 키로 다시 봉인하고 커서를 저장한다 (진행 중인 교체가 없으면 None)
+    // 남은 값이 없으면 활성 키 전환과 예전 키 폐기를 키 묶음 한 번의 쓰기로 끝낸다.
+    pub async fn rotate_step(&self) -> Result<Option<KeyRotationProgress>, StorageError> {
+        let mut cached = self.keyring.lock().await;
+        self.load_keyring(&mut cached).await?;
+        let Some(keyring) = cached.as_mut() else {
+            return Ok(None);
+        };
+        let Some(rotation) = keyring.rotation.clone() else {
+            return Ok(None);
+        };
+
+        let names = self.secret_names().await?;
+        let total = names.len() as u64;
+        let next = names
+            .iter()
+            .enumerate()
+            .find(|(_, name)| rotation.cursor.as_ref().is_none_or(|cursor| *name > cursor));
+
+        let mut updated = keyring.clone();
+        let progress = match next {
+            Some((index, name)) => {
+                if let Some(value) = self.inner.get_secret(name).await?
+                    && sealed_parts(&value)?.map(|(key_id, _)| key_id)
+                        != Some(rotation.key_id.as_str())
+                {
+                    let plaintext = Zeroizing::new(keyring.open(name, &value)?);
+                    self.inner
+                        .put_secret(name, &keyring.seal(name, &plaintext)?)
+                        .await?;
+                }
+                updated.rotation = Some(Rotation {
+                    key_id: rotation.key_id.clone(),
+                    cursor: Some(name.clone()),
+                });
+                KeyRotationProgress {
+                    key_id: rotation.key_id,
+                    done: index as u64 + 1,
+                    total,
+                    finished: false,
+                }
+            }
+            None => {
+                updated.active_key_id = rotation.key_id.clone();
+                updated.rotation = None;
+                updated.keys.retain(|key_id, key| {
+                    let keep = *key_id == rotation.key_id;
+                    if !keep {
+                        key.zeroize();
+                    }
+                    keep
+                });
+                KeyRotationProgress {
+                    key_id: rotation.key_id,
+                    done: total,
+                    total,
+                    finished: true,
+                }
+            }
+        };
+        self.save_keyring(&updated).await?;
+        *keyring = updated;
+        Ok(Some(progress))
+    }
+}
+
+#[async_trait]
+impl SecretStore for SealedSecretStore {
+    async fn put_secret(&self, name: &str, secret: &[u8]) -> Result<(), StorageError> {
+        let mut cached = self.keyring.lock().await;
+        let keyring = self.keyring_for_write(&mut cached).await?;
+        let sealed = keyring.seal(name, secret)?;
+        self.inner.put_secret(name, &sealed).await
+    }
+
+    async fn get_secret(&self, name: &str) -> Result<Option<Vec<u8>>, StorageError> {
+        let mut cached = self.keyring.lock().await;
+        let Some(value) = self.inner.get_secret(name).await? else {
+            return Ok(None);
+        };
+        self.load_keyring(&mut cached).await?;
+        match cached.as_ref() {
+            Some(keyring) => keyring.open(name, &value).map(Some),
+            None if sealed_parts(&value)?.is_none() => Ok(Some(value)),
+            None => Err(StorageError::Sealed(format!("No data key for {}", name))),
+        }
+    }
+
+    async fn secret_names(&self) -> Result<Vec<String>, StorageError> {
+        let mut names = self.inner.secret_names().await?;
+        names.retain(|name| name != KEYRING_NAME);
+        Ok(names)
+    }
+
+    async fn clear_secrets(&self) -> Result<(), StorageError> {
+        let mut cached = self.keyring.lock().await;
+        self.inner.clear_secrets().await?;
+        *cached = None;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{error::Error, sync::Arc};
+
+    use super::{DataKeyring, KEYRING_NAME, MAGIC, SealedSecretStore, sealed_parts};
+    use crate::study_actors::storage::{MemorySecretStore, SecretStore};
+
+    #[tokio::test]
+    async fn rotation_resumes_after_restart_and_retires_the_old_key() -> Result<(), Box<dyn Error>>
+    {
+        let inner = Arc::new(MemorySecretStore::new());
+        // 봉인을 도입하기 전에 평문으로 저장된 값도 교체하면서 봉인된다
+        inner.put_secret("aes_key/legacy", b"legacy").await?;
+        let store = SealedSecretStore::new(inner.clone());
+        for name in ["aes_key/a", "x25519/a"] {
+            store.put_secret(name, name.as_bytes()).await?;
+        }
+
+        let key_id = store.begin_rotation().await?;
+        let first = store.rotate_step().await?.ok_or("rotation not started")?;
+        assert_eq!((first.done, first.total, first.finished), (1, 3, false));
+
+        // 재시작: 같은 안쪽 저장소로 다시 열면 커서 다음부터 이어 간다
+        let store = SealedSecretStore::new(inner.clone());
+        assert_eq!(store.pending_rotation().await?, Some(key_id.clone()));
+        store
+            .put_secret("x25519/b", b"written during rotation")
+            .await?;
+
+        let mut steps = Vec::new();
+        while let Some(progress) = store.rotate_step().await? {
+            steps.push((progress.done, progress.total, progress.finished));
+        }
+        assert_eq!(
+            steps,
+            vec![(2, 4, false), (3, 4, false), (4, 4, false), (4, 4, true)]
+        );
+        assert_eq!(store.pending_rotation().await?, None);
+
+        for name in ["aes_key/a", "aes_key/legacy", "x25519/a", "x25519/b"] {
+            let raw = inner.get_secret(name).await?.ok_or("missing secret")?;
+            let sealed_with = sealed_parts(&raw)?.map(|(id, _)| id.to_string());
+            assert_eq!(sealed_with.as_deref(), Some(key_id.as_str()), "{}", name);
+        }
+        assert_eq!(
+            store.get_secret("aes_key/legacy").await?,
+            Some(b"legacy".to_vec())
+        );
+        assert_eq!(
+            store.get_secret("x25519/a").await?,
+            Some(b"x25519/a".to_vec())
+        );
+
+        let keyring: DataKeyring =
+            serde_json::from_slice(&inner.get_secret(KEYRING_NAME).await?.ok_or("no keyring")?)?;
+        assert_eq!(keyring.active_key_id, key_id);
+        assert_eq!(keyring.keys.keys().collect::<Vec<_>>(), vec![&key_id]);
+        Ok(())
+    }
+
+    // 키체인이 없을 때의 한계: 메모리 저장소는 재시작하면 비어 있으므로 이어 갈 것이 없다
+    #[tokio::test]
+    async fn memory_backed_keyring_is_lost_on_restart() -> Result<(), Box<dyn Error>> {
+        let inner = Arc::new(MemorySecretStore::new());
+        let store = SealedSecretStore::new(inner.clone());
+        store.put_secret("aes_key/a", b"secret a").await?;
+        store.begin_rotation().await?;
+        let sealed = inner
+            .get_secret("aes_key/a")
+            .await?
+            .ok_or("missing secret")?;
+
+        let restarted_inner = Arc::new(MemorySecretStore::new());
+        let restarted = SealedSecretStore::new(restarted_inner.clone());
+        assert_eq!(restarted.pending_rotation().await?, None);
+        assert!(restarted.secret_names().await?.is_empty());
+        assert_eq!(restarted.get_secret("aes_key/a").await?, None);
+
+        // 예전 키로 봉인한 값을 옮겨 와도 새 키 묶음으로는 풀 수 없다
+        restarted.put_secret("aes_key/b", b"secret b").await?;
+        restarted_inner.put_secret("aes_key/a", &sealed).await?;
+        assert!(restarted.get_secret("aes_key/a").await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn tampered_secrets_do_not_open_and_stop_the_rotation() -> Result<(), Box<dyn Error>> {
+        let inner = Arc::new(MemorySecretStore::new());
+        let store = SealedSecretStore::new(inner.clone());
+        store.put_secret("aes_key/a", b"secret a").await?;
+        store.put_secret("aes_key/b", b"secret b").await?;
+
+        // 암호문 한 바이트가 바뀐 값
+        let mut tampered = inner
+            .get_secret("aes_key/a")
+            .await?
+            .ok_or("missing secret")?;
+        if let Some(last) = tampered.last_mut() {
+            *last ^= 0x01;
+        }
+        inner.put_secret("aes_key/a", &tampered).await?;
+        assert!(store.get_secret("aes_key/a").await.is_err());
+
+        // 다른 이름 자리로 옮긴 값과 머리만 남은 값
+        let moved = inner
+            .get_secret("aes_key/b")
+            .await?
+            .ok_or("missing secret")?;
+        inner.put_secret("x25519/b", &moved).await?;
+        assert!(store.get_secret("x25519/b").await.is_err());
+        inner
+            .put_secret("x25519/c", &moved[..MAGIC.len() + 1])
+            .await?;
+        assert!(store.get_secret("x25519/c").await.is_err());
+        assert_eq!(
+            store.get_secret("aes_key/b").await?,
+            Some(b"secret b".to_vec())
+        );
+
+        // 교체는 변조된 값에서 멈추고, 값을 덮어쓰지 않은 채 진행 중으로 남는다
+        let key_id = store.begin_rotation().await?;
+        assert!(store.rotate_step().await.is_err());
+        assert_eq!(store.pending_rotation().await?, Some(key_id));
+        assert_eq!(inner.get_secret("aes_key/a").await?, Some(tampered));
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use async_trait::async_trait;
+use std::{collections::HashMap, sync::Mutex};
+use zeroize::Zeroize;
+
+use crate::study_actors::messages::StorageError;
+
+// 비밀 값(암호화 키 등) 저장소
+// 실제 구현에서는 플랫폼 키체인/키스토어를 사용해야 한다.
+#[async_trait]
+pub trait SecretStore: Send + Sync + 'static {
+    async fn put_secret(&self, name: &str, secret: &[u8]) -> Result<(), StorageError>;
+    async fn get_secret(&self, name: &str) -> Result<Option<Vec<u8>>, StorageError>;
+    // 저장된 비밀 값의 이름 (이름 순서, 키 교체 작업이 훑는다)
+    async fn secret_names(&self) -> Result<Vec<String>, StorageError>;
+    // 모든 비밀 값 삭제 (원격 삭제용)
+    async fn clear_secrets(&self) -> Result<(), StorageError>;
+}
+
+// 메모리 기반 비밀 저장소 (앱 재시작 시 사라짐)
+#[derive(Default)]
+pub struct MemorySecretStore {
+    secrets: Mutex<HashMap<String, Vec<u8>>>,
+}
+
+impl MemorySecretStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl SecretStore for MemorySecretStore {
+    async fn put_secret(&self, name: &str, secret: &[u8]) -> Result<(), StorageError> {
+        let mut secrets = self
+            .secrets
+            .lock()
+            .map_err(|_| StorageError::LockPoisoned)?;
+        secrets.insert(name.to_string(), secret.to_vec());
+        Ok(())
+    }
+
+    async fn get_secret(&self, name: &str) -> Result<Option<Vec<u8>>, StorageError> {
+        let secrets = self
+            .secrets
+            .lock()
+            .map_err(|_| StorageError::LockPoisoned)?;
+        Ok(secrets.get(name).cloned())
+    }
+
+    async fn secret_names(&self) -> Result<Vec<String>, StorageError> {
+        let secrets = self
+            .secrets
+            .lock()
+            .map_err(|_| StorageError::LockPoisoned)?;
+        let mut names: Vec<String> = secrets.keys().cloned().collect();
+        names.sort();
+        Ok(names)
+    }
+
+    async fn clear_secrets(&self) -> Result<(), StorageError> {
+        let mut secrets = self
+            .secrets
+            .lock()
+            .map_err(|_| StorageError::LockPoisoned)?;
+        for (_, mut secret) in secrets.drain() {
+            secret.zeroize();
+        }
+        Ok(())
+    }
+}
@@ -1,49 +1,67 @@
 use async_trait::async_trait;
-use rinf::debug_print;
-use std::path::Path;
 
-use crate::study_actors::messages::StorageError;
+use crate::study_actors::logging::debug_print;
+use crate::study_actors::messages::{CompactionStats, StorageError};
 use super::Storage;
 
 pub struct SledStorage {
-    db_name: String,
-    // 실제 구현에서는 sled::Db 인스턴스 필요
+    db: sled::Db,
 }
 
 impl SledStorage {
-    pub async fn new(db_name: &str) -> Self {
-        // 실제 구현에서는 sled::open(db_path) 호출
-        debug_print!("Opening sled database: {}", db_name);
-        
-        Self {
-            db_name: db_name.to_string(),
-        }
+    // 감독자 생성 시점에 바로 사용할 수 있도록 동기적으로 연다
+    pub fn open(db_path: &str) -> Result<Self, StorageError> {
+        debug_print!("Opening sled database: {}", db_path);
+        Ok(Self {
+            db: sled::open(db_path)?,
+        })
     }
 }
 
 #[async_trait]
 impl Storage for SledStorage {
     async fn save(&self, key: &str, data: &[u8]) -> Result<(), StorageError> {
-        // 실제 구현에서는 self.db.insert(key, data)
-        debug_print!("Saving {} bytes to key: {}", data.len(), key);
+        self.db.insert(key, data)?;
+        self.db.flush_async().await?;
         Ok(())
     }
-    
+
     async fn load(&self, key: &str) -> Result<Vec<u8>, StorageError> {
-        // 실제 구현에서는 self.db.get(key)
-        debug_print!("Loading data for key: {}", key);
-        Err(format!("Key not found: {}", key).into())
+        match self.db.get(key)? {
+            Some(value) => Ok(value.to_vec()),
+            None => Err(StorageError::NotFound(key.to_string())),
+        }
     }
-    
+
     async fn delete(&self, key: &str) -> Result<(), StorageError> {
-        // 실제 구현에서는 self.db.remove(key)
-        debug_print!("Deleting key: {}", key);
+        self.db.remove(key)?;
+        Ok(())
+    }
+
+    async fn clear(&self) -> Result<(), StorageError> {
+        self.db.clear()?;
+        self.db.flush_async().await?;
         Ok(())
     }
-    
-    async fn exists(&self, key: &str) -> Result<bool, StorageError> {
-        // 실제 구현에서는 self.db.contains_key(key)
-        debug_print!("Checking if key exists: {}", key);
-        Ok(false)
+
+    async fn scan_prefix(&self, prefix: &str) -> Result<Vec<(String, Vec<u8>)>, StorageError> {
+        self.db
+            .scan_prefix(prefix)
+            .map(|entry| {
+                let (key, value) = entry?;
+                Ok((String::from_utf8_lossy(&key).into_owned(), value.to_vec()))
+            })
+            .collect()
+    }
+
+    // sled는 비어 가는 로그 세그먼트를 쓰기와 flush 때 스스로 옮겨 적고 회수하며,
+    // 직접 압축하는 API는 없다. 밀린 쓰기를 내려 보내 회수를 앞당기고 전후 크기를 알린다.
+    async fn compact(&self) -> Result<Option<CompactionStats>, StorageError> {
+        let bytes_before = self.db.size_on_disk()?;
+        self.db.flush_async().await?;
+        Ok(Some(CompactionStats {
+            bytes_before,
+            bytes_after: self.db.size_on_disk()?,
+        }))
     }
 }
@@ -0,0 +1,162 @@
+use messages::{
+    actor::Actor,
+    prelude::{Address, Context, Handler, Notifiable, SendError},
+};
+use serde::{Serialize, de::DeserializeOwned};
+use std::{cell::RefCell, time::Duration};
+use tokio::{sync::mpsc, task::JoinHandle};
+
+use crate::study_actors::{
+    messages::{CacheData, FetchData, FetchMode, Login, StoreData, VerifyToken},
+    signals::CapturedSignal,
+};
+
+// 액터 테스트 도구
+// - 시간: #[tokio::test(start_paused = true)]로 시작하고 advance()로 앞당긴다.
+//   TrustedClock도 tokio 시계를 따르므로 토큰/캐시 만료를 기다리지 않고 확인할 수 있다.
+// - 신호: EmitSignal, EmitBinarySignal로 나가는 Dart 신호를 FFI 대신 하네스의 채널로 받는다.
+//   단일 스레드 테스트 런타임 기준이라 수집기는 스레드별로 둔다.
+
+thread_local! {
+    static CAPTURE: RefCell<Vec<mpsc::UnboundedSender<CapturedSignal>>> =
+        const { RefCell::new(Vec::new()) };
+}
+
+// 이 스레드에 하네스가 있으면 신호를 넘기고 true를 돌려준다
+pub(crate) fn capture<T: Serialize>(signal: &T, binary: &[u8]) -> bool {
+    CAPTURE.with(|senders| {
+        let mut senders = senders.borrow_mut();
+        senders.retain(|sender| !sender.is_closed());
+        if senders.is_empty() {
+            return false;
+        }
+        let captured = CapturedSignal::new(signal, binary);
+        for sender in senders.iter() {
+            let _ = sender.send(captured.clone());
+        }
+        true
+    })
+}
+
+// 액터 하나를 띄우고 메시지 전달, 시간 이동, 신호 확인을 돕는다
+pub struct TestActorHarness<A: Actor> {
+    addr: Address<A>,
+    signals: mpsc::UnboundedReceiver<CapturedSignal>,
+    task: JoinHandle<()>,
+}
+
+impl<A: Actor + Send + 'static> TestActorHarness<A> {
+    pub fn start(build: impl FnOnce(Address<A>) -> A) -> Self {
+        let (sender, signals) = mpsc::unbounded_channel();
+        CAPTURE.with(|senders| senders.borrow_mut().push(sender));
+
+        let context = Context::new();
+        let addr = context.address();
+        let actor = build(addr.clone());
+        let task = tokio::spawn(async move {
+            context.run(actor).await;
+        });
+        Self {
+            addr,
+            signals,
+            task,
+        }
+    }
+
+    pub fn addr(&self) -> Address<A> {
+        self.addr.clone()
+    }
+
+    pub async fn send<M>(&mut self, msg: M) -> Result<A::Result, SendError>
+    where
+        A: Handler<M>,
+        A::Result: Send + Sync,
+        M: Send + 'static,
+    {
+        self.addr.send(msg).await
+    }
+
+    pub async fn notify<M>(&mut self, msg: M) -> Result<(), SendError>
+    where
+        A: Notifiable<M>,
+        M: Send + 'static,
+    {
+        self.addr.notify(msg).await
+    }
+
+    // 멈춘 시계를 앞당기고, 깨어난 주기 작업이 메시지를 넣을 때까지 양보한다
+    pub async fn advance(&self, duration: Duration) {
+        tokio::time::advance(duration).await;
+        settle().await;
+    }
+
+    // 지금까지 수집된 신호를 모두 꺼낸다
+    pub fn drain_signals(&mut self) -> Vec<CapturedSignal> {
+        let mut drained = Vec::new();
+        while let Ok(signal) = self.signals.try_recv() {
+            drained.push(signal);
+        }
+        drained
+    }
+
+    // 지금까지 수집된 신호 중 T 타입만 해석해 꺼낸다 (다른 신호는 버려진다)
+    pub fn signals_of<T: DeserializeOwned>(&mut self) -> Vec<T> {
+        self.drain_signals()
+            .iter()
+            .filter_map(CapturedSignal::decode)
+            .collect()
+    }
+}
+
+impl<A: Actor> Drop for TestActorHarness<A> {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+// 다른 작업들이 한 차례씩 실행될 기회를 준다
+pub async fn settle() {
+    for _ in 0..16 {
+        tokio::task::yield_now().await;
+    }
+}
+
+// 자주 쓰는 메시지 생성
+pub fn fetch_data(key: &str) -> FetchData {
+    FetchData {
+        key: key.to_string(),
+        user_id: None,
+        mode: FetchMode::CacheFirst,
+    }
+}
+
+pub fn store_data(key: &str, data: &[u8]) -> StoreData {
+    StoreData {
+        key: key.to_string(),
+        data: data.to_vec(),
+        user_id: None,
+        ttl: None,
+    }
+}
+
+pub fn cache_data(key: &str, data: &[u8], ttl_secs: Option<u64>) -> CacheData {
+    CacheData {
+        key: key.to_string(),
+        data: data.into(),
+        ttl: ttl_secs,
+    }
+}
+
+// AuthActor의 데모 계정으로 로그인
+pub fn demo_login() -> Login {
+    Login {
+        username: "demo".to_string(),
+        password: "password".to_string(),
+    }
+}
+
+pub fn verify_token(token: &str) -> VerifyToken {
+    VerifyToken {
+        token: token.to_string(),
+    }
+}
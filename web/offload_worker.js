@@ -0,0 +1,17 @@
+// Runs heavy Rust jobs (fractal frames, encryption) off the browser's main thread.
+// The main thread posts { id, name, input } from native/hub/src/study_actors/actors/offload.rs
+// and this worker answers with { id, output } or { id, error }.
+import init, { run_worker_job } from "./pkg/hub.js";
+
+const ready = init();
+
+self.onmessage = async (event) => {
+  const { id, name, input } = event.data;
+  try {
+    await ready;
+    const output = run_worker_job(name, input);
+    self.postMessage({ id, output }, [output.buffer]);
+  } catch (error) {
+    self.postMessage({ id, error: String(error) });
+  }
+};